@@ -1,4 +1,4 @@
-use std::{convert::From, ops::Deref};
+use std::{convert::From, fmt, ops::Deref};
 
 mod arena;
 
@@ -8,11 +8,85 @@ use bumpalo::Bump;
 #[derive(Default)]
 pub struct Allocator {
     bump: Bump,
+    /// See [`Allocator::with_memory_limit`]
+    memory_limit: Option<usize>,
+}
+
+/// The arena of an [`Allocator`] outgrew its memory limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLimitExceeded {
+    pub limit: usize,
+    /// Bytes allocated by the arena when the limit was checked
+    pub allocated: usize,
+}
+
+impl fmt::Display for MemoryLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes allocated, exceeding the limit of {} bytes", self.allocated, self.limit)
+    }
+}
+
+impl std::error::Error for MemoryLimitExceeded {}
+
+/// Arena bytes taken by the AST and semantic data of a file per byte of its source text,
+/// a rough average over JavaScript and TypeScript code, see [`Allocator::capacity_for_source_len`]
+const ARENA_BYTES_PER_SOURCE_BYTE: usize = 8;
+
+impl Allocator {
+    /// An allocator with a first chunk of `capacity` bytes.
+    ///
+    /// The arena of [`Allocator::default`] starts small and doubles its chunk size as it grows,
+    /// reserving the memory for a big file upfront avoids the allocations and the memory
+    /// left unused in the smaller chunks, and makes the peak memory usage predictable,
+    /// e.g. in WASM where memory cannot be returned.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { bump: Bump::with_capacity(capacity), memory_limit: None }
+    }
+
+    /// An estimate of the capacity needed for processing a source text of `source_len` bytes,
+    /// to be passed to [`Allocator::with_capacity`].
+    pub fn capacity_for_source_len(source_len: usize) -> usize {
+        source_len.saturating_mul(ARENA_BYTES_PER_SOURCE_BYTE)
+    }
+
+    /// An allocator for processing a single file, e.g. an untrusted or generated one,
+    /// which must not take down the whole process by exhausting its memory.
+    ///
+    /// Allocations never fail: the arena can grow past `limit` bytes, consumers call
+    /// [`Allocator::check_memory_limit`] regularly and abort their work with an error
+    /// once it is exceeded. The parser does so for every statement and expression.
+    pub fn with_memory_limit(limit: usize) -> Self {
+        Self { bump: Bump::new(), memory_limit: Some(limit) }
+    }
+
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
+    /// Set or remove the limit of [`Allocator::with_memory_limit`].
+    ///
+    /// The capacity reserved by [`Allocator::with_capacity`] counts as allocated.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit;
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if the arena has allocated more than the limit of
+    /// [`Allocator::with_memory_limit`].
+    pub fn check_memory_limit(&self) -> Result<(), MemoryLimitExceeded> {
+        match self.memory_limit {
+            Some(limit) if self.bump.allocated_bytes() > limit => {
+                Err(MemoryLimitExceeded { limit, allocated: self.bump.allocated_bytes() })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl From<Bump> for Allocator {
     fn from(bump: Bump) -> Self {
-        Self { bump }
+        Self { bump, memory_limit: None }
     }
 }
 
@@ -28,7 +102,7 @@ impl Deref for Allocator {
 mod test {
     use std::ops::Deref;
 
-    use crate::Allocator;
+    use crate::{Allocator, Vec};
     use bumpalo::Bump;
 
     #[test]
@@ -40,4 +114,39 @@ mod test {
             _ = allocator.deref();
         }
     }
+
+    #[test]
+    fn memory_limit() {
+        let allocator = Allocator::with_memory_limit(1024);
+        assert_eq!(allocator.memory_limit(), Some(1024));
+        assert!(allocator.check_memory_limit().is_ok());
+        let mut vec = Vec::new_in(&allocator);
+        vec.extend(std::iter::repeat(0u8).take(4096));
+        let exceeded = allocator.check_memory_limit().unwrap_err();
+        assert_eq!(exceeded.limit, 1024);
+        assert!(exceeded.allocated >= 4096);
+
+        assert!(Allocator::default().check_memory_limit().is_ok());
+    }
+
+    #[test]
+    fn capacity() {
+        let capacity = Allocator::capacity_for_source_len(1024);
+        assert!(capacity > 1024);
+        assert_eq!(Allocator::capacity_for_source_len(usize::MAX), usize::MAX);
+
+        let mut allocator = Allocator::with_capacity(capacity);
+        let reserved = allocator.allocated_bytes();
+        assert!(reserved >= capacity);
+        {
+            let mut vec = Vec::with_capacity_in(capacity / 2, &allocator);
+            vec.extend(std::iter::repeat(0u8).take(capacity / 2));
+        }
+        // No chunk was added
+        assert_eq!(allocator.allocated_bytes(), reserved);
+
+        assert_eq!(allocator.memory_limit(), None);
+        allocator.set_memory_limit(Some(capacity / 2));
+        assert!(allocator.check_memory_limit().is_err());
+    }
 }
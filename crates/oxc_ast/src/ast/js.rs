@@ -1,8 +1,9 @@
 use std::{cell::Cell, fmt, hash::Hash};
 
 use oxc_allocator::{Box, Vec};
-use oxc_span::{Atom, SourceType, Span};
+use oxc_span::{Atom, GetSpan, SourceType, Span};
 use oxc_syntax::{
+    module_record::ImportPhase,
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
     },
@@ -81,6 +82,13 @@ pub enum Expression<'a> {
     YieldExpression(Box<'a, YieldExpression<'a>>),
     PrivateInExpression(Box<'a, PrivateInExpression<'a>>),
 
+    PipelineExpression(Box<'a, PipelineExpression<'a>>),
+    DoExpression(Box<'a, DoExpression<'a>>),
+    ThrowExpression(Box<'a, ThrowExpression<'a>>),
+
+    /// Placeholder of a missing expression, see [`ErrorExpression`]
+    ErrorExpression(Box<'a, ErrorExpression>),
+
     JSXElement(Box<'a, JSXElement<'a>>),
     JSXFragment(Box<'a, JSXFragment<'a>>),
 
@@ -1101,6 +1109,55 @@ pub struct AwaitExpression<'a> {
     pub argument: Expression<'a>,
 }
 
+/// Pipeline Expression `left |> right`, calls `right` with `left`
+///
+/// The minimal variant of <https://github.com/tc39/proposal-pipeline-operator>
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct PipelineExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub left: Expression<'a>,
+    pub right: Expression<'a>,
+}
+
+/// Do Expression `do { ... }`, evaluates to the completion value of its block
+///
+/// Proposal <https://github.com/tc39/proposal-do-expressions>
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct DoExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Box<'a, BlockStatement<'a>>,
+}
+
+/// Throw Expression `throw argument` in expression position
+///
+/// Proposal <https://github.com/tc39/proposal-throw-expressions>
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct ThrowExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub argument: Expression<'a>,
+}
+
+/// Placeholder of an expression which is missing from the source text, e.g. the right side
+/// of `a = ;`, inserted by the parser in error recovery mode.
+///
+/// The span is empty, at the token where the expression was expected.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct ErrorExpression {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -1153,6 +1210,9 @@ pub enum Statement<'a> {
     WhileStatement(Box<'a, WhileStatement<'a>>),
     WithStatement(Box<'a, WithStatement<'a>>),
 
+    /// Source text which could not be parsed, see [`ErrorStatement`]
+    ErrorStatement(Box<'a, ErrorStatement>),
+
     ModuleDeclaration(Box<'a, ModuleDeclaration<'a>>),
     Declaration(Declaration<'a>),
 }
@@ -1511,6 +1571,13 @@ pub struct SwitchStatement<'a> {
     pub cases: Vec<'a, SwitchCase<'a>>,
 }
 
+impl<'a> SwitchStatement<'a> {
+    /// Span from the end of the discriminant to the closing brace, which contains the cases.
+    pub fn body_span(&self) -> Span {
+        Span::new(self.discriminant.span().end, self.span.end)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -1579,6 +1646,18 @@ pub struct DebuggerStatement {
     pub span: Span,
 }
 
+/// Source text which could not be parsed as a statement, inserted by the parser in
+/// error recovery mode.
+///
+/// The span covers the tokens skipped up to the next statement.
+#[derive(Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct ErrorStatement {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
 /// Destructuring Binding Patterns
 /// * <https://tc39.es/ecma262/#prod-BindingPattern>
 #[derive(Debug, Hash)]
@@ -1760,6 +1839,23 @@ impl<'a> Function<'a> {
             body.directives.iter().any(|directive| directive.directive == "use strict")
         })
     }
+
+    /// `foo` of `function foo() {}`
+    pub fn name_span(&self) -> Option<Span> {
+        self.id.as_ref().map(|id| id.span)
+    }
+
+    /// The body including its braces, `None` for functions without a body
+    pub fn body_span(&self) -> Option<Span> {
+        self.body.as_ref().map(|body| body.span)
+    }
+
+    /// The function up to the end of its parameters or return type,
+    /// e.g. `function foo(a): void` of `function foo(a): void {}`
+    pub fn signature_span(&self) -> Span {
+        let end = self.return_type.as_ref().map_or(self.params.span.end, |ty| ty.span.end);
+        Span::new(self.span.start, end)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1872,6 +1968,18 @@ impl<'a> ArrowFunctionExpression<'a> {
         }
         None
     }
+
+    /// The body, i.e. the expression of `() => expression` or the block with its braces
+    pub fn body_span(&self) -> Span {
+        self.body.span
+    }
+
+    /// The arrow function up to the end of its parameters or return type,
+    /// e.g. `async (a): void` of `async (a): void => {}`
+    pub fn signature_span(&self) -> Span {
+        let end = self.return_type.as_ref().map_or(self.params.span.end, |ty| ty.span.end);
+        Span::new(self.span.start, end)
+    }
 }
 
 /// Generator Function Definitions
@@ -1920,6 +2028,28 @@ impl<'a> Class<'a> {
     pub fn is_typescript_syntax(&self) -> bool {
         self.is_declare()
     }
+
+    /// `Foo` of `class Foo {}`
+    pub fn name_span(&self) -> Option<Span> {
+        self.id.as_ref().map(|id| id.span)
+    }
+
+    /// The body including its braces
+    pub fn body_span(&self) -> Span {
+        self.body.span
+    }
+
+    /// The class without its decorators, e.g. `class Foo {}` of `@dec class Foo {}`
+    pub fn span_without_decorators(&self, source_text: &str) -> Span {
+        span_without_decorators(self.span, &self.decorators, source_text)
+    }
+}
+
+/// The part of `span` after the last of its `decorators`
+fn span_without_decorators(span: Span, decorators: &[Decorator], source_text: &str) -> Span {
+    decorators
+        .last()
+        .map_or(span, |decorator| Span::new(decorator.span.end, span.end).trim(source_text))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -2081,6 +2211,23 @@ pub struct MethodDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
+impl<'a> MethodDefinition<'a> {
+    /// The key, e.g. `foo` of `static async foo() {}`
+    pub fn name_span(&self) -> Span {
+        self.key.span()
+    }
+
+    /// The body including its braces, `None` for overloads without a body
+    pub fn body_span(&self) -> Option<Span> {
+        self.value.body_span()
+    }
+
+    /// The method without its decorators, modifiers such as `static` are kept
+    pub fn span_without_decorators(&self, source_text: &str) -> Span {
+        span_without_decorators(self.span, &self.decorators, source_text)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -2101,6 +2248,18 @@ pub struct PropertyDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
+impl<'a> PropertyDefinition<'a> {
+    /// The key, e.g. `foo` of `private readonly foo = 1`
+    pub fn name_span(&self) -> Span {
+        self.key.span()
+    }
+
+    /// The property without its decorators, modifiers such as `static` are kept
+    pub fn span_without_decorators(&self, source_text: &str) -> Span {
+        span_without_decorators(self.span, &self.decorators, source_text)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -2213,6 +2372,18 @@ pub struct AccessorProperty<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
+impl<'a> AccessorProperty<'a> {
+    /// The key, e.g. `foo` of `accessor foo = 1`
+    pub fn name_span(&self) -> Span {
+        self.key.span()
+    }
+
+    /// The property without its decorators, modifiers such as `static` are kept
+    pub fn span_without_decorators(&self, source_text: &str) -> Span {
+        span_without_decorators(self.span, &self.decorators, source_text)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -2236,6 +2407,8 @@ pub struct ImportDeclaration<'a> {
     pub with_clause: Option<WithClause<'a>>,
     /// `import type { foo } from 'bar'`
     pub import_kind: ImportOrExportKind,
+    /// `import source x from 'foo.wasm'` and `import defer * as ns from 'foo'`
+    pub phase: Option<ImportPhase>,
 }
 
 #[derive(Debug, Hash)]
@@ -2330,6 +2503,8 @@ pub struct ExportNamedDeclaration<'a> {
     pub declaration: Option<Declaration<'a>>,
     pub specifiers: Vec<'a, ExportSpecifier>,
     pub source: Option<StringLiteral>,
+    /// `export { foo } from "mod" with { type: "json" }`
+    pub with_clause: Option<WithClause<'a>>,
     /// `export type { foo }`
     pub export_kind: ImportOrExportKind,
 }
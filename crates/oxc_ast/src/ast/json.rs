@@ -0,0 +1,101 @@
+//! JSON values, e.g. of `package.json` and `tsconfig.json`
+//!
+//! Parsed by `Parser::parse_json` of `oxc_parser`, which also accepts JSONC and JSON5.
+
+use oxc_allocator::{Box, Vec};
+use oxc_span::Span;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::ast::{BooleanLiteral, NullLiteral, NumericLiteral, StringLiteral};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub enum JsonValue<'a> {
+    Null(NullLiteral),
+    Boolean(BooleanLiteral),
+    /// Includes the sign, and `Infinity` and `NaN` in JSON5
+    Number(NumericLiteral<'a>),
+    String(StringLiteral),
+    Array(Box<'a, JsonArray<'a>>),
+    Object(Box<'a, JsonObject<'a>>),
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct JsonArray<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub elements: Vec<'a, JsonValue<'a>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct JsonObject<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// In source order, duplicate keys are kept
+    pub properties: Vec<'a, JsonProperty<'a>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub struct JsonProperty<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// Unquoted JSON5 keys are strings as well, spanning the identifier
+    pub key: StringLiteral,
+    pub value: JsonValue<'a>,
+}
+
+impl<'a> JsonValue<'a> {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(literal) => Some(literal.value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(literal) => Some(literal.value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(literal) => Some(literal.value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&JsonArray<'a>> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&JsonObject<'a>> {
+        match self {
+            Self::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null(_))
+    }
+}
+
+impl<'a> JsonObject<'a> {
+    /// The value of the last property named `key`, as `JSON.parse` keeps the last duplicate.
+    pub fn get(&self, key: &str) -> Option<&JsonValue<'a>> {
+        self.properties.iter().rev().find(|property| property.key.value == key).map(|p| &p.value)
+    }
+}
@@ -1,6 +1,7 @@
 //! Literals
 
 use std::{
+    borrow::Cow,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -72,6 +73,38 @@ impl<'a> NumericLiteral<'a> {
         Self { span, value, raw, base }
     }
 
+    /// Whether `raw` is still the source text of `value`.
+    ///
+    /// Transforms may change `value` without updating `raw`, codegen only preserves `raw`
+    /// (e.g. `1.50`, `1e21` or `0xFF`) when this returns `true`.
+    pub fn is_raw_exact(&self) -> bool {
+        let (is_negative, raw) =
+            self.raw.strip_prefix('-').map_or((false, self.raw), |raw| (true, raw));
+        let raw = if raw.contains('_') { Cow::Owned(raw.replace('_', "")) } else { raw.into() };
+        let radix = match self.base {
+            NumberBase::Float | NumberBase::Decimal => 10,
+            NumberBase::Binary => 2,
+            NumberBase::Octal => 8,
+            NumberBase::Hex => 16,
+        };
+        let value = if radix == 10 {
+            raw.parse::<f64>().ok()
+        } else {
+            // `0xFF`, `0o17`, `0b11` and the legacy octal `017`
+            let digits = match raw.as_bytes().get(1) {
+                Some(b) if b.is_ascii_alphabetic() => &raw[2..],
+                _ => raw.get(1..).unwrap_or_default(),
+            };
+            digits.chars().try_fold(0.0, |value, c| {
+                c.to_digit(radix).map(|digit| value * f64::from(radix) + f64::from(digit))
+            })
+        };
+        value.is_some_and(|value| {
+            let value = if is_negative { -value } else { value };
+            value.to_bits() == self.value.to_bits()
+        })
+    }
+
     /// port from [closure compiler](https://github.com/google/closure-compiler/blob/a4c880032fba961f7a6c06ef99daa3641810bfdd/src/com/google/javascript/jscomp/base/JSCompDoubles.java#L113)
     /// <https://262.ecma-international.org/5.1/#sec-9.5>
     #[allow(clippy::cast_possible_truncation)] // for `as i32`
@@ -169,7 +202,7 @@ bitflags! {
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type RegExpFlags = {
     G: 1,
     I: 2,
@@ -180,7 +213,7 @@ export type RegExpFlags = {
     D: 64,
     V: 128
 };
-"#;
+";
 
 impl TryFrom<char> for RegExpFlags {
     type Error = char;
@@ -242,11 +275,14 @@ pub struct StringLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub value: Atom,
+    /// The literal contains escape sequences, so `value` is not a slice of the source text
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub escaped: bool,
 }
 
 impl StringLiteral {
     pub fn new(span: Span, value: Atom) -> Self {
-        Self { span, value }
+        Self { span, value, escaped: false }
     }
 
     /// Static Semantics: `IsStringWellFormedUnicode`
@@ -2,8 +2,9 @@
 
 mod js;
 mod jsdoc;
+mod json;
 mod jsx;
 mod literal;
 mod ts;
 
-pub use self::{js::*, jsdoc::*, jsx::*, literal::*, ts::*};
+pub use self::{js::*, jsdoc::*, json::*, jsx::*, literal::*, ts::*};
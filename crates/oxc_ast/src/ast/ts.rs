@@ -9,17 +9,17 @@ use oxc_span::{Atom, GetSpan, Span};
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
-use crate::ast::*;
+use crate::{ast::*, Visit};
 
 #[cfg_attr(
     all(feature = "serde", feature = "wasm"),
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export interface TSAbstractPropertyDefinition extends Omit<PropertyDefinition, 'type'> {}
 export interface TSAbstractMethodDefinition extends Omit<MethodDefinition, 'type'> {}
-"#;
+";
 
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
@@ -46,6 +46,13 @@ pub struct TSEnumDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
+impl<'a> TSEnumDeclaration<'a> {
+    /// Span from the end of the name to the closing brace, which contains the members.
+    pub fn body_span(&self) -> Span {
+        Span::new(self.id.span.end, self.span.end)
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -147,6 +154,45 @@ impl<'a> TSType<'a> {
     pub fn is_const_type_reference(&self) -> bool {
         matches!(self, TSType::TSTypeReference(reference) if reference.type_name.is_const())
     }
+
+    /// Whether this is a reference to the unqualified type `name`, e.g. `Array<Foo>` for `Array`
+    pub fn is_type_reference_to(&self, name: &str) -> bool {
+        self.get_type_reference_to(name).is_some()
+    }
+
+    /// The reference to the unqualified type `name`, see [`TSType::is_type_reference_to`]
+    pub fn get_type_reference_to(&self, name: &str) -> Option<&TSTypeReference<'a>> {
+        match self {
+            TSType::TSTypeReference(reference) if reference.is_reference_to(name) => {
+                Some(reference)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the unqualified type `name` is referenced anywhere in this type,
+    /// including the type arguments and the constraints and defaults of type parameters,
+    /// e.g. `Foo` in `Array<Foo>` or `<T extends Foo>() => T`
+    pub fn contains_type_reference_to(&self, name: &str) -> bool {
+        let mut finder = TypeReferenceFinder { name, found: false };
+        finder.visit_ts_type(self);
+        finder.found
+    }
+}
+
+struct TypeReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a, 'n> Visit<'a> for TypeReferenceFinder<'n> {
+    fn visit_ts_type_reference(&mut self, ty: &TSTypeReference<'a>) {
+        if ty.is_reference_to(self.name) {
+            self.found = true;
+        } else if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
 }
 
 /// `SomeType extends OtherType ? TrueType : FalseType;`
@@ -403,6 +449,21 @@ pub struct TSTypeReference<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
+impl<'a> TSTypeReference<'a> {
+    /// Whether this is a reference to the unqualified type `name`
+    pub fn is_reference_to(&self, name: &str) -> bool {
+        matches!(&self.type_name, TSTypeName::IdentifierReference(ident) if ident.name == name)
+    }
+
+    /// The type arguments, e.g. `[A, B]` for `Map<A, B>`, empty if there are none
+    pub fn type_arguments(&self) -> &[TSType<'a>] {
+        match &self.type_parameters {
+            Some(parameters) => &parameters.params,
+            None => &[],
+        }
+    }
+}
+
 /// TypeName:
 ///     IdentifierReference
 ///     NamespaceName . IdentifierReference
@@ -10,6 +10,7 @@ use std::mem;
 use oxc_allocator::{Allocator, Box, String, Vec};
 use oxc_span::{Atom, GetSpan, SourceType, Span, SPAN};
 use oxc_syntax::{
+    module_record::ImportPhase,
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
     },
@@ -234,6 +235,10 @@ impl<'a> AstBuilder<'a> {
         Statement::DebuggerStatement(self.alloc(DebuggerStatement { span }))
     }
 
+    pub fn error_statement(&self, span: Span) -> Statement<'a> {
+        Statement::ErrorStatement(self.alloc(ErrorStatement { span }))
+    }
+
     pub fn using_statement(
         &self,
         span: Span,
@@ -625,6 +630,27 @@ impl<'a> AstBuilder<'a> {
         }))
     }
 
+    pub fn pipeline_expression(
+        &self,
+        span: Span,
+        left: Expression<'a>,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        Expression::PipelineExpression(self.alloc(PipelineExpression { span, left, right }))
+    }
+
+    pub fn do_expression(&self, span: Span, body: Box<'a, BlockStatement<'a>>) -> Expression<'a> {
+        Expression::DoExpression(self.alloc(DoExpression { span, body }))
+    }
+
+    pub fn throw_expression(&self, span: Span, argument: Expression<'a>) -> Expression<'a> {
+        Expression::ThrowExpression(self.alloc(ThrowExpression { span, argument }))
+    }
+
+    pub fn error_expression(&self, span: Span) -> Expression<'a> {
+        Expression::ErrorExpression(self.alloc(ErrorExpression { span }))
+    }
+
     pub fn private_field_expression(
         &self,
         span: Span,
@@ -1064,8 +1090,9 @@ impl<'a> AstBuilder<'a> {
         source: StringLiteral,
         with_clause: Option<WithClause<'a>>,
         import_kind: ImportOrExportKind,
+        phase: Option<ImportPhase>,
     ) -> Box<'a, ImportDeclaration<'a>> {
-        self.alloc(ImportDeclaration { span, specifiers, source, with_clause, import_kind })
+        self.alloc(ImportDeclaration { span, specifiers, source, with_clause, import_kind, phase })
     }
 
     pub fn export_all_declaration(
@@ -1094,9 +1121,17 @@ impl<'a> AstBuilder<'a> {
         declaration: Option<Declaration<'a>>,
         specifiers: Vec<'a, ExportSpecifier>,
         source: Option<StringLiteral>,
+        with_clause: Option<WithClause<'a>>,
         export_kind: ImportOrExportKind,
     ) -> Box<'a, ExportNamedDeclaration<'a>> {
-        self.alloc(ExportNamedDeclaration { span, declaration, specifiers, source, export_kind })
+        self.alloc(ExportNamedDeclaration {
+            span,
+            declaration,
+            specifiers,
+            source,
+            with_clause,
+            export_kind,
+        })
     }
 
     /* ---------- JSX ----------------- */
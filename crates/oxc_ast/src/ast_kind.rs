@@ -29,6 +29,7 @@ pub enum AstKind<'a> {
     TryStatement(&'a TryStatement<'a>),
     WhileStatement(&'a WhileStatement<'a>),
     WithStatement(&'a WithStatement<'a>),
+    ErrorStatement(&'a ErrorStatement),
 
     SwitchCase(&'a SwitchCase<'a>),
     CatchClause(&'a CatchClause<'a>),
@@ -77,6 +78,10 @@ pub enum AstKind<'a> {
     YieldExpression(&'a YieldExpression<'a>),
     ImportExpression(&'a ImportExpression<'a>),
     PrivateInExpression(&'a PrivateInExpression<'a>),
+    PipelineExpression(&'a PipelineExpression<'a>),
+    DoExpression(&'a DoExpression<'a>),
+    ThrowExpression(&'a ThrowExpression<'a>),
+    ErrorExpression(&'a ErrorExpression),
 
     ObjectProperty(&'a ObjectProperty<'a>),
     PropertyKey(&'a PropertyKey<'a>),
@@ -146,6 +151,19 @@ pub enum AstKind<'a> {
     TSTypeReference(&'a TSTypeReference<'a>),
     TSUnionType(&'a TSUnionType<'a>),
     TSVoidKeyword(&'a TSVoidKeyword),
+    TSBigIntKeyword(&'a TSBigIntKeyword),
+    TSBooleanKeyword(&'a TSBooleanKeyword),
+    TSNeverKeyword(&'a TSNeverKeyword),
+    TSNumberKeyword(&'a TSNumberKeyword),
+    TSObjectKeyword(&'a TSObjectKeyword),
+    TSStringKeyword(&'a TSStringKeyword),
+    TSSymbolKeyword(&'a TSSymbolKeyword),
+    TSThisType(&'a TSThisType),
+    TSUndefinedKeyword(&'a TSUndefinedKeyword),
+    TSUnknownKeyword(&'a TSUnknownKeyword),
+    TSImportType(&'a TSImportType<'a>),
+    TSInferType(&'a TSInferType<'a>),
+    TSTemplateLiteralType(&'a TSTemplateLiteralType<'a>),
 
     TSIndexedAccessType(&'a TSIndexedAccessType<'a>),
 
@@ -192,7 +210,7 @@ impl<'a> AstKind<'a> {
                     | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
                     | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
                     | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
-                    | Self::IfStatement(_) | Self::VariableDeclaration(_))
+                    | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::ErrorStatement(_))
     }
 
     #[rustfmt::skip]
@@ -225,6 +243,19 @@ impl<'a> AstKind<'a> {
                 | Self::TSLiteralType(_)
                 | Self::TSTypeReference(_)
                 | Self::TSMethodSignature(_)
+                | Self::TSBigIntKeyword(_)
+                | Self::TSBooleanKeyword(_)
+                | Self::TSNeverKeyword(_)
+                | Self::TSNumberKeyword(_)
+                | Self::TSObjectKeyword(_)
+                | Self::TSStringKeyword(_)
+                | Self::TSSymbolKeyword(_)
+                | Self::TSThisType(_)
+                | Self::TSUndefinedKeyword(_)
+                | Self::TSUnknownKeyword(_)
+                | Self::TSImportType(_)
+                | Self::TSInferType(_)
+                | Self::TSTemplateLiteralType(_)
         )
     }
 
@@ -310,6 +341,10 @@ impl<'a> AstKind<'a> {
             Expression::UpdateExpression(e) => Self::UpdateExpression(e),
             Expression::YieldExpression(e) => Self::YieldExpression(e),
             Expression::PrivateInExpression(e) => Self::PrivateInExpression(e),
+            Expression::PipelineExpression(e) => Self::PipelineExpression(e),
+            Expression::DoExpression(e) => Self::DoExpression(e),
+            Expression::ThrowExpression(e) => Self::ThrowExpression(e),
+            Expression::ErrorExpression(e) => Self::ErrorExpression(e),
             Expression::JSXElement(e) => Self::JSXElement(e),
             Expression::JSXFragment(e) => Self::JSXFragment(e),
             Expression::TSAsExpression(e) => Self::TSAsExpression(e),
@@ -348,6 +383,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TryStatement(x) => x.span,
             Self::WhileStatement(x) => x.span,
             Self::WithStatement(x) => x.span,
+            Self::ErrorStatement(x) => x.span,
 
             Self::SwitchCase(x) => x.span,
             Self::CatchClause(x) => x.span,
@@ -396,6 +432,10 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::YieldExpression(x) => x.span,
             Self::ImportExpression(x) => x.span,
             Self::PrivateInExpression(x) => x.span,
+            Self::PipelineExpression(x) => x.span,
+            Self::DoExpression(x) => x.span,
+            Self::ThrowExpression(x) => x.span,
+            Self::ErrorExpression(x) => x.span,
 
             Self::ObjectProperty(x) => x.span,
             Self::PropertyKey(x) => x.span(),
@@ -461,6 +501,19 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSTypeReference(x) => x.span,
             Self::TSUnionType(x) => x.span,
             Self::TSVoidKeyword(x) => x.span,
+            Self::TSBigIntKeyword(x) => x.span,
+            Self::TSBooleanKeyword(x) => x.span,
+            Self::TSNeverKeyword(x) => x.span,
+            Self::TSNumberKeyword(x) => x.span,
+            Self::TSObjectKeyword(x) => x.span,
+            Self::TSStringKeyword(x) => x.span,
+            Self::TSSymbolKeyword(x) => x.span,
+            Self::TSThisType(x) => x.span,
+            Self::TSUndefinedKeyword(x) => x.span,
+            Self::TSUnknownKeyword(x) => x.span,
+            Self::TSImportType(x) => x.span,
+            Self::TSInferType(x) => x.span,
+            Self::TSTemplateLiteralType(x) => x.span,
 
             Self::TSIndexedAccessType(x) => x.span,
 
@@ -492,6 +545,170 @@ impl<'a> GetSpan for AstKind<'a> {
 }
 
 impl<'a> AstKind<'a> {
+    /// Name of the kind, e.g. `"IfStatement"`, for statistics and reports
+    pub fn type_name(self) -> &'static str {
+        match self {
+            Self::Program(_) => "Program",
+            Self::Directive(_) => "Directive",
+            Self::Hashbang(_) => "Hashbang",
+            Self::BlockStatement(_) => "BlockStatement",
+            Self::BreakStatement(_) => "BreakStatement",
+            Self::ContinueStatement(_) => "ContinueStatement",
+            Self::DebuggerStatement(_) => "DebuggerStatement",
+            Self::DoWhileStatement(_) => "DoWhileStatement",
+            Self::EmptyStatement(_) => "EmptyStatement",
+            Self::ExpressionStatement(_) => "ExpressionStatement",
+            Self::ForInStatement(_) => "ForInStatement",
+            Self::ForOfStatement(_) => "ForOfStatement",
+            Self::ForStatement(_) => "ForStatement",
+            Self::ForStatementInit(_) => "ForStatementInit",
+            Self::IfStatement(_) => "IfStatement",
+            Self::LabeledStatement(_) => "LabeledStatement",
+            Self::ReturnStatement(_) => "ReturnStatement",
+            Self::SwitchStatement(_) => "SwitchStatement",
+            Self::ThrowStatement(_) => "ThrowStatement",
+            Self::TryStatement(_) => "TryStatement",
+            Self::WhileStatement(_) => "WhileStatement",
+            Self::WithStatement(_) => "WithStatement",
+            Self::ErrorStatement(_) => "ErrorStatement",
+            Self::SwitchCase(_) => "SwitchCase",
+            Self::CatchClause(_) => "CatchClause",
+            Self::FinallyClause(_) => "FinallyClause",
+            Self::VariableDeclaration(_) => "VariableDeclaration",
+            Self::VariableDeclarator(_) => "VariableDeclarator",
+            Self::UsingDeclaration(_) => "UsingDeclaration",
+            Self::IdentifierName(_) => "IdentifierName",
+            Self::IdentifierReference(_) => "IdentifierReference",
+            Self::BindingIdentifier(_) => "BindingIdentifier",
+            Self::LabelIdentifier(_) => "LabelIdentifier",
+            Self::PrivateIdentifier(_) => "PrivateIdentifier",
+            Self::NumericLiteral(_) => "NumericLiteral",
+            Self::StringLiteral(_) => "StringLiteral",
+            Self::BooleanLiteral(_) => "BooleanLiteral",
+            Self::NullLiteral(_) => "NullLiteral",
+            Self::BigintLiteral(_) => "BigintLiteral",
+            Self::RegExpLiteral(_) => "RegExpLiteral",
+            Self::TemplateLiteral(_) => "TemplateLiteral",
+            Self::MetaProperty(_) => "MetaProperty",
+            Self::Super(_) => "Super",
+            Self::ArrayExpression(_) => "ArrayExpression",
+            Self::ArrowFunctionExpression(_) => "ArrowFunctionExpression",
+            Self::AssignmentExpression(_) => "AssignmentExpression",
+            Self::AwaitExpression(_) => "AwaitExpression",
+            Self::BinaryExpression(_) => "BinaryExpression",
+            Self::CallExpression(_) => "CallExpression",
+            Self::ChainExpression(_) => "ChainExpression",
+            Self::ConditionalExpression(_) => "ConditionalExpression",
+            Self::LogicalExpression(_) => "LogicalExpression",
+            Self::MemberExpression(_) => "MemberExpression",
+            Self::NewExpression(_) => "NewExpression",
+            Self::ObjectExpression(_) => "ObjectExpression",
+            Self::ParenthesizedExpression(_) => "ParenthesizedExpression",
+            Self::SequenceExpression(_) => "SequenceExpression",
+            Self::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+            Self::ThisExpression(_) => "ThisExpression",
+            Self::UnaryExpression(_) => "UnaryExpression",
+            Self::UpdateExpression(_) => "UpdateExpression",
+            Self::YieldExpression(_) => "YieldExpression",
+            Self::ImportExpression(_) => "ImportExpression",
+            Self::PrivateInExpression(_) => "PrivateInExpression",
+            Self::PipelineExpression(_) => "PipelineExpression",
+            Self::DoExpression(_) => "DoExpression",
+            Self::ThrowExpression(_) => "ThrowExpression",
+            Self::ErrorExpression(_) => "ErrorExpression",
+            Self::ObjectProperty(_) => "ObjectProperty",
+            Self::PropertyKey(_) => "PropertyKey",
+            Self::Argument(_) => "Argument",
+            Self::AssignmentTarget(_) => "AssignmentTarget",
+            Self::SimpleAssignmentTarget(_) => "SimpleAssignmentTarget",
+            Self::AssignmentTargetWithDefault(_) => "AssignmentTargetWithDefault",
+            Self::ArrayExpressionElement(_) => "ArrayExpressionElement",
+            Self::Elision(_) => "Elision",
+            Self::ExpressionArrayElement(_) => "ExpressionArrayElement",
+            Self::SpreadElement(_) => "SpreadElement",
+            Self::BindingRestElement(_) => "BindingRestElement",
+            Self::Function(_) => "Function",
+            Self::FunctionBody(_) => "FunctionBody",
+            Self::FormalParameters(_) => "FormalParameters",
+            Self::FormalParameter(_) => "FormalParameter",
+            Self::Class(_) => "Class",
+            Self::ClassBody(_) => "ClassBody",
+            Self::ClassHeritage(_) => "ClassHeritage",
+            Self::StaticBlock(_) => "StaticBlock",
+            Self::PropertyDefinition(_) => "PropertyDefinition",
+            Self::MethodDefinition(_) => "MethodDefinition",
+            Self::ArrayPattern(_) => "ArrayPattern",
+            Self::ObjectPattern(_) => "ObjectPattern",
+            Self::AssignmentPattern(_) => "AssignmentPattern",
+            Self::Decorator(_) => "Decorator",
+            Self::ModuleDeclaration(_) => "ModuleDeclaration",
+            Self::ImportDeclaration(_) => "ImportDeclaration",
+            Self::ImportSpecifier(_) => "ImportSpecifier",
+            Self::ImportDefaultSpecifier(_) => "ImportDefaultSpecifier",
+            Self::ImportNamespaceSpecifier(_) => "ImportNamespaceSpecifier",
+            Self::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+            Self::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+            Self::ExportAllDeclaration(_) => "ExportAllDeclaration",
+            Self::JSXElement(_) => "JSXElement",
+            Self::JSXFragment(_) => "JSXFragment",
+            Self::JSXOpeningElement(_) => "JSXOpeningElement",
+            Self::JSXClosingElement(_) => "JSXClosingElement",
+            Self::JSXElementName(_) => "JSXElementName",
+            Self::JSXExpressionContainer(_) => "JSXExpressionContainer",
+            Self::JSXAttributeItem(_) => "JSXAttributeItem",
+            Self::JSXSpreadAttribute(_) => "JSXSpreadAttribute",
+            Self::JSXText(_) => "JSXText",
+            Self::JSXIdentifier(_) => "JSXIdentifier",
+            Self::JSXMemberExpression(_) => "JSXMemberExpression",
+            Self::JSXMemberExpressionObject(_) => "JSXMemberExpressionObject",
+            Self::JSXNamespacedName(_) => "JSXNamespacedName",
+            Self::TSModuleBlock(_) => "TSModuleBlock",
+            Self::TSAnyKeyword(_) => "TSAnyKeyword",
+            Self::TSIntersectionType(_) => "TSIntersectionType",
+            Self::TSLiteralType(_) => "TSLiteralType",
+            Self::TSMethodSignature(_) => "TSMethodSignature",
+            Self::TSNullKeyword(_) => "TSNullKeyword",
+            Self::TSTypeLiteral(_) => "TSTypeLiteral",
+            Self::TSTypeReference(_) => "TSTypeReference",
+            Self::TSUnionType(_) => "TSUnionType",
+            Self::TSVoidKeyword(_) => "TSVoidKeyword",
+            Self::TSBigIntKeyword(_) => "TSBigIntKeyword",
+            Self::TSBooleanKeyword(_) => "TSBooleanKeyword",
+            Self::TSNeverKeyword(_) => "TSNeverKeyword",
+            Self::TSNumberKeyword(_) => "TSNumberKeyword",
+            Self::TSObjectKeyword(_) => "TSObjectKeyword",
+            Self::TSStringKeyword(_) => "TSStringKeyword",
+            Self::TSSymbolKeyword(_) => "TSSymbolKeyword",
+            Self::TSThisType(_) => "TSThisType",
+            Self::TSUndefinedKeyword(_) => "TSUndefinedKeyword",
+            Self::TSUnknownKeyword(_) => "TSUnknownKeyword",
+            Self::TSImportType(_) => "TSImportType",
+            Self::TSInferType(_) => "TSInferType",
+            Self::TSTemplateLiteralType(_) => "TSTemplateLiteralType",
+            Self::TSIndexedAccessType(_) => "TSIndexedAccessType",
+            Self::TSAsExpression(_) => "TSAsExpression",
+            Self::TSSatisfiesExpression(_) => "TSSatisfiesExpression",
+            Self::TSNonNullExpression(_) => "TSNonNullExpression",
+            Self::TSInstantiationExpression(_) => "TSInstantiationExpression",
+            Self::TSEnumDeclaration(_) => "TSEnumDeclaration",
+            Self::TSEnumMember(_) => "TSEnumMember",
+            Self::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+            Self::TSTypeName(_) => "TSTypeName",
+            Self::TSExternalModuleReference(_) => "TSExternalModuleReference",
+            Self::TSQualifiedName(_) => "TSQualifiedName",
+            Self::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+            Self::TSModuleDeclaration(_) => "TSModuleDeclaration",
+            Self::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+            Self::TSTypeAnnotation(_) => "TSTypeAnnotation",
+            Self::TSTypeQuery(_) => "TSTypeQuery",
+            Self::TSTypeAssertion(_) => "TSTypeAssertion",
+            Self::TSTypeParameter(_) => "TSTypeParameter",
+            Self::TSTypeParameterDeclaration(_) => "TSTypeParameterDeclaration",
+            Self::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation",
+            Self::TSPropertySignature(_) => "TSPropertySignature",
+        }
+    }
+
     #[allow(clippy::match_same_arms)]
     /// Get the AST kind name with minimal details. Particularly useful for
     /// when debugging an iteration over an AST.
@@ -523,6 +740,7 @@ impl<'a> AstKind<'a> {
             Self::TryStatement(_) => "TryStatement".into(),
             Self::WhileStatement(_) => "WhileStatement".into(),
             Self::WithStatement(_) => "WithStatement".into(),
+            Self::ErrorStatement(_) => "ErrorStatement".into(),
 
             Self::SwitchCase(_) => "SwitchCase".into(),
             Self::CatchClause(_) => "CatchClause".into(),
@@ -575,6 +793,10 @@ impl<'a> AstKind<'a> {
             Self::YieldExpression(_) => "YieldExpression".into(),
             Self::ImportExpression(_) => "ImportExpression".into(),
             Self::PrivateInExpression(_) => "PrivateInExpression".into(),
+            Self::PipelineExpression(_) => "PipelineExpression".into(),
+            Self::DoExpression(_) => "DoExpression".into(),
+            Self::ThrowExpression(_) => "ThrowExpression".into(),
+            Self::ErrorExpression(_) => "ErrorExpression".into(),
 
             Self::ObjectProperty(_) => "ObjectProperty".into(),
             Self::PropertyKey(_) => "PropertyKey".into(),
@@ -647,6 +869,19 @@ impl<'a> AstKind<'a> {
             Self::TSTypeReference(_) => "TSTypeReference".into(),
             Self::TSUnionType(_) => "TSUnionType".into(),
             Self::TSVoidKeyword(_) => "TSVoidKeyword".into(),
+            Self::TSBigIntKeyword(_) => "TSBigIntKeyword".into(),
+            Self::TSBooleanKeyword(_) => "TSBooleanKeyword".into(),
+            Self::TSNeverKeyword(_) => "TSNeverKeyword".into(),
+            Self::TSNumberKeyword(_) => "TSNumberKeyword".into(),
+            Self::TSObjectKeyword(_) => "TSObjectKeyword".into(),
+            Self::TSStringKeyword(_) => "TSStringKeyword".into(),
+            Self::TSSymbolKeyword(_) => "TSSymbolKeyword".into(),
+            Self::TSThisType(_) => "TSThisType".into(),
+            Self::TSUndefinedKeyword(_) => "TSUndefinedKeyword".into(),
+            Self::TSUnknownKeyword(_) => "TSUnknownKeyword".into(),
+            Self::TSImportType(_) => "TSImportType".into(),
+            Self::TSInferType(_) => "TSInferType".into(),
+            Self::TSTemplateLiteralType(_) => "TSTemplateLiteralType".into(),
 
             Self::TSIndexedAccessType(_) => "TSIndexedAccessType".into(),
 
@@ -0,0 +1,196 @@
+//! Leading, trailing and inner comments of AST nodes, similar to Babel's comment attachment
+
+use std::collections::BTreeMap;
+
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast::Program, AstKind, CommentKind, Visit};
+
+/// Comments attached to the AST nodes next to them, keyed by node span.
+///
+/// Nodes with the same span, e.g. an expression statement without a semicolon and its
+/// expression, share their comments.
+#[derive(Debug, Default)]
+pub struct CommentsMap {
+    comments: BTreeMap<Span, Vec<AttachedComment>>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AttachedComment {
+    /// Span of the comment including its `//`, `/*` and `*/`
+    pub span: Span,
+    pub kind: CommentKind,
+    pub position: CommentPosition,
+}
+
+impl AttachedComment {
+    /// Text of the comment without its `//`, `/*` and `*/`, e.g. ` #__PURE__ `
+    pub fn text<'a>(&self, source_text: &'a str) -> &'a str {
+        let text = self.span.source_text(source_text);
+        match self.kind {
+            CommentKind::SingleLine => text.get(2..).unwrap_or_default(),
+            CommentKind::MultiLine => text.get(2..text.len() - 2).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CommentPosition {
+    /// Before the node, e.g. `/* #__PURE__ */ f()` or a license comment before a statement
+    Leading,
+    /// After the node and on the same line, followed by a line break, e.g. `a; // comment`
+    Trailing,
+    /// Inside of a node without a child next to the comment, e.g. `{ /* empty */ }`
+    Inner,
+}
+
+impl CommentsMap {
+    /// Attach `comments`, as collected into [`crate::Trivias::comments`], to the nodes of
+    /// `program`.
+    ///
+    /// A comment followed by a line break on the line a node ends on trails the outermost node
+    /// ending there. Other comments lead the outermost node starting after them, with only
+    /// whitespace and comments in between. The remaining comments are inner comments of the
+    /// innermost node containing them.
+    pub fn new(source_text: &str, program: &Program, comments: &[(u32, u32, CommentKind)]) -> Self {
+        let mut map = Self::default();
+        if comments.is_empty() {
+            return map;
+        }
+        // Comments are collected without their delimiters, and again after a rewind of the lexer
+        let mut comments = comments
+            .iter()
+            .map(|&(start, end, kind)| match kind {
+                CommentKind::SingleLine => (Span::new(start - 2, end), kind),
+                CommentKind::MultiLine => (Span::new(start - 2, end + 2), kind),
+            })
+            .collect::<Vec<_>>();
+        comments.sort_unstable_by_key(|(span, _)| span.start);
+        comments.dedup();
+        let mut collector = NodeCollector::default();
+        collector.visit_program(program);
+        let nodes = collector.nodes;
+        // Ancestors are visited first, so the first node starting or ending somewhere is the
+        // outermost one
+        let mut starts = BTreeMap::new();
+        let mut ends = BTreeMap::new();
+        for span in &nodes {
+            starts.entry(span.start).or_insert(*span);
+            ends.entry(span.end).or_insert(*span);
+        }
+
+        for (i, &(span, kind)) in comments.iter().enumerate() {
+            let Span { start, end } = span;
+            let previous = code_end_before(source_text, &comments, i);
+            let next = code_start_after(source_text, &comments, i);
+            let is_trailing = !has_line_break(&source_text[previous as usize..start as usize])
+                && (next as usize == source_text.len()
+                    || has_line_break(&source_text[end as usize..next as usize]));
+            let trailing = ends.get(&previous).filter(|_| is_trailing);
+            let (node, position) = if let Some(node) = trailing {
+                (*node, CommentPosition::Trailing)
+            } else if let Some(node) = starts.get(&next) {
+                (*node, CommentPosition::Leading)
+            } else if let Some(node) = ends.get(&previous) {
+                (*node, CommentPosition::Trailing)
+            } else {
+                // Nodes start in source order, so the last node containing the comment which
+                // starts before it is the innermost one
+                let before = nodes.partition_point(|node| node.start <= start);
+                let Some(node) = nodes[..before].iter().rev().find(|node| node.end >= end) else {
+                    continue;
+                };
+                (*node, CommentPosition::Inner)
+            };
+            map.comments.entry(node).or_default().push(AttachedComment { span, kind, position });
+        }
+        map
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// All comments attached to the node at `span`, in source order.
+    pub fn get(&self, span: Span) -> &[AttachedComment] {
+        self.comments.get(&span).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn leading(&self, span: Span) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.with_position(span, CommentPosition::Leading)
+    }
+
+    pub fn trailing(&self, span: Span) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.with_position(span, CommentPosition::Trailing)
+    }
+
+    pub fn inner(&self, span: Span) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.with_position(span, CommentPosition::Inner)
+    }
+
+    /// Node spans and their comments, ordered by span.
+    pub fn iter(&self) -> impl Iterator<Item = (Span, &[AttachedComment])> + '_ {
+        self.comments.iter().map(|(span, comments)| (*span, comments.as_slice()))
+    }
+
+    fn with_position(
+        &self,
+        span: Span,
+        position: CommentPosition,
+    ) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.get(span).iter().filter(move |comment| comment.position == position)
+    }
+}
+
+/// Spans of all nodes in pre-order, except empty ones
+#[derive(Default)]
+struct NodeCollector {
+    nodes: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for NodeCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        if span.start < span.end {
+            self.nodes.push(span);
+        }
+    }
+}
+
+/// End of the code before comment `i`, skipping whitespace and the comments before it.
+fn code_end_before(source_text: &str, comments: &[(Span, CommentKind)], i: usize) -> u32 {
+    let mut position = comments[i].0.start;
+    let mut i = i;
+    loop {
+        let trimmed = source_text[..position as usize].trim_end();
+        position = u32::try_from(trimmed.len()).unwrap();
+        match i.checked_sub(1) {
+            Some(previous) if comments[previous].0.end == position => {
+                position = comments[previous].0.start;
+                i = previous;
+            }
+            _ => return position,
+        }
+    }
+}
+
+/// Start of the code after comment `i`, skipping whitespace and the comments after it.
+fn code_start_after(source_text: &str, comments: &[(Span, CommentKind)], i: usize) -> u32 {
+    let mut position = comments[i].0.end;
+    let mut i = i;
+    loop {
+        let rest = &source_text[position as usize..];
+        position += u32::try_from(rest.len() - rest.trim_start().len()).unwrap();
+        match comments.get(i + 1) {
+            Some((next, _)) if next.start == position => {
+                position = next.end;
+                i += 1;
+            }
+            _ => return position,
+        }
+    }
+}
+
+fn has_line_break(text: &str) -> bool {
+    text.contains(['\n', '\r', '\u{2028}', '\u{2029}'])
+}
@@ -15,6 +15,7 @@ mod serialize;
 pub mod ast;
 mod ast_builder;
 mod ast_kind;
+mod comment_attachment;
 pub mod precedence;
 mod span;
 pub mod syntax_directed_operations;
@@ -27,7 +28,12 @@ pub use num_bigint::BigUint;
 pub use crate::{
     ast_builder::AstBuilder,
     ast_kind::AstKind,
-    trivia::{Comment, CommentKind, Trivias, TriviasMap},
+    comment_attachment::{AttachedComment, CommentPosition, CommentsMap},
+    trivia::{
+        Comment, CommentKind, IrregularCharacter, IrregularCharacterContext,
+        IrregularCharacterKind, SourcePiece, SourcePieceKind, Trivias, TriviasMap, TsSuppression,
+        TsSuppressionKind,
+    },
     visit::Visit,
     visit_mut::VisitMut,
 };
@@ -3,8 +3,8 @@ use oxc_syntax::precedence::{GetPrecedence, Precedence};
 use crate::ast::{
     ArrowFunctionExpression, AssignmentExpression, AwaitExpression, BinaryExpression,
     CallExpression, ConditionalExpression, Expression, ImportExpression, LogicalExpression,
-    MemberExpression, NewExpression, SequenceExpression, UnaryExpression, UpdateExpression,
-    YieldExpression,
+    MemberExpression, NewExpression, PipelineExpression, SequenceExpression, ThrowExpression,
+    UnaryExpression, UpdateExpression, YieldExpression,
 };
 
 impl<'a> GetPrecedence for Expression<'a> {
@@ -20,6 +20,8 @@ impl<'a> GetPrecedence for Expression<'a> {
             Self::UnaryExpression(expr) => expr.precedence(),
             Self::UpdateExpression(expr) => expr.precedence(),
             Self::AwaitExpression(expr) => expr.precedence(),
+            Self::PipelineExpression(expr) => expr.precedence(),
+            Self::ThrowExpression(expr) => expr.precedence(),
             Self::NewExpression(expr) => expr.precedence(),
             Self::CallExpression(expr) => expr.precedence(),
             Self::MemberExpression(expr) => expr.precedence(),
@@ -82,6 +84,18 @@ impl<'a> GetPrecedence for AwaitExpression<'a> {
     }
 }
 
+impl<'a> GetPrecedence for PipelineExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Pipeline
+    }
+}
+
+impl<'a> GetPrecedence for ThrowExpression<'a> {
+    fn precedence(&self) -> Precedence {
+        Precedence::Prefix
+    }
+}
+
 impl<'a> GetPrecedence for UpdateExpression<'a> {
     fn precedence(&self) -> Precedence {
         if self.prefix {
@@ -23,6 +23,7 @@ impl<'a> GetSpan for Statement<'a> {
             Self::TryStatement(stmt) => stmt.span,
             Self::WhileStatement(stmt) => stmt.span,
             Self::WithStatement(stmt) => stmt.span,
+            Self::ErrorStatement(stmt) => stmt.span,
             Self::ModuleDeclaration(decl) => decl.span(),
             Self::Declaration(decl) => decl.span(),
         }
@@ -48,6 +49,10 @@ impl<'a> GetSpan for Expression<'a> {
             Self::AwaitExpression(e) => e.span,
             Self::BinaryExpression(e) => e.span,
             Self::PrivateInExpression(e) => e.span,
+            Self::PipelineExpression(e) => e.span,
+            Self::DoExpression(e) => e.span,
+            Self::ThrowExpression(e) => e.span,
+            Self::ErrorExpression(e) => e.span,
             Self::CallExpression(e) => e.span,
             Self::ChainExpression(e) => e.span,
             Self::ClassExpression(e) => e.span,
@@ -144,6 +149,19 @@ impl<'a> GetSpan for MemberExpression<'a> {
     }
 }
 
+impl<'a> GetSpan for JsonValue<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Null(literal) => literal.span,
+            Self::Boolean(literal) => literal.span,
+            Self::Number(literal) => literal.span,
+            Self::String(literal) => literal.span,
+            Self::Array(array) => array.span,
+            Self::Object(object) => object.span,
+        }
+    }
+}
+
 impl GetSpan for ImportAttributeKey {
     fn span(&self) -> Span {
         match self {
@@ -200,6 +218,17 @@ impl GetSpan for TSModuleDeclarationName {
     }
 }
 
+impl<'a> GetSpan for TSEnumMemberName<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Identifier(ident) => ident.span,
+            Self::StringLiteral(lit) => lit.span,
+            Self::ComputedPropertyName(expr) => expr.span(),
+            Self::NumericLiteral(lit) => lit.span,
+        }
+    }
+}
+
 impl<'a> GetSpan for ObjectPropertyKind<'a> {
     fn span(&self) -> Span {
         match self {
@@ -7,7 +7,15 @@ use std::collections::BTreeMap;
 #[derive(Debug, Default)]
 pub struct Trivias {
     pub comments: Vec<(u32, u32, CommentKind)>,
-    pub irregular_whitespaces: Vec<Span>,
+    /// Characters which are likely mistakes, e.g. pasted from a word processor, in source order
+    pub irregular_characters: Vec<IrregularCharacter>,
+    /// Positions of automatically inserted semicolons, at the end of the token before the insertion.
+    pub inserted_semicolons: Vec<u32>,
+    /// `@ts-expect-error` and `@ts-ignore` comments in source order
+    pub ts_suppressions: Vec<TsSuppression>,
+    /// Every token, comment and whitespace run in source order, which together are the whole
+    /// source text. Only collected by the lossless mode of the parser.
+    pub pieces: Vec<SourcePiece>,
 }
 
 /// Trivias such as comments
@@ -18,14 +26,18 @@ pub struct Trivias {
 pub struct TriviasMap {
     /// Keyed by span.start
     comments: BTreeMap<u32, Comment>,
-    irregular_whitespaces: Vec<Span>,
+    irregular_characters: Vec<IrregularCharacter>,
+    inserted_semicolons: Vec<u32>,
+    ts_suppressions: Vec<TsSuppression>,
 }
 
 impl From<Trivias> for TriviasMap {
     fn from(trivias: Trivias) -> Self {
         Self {
             comments: trivias.comments.iter().map(|t| (t.0, Comment::new(t.1, t.2))).collect(),
-            irregular_whitespaces: trivias.irregular_whitespaces,
+            irregular_characters: trivias.irregular_characters,
+            inserted_semicolons: trivias.inserted_semicolons,
+            ts_suppressions: trivias.ts_suppressions,
         }
     }
 }
@@ -44,6 +56,120 @@ pub enum CommentKind {
     MultiLine,
 }
 
+/// A character which is valid in its place but likely a mistake,
+/// e.g. whitespace pasted from a word processor or a null character in a string
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct IrregularCharacter {
+    pub kind: IrregularCharacterKind,
+    pub context: IrregularCharacterContext,
+    /// Span of the character, or of the escape sequence of a lone surrogate
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IrregularCharacterKind {
+    /// Whitespace or line terminator other than space, tab, `\n` and `\r`, e.g. `<NBSP>`
+    IrregularWhitespace,
+    /// `<ZWNBSP>` at the start of the file
+    ByteOrderMark,
+    /// `\uD800` - `\uDFFF` escape which is not part of a surrogate pair
+    LoneSurrogate,
+    /// U+0000
+    Null,
+}
+
+/// Where an [`IrregularCharacter`] is
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IrregularCharacterContext {
+    /// Between tokens
+    Code,
+    Comment,
+    String,
+    Template,
+    RegExp,
+    JSXText,
+}
+
+/// A token or trivia of the source text, see [`Trivias::pieces`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourcePiece {
+    pub kind: SourcePieceKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SourcePieceKind {
+    Token,
+    /// Comment including its `//`, `/*` and `*/`, or an HTML-like comment
+    Comment,
+    /// Run of whitespace and line terminators
+    Whitespace,
+    /// Source text which was not lexed because a syntax error stopped parsing
+    Unparsed,
+}
+
+/// A comment directive which suppresses the TypeScript errors of the next line
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TsSuppression {
+    pub kind: TsSuppressionKind,
+    /// Span of the comment, without the leading `//` or `/*` and the trailing `*/`
+    pub span: Span,
+}
+
+impl TsSuppression {
+    /// The text after the directive, e.g. `reason` for `// @ts-ignore: reason`
+    pub fn description<'a>(&self, source_text: &'a str) -> &'a str {
+        let text = &source_text[self.span.start as usize..self.span.end as usize];
+        let directive = self.kind.as_str();
+        text.rfind(directive).map_or("", |index| {
+            text[index + directive.len()..]
+                .trim_start_matches(|c: char| c.is_whitespace() || c == ':' || c == '-')
+                .trim_end()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TsSuppressionKind {
+    /// `@ts-expect-error`, an error if the next line has no errors
+    ExpectError,
+    /// `@ts-ignore`
+    Ignore,
+}
+
+impl TsSuppressionKind {
+    /// Detect the directive of a comment, from its text without the leading `//` or `/*` and the
+    /// trailing `*/`. Like TypeScript, the directive of a multi-line comment is on its last line,
+    /// where it may be preceded by `*`.
+    pub fn from_comment(text: &str, kind: CommentKind) -> Option<Self> {
+        let text = match kind {
+            CommentKind::SingleLine => {
+                text.trim_start_matches(|c: char| c.is_whitespace() || c == '/')
+            }
+            CommentKind::MultiLine => text
+                .rsplit(['\n', '\r'])
+                .next()
+                .unwrap_or(text)
+                .trim_start_matches(|c: char| c.is_whitespace() || c == '*' || c == '/'),
+        };
+        let text = text.strip_prefix('@')?;
+        if text.starts_with("ts-expect-error") {
+            Some(Self::ExpectError)
+        } else if text.starts_with("ts-ignore") {
+            Some(Self::Ignore)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ExpectError => "@ts-expect-error",
+            Self::Ignore => "@ts-ignore",
+        }
+    }
+}
+
 impl CommentKind {
     pub fn is_single_line(self) -> bool {
         matches!(self, Self::SingleLine)
@@ -99,7 +225,23 @@ impl TriviasMap {
         self.comments().iter().map(|(start, comment)| (*comment, Span::new(*start, comment.end)))
     }
 
-    pub fn irregular_whitespaces(&self) -> &Vec<Span> {
-        &self.irregular_whitespaces
+    /// Irregular whitespace, byte order marks, lone surrogates and null characters in source order
+    pub fn irregular_characters(&self) -> &Vec<IrregularCharacter> {
+        &self.irregular_characters
+    }
+
+    /// Statements which relied on [Automatic Semicolon Insertion](https://tc39.es/ecma262/#sec-automatic-semicolon-insertion),
+    /// sorted by the end of the token before the inserted semicolon.
+    pub fn inserted_semicolons(&self) -> &Vec<u32> {
+        &self.inserted_semicolons
+    }
+
+    pub fn is_inserted_semicolon(&self, position: u32) -> bool {
+        self.inserted_semicolons.binary_search(&position).is_ok()
+    }
+
+    /// `@ts-expect-error` and `@ts-ignore` comments in source order
+    pub fn ts_suppressions(&self) -> &Vec<TsSuppression> {
+        &self.ts_suppressions
     }
 }
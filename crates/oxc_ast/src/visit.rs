@@ -75,6 +75,7 @@ pub trait Visit<'a>: Sized {
             Statement::TryStatement(stmt) => self.visit_try_statement(stmt),
             Statement::WhileStatement(stmt) => self.visit_while_statement(stmt),
             Statement::WithStatement(stmt) => self.visit_with_statement(stmt),
+            Statement::ErrorStatement(stmt) => self.visit_error_statement(stmt),
 
             Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
             Statement::Declaration(decl) => self.visit_declaration(decl),
@@ -290,7 +291,7 @@ pub trait Visit<'a>: Sized {
 
     fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
         let kind = AstKind::CatchClause(self.alloc(clause));
-        self.enter_scope(ScopeFlags::empty());
+        self.enter_scope(ScopeFlags::CatchClause);
         self.enter_node(kind);
         if let Some(param) = &clause.param {
             self.visit_binding_pattern(param);
@@ -319,7 +320,15 @@ pub trait Visit<'a>: Sized {
         let kind = AstKind::WithStatement(self.alloc(stmt));
         self.enter_node(kind);
         self.visit_expression(&stmt.object);
+        self.enter_scope(ScopeFlags::With);
         self.visit_statement(&stmt.body);
+        self.leave_scope();
+        self.leave_node(kind);
+    }
+
+    fn visit_error_statement(&mut self, stmt: &ErrorStatement) {
+        let kind = AstKind::ErrorStatement(self.alloc(stmt));
+        self.enter_node(kind);
         self.leave_node(kind);
     }
 
@@ -453,6 +462,11 @@ pub trait Visit<'a>: Sized {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&class.body);
         self.leave_node(kind);
         if is_class_expr {
@@ -583,6 +597,10 @@ pub trait Visit<'a>: Sized {
                 self.visit_parenthesized_expression(expr);
             }
             Expression::PrivateInExpression(expr) => self.visit_private_in_expression(expr),
+            Expression::PipelineExpression(expr) => self.visit_pipeline_expression(expr),
+            Expression::DoExpression(expr) => self.visit_do_expression(expr),
+            Expression::ThrowExpression(expr) => self.visit_throw_expression(expr),
+            Expression::ErrorExpression(expr) => self.visit_error_expression(expr),
             Expression::SequenceExpression(expr) => self.visit_sequence_expression(expr),
             Expression::TaggedTemplateExpression(expr) => {
                 self.visit_tagged_template_expression(expr);
@@ -846,6 +864,34 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_pipeline_expression(&mut self, expr: &PipelineExpression<'a>) {
+        let kind = AstKind::PipelineExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_expression(&expr.left);
+        self.visit_expression(&expr.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_do_expression(&mut self, expr: &DoExpression<'a>) {
+        let kind = AstKind::DoExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_block_statement(&expr.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_throw_expression(&mut self, expr: &ThrowExpression<'a>) {
+        let kind = AstKind::ThrowExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_expression(&expr.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_error_expression(&mut self, expr: &ErrorExpression) {
+        let kind = AstKind::ErrorExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_sequence_expression(&mut self, expr: &SequenceExpression<'a>) {
         let kind = AstKind::SequenceExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1342,10 +1388,25 @@ pub trait Visit<'a>: Sized {
             }
         }
         self.visit_string_literal(&decl.source);
-        // TODO: assertions
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
+    fn visit_with_clause(&mut self, with_clause: &WithClause<'a>) {
+        for attribute in &with_clause.with_entries {
+            self.visit_import_attribute(attribute);
+        }
+    }
+
+    fn visit_import_attribute(&mut self, attribute: &ImportAttribute) {
+        if let ImportAttributeKey::StringLiteral(literal) = &attribute.key {
+            self.visit_string_literal(literal);
+        }
+        self.visit_string_literal(&attribute.value);
+    }
+
     fn visit_import_declaration_specifier(&mut self, specifier: &ImportDeclarationSpecifier) {
         match &specifier {
             ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
@@ -1386,6 +1447,9 @@ pub trait Visit<'a>: Sized {
         let kind = AstKind::ExportAllDeclaration(self.alloc(decl));
         self.enter_node(kind);
         self.visit_string_literal(&decl.source);
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
@@ -1412,6 +1476,9 @@ pub trait Visit<'a>: Sized {
         if let Some(ref source) = decl.source {
             self.visit_string_literal(source);
         }
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
@@ -1548,12 +1615,31 @@ pub trait Visit<'a>: Sized {
         if let Some(parameters) = &decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(extends) = &decl.extends {
+            for heritage in extends {
+                self.visit_ts_interface_heritage(heritage);
+            }
+        }
         for signature in &decl.body.body {
             self.visit_ts_signature(signature);
         }
         self.leave_node(kind);
     }
 
+    fn visit_ts_interface_heritage(&mut self, heritage: &TSInterfaceHeritage<'a>) {
+        self.visit_expression(&heritage.expression);
+        if let Some(parameters) = &heritage.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
+    fn visit_ts_class_implements(&mut self, implements: &TSClassImplements<'a>) {
+        self.visit_ts_type_name(&implements.expression);
+        if let Some(parameters) = &implements.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
     fn visit_ts_as_expression(&mut self, expr: &TSAsExpression<'a>) {
         let kind = AstKind::TSAsExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1617,7 +1703,22 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
             TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
-            _ => {}
+            TSType::TSBigIntKeyword(ty) => self.visit_ts_bigint_keyword(ty),
+            TSType::TSBooleanKeyword(ty) => self.visit_ts_boolean_keyword(ty),
+            TSType::TSNeverKeyword(ty) => self.visit_ts_never_keyword(ty),
+            TSType::TSNumberKeyword(ty) => self.visit_ts_number_keyword(ty),
+            TSType::TSObjectKeyword(ty) => self.visit_ts_object_keyword(ty),
+            TSType::TSStringKeyword(ty) => self.visit_ts_string_keyword(ty),
+            TSType::TSSymbolKeyword(ty) => self.visit_ts_symbol_keyword(ty),
+            TSType::TSThisType(ty) => self.visit_ts_this_type(ty),
+            TSType::TSUndefinedKeyword(ty) => self.visit_ts_undefined_keyword(ty),
+            TSType::TSUnknownKeyword(ty) => self.visit_ts_unknown_keyword(ty),
+            TSType::TSImportType(ty) => self.visit_ts_import_type(ty),
+            TSType::TSInferType(ty) => self.visit_ts_infer_type(ty),
+            TSType::TSQualifiedName(name) => self.visit_ts_qualified_name(name),
+            TSType::TSTemplateLiteralType(ty) => self.visit_ts_template_literal_type(ty),
+            TSType::JSDocNullableType(ty) => self.visit_ts_type(&ty.type_annotation),
+            TSType::JSDocUnknownType(_) => {}
         }
     }
 
@@ -1751,6 +1852,98 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_ts_bigint_keyword(&mut self, ty: &TSBigIntKeyword) {
+        let kind = AstKind::TSBigIntKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_boolean_keyword(&mut self, ty: &TSBooleanKeyword) {
+        let kind = AstKind::TSBooleanKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_never_keyword(&mut self, ty: &TSNeverKeyword) {
+        let kind = AstKind::TSNeverKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_number_keyword(&mut self, ty: &TSNumberKeyword) {
+        let kind = AstKind::TSNumberKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_object_keyword(&mut self, ty: &TSObjectKeyword) {
+        let kind = AstKind::TSObjectKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_string_keyword(&mut self, ty: &TSStringKeyword) {
+        let kind = AstKind::TSStringKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_symbol_keyword(&mut self, ty: &TSSymbolKeyword) {
+        let kind = AstKind::TSSymbolKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_this_type(&mut self, ty: &TSThisType) {
+        let kind = AstKind::TSThisType(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_undefined_keyword(&mut self, ty: &TSUndefinedKeyword) {
+        let kind = AstKind::TSUndefinedKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_unknown_keyword(&mut self, ty: &TSUnknownKeyword) {
+        let kind = AstKind::TSUnknownKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_import_type(&mut self, ty: &TSImportType<'a>) {
+        let kind = AstKind::TSImportType(self.alloc(ty));
+        self.enter_node(kind);
+        self.visit_ts_type(&ty.argument);
+        if let Some(name) = &ty.qualifier {
+            self.visit_ts_type_name(name);
+        }
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_infer_type(&mut self, ty: &TSInferType<'a>) {
+        let kind = AstKind::TSInferType(self.alloc(ty));
+        self.enter_node(kind);
+        self.visit_ts_type_parameter(&ty.type_parameter);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_template_literal_type(&mut self, ty: &TSTemplateLiteralType<'a>) {
+        let kind = AstKind::TSTemplateLiteralType(self.alloc(ty));
+        self.enter_node(kind);
+        for quasi in &ty.quasis {
+            self.visit_template_element(quasi);
+        }
+        for ty in &ty.types {
+            self.visit_ts_type(ty);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_intersection_type(&mut self, ty: &TSIntersectionType<'a>) {
         let kind = AstKind::TSIntersectionType(self.alloc(ty));
         self.enter_node(kind);
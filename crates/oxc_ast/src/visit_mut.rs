@@ -72,6 +72,7 @@ pub trait VisitMut<'a>: Sized {
             Statement::TryStatement(stmt) => self.visit_try_statement(stmt),
             Statement::WhileStatement(stmt) => self.visit_while_statement(stmt),
             Statement::WithStatement(stmt) => self.visit_with_statement(stmt),
+            Statement::ErrorStatement(stmt) => self.visit_error_statement(stmt),
 
             Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
             Statement::Declaration(decl) => self.visit_declaration(decl),
@@ -287,7 +288,7 @@ pub trait VisitMut<'a>: Sized {
 
     fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
         let kind = AstKind::CatchClause(self.alloc(clause));
-        self.enter_scope(ScopeFlags::empty());
+        self.enter_scope(ScopeFlags::CatchClause);
         self.enter_node(kind);
         if let Some(param) = &mut clause.param {
             self.visit_binding_pattern(param);
@@ -316,7 +317,15 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::WithStatement(self.alloc(stmt));
         self.enter_node(kind);
         self.visit_expression(&mut stmt.object);
+        self.enter_scope(ScopeFlags::With);
         self.visit_statement(&mut stmt.body);
+        self.leave_scope();
+        self.leave_node(kind);
+    }
+
+    fn visit_error_statement(&mut self, stmt: &mut ErrorStatement) {
+        let kind = AstKind::ErrorStatement(self.alloc(stmt));
+        self.enter_node(kind);
         self.leave_node(kind);
     }
 
@@ -456,6 +465,11 @@ pub trait VisitMut<'a>: Sized {
         if let Some(super_parameters) = &mut class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &mut class.implements {
+            for implement in implements.iter_mut() {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&mut class.body);
         self.leave_node(kind);
         if is_class_expr {
@@ -573,6 +587,10 @@ pub trait VisitMut<'a>: Sized {
                 self.visit_parenthesized_expression(expr);
             }
             Expression::PrivateInExpression(expr) => self.visit_private_in_expression(expr),
+            Expression::PipelineExpression(expr) => self.visit_pipeline_expression(expr),
+            Expression::DoExpression(expr) => self.visit_do_expression(expr),
+            Expression::ThrowExpression(expr) => self.visit_throw_expression(expr),
+            Expression::ErrorExpression(expr) => self.visit_error_expression(expr),
             Expression::SequenceExpression(expr) => self.visit_sequence_expression(expr),
             Expression::TaggedTemplateExpression(expr) => {
                 self.visit_tagged_template_expression(expr);
@@ -833,6 +851,34 @@ pub trait VisitMut<'a>: Sized {
         self.visit_expression(&mut expr.right);
     }
 
+    fn visit_pipeline_expression(&mut self, expr: &mut PipelineExpression<'a>) {
+        let kind = AstKind::PipelineExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_expression(&mut expr.left);
+        self.visit_expression(&mut expr.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_do_expression(&mut self, expr: &mut DoExpression<'a>) {
+        let kind = AstKind::DoExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_block_statement(&mut expr.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_throw_expression(&mut self, expr: &mut ThrowExpression<'a>) {
+        let kind = AstKind::ThrowExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_expression(&mut expr.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_error_expression(&mut self, expr: &mut ErrorExpression) {
+        let kind = AstKind::ErrorExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_sequence_expression(&mut self, expr: &mut SequenceExpression<'a>) {
         let kind = AstKind::SequenceExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1341,10 +1387,25 @@ pub trait VisitMut<'a>: Sized {
             }
         }
         self.visit_string_literal(&mut decl.source);
-        // TODO: assertions
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
+    fn visit_with_clause(&mut self, with_clause: &mut WithClause<'a>) {
+        for attribute in with_clause.with_entries.iter_mut() {
+            self.visit_import_attribute(attribute);
+        }
+    }
+
+    fn visit_import_attribute(&mut self, attribute: &mut ImportAttribute) {
+        if let ImportAttributeKey::StringLiteral(literal) = &mut attribute.key {
+            self.visit_string_literal(literal);
+        }
+        self.visit_string_literal(&mut attribute.value);
+    }
+
     fn visit_import_declaration_specifier(&mut self, specifier: &mut ImportDeclarationSpecifier) {
         match specifier {
             ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
@@ -1385,6 +1446,9 @@ pub trait VisitMut<'a>: Sized {
         let kind = AstKind::ExportAllDeclaration(self.alloc(decl));
         self.enter_node(kind);
         self.visit_string_literal(&mut decl.source);
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
@@ -1411,6 +1475,9 @@ pub trait VisitMut<'a>: Sized {
         if let Some(source) = &mut decl.source {
             self.visit_string_literal(source);
         }
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
         self.leave_node(kind);
     }
 
@@ -1546,12 +1613,31 @@ pub trait VisitMut<'a>: Sized {
         if let Some(parameters) = &mut decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(extends) = &mut decl.extends {
+            for heritage in extends.iter_mut() {
+                self.visit_ts_interface_heritage(heritage);
+            }
+        }
         for signature in decl.body.body.iter_mut() {
             self.visit_ts_signature(signature);
         }
         self.leave_node(kind);
     }
 
+    fn visit_ts_interface_heritage(&mut self, heritage: &mut TSInterfaceHeritage<'a>) {
+        self.visit_expression(&mut heritage.expression);
+        if let Some(parameters) = &mut heritage.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
+    fn visit_ts_class_implements(&mut self, implements: &mut TSClassImplements<'a>) {
+        self.visit_ts_type_name(&mut implements.expression);
+        if let Some(parameters) = &mut implements.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
     fn visit_ts_as_expression(&mut self, expr: &mut TSAsExpression<'a>) {
         let kind = AstKind::TSAsExpression(self.alloc(expr));
         self.enter_node(kind);
@@ -1615,7 +1701,22 @@ pub trait VisitMut<'a>: Sized {
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
             TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
-            _ => {}
+            TSType::TSBigIntKeyword(ty) => self.visit_ts_bigint_keyword(ty),
+            TSType::TSBooleanKeyword(ty) => self.visit_ts_boolean_keyword(ty),
+            TSType::TSNeverKeyword(ty) => self.visit_ts_never_keyword(ty),
+            TSType::TSNumberKeyword(ty) => self.visit_ts_number_keyword(ty),
+            TSType::TSObjectKeyword(ty) => self.visit_ts_object_keyword(ty),
+            TSType::TSStringKeyword(ty) => self.visit_ts_string_keyword(ty),
+            TSType::TSSymbolKeyword(ty) => self.visit_ts_symbol_keyword(ty),
+            TSType::TSThisType(ty) => self.visit_ts_this_type(ty),
+            TSType::TSUndefinedKeyword(ty) => self.visit_ts_undefined_keyword(ty),
+            TSType::TSUnknownKeyword(ty) => self.visit_ts_unknown_keyword(ty),
+            TSType::TSImportType(ty) => self.visit_ts_import_type(ty),
+            TSType::TSInferType(ty) => self.visit_ts_infer_type(ty),
+            TSType::TSQualifiedName(name) => self.visit_ts_qualified_name(name),
+            TSType::TSTemplateLiteralType(ty) => self.visit_ts_template_literal_type(ty),
+            TSType::JSDocNullableType(ty) => self.visit_ts_type(&mut ty.type_annotation),
+            TSType::JSDocUnknownType(_) => {}
         }
     }
 
@@ -1749,6 +1850,98 @@ pub trait VisitMut<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_ts_bigint_keyword(&mut self, ty: &mut TSBigIntKeyword) {
+        let kind = AstKind::TSBigIntKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_boolean_keyword(&mut self, ty: &mut TSBooleanKeyword) {
+        let kind = AstKind::TSBooleanKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_never_keyword(&mut self, ty: &mut TSNeverKeyword) {
+        let kind = AstKind::TSNeverKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_number_keyword(&mut self, ty: &mut TSNumberKeyword) {
+        let kind = AstKind::TSNumberKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_object_keyword(&mut self, ty: &mut TSObjectKeyword) {
+        let kind = AstKind::TSObjectKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_string_keyword(&mut self, ty: &mut TSStringKeyword) {
+        let kind = AstKind::TSStringKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_symbol_keyword(&mut self, ty: &mut TSSymbolKeyword) {
+        let kind = AstKind::TSSymbolKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_this_type(&mut self, ty: &mut TSThisType) {
+        let kind = AstKind::TSThisType(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_undefined_keyword(&mut self, ty: &mut TSUndefinedKeyword) {
+        let kind = AstKind::TSUndefinedKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_unknown_keyword(&mut self, ty: &mut TSUnknownKeyword) {
+        let kind = AstKind::TSUnknownKeyword(self.alloc(ty));
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_import_type(&mut self, ty: &mut TSImportType<'a>) {
+        let kind = AstKind::TSImportType(self.alloc(ty));
+        self.enter_node(kind);
+        self.visit_ts_type(&mut ty.argument);
+        if let Some(name) = &mut ty.qualifier {
+            self.visit_ts_type_name(name);
+        }
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_infer_type(&mut self, ty: &mut TSInferType<'a>) {
+        let kind = AstKind::TSInferType(self.alloc(ty));
+        self.enter_node(kind);
+        self.visit_ts_type_parameter(&mut ty.type_parameter);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_template_literal_type(&mut self, ty: &mut TSTemplateLiteralType<'a>) {
+        let kind = AstKind::TSTemplateLiteralType(self.alloc(ty));
+        self.enter_node(kind);
+        for quasi in ty.quasis.iter_mut() {
+            self.visit_template_element(quasi);
+        }
+        for ty in ty.types.iter_mut() {
+            self.visit_ts_type(ty);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_intersection_type(&mut self, ty: &mut TSIntersectionType<'a>) {
         let kind = AstKind::TSIntersectionType(self.alloc(ty));
         self.enter_node(kind);
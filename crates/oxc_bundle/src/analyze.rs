@@ -0,0 +1,448 @@
+//! Per module analysis: the import and export bindings of a module,
+//! the occurrences of its top level bindings and the edits which remove its module syntax.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentTarget, AssignmentTargetPattern, AssignmentTargetProperty,
+        BindingIdentifier, BindingPatternKind, ExportDefaultDeclarationKind, Expression,
+        ImportDeclarationSpecifier, ModuleDeclaration, Statement, WithClause,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::Error;
+use oxc_parser::Parser;
+use oxc_semantic::{Reference, Semantic, SemanticBuilder, SymbolFlags};
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Replace `span` with `content`, an insertion if `span` is empty.
+#[derive(Debug, Clone)]
+pub struct Edit {
+    pub span: Span,
+    pub content: String,
+}
+
+impl Edit {
+    pub fn new(span: Span, content: String) -> Self {
+        Self { span, content }
+    }
+
+    pub fn remove(span: Span) -> Self {
+        Self { span, content: String::new() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportName {
+    Name(String),
+    Default,
+    Namespace,
+}
+
+impl ImportName {
+    fn from_export_name(name: &str) -> Self {
+        if name == "default" {
+            Self::Default
+        } else {
+            Self::Name(name.to_string())
+        }
+    }
+
+    /// The export name of a named or default import.
+    pub fn as_export_name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name) => Some(name),
+            Self::Default => Some("default"),
+            Self::Namespace => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportBinding {
+    pub specifier: String,
+    pub imported: ImportName,
+    pub local: String,
+    /// Span of the local binding
+    pub span: Span,
+    pub references: Vec<Span>,
+}
+
+#[derive(Debug)]
+pub enum Export {
+    /// Index into [`ModuleInfo::top_level`]
+    Local(usize),
+    ReExport {
+        specifier: String,
+        imported: ImportName,
+    },
+}
+
+#[derive(Debug)]
+pub struct TopLevelBinding {
+    pub name: String,
+    /// Declarations and references
+    pub occurrences: Vec<Span>,
+}
+
+/// `export default <expression>` and anonymous default exported functions and classes,
+/// which are turned into a declaration of the binding `binding`.
+#[derive(Debug)]
+pub struct DefaultExport {
+    /// `export default `, replaced with the declaration
+    pub prefix: Span,
+    /// A `;` is needed after function and class declarations
+    pub semicolon: Option<u32>,
+    /// Index into [`ModuleInfo::top_level`]
+    pub binding: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct ModuleInfo {
+    /// Has import or export declarations
+    pub is_esm: bool,
+    /// Specifiers of static imports and re-exports in source order
+    pub requested_modules: Vec<(String, Span)>,
+    /// Import attributes of requested modules, `with { type: "json" }`
+    pub attributes: FxHashMap<String, Vec<(String, String)>>,
+    pub imports: Vec<ImportBinding>,
+    /// Export name -> export
+    pub exports: Vec<(String, Export)>,
+    /// `export * from` specifiers
+    pub star_exports: Vec<String>,
+    /// Top level bindings except import bindings, in source order
+    pub top_level: Vec<TopLevelBinding>,
+    pub default_export: Option<DefaultExport>,
+    /// `import("specifier")` calls
+    pub dynamic_imports: Vec<(Span, String)>,
+    /// `require("specifier")` calls of the global `require`
+    pub requires: Vec<(Span, String)>,
+    /// Edits removing import and export declarations
+    pub edits: Vec<Edit>,
+    /// Identifiers of shorthand properties, `a` in `({ a })` and `const { a } = b`
+    pub shorthands: FxHashSet<Span>,
+    /// Identifiers which are callees, `a` in `a()` and ``a`b` ``
+    pub callees: FxHashSet<Span>,
+    /// Names of all bindings and references
+    pub names: FxHashSet<String>,
+    /// Names of references to global variables
+    pub globals: FxHashSet<String>,
+}
+
+enum PendingExport {
+    Local(String),
+    ReExport { specifier: String, imported: ImportName },
+}
+
+impl ModuleInfo {
+    /// # Errors
+    ///
+    /// Returns the syntax errors of the module.
+    pub fn analyze(source_text: &str, source_type: SourceType) -> Result<Self, Vec<Error>> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+
+        let mut info = Self::default();
+        let mut exports = vec![];
+        let mut default_export = None;
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            info.is_esm = true;
+            match &**decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    info.edits.push(Edit::remove(decl.span));
+                    if decl.import_kind.is_type() {
+                        continue;
+                    }
+                    let specifier = decl.source.value.to_string();
+                    info.requested_modules.push((specifier.clone(), decl.source.span));
+                    info.add_attributes(&specifier, decl.with_clause.as_ref());
+                    for import in decl.specifiers.iter().flatten() {
+                        let (local, imported) = match import {
+                            ImportDeclarationSpecifier::ImportSpecifier(import) => {
+                                if import.import_kind.is_type() {
+                                    continue;
+                                }
+                                (
+                                    &import.local,
+                                    ImportName::from_export_name(import.imported.name()),
+                                )
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(import) => {
+                                (&import.local, ImportName::Default)
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(import) => {
+                                (&import.local, ImportName::Namespace)
+                            }
+                        };
+                        info.imports.push(ImportBinding {
+                            specifier: specifier.clone(),
+                            imported,
+                            local: local.name.to_string(),
+                            span: local.span,
+                            references: references(&semantic, local),
+                        });
+                    }
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    info.edits.push(Edit::remove(decl.span));
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    let specifier = decl.source.value.to_string();
+                    info.requested_modules.push((specifier.clone(), decl.source.span));
+                    info.add_attributes(&specifier, decl.with_clause.as_ref());
+                    if let Some(exported) = &decl.exported {
+                        exports.push((
+                            exported.name().to_string(),
+                            PendingExport::ReExport { specifier, imported: ImportName::Namespace },
+                        ));
+                    } else {
+                        info.star_exports.push(specifier);
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        info.edits.push(Edit::remove(decl.span));
+                        continue;
+                    }
+                    if let Some(declaration) = &decl.declaration {
+                        info.edits.push(Edit::remove(Span::new(
+                            decl.span.start,
+                            declaration.span().start,
+                        )));
+                        declaration.bound_names(&mut |ident| {
+                            let name = ident.name.to_string();
+                            exports.push((name.clone(), PendingExport::Local(name)));
+                        });
+                        continue;
+                    }
+                    info.edits.push(Edit::remove(decl.span));
+                    if let Some(source) = &decl.source {
+                        info.requested_modules.push((source.value.to_string(), source.span));
+                    }
+                    let source = decl.source.as_ref().map(|source| source.value.to_string());
+                    for export in &decl.specifiers {
+                        if export.export_kind.is_type() {
+                            continue;
+                        }
+                        let exported = export.exported.name().to_string();
+                        let local = export.local.name().to_string();
+                        let export = match &source {
+                            Some(specifier) => PendingExport::ReExport {
+                                specifier: specifier.clone(),
+                                imported: ImportName::from_export_name(&local),
+                            },
+                            None => PendingExport::Local(local),
+                        };
+                        exports.push((exported, export));
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let (declaration_span, id) = match &decl.declaration {
+                        ExportDefaultDeclarationKind::Expression(expr) => (expr.span(), None),
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            (func.span, func.id.as_ref())
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            (class.span, class.id.as_ref())
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+                        | ExportDefaultDeclarationKind::TSEnumDeclaration(_) => {
+                            info.edits.push(Edit::remove(decl.span));
+                            continue;
+                        }
+                    };
+                    let prefix = Span::new(decl.span.start, declaration_span.start);
+                    if let Some(id) = id {
+                        info.edits.push(Edit::remove(prefix));
+                        exports.push((
+                            "default".to_string(),
+                            PendingExport::Local(id.name.to_string()),
+                        ));
+                    } else {
+                        let is_expression =
+                            matches!(decl.declaration, ExportDefaultDeclarationKind::Expression(_));
+                        let semicolon = (!is_expression).then_some(declaration_span.end);
+                        default_export = Some((prefix, semicolon));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        info.collect_names(&semantic);
+        info.collect_top_level(&semantic);
+        if let Some((prefix, semicolon)) = default_export {
+            let name = unique_name(&info.names, "_default");
+            info.names.insert(name.clone());
+            info.top_level.push(TopLevelBinding { name, occurrences: vec![] });
+            let binding = info.top_level.len() - 1;
+            info.default_export = Some(DefaultExport { prefix, semicolon, binding });
+            info.exports.push(("default".to_string(), Export::Local(binding)));
+        }
+        for (exported, export) in exports {
+            let export = match export {
+                PendingExport::Local(local) => {
+                    if let Some(index) = info.top_level.iter().position(|b| b.name == local) {
+                        Export::Local(index)
+                    } else if let Some(import) =
+                        info.imports.iter().find(|import| import.local == local)
+                    {
+                        // `import { a } from "b"; export { a }`
+                        Export::ReExport {
+                            specifier: import.specifier.clone(),
+                            imported: import.imported.clone(),
+                        }
+                    } else {
+                        continue;
+                    }
+                }
+                PendingExport::ReExport { specifier, imported } => {
+                    Export::ReExport { specifier, imported }
+                }
+            };
+            info.exports.push((exported, export));
+        }
+        info.collect_nodes(&semantic);
+        Ok(info)
+    }
+
+    pub fn export(&self, name: &str) -> Option<&Export> {
+        self.exports.iter().find(|(exported, _)| exported == name).map(|(_, export)| export)
+    }
+
+    fn add_attributes(&mut self, specifier: &str, with_clause: Option<&WithClause>) {
+        let Some(with_clause) = with_clause else { return };
+        if with_clause.with_entries.is_empty() || self.attributes.contains_key(specifier) {
+            return;
+        }
+        let attributes = with_clause
+            .with_entries
+            .iter()
+            .map(|entry| (entry.key.as_atom().to_string(), entry.value.value.to_string()))
+            .collect();
+        self.attributes.insert(specifier.to_string(), attributes);
+    }
+
+    fn collect_names(&mut self, semantic: &Semantic) {
+        let scopes = semantic.scopes();
+        self.names.extend(scopes.iter_bindings().map(|(_, _, name)| name.to_string()));
+        for name in scopes.root_unresolved_references().keys() {
+            self.names.insert(name.to_string());
+            self.globals.insert(name.to_string());
+        }
+    }
+
+    fn collect_top_level(&mut self, semantic: &Semantic) {
+        let scopes = semantic.scopes();
+        let symbols = semantic.symbols();
+        for (name, symbol_id) in scopes.get_bindings(scopes.root_scope_id()) {
+            let symbol_id = *symbol_id;
+            if symbols.get_flag(symbol_id).intersects(SymbolFlags::ImportBinding) {
+                continue;
+            }
+            let mut occurrences = vec![symbols.get_span(symbol_id)];
+            occurrences.extend(
+                semantic
+                    .redeclare_variables()
+                    .iter()
+                    .filter(|variable| variable.symbol_id == symbol_id)
+                    .map(|variable| variable.span),
+            );
+            occurrences.extend(semantic.symbol_references(symbol_id).map(Reference::span));
+            occurrences.sort_unstable_by_key(|span| span.start);
+            occurrences.dedup();
+            self.top_level.push(TopLevelBinding { name: name.to_string(), occurrences });
+        }
+        self.top_level.sort_by_key(|binding| binding.occurrences.first().map(|span| span.start));
+    }
+
+    fn collect_nodes(&mut self, semantic: &Semantic) {
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::ObjectProperty(prop) if prop.shorthand => {
+                    if let Expression::Identifier(ident) = &prop.value {
+                        self.shorthands.insert(ident.span);
+                    }
+                }
+                AstKind::ObjectPattern(pattern) => {
+                    for prop in pattern.properties.iter().filter(|prop| prop.shorthand) {
+                        let ident = match &prop.value.kind {
+                            BindingPatternKind::BindingIdentifier(ident) => ident,
+                            BindingPatternKind::AssignmentPattern(pattern) => {
+                                let BindingPatternKind::BindingIdentifier(ident) =
+                                    &pattern.left.kind
+                                else {
+                                    continue;
+                                };
+                                ident
+                            }
+                            _ => continue,
+                        };
+                        self.shorthands.insert(ident.span);
+                    }
+                }
+                AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(target),
+                )) => {
+                    for prop in &target.properties {
+                        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) =
+                            prop
+                        {
+                            self.shorthands.insert(prop.binding.span);
+                        }
+                    }
+                }
+                AstKind::CallExpression(call) => {
+                    let Expression::Identifier(callee) = &call.callee else { continue };
+                    self.callees.insert(callee.span);
+                    let is_global = callee
+                        .reference_id
+                        .get()
+                        .is_some_and(|id| !semantic.symbols().has_binding(id));
+                    if callee.name == "require" && is_global && call.arguments.len() == 1 {
+                        if let Argument::Expression(Expression::StringLiteral(specifier)) =
+                            &call.arguments[0]
+                        {
+                            self.requires.push((call.span, specifier.value.to_string()));
+                        }
+                    }
+                }
+                AstKind::TaggedTemplateExpression(expr) => {
+                    if let Expression::Identifier(tag) = &expr.tag {
+                        self.callees.insert(tag.span);
+                    }
+                }
+                AstKind::ImportExpression(expr) => {
+                    if let Expression::StringLiteral(specifier) = &expr.source {
+                        self.dynamic_imports.push((expr.span, specifier.value.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn references(semantic: &Semantic, ident: &BindingIdentifier) -> Vec<Span> {
+    ident.symbol_id.get().map_or_else(Vec::new, |symbol_id| {
+        semantic.symbol_references(symbol_id).map(Reference::span).collect()
+    })
+}
+
+/// `base`, or `base` with the smallest numeric suffix which is not in `names`.
+pub fn unique_name(names: &FxHashSet<String>, base: &str) -> String {
+    if !names.contains(base) {
+        return base.to_string();
+    }
+    // One of `names.len() + 1` suffixes is not taken
+    (1..=names.len() + 1).map(|i| format!("{base}${i}")).find(|name| !names.contains(name)).unwrap()
+}
@@ -0,0 +1,232 @@
+//! Code splitting analysis
+//!
+//! Every entry and every module loaded with `import()` is the root of a chunk.
+//! Modules are assigned to chunks by the set of roots which load them synchronously,
+//! modules loaded by a single root belong to the chunk of the root,
+//! modules loaded by multiple roots belong to a shared chunk of these roots.
+
+use oxc_diagnostics::Error;
+use oxc_index::{define_index_type, IndexVec};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    analyze::ModuleInfo,
+    analyze_modules,
+    graph::{ModuleGraph, ModuleId},
+};
+
+define_index_type! {
+    pub struct ChunkId = u32;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkKind {
+    /// The chunk of an entry point
+    Entry,
+    /// The chunk of a module loaded with `import()`
+    DynamicEntry,
+    /// Modules loaded by multiple entries or dynamic entries
+    Shared,
+}
+
+#[derive(Debug)]
+pub struct Chunk {
+    pub kind: ChunkKind,
+    /// The entry module of entry and dynamic entry chunks.
+    ///
+    /// The module is not in `modules` if it is also loaded by another root,
+    /// the chunk then only imports the shared chunk containing it.
+    pub entry: Option<ModuleId>,
+    /// Modules in execution order
+    pub modules: Vec<ModuleId>,
+    /// Chunks which must be loaded before this chunk
+    pub imports: Vec<ChunkId>,
+    /// Chunks loaded with `import()` by modules of this chunk
+    pub dynamic_imports: Vec<ChunkId>,
+}
+
+#[derive(Debug)]
+pub struct ChunkGraph {
+    chunks: IndexVec<ChunkId, Chunk>,
+    module_chunks: FxHashMap<ModuleId, ChunkId>,
+}
+
+impl ChunkGraph {
+    /// Split `entries` and the modules they depend on into chunks.
+    ///
+    /// `require()` calls load modules synchronously, they do not start a chunk.
+    /// Assets are leaves, they are assigned to chunks like JavaScript modules.
+    ///
+    /// # Errors
+    ///
+    /// Returns syntax errors and static imports of modules which are not in the graph.
+    pub fn build(graph: &ModuleGraph, entries: &[ModuleId]) -> Result<Self, Vec<Error>> {
+        let infos = analyze_modules(graph, entries, true)?;
+        let edges = Edges::new(graph, &infos);
+        let order = edges.execution_order(entries);
+
+        // Entries first, then dynamically imported modules in execution order
+        let mut roots = vec![];
+        let mut kinds = vec![];
+        for entry in entries {
+            if !roots.contains(entry) {
+                roots.push(*entry);
+                kinds.push(ChunkKind::Entry);
+            }
+        }
+        for module in &order {
+            for dependency in &edges.dynamic[module] {
+                if !roots.contains(dependency) {
+                    roots.push(*dependency);
+                    kinds.push(ChunkKind::DynamicEntry);
+                }
+            }
+        }
+
+        // Module -> indices of the roots which load it, in ascending order
+        let mut loaded_by = FxHashMap::<ModuleId, Vec<usize>>::default();
+        for (index, root) in roots.iter().enumerate() {
+            let mut stack = vec![*root];
+            let mut visited = FxHashSet::default();
+            while let Some(module) = stack.pop() {
+                if visited.insert(module) {
+                    loaded_by.entry(module).or_default().push(index);
+                    stack.extend(&edges.statics[&module]);
+                }
+            }
+        }
+
+        let mut chunks = IndexVec::<ChunkId, Chunk>::default();
+        let mut chunk_by_roots = FxHashMap::<Vec<usize>, ChunkId>::default();
+        let mut root_chunks = FxHashMap::default();
+        for (index, (root, kind)) in roots.iter().zip(kinds).enumerate() {
+            let chunk = chunks.push(Chunk::new(kind, Some(*root)));
+            chunk_by_roots.insert(vec![index], chunk);
+            root_chunks.insert(*root, chunk);
+        }
+        let mut module_chunks = FxHashMap::default();
+        for module in &order {
+            let Some(roots) = loaded_by.remove(module) else { continue };
+            let chunk = *chunk_by_roots
+                .entry(roots)
+                .or_insert_with(|| chunks.push(Chunk::new(ChunkKind::Shared, None)));
+            chunks[chunk].modules.push(*module);
+            module_chunks.insert(*module, chunk);
+        }
+
+        for (id, chunk) in chunks.iter_mut_enumerated() {
+            let mut imports = vec![];
+            let mut dynamic_imports = vec![];
+            if let Some(entry) = chunk.entry {
+                // The entry module is in a shared chunk if it is loaded by other roots as well
+                if !chunk.modules.contains(&entry) {
+                    imports.push(module_chunks[&entry]);
+                }
+            }
+            for module in &chunk.modules {
+                for dependency in &edges.statics[module] {
+                    let dependency_chunk = module_chunks[dependency];
+                    if dependency_chunk != id {
+                        push_unique(&mut imports, dependency_chunk);
+                    }
+                }
+                for dependency in &edges.dynamic[module] {
+                    push_unique(&mut dynamic_imports, root_chunks[dependency]);
+                }
+            }
+            chunk.imports = imports;
+            chunk.dynamic_imports = dynamic_imports;
+        }
+
+        Ok(Self { chunks, module_chunks })
+    }
+
+    pub fn chunk(&self, id: ChunkId) -> &Chunk {
+        &self.chunks[id]
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = (ChunkId, &Chunk)> + '_ {
+        self.chunks.iter_enumerated()
+    }
+
+    /// The chunk containing `module`, `None` if it is not loaded by any entry.
+    pub fn chunk_of(&self, module: ModuleId) -> Option<ChunkId> {
+        self.module_chunks.get(&module).copied()
+    }
+}
+
+impl Chunk {
+    fn new(kind: ChunkKind, entry: Option<ModuleId>) -> Self {
+        Self { kind, entry, modules: vec![], imports: vec![], dynamic_imports: vec![] }
+    }
+}
+
+/// Resolved dependencies of the analyzed modules.
+struct Edges {
+    /// Static imports, re-exports and `require()` calls
+    statics: FxHashMap<ModuleId, Vec<ModuleId>>,
+    /// `import()` calls
+    dynamic: FxHashMap<ModuleId, Vec<ModuleId>>,
+}
+
+impl Edges {
+    fn new(graph: &ModuleGraph, infos: &FxHashMap<ModuleId, ModuleInfo>) -> Self {
+        let mut statics = FxHashMap::default();
+        let mut dynamic = FxHashMap::default();
+        for (id, info) in infos {
+            let resolve = |specifier: &String| graph.resolve(*id, specifier);
+            let mut dependencies = vec![];
+            for dependency in info
+                .requested_modules
+                .iter()
+                .map(|(specifier, _)| specifier)
+                .chain(info.requires.iter().map(|(_, specifier)| specifier))
+                .filter_map(resolve)
+            {
+                push_unique(&mut dependencies, dependency);
+            }
+            statics.insert(*id, dependencies);
+            let mut dependencies = vec![];
+            for dependency in
+                info.dynamic_imports.iter().map(|(_, specifier)| specifier).filter_map(resolve)
+            {
+                push_unique(&mut dependencies, dependency);
+            }
+            dynamic.insert(*id, dependencies);
+        }
+        Self { statics, dynamic }
+    }
+
+    /// Post order of static and then dynamic dependencies, starting from `entries`.
+    fn execution_order(&self, entries: &[ModuleId]) -> Vec<ModuleId> {
+        fn visit(
+            edges: &Edges,
+            id: ModuleId,
+            visited: &mut FxHashSet<ModuleId>,
+            order: &mut Vec<ModuleId>,
+        ) {
+            if !visited.insert(id) {
+                return;
+            }
+            for dependency in &edges.statics[&id] {
+                visit(edges, *dependency, visited, order);
+            }
+            order.push(id);
+            for dependency in &edges.dynamic[&id] {
+                visit(edges, *dependency, visited, order);
+            }
+        }
+        let mut order = vec![];
+        let mut visited = FxHashSet::default();
+        for entry in entries {
+            visit(self, *entry, &mut visited, &mut order);
+        }
+        order
+    }
+}
+
+fn push_unique<T: PartialEq>(items: &mut Vec<T>, item: T) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
@@ -0,0 +1,20 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Could not resolve `{0}` imported by `{1}`")]
+#[diagnostic(help("Add the module to the module graph"))]
+pub struct UnresolvedImport(pub String, pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`{0}` is not exported by `{1}`")]
+#[diagnostic()]
+pub struct MissingExport(pub String, pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot bundle the asset `{0}`")]
+#[diagnostic(help("Only JavaScript modules can be bundled, load the asset at runtime instead"))]
+pub struct UnsupportedAsset(pub String, #[label] pub Span);
@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf};
+
+use oxc_index::{define_index_type, IndexVec};
+use oxc_span::SourceType;
+use rustc_hash::FxHashMap;
+
+use crate::{analyze::ModuleInfo, source_map::SourceMap};
+
+define_index_type! {
+    pub struct ModuleId = u32;
+}
+
+/// A module of the [`ModuleGraph`].
+#[derive(Debug)]
+pub struct Module {
+    pub path: PathBuf,
+    pub source_text: String,
+    pub source_type: SourceType,
+    /// Specifier -> resolved module.
+    ///
+    /// Static imports of specifiers missing here are an error,
+    /// `require` calls and dynamic imports of them are left as is.
+    pub dependencies: FxHashMap<String, ModuleId>,
+    /// Non-JavaScript modules are leaves of the graph, they are neither parsed nor bundled.
+    pub asset: Option<Asset>,
+    /// Source map of a module compiled by another tool, e.g. a Svelte or Vue component,
+    /// the mappings of the bundle point into its sources.
+    pub input_source_map: Option<SourceMap>,
+}
+
+impl Module {
+    pub fn is_asset(&self) -> bool {
+        self.asset.is_some()
+    }
+}
+
+/// A non-JavaScript module, e.g. `import data from "./data.json" with { type: "json" }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub kind: AssetKind,
+    /// Import attributes of the first import of the asset, e.g. `[("type", "json")]`
+    pub attributes: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    Json,
+    Css,
+    Wasm,
+    /// A file imported as its URL, e.g. an image
+    Url,
+}
+
+impl AssetKind {
+    /// The kind of the `type` import attribute, `with { type: "json" }` and `with { type: "css" }`.
+    pub fn from_type_attribute(value: &str) -> Option<Self> {
+        match value {
+            "json" => Some(Self::Json),
+            "css" => Some(Self::Css),
+            "webassembly" => Some(Self::Wasm),
+            _ => None,
+        }
+    }
+}
+
+/// A specifier which is not resolved by [`ModuleGraph::add_dependency`],
+/// passed to the resolver of [`ModuleGraph::add_assets`].
+#[derive(Debug)]
+pub struct AssetRequest<'a> {
+    /// Path of the importing module
+    pub importer: &'a Path,
+    pub specifier: &'a str,
+    /// Import attributes of static imports and re-exports, empty for `require()` and `import()`
+    pub attributes: &'a [(String, String)],
+}
+
+/// Modules to bundle and how their specifiers resolve.
+///
+/// Resolution is done by the caller, the bundler does not touch the file system.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: IndexVec<ModuleId, Module>,
+    assets: FxHashMap<(PathBuf, AssetKind), ModuleId>,
+}
+
+impl ModuleGraph {
+    pub fn add_module(
+        &mut self,
+        path: PathBuf,
+        source_text: String,
+        source_type: SourceType,
+    ) -> ModuleId {
+        self.modules.push(Module {
+            path,
+            source_text,
+            source_type,
+            dependencies: FxHashMap::default(),
+            asset: None,
+            input_source_map: None,
+        })
+    }
+
+    /// Add a non-JavaScript module, once per path and kind.
+    pub fn add_asset(
+        &mut self,
+        path: PathBuf,
+        kind: AssetKind,
+        attributes: Vec<(String, String)>,
+    ) -> ModuleId {
+        if let Some(id) = self.assets.get(&(path.clone(), kind)) {
+            return *id;
+        }
+        let id = self.modules.push(Module {
+            path: path.clone(),
+            source_text: String::new(),
+            source_type: SourceType::default(),
+            dependencies: FxHashMap::default(),
+            asset: Some(Asset { kind, attributes }),
+            input_source_map: None,
+        });
+        self.assets.insert((path, kind), id);
+        id
+    }
+
+    /// Resolve the specifiers of the JavaScript modules which are not in the graph with
+    /// `resolver`, adding the assets it returns a path and a kind for.
+    ///
+    /// Modules with syntax errors are skipped, the errors are reported when bundling.
+    pub fn add_assets<F>(&mut self, mut resolver: F)
+    where
+        F: FnMut(&AssetRequest) -> Option<(PathBuf, AssetKind)>,
+    {
+        for id in self.modules.indices().collect::<Vec<_>>() {
+            let module = &self.modules[id];
+            if module.is_asset() {
+                continue;
+            }
+            let Ok(info) = ModuleInfo::analyze(&module.source_text, module.source_type) else {
+                continue;
+            };
+            let specifiers = info
+                .requested_modules
+                .iter()
+                .map(|(specifier, _)| specifier)
+                .chain(info.requires.iter().chain(&info.dynamic_imports).map(|(_, s)| s));
+            for specifier in specifiers {
+                let module = &self.modules[id];
+                if module.dependencies.contains_key(specifier) {
+                    continue;
+                }
+                let attributes = info.attributes.get(specifier).map_or(&[][..], Vec::as_slice);
+                let request = AssetRequest { importer: &module.path, specifier, attributes };
+                let Some((path, kind)) = resolver(&request) else { continue };
+                let asset = self.add_asset(path, kind, attributes.to_vec());
+                self.add_dependency(id, specifier, asset);
+            }
+        }
+    }
+
+    /// Chain the source map of a module which was compiled from other sources.
+    pub fn set_input_source_map(&mut self, id: ModuleId, source_map: SourceMap) {
+        self.modules[id].input_source_map = Some(source_map);
+    }
+
+    /// Resolve `specifier` imported by `from` to `to`.
+    pub fn add_dependency(&mut self, from: ModuleId, specifier: &str, to: ModuleId) {
+        self.modules[from].dependencies.insert(specifier.to_string(), to);
+    }
+
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id]
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = (ModuleId, &Module)> + '_ {
+        self.modules.iter_enumerated()
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// The module `specifier` imported by `from` resolves to.
+    pub fn resolve(&self, from: ModuleId, specifier: &str) -> Option<ModuleId> {
+        self.modules[from].dependencies.get(specifier).copied()
+    }
+}
@@ -0,0 +1,151 @@
+//! Experimental bundler
+//!
+//! Bundles the modules of a [`ModuleGraph`] reachable from an entry into a single script
+//! with a source map, enough to bundle e.g. a web worker.
+//!
+//! * [`BundleMode::Wrap`] registers every module as a factory function with a tiny runtime,
+//!   modules are evaluated on their first `require`.
+//! * [`BundleMode::Concatenate`] hoists ES modules into a single scope and renames colliding
+//!   top level bindings, CommonJS modules and modules loaded with `require()` or `import()`
+//!   are still wrapped.
+//!
+//! [`ChunkGraph`] assigns the modules of multiple entries to chunks, for tools implementing
+//! code splitting on top of the module graph.
+//!
+//! Non-JavaScript modules such as JSON, CSS and WebAssembly are added to the graph as assets,
+//! leaves which are part of the chunks but cannot be bundled.
+//!
+//! Modules are edited textually, TypeScript and JSX must be transformed beforehand.
+//! Names starting with `__oxc_` are reserved for the bundler.
+
+mod analyze;
+mod chunk;
+mod diagnostics;
+mod graph;
+mod render;
+mod runtime;
+mod source_map;
+
+use oxc_diagnostics::{miette::NamedSource, Error};
+use rustc_hash::FxHashMap;
+
+use crate::{analyze::ModuleInfo, render::Linker};
+pub use crate::{
+    chunk::{Chunk, ChunkGraph, ChunkId, ChunkKind},
+    graph::{Asset, AssetKind, AssetRequest, Module, ModuleGraph, ModuleId},
+    source_map::{SourceMap, SourceMapOptions},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BundleMode {
+    #[default]
+    Wrap,
+    Concatenate,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct BundleOptions {
+    pub mode: BundleMode,
+    pub source_map: SourceMapOptions,
+}
+
+#[derive(Debug)]
+pub struct BundleOutput {
+    pub code: String,
+    pub source_map: SourceMap,
+}
+
+pub struct Bundler {
+    options: BundleOptions,
+}
+
+impl Bundler {
+    pub fn new(options: BundleOptions) -> Self {
+        Self { options }
+    }
+
+    /// Bundle `entry` and the modules it depends on.
+    ///
+    /// # Errors
+    ///
+    /// Returns syntax errors, static imports of modules which are not in the graph, imports of
+    /// assets and, when concatenating, imports of bindings which are not exported.
+    pub fn bundle(&self, graph: &ModuleGraph, entry: ModuleId) -> Result<BundleOutput, Vec<Error>> {
+        let infos = analyze_modules(graph, &[entry], false)?;
+        let mut output = Linker::new(graph, &infos, entry, self.options.mode).render()?;
+        if let Some(comment) = self.options.source_map.apply(&mut output.source_map) {
+            output.code.push_str(&comment);
+        }
+        Ok(output)
+    }
+}
+
+/// Analyze `entries` and the modules they depend on, assets are not parsed.
+///
+/// # Errors
+///
+/// Returns syntax errors, static imports of modules which are not in the graph and,
+/// unless `allow_assets`, imports of assets.
+fn analyze_modules(
+    graph: &ModuleGraph,
+    entries: &[ModuleId],
+    allow_assets: bool,
+) -> Result<FxHashMap<ModuleId, ModuleInfo>, Vec<Error>> {
+    let mut infos = FxHashMap::default();
+    let mut errors = vec![];
+    let mut stack = entries.to_vec();
+    while let Some(id) = stack.pop() {
+        if infos.contains_key(&id) {
+            continue;
+        }
+        let module = graph.module(id);
+        if module.is_asset() {
+            infos.insert(id, ModuleInfo::default());
+            continue;
+        }
+        let info = match ModuleInfo::analyze(&module.source_text, module.source_type) {
+            Ok(info) => info,
+            Err(module_errors) => {
+                errors.extend(module_errors.into_iter().map(|error| with_source(error, module)));
+                // Keep going to report the errors of all modules
+                ModuleInfo::default()
+            }
+        };
+        for (specifier, span) in &info.requested_modules {
+            if let Some(dependency) = graph.resolve(id, specifier) {
+                stack.push(dependency);
+            } else {
+                let path = module.path.display().to_string();
+                let error = diagnostics::UnresolvedImport(specifier.clone(), path, *span);
+                errors.push(with_source(error.into(), module));
+            }
+        }
+        for (_, specifier) in info.requires.iter().chain(&info.dynamic_imports) {
+            stack.extend(graph.resolve(id, specifier));
+        }
+        if !allow_assets {
+            let requests = info.requested_modules.iter().map(|(specifier, span)| (span, specifier));
+            let calls = info.requires.iter().chain(&info.dynamic_imports);
+            for (span, specifier) in requests.chain(calls.map(|call| (&call.0, &call.1))) {
+                let Some(dependency) = graph.resolve(id, specifier) else { continue };
+                let dependency = graph.module(dependency);
+                if dependency.is_asset() {
+                    let path = dependency.path.display().to_string();
+                    let error = diagnostics::UnsupportedAsset(path, *span);
+                    errors.push(with_source(error.into(), module));
+                }
+            }
+        }
+        infos.insert(id, info);
+    }
+    if errors.is_empty() {
+        Ok(infos)
+    } else {
+        Err(errors)
+    }
+}
+
+fn with_source(error: Error, module: &Module) -> Error {
+    let source = NamedSource::new(module.path.to_string_lossy(), module.source_text.clone());
+    error.with_source_code(source)
+}
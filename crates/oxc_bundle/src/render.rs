@@ -0,0 +1,673 @@
+//! Links the analyzed modules and renders the bundle.
+
+use std::{collections::BTreeSet, fmt::Write};
+
+use oxc_diagnostics::Error;
+use oxc_span::Span;
+use oxc_syntax::identifier::is_identifier_name;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    analyze::{Edit, Export, ImportName, ModuleInfo},
+    diagnostics,
+    graph::{Module, ModuleGraph, ModuleId},
+    runtime::{self, EXPORT, REEXPORT, REGISTER, REQUIRE, TO_ESM},
+    source_map::{utf16_len, LineIndex, SourceMapBuilder, SourceMapLookup},
+    with_source, BundleMode, BundleOutput,
+};
+
+/// Where an imported binding lives in the bundle.
+enum Binding {
+    /// Top level binding of a concatenated module
+    Local(ModuleId, usize),
+    /// Namespace object of a concatenated module
+    Namespace(ModuleId),
+    /// Export of a wrapped module, its namespace if `None`
+    Wrapped(ModuleId, Option<String>),
+}
+
+/// A module ready to be printed.
+struct RenderedModule {
+    id: ModuleId,
+    /// Code inserted before the module
+    prologue: String,
+    edits: Vec<Edit>,
+}
+
+pub struct Linker<'a> {
+    graph: &'a ModuleGraph,
+    infos: &'a FxHashMap<ModuleId, ModuleInfo>,
+    entry: ModuleId,
+    /// Modules registered with the runtime, all modules when wrapping
+    wrapped: FxHashSet<ModuleId>,
+    /// Concatenated modules in execution order
+    concatenated: Vec<ModuleId>,
+    /// Final names of the top level bindings of concatenated modules
+    names: FxHashMap<ModuleId, Vec<String>>,
+    /// Concatenated modules whose namespace object is used
+    namespaces: BTreeSet<ModuleId>,
+    errors: Vec<Error>,
+}
+
+impl<'a> Linker<'a> {
+    pub fn new(
+        graph: &'a ModuleGraph,
+        infos: &'a FxHashMap<ModuleId, ModuleInfo>,
+        entry: ModuleId,
+        mode: BundleMode,
+    ) -> Self {
+        let mut linker = Self {
+            graph,
+            infos,
+            entry,
+            wrapped: FxHashSet::default(),
+            concatenated: vec![],
+            names: FxHashMap::default(),
+            namespaces: BTreeSet::default(),
+            errors: vec![],
+        };
+        match mode {
+            BundleMode::Wrap => linker.wrapped = infos.keys().copied().collect(),
+            BundleMode::Concatenate => {
+                linker.collect_wrapped();
+                linker.concatenated = linker.execution_order();
+                linker.deconflict();
+            }
+        }
+        linker
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error for each import of a binding which is not exported.
+    pub fn render(mut self) -> Result<BundleOutput, Vec<Error>> {
+        let mut wrapped = self.wrapped.iter().copied().collect::<Vec<_>>();
+        wrapped.sort_unstable();
+        let wrapped = wrapped.into_iter().map(|id| self.render_wrapped(id)).collect::<Vec<_>>();
+        let concatenated = self
+            .concatenated
+            .clone()
+            .into_iter()
+            .map(|id| self.render_concatenated(id))
+            .collect::<Vec<_>>();
+        let namespaces = self.render_namespaces();
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+
+        let mut output = Output::default();
+        output.push_str("(function () {\n");
+        if !wrapped.is_empty() || !namespaces.is_empty() {
+            output.push_str(runtime::RUNTIME);
+        }
+        output.push_str(&namespaces);
+        for module in wrapped {
+            let header = format!(
+                "// {}\n{REGISTER}({}, function (module, exports) {{\n",
+                self.graph.module(module.id).path.display(),
+                module.id.index()
+            );
+            output.push_str(&header);
+            self.print_module(&mut output, module);
+            output.push_str("});\n");
+        }
+        for module in concatenated {
+            output.push_str(&format!("// {}\n", self.graph.module(module.id).path.display()));
+            self.print_module(&mut output, module);
+        }
+        if self.wrapped.contains(&self.entry) {
+            output.push_str(&format!("{REQUIRE}({});\n", self.entry.index()));
+        }
+        output.push_str("})();\n");
+        Ok(BundleOutput { code: output.code, source_map: output.source_map.build() })
+    }
+
+    /// CommonJS modules, modules loaded with `require()` or `import()`,
+    /// and the static dependencies of wrapped modules.
+    fn collect_wrapped(&mut self) {
+        let mut stack = vec![];
+        for (id, info) in self.infos {
+            if !info.is_esm {
+                stack.push(*id);
+            }
+            for (_, specifier) in info.requires.iter().chain(&info.dynamic_imports) {
+                stack.extend(self.graph.resolve(*id, specifier));
+            }
+        }
+        while let Some(id) = stack.pop() {
+            if self.wrapped.insert(id) {
+                stack.extend(self.static_dependencies(id));
+            }
+        }
+    }
+
+    /// Post order of the static imports of concatenated modules, starting from the entry.
+    fn execution_order(&self) -> Vec<ModuleId> {
+        fn visit(
+            linker: &Linker,
+            id: ModuleId,
+            visited: &mut FxHashSet<ModuleId>,
+            order: &mut Vec<ModuleId>,
+        ) {
+            if linker.wrapped.contains(&id) || !visited.insert(id) {
+                return;
+            }
+            for dependency in linker.static_dependencies(id) {
+                visit(linker, dependency, visited, order);
+            }
+            order.push(id);
+        }
+        let mut order = vec![];
+        visit(self, self.entry, &mut FxHashSet::default(), &mut order);
+        order
+    }
+
+    /// Rename top level bindings of concatenated modules which collide with each other,
+    /// with globals referenced by any module or with the runtime.
+    fn deconflict(&mut self) {
+        let mut reserved = runtime::NAMES.iter().map(ToString::to_string).collect::<FxHashSet<_>>();
+        let mut all_names = FxHashSet::default();
+        for info in self.infos.values() {
+            reserved.extend(info.globals.iter().cloned());
+            all_names.extend(info.names.iter().cloned());
+        }
+        let mut taken = FxHashSet::default();
+        for id in &self.concatenated {
+            let names = self.infos[id]
+                .top_level
+                .iter()
+                .map(|binding| {
+                    let mut name = binding.name.clone();
+                    if taken.contains(&name) || reserved.contains(&name) {
+                        // Renamed bindings must not be shadowed by any binding of any module
+                        let count = taken.len() + reserved.len() + all_names.len();
+                        name = (1..=count + 1)
+                            .map(|i| format!("{}${i}", binding.name))
+                            .find(|name| {
+                                !taken.contains(name)
+                                    && !reserved.contains(name)
+                                    && !all_names.contains(name)
+                            })
+                            .unwrap();
+                    }
+                    taken.insert(name.clone());
+                    name
+                })
+                .collect();
+            self.names.insert(*id, names);
+        }
+    }
+
+    fn static_dependencies(&self, id: ModuleId) -> impl Iterator<Item = ModuleId> + '_ {
+        self.infos[&id]
+            .requested_modules
+            .iter()
+            .filter_map(move |(specifier, _)| self.graph.resolve(id, specifier))
+    }
+
+    fn render_wrapped(&self, id: ModuleId) -> RenderedModule {
+        let info = &self.infos[&id];
+        let source_text = &self.graph.module(id).source_text;
+        let mut prologue = String::new();
+        let mut edits = info.edits.clone();
+        if info.is_esm {
+            prologue.push_str("\"use strict\";\n");
+            let getters = info
+                .exports
+                .iter()
+                .filter_map(|(name, export)| {
+                    let value = match export {
+                        Export::Local(index) => info.top_level[*index].name.clone(),
+                        Export::ReExport { specifier, imported } => {
+                            let dependency = self.graph.resolve(id, specifier)?;
+                            wrapped_import(dependency, imported).0
+                        }
+                    };
+                    Some((name.as_str(), value))
+                })
+                .collect::<Vec<_>>();
+            prologue.push_str(&export_call("exports", &getters));
+            let mut dependencies = vec![];
+            for dependency in self.static_dependencies(id) {
+                if !dependencies.contains(&dependency) {
+                    dependencies.push(dependency);
+                    prologue.push_str(&format!(
+                        "var {} = {};\n",
+                        module_var(dependency),
+                        self.require_namespace(dependency)
+                    ));
+                }
+            }
+            for specifier in &info.star_exports {
+                if let Some(dependency) = self.graph.resolve(id, specifier) {
+                    prologue
+                        .push_str(&format!("{REEXPORT}(exports, {});\n", module_var(dependency)));
+                }
+            }
+            for import in &info.imports {
+                let Some(dependency) = self.graph.resolve(id, &import.specifier) else { continue };
+                let (value, is_member) = wrapped_import(dependency, &import.imported);
+                for span in &import.references {
+                    edits.push(replace_identifier(info, source_text, *span, &value, is_member));
+                }
+            }
+            if let Some(default_export) = &info.default_export {
+                let name = &info.top_level[default_export.binding].name;
+                push_default_export_edits(info, name, &mut edits);
+            }
+        }
+        self.push_call_edits(id, &mut edits);
+        RenderedModule { id, prologue, edits }
+    }
+
+    fn render_concatenated(&mut self, id: ModuleId) -> RenderedModule {
+        let (graph, infos) = (self.graph, self.infos);
+        let info = &infos[&id];
+        let source_text = &graph.module(id).source_text;
+        let mut edits = info.edits.clone();
+        // Wrapped modules are evaluated before the module, in import order
+        let mut wrapped_dependencies = vec![];
+        for dependency in self.static_dependencies(id) {
+            if self.wrapped.contains(&dependency) && !wrapped_dependencies.contains(&dependency) {
+                wrapped_dependencies.push(dependency);
+            }
+        }
+
+        let names = self.names[&id].clone();
+        for (binding, name) in info.top_level.iter().zip(&names) {
+            if binding.name != *name {
+                for span in &binding.occurrences {
+                    edits.push(replace_identifier(info, source_text, *span, name, false));
+                }
+            }
+        }
+        for import in &info.imports {
+            let Some(dependency) = graph.resolve(id, &import.specifier) else { continue };
+            let Some(binding) =
+                self.resolve_import(dependency, &import.imported, &mut FxHashSet::default())
+            else {
+                let error = diagnostics::MissingExport(
+                    import.imported.as_export_name().unwrap_or_default().to_string(),
+                    graph.module(dependency).path.display().to_string(),
+                    import.span,
+                );
+                self.errors.push(with_source(error.into(), graph.module(id)));
+                continue;
+            };
+            if let Binding::Wrapped(dependency, _) = &binding {
+                if !wrapped_dependencies.contains(dependency) {
+                    wrapped_dependencies.push(*dependency);
+                }
+            }
+            let (value, is_member) = self.binding_value(&binding, false);
+            for span in &import.references {
+                edits.push(replace_identifier(info, source_text, *span, &value, is_member));
+            }
+        }
+        if let Some(default_export) = &info.default_export {
+            push_default_export_edits(info, &names[default_export.binding], &mut edits);
+        }
+        self.push_call_edits(id, &mut edits);
+
+        let prologue =
+            wrapped_dependencies.into_iter().fold(String::new(), |mut prologue, dependency| {
+                let namespace = self.require_namespace(dependency);
+                let _ = writeln!(prologue, "var {} = {namespace};", module_var(dependency));
+                prologue
+            });
+        RenderedModule { id, prologue, edits }
+    }
+
+    /// Namespace objects of concatenated modules, with getters for all exports.
+    fn render_namespaces(&mut self) -> String {
+        let mut code = String::new();
+        let mut rendered = FxHashSet::default();
+        // Namespaces can re-export other namespaces
+        while let Some(id) = self.namespaces.iter().copied().find(|id| !rendered.contains(id)) {
+            rendered.insert(id);
+            let mut names = vec![];
+            let mut wrapped_stars = vec![];
+            self.export_names(id, true, &mut names, &mut wrapped_stars, &mut FxHashSet::default());
+            let getters = names
+                .iter()
+                .filter_map(|name| {
+                    let binding = self.resolve_export(id, name, &mut FxHashSet::default())?;
+                    Some((name.as_str(), self.binding_value(&binding, true).0))
+                })
+                .collect::<Vec<_>>();
+            let namespace = namespace_var(id);
+            code.push_str(&format!("var {namespace} = {{}};\n"));
+            code.push_str(&export_call(&namespace, &getters));
+            for dependency in wrapped_stars {
+                // Evaluates the wrapped module early, its exports cannot be known statically
+                let value = self.require_namespace(dependency);
+                code.push_str(&format!("{REEXPORT}({namespace}, {value});\n"));
+            }
+        }
+        code
+    }
+
+    /// All export names of the concatenated module `id`,
+    /// `wrapped_stars` are the wrapped modules it re-exports everything from.
+    fn export_names(
+        &self,
+        id: ModuleId,
+        include_default: bool,
+        names: &mut Vec<String>,
+        wrapped_stars: &mut Vec<ModuleId>,
+        visited: &mut FxHashSet<ModuleId>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+        let info = &self.infos[&id];
+        for (name, _) in &info.exports {
+            if (include_default || name != "default") && !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        for specifier in &info.star_exports {
+            let Some(dependency) = self.graph.resolve(id, specifier) else { continue };
+            if self.wrapped.contains(&dependency) {
+                wrapped_stars.push(dependency);
+            } else {
+                self.export_names(dependency, false, names, wrapped_stars, visited);
+            }
+        }
+    }
+
+    fn resolve_import(
+        &self,
+        id: ModuleId,
+        imported: &ImportName,
+        visited: &mut FxHashSet<(ModuleId, String)>,
+    ) -> Option<Binding> {
+        match imported.as_export_name() {
+            Some(name) => self.resolve_export(id, name, visited),
+            None if self.wrapped.contains(&id) => Some(Binding::Wrapped(id, None)),
+            None => Some(Binding::Namespace(id)),
+        }
+    }
+
+    /// The binding exported as `name` by module `id`, following re-exports.
+    fn resolve_export(
+        &self,
+        id: ModuleId,
+        name: &str,
+        visited: &mut FxHashSet<(ModuleId, String)>,
+    ) -> Option<Binding> {
+        if self.wrapped.contains(&id) {
+            return Some(Binding::Wrapped(id, Some(name.to_string())));
+        }
+        if !visited.insert((id, name.to_string())) {
+            return None;
+        }
+        let info = &self.infos[&id];
+        if let Some(export) = info.export(name) {
+            return match export {
+                Export::Local(index) => Some(Binding::Local(id, *index)),
+                Export::ReExport { specifier, imported } => {
+                    let dependency = self.graph.resolve(id, specifier)?;
+                    self.resolve_import(dependency, imported, visited)
+                }
+            };
+        }
+        if name == "default" {
+            return None;
+        }
+        let dependencies = info
+            .star_exports
+            .iter()
+            .filter_map(|specifier| self.graph.resolve(id, specifier))
+            .collect::<Vec<_>>();
+        // Exports of wrapped modules are not known statically, try them last
+        let (wrapped, concatenated): (Vec<_>, Vec<_>) =
+            dependencies.into_iter().partition(|dependency| self.wrapped.contains(dependency));
+        concatenated
+            .into_iter()
+            .chain(wrapped)
+            .find_map(|dependency| self.resolve_export(dependency, name, visited))
+    }
+
+    /// The expression of `binding` and whether it is a member expression.
+    ///
+    /// `inline` requires wrapped modules in place instead of using their module variable.
+    fn binding_value(&mut self, binding: &Binding, inline: bool) -> (String, bool) {
+        match binding {
+            Binding::Local(id, index) => (self.names[id][*index].clone(), false),
+            Binding::Namespace(id) => {
+                self.namespaces.insert(*id);
+                (namespace_var(*id), false)
+            }
+            Binding::Wrapped(id, name) => {
+                let object = if inline {
+                    format!("({})", self.require_namespace(*id))
+                } else {
+                    module_var(*id)
+                };
+                match name {
+                    Some(name) => (member(&object, name), true),
+                    None => (object, false),
+                }
+            }
+        }
+    }
+
+    /// `require()` calls and `import()` of bundled modules.
+    fn push_call_edits(&self, id: ModuleId, edits: &mut Vec<Edit>) {
+        let info = &self.infos[&id];
+        for (span, specifier) in &info.requires {
+            if let Some(dependency) = self.graph.resolve(id, specifier) {
+                edits.push(Edit::new(*span, format!("{REQUIRE}({})", dependency.index())));
+            }
+        }
+        for (span, specifier) in &info.dynamic_imports {
+            if let Some(dependency) = self.graph.resolve(id, specifier) {
+                let namespace = self.require_namespace(dependency);
+                let content =
+                    format!("Promise.resolve().then(function () {{ return {namespace}; }})");
+                edits.push(Edit::new(*span, content));
+            }
+        }
+    }
+
+    /// Require the wrapped module `id` as an ES module namespace.
+    fn require_namespace(&self, id: ModuleId) -> String {
+        let require = format!("{REQUIRE}({})", id.index());
+        if self.infos[&id].is_esm {
+            require
+        } else {
+            format!("{TO_ESM}({require})")
+        }
+    }
+
+    fn print_module(&self, output: &mut Output, module: RenderedModule) {
+        let RenderedModule { id, prologue, mut edits } = module;
+        let graph_module = self.graph.module(id);
+        let source_text = graph_module.source_text.as_str();
+        let origin = Origin::new(output, graph_module);
+
+        output.push_str(&prologue);
+        edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+        let mut cursor = 0;
+        for edit in edits {
+            // Edits inside a replaced span, e.g. references in a removed export declaration
+            if edit.span.start < cursor {
+                continue;
+            }
+            output.push_source(
+                &source_text[cursor as usize..edit.span.start as usize],
+                cursor,
+                &origin,
+            );
+            if !edit.content.is_empty() {
+                output.add_mapping(edit.span.start, &origin);
+                output.push_str(&edit.content);
+            }
+            cursor = edit.span.end;
+        }
+        output.push_source(&source_text[cursor as usize..], cursor, &origin);
+        if !source_text.ends_with('\n') {
+            output.push_str("\n");
+        }
+    }
+}
+
+/// Bundled code with its source map.
+#[derive(Default)]
+struct Output {
+    code: String,
+    line: u32,
+    column: u32,
+    source_map: SourceMapBuilder,
+}
+
+impl Output {
+    /// Push generated code without mappings.
+    fn push_str(&mut self, text: &str) {
+        self.code.push_str(text);
+        if let Some(last_newline) = text.rfind('\n') {
+            self.line += u32::try_from(text.matches('\n').count()).unwrap();
+            self.column = utf16_len(&text[last_newline + 1..]);
+        } else {
+            self.column += utf16_len(text);
+        }
+    }
+
+    /// Push `text` copied from the module at `offset`, mapping the start of each line.
+    fn push_source(&mut self, text: &str, offset: u32, origin: &Origin) {
+        let mut offset = offset;
+        for line in text.split_inclusive('\n') {
+            self.add_mapping(offset, origin);
+            self.push_str(line);
+            offset += u32::try_from(line.len()).unwrap();
+        }
+    }
+
+    fn add_mapping(&mut self, offset: u32, origin: &Origin) {
+        if let Some((source, position)) = origin.original_position(offset) {
+            self.source_map.add_mapping((self.line, self.column), source, position);
+        }
+    }
+}
+
+/// Maps byte offsets of a module to the sources of the bundle's source map.
+struct Origin<'a> {
+    index: LineIndex<'a>,
+    /// The module itself, or the sources of its input source map
+    sources: Vec<u32>,
+    input_source_map: Option<SourceMapLookup>,
+}
+
+impl<'a> Origin<'a> {
+    /// Add the module, or the sources of its input source map, to the sources of `output`.
+    fn new(output: &mut Output, module: &'a Module) -> Self {
+        let index = LineIndex::new(&module.source_text);
+        match &module.input_source_map {
+            Some(input_source_map) => {
+                let sources = input_source_map
+                    .sources
+                    .iter()
+                    .enumerate()
+                    .map(|(i, source)| {
+                        let content = input_source_map.sources_content.get(i);
+                        output
+                            .source_map
+                            .add_source(source.clone(), content.cloned().unwrap_or_default())
+                    })
+                    .collect();
+                let lookup = SourceMapLookup::new(input_source_map);
+                Self { index, sources, input_source_map: Some(lookup) }
+            }
+            None => {
+                let name = module.path.display().to_string();
+                let source = output.source_map.add_source(name, module.source_text.clone());
+                Self { index, sources: vec![source], input_source_map: None }
+            }
+        }
+    }
+
+    /// `None` for code the input source map has no original position for.
+    fn original_position(&self, offset: u32) -> Option<(u32, (u32, u32))> {
+        let position = self.index.line_column(offset);
+        match &self.input_source_map {
+            Some(lookup) => {
+                let (source, position) = lookup.original_position(position)?;
+                Some((*self.sources.get(source as usize)?, position))
+            }
+            None => Some((self.sources[0], position)),
+        }
+    }
+}
+
+/// Replace the identifier at `span` with `value`, keeping shorthand properties valid
+/// and calls of member expressions without a `this` value.
+fn replace_identifier(
+    info: &ModuleInfo,
+    source_text: &str,
+    span: Span,
+    value: &str,
+    is_member: bool,
+) -> Edit {
+    let content = if info.shorthands.contains(&span) {
+        format!("{}: {value}", span.source_text(source_text))
+    } else if is_member && info.callees.contains(&span) {
+        format!("(0, {value})")
+    } else {
+        value.to_string()
+    };
+    Edit::new(span, content)
+}
+
+fn push_default_export_edits(info: &ModuleInfo, name: &str, edits: &mut Vec<Edit>) {
+    let Some(default_export) = &info.default_export else { return };
+    edits.push(Edit::new(default_export.prefix, format!("var {name} = ")));
+    if let Some(position) = default_export.semicolon {
+        edits.push(Edit::new(Span::new(position, position), ";".to_string()));
+    }
+}
+
+/// The value of an import of the wrapped module `id` inside of a wrapped module.
+fn wrapped_import(id: ModuleId, imported: &ImportName) -> (String, bool) {
+    match imported.as_export_name() {
+        Some(name) => (member(&module_var(id), name), true),
+        None => (module_var(id), false),
+    }
+}
+
+/// `__oxc_export(object, { name: function () { return value; } });`
+fn export_call(object: &str, getters: &[(&str, String)]) -> String {
+    if getters.is_empty() {
+        return String::new();
+    }
+    let mut code = format!("{EXPORT}({object}, {{\n");
+    for (name, value) in getters {
+        code.push_str(&format!("  {}: function () {{ return {value}; }},\n", property_key(name)));
+    }
+    code.push_str("});\n");
+    code
+}
+
+fn module_var(id: ModuleId) -> String {
+    format!("__oxc_m{}", id.index())
+}
+
+fn namespace_var(id: ModuleId) -> String {
+    format!("__oxc_ns{}", id.index())
+}
+
+fn member(object: &str, name: &str) -> String {
+    if is_identifier_name(name) {
+        format!("{object}.{name}")
+    } else {
+        format!("{object}[{}]", serde_json::Value::from(name))
+    }
+}
+
+fn property_key(name: &str) -> String {
+    if is_identifier_name(name) {
+        name.to_string()
+    } else {
+        serde_json::Value::from(name).to_string()
+    }
+}
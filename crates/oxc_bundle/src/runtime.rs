@@ -0,0 +1,52 @@
+//! The runtime of wrapped modules.
+//!
+//! Wrapped modules are registered as factories and evaluated on their first `__oxc_require`,
+//! ES modules define their exports as getters so bindings stay live.
+
+pub const REGISTER: &str = "__oxc_register";
+pub const REQUIRE: &str = "__oxc_require";
+pub const EXPORT: &str = "__oxc_export";
+pub const REEXPORT: &str = "__oxc_reexport";
+pub const TO_ESM: &str = "__oxc_to_esm";
+
+/// Names declared by the runtime, modules must not shadow them
+pub const NAMES: [&str; 7] =
+    ["__oxc_modules", "__oxc_cache", REGISTER, REQUIRE, EXPORT, REEXPORT, TO_ESM];
+
+pub const RUNTIME: &str = r#"var __oxc_modules = {};
+var __oxc_cache = {};
+function __oxc_register(id, factory) {
+  __oxc_modules[id] = factory;
+}
+function __oxc_require(id) {
+  var cached = __oxc_cache[id];
+  if (cached) return cached.exports;
+  var module = (__oxc_cache[id] = { exports: {} });
+  __oxc_modules[id].call(module.exports, module, module.exports);
+  return module.exports;
+}
+function __oxc_export(exports, getters) {
+  Object.defineProperty(exports, "__esModule", { value: true });
+  for (var name in getters) {
+    Object.defineProperty(exports, name, { enumerable: true, get: getters[name] });
+  }
+}
+function __oxc_reexport(exports, source) {
+  Object.keys(source).forEach(function (name) {
+    if (name === "default" || Object.prototype.hasOwnProperty.call(exports, name)) return;
+    Object.defineProperty(exports, name, {
+      enumerable: true,
+      get: function () {
+        return source[name];
+      },
+    });
+  });
+}
+function __oxc_to_esm(module) {
+  if (module && module.__esModule) return module;
+  var namespace = {};
+  if (module && typeof module === "object") __oxc_reexport(namespace, module);
+  Object.defineProperty(namespace, "default", { enumerable: true, value: module });
+  return namespace;
+}
+"#;
@@ -0,0 +1,500 @@
+//! [Source map v3](https://sourcemaps.info/spec.html)
+
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::{json, Value};
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Source map of a bundle.
+#[derive(Debug, Default, Clone)]
+pub struct SourceMap {
+    pub source_root: Option<String>,
+    pub sources: Vec<String>,
+    /// Empty if the source texts are not embedded
+    pub sources_content: Vec<String>,
+    /// Encoded mappings
+    pub mappings: String,
+}
+
+impl SourceMap {
+    pub fn to_json_string(&self) -> String {
+        let mut value = json!({
+            "version": 3,
+            "sources": self.sources,
+            "names": [],
+            "mappings": self.mappings,
+        });
+        if let Some(source_root) = &self.source_root {
+            value["sourceRoot"] = json!(source_root);
+        }
+        if !self.sources_content.is_empty() {
+            value["sourcesContent"] = json!(self.sources_content);
+        }
+        value.to_string()
+    }
+
+    /// `data:` URL of the source map, for an inline `//# sourceMappingURL=` comment.
+    pub fn to_data_url(&self) -> String {
+        format!(
+            "data:application/json;charset=utf-8;base64,{}",
+            encode_base64(self.to_json_string().as_bytes())
+        )
+    }
+
+    /// Parse a source map, e.g. the input source map of a module compiled by another tool.
+    ///
+    /// `sourceRoot` is prepended to the sources and missing `sourcesContent` are empty.
+    /// Returns `None` for invalid source maps and index maps with `sections`.
+    pub fn from_json_string(json: &str) -> Option<Self> {
+        let value = serde_json::from_str::<Value>(json).ok()?;
+        if value.get("version")?.as_u64()? != 3 {
+            return None;
+        }
+        let source_root = value.get("sourceRoot").and_then(Value::as_str).unwrap_or_default();
+        let sources = value
+            .get("sources")?
+            .as_array()?
+            .iter()
+            .map(|source| {
+                let source = source.as_str().unwrap_or_default();
+                if source_root.is_empty() || source_root.ends_with('/') {
+                    format!("{source_root}{source}")
+                } else {
+                    format!("{source_root}/{source}")
+                }
+            })
+            .collect::<Vec<_>>();
+        let sources_content = (0..sources.len())
+            .map(|i| {
+                value
+                    .get("sourcesContent")
+                    .and_then(|contents| contents.get(i))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            })
+            .collect();
+        let mappings = value.get("mappings")?.as_str()?.to_string();
+        let source_map = Self { source_root: None, sources, sources_content, mappings };
+        let segments = source_map.decode_mappings()?;
+        let source_count = source_map.sources.len();
+        if segments
+            .iter()
+            .filter_map(|segment| segment.original)
+            .any(|(source, _)| source as usize >= source_count)
+        {
+            return None;
+        }
+        Some(source_map)
+    }
+
+    /// Segments in the order of their generated position, `None` for invalid mappings.
+    fn decode_mappings(&self) -> Option<Vec<Segment>> {
+        let mut segments = vec![];
+        let mut source = 0;
+        let mut original_line = 0;
+        let mut original_column = 0;
+        for (line, segments_of_line) in self.mappings.split(';').enumerate() {
+            let generated_line = u32::try_from(line).ok()?;
+            let mut generated_column = 0;
+            for segment in segments_of_line.split(',').filter(|segment| !segment.is_empty()) {
+                let fields = decode_vlqs(segment)?;
+                generated_column = add_delta(generated_column, *fields.first()?)?;
+                let original = match fields.len() {
+                    1 => None,
+                    4 | 5 => {
+                        source = add_delta(source, fields[1])?;
+                        original_line = add_delta(original_line, fields[2])?;
+                        original_column = add_delta(original_column, fields[3])?;
+                        Some((source, (original_line, original_column)))
+                    }
+                    _ => return None,
+                };
+                segments.push(Segment { generated_line, generated_column, original });
+            }
+        }
+        segments.sort_by_key(|segment| (segment.generated_line, segment.generated_column));
+        Some(segments)
+    }
+}
+
+/// A decoded segment of the mappings of a [`SourceMap`].
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    generated_line: u32,
+    generated_column: u32,
+    /// Source and original position, `None` for generated code without a source
+    original: Option<(u32, (u32, u32))>,
+}
+
+/// Maps positions of the generated code of a [`SourceMap`] back to their original position.
+pub struct SourceMapLookup {
+    segments: Vec<Segment>,
+}
+
+impl SourceMapLookup {
+    pub fn new(source_map: &SourceMap) -> Self {
+        Self { segments: source_map.decode_mappings().unwrap_or_default() }
+    }
+
+    /// Source and original position of the segment containing the generated position,
+    /// segments end at the next segment or at the end of their line.
+    pub fn original_position(&self, (line, column): (u32, u32)) -> Option<(u32, (u32, u32))> {
+        let index = self.segments.partition_point(|segment| {
+            (segment.generated_line, segment.generated_column) <= (line, column)
+        });
+        let segment = self.segments[..index].last()?;
+        if segment.generated_line != line {
+            return None;
+        }
+        let (source, (original_line, original_column)) = segment.original?;
+        // Positions inside of the segment keep their distance to its start
+        let column = original_column + (column - segment.generated_column);
+        Some((source, (original_line, column)))
+    }
+}
+
+/// How the source map of a bundle refers to its sources.
+#[derive(Debug, Clone)]
+pub struct SourceMapOptions {
+    /// Embed the source texts in `sourcesContent`, default `true`
+    pub sources_content: bool,
+    /// `sourceRoot` of the source map, sources are resolved relative to it
+    pub source_root: Option<String>,
+    /// Make the sources relative to this directory, usually the directory of the source map
+    pub sources_relative_to: Option<PathBuf>,
+    /// Append the source map to the code as a `//# sourceMappingURL=data:` comment
+    pub inline: bool,
+}
+
+impl Default for SourceMapOptions {
+    fn default() -> Self {
+        Self { sources_content: true, source_root: None, sources_relative_to: None, inline: false }
+    }
+}
+
+impl SourceMapOptions {
+    /// Apply the options to the source map of a bundle, returning the comment to append to its
+    /// code if the source map is inlined.
+    pub(crate) fn apply(&self, source_map: &mut SourceMap) -> Option<String> {
+        if !self.sources_content {
+            source_map.sources_content.clear();
+        }
+        source_map.source_root.clone_from(&self.source_root);
+        if let Some(dir) = &self.sources_relative_to {
+            for source in &mut source_map.sources {
+                if let Some(relative) = relative_path(dir, Path::new(source)) {
+                    *source = relative;
+                }
+            }
+        }
+        self.inline.then(|| format!("//# sourceMappingURL={}\n", source_map.to_data_url()))
+    }
+}
+
+/// `path` relative to `dir` with `/` separators,
+/// `None` if one of them is absolute and the other one is not.
+fn relative_path(dir: &Path, path: &Path) -> Option<String> {
+    if dir.has_root() != path.has_root() {
+        return None;
+    }
+    let dir = normal_components(dir);
+    let path = normal_components(path);
+    let common = dir.iter().zip(&path).take_while(|(a, b)| a == b).count();
+    if dir[common..].contains(&Component::ParentDir) {
+        return None;
+    }
+    let components = std::iter::repeat("..")
+        .take(dir.len() - common)
+        .map(ToString::to_string)
+        .chain(path[common..].iter().map(|c| c.as_os_str().to_string_lossy().into_owned()))
+        .collect::<Vec<_>>();
+    Some(components.join("/"))
+}
+
+fn normal_components(path: &Path) -> Vec<Component> {
+    path.components().filter(|c| *c != Component::CurDir).collect()
+}
+
+/// A mapping from a position of the generated code to a position of a source,
+/// lines and columns are zero based, columns are counted in UTF-16 code units.
+#[derive(Debug, Clone, Copy)]
+struct Mapping {
+    generated_line: u32,
+    generated_column: u32,
+    source: u32,
+    original_line: u32,
+    original_column: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct SourceMapBuilder {
+    sources: Vec<String>,
+    sources_content: Vec<String>,
+    mappings: Vec<Mapping>,
+}
+
+impl SourceMapBuilder {
+    pub fn add_source(&mut self, name: String, content: String) -> u32 {
+        self.sources.push(name);
+        self.sources_content.push(content);
+        u32::try_from(self.sources.len() - 1).unwrap()
+    }
+
+    /// Mappings must be added in the order of their generated position.
+    pub fn add_mapping(
+        &mut self,
+        generated: (u32, u32),
+        source: u32,
+        (original_line, original_column): (u32, u32),
+    ) {
+        let (generated_line, generated_column) = generated;
+        let mapping =
+            Mapping { generated_line, generated_column, source, original_line, original_column };
+        // A later mapping of the same generated position wins
+        if let Some(last) = self.mappings.last_mut() {
+            if (last.generated_line, last.generated_column) == generated {
+                *last = mapping;
+                return;
+            }
+        }
+        self.mappings.push(mapping);
+    }
+
+    pub fn build(self) -> SourceMap {
+        let mut mappings = String::new();
+        let mut line = 0;
+        let mut prev_generated_column = 0;
+        let mut prev_source = 0;
+        let mut prev_original_line = 0;
+        let mut prev_original_column = 0;
+        for (i, mapping) in self.mappings.iter().enumerate() {
+            if mapping.generated_line != line {
+                while line < mapping.generated_line {
+                    mappings.push(';');
+                    line += 1;
+                }
+                prev_generated_column = 0;
+            } else if i > 0 {
+                mappings.push(',');
+            }
+            encode_vlq(&mut mappings, delta(mapping.generated_column, prev_generated_column));
+            encode_vlq(&mut mappings, delta(mapping.source, prev_source));
+            encode_vlq(&mut mappings, delta(mapping.original_line, prev_original_line));
+            encode_vlq(&mut mappings, delta(mapping.original_column, prev_original_column));
+            prev_generated_column = mapping.generated_column;
+            prev_source = mapping.source;
+            prev_original_line = mapping.original_line;
+            prev_original_column = mapping.original_column;
+        }
+        SourceMap {
+            source_root: None,
+            sources: self.sources,
+            sources_content: self.sources_content,
+            mappings,
+        }
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |bits, (i, byte)| bits | (u32::from(*byte) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(bits >> (18 - 6 * i)) as usize & 0b11_1111] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn delta(value: u32, prev: u32) -> i64 {
+    i64::from(value) - i64::from(prev)
+}
+
+/// <https://en.wikipedia.org/wiki/Variable-length_quantity>, with the sign in the lowest bit
+fn encode_vlq(out: &mut String, value: i64) {
+    let mut vlq = if value < 0 { ((-value) << 1) | 1 } else { value << 1 };
+    loop {
+        let mut digit = vlq & 0b1_1111;
+        vlq >>= 5;
+        if vlq > 0 {
+            // More digits follow
+            digit |= 0b10_0000;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        out.push(BASE64_CHARS[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn add_delta(value: u32, delta: i64) -> Option<u32> {
+    u32::try_from(i64::from(value) + delta).ok()
+}
+
+/// Decode the base64 VLQ fields of a segment.
+fn decode_vlqs(segment: &str) -> Option<Vec<i64>> {
+    let mut fields = vec![];
+    let mut value = 0i64;
+    let mut shift = 0;
+    for byte in segment.bytes() {
+        let digit = i64::try_from(BASE64_CHARS.iter().position(|c| *c == byte)?).ok()?;
+        if shift > 32 {
+            return None;
+        }
+        value |= (digit & 0b1_1111) << shift;
+        if digit & 0b10_0000 == 0 {
+            let magnitude = value >> 1;
+            fields.push(if value & 1 == 1 { -magnitude } else { magnitude });
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 5;
+        }
+    }
+    // A continuation bit without a following digit
+    if shift > 0 {
+        return None;
+    }
+    Some(fields)
+}
+
+/// Converts byte offsets of a source text to lines and UTF-16 columns.
+pub struct LineIndex<'a> {
+    source_text: &'a str,
+    line_starts: Vec<u32>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        for (offset, byte) in source_text.bytes().enumerate() {
+            if byte == b'\n' {
+                line_starts.push(u32::try_from(offset + 1).unwrap());
+            }
+        }
+        Self { source_text, line_starts }
+    }
+
+    pub fn line_column(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let line_start = self.line_starts[line] as usize;
+        let column = utf16_len(&self.source_text[line_start..offset as usize]);
+        (u32::try_from(line).unwrap(), column)
+    }
+}
+
+pub fn utf16_len(text: &str) -> u32 {
+    u32::try_from(text.chars().map(char::len_utf16).sum::<usize>()).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{
+        decode_vlqs, encode_base64, encode_vlq, relative_path, LineIndex, SourceMap,
+        SourceMapBuilder, SourceMapLookup,
+    };
+
+    #[test]
+    fn vlq() {
+        let encode = |value| {
+            let mut out = String::new();
+            encode_vlq(&mut out, value);
+            out
+        };
+        assert_eq!(encode(0), "A");
+        assert_eq!(encode(1), "C");
+        assert_eq!(encode(-1), "D");
+        assert_eq!(encode(15), "e");
+        assert_eq!(encode(16), "gB");
+        assert_eq!(encode(-16), "hB");
+        assert_eq!(encode(1000), "w+B");
+    }
+
+    #[test]
+    fn vlq_roundtrip() {
+        let mut out = String::new();
+        for value in [0, 1, -1, 15, 16, -16, 1000] {
+            encode_vlq(&mut out, value);
+        }
+        assert_eq!(decode_vlqs(&out), Some(vec![0, 1, -1, 15, 16, -16, 1000]));
+        assert_eq!(decode_vlqs("g"), None);
+        assert_eq!(decode_vlqs("A!"), None);
+    }
+
+    #[test]
+    fn mappings() {
+        let mut builder = SourceMapBuilder::default();
+        let a = builder.add_source("a.js".into(), String::new());
+        let b = builder.add_source("b.js".into(), String::new());
+        builder.add_mapping((0, 0), a, (0, 0));
+        builder.add_mapping((0, 4), a, (0, 4));
+        builder.add_mapping((2, 2), b, (1, 0));
+        let source_map = builder.build();
+        assert_eq!(source_map.mappings, "AAAA,IAAI;;ECCJ");
+    }
+
+    #[test]
+    fn parse_and_lookup() {
+        let json =
+            r#"{"version":3,"sourceRoot":"src","sources":["a.ts"],"mappings":"AAAA,IAAI;;ECCJ,C"}"#;
+        assert!(SourceMap::from_json_string(json).is_none(), "source 1 does not exist");
+
+        let json = r#"{"version":3,"sourceRoot":"src","sources":["a.ts","b.ts"],"sourcesContent":["let a",null],"mappings":"AAAA,IAAI;;ECCJ,C"}"#;
+        let source_map = SourceMap::from_json_string(json).unwrap();
+        assert_eq!(source_map.sources, vec!["src/a.ts", "src/b.ts"]);
+        assert_eq!(source_map.sources_content, vec!["let a", ""]);
+        let lookup = SourceMapLookup::new(&source_map);
+        assert_eq!(lookup.original_position((0, 0)), Some((0, (0, 0))));
+        assert_eq!(lookup.original_position((0, 6)), Some((0, (0, 6))));
+        assert_eq!(lookup.original_position((1, 0)), None);
+        assert_eq!(lookup.original_position((2, 2)), Some((1, (1, 0))));
+        assert_eq!(lookup.original_position((2, 3)), None);
+
+        assert!(
+            SourceMap::from_json_string(r#"{"version":2,"sources":[],"mappings":""}"#).is_none()
+        );
+        assert!(
+            SourceMap::from_json_string(r#"{"version":3,"sources":[],"mappings":"AA"}"#).is_none()
+        );
+    }
+
+    #[test]
+    fn base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"M"), "TQ==");
+        assert_eq!(encode_base64(b"Ma"), "TWE=");
+        assert_eq!(encode_base64(b"Man"), "TWFu");
+        assert_eq!(encode_base64(b"{\"a\":1}"), "eyJhIjoxfQ==");
+    }
+
+    #[test]
+    fn relative_paths() {
+        let relative = |dir: &str, path: &str| relative_path(Path::new(dir), Path::new(path));
+        assert_eq!(relative("/a/dist", "/a/src/b.js"), Some("../src/b.js".to_string()));
+        assert_eq!(relative("/a", "/a/b.js"), Some("b.js".to_string()));
+        assert_eq!(relative("dist", "./src/b.js"), Some("../src/b.js".to_string()));
+        assert_eq!(relative("/a", "b.js"), None);
+        assert_eq!(relative("../dist", "b.js"), None);
+    }
+
+    #[test]
+    fn line_index() {
+        let index = LineIndex::new("a\n€b\nc");
+        assert_eq!(index.line_column(0), (0, 0));
+        assert_eq!(index.line_column(2), (1, 0));
+        assert_eq!(index.line_column(5), (1, 1));
+        assert_eq!(index.line_column(7), (2, 0));
+    }
+}
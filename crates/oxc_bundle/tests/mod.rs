@@ -0,0 +1,286 @@
+use std::path::{Path, PathBuf};
+
+use oxc_bundle::{
+    Asset, AssetKind, BundleMode, BundleOptions, BundleOutput, Bundler, ChunkGraph, ChunkId,
+    ChunkKind, ModuleGraph, ModuleId, SourceMap, SourceMapOptions,
+};
+use oxc_span::SourceType;
+
+/// The first module is the entry.
+fn bundle(mode: BundleMode, modules: &[(&str, &str)]) -> BundleOutput {
+    try_bundle(mode, modules).unwrap_or_else(|errors| panic!("{errors:?}"))
+}
+
+fn try_bundle(
+    mode: BundleMode,
+    modules: &[(&str, &str)],
+) -> Result<BundleOutput, Vec<oxc_diagnostics::Error>> {
+    Bundler::new(BundleOptions { mode, ..BundleOptions::default() })
+        .bundle(&graph(modules), ModuleId::new(0))
+}
+
+/// `./name` resolves to the module `name.js`.
+fn graph(modules: &[(&str, &str)]) -> ModuleGraph {
+    let mut graph = ModuleGraph::default();
+    let ids = modules
+        .iter()
+        .map(|(path, source_text)| {
+            graph.add_module(PathBuf::from(path), (*source_text).to_string(), SourceType::default())
+        })
+        .collect::<Vec<_>>();
+    for from in &ids {
+        for (to, (path, _)) in ids.iter().zip(modules) {
+            let specifier = format!("./{}", path.trim_end_matches(".js"));
+            graph.add_dependency(*from, &specifier, *to);
+        }
+    }
+    graph
+}
+
+#[test]
+fn wrap() {
+    let output = bundle(
+        BundleMode::Wrap,
+        &[
+            (
+                "index.js",
+                "import { add } from './math';\nimport greet from './greet';\nconsole.log(add(1, 2), greet);\n",
+            ),
+            ("math.js", "export function add(a, b) { return a + b; }\n"),
+            ("greet.js", "module.exports = 'hello';\n"),
+        ],
+    );
+    let code = &output.code;
+    assert!(code.starts_with("(function () {\n"));
+    assert!(code.contains("__oxc_register(1, function (module, exports) {"));
+    assert!(code.contains("__oxc_export(exports, {\n  add: function () { return add; },\n});"));
+    assert!(code.contains("var __oxc_m1 = __oxc_require(1);"));
+    assert!(code.contains("var __oxc_m2 = __oxc_to_esm(__oxc_require(2));"));
+    assert!(code.contains("console.log((0, __oxc_m1.add)(1, 2), __oxc_m2.default);"));
+    assert!(code.contains("function add(a, b) { return a + b; }"));
+    assert!(!code.contains("import "));
+    assert!(code.ends_with("__oxc_require(0);\n})();\n"));
+}
+
+#[test]
+fn concatenate() {
+    let output = bundle(
+        BundleMode::Concatenate,
+        &[
+            (
+                "index.js",
+                "import f, { value } from './a';\nimport { other } from './b';\nconsole.log(f(), value, other, { value });\n",
+            ),
+            ("a.js", "export const value = 1;\nexport default function () { return value; }\n"),
+            ("b.js", "const value = 2;\nexport { value as other };\n"),
+        ],
+    );
+    let code = &output.code;
+    // Nothing is wrapped, the runtime is not needed
+    assert!(!code.contains("__oxc_register"));
+    assert!(code.contains("const value = 1;\nvar _default = function () { return value; };\n"));
+    assert!(code.contains("const value$1 = 2;\n"));
+    assert!(code.contains("console.log(_default(), value, value$1, { value: value });"));
+    // Dependencies are evaluated first
+    assert!(code.find("// a.js").unwrap() < code.find("// b.js").unwrap());
+    assert!(code.find("// b.js").unwrap() < code.find("// index.js").unwrap());
+}
+
+#[test]
+fn concatenate_namespace_and_commonjs() {
+    let output = bundle(
+        BundleMode::Concatenate,
+        &[
+            (
+                "index.js",
+                "import * as ns from './a';\nimport lib from './lib';\nconsole.log(ns.value, lib);\n",
+            ),
+            ("a.js", "export let value = 1;\n"),
+            ("lib.js", "module.exports = require('./a');\n"),
+        ],
+    );
+    let code = &output.code;
+    assert!(code.contains("__oxc_register(1, function (module, exports) {"));
+    assert!(code.contains("__oxc_register(2, function (module, exports) {"));
+    assert!(code.contains("module.exports = __oxc_require(1);"));
+    // `a.js` is required by a wrapped module, so it is wrapped as well
+    assert!(code.contains("var __oxc_m1 = __oxc_require(1);"));
+    assert!(code.contains("var __oxc_m2 = __oxc_to_esm(__oxc_require(2));"));
+    assert!(code.contains("console.log(__oxc_m1.value, __oxc_m2.default);"));
+}
+
+#[test]
+fn dynamic_import() {
+    let output = bundle(
+        BundleMode::Concatenate,
+        &[
+            ("index.js", "import('./worker').then(console.log);\n"),
+            ("worker.js", "export default 1;\n"),
+        ],
+    );
+    let code = &output.code;
+    assert!(code.contains(
+        "Promise.resolve().then(function () { return __oxc_require(1); }).then(console.log);"
+    ));
+    assert!(code.contains("var _default = 1;"));
+}
+
+#[test]
+fn errors() {
+    let errors =
+        try_bundle(BundleMode::Wrap, &[("index.js", "import './missing';\n")]).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("Could not resolve `./missing`"));
+
+    let errors = try_bundle(
+        BundleMode::Concatenate,
+        &[("index.js", "import { missing } from './a';\n"), ("a.js", "export const a = 1;\n")],
+    )
+    .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("`missing` is not exported by `a.js`"));
+
+    let errors = try_bundle(BundleMode::Wrap, &[("index.js", "let x = ;")]).unwrap_err();
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn source_map() {
+    let output = bundle(
+        BundleMode::Wrap,
+        &[
+            ("index.js", "import { a } from './a';\nconsole.log(a);\n"),
+            ("a.js", "export const a = 1;\n"),
+        ],
+    );
+    let source_map = &output.source_map;
+    assert_eq!(source_map.sources, vec!["index.js", "a.js"]);
+    assert_eq!(source_map.sources_content[1], "export const a = 1;\n");
+    assert!(!source_map.mappings.is_empty());
+    assert!(source_map.to_json_string().contains(r#""version":3"#));
+}
+
+#[test]
+fn source_map_options() {
+    let graph = graph(&[("/project/src/index.js", "console.log(1);\n")]);
+    let source_map = SourceMapOptions {
+        sources_content: false,
+        source_root: Some("/root".to_string()),
+        sources_relative_to: Some(PathBuf::from("/project/dist")),
+        inline: true,
+    };
+    let options = BundleOptions { source_map, ..BundleOptions::default() };
+    let output = Bundler::new(options).bundle(&graph, ModuleId::new(0)).unwrap();
+    let source_map = &output.source_map;
+    assert_eq!(source_map.sources, vec!["../src/index.js"]);
+    assert!(source_map.sources_content.is_empty());
+    assert!(!source_map.to_json_string().contains("sourcesContent"));
+    assert!(source_map.to_json_string().contains(r#""sourceRoot":"/root""#));
+    let comment = format!("//# sourceMappingURL={}\n", source_map.to_data_url());
+    assert!(output.code.ends_with(&comment));
+}
+
+#[test]
+fn input_source_map() {
+    let mut graph = graph(&[
+        ("index.js", "import { a } from './a';\nconsole.log(a);\n"),
+        ("a.js", "export const a = 1;\n"),
+    ]);
+    let json = r#"{"version":3,"sources":["a.svelte"],"sourcesContent":["<script>\n\nexport const a = 1;\n</script>"],"mappings":"AAEA"}"#;
+    graph.set_input_source_map(ModuleId::new(1), SourceMap::from_json_string(json).unwrap());
+    let output = Bundler::new(BundleOptions::default()).bundle(&graph, ModuleId::new(0)).unwrap();
+    let source_map = &output.source_map;
+    assert_eq!(source_map.sources, vec!["index.js", "a.svelte"]);
+    assert!(source_map.sources_content[1].starts_with("<script>"));
+}
+
+#[test]
+fn chunks() {
+    let graph = graph(&[
+        ("a.js", "import './shared';\nimport('./lazy');\n"),
+        ("b.js", "import './shared';\n"),
+        ("shared.js", "export const x = 1;\n"),
+        ("lazy.js", "import './shared';\nimport './util';\n"),
+        ("util.js", "export {};\n"),
+    ]);
+    let [a, b, shared, lazy, util] = [0, 1, 2, 3, 4].map(ModuleId::new);
+    let chunk_graph = ChunkGraph::build(&graph, &[a, b]).unwrap();
+    assert_eq!(chunk_graph.chunks().count(), 4);
+
+    let chunk = chunk_graph.chunk(ChunkId::new(0));
+    assert_eq!(chunk.kind, ChunkKind::Entry);
+    assert_eq!(chunk.entry, Some(a));
+    assert_eq!(chunk.modules, vec![a]);
+    assert_eq!(chunk.imports, vec![ChunkId::new(3)]);
+    assert_eq!(chunk.dynamic_imports, vec![ChunkId::new(2)]);
+
+    let chunk = chunk_graph.chunk(ChunkId::new(1));
+    assert_eq!(chunk.kind, ChunkKind::Entry);
+    assert_eq!(chunk.modules, vec![b]);
+    assert_eq!(chunk.imports, vec![ChunkId::new(3)]);
+
+    let chunk = chunk_graph.chunk(ChunkId::new(2));
+    assert_eq!(chunk.kind, ChunkKind::DynamicEntry);
+    assert_eq!(chunk.entry, Some(lazy));
+    assert_eq!(chunk.modules, vec![util, lazy]);
+    assert_eq!(chunk.imports, vec![ChunkId::new(3)]);
+
+    let chunk = chunk_graph.chunk(ChunkId::new(3));
+    assert_eq!(chunk.kind, ChunkKind::Shared);
+    assert_eq!(chunk.entry, None);
+    assert_eq!(chunk.modules, vec![shared]);
+    assert!(chunk.imports.is_empty());
+
+    assert_eq!(chunk_graph.chunk_of(shared), Some(ChunkId::new(3)));
+
+    // An entry imported by another entry is moved to a shared chunk
+    let chunk_graph = ChunkGraph::build(&graph, &[a, shared]).unwrap();
+    let chunk = chunk_graph.chunk(ChunkId::new(1));
+    assert_eq!(chunk.entry, Some(shared));
+    assert!(chunk.modules.is_empty());
+    assert_eq!(chunk.imports, vec![chunk_graph.chunk_of(shared).unwrap()]);
+}
+
+#[test]
+fn assets() {
+    let mut graph = graph(&[
+        (
+            "index.js",
+            "import data from './data.json' with { type: 'json' };\nimport('./lazy');\nconsole.log(data);\n",
+        ),
+        ("lazy.js", "import data from './data.json' with { type: 'json' };\nrequire('./logo.png');\n"),
+    ]);
+    graph.add_assets(|request| {
+        let kind = match request.attributes {
+            [(key, value)] if key == "type" => AssetKind::from_type_attribute(value)?,
+            _ if Path::new(request.specifier).extension().is_some_and(|ext| ext == "png") => {
+                AssetKind::Url
+            }
+            _ => return None,
+        };
+        Some((PathBuf::from(request.specifier.trim_start_matches("./")), kind))
+    });
+    let [index, lazy, data, logo] = [0, 1, 2, 3].map(ModuleId::new);
+    assert_eq!(graph.len(), 4);
+    assert_eq!(graph.resolve(index, "./data.json"), Some(data));
+    assert_eq!(graph.resolve(lazy, "./data.json"), Some(data));
+    assert_eq!(
+        graph.module(data).asset,
+        Some(Asset {
+            kind: AssetKind::Json,
+            attributes: vec![("type".to_string(), "json".to_string())]
+        })
+    );
+    assert_eq!(graph.module(logo).asset, Some(Asset { kind: AssetKind::Url, attributes: vec![] }));
+
+    // Assets are leaves of the chunks
+    let chunk_graph = ChunkGraph::build(&graph, &[index]).unwrap();
+    assert_eq!(chunk_graph.chunk(ChunkId::new(0)).modules, vec![index]);
+    assert_eq!(chunk_graph.chunk(ChunkId::new(1)).modules, vec![logo, lazy]);
+    assert_eq!(chunk_graph.chunk_of(data), Some(ChunkId::new(2)));
+
+    let errors = Bundler::new(BundleOptions::default()).bundle(&graph, index).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(errors[0].to_string().contains("Cannot bundle the asset `data.json`"));
+    assert!(errors[2].to_string().contains("Cannot bundle the asset `logo.png`"));
+}
@@ -0,0 +1,22 @@
+import { Point } from "./point";
+
+export * from "./types";
+export * from "some-package";
+export * as shapes from "./shapes";
+export { Point };
+
+export const VERSION: string = "1.0.0";
+
+export interface Options {
+  verbose?: boolean;
+}
+
+export function format(value: string): string;
+export function format(value: number): string;
+export function format(value: string | number): string {
+  return String(value);
+}
+
+export default function main(options?: Options): void {
+  console.log(options);
+}
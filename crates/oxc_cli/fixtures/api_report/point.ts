@@ -0,0 +1,13 @@
+export class Point {
+  x: number;
+  #id = 0;
+  private cache?: number;
+
+  constructor(x: number) {
+    this.x = x;
+  }
+
+  distance(other: Point): number {
+    return Math.abs(this.x - other.x);
+  }
+}
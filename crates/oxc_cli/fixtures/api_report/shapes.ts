@@ -0,0 +1,3 @@
+import type { Shape } from "./types";
+
+export const area = (shape: Shape, size: number): number => size * size;
@@ -0,0 +1 @@
+export type Shape = "circle" | "square";
@@ -0,0 +1,24 @@
+function total(items) {
+  let sum = 0;
+  for (const item of items) {
+    sum += item.price * item.quantity;
+  }
+  return sum;
+}
+
+export function first(rows) {
+  const header = rows[0];
+  if (!header) {
+    throw new Error("missing header");
+  }
+  console.log(header.name);
+  return header;
+}
+export function second(lines) {
+  const head = lines[0];
+  if (!head) {
+    throw new Error("missing head");
+  }
+  console.log(head.name);
+  return head.id;
+}
@@ -0,0 +1,11 @@
+import { format } from "./format";
+
+function sum(values) {
+  let result = 0;
+  for (const value of values) {
+    result += value.price * value.quantity;
+  }
+  return result;
+}
+
+export const formatted = format(sum([]));
@@ -0,0 +1,2 @@
+import { e } from "./e";
+export const d = () => e;
@@ -0,0 +1,2 @@
+var app = <div />;
+return app;
@@ -0,0 +1 @@
+fetch(import.meta.env.PUBLIC_API, { headers: { key: process.env.SECRET } });
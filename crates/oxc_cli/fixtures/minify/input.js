@@ -0,0 +1,5 @@
+if (true) {
+  console.log(typeof foo == "undefined");
+}
+var a = 1;
+var b = 2;
@@ -0,0 +1,8 @@
+// @ts-expect-error: untyped module
+import legacy from "legacy";
+
+// @ts-ignore
+
+const a: string = legacy;
+/* @ts-expect-error - wrong type */
+legacy(a);
@@ -0,0 +1,5 @@
+import { used } from "./lib";
+import * as all from "./all";
+import def from "./def";
+
+export const main = [used, all, def];
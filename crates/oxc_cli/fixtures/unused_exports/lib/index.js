@@ -0,0 +1,2 @@
+export * from "./a";
+export { b } from "./b";
@@ -0,0 +1,442 @@
+mod signature;
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+
+use serde_json::{json, Value};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_diagnostics::DiagnosticService;
+use oxc_linter::{AllowWarnDeny, LintOptions, LintService, Linter};
+use oxc_parser::Parser;
+use oxc_semantic::ModuleRecord;
+use oxc_span::SourceType;
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ResolvedExport,
+};
+
+use self::signature::{signatures, type_export_names, Signature};
+use crate::{
+    command::{ApiReportOptions, OutputFormat},
+    file_options::FileOptionsResolver,
+    CliRunResult, Runner,
+};
+
+pub struct ApiReportRunner {
+    options: ApiReportOptions,
+}
+
+/// A declaration of an export, or of a public member of an exported class
+struct ApiItem {
+    /// The export name, with the member or the namespace export appended,
+    /// e.g. `Point.x` or `ns.add`
+    name: String,
+    declaration: String,
+    /// The declaring module
+    module: PathBuf,
+}
+
+impl Runner for ApiReportRunner {
+    type Options = ApiReportOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let ApiReportOptions { output_options, paths, .. } = self.options;
+
+        if paths.is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: "No entry point is given.".to_string(),
+            };
+        }
+        let missing = paths.iter().filter(|path| !path.is_file()).cloned().collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return CliRunResult::PathNotFound { paths: missing };
+        }
+
+        let now = Instant::now();
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        let entries = paths.into_iter().map(PathBuf::into_boxed_path).collect::<Vec<_>>();
+
+        // Only the module graph is needed, no rule is run.
+        let lint_options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Allow, "all".to_string())])
+            .with_import_plugin(true);
+        let Ok(linter) = Linter::from_options(lint_options) else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to create the linter.".to_string(),
+            };
+        };
+        let lint_service = LintService::new(cwd.clone().into_boxed_path(), &entries, linter)
+            .with_parse_options_provider(Box::new(FileOptionsResolver::new(cwd.clone())));
+
+        // Syntax errors are reported like lint diagnostics, the entry is left out of the report.
+        let mut diagnostic_service = DiagnosticService::default();
+        if output_options.format == OutputFormat::Json {
+            diagnostic_service.set_json_reporter();
+        }
+        rayon::spawn({
+            let tx_error = diagnostic_service.sender().clone();
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run(&tx_error);
+            }
+        });
+        diagnostic_service.run();
+
+        let allocator = Allocator::default();
+        let mut report = ApiReport::new(&allocator);
+        let mut number_of_exports = 0;
+        let mut output = String::new();
+        let mut json_entries = vec![];
+        for entry in &entries {
+            let Some(module) = lint_service.module_record(entry) else { continue };
+            let exports = report.exports(&module, &mut vec![]);
+            number_of_exports += exports.len();
+            let items = exports.into_values().flatten().collect::<Vec<_>>();
+            match output_options.format {
+                OutputFormat::Default => {
+                    output.push_str(&format!("{}\n", relative_path(&cwd, entry)));
+                    for item in &items {
+                        output.push_str(&format!("  {}: {}\n", item.name, item.declaration));
+                    }
+                }
+                OutputFormat::Json => {
+                    let exports = items
+                        .iter()
+                        .map(|item| {
+                            json!({
+                                "name": item.name,
+                                "declaration": item.declaration,
+                                "module": relative_path(&cwd, &item.module),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    json_entries
+                        .push(json!({ "entry": relative_path(&cwd, entry), "exports": exports }));
+                }
+            }
+        }
+        if output_options.format == OutputFormat::Json {
+            output = format!("{}\n", Value::Array(json_entries));
+        }
+
+        CliRunResult::ApiReportResult { duration: now.elapsed(), number_of_exports, output }
+    }
+}
+
+/// Resolves exports to their declarations, parsing each declaring module once
+struct ApiReport<'a> {
+    allocator: &'a Allocator,
+    /// `None` for modules which fail to be read
+    programs: HashMap<PathBuf, Option<(&'a str, &'a Program<'a>)>>,
+}
+
+impl<'a> ApiReport<'a> {
+    fn new(allocator: &'a Allocator) -> Self {
+        Self { allocator, programs: HashMap::new() }
+    }
+
+    /// The declarations of each export of `module`, sorted by export name.
+    ///
+    /// `stack` holds the exports being resolved, to stop at circular re-exports.
+    fn exports(
+        &mut self,
+        module: &Arc<ModuleRecord>,
+        stack: &mut Vec<(PathBuf, String)>,
+    ) -> BTreeMap<String, Vec<ApiItem>> {
+        let mut names = vec![];
+        let mut types = vec![];
+        let mut unloaded = vec![];
+        self.export_names(module, true, &mut names, &mut types, &mut unloaded, &mut HashSet::new());
+
+        let mut exports = BTreeMap::new();
+        for name in names {
+            if exports.contains_key(&name) {
+                continue;
+            }
+            let mut items = vec![];
+            self.export_items(module, &name, &name, &mut items, stack);
+            exports.insert(name, items);
+        }
+        // Types of star exported modules are not known to the module record
+        for (name, path) in types {
+            if exports.contains_key(&name) {
+                continue;
+            }
+            let mut items = vec![];
+            self.declaration_items(&path, Some(&name), &name, &mut items);
+            exports.insert(name, items);
+        }
+        for specifier in unloaded {
+            let item = ApiItem {
+                name: "*".to_string(),
+                declaration: format!("export * from \"{specifier}\""),
+                module: module.resolved_absolute_path.clone(),
+            };
+            exports.entry("*".to_string()).or_default().push(item);
+        }
+        exports
+    }
+
+    /// Names exported by `module`, including the star exports of its dependencies.
+    ///
+    /// Types are listed with their declaring module in `types`, the specifiers of star exports
+    /// of modules which are not loaded, e.g. packages, in `unloaded`.
+    fn export_names(
+        &mut self,
+        module: &Arc<ModuleRecord>,
+        include_default: bool,
+        names: &mut Vec<String>,
+        types: &mut Vec<(String, PathBuf)>,
+        unloaded: &mut Vec<String>,
+        visited: &mut HashSet<PathBuf>,
+    ) {
+        let path = &module.resolved_absolute_path;
+        if !visited.insert(path.clone()) {
+            return;
+        }
+        for entry in module.local_export_entries.iter().chain(&module.indirect_export_entries) {
+            match &entry.export_name {
+                ExportExportName::Name(name) => names.push(name.name().to_string()),
+                ExportExportName::Default(_) if include_default => names.push("default".into()),
+                _ => {}
+            }
+        }
+        if let Some((_, program)) = self.program(path) {
+            types.extend(type_export_names(program).into_iter().map(|name| (name, path.clone())));
+        }
+        // `export *` does not re-export the default export
+        for entry in &module.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let loaded_module =
+                module.loaded_modules.get(request.name()).map(|module| Arc::clone(module.value()));
+            match loaded_module {
+                Some(loaded_module) => {
+                    self.export_names(&loaded_module, false, names, types, unloaded, visited);
+                }
+                None => unloaded.push(request.name().to_string()),
+            }
+        }
+    }
+
+    /// Declarations of the export `export_name` of `module`, listed as `name`
+    fn export_items(
+        &mut self,
+        module: &Arc<ModuleRecord>,
+        export_name: &str,
+        name: &str,
+        items: &mut Vec<ApiItem>,
+        stack: &mut Vec<(PathBuf, String)>,
+    ) {
+        let key = (module.resolved_absolute_path.clone(), export_name.to_string());
+        if stack.contains(&key) {
+            return;
+        }
+        stack.push(key);
+        match ModuleRecord::resolve_export(module, export_name) {
+            Some(ResolvedExport::Binding { module, name: export_name }) => {
+                self.binding_items(&module, export_name.as_str(), name, items, stack);
+            }
+            Some(ResolvedExport::Namespace(namespace)) => {
+                items.push(ApiItem {
+                    name: name.to_string(),
+                    declaration: format!("* as {name}"),
+                    module: namespace.resolved_absolute_path.clone(),
+                });
+                for item in self.exports(&namespace, stack).into_values().flatten() {
+                    items.push(ApiItem { name: format!("{name}.{}", item.name), ..item });
+                }
+            }
+            None => items.push(ApiItem {
+                name: name.to_string(),
+                declaration: Self::unresolved_declaration(module, export_name),
+                module: module.resolved_absolute_path.clone(),
+            }),
+        }
+        stack.pop();
+    }
+
+    /// Declarations of the export `export_name` of a binding declared or imported by `module`
+    fn binding_items(
+        &mut self,
+        module: &Arc<ModuleRecord>,
+        export_name: &str,
+        name: &str,
+        items: &mut Vec<ApiItem>,
+        stack: &mut Vec<(PathBuf, String)>,
+    ) {
+        let local_name = module
+            .local_export_entries
+            .iter()
+            .find(|entry| entry.export_name.matches(export_name))
+            .and_then(|entry| match &entry.local_name {
+                ExportLocalName::Name(local_name) => Some(local_name.name().to_string()),
+                ExportLocalName::Default(_) | ExportLocalName::Null => None,
+            });
+
+        // `import { a } from "./a"; export { a };`
+        let import_entry = local_name.as_ref().and_then(|local_name| {
+            module.import_entries.iter().find(|entry| entry.local_name.name() == local_name)
+        });
+        if let Some(import_entry) = import_entry {
+            let import_name = match &import_entry.import_name {
+                ImportImportName::Name(import_name) => import_name.name().to_string(),
+                ImportImportName::Default(_) => "default".to_string(),
+                // Resolved to the namespace already
+                ImportImportName::NamespaceObject => return,
+            };
+            let specifier = import_entry.module_request.name();
+            let loaded_module =
+                module.loaded_modules.get(specifier).map(|module| Arc::clone(module.value()));
+            match loaded_module {
+                Some(loaded_module) => {
+                    self.export_items(&loaded_module, &import_name, name, items, stack);
+                }
+                None => items.push(ApiItem {
+                    name: name.to_string(),
+                    declaration: format!("import {{ {import_name} }} from \"{specifier}\""),
+                    module: module.resolved_absolute_path.clone(),
+                }),
+            }
+            return;
+        }
+
+        self.declaration_items(&module.resolved_absolute_path, local_name.as_deref(), name, items);
+    }
+
+    /// Declarations of `local_name` in the module at `path`, or of its default export
+    fn declaration_items(
+        &mut self,
+        path: &Path,
+        local_name: Option<&str>,
+        name: &str,
+        items: &mut Vec<ApiItem>,
+    ) {
+        let signatures = self
+            .program(path)
+            .map(|(source_text, program)| signatures(program, source_text, local_name))
+            .unwrap_or_default();
+        if signatures.is_empty() {
+            items.push(ApiItem {
+                name: name.to_string(),
+                declaration: "unknown".to_string(),
+                module: path.to_path_buf(),
+            });
+        }
+        for Signature { member, text } in signatures {
+            items.push(ApiItem {
+                name: member.map_or_else(|| name.to_string(), |member| format!("{name}.{member}")),
+                declaration: text,
+                module: path.to_path_buf(),
+            });
+        }
+    }
+
+    /// Re-exports of modules which are not loaded, e.g. packages
+    fn unresolved_declaration(module: &ModuleRecord, export_name: &str) -> String {
+        let Some((import_name, specifier)) =
+            module.indirect_export_entries.iter().find_map(|entry| {
+                let request = entry.module_request.as_ref()?;
+                entry.export_name.matches(export_name).then_some((&entry.import_name, request))
+            })
+        else {
+            return "unknown".to_string();
+        };
+        let specifier = specifier.name();
+        match import_name {
+            ExportImportName::Name(import_name) if import_name.name() == export_name => {
+                format!("export {{ {export_name} }} from \"{specifier}\"")
+            }
+            ExportImportName::Name(import_name) => {
+                format!("export {{ {} as {export_name} }} from \"{specifier}\"", import_name.name())
+            }
+            _ => format!("export * as {export_name} from \"{specifier}\""),
+        }
+    }
+
+    fn program(&mut self, path: &Path) -> Option<(&'a str, &'a Program<'a>)> {
+        let allocator = self.allocator;
+        *self.programs.entry(path.to_path_buf()).or_insert_with(|| {
+            let source_type = SourceType::from_path(path).ok()?;
+            let source_text = fs::read_to_string(path).ok()?;
+            let source_text: &'a str = allocator.alloc_str(&source_text);
+            let ret = Parser::new(allocator, source_text, source_type).parse();
+            let program: &'a Program<'a> = allocator.alloc(ret.program);
+            Some((source_text, program))
+        })
+    }
+}
+
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod test {
+    use super::ApiReportRunner;
+    use crate::{cli_command, CliCommand, CliRunResult, Runner};
+
+    fn test(args: &[&str]) -> (usize, String) {
+        let CliCommand::ApiReport(options) = cli_command().run_inner(args).unwrap() else {
+            unreachable!()
+        };
+        match ApiReportRunner::new(options).run() {
+            CliRunResult::ApiReportResult { number_of_exports, output, .. } => {
+                (number_of_exports, output)
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn api_report() {
+        let (number_of_exports, output) = test(&["api-report", "fixtures/api_report/index.ts"]);
+        assert_eq!(number_of_exports, 8);
+        assert_eq!(
+            output,
+            r#"fixtures/api_report/index.ts
+  *: export * from "some-package"
+  Options: interface Options { verbose?: boolean; }
+  Point: class Point
+  Point.x: x: number
+  Point.constructor: constructor(x: number)
+  Point.distance: distance(other: Point): number
+  Shape: type Shape = "circle" | "square"
+  VERSION: const VERSION: string
+  default: function main(options?: Options): void
+  format: function format(value: string): string;
+  format: function format(value: number): string;
+  shapes: * as shapes
+  shapes.area: const area = (shape: Shape, size: number): number =>
+"#
+        );
+    }
+
+    #[test]
+    fn api_report_json() {
+        let (_, output) = test(&["api-report", "-f", "fixtures/api_report/index.ts"]);
+        let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(report[0]["entry"], "fixtures/api_report/index.ts");
+        assert_eq!(
+            report[0]["exports"][2],
+            serde_json::json!({
+                "name": "Point",
+                "declaration": "class Point",
+                "module": "fixtures/api_report/point.ts",
+            })
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use oxc_ast::{
+    ast::{
+        BindingIdentifier, Class, ClassElement, Declaration, ExportDefaultDeclarationKind,
+        Expression, Function, ModuleDeclaration, Program, Statement, TSAccessibility,
+        VariableDeclaration,
+    },
+    syntax_directed_operations::BoundNames,
+};
+use oxc_span::{GetSpan, Span};
+
+/// The declaration text of an exported binding, or of a public member of an exported class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    /// The member name, e.g. `foo` of `class A { foo(): void {} }`
+    pub member: Option<String>,
+    pub text: String,
+}
+
+/// Signatures of the top-level declarations of `name` in `program`,
+/// or of the default export with `name = None`.
+///
+/// Bodies and initializers are left out, the rest of the declaration text is kept with
+/// whitespace collapsed, e.g. `function f(a: string): number` of
+/// `function f(a: string): number { return 1 }`.
+/// Types, interfaces, enums and namespaces are kept as a whole.
+pub fn signatures(program: &Program, source_text: &str, name: Option<&str>) -> Vec<Signature> {
+    let mut signatures = vec![];
+    for stmt in &program.body {
+        match (stmt, name) {
+            (Statement::Declaration(decl), Some(name)) => {
+                declaration_signatures(decl, source_text, name, &mut signatures);
+            }
+            (Statement::ModuleDeclaration(decl), _) => match (&**decl, name) {
+                (ModuleDeclaration::ExportNamedDeclaration(decl), Some(name)) => {
+                    if let Some(decl) = &decl.declaration {
+                        declaration_signatures(decl, source_text, name, &mut signatures);
+                    }
+                }
+                (ModuleDeclaration::ExportDefaultDeclaration(decl), _) => {
+                    default_signatures(&decl.declaration, source_text, name, &mut signatures);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    signatures
+}
+
+/// Names of the types, interfaces and ambient declarations exported by `program`,
+/// which are left out of its module record.
+/// Type-only re-exports, e.g. `export type { A } from "./a"`, are not followed.
+pub fn type_export_names(program: &Program) -> Vec<String> {
+    let mut names = vec![];
+    for stmt in &program.body {
+        let Statement::ModuleDeclaration(decl) = stmt else { continue };
+        let ModuleDeclaration::ExportNamedDeclaration(decl) = &**decl else { continue };
+        let Some(decl) = decl.declaration.as_ref().filter(|decl| decl.is_typescript_syntax())
+        else {
+            continue;
+        };
+        let name = match decl {
+            Declaration::VariableDeclaration(decl) => {
+                decl.bound_names(&mut |id| names.push(id.name.to_string()));
+                continue;
+            }
+            Declaration::UsingDeclaration(_) => continue,
+            Declaration::FunctionDeclaration(func) => func.id.as_ref().map(|id| &id.name),
+            Declaration::ClassDeclaration(class) => class.id.as_ref().map(|id| &id.name),
+            Declaration::TSTypeAliasDeclaration(decl) => Some(&decl.id.name),
+            Declaration::TSInterfaceDeclaration(decl) => Some(&decl.id.name),
+            Declaration::TSEnumDeclaration(decl) => Some(&decl.id.name),
+            Declaration::TSModuleDeclaration(decl) => Some(decl.id.name()),
+            Declaration::TSImportEqualsDeclaration(decl) => Some(&decl.id.name),
+        };
+        names.extend(name.map(ToString::to_string));
+    }
+    names
+}
+
+fn declaration_signatures(
+    decl: &Declaration,
+    source_text: &str,
+    name: &str,
+    signatures: &mut Vec<Signature>,
+) {
+    let text = |span: Span| {
+        let text = normalize(source_text, span);
+        Signature { member: None, text: text.trim_end_matches(';').to_string() }
+    };
+    match decl {
+        Declaration::FunctionDeclaration(func) if is_named(func.id.as_ref(), name) => {
+            function_signatures(func, source_text, signatures);
+        }
+        Declaration::ClassDeclaration(class) if is_named(class.id.as_ref(), name) => {
+            class_signatures(class, source_text, signatures);
+        }
+        Declaration::VariableDeclaration(decl) => {
+            variable_signatures(decl, source_text, name, signatures);
+        }
+        Declaration::TSTypeAliasDeclaration(decl) if decl.id.name == name => {
+            signatures.push(text(decl.span));
+        }
+        Declaration::TSInterfaceDeclaration(decl) if decl.id.name == name => {
+            signatures.push(text(decl.span));
+        }
+        Declaration::TSEnumDeclaration(decl) if decl.id.name == name => {
+            signatures.push(text(decl.span));
+        }
+        Declaration::TSModuleDeclaration(decl) if decl.id.name() == name => {
+            signatures.push(text(decl.span));
+        }
+        Declaration::TSImportEqualsDeclaration(decl) if decl.id.name == name => {
+            signatures.push(text(decl.span));
+        }
+        _ => {}
+    }
+}
+
+/// `export default function f() {}` is also found by its name
+fn default_signatures(
+    decl: &ExportDefaultDeclarationKind,
+    source_text: &str,
+    name: Option<&str>,
+    signatures: &mut Vec<Signature>,
+) {
+    let is_default = |id: Option<&BindingIdentifier>| name.map_or(true, |name| is_named(id, name));
+    match decl {
+        ExportDefaultDeclarationKind::FunctionDeclaration(func) if is_default(func.id.as_ref()) => {
+            function_signatures(func, source_text, signatures);
+        }
+        ExportDefaultDeclarationKind::ClassDeclaration(class) if is_default(class.id.as_ref()) => {
+            class_signatures(class, source_text, signatures);
+        }
+        ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl)
+            if name.map_or(true, |name| decl.id.name == name) =>
+        {
+            signatures.push(Signature { member: None, text: normalize(source_text, decl.span) });
+        }
+        ExportDefaultDeclarationKind::TSEnumDeclaration(decl)
+            if name.map_or(true, |name| decl.id.name == name) =>
+        {
+            signatures.push(Signature { member: None, text: normalize(source_text, decl.span) });
+        }
+        ExportDefaultDeclarationKind::Expression(expr) if name.is_none() => {
+            let text = expression_signature(expr, source_text)
+                .unwrap_or_else(|| normalize(source_text, expr.span()));
+            signatures.push(Signature { member: None, text });
+        }
+        _ => {}
+    }
+}
+
+fn is_named(id: Option<&BindingIdentifier>, name: &str) -> bool {
+    id.is_some_and(|id| id.name == name)
+}
+
+/// Overload signatures without the implementation, which is not callable from outside
+fn function_signatures(func: &Function, source_text: &str, signatures: &mut Vec<Signature>) {
+    let text = normalize(source_text, func.signature_span());
+    if func.body.is_none() {
+        signatures.push(Signature { member: None, text: format!("{text};") });
+    } else if !signatures.iter().any(|signature| signature.text.ends_with(';')) {
+        signatures.push(Signature { member: None, text });
+    }
+}
+
+fn class_signatures(class: &Class, source_text: &str, signatures: &mut Vec<Signature>) {
+    let start = class.span_without_decorators(source_text).start;
+    let header = normalize(source_text, Span::new(start, class.body.span.start));
+    signatures.push(Signature { member: None, text: header });
+
+    let mut members: Vec<Signature> = vec![];
+    for element in &class.body.body {
+        let (key, span, accessibility) = match element {
+            ClassElement::MethodDefinition(method) => {
+                let start = method.span_without_decorators(source_text).start;
+                let end = method.value.signature_span().end;
+                (&method.key, Span::new(start, end), method.accessibility)
+            }
+            ClassElement::TSAbstractMethodDefinition(def) => {
+                let method = &def.method_definition;
+                let end = method.value.signature_span().end;
+                (&method.key, Span::new(method.span.start, end), method.accessibility)
+            }
+            ClassElement::PropertyDefinition(property) => {
+                let span = property.span_without_decorators(source_text);
+                let end = property.value.as_ref().map_or(span.end, |value| value.span().start);
+                (&property.key, Span::new(span.start, end), property.accessibility)
+            }
+            ClassElement::TSAbstractPropertyDefinition(def) => {
+                let property = &def.property_definition;
+                (&property.key, property.span, property.accessibility)
+            }
+            ClassElement::AccessorProperty(property) => {
+                let span = property.span_without_decorators(source_text);
+                let end = property.value.as_ref().map_or(span.end, |value| value.span().start);
+                (&property.key, Span::new(span.start, end), None)
+            }
+            ClassElement::TSIndexSignature(signature) => {
+                let text = normalize(source_text, signature.span);
+                members.push(Signature { member: Some("[index]".to_string()), text });
+                continue;
+            }
+            ClassElement::StaticBlock(_) => continue,
+        };
+        if key.is_private_identifier() || accessibility == Some(TSAccessibility::Private) {
+            continue;
+        }
+        let member = key.span().source_text(source_text).to_string();
+        let text = normalize(source_text, span);
+        let text = text.trim_end_matches([';', '=', ' ']).to_string();
+        // Overloads without the implementation
+        let is_implementation = matches!(element, ClassElement::MethodDefinition(method) if method.value.body.is_some());
+        let is_overloaded = members.iter().any(|m| m.member.as_ref() == Some(&member));
+        if is_implementation && is_overloaded {
+            continue;
+        }
+        members.push(Signature { member: Some(member), text });
+    }
+    signatures.extend(members);
+}
+
+fn variable_signatures(
+    decl: &VariableDeclaration,
+    source_text: &str,
+    name: &str,
+    signatures: &mut Vec<Signature>,
+) {
+    for declarator in &decl.declarations {
+        let mut is_declared = false;
+        declarator.id.bound_names(&mut |id| is_declared |= id.name == name);
+        if !is_declared {
+            continue;
+        }
+        let kind = declarator.kind.as_str();
+        let Some(init) = &declarator.init else {
+            let text = normalize(source_text, declarator.span);
+            signatures.push(Signature { member: None, text: format!("{kind} {text}") });
+            continue;
+        };
+        let id = normalize(source_text, Span::new(declarator.span.start, init.span().start));
+        let id = id.trim_end_matches([' ', '=']);
+        // The type of an unannotated function is its signature
+        let text = match expression_signature(init, source_text) {
+            Some(signature) if declarator.id.type_annotation.is_none() => {
+                format!("{kind} {id} = {signature}")
+            }
+            _ => format!("{kind} {id}"),
+        };
+        signatures.push(Signature { member: None, text });
+    }
+}
+
+/// The signature of a function or class expression
+fn expression_signature(expr: &Expression, source_text: &str) -> Option<String> {
+    match expr.without_parenthesized() {
+        Expression::ArrowFunctionExpression(arrow) => {
+            Some(normalize(source_text, Span::new(arrow.span.start, arrow.body.span.start)))
+        }
+        Expression::FunctionExpression(func) => Some(normalize(source_text, func.signature_span())),
+        Expression::ClassExpression(class) => {
+            let start = class.span_without_decorators(source_text).start;
+            Some(normalize(source_text, Span::new(start, class.body.span.start)))
+        }
+        _ => None,
+    }
+}
+
+/// The text of `span` with runs of whitespace collapsed to a space
+fn normalize(source_text: &str, span: Span) -> String {
+    span.source_text(source_text).split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,264 @@
+//! Clone detection over windows of consecutive statements.
+//!
+//! Every statement is hashed from the kinds of its nodes in source order, so identifiers and
+//! literals are normalized: `a + 1` and `b + 2` have the same hash, `a + 1` and `a - 1` do not.
+//! The shortest windows of statements of a statement list which span `min_lines` lines are
+//! hashed and grouped across files, overlapping windows of the same group are merged into the
+//! longest duplicated sequence.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use oxc_ast::{ast::Statement, AstKind, Visit};
+use oxc_span::{GetSpan, Span};
+
+/// A statement of a statement list
+#[derive(Debug, Clone, Copy)]
+struct StatementHash {
+    hash: u64,
+    span: Span,
+    /// One based line and column, in characters, of the start of the statement
+    start_line: usize,
+    start_column: usize,
+    /// One based line of the end of the statement
+    end_line: usize,
+}
+
+/// The statement lists of a file, e.g. the body of the program and of each block
+#[derive(Debug)]
+pub struct FileStatements {
+    pub path: Box<Path>,
+    lists: Vec<Vec<StatementHash>>,
+}
+
+impl FileStatements {
+    pub fn new(path: Box<Path>, source_text: &str, statements: &[Statement]) -> Self {
+        let mut fingerprinter = Fingerprinter::new(source_text);
+        fingerprinter.visit_statement_list(statements);
+        Self { path, lists: fingerprinter.lists }
+    }
+}
+
+/// A sequence of statements duplicated in several places
+#[derive(Debug, Clone)]
+pub struct CodeClone {
+    /// Number of lines of the first location
+    pub lines: usize,
+    pub locations: Vec<CloneLocation>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CloneLocation {
+    /// Index of the file in the slice given to [`find_clones`]
+    pub file: usize,
+    pub span: Span,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+}
+
+impl CloneLocation {
+    fn contains(&self, other: &Self) -> bool {
+        self.file == other.file
+            && self.span.start <= other.span.start
+            && other.span.end <= self.span.end
+    }
+}
+
+/// The statements `start..=end` of a list
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    list: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Find the sequences of statements of at least `min_lines` lines which are duplicated in
+/// `files`, longest first. Clones which are part of a longer clone are left out.
+pub fn find_clones(files: &[FileStatements], min_lines: usize) -> Vec<CodeClone> {
+    let lists = files
+        .iter()
+        .enumerate()
+        .flat_map(|(file, statements)| statements.lists.iter().map(move |list| (file, list)))
+        .collect::<Vec<_>>();
+
+    let mut windows: HashMap<u64, Vec<Window>> = HashMap::new();
+    for (list_index, (_, list)) in lists.iter().enumerate() {
+        for (start, first) in list.iter().enumerate() {
+            let first_line = first.start_line;
+            let Some(end) = (start..list.len())
+                .find(|end| list[*end].end_line + 1 >= first_line + min_lines.max(1))
+            else {
+                // Later windows start on a later line
+                break;
+            };
+            let mut hasher = DefaultHasher::new();
+            for statement in &list[start..=end] {
+                statement.hash.hash(&mut hasher);
+            }
+            windows.entry(hasher.finish()).or_default().push(Window {
+                list: list_index,
+                start,
+                end,
+            });
+        }
+    }
+
+    let mut groups = windows
+        .into_values()
+        .filter_map(|group| {
+            // Drop windows overlapping the previous window of the same list, e.g. in a list of
+            // repeated statements
+            let mut members: Vec<Window> = vec![];
+            for window in group {
+                let overlaps = members
+                    .last()
+                    .is_some_and(|last| last.list == window.list && window.start <= last.end);
+                if !overlaps {
+                    members.push(window);
+                }
+            }
+            (members.len() > 1).then_some(members)
+        })
+        .collect::<Vec<_>>();
+    groups.sort_unstable_by_key(|members| (members[0].start, members[0].list));
+
+    // Merge each group into the clone of the group of the windows starting one statement earlier
+    let mut clones: Vec<Vec<Window>> = vec![];
+    let mut clone_by_starts: HashMap<Vec<(usize, usize)>, usize> = HashMap::new();
+    for members in groups {
+        let starts = members.iter().map(|window| (window.list, window.start)).collect::<Vec<_>>();
+        let previous_starts = starts
+            .iter()
+            .map(|(list, start)| start.checked_sub(1).map(|start| (*list, start)))
+            .collect::<Option<Vec<_>>>();
+        let previous = previous_starts.and_then(|starts| clone_by_starts.get(&starts).copied());
+        let index = if let Some(index) = previous {
+            for (window, member) in clones[index].iter_mut().zip(&members) {
+                window.end = window.end.max(member.end);
+            }
+            index
+        } else {
+            clones.push(members);
+            clones.len() - 1
+        };
+        clone_by_starts.insert(starts, index);
+    }
+
+    let clones = clones
+        .into_iter()
+        .map(|windows| {
+            let locations = windows
+                .iter()
+                .map(|window| {
+                    let (file, list) = lists[window.list];
+                    let (first_statement, last_statement) = (list[window.start], list[window.end]);
+                    CloneLocation {
+                        file,
+                        span: Span::new(first_statement.span.start, last_statement.span.end),
+                        start_line: first_statement.start_line,
+                        start_column: first_statement.start_column,
+                        end_line: last_statement.end_line,
+                    }
+                })
+                .collect::<Vec<_>>();
+            let lines = locations[0].end_line + 1 - locations[0].start_line;
+            CodeClone { lines, locations }
+        })
+        .collect::<Vec<_>>();
+
+    // Statements of a duplicated function are duplicated as well
+    let is_nested = |index: usize| {
+        let clone = &clones[index];
+        clones.iter().enumerate().any(|(other_index, other)| {
+            other_index != index
+                && other.lines >= clone.lines
+                && clone.locations.iter().all(|location| {
+                    other.locations.iter().any(|other_location| other_location.contains(location))
+                })
+        })
+    };
+    let mut result = (0..clones.len())
+        .filter(|index| !is_nested(*index))
+        .map(|index| clones[index].clone())
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| {
+        let key = |clone: &CodeClone| (clone.locations[0].file, clone.locations[0].span.start);
+        b.lines.cmp(&a.lines).then_with(|| key(a).cmp(&key(b)))
+    });
+    result
+}
+
+struct Fingerprinter<'s> {
+    source_text: &'s str,
+    /// Start offset of each line
+    line_starts: Vec<u32>,
+    /// Hash of each node in source order
+    tokens: Vec<u64>,
+    lists: Vec<Vec<StatementHash>>,
+}
+
+impl<'s> Fingerprinter<'s> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &'s str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source_text.match_indices('\n').map(|(index, _)| index as u32 + 1))
+            .collect();
+        Self { source_text, line_starts, tokens: vec![], lists: vec![] }
+    }
+
+    fn line(&self, offset: u32) -> usize {
+        self.line_starts.partition_point(|start| *start <= offset)
+    }
+
+    fn column(&self, line: usize, offset: u32) -> usize {
+        let line_start = self.line_starts[line - 1] as usize;
+        self.source_text[line_start..offset as usize].chars().count() + 1
+    }
+
+    fn visit_statement_list(&mut self, stmts: &[Statement]) {
+        let mut list = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            let start = self.tokens.len();
+            self.visit_statement(stmt);
+            let mut hasher = DefaultHasher::new();
+            self.tokens[start..].hash(&mut hasher);
+            let span = stmt.span();
+            let start_line = self.line(span.start);
+            list.push(StatementHash {
+                hash: hasher.finish(),
+                span,
+                start_line,
+                start_column: self.column(start_line, span.start),
+                end_line: self.line(span.end.saturating_sub(1).max(span.start)),
+            });
+        }
+        if !list.is_empty() {
+            self.lists.push(list);
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for Fingerprinter<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let mut hasher = DefaultHasher::new();
+        std::mem::discriminant(&kind).hash(&mut hasher);
+        match kind {
+            AstKind::AssignmentExpression(expr) => expr.operator.hash(&mut hasher),
+            AstKind::BinaryExpression(expr) => expr.operator.hash(&mut hasher),
+            AstKind::LogicalExpression(expr) => expr.operator.hash(&mut hasher),
+            AstKind::UnaryExpression(expr) => expr.operator.hash(&mut hasher),
+            AstKind::UpdateExpression(expr) => expr.operator.hash(&mut hasher),
+            AstKind::VariableDeclaration(decl) => decl.kind.hash(&mut hasher),
+            _ => {}
+        }
+        self.tokens.push(hasher.finish());
+    }
+
+    fn visit_statements(&mut self, stmts: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        self.visit_statement_list(stmts);
+    }
+}
@@ -0,0 +1,195 @@
+mod detector;
+
+use std::{env, fs, path::Path, time::Instant};
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use serde_json::{json, Value};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+
+use self::detector::{find_clones, CodeClone, FileStatements};
+use crate::{
+    command::{ClonesOptions, OutputFormat},
+    file_options::{FileOptions, FileOptionsResolver},
+    walk::Walk,
+    CliRunResult, Runner,
+};
+
+pub struct ClonesRunner {
+    options: ClonesOptions,
+}
+
+impl Runner for ClonesRunner {
+    type Options = ClonesOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let ClonesOptions { min_lines, output_options, ignore_options, mut paths, .. } =
+            self.options;
+
+        let now = Instant::now();
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        if paths.is_empty() {
+            paths.push(cwd.clone());
+        }
+        let mut paths = Walk::new(&paths, &ignore_options).paths();
+        // Clones are reported in the order of their files
+        paths.sort_unstable();
+        let file_options_resolver = FileOptionsResolver::new(cwd.clone());
+
+        let files = paths
+            .par_iter()
+            .filter_map(|path| Self::statements(path, &file_options_resolver))
+            .collect::<Vec<_>>();
+        let clones = find_clones(&files, min_lines);
+
+        let output = match output_options.format {
+            OutputFormat::Default => {
+                clones.iter().map(|clone| Self::format_clone(&cwd, &files, clone)).collect()
+            }
+            OutputFormat::Json => {
+                let clones = clones
+                    .iter()
+                    .map(|clone| Self::clone_to_json(&cwd, &files, clone))
+                    .collect::<Vec<_>>();
+                format!("{}\n", Value::Array(clones))
+            }
+        };
+
+        CliRunResult::ClonesResult {
+            duration: now.elapsed(),
+            number_of_files: files.len(),
+            number_of_clones: clones.len(),
+            output,
+        }
+    }
+}
+
+impl ClonesRunner {
+    /// Files with syntax errors are skipped.
+    fn statements(
+        path: &Path,
+        file_options_resolver: &FileOptionsResolver,
+    ) -> Option<FileStatements> {
+        let source_text = fs::read_to_string(path).ok()?;
+        let FileOptions { source_type, parse_options, .. } = file_options_resolver.resolve(path)?;
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, &source_text, source_type).with_options(parse_options).parse();
+        if !ret.errors.is_empty() {
+            return None;
+        }
+        Some(FileStatements::new(path.into(), &source_text, &ret.program.body))
+    }
+
+    /// ```text
+    /// 12 duplicated lines in 2 places:
+    ///   src/a.js:3:1 (lines 3-14)
+    ///   src/b.js:20:3 (lines 20-31)
+    /// ```
+    fn format_clone(cwd: &Path, files: &[FileStatements], clone: &CodeClone) -> String {
+        let mut output =
+            format!("{} duplicated lines in {} places:\n", clone.lines, clone.locations.len());
+        for location in &clone.locations {
+            output.push_str(&format!(
+                "  {}:{}:{} (lines {}-{})\n",
+                relative_path(cwd, &files[location.file].path),
+                location.start_line,
+                location.start_column,
+                location.start_line,
+                location.end_line
+            ));
+        }
+        output
+    }
+
+    fn clone_to_json(cwd: &Path, files: &[FileStatements], clone: &CodeClone) -> Value {
+        let locations = clone
+            .locations
+            .iter()
+            .map(|location| {
+                json!({
+                    "path": relative_path(cwd, &files[location.file].path),
+                    "start": location.span.start,
+                    "end": location.span.end,
+                    "line": location.start_line,
+                    "column": location.start_column,
+                    "end_line": location.end_line,
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "lines": clone.lines, "locations": locations })
+    }
+}
+
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod test {
+    use super::ClonesRunner;
+    use crate::{cli_command, CliCommand, CliRunResult, Runner};
+
+    fn test(args: &[&str]) -> (usize, String) {
+        let CliCommand::Clones(options) = cli_command().run_inner(args).unwrap() else {
+            unreachable!()
+        };
+        match ClonesRunner::new(options).run() {
+            CliRunResult::ClonesResult { number_of_clones, output, .. } => {
+                (number_of_clones, output)
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn clones() {
+        let (number_of_clones, output) = test(&["clones", "fixtures/clones"]);
+        assert_eq!(number_of_clones, 2);
+        assert_eq!(
+            output,
+            "7 duplicated lines in 2 places:
+  fixtures/clones/a.js:1:1 (lines 1-7)
+  fixtures/clones/b.js:3:1 (lines 3-9)
+5 duplicated lines in 2 places:
+  fixtures/clones/a.js:10:3 (lines 10-14)
+  fixtures/clones/a.js:18:3 (lines 18-22)
+"
+        );
+    }
+
+    #[test]
+    fn min_lines() {
+        let (number_of_clones, _) = test(&["clones", "--min-lines", "6", "fixtures/clones"]);
+        assert_eq!(number_of_clones, 1);
+        let (number_of_clones, _) = test(&["clones", "--min-lines", "8", "fixtures/clones"]);
+        assert_eq!(number_of_clones, 0);
+    }
+
+    #[test]
+    fn clones_json() {
+        let (_, output) = test(&["clones", "-f", "json", "fixtures/clones"]);
+        let clones: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(clones[0]["lines"], 7);
+        assert_eq!(
+            clones[0]["locations"][1],
+            serde_json::json!({
+                "path": "fixtures/clones/b.js",
+                "start": 36,
+                "end": 175,
+                "line": 3,
+                "column": 1,
+                "end_line": 9,
+            })
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    lint::{output_options, OutputOptions},
+    misc_options, MiscOptions,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct ApiReportOptions {
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Entry points of the package
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod api_report_options {
+    use std::path::PathBuf;
+
+    use super::ApiReportOptions;
+    use crate::{cli_command, CliCommand, OutputFormat};
+
+    fn get_api_report_options(arg: &str) -> ApiReportOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::ApiReport(options) => options,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_api_report_options("api-report src/index.ts");
+        assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert_eq!(options.paths, vec![PathBuf::from("src/index.ts")]);
+    }
+
+    #[test]
+    fn json() {
+        let options = get_api_report_options("api-report -f src/index.ts src/cli.ts");
+        assert_eq!(options.output_options.format, OutputFormat::Json);
+        assert_eq!(options.paths.len(), 2);
+    }
+}
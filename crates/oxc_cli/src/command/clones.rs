@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    ignore::{ignore_options, IgnoreOptions},
+    lint::{output_options, OutputOptions},
+    misc_options, MiscOptions,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct ClonesOptions {
+    /// Minimum number of lines of a duplicated sequence of statements
+    #[bpaf(argument("INT"), fallback(5))]
+    pub min_lines: usize,
+
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod clones_options {
+    use std::path::PathBuf;
+
+    use super::ClonesOptions;
+    use crate::{cli_command, CliCommand, OutputFormat};
+
+    fn get_clones_options(arg: &str) -> ClonesOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Clones(options) => options,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_clones_options("clones .");
+        assert_eq!(options.min_lines, 5);
+        assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn min_lines() {
+        let options = get_clones_options("clones --min-lines 10 -f json src");
+        assert_eq!(options.min_lines, 10);
+        assert_eq!(options.output_options.format, OutputFormat::Json);
+    }
+}
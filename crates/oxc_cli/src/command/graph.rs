@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    ignore::{ignore_options, IgnoreOptions},
+    lint::{output_options, OutputOptions},
+    misc_options, MiscOptions,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct GraphOptions {
+    /// List the circular dependencies, each with one cycle and the imports on it
+    #[bpaf(switch)]
+    pub cycles: bool,
+
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod graph_options {
+    use std::path::PathBuf;
+
+    use super::GraphOptions;
+    use crate::{cli_command, CliCommand, OutputFormat};
+
+    fn get_graph_options(arg: &str) -> GraphOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Graph(options) => options,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_graph_options("graph .");
+        assert!(!options.cycles);
+        assert_eq!(options.output_options.format, OutputFormat::Default);
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn cycles() {
+        let options = get_graph_options("graph --cycles -f json src");
+        assert!(options.cycles);
+        assert_eq!(options.output_options.format, OutputFormat::Json);
+    }
+}
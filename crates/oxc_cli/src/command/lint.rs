@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
 use oxc_linter::AllowWarnDeny;
@@ -48,6 +48,40 @@ pub struct LintOptions {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Write a starter `.oxlintrc.json` for the project in the current directory, instead of linting
+    ///
+    /// * plugins are enabled for the detected dependencies of `package.json`
+    /// * rules of an existing `.eslintrc.json` can be copied over
+    #[bpaf(switch, hide_usage)]
+    pub init: bool,
+
+    /// print statistics of the source files (node counts, scopes, symbols, ...) instead of linting
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// count the `@ts-expect-error` and `@ts-ignore` comments per file instead of linting
+    #[bpaf(switch, hide_usage)]
+    pub suppression_report: bool,
+
+    /// report exports which are not imported by any other file instead of linting, enables the import plugin
+    #[bpaf(switch, hide_usage)]
+    pub unused_exports: bool,
+
+    /// Print the fixes of all diagnostics in FORMAT instead of linting, for editor extensions
+    ///
+    /// * the only supported format is `json`
+    /// * fixes are not applied
+    /// * fixes which may change the behavior of the code are only printed with `--fix-dangerously`
+    #[bpaf(argument("FORMAT"), hide_usage)]
+    pub fix_suggestions: Option<FixSuggestionsFormat>,
+
+    /// Files whose exports are used outside of the linted files, e.g. entry points and the public API
+    ///
+    /// * only used with `--unused-exports`
+    /// * the supported syntax is the same as for `--ignore-pattern`
+    #[bpaf(argument("GLOB"), many, hide_usage)]
+    pub entry: Vec<String>,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -57,6 +91,40 @@ pub struct LintOptions {
     #[bpaf(long, short, argument("PATH"))]
     pub config: Option<PathBuf>,
 
+    /// Persist module resolutions of the import plugin to PATH and reuse them in the next run
+    ///
+    /// * entries are invalidated when the resolved file or its `package.json` changes
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub resolve_cache: Option<PathBuf>,
+
+    /// Reuse the results of files linted before with the same configuration from the directory at PATH
+    ///
+    /// * results are not cached with the import plugin, `--fix` or `--fix-suggestions`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Share the results of files linted before with the HTTP cache server at URL, e.g. between CI machines
+    ///
+    /// * results are read with `GET URL/KEY` and written with `PUT URL/KEY`, KEY is a SHA-256 of the
+    ///   linter configuration and of the path and content of the file
+    /// * the `OXC_REMOTE_CACHE_TOKEN` environment variable is sent as a bearer token
+    /// * takes precedence over `--cache-dir`
+    #[bpaf(argument("URL"), hide_usage)]
+    pub remote_cache: Option<String>,
+
+    /// Stop linting a file with an error once its syntax tree takes more than MIB mebibytes of memory
+    ///
+    /// * keeps pathological files, such as huge generated ones, from exhausting the memory
+    #[bpaf(argument("MIB"), hide_usage)]
+    pub max_file_memory: Option<usize>,
+
+    /// Keep running and serve lint, format and transform requests on the Unix socket at PATH
+    ///
+    /// * requests and responses are JSON objects, one per line
+    /// * the diagnostics of a file are reused until it is modified
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub daemon: Option<PathBuf>,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -116,8 +184,16 @@ impl LintFilter {
 #[derive(Debug, Clone, Bpaf)]
 pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
+    ///
+    /// * only fixes which preserve the behavior of the code are applied
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Also apply fixes which may change the behavior of the code, e.g. removing `debugger`
+    ///
+    /// * applies to `--fix` and `--fix-suggestions`
+    #[bpaf(switch, hide_usage)]
+    pub fix_dangerously: bool,
 }
 
 /// Handle Warnings
@@ -144,6 +220,10 @@ pub struct OutputOptions {
     // last flag is the default
     #[bpaf(long, short, flag(OutputFormat::Json, OutputFormat::Default))]
     pub format: OutputFormat,
+
+    /// Number of columns of a tab in the source code shown by the default format
+    #[bpaf(argument("INT"), fallback(4), hide_usage)]
+    pub tab_width: usize,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -152,6 +232,22 @@ pub enum OutputFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FixSuggestionsFormat {
+    Json,
+}
+
+impl FromStr for FixSuggestionsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unsupported format `{s}`, expected `json`")),
+        }
+    }
+}
+
 /// Enable Plugins
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Clone, Bpaf)]
@@ -212,7 +308,7 @@ mod lint_options {
 
     use oxc_linter::AllowWarnDeny;
 
-    use super::{lint_command, LintOptions, OutputFormat};
+    use super::{lint_command, FixSuggestionsFormat, LintOptions, OutputFormat};
 
     fn get_lint_options(arg: &str) -> LintOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -225,9 +321,23 @@ mod lint_options {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
+        assert!(!options.init);
+        assert!(!options.stats);
+        assert!(!options.suppression_report);
+        assert!(!options.unused_exports);
+        assert!(options.fix_suggestions.is_none());
+        assert!(options.entry.is_empty());
+        assert!(options.daemon.is_none());
         assert_eq!(options.output_options.format, OutputFormat::Default);
     }
 
+    #[test]
+    fn daemon() {
+        let options = get_lint_options("--daemon /tmp/oxlint.sock");
+        assert_eq!(options.daemon, Some(PathBuf::from("/tmp/oxlint.sock")));
+        assert!(options.paths.is_empty());
+    }
+
     #[test]
     fn multiple_paths() {
         let options = get_lint_options("foo bar baz");
@@ -256,6 +366,13 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dangerously() {
+        let options = get_lint_options("--fix --fix-dangerously test.js");
+        assert!(options.fix_options.fix);
+        assert!(options.fix_options.fix_dangerously);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -277,9 +394,72 @@ mod lint_options {
         assert_eq!(options.output_options.format, OutputFormat::Json);
     }
 
+    #[test]
+    fn tab_width() {
+        assert_eq!(get_lint_options(".").output_options.tab_width, 4);
+        let options = get_lint_options("--tab-width 2 .");
+        assert_eq!(options.output_options.tab_width, 2);
+    }
+
     #[test]
     fn list_rules() {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn init() {
+        let options = get_lint_options("--init");
+        assert!(options.init);
+    }
+
+    #[test]
+    fn stats() {
+        let options = get_lint_options("--stats .");
+        assert!(options.stats);
+    }
+
+    #[test]
+    fn suppression_report() {
+        let options = get_lint_options("--suppression-report .");
+        assert!(options.suppression_report);
+    }
+
+    #[test]
+    fn unused_exports() {
+        let options = get_lint_options("--unused-exports --entry src/index.js --entry bin/* .");
+        assert!(options.unused_exports);
+        assert_eq!(options.entry, vec![String::from("src/index.js"), String::from("bin/*")]);
+    }
+
+    #[test]
+    fn fix_suggestions() {
+        let options = get_lint_options("--fix-suggestions json .");
+        assert_eq!(options.fix_suggestions, Some(FixSuggestionsFormat::Json));
+        assert!(lint_command().run_inner(&["--fix-suggestions", "yaml", "."]).is_err());
+    }
+
+    #[test]
+    fn resolve_cache() {
+        let options = get_lint_options("--import-plugin --resolve-cache .oxc_cache .");
+        assert_eq!(options.resolve_cache, Some(PathBuf::from(".oxc_cache")));
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn max_file_memory() {
+        let options = get_lint_options("--max-file-memory 512 .");
+        assert_eq!(options.max_file_memory, Some(512));
+        assert_eq!(get_lint_options(".").max_file_memory, None);
+    }
+
+    #[test]
+    fn result_cache() {
+        let options = get_lint_options("--cache-dir .oxc_cache .");
+        assert_eq!(options.cache_dir, Some(PathBuf::from(".oxc_cache")));
+        assert_eq!(options.remote_cache, None);
+        let options = get_lint_options("--remote-cache https://cache.example.com/oxlint .");
+        assert_eq!(options.remote_cache.as_deref(), Some("https://cache.example.com/oxlint"));
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
 }
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct MinifyOptions {
+    /// Print the program after each pass of the minifier, with the size change of each pass,
+    /// instead of the minified program
+    #[bpaf(switch)]
+    pub debug_passes: bool,
+
+    /// Write the program after each pass to a numbered file in DIR instead of printing it,
+    /// implies `--debug-passes`
+    #[bpaf(argument("DIR"))]
+    pub debug_passes_dir: Option<PathBuf>,
+
+    /// Load the environment variables inlined for `import.meta.env.*` and `process.env.*`
+    /// from FILE, later files take precedence, e.g. `--env-file .env --env-file .env.production`
+    #[bpaf(argument("FILE"), many)]
+    pub env_file: Vec<PathBuf>,
+
+    /// Only inline the environment variables starting with PREFIX, defaults to `VITE_`.
+    /// Variables of the environment of the process with the prefix take precedence over the files
+    #[bpaf(argument("PREFIX"), many)]
+    pub env_prefix: Vec<String>,
+
+    /// File to minify
+    #[bpaf(positional("PATH"))]
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+mod minify_options {
+    use std::path::PathBuf;
+
+    use super::MinifyOptions;
+    use crate::{cli_command, CliCommand};
+
+    fn get_minify_options(arg: &str) -> MinifyOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::Minify(options) => options,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_minify_options("minify index.js");
+        assert!(!options.debug_passes);
+        assert!(options.debug_passes_dir.is_none());
+        assert!(options.env_file.is_empty());
+        assert!(options.env_prefix.is_empty());
+        assert_eq!(options.path, PathBuf::from("index.js"));
+    }
+
+    #[test]
+    fn env() {
+        let options = get_minify_options(
+            "minify --env-file .env --env-file .env.production --env-prefix PUBLIC_ index.js",
+        );
+        assert_eq!(options.env_file, vec![PathBuf::from(".env"), PathBuf::from(".env.production")]);
+        assert_eq!(options.env_prefix, vec!["PUBLIC_".to_string()]);
+    }
+
+    #[test]
+    fn debug_passes() {
+        let options =
+            get_minify_options("minify --debug-passes --debug-passes-dir passes index.js");
+        assert!(options.debug_passes);
+        assert_eq!(options.debug_passes_dir, Some(PathBuf::from("passes")));
+    }
+}
@@ -1,22 +1,41 @@
+mod api_report;
+mod clones;
 mod format;
+mod graph;
 mod ignore;
 mod lint;
+mod minify;
+mod organize_imports;
 
 use bpaf::Bpaf;
 
 pub use self::{
+    api_report::ApiReportOptions,
+    clones::ClonesOptions,
     format::{format_command, FormatOptions},
+    graph::GraphOptions,
     ignore::IgnoreOptions,
-    lint::{lint_command, LintOptions, OutputFormat, OutputOptions, WarningOptions},
+    lint::{
+        lint_command, FixSuggestionsFormat, LintOptions, OutputFormat, OutputOptions,
+        WarningOptions,
+    },
+    minify::MinifyOptions,
+    organize_imports::{OrganizeImportsMode, OrganizeImportsOptions},
 };
 
-use self::{format::format_options, lint::lint_options};
+use self::{
+    api_report::api_report_options, clones::clones_options, format::format_options,
+    graph::graph_options, lint::lint_options, minify::minify_options,
+    organize_imports::organize_imports_options,
+};
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
 
+// The command is parsed once, the size of the lint options does not matter.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options, version(VERSION))]
 pub enum CliCommand {
@@ -27,6 +46,26 @@ pub enum CliCommand {
     /// Format this repository
     #[bpaf(command)]
     Format(#[bpaf(external(format_options))] FormatOptions),
+
+    /// Analyze the module graph of this repository
+    #[bpaf(command)]
+    Graph(#[bpaf(external(graph_options))] GraphOptions),
+
+    /// Remove unused imports, sort and merge the imports of this repository
+    #[bpaf(command("organize-imports"))]
+    OrganizeImports(#[bpaf(external(organize_imports_options))] OrganizeImportsOptions),
+
+    /// Find duplicated code in this repository
+    #[bpaf(command)]
+    Clones(#[bpaf(external(clones_options))] ClonesOptions),
+
+    /// Minify a file
+    #[bpaf(command)]
+    Minify(#[bpaf(external(minify_options))] MinifyOptions),
+
+    /// List the exported declarations of entry points, to compare the public API across versions
+    #[bpaf(command("api-report"))]
+    ApiReport(#[bpaf(external(api_report_options))] ApiReportOptions),
 }
 
 impl CliCommand {
@@ -38,6 +77,19 @@ impl CliCommand {
             Self::Format(options) => {
                 Self::set_rayon_threads(options.misc_options.threads);
             }
+            Self::Graph(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::OrganizeImports(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::Clones(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::ApiReport(options) => {
+                Self::set_rayon_threads(options.misc_options.threads);
+            }
+            Self::Minify(_) => {}
         }
     }
 
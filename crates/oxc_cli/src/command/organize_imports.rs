@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    ignore::{ignore_options, IgnoreOptions},
+    misc_options, MiscOptions,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+pub struct OrganizeImportsOptions {
+    /// Organize the imports of the files in place,
+    /// otherwise only report the files whose imports are not organized
+    // last flag is the default
+    #[bpaf(long("write"), flag(OrganizeImportsMode::Write, OrganizeImportsMode::Check))]
+    pub mode: OrganizeImportsMode,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many)]
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OrganizeImportsMode {
+    /// Report the files whose imports are not organized and fail if there are any
+    Check,
+    /// Organize the imports of the files in place
+    Write,
+}
+
+#[cfg(test)]
+mod organize_imports_options {
+    use std::path::PathBuf;
+
+    use super::{OrganizeImportsMode, OrganizeImportsOptions};
+    use crate::{cli_command, CliCommand};
+
+    fn get_organize_imports_options(arg: &str) -> OrganizeImportsOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        match cli_command().run_inner(args.as_slice()).unwrap() {
+            CliCommand::OrganizeImports(options) => options,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn default() {
+        let options = get_organize_imports_options("organize-imports .");
+        assert_eq!(options.mode, OrganizeImportsMode::Check);
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
+
+    #[test]
+    fn write() {
+        let options = get_organize_imports_options("organize-imports --write src");
+        assert_eq!(options.mode, OrganizeImportsMode::Write);
+    }
+}
@@ -0,0 +1,273 @@
+//! Parse and transform options of each file, derived from the tsconfig and Babel config governing it.
+//!
+//! Packages of a monorepo are often compiled differently, e.g. one package uses legacy decorators
+//! and the classic JSX runtime while another one uses the automatic runtime,
+//! so a single global setting does not fit all files.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use oxc_linter::{CompilerOptions, JsxMode, ParseOptionsProvider, TsconfigCache};
+use oxc_parser::ParseOptions;
+use oxc_span::SourceType;
+use oxc_transformer::{ReactJsxOptions, TransformOptions, TransformTarget};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+/// Babel config files looked up in each directory, only JSON configs are supported.
+const BABEL_CONFIGS: [&str; 3] = [".babelrc", ".babelrc.json", "babel.config.json"];
+
+#[derive(Debug, Default)]
+pub struct FileOptions {
+    pub source_type: SourceType,
+    pub parse_options: ParseOptions,
+    pub transform_options: TransformOptions,
+}
+
+/// The parts of a Babel config which affect parsing and transforming.
+///
+/// <https://babeljs.io/docs/options>
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BabelConfig {
+    source_type: Option<String>,
+    #[serde(default)]
+    parser_opts: BabelParserOptions,
+    #[serde(default)]
+    presets: Vec<Value>, // Can be a string or an array
+    #[serde(default)]
+    plugins: Vec<Value>, // Can be a string or an array
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BabelParserOptions {
+    allow_return_outside_function: Option<bool>,
+}
+
+impl BabelConfig {
+    /// Options of the preset or plugin `name`, `Value::Null` if it has none.
+    ///
+    /// `react` matches `@babel/preset-react` and `babel-preset-react`,
+    /// `proposal-decorators` matches `@babel/plugin-proposal-decorators` etc.
+    fn get(&self, name: &str) -> Option<Value> {
+        self.presets.iter().chain(&self.plugins).find_map(|entry| {
+            let (entry_name, options) = match entry {
+                Value::String(entry_name) => (entry_name.as_str(), Value::Null),
+                Value::Array(entry) => {
+                    (entry.first()?.as_str()?, entry.get(1).cloned().unwrap_or_default())
+                }
+                _ => return None,
+            };
+            let entry_name = entry_name.trim_start_matches("@babel/").trim_start_matches("babel-");
+            let entry_name = entry_name
+                .strip_prefix("plugin-")
+                .or_else(|| entry_name.strip_prefix("preset-"))
+                .unwrap_or(entry_name);
+            (entry_name == name).then_some(options)
+        })
+    }
+
+    fn apply_to_parse_options(&self, source_type: &mut SourceType, options: &mut ParseOptions) {
+        match self.source_type.as_deref() {
+            Some("script") => *source_type = source_type.with_script(true),
+            Some("module") => *source_type = source_type.with_module(true),
+            _ => {}
+        }
+        if let Some(allow) = self.parser_opts.allow_return_outside_function {
+            options.allow_return_outside_function = allow;
+        }
+        // Only the minimal proposal is supported, the Hack and F# proposals differ in syntax
+        if let Some(pipeline_options) = self.get("proposal-pipeline-operator") {
+            options.pipeline_operator =
+                pipeline_options.get("proposal").and_then(Value::as_str) == Some("minimal");
+        }
+        if self.get("proposal-do-expressions").is_some() {
+            options.do_expressions = true;
+        }
+        if self.get("proposal-throw-expressions").is_some() {
+            options.throw_expressions = true;
+        }
+    }
+}
+
+/// Derives the [`FileOptions`] of each file from the nearest tsconfig and Babel config.
+///
+/// Babel compiles the files it is configured for, so its options take precedence over the tsconfig.
+pub struct FileOptionsResolver {
+    cwd: PathBuf,
+    tsconfigs: TsconfigCache,
+    /// directory -> nearest Babel config in it or its ancestors
+    babel_configs: Mutex<HashMap<PathBuf, Option<Arc<BabelConfig>>>>,
+}
+
+impl FileOptionsResolver {
+    pub fn new(cwd: PathBuf) -> Self {
+        Self { cwd, tsconfigs: TsconfigCache::default(), babel_configs: Mutex::default() }
+    }
+
+    /// Options of the file at `path`, `None` if the file extension is not supported.
+    pub fn resolve(&self, path: &Path) -> Option<FileOptions> {
+        let path = self.cwd.join(path);
+        let mut source_type = SourceType::from_path(&path).ok()?;
+        let mut parse_options = ParseOptions::default();
+        let babel_config = path.parent().and_then(|dir| self.babel_config(dir));
+        if let Some(babel_config) = &babel_config {
+            babel_config.apply_to_parse_options(&mut source_type, &mut parse_options);
+        }
+
+        let mut transform_options = TransformOptions::default();
+        if let Some(tsconfig) = self.tsconfigs.find(&path) {
+            apply_compiler_options(tsconfig.compiler_options(), &mut transform_options);
+        }
+        if let Some(babel_config) = &babel_config {
+            apply_babel_config(babel_config, &mut transform_options);
+        }
+
+        Some(FileOptions { source_type, parse_options, transform_options })
+    }
+
+    fn babel_config(&self, dir: &Path) -> Option<Arc<BabelConfig>> {
+        if let Some(config) = self.babel_configs.lock().unwrap().get(dir) {
+            return config.clone();
+        }
+        let config = BABEL_CONFIGS
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+            .map(|path| {
+                let config = fs::read_to_string(path)
+                    .ok()
+                    .and_then(|text| serde_json::from_str::<BabelConfig>(&text).ok());
+                // An unreadable config still stops the lookup, as it does in Babel
+                Arc::new(config.unwrap_or_default())
+            })
+            .or_else(|| dir.parent().and_then(|parent| self.babel_config(parent)));
+        self.babel_configs.lock().unwrap().insert(dir.to_path_buf(), config.clone());
+        config
+    }
+}
+
+impl ParseOptionsProvider for FileOptionsResolver {
+    fn provide(&self, path: &Path, source_type: &mut SourceType, options: &mut ParseOptions) {
+        let path = self.cwd.join(path);
+        if let Some(babel_config) = path.parent().and_then(|dir| self.babel_config(dir)) {
+            babel_config.apply_to_parse_options(source_type, options);
+        }
+    }
+}
+
+fn apply_compiler_options(compiler_options: &CompilerOptions, options: &mut TransformOptions) {
+    if let Some(target) = compiler_options.target.as_deref().and_then(parse_target) {
+        options.target = target;
+    }
+    options.react_jsx = match compiler_options.jsx {
+        Some(JsxMode::React) => {
+            let mut jsx_options = json!({ "runtime": "classic" });
+            if let Some(factory) = &compiler_options.jsx_factory {
+                jsx_options["pragma"] = json!(factory);
+            }
+            if let Some(fragment_factory) = &compiler_options.jsx_fragment_factory {
+                jsx_options["pragmaFrag"] = json!(fragment_factory);
+            }
+            Some(deserialize::<ReactJsxOptions>(jsx_options))
+        }
+        Some(JsxMode::ReactJsx | JsxMode::ReactJsxdev) => {
+            let mut jsx_options = json!({ "runtime": "automatic" });
+            if let Some(import_source) = &compiler_options.jsx_import_source {
+                jsx_options["importSource"] = json!(import_source);
+            }
+            Some(deserialize::<ReactJsxOptions>(jsx_options))
+        }
+        // JSX is kept as is
+        Some(JsxMode::Preserve | JsxMode::ReactNative) | None => None,
+    };
+    if compiler_options.experimental_decorators == Some(true) {
+        options.decorators = Some(deserialize(json!({ "version": "legacy" })));
+    }
+}
+
+fn apply_babel_config(babel_config: &BabelConfig, options: &mut TransformOptions) {
+    if let Some(jsx_options) =
+        babel_config.get("react").or_else(|| babel_config.get("transform-react-jsx"))
+    {
+        options.react_jsx = Some(deserialize(jsx_options));
+    }
+    if let Some(mut decorators_options) = babel_config.get("proposal-decorators") {
+        // Babel 7 configures legacy decorators with `{ "legacy": true }`
+        if decorators_options.get("legacy").and_then(Value::as_bool) == Some(true) {
+            decorators_options = json!({ "version": "legacy" });
+        }
+        options.decorators = Some(deserialize(decorators_options));
+    }
+}
+
+fn deserialize<T: Default + DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value::<T>(value).unwrap_or_default()
+}
+
+/// <https://www.typescriptlang.org/tsconfig#target>
+///
+/// ES2017 and ES2023 have no transforms of their own and map to the previous target.
+fn parse_target(target: &str) -> Option<TransformTarget> {
+    let target = match target.to_ascii_lowercase().as_str() {
+        "es3" => TransformTarget::ES3,
+        "es5" => TransformTarget::ES5,
+        "es6" | "es2015" => TransformTarget::ES2015,
+        "es2016" | "es2017" => TransformTarget::ES2016,
+        "es2018" => TransformTarget::ES2018,
+        "es2019" => TransformTarget::ES2019,
+        "es2020" => TransformTarget::ES2020,
+        "es2021" => TransformTarget::ES2021,
+        "es2022" | "es2023" => TransformTarget::ES2022,
+        "es2024" => TransformTarget::ES2024,
+        "esnext" => TransformTarget::ESNext,
+        _ => return None,
+    };
+    Some(target)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_transformer::TransformTarget;
+
+    use super::FileOptionsResolver;
+
+    #[test]
+    fn file_options() {
+        let cwd = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/file_options");
+        let resolver = FileOptionsResolver::new(cwd);
+
+        // tsconfig only
+        let options = resolver.resolve(Path::new("packages/web/src/app.tsx")).unwrap();
+        assert!(options.source_type.is_module());
+        assert!(!options.parse_options.allow_return_outside_function);
+        assert_eq!(options.transform_options.target, TransformTarget::ES2019);
+        let react_jsx = options.transform_options.react_jsx.unwrap();
+        assert!(react_jsx.runtime.is_some());
+        assert_eq!(react_jsx.pragma, "h");
+        assert_eq!(react_jsx.pragma_frag, "Fragment");
+        assert!(options.transform_options.decorators.is_some());
+
+        // The Babel config of the package takes precedence over the tsconfig
+        let options = resolver.resolve(Path::new("packages/legacy/src/index.js")).unwrap();
+        assert!(options.source_type.is_script());
+        assert!(options.parse_options.allow_return_outside_function);
+        assert!(options.parse_options.pipeline_operator);
+        assert!(!options.parse_options.do_expressions);
+        assert!(options.parse_options.throw_expressions);
+        assert_eq!(options.transform_options.target, TransformTarget::ES2019);
+        let react_jsx = options.transform_options.react_jsx.unwrap();
+        assert_eq!(react_jsx.pragma, "React.createElement");
+        assert_eq!(react_jsx.import_source, "preact");
+        assert!(options.transform_options.decorators.is_some());
+
+        assert!(resolver.resolve(Path::new("README.md")).is_none());
+    }
+}
@@ -3,11 +3,11 @@ use std::path::Path;
 use oxc_allocator::Allocator;
 use oxc_parser::Parser;
 use oxc_prettier::{Prettier, PrettierOptions};
-use oxc_span::SourceType;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{
     command::FormatOptions,
+    file_options::{FileOptions, FileOptionsResolver},
     result::{CliRunResult, FormatResult},
     walk::Walk,
     Runner,
@@ -34,9 +34,11 @@ impl Runner for FormatRunner {
         let now = std::time::Instant::now();
 
         let paths = Walk::new(paths, ignore_options).paths();
+        let file_options_resolver =
+            FileOptionsResolver::new(std::env::current_dir().unwrap_or_default());
 
         paths.par_iter().for_each(|path| {
-            Self::format(path);
+            Self::format(path, &file_options_resolver);
         });
 
         CliRunResult::FormatResult(FormatResult {
@@ -47,11 +49,15 @@ impl Runner for FormatRunner {
 }
 
 impl FormatRunner {
-    fn format(path: &Path) {
+    fn format(path: &Path, file_options_resolver: &FileOptionsResolver) {
         let source_text = std::fs::read_to_string(path).unwrap();
         let allocator = Allocator::default();
-        let source_type = SourceType::from_path(path).unwrap();
-        let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
+        let FileOptions { source_type, parse_options, .. } =
+            file_options_resolver.resolve(path).unwrap();
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .with_options(parse_options)
+            .preserve_parens(false)
+            .parse();
         let _ = Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
             .build(&ret.program);
     }
@@ -0,0 +1,204 @@
+use std::{env, fs, path::Path, time::Instant};
+
+use serde_json::{json, Value};
+
+use oxc_diagnostics::DiagnosticService;
+use oxc_linter::{AllowWarnDeny, CycleImport, LintOptions, LintService, Linter, ModuleCycle};
+
+use crate::{
+    command::{GraphOptions, OutputFormat},
+    file_options::FileOptionsResolver,
+    lint::line_column,
+    walk::Walk,
+    CliRunResult, Runner,
+};
+
+pub struct GraphRunner {
+    options: GraphOptions,
+}
+
+impl Runner for GraphRunner {
+    type Options = GraphOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let GraphOptions { cycles, output_options, ignore_options, mut paths, .. } = self.options;
+
+        if !cycles {
+            return CliRunResult::InvalidOptions {
+                message: "No analysis is selected, use `--cycles`.".to_string(),
+            };
+        }
+
+        let now = Instant::now();
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        if paths.is_empty() {
+            paths.push(cwd.clone());
+        }
+        let paths = Walk::new(&paths, &ignore_options).paths();
+
+        // Only the module graph is needed, no rule is run.
+        let lint_options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Allow, "all".to_string())])
+            .with_import_plugin(true);
+        let Ok(linter) = Linter::from_options(lint_options) else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to create the linter.".to_string(),
+            };
+        };
+        let lint_service = LintService::new(cwd.clone().into_boxed_path(), &paths, linter)
+            .with_parse_options_provider(Box::new(FileOptionsResolver::new(cwd.clone())));
+
+        // Syntax errors are reported like lint diagnostics.
+        let mut diagnostic_service = DiagnosticService::default();
+        if output_options.format == OutputFormat::Json {
+            diagnostic_service.set_json_reporter();
+        }
+        rayon::spawn({
+            let tx_error = diagnostic_service.sender().clone();
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run(&tx_error);
+            }
+        });
+        diagnostic_service.run();
+
+        let module_cycles = lint_service.module_cycles();
+        let output = match output_options.format {
+            OutputFormat::Default => {
+                module_cycles.iter().map(|cycle| Self::format_cycle(&cwd, cycle)).collect()
+            }
+            OutputFormat::Json => {
+                let cycles = module_cycles
+                    .iter()
+                    .map(|cycle| Self::cycle_to_json(&cwd, cycle))
+                    .collect::<Vec<_>>();
+                format!("{}\n", Value::Array(cycles))
+            }
+        };
+
+        CliRunResult::GraphResult {
+            duration: now.elapsed(),
+            number_of_cycles: module_cycles.len(),
+            output,
+        }
+    }
+}
+
+impl GraphRunner {
+    /// ```text
+    /// Cycle between 2 modules:
+    ///   src/a.js:1:19 imports "./b" (src/b.js)
+    ///   src/b.js:3:15 imports "./a" (src/a.js)
+    /// ```
+    fn format_cycle(cwd: &Path, cycle: &ModuleCycle) -> String {
+        let mut output = format!("Cycle between {} modules:\n", cycle.modules.len());
+        for import in &cycle.imports {
+            let importer = relative_path(cwd, &import.importer);
+            let target = relative_path(cwd, &import.target);
+            for (line, column) in Self::locations(import) {
+                output.push_str(&format!(
+                    "  {importer}:{line}:{column} imports \"{}\" ({target})\n",
+                    import.specifier
+                ));
+            }
+        }
+        output
+    }
+
+    fn cycle_to_json(cwd: &Path, cycle: &ModuleCycle) -> Value {
+        let modules = cycle.modules.iter().map(|path| relative_path(cwd, path)).collect::<Vec<_>>();
+        let imports = cycle
+            .imports
+            .iter()
+            .map(|import| {
+                let spans = import
+                    .spans
+                    .iter()
+                    .zip(Self::locations(import))
+                    .map(|(span, (line, column))| {
+                        json!({ "start": span.start, "end": span.end, "line": line, "column": column })
+                    })
+                    .collect::<Vec<_>>();
+                json!({
+                    "importer": relative_path(cwd, &import.importer),
+                    "specifier": import.specifier.as_str(),
+                    "target": relative_path(cwd, &import.target),
+                    "spans": spans,
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "modules": modules, "imports": imports })
+    }
+
+    /// Line and column of each span of `import`
+    fn locations(import: &CycleImport) -> Vec<(usize, usize)> {
+        let source_text = fs::read_to_string(&import.importer).unwrap_or_default();
+        import.spans.iter().map(|span| line_column(&source_text, span.start)).collect()
+    }
+}
+
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod test {
+    use super::GraphRunner;
+    use crate::{cli_command, CliCommand, CliRunResult, Runner};
+
+    fn test(args: &[&str]) -> (usize, String) {
+        let CliCommand::Graph(options) = cli_command().run_inner(args).unwrap() else {
+            unreachable!()
+        };
+        match GraphRunner::new(options).run() {
+            CliRunResult::GraphResult { number_of_cycles, output, .. } => {
+                (number_of_cycles, output)
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let (number_of_cycles, output) = test(&["graph", "--cycles", "fixtures/cycles"]);
+        assert_eq!(number_of_cycles, 2);
+        assert_eq!(
+            output,
+            r#"Cycle between 2 modules:
+  fixtures/cycles/a.js:1:19 imports "./b" (fixtures/cycles/b.js)
+  fixtures/cycles/b.js:1:19 imports "./a" (fixtures/cycles/a.js)
+Cycle between 3 modules:
+  fixtures/cycles/c.js:1:19 imports "./d" (fixtures/cycles/d.js)
+  fixtures/cycles/d.js:1:19 imports "./e" (fixtures/cycles/e.js)
+  fixtures/cycles/e.js:1:15 imports "./c" (fixtures/cycles/c.js)
+"#
+        );
+    }
+
+    #[test]
+    fn cycles_json() {
+        let (_, output) = test(&["graph", "--cycles", "-f", "json", "fixtures/cycles"]);
+        let cycles: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            cycles[0]["modules"],
+            serde_json::json!(["fixtures/cycles/a.js", "fixtures/cycles/b.js"])
+        );
+        assert_eq!(
+            cycles[0]["imports"][0],
+            serde_json::json!({
+                "importer": "fixtures/cycles/a.js",
+                "specifier": "./b",
+                "target": "fixtures/cycles/b.js",
+                "spans": [{ "start": 18, "end": 23, "line": 1, "column": 19 }],
+            })
+        );
+    }
+}
@@ -1,14 +1,26 @@
+mod api_report;
+mod clones;
 mod command;
+mod file_options;
 mod format;
+mod graph;
 mod lint;
+mod minify;
+mod organize_imports;
 mod result;
 mod runner;
 mod walk;
 
 pub use crate::{
+    api_report::ApiReportRunner,
+    clones::ClonesRunner,
     command::*,
+    file_options::{FileOptions, FileOptionsResolver},
     format::FormatRunner,
-    lint::LintRunner,
+    graph::GraphRunner,
+    lint::{LintRunner, SuppressionReport},
+    minify::MinifyRunner,
+    organize_imports::OrganizeImportsRunner,
     result::{CliRunResult, LintResult},
     runner::Runner,
 };
@@ -0,0 +1,464 @@
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    os::unix::{
+        fs::{DirBuilderExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::SystemTime,
+};
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_diagnostics::{miette::JSONReportHandler, DiagnosticService, Error, FailedToOpenFileError};
+use oxc_linter::{
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    LintService, Linter,
+};
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_transformer::Transformer;
+
+use crate::{
+    command::IgnoreOptions,
+    file_options::{FileOptions, FileOptionsResolver},
+    walk::{Extensions, Walk},
+    CliRunResult,
+};
+
+/// Serves the requests of editors and scripts on a Unix socket for `oxlint --daemon`,
+/// keeping the linter between them.
+///
+/// The diagnostics of each linted file are cached until its modification time changes,
+/// nothing else is cached: every request parses its files again.
+///
+/// Each request and response is a JSON object on one line, e.g.
+///
+/// ```text
+/// {"id":1,"method":"lint","paths":["src"]}
+/// {"id":1,"result":{"diagnostics":[...]}}
+/// {"id":2,"method":"format","path":"src/a.js"}
+/// {"id":2,"result":{"code":"..."}}
+/// {"id":3,"method":"transform","path":"src/a.ts"}
+/// {"id":4,"method":"changed","paths":["src/a.js"]}
+/// {"id":5,"method":"shutdown"}
+/// ```
+///
+/// Files are linted on their own, cross-file rules of the import plugin are not run.
+/// The configuration is read once, restart the daemon after changing it.
+/// Only the files in the current directory are served, and only to the user of the daemon.
+pub struct Daemon {
+    cwd: PathBuf,
+    lint_service: LintService,
+    ignore_options: IgnoreOptions,
+    file_options_resolver: FileOptionsResolver,
+    /// Diagnostics of the linted files by absolute path,
+    /// until the file is modified or a change is notified
+    diagnostics: Mutex<HashMap<PathBuf, CachedDiagnostics>>,
+    shutdown: AtomicBool,
+}
+
+struct CachedDiagnostics {
+    modified: SystemTime,
+    diagnostics: Arc<[Value]>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    /// Echoed in the response
+    #[serde(default)]
+    id: Value,
+    #[serde(flatten)]
+    method: Method,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "method", rename_all = "lowercase")]
+enum Method {
+    /// Lint files and directories, the current directory without paths
+    Lint {
+        #[serde(default)]
+        paths: Vec<PathBuf>,
+    },
+    Format {
+        path: PathBuf,
+    },
+    /// Transform with the options of the tsconfig of the file
+    Transform {
+        path: PathBuf,
+    },
+    /// Files or directories which were changed, e.g. by a file watcher of the editor
+    Changed {
+        paths: Vec<PathBuf>,
+    },
+    Shutdown,
+}
+
+impl Daemon {
+    pub fn new(cwd: PathBuf, linter: Linter, ignore_options: IgnoreOptions) -> Self {
+        let lint_service = LintService::new(cwd.clone().into_boxed_path(), &[], linter)
+            .with_parse_options_provider(Box::new(FileOptionsResolver::new(cwd.clone())));
+        Self {
+            file_options_resolver: FileOptionsResolver::new(cwd.clone()),
+            cwd,
+            lint_service,
+            ignore_options,
+            diagnostics: Mutex::default(),
+            shutdown: AtomicBool::new(false),
+        }
+    }
+
+    /// Listen on `socket`, which only the current user can connect to.
+    ///
+    /// Other users could read any file of the current user through the daemon, so the socket
+    /// is bound in a new directory only the current user can access, restricted to the user,
+    /// and only then moved to `socket`. Binding at `socket` and restricting it afterwards would
+    /// leave a window in which the socket has the permissions of the umask.
+    fn bind_private(socket: &Path) -> io::Result<UnixListener> {
+        let parent = socket.parent().filter(|parent| !parent.as_os_str().is_empty());
+        let dir = parent
+            .unwrap_or_else(|| Path::new("."))
+            .join(format!(".oxlint-daemon-{}", std::process::id()));
+        // Fails if the directory exists, so it cannot be one prepared by another user
+        fs::DirBuilder::new().mode(0o700).create(&dir)?;
+        let private_socket = dir.join("socket");
+        let listener = UnixListener::bind(&private_socket).and_then(|listener| {
+            fs::set_permissions(&private_socket, fs::Permissions::from_mode(0o600))?;
+            fs::rename(&private_socket, socket)?;
+            Ok(listener)
+        });
+        let _ = fs::remove_dir_all(&dir);
+        listener
+    }
+
+    /// Serve connections on `socket` until a shutdown request, each on its own thread.
+    pub fn serve(self, socket: &Path) -> CliRunResult {
+        if UnixStream::connect(socket).is_ok() {
+            return CliRunResult::InvalidOptions {
+                message: format!("A daemon is already listening on {}.", socket.display()),
+            };
+        }
+        // Left over by a daemon which did not shut down
+        let _ = fs::remove_file(socket);
+        let listener = match Self::bind_private(socket) {
+            Ok(listener) => listener,
+            Err(error) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to listen on {}: {error}.", socket.display()),
+                };
+            }
+        };
+
+        let daemon = Arc::new(self);
+        for stream in listener.incoming() {
+            if daemon.shutdown.load(Ordering::Acquire) {
+                break;
+            }
+            let Ok(stream) = stream else { continue };
+            let daemon = Arc::clone(&daemon);
+            let socket = socket.to_path_buf();
+            thread::spawn(move || {
+                daemon.handle_connection(stream);
+                if daemon.shutdown.load(Ordering::Acquire) {
+                    // Wake up the listener blocked on the next connection
+                    let _ = UnixStream::connect(socket);
+                }
+            });
+        }
+        let _ = fs::remove_file(socket);
+        CliRunResult::None
+    }
+
+    fn handle_connection(&self, stream: UnixStream) {
+        let Ok(mut writer) = stream.try_clone() else { return };
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else { return };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = match serde_json::from_str::<Request>(&line) {
+                Ok(Request { id, method }) => match self.handle(method) {
+                    Ok(result) => json!({ "id": id, "result": result }),
+                    Err(error) => json!({ "id": id, "error": error }),
+                },
+                Err(error) => json!({ "id": Value::Null, "error": error.to_string() }),
+            };
+            if writeln!(writer, "{response}").is_err() || self.shutdown.load(Ordering::Acquire) {
+                return;
+            }
+        }
+    }
+
+    fn handle(&self, method: Method) -> Result<Value, String> {
+        match method {
+            Method::Lint { paths } => {
+                for path in &paths {
+                    self.check_path(path)?;
+                }
+                Ok(json!({ "diagnostics": self.lint(paths) }))
+            }
+            Method::Format { path } => self.format(&path).map(|code| json!({ "code": code })),
+            Method::Transform { path } => self.transform(&path).map(|code| json!({ "code": code })),
+            Method::Changed { paths } => {
+                let paths = paths.iter().map(|path| self.cwd.join(path)).collect::<Vec<_>>();
+                self.diagnostics
+                    .lock()
+                    .unwrap()
+                    .retain(|cached, _| !paths.iter().any(|path| cached.starts_with(path)));
+                Ok(Value::Null)
+            }
+            Method::Shutdown => {
+                self.shutdown.store(true, Ordering::Release);
+                Ok(Value::Null)
+            }
+        }
+    }
+
+    /// Diagnostics in the JSON format of `--format json`,
+    /// reusing those of the files which were not modified since they were linted
+    fn lint(&self, mut paths: Vec<PathBuf>) -> Vec<Value> {
+        if paths.is_empty() {
+            paths.push(self.cwd.clone());
+        }
+        let extensions = VALID_EXTENSIONS
+            .iter()
+            .chain(LINT_PARTIAL_LOADER_EXT.iter())
+            .copied()
+            .collect::<Vec<&'static str>>();
+        let paths =
+            Walk::new(&paths, &self.ignore_options).with_extensions(Extensions(extensions)).paths();
+        let mut diagnostics =
+            paths.par_iter().map(|path| (path, self.lint_cached(path))).collect::<Vec<_>>();
+        diagnostics.sort_by(|(a, _), (b, _)| a.cmp(b));
+        diagnostics.into_iter().flat_map(|(_, diagnostics)| diagnostics.to_vec()).collect()
+    }
+
+    fn lint_cached(&self, path: &Path) -> Arc<[Value]> {
+        let absolute_path = self.cwd.join(path);
+        let modified = fs::metadata(&absolute_path).and_then(|metadata| metadata.modified()).ok();
+        if let Some(modified) = modified {
+            let cache = self.diagnostics.lock().unwrap();
+            if let Some(cached) = cache.get(&absolute_path).filter(|c| c.modified == modified) {
+                return Arc::clone(&cached.diagnostics);
+            }
+        }
+
+        let diagnostics = Arc::from(self.lint_file(path));
+        if let Some(modified) = modified {
+            let cached = CachedDiagnostics { modified, diagnostics: Arc::clone(&diagnostics) };
+            self.diagnostics.lock().unwrap().insert(absolute_path, cached);
+        }
+        diagnostics
+    }
+
+    fn lint_file(&self, path: &Path) -> Vec<Value> {
+        let source_text = match fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(error) => {
+                let error = Error::new(FailedToOpenFileError(path.to_path_buf(), error));
+                return render_json(path, "", 0, vec![error]);
+            }
+        };
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let Some(sources) = PartialLoader::parse(ext, &source_text).or_else(|| {
+            let source_type = SourceType::from_path(path).ok()?;
+            Some(vec![JavaScriptSource::new(&source_text, source_type, 0)])
+        }) else {
+            return vec![];
+        };
+
+        let mut diagnostics = vec![];
+        for JavaScriptSource { source_text, source_type, start } in sources {
+            let errors = self.lint_service.lint_source(path, source_text, source_type);
+            diagnostics.extend(render_json(path, source_text, start, errors));
+        }
+        diagnostics
+    }
+
+    fn format(&self, path: &Path) -> Result<String, String> {
+        self.check_path(path)?;
+        let source_text = read_file(path)?;
+        let FileOptions { source_type, parse_options, .. } = self.resolve(path)?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .with_options(parse_options)
+            .preserve_parens(false)
+            .parse();
+        if !ret.errors.is_empty() {
+            return Err(format!("Failed to parse {}.", path.display()));
+        }
+        Ok(Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
+            .build(&ret.program))
+    }
+
+    fn transform(&self, path: &Path) -> Result<String, String> {
+        self.check_path(path)?;
+        let source_text = read_file(path)?;
+        let FileOptions { source_type, parse_options, transform_options } = self.resolve(path)?;
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, &source_text, source_type).with_options(parse_options).parse();
+        if !ret.errors.is_empty() {
+            return Err(format!("Failed to parse {}.", path.display()));
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(&source_text, source_type).build(program).semantic;
+        if let Err(errors) =
+            Transformer::new(&allocator, source_type, semantic, transform_options).build(program)
+        {
+            let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Err(errors.join("\n"));
+        }
+        Ok(Codegen::<false>::new(source_text.len(), CodegenOptions::default()).build(program))
+    }
+
+    /// Reject the paths outside of the current directory, following symlinks
+    fn check_path(&self, path: &Path) -> Result<(), String> {
+        let outside = || format!("{} is outside of {}.", path.display(), self.cwd.display());
+        let cwd = self.cwd.canonicalize().map_err(|_| outside())?;
+        match self.cwd.join(path).canonicalize() {
+            Ok(path) if path.starts_with(cwd) => Ok(()),
+            Ok(_) => Err(outside()),
+            Err(error) => Err(format!("Failed to read {}: {error}.", path.display())),
+        }
+    }
+
+    fn resolve(&self, path: &Path) -> Result<FileOptions, String> {
+        self.file_options_resolver
+            .resolve(path)
+            .ok_or_else(|| format!("{} is not a JavaScript or TypeScript file.", path.display()))
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Failed to read {}: {error}.", path.display()))
+}
+
+/// Diagnostics of the source text starting at `start` in the file,
+/// with the offsets of their labels in the file
+fn render_json(path: &Path, source_text: &str, start: usize, errors: Vec<Error>) -> Vec<Value> {
+    let (_, errors) = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+    let handler = JSONReportHandler::new();
+    errors
+        .iter()
+        .filter_map(|error| {
+            let mut output = String::new();
+            handler.render_report(&mut output, error.as_ref()).ok()?;
+            let mut diagnostic: Value = serde_json::from_str(&output).ok()?;
+            if let Some(labels) = diagnostic["labels"].as_array_mut() {
+                for label in labels {
+                    let offset = &mut label["span"]["offset"];
+                    if let Some(value) = offset.as_u64() {
+                        *offset = json!(value + start as u64);
+                    }
+                }
+            }
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Write},
+        os::unix::{fs::PermissionsExt, net::UnixStream},
+        path::PathBuf,
+        thread,
+        time::Duration,
+    };
+
+    use serde_json::Value;
+
+    use super::Daemon;
+    use crate::{lint_command, CliRunResult};
+
+    fn request(stream: &mut UnixStream, request: &str) -> Value {
+        writeln!(stream, "{request}").unwrap();
+        let mut line = String::new();
+        BufReader::new(stream.try_clone().unwrap()).read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn daemon() {
+        let socket = std::env::temp_dir().join(format!("oxlint-{}.sock", std::process::id()));
+        let options = lint_command().run_inner(&["--quiet"]).unwrap().lint_options;
+        let cwd = std::env::current_dir().unwrap();
+        let daemon = Daemon::new(cwd, oxc_linter::Linter::default(), options.ignore_options);
+        let server = thread::spawn({
+            let socket = socket.clone();
+            move || daemon.serve(&socket)
+        });
+
+        let mut stream = (0..100)
+            .find_map(|_| {
+                UnixStream::connect(&socket)
+                    .map_err(|_| thread::sleep(Duration::from_millis(10)))
+                    .ok()
+            })
+            .unwrap();
+        let mode = fs::metadata(&socket).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        // The socket was bound in a private directory, which is removed
+        let private_dir = format!(".oxlint-daemon-{}", std::process::id());
+        assert!(!std::env::temp_dir().join(private_dir).exists());
+
+        let lint = r#"{"id":1,"method":"lint","paths":["fixtures/linter/debugger.js"]}"#;
+        let response = request(&mut stream, lint);
+        assert_eq!(response["id"], 1);
+        let diagnostics = response["result"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["filename"], "fixtures/linter/debugger.js");
+        // Served from the cache
+        assert_eq!(request(&mut stream, lint), response);
+
+        let changed = r#"{"id":2,"method":"changed","paths":["fixtures/linter"]}"#;
+        assert_eq!(request(&mut stream, changed)["result"], Value::Null);
+        assert_eq!(request(&mut stream, lint), response);
+
+        let format = r#"{"id":3,"method":"format","path":"fixtures/linter/debugger.js"}"#;
+        assert_eq!(request(&mut stream, format)["result"]["code"], "debugger;");
+
+        let response = request(&mut stream, r#"{"id":4,"method":"unknown"}"#);
+        assert!(response["error"].is_string());
+
+        // Offsets in the file, not in the script blocks
+        let lint = r#"{"id":5,"method":"lint","paths":["fixtures/vue/debugger.vue"]}"#;
+        let response = request(&mut stream, lint);
+        let source_text = fs::read_to_string("fixtures/vue/debugger.vue").unwrap();
+        let diagnostics = response["result"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        for diagnostic in diagnostics {
+            let span = &diagnostic["labels"][0]["span"];
+            let offset = usize::try_from(span["offset"].as_u64().unwrap()).unwrap();
+            assert_eq!(&source_text[offset..offset + 8], "debugger");
+        }
+
+        for path in ["../Cargo.toml", "/etc/hosts"] {
+            let format = format!(r#"{{"id":6,"method":"format","path":"{path}"}}"#);
+            assert!(request(&mut stream, &format)["error"].is_string());
+            let lint = format!(r#"{{"id":7,"method":"lint","paths":["{path}"]}}"#);
+            assert!(request(&mut stream, &lint)["error"].is_string());
+        }
+
+        request(&mut stream, r#"{"id":8,"method":"shutdown"}"#);
+        assert!(matches!(server.join().unwrap(), CliRunResult::None));
+        assert!(!PathBuf::from(&socket).exists());
+    }
+}
@@ -0,0 +1,273 @@
+//! `oxlint --init`: scaffold a configuration file for the project in the current directory.
+
+use std::{
+    io::{BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::CliRunResult;
+
+pub const CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// ESLint configuration files whose `rules`, `env` and `settings` can be carried over
+const ESLINT_CONFIG_FILE_NAMES: [&str; 2] = [".eslintrc.json", ".eslintrc"];
+
+/// What `--init` learns about the project from its files
+#[derive(Debug, Default)]
+struct Project {
+    /// Names of `dependencies`, `devDependencies` and `peerDependencies` in `package.json`
+    dependencies: Vec<String>,
+    typescript: bool,
+    /// `lib` of `tsconfig.json` includes `dom`
+    dom: bool,
+    /// Path and content of an existing JSON ESLint configuration
+    eslint_config: Option<(PathBuf, Map<String, Value>)>,
+}
+
+impl Project {
+    fn detect(dir: &Path) -> Self {
+        let read_json = |name: &str| {
+            std::fs::read_to_string(dir.join(name))
+                .ok()
+                .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+        };
+
+        let mut project = Self::default();
+
+        if let Some(package_json) = read_json("package.json") {
+            for key in ["dependencies", "devDependencies", "peerDependencies"] {
+                if let Some(dependencies) = package_json.get(key).and_then(Value::as_object) {
+                    project.dependencies.extend(dependencies.keys().cloned());
+                }
+            }
+        }
+
+        let tsconfig_path = dir.join("tsconfig.json");
+        project.typescript = tsconfig_path.is_file() || project.has_dependency(&["typescript"]);
+        if let Some(tsconfig) = read_json("tsconfig.json") {
+            project.dom = tsconfig
+                .pointer("/compilerOptions/lib")
+                .and_then(Value::as_array)
+                .is_some_and(|lib| {
+                    lib.iter().filter_map(Value::as_str).any(|lib| lib.eq_ignore_ascii_case("dom"))
+                });
+        }
+
+        project.eslint_config = ESLINT_CONFIG_FILE_NAMES.iter().find_map(|name| {
+            let config = read_json(name)?;
+            Some((dir.join(name), config.as_object()?.clone()))
+        });
+
+        project
+    }
+
+    fn has_dependency(&self, names: &[&str]) -> bool {
+        self.dependencies.iter().any(|dependency| names.contains(&dependency.as_str()))
+    }
+
+    /// Plugins for the frameworks the project depends on, by their configuration names
+    fn plugins(&self) -> Vec<&'static str> {
+        let mut plugins = vec![];
+        if self.has_dependency(&["jest", "vitest"]) {
+            plugins.push("jest");
+        }
+        if self.has_dependency(&["react", "preact", "next"]) {
+            plugins.push("jsx-a11y");
+            plugins.push("react-perf");
+        }
+        if self.has_dependency(&["next"]) {
+            plugins.push("nextjs");
+        }
+        plugins
+    }
+
+    fn browser(&self) -> bool {
+        self.dom || self.has_dependency(&["react", "preact", "next", "vue", "svelte"])
+    }
+
+    fn node(&self) -> bool {
+        self.has_dependency(&["@types/node"])
+    }
+}
+
+/// Answers to the questions of `--init`
+#[derive(Debug, Default)]
+struct Answers {
+    suspicious: bool,
+    import_plugin: bool,
+    eslint_config: bool,
+}
+
+/// The configuration written by `--init`
+fn starter_config(project: &Project, answers: &Answers) -> Value {
+    let mut config = Map::new();
+
+    let mut plugins = project.plugins();
+    if answers.import_plugin {
+        plugins.insert(0, "import");
+    }
+    config.insert("plugins".to_string(), json!(plugins));
+
+    let mut categories = Map::new();
+    categories.insert("correctness".to_string(), json!("error"));
+    if answers.suspicious {
+        categories.insert("suspicious".to_string(), json!("warn"));
+    }
+    config.insert("categories".to_string(), Value::Object(categories));
+
+    let eslint_config = project.eslint_config.as_ref().filter(|_| answers.eslint_config);
+    let eslint_property =
+        |key: &str| eslint_config.and_then(|(_, config)| config.get(key)).cloned();
+
+    let mut env = match eslint_property("env") {
+        Some(Value::Object(env)) => env,
+        _ => Map::new(),
+    };
+    if project.browser() {
+        env.entry("browser").or_insert(json!(true));
+    }
+    if project.node() {
+        env.entry("node").or_insert(json!(true));
+    }
+    config.insert("env".to_string(), Value::Object(env));
+
+    for key in ["settings", "rules"] {
+        config.insert(key.to_string(), eslint_property(key).unwrap_or_else(|| json!({})));
+    }
+
+    Value::Object(config)
+}
+
+/// Ask a yes or no question, `default` answers it when stdin is not a terminal.
+fn ask(question: &str, default: bool) -> bool {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return default;
+    }
+    print!("{question} {} ", if default { "[Y/n]" } else { "[y/N]" });
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    if stdin.lock().read_line(&mut answer).is_err() {
+        return default;
+    }
+    match answer.trim().to_ascii_lowercase().as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    }
+}
+
+/// Inspect the project in `dir`, ask a few questions and write [`CONFIG_FILE_NAME`].
+/// An existing configuration file is never overwritten.
+pub fn init(dir: &Path) -> CliRunResult {
+    let path = dir.join(CONFIG_FILE_NAME);
+    if path.exists() {
+        return CliRunResult::InvalidOptions {
+            message: format!("{} already exists.", path.display()),
+        };
+    }
+
+    let project = Project::detect(dir);
+    let plugins = project.plugins();
+    if !plugins.is_empty() {
+        println!("Detected dependencies for the plugins: {}.", plugins.join(", "));
+    }
+    if project.typescript {
+        println!("Detected TypeScript, the typescript rules are always enabled.");
+    }
+
+    let answers = Answers {
+        suspicious: ask(
+            "Warn about code that is most likely wrong (the suspicious category)?",
+            true,
+        ),
+        import_plugin: ask(
+            "Enable the import plugin? It resolves imports, which makes linting slower.",
+            false,
+        ),
+        eslint_config: project.eslint_config.as_ref().is_some_and(|(eslint_path, _)| {
+            ask(
+                &format!(
+                    "Copy rules, env and settings from {}? Unknown rules are ignored.",
+                    eslint_path.display()
+                ),
+                true,
+            )
+        }),
+    };
+
+    let config = starter_config(&project, &answers);
+    let mut text = serde_json::to_string_pretty(&config).unwrap();
+    text.push('\n');
+    if let Err(err) = std::fs::write(&path, text) {
+        return CliRunResult::InvalidOptions {
+            message: format!("Failed to write {}: {err}.", path.display()),
+        };
+    }
+    CliRunResult::InitResult { path }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{starter_config, Answers, Project};
+
+    #[test]
+    fn default() {
+        let config = starter_config(&Project::default(), &Answers::default());
+        assert_eq!(
+            config,
+            json!({
+                "plugins": [],
+                "categories": { "correctness": "error" },
+                "env": {},
+                "settings": {},
+                "rules": {}
+            })
+        );
+    }
+
+    #[test]
+    fn dependencies() {
+        let project = Project {
+            dependencies: vec!["next".to_string(), "vitest".to_string(), "@types/node".to_string()],
+            ..Project::default()
+        };
+        let answers = Answers { suspicious: true, import_plugin: true, ..Answers::default() };
+        let config = starter_config(&project, &answers);
+        assert_eq!(
+            config["plugins"],
+            json!(["import", "jest", "jsx-a11y", "react-perf", "nextjs"])
+        );
+        assert_eq!(config["categories"], json!({ "correctness": "error", "suspicious": "warn" }));
+        assert_eq!(config["env"], json!({ "browser": true, "node": true }));
+    }
+
+    #[test]
+    fn eslint_config() {
+        let eslint_config = json!({
+            "env": { "browser": false, "es2021": true },
+            "rules": { "no-debugger": "warn" }
+        });
+        let project = Project {
+            dom: true,
+            eslint_config: Some((
+                ".eslintrc.json".into(),
+                eslint_config.as_object().unwrap().clone(),
+            )),
+            ..Project::default()
+        };
+
+        let answers = Answers { eslint_config: true, ..Answers::default() };
+        let config = starter_config(&project, &answers);
+        assert_eq!(config["env"], json!({ "browser": false, "es2021": true }));
+        assert_eq!(config["rules"], json!({ "no-debugger": "warn" }));
+
+        let config = starter_config(&project, &Answers::default());
+        assert_eq!(config["env"], json!({ "browser": true }));
+        assert_eq!(config["rules"], json!({}));
+    }
+}
@@ -1,12 +1,40 @@
-use ignore::gitignore::Gitignore;
-use std::{env, io::BufWriter, time::Instant, vec::Vec};
+#[cfg(unix)]
+mod daemon;
+mod init;
+mod remote_cache;
+mod suppression_report;
+
+pub use suppression_report::SuppressionReport;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use std::{
+    env,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Instant,
+    vec::Vec,
+};
+
+use serde_json::json;
 
+use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
-use oxc_linter::{partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, Linter};
-use oxc_span::VALID_EXTENSIONS;
+use oxc_linter::{
+    partial_loader::LINT_PARTIAL_LOADER_EXT, AllowWarnDeny, CacheBackend, DirectoryCache,
+    LintOptions, LintService, Linter, UnusedExport,
+};
+use oxc_parser::Parser;
+use oxc_semantic::{SemanticBuilder, Stats};
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 use crate::{
-    command::{LintOptions as CliLintOptions, OutputFormat, OutputOptions, WarningOptions},
+    command::{
+        FixSuggestionsFormat, LintOptions as CliLintOptions, OutputFormat, OutputOptions,
+        WarningOptions,
+    },
+    file_options::FileOptionsResolver,
     walk::{Extensions, Walk},
     CliRunResult, LintResult, Runner,
 };
@@ -23,6 +51,15 @@ impl Runner for LintRunner {
     }
 
     fn run(self) -> CliRunResult {
+        if self.options.init {
+            return match env::current_dir() {
+                Ok(cwd) => init::init(&cwd),
+                Err(_) => CliRunResult::InvalidOptions {
+                    message: "Failed to get current working directory.".to_string(),
+                },
+            };
+        }
+
         if self.options.list_rules {
             let mut stdout = BufWriter::new(std::io::stdout());
             Linter::print_rules(&mut stdout);
@@ -37,7 +74,17 @@ impl Runner for LintRunner {
             fix_options,
             enable_plugins,
             config,
+            resolve_cache,
+            cache_dir,
+            remote_cache,
+            max_file_memory,
             output_options,
+            stats,
+            suppression_report,
+            unused_exports,
+            fix_suggestions,
+            entry,
+            daemon,
             ..
         } = self.options;
 
@@ -84,18 +131,42 @@ impl Runner for LintRunner {
         let paths =
             Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
 
+        if stats {
+            return Self::collect_stats(&paths, now);
+        }
+
+        if suppression_report {
+            return Self::collect_suppression_report(&paths, now);
+        }
+
         let number_of_files = paths.len();
 
         let cwd = std::env::current_dir().unwrap().into_boxed_path();
+
+        if unused_exports {
+            return Self::find_unused_exports(
+                &cwd,
+                &paths,
+                &entry,
+                resolve_cache,
+                &warning_options,
+                &output_options,
+                now,
+            );
+        }
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(config)
-            .with_fix(fix_options.fix)
-            .with_import_plugin(enable_plugins.import_plugin)
+            .with_fix(fix_options.fix && fix_suggestions.is_none() && daemon.is_none())
+            .with_fix_dangerously(fix_options.fix_dangerously)
+            .with_fix_suggestions(fix_suggestions.is_some())
+            .with_import_plugin(enable_plugins.import_plugin && daemon.is_none())
             .with_jest_plugin(enable_plugins.jest_plugin)
             .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
             .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
+            .with_react_perf_plugin(enable_plugins.react_perf_plugin)
+            .with_resolve_cache_path(resolve_cache)
+            .with_memory_limit(max_file_memory.map(|mib| mib.saturating_mul(1024 * 1024)));
 
         let linter = match Linter::from_options(lint_options) {
             Ok(lint_service) => lint_service,
@@ -110,7 +181,37 @@ impl Runner for LintRunner {
             }
         };
 
-        let lint_service = LintService::new(cwd, &paths, linter);
+        if let Some(socket) = daemon {
+            #[cfg(unix)]
+            return daemon::Daemon::new(cwd.to_path_buf(), linter, ignore_options).serve(&socket);
+            #[cfg(not(unix))]
+            return CliRunResult::InvalidOptions {
+                message: format!(
+                    "Cannot serve on {}, `--daemon` requires Unix sockets.",
+                    socket.display()
+                ),
+            };
+        }
+
+        let file_options_resolver = FileOptionsResolver::new(cwd.to_path_buf());
+        let mut lint_service = LintService::new(cwd.clone(), &paths, linter)
+            .with_parse_options_provider(Box::new(file_options_resolver));
+        let result_cache: Option<Box<dyn CacheBackend>> = match (remote_cache, cache_dir) {
+            (Some(url), _) => {
+                let token = env::var(remote_cache::REMOTE_CACHE_TOKEN_ENV).ok();
+                Some(Box::new(remote_cache::RemoteCache::new(&url, token)))
+            }
+            (None, Some(dir)) => Some(Box::new(DirectoryCache::new(dir))),
+            (None, None) => None,
+        };
+        if let Some(result_cache) = result_cache {
+            lint_service = lint_service.with_result_cache(result_cache);
+        }
+
+        if fix_suggestions == Some(FixSuggestionsFormat::Json) {
+            return Self::print_fix_suggestions(&cwd, &lint_service);
+        }
+
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options);
 
@@ -137,13 +238,173 @@ impl Runner for LintRunner {
 }
 
 impl LintRunner {
+    /// Aggregate [`Stats`] of all files instead of linting them.
+    /// Files which are not JavaScript or TypeScript, such as `.vue`, are skipped.
+    fn collect_stats(paths: &[Box<Path>], now: Instant) -> CliRunResult {
+        let stats = paths
+            .par_iter()
+            .filter_map(|path| Self::file_stats(path))
+            .fold(Stats::default, |mut total, stats| {
+                total.merge(&stats);
+                total
+            })
+            .reduce(Stats::default, |mut total, stats| {
+                total.merge(&stats);
+                total
+            });
+        CliRunResult::StatsResult { duration: now.elapsed(), stats }
+    }
+
+    /// Count the TypeScript suppression comments of all files instead of linting them.
+    fn collect_suppression_report(paths: &[Box<Path>], now: Instant) -> CliRunResult {
+        let mut report = paths.par_iter().filter_map(|path| SuppressionReport::new(path)).reduce(
+            SuppressionReport::default,
+            |mut total, report| {
+                total.merge(report);
+                total
+            },
+        );
+        report.sort();
+        CliRunResult::SuppressionReportResult { duration: now.elapsed(), report }
+    }
+
+    fn file_stats(path: &Path) -> Option<Stats> {
+        let source_type = SourceType::from_path(path).ok()?;
+        let source_text = std::fs::read_to_string(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let semantic = SemanticBuilder::new(&source_text, source_type).build(&ret.program);
+        Some(semantic.semantic.stats())
+    }
+
+    /// Report the exports of `paths` which are not imported by any file instead of linting them.
+    /// Exports of files matched by the `entries` globs are used outside of the project.
+    fn find_unused_exports(
+        cwd: &Path,
+        paths: &[Box<Path>],
+        entries: &[String],
+        resolve_cache: Option<PathBuf>,
+        warning_options: &WarningOptions,
+        output_options: &OutputOptions,
+        now: Instant,
+    ) -> CliRunResult {
+        let mut builder = GitignoreBuilder::new(cwd);
+        for entry in entries {
+            if builder.add_line(None, entry).is_err() {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Invalid entry glob `{entry}`."),
+                };
+            }
+        }
+        let Ok(entries) = builder.build() else {
+            return CliRunResult::InvalidOptions { message: "Invalid entry globs.".to_string() };
+        };
+
+        // Only the module graph is needed, no rule is run.
+        let lint_options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Allow, "all".to_string())])
+            .with_import_plugin(true)
+            .with_resolve_cache_path(resolve_cache);
+        let Ok(linter) = Linter::from_options(lint_options) else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to create the linter.".to_string(),
+            };
+        };
+        let file_options_resolver = FileOptionsResolver::new(cwd.to_path_buf());
+        let lint_service = LintService::new(cwd.into(), paths, linter)
+            .with_parse_options_provider(Box::new(file_options_resolver));
+
+        // Syntax errors are reported like lint diagnostics.
+        let mut diagnostic_service = Self::get_diagnostic_service(warning_options, output_options);
+        rayon::spawn({
+            let tx_error = diagnostic_service.sender().clone();
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run(&tx_error);
+            }
+        });
+        diagnostic_service.run();
+
+        let unused_exports = lint_service.unused_exports(|path| {
+            path.ancestors().any(|ancestor| entries.matched(ancestor, ancestor != path).is_ignore())
+        });
+        CliRunResult::UnusedExportsResult {
+            duration: now.elapsed(),
+            unused_exports: Self::format_unused_exports(cwd, &unused_exports),
+        }
+    }
+
+    /// Lint without reporting the diagnostics, and print their fixes as JSON.
+    fn print_fix_suggestions(cwd: &Path, lint_service: &LintService) -> CliRunResult {
+        let (tx_error, rx_error) = mpsc::channel();
+        rayon::spawn({
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run(&tx_error);
+            }
+        });
+        // Drain the diagnostics, only their fixes are printed.
+        while let Ok(Some(_)) = rx_error.recv() {}
+
+        let mut source: Option<(&Path, String)> = None;
+        let fix_suggestions = lint_service.fix_suggestions();
+        let fix_suggestions = fix_suggestions
+            .iter()
+            .map(|suggestion| {
+                // Suggestions are sorted by path, read each file once.
+                if !source.as_ref().is_some_and(|(path, _)| *path == &*suggestion.path) {
+                    let text = std::fs::read_to_string(&suggestion.path).unwrap_or_default();
+                    source = Some((&*suggestion.path, text));
+                }
+                let text = source.as_ref().map_or("", |(_, text)| text.as_str());
+                let position = |offset: u32| {
+                    let (line, column) = line_column(text, offset);
+                    json!({ "offset": offset, "line": line, "column": column })
+                };
+                let path = suggestion.path.strip_prefix(cwd).unwrap_or(&suggestion.path);
+                json!({
+                    "path": path.to_string_lossy(),
+                    "message": suggestion.message,
+                    "help": suggestion.help,
+                    "range": {
+                        "start": position(suggestion.span.start),
+                        "end": position(suggestion.span.end),
+                    },
+                    "content": suggestion.content,
+                })
+            })
+            .collect::<Vec<_>>();
+        let output = serde_json::to_string_pretty(&fix_suggestions).unwrap();
+        CliRunResult::FixSuggestionsResult { output }
+    }
+
+    /// `path:line:column - name` of each unused export
+    fn format_unused_exports(cwd: &Path, unused_exports: &[UnusedExport]) -> Vec<String> {
+        let mut source: Option<(&Path, String)> = None;
+        unused_exports
+            .iter()
+            .map(|export| {
+                // Unused exports are sorted by path, read each file once.
+                if !source.as_ref().is_some_and(|(path, _)| *path == &*export.path) {
+                    let text = std::fs::read_to_string(&export.path).unwrap_or_default();
+                    source = Some((&*export.path, text));
+                }
+                let text = source.as_ref().map_or("", |(_, text)| text.as_str());
+                let (line, column) = line_column(text, export.span.start);
+                let path = export.path.strip_prefix(cwd).unwrap_or(&export.path);
+                format!("{}:{line}:{column} - {}", path.display(), export.name)
+            })
+            .collect()
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
     ) -> DiagnosticService {
         let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_tab_width(output_options.tab_width);
 
         match output_options.format {
             OutputFormat::Default => {}
@@ -154,8 +415,18 @@ impl LintRunner {
     }
 }
 
+/// One based line and column, in characters, of `offset` in `source_text`
+pub(crate) fn line_column(source_text: &str, offset: u32) -> (usize, usize) {
+    let before = source_text.get(..offset as usize).unwrap_or_default();
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (line, column)
+}
+
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use serde_json::{json, Value};
+
     use super::LintRunner;
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
@@ -398,4 +669,96 @@ mod test {
         assert_eq!(result.number_of_warnings, 1);
         assert_eq!(result.number_of_errors, 0);
     }
+
+    #[test]
+    fn stats() {
+        let options =
+            lint_command().run_inner(&["--stats", "fixtures/linter"]).unwrap().lint_options;
+        match LintRunner::new(options).run() {
+            CliRunResult::StatsResult { stats, .. } => {
+                assert_eq!(stats.files, 2);
+                assert!(stats.nodes > 0);
+                assert_eq!(stats.node_kinds.get("Program"), Some(&2));
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn suppression_report() {
+        let options = lint_command()
+            .run_inner(&["--suppression-report", "fixtures/ts_suppressions"])
+            .unwrap()
+            .lint_options;
+        match LintRunner::new(options).run() {
+            CliRunResult::SuppressionReportResult { report, .. } => {
+                assert_eq!(report.files, 2);
+                assert_eq!(report.expect_error, 2);
+                assert_eq!(report.ignore, 1);
+                assert_eq!(report.without_description, 1);
+                assert_eq!(report.unused, 1);
+                assert_eq!(report.per_file.len(), 1);
+                assert_eq!(report.per_file[0].1, 3);
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn unused_exports() {
+        let args = &[
+            "--unused-exports",
+            "--entry",
+            "fixtures/unused_exports/index.js",
+            "fixtures/unused_exports",
+        ];
+        let options = lint_command().run_inner(args).unwrap().lint_options;
+        match LintRunner::new(options).run() {
+            CliRunResult::UnusedExportsResult { unused_exports, .. } => {
+                assert_eq!(
+                    unused_exports,
+                    [
+                        "fixtures/unused_exports/def.js:2:14 - z",
+                        "fixtures/unused_exports/lib/a.js:2:14 - unused",
+                        "fixtures/unused_exports/lib/b.js:1:14 - b",
+                        "fixtures/unused_exports/lib/index.js:2:10 - b",
+                    ]
+                );
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn fix_suggestions() {
+        let args = &[
+            "--fix-suggestions",
+            "json",
+            "-A",
+            "all",
+            "-D",
+            "no-zero-fractions",
+            "fixtures/fix_suggestions",
+        ];
+        let options = lint_command().run_inner(args).unwrap().lint_options;
+        match LintRunner::new(options).run() {
+            CliRunResult::FixSuggestionsResult { output } => {
+                let fix_suggestions = serde_json::from_str::<Value>(&output).unwrap();
+                assert_eq!(
+                    fix_suggestions,
+                    json!([{
+                        "path": "fixtures/fix_suggestions/zero_fraction.js",
+                        "message": "eslint-plugin-unicorn(no-zero-fractions): Don't use a zero fraction in the number.",
+                        "help": "Replace the number literal with `1`",
+                        "range": {
+                            "start": { "offset": 10, "line": 1, "column": 11 },
+                            "end": { "offset": 13, "line": 1, "column": 14 },
+                        },
+                        "content": "1",
+                    }])
+                );
+            }
+            other => panic!("{other:?}"),
+        }
+    }
 }
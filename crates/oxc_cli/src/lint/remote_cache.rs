@@ -0,0 +1,73 @@
+//! [`CacheBackend`] of an HTTP server, which lets all machines of a CI fleet share lint results.
+//!
+//! A value is read with `GET <url>/<key>`, where `404 Not Found` is a miss,
+//! and written with `PUT <url>/<key>`.
+
+use std::{
+    io::{self, Read},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use oxc_linter::{CacheBackend, CacheKey};
+
+/// Environment variable of the bearer token sent to the remote cache
+pub const REMOTE_CACHE_TOKEN_ENV: &str = "OXC_REMOTE_CACHE_TOKEN";
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct RemoteCache {
+    agent: ureq::Agent,
+    url: String,
+    token: Option<String>,
+    /// Set after the first failed connection, so an unreachable server is not waited on for
+    /// every file.
+    unavailable: AtomicBool,
+}
+
+impl RemoteCache {
+    pub fn new(url: &str, token: Option<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            url: url.trim_end_matches('/').to_string(),
+            token,
+            unavailable: AtomicBool::new(false),
+        }
+    }
+
+    fn request(&self, method: &str, key: &CacheKey) -> io::Result<ureq::Request> {
+        if self.unavailable.load(Ordering::Relaxed) {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "remote cache unavailable"));
+        }
+        let request = self.agent.request(method, &format!("{}/{key}", self.url));
+        Ok(match &self.token {
+            Some(token) => request.set("Authorization", &format!("Bearer {token}")),
+            None => request,
+        })
+    }
+
+    fn error(&self, error: ureq::Error) -> io::Error {
+        if matches!(error, ureq::Error::Transport(_)) {
+            self.unavailable.store(true, Ordering::Relaxed);
+        }
+        io::Error::new(io::ErrorKind::Other, error)
+    }
+}
+
+impl CacheBackend for RemoteCache {
+    fn get(&self, key: &CacheKey) -> io::Result<Option<Vec<u8>>> {
+        match self.request("GET", key)?.call() {
+            Ok(response) => {
+                let mut value = vec![];
+                response.into_reader().read_to_end(&mut value)?;
+                Ok(Some(value))
+            }
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(error) => Err(self.error(error)),
+        }
+    }
+
+    fn put(&self, key: &CacheKey, value: &[u8]) -> io::Result<()> {
+        self.request("PUT", key)?.send_bytes(value).map(|_| ()).map_err(|error| self.error(error))
+    }
+}
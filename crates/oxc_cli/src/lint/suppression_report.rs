@@ -0,0 +1,90 @@
+//! `oxlint --suppression-report`: count the `@ts-expect-error` and `@ts-ignore` comments of the
+//! project, to track them down over time.
+
+use std::{fmt, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_ast::TsSuppressionKind;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[derive(Debug, Default)]
+pub struct SuppressionReport {
+    pub files: usize,
+    pub expect_error: usize,
+    pub ignore: usize,
+    pub without_description: usize,
+    /// Suppressions whose next line has no code, see `oxc/no-unused-ts-suppression`
+    pub unused: usize,
+    /// Number of suppressions per file, most suppressed first. Files without suppressions are
+    /// left out.
+    pub per_file: Vec<(String, usize)>,
+}
+
+impl SuppressionReport {
+    /// Files which are not JavaScript or TypeScript, such as `.vue`, are skipped.
+    pub fn new(path: &Path) -> Option<Self> {
+        let source_type = SourceType::from_path(path).ok()?;
+        let source_text = std::fs::read_to_string(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let semantic = SemanticBuilder::new(&source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(&ret.program);
+
+        let mut report = Self { files: 1, ..Self::default() };
+        let targets = semantic.semantic.ts_suppressions();
+        for target in &targets {
+            match target.suppression.kind {
+                TsSuppressionKind::ExpectError => report.expect_error += 1,
+                TsSuppressionKind::Ignore => report.ignore += 1,
+            }
+            if target.suppression.description(&source_text).is_empty() {
+                report.without_description += 1;
+            }
+            if target.nodes.is_empty() {
+                report.unused += 1;
+            }
+        }
+        if !targets.is_empty() {
+            report.per_file.push((path.to_string_lossy().to_string(), targets.len()));
+        }
+        Some(report)
+    }
+
+    pub fn total(&self) -> usize {
+        self.expect_error + self.ignore
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        self.files += other.files;
+        self.expect_error += other.expect_error;
+        self.ignore += other.ignore;
+        self.without_description += other.without_description;
+        self.unused += other.unused;
+        self.per_file.extend(other.per_file);
+    }
+
+    /// Sort [`SuppressionReport::per_file`] by number of suppressions, after merging
+    pub fn sort(&mut self) {
+        self.per_file.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    }
+}
+
+impl fmt::Display for SuppressionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Files:                {}", self.files)?;
+        writeln!(f, "@ts-expect-error:     {}", self.expect_error)?;
+        writeln!(f, "@ts-ignore:           {}", self.ignore)?;
+        writeln!(f, "Without description:  {}", self.without_description)?;
+        writeln!(f, "Unused:               {}", self.unused)?;
+        if !self.per_file.is_empty() {
+            writeln!(f, "Suppressions per file:")?;
+            for (path, count) in &self.per_file {
+                writeln!(f, "  {count:>5}  {path}")?;
+            }
+        }
+        Ok(())
+    }
+}
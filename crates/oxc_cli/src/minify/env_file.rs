@@ -0,0 +1,80 @@
+//! `.env` files, see <https://github.com/motdotla/dotenv#what-rules-does-the-parsing-engine-follow>
+
+/// Variables of a `.env` file in order of appearance.
+///
+/// * `KEY=VALUE` lines, optionally preceded by `export`, other lines are ignored
+/// * `#` starts a comment, unless it is in a quoted value or not preceded by whitespace
+/// * Values in single, double or back quotes are taken as is,
+///   `\n` is a line break in double quoted values, which may span lines
+/// * Whitespace around unquoted values is trimmed
+pub fn parse_env_file(text: &str) -> Vec<(String, String)> {
+    let mut variables = vec![];
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (line, next_lines) = rest.split_once('\n').unwrap_or((rest, ""));
+        rest = next_lines;
+        let line = line.trim();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let key = key.trim();
+        if key.is_empty() || key.starts_with('#') {
+            continue;
+        }
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'));
+        let value = if let Some(quote) = quote {
+            let value = &value[1..];
+            if let Some(end) = value.find(quote) {
+                value[..end].to_string()
+            } else if let Some(end) = rest.find(quote) {
+                // A quoted value spanning lines
+                let value = format!("{value}\n{}", &rest[..end]);
+                rest = rest[end + 1..].split_once('\n').map_or("", |(_, next)| next);
+                value
+            } else {
+                value.to_string()
+            }
+        } else {
+            let end = value.find(" #").unwrap_or(value.len());
+            value[..end].trim_end().to_string()
+        };
+        let value = if quote == Some('"') { value.replace("\\n", "\n") } else { value };
+        variables.push((key.to_string(), value));
+    }
+    variables
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_env_file;
+
+    #[test]
+    fn env_file() {
+        let text = "
+# comment
+VITE_A=a
+export VITE_B = b # comment
+VITE_C='single # quoted'
+VITE_D=\"line\\nbreak\"
+VITE_E=\"multi
+line\"
+VITE_F=
+VITE_A=override
+invalid line
+";
+        let variables = parse_env_file(text);
+        let variables = variables.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect::<Vec<_>>();
+        assert_eq!(
+            variables,
+            vec![
+                ("VITE_A", "a"),
+                ("VITE_B", "b"),
+                ("VITE_C", "single # quoted"),
+                ("VITE_D", "line\nbreak"),
+                ("VITE_E", "multi\nline"),
+                ("VITE_F", ""),
+                ("VITE_A", "override"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+mod env_file;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_linter::PackageTypeCache;
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{EnvOptions, TransformOptions, Transformer};
+
+use self::env_file::parse_env_file;
+use crate::{command::MinifyOptions, CliRunResult, Runner};
+
+pub struct MinifyRunner {
+    options: MinifyOptions,
+}
+
+/// The program after a pass of the minifier
+struct PassOutput {
+    name: &'static str,
+    /// Printed with whitespace, to be read and diffed
+    code: String,
+    /// Size of the program printed without whitespace
+    size: usize,
+}
+
+impl PassOutput {
+    fn new(name: &'static str, program: &Program, source_len: usize) -> Self {
+        let code = Codegen::<false>::new(source_len, CodegenOptions::default()).build(program);
+        let size = Codegen::<true>::new(source_len, CodegenOptions::default()).build(program).len();
+        Self { name, code, size }
+    }
+}
+
+impl Runner for MinifyRunner {
+    type Options = MinifyOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let MinifyOptions { debug_passes, debug_passes_dir, env_file, env_prefix, path } =
+            self.options;
+
+        let Ok(source_text) = fs::read_to_string(&path) else {
+            return CliRunResult::PathNotFound { paths: vec![path] };
+        };
+        let is_module_package = || PackageTypeCache::default().is_module(&path);
+        let Ok(source_type) = SourceType::detect(&source_text, &path, is_module_package) else {
+            return CliRunResult::InvalidOptions {
+                message: format!("{} is not a JavaScript or TypeScript file.", path.display()),
+            };
+        };
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            let number_of_errors = ret.errors.len();
+            let output = Self::render_errors(&path, &source_text, ret.errors);
+            return CliRunResult::MinifyResult { output, number_of_errors };
+        }
+        let program = allocator.alloc(ret.program);
+
+        if !env_file.is_empty() || !env_prefix.is_empty() {
+            let env = match Self::load_env(&env_file, env_prefix) {
+                Ok(env) => env,
+                Err(message) => return CliRunResult::InvalidOptions { message },
+            };
+            let semantic = SemanticBuilder::new(&source_text, source_type).build(program).semantic;
+            let options = TransformOptions { env: Some(env), ..TransformOptions::default() };
+            if let Err(errors) =
+                Transformer::new(&allocator, source_type, semantic, options).build(program)
+            {
+                let number_of_errors = errors.len();
+                let output = Self::render_errors(&path, &source_text, errors);
+                return CliRunResult::MinifyResult { output, number_of_errors };
+            }
+        }
+
+        let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+        if !debug_passes && debug_passes_dir.is_none() {
+            Minifier::new(options).build(&allocator, program);
+            let output =
+                Codegen::<true>::new(source_text.len(), CodegenOptions::default()).build(program);
+            return CliRunResult::MinifyResult { output, number_of_errors: 0 };
+        }
+
+        let mut passes = vec![PassOutput::new("input", program, source_text.len())];
+        Minifier::new(options).build_with_pass_inspector(&allocator, program, |name, program| {
+            passes.push(PassOutput::new(name, program, source_text.len()));
+        });
+
+        let output = if let Some(dir) = debug_passes_dir {
+            match Self::write_passes(&dir, &passes) {
+                Ok(()) => Self::format_sizes(&passes),
+                Err(error) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to write to {}: {error}", dir.display()),
+                    };
+                }
+            }
+        } else {
+            let mut output = String::new();
+            for (index, pass) in passes.iter().enumerate() {
+                output.push_str(&format!("// ===== {index}-{} =====\n", pass.name));
+                output.push_str(&pass.code);
+                output.push('\n');
+            }
+            output.push_str(&Self::format_sizes(&passes));
+            output
+        };
+        CliRunResult::MinifyResult { output, number_of_errors: 0 }
+    }
+}
+
+impl MinifyRunner {
+    fn render_errors(
+        path: &Path,
+        source_text: &str,
+        errors: Vec<oxc_diagnostics::Error>,
+    ) -> String {
+        let (_, errors) = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+        let handler = GraphicalReportHandler::new();
+        let mut output = String::new();
+        for error in errors {
+            handler.render_report(&mut output, error.as_ref()).unwrap();
+        }
+        output
+    }
+
+    /// Variables of the env files, overridden by the variables of the environment of the process
+    /// which have one of the prefixes
+    fn load_env(env_files: &[PathBuf], prefixes: Vec<String>) -> Result<EnvOptions, String> {
+        let mut env = EnvOptions::default();
+        if !prefixes.is_empty() {
+            env.prefixes = prefixes;
+        }
+        for path in env_files {
+            let text = fs::read_to_string(path)
+                .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+            env.variables.extend(parse_env_file(&text));
+        }
+        let has_prefix = |name: &str| env.prefixes.iter().any(|prefix| name.starts_with(prefix));
+        let process_env = std::env::vars().filter(|(name, _)| has_prefix(name)).collect::<Vec<_>>();
+        env.variables.extend(process_env);
+        Ok(env)
+    }
+
+    /// Write each pass to `dir/<index>-<pass>.js`, e.g. `1-prepass.js`
+    fn write_passes(dir: &Path, passes: &[PassOutput]) -> std::io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for (index, pass) in passes.iter().enumerate() {
+            fs::write(dir.join(format!("{index}-{}.js", pass.name)), &pass.code)?;
+        }
+        Ok(())
+    }
+
+    /// ```text
+    /// 0-input        1234 bytes
+    /// 1-prepass      1200 bytes  -34
+    /// 2-compress      800 bytes  -400
+    /// ```
+    #[allow(clippy::cast_possible_wrap)]
+    fn format_sizes(passes: &[PassOutput]) -> String {
+        let mut output = String::new();
+        for (index, pass) in passes.iter().enumerate() {
+            let name = format!("{index}-{}", pass.name);
+            output.push_str(&format!("{name:<12} {:>8} bytes", pass.size));
+            if let Some(previous) = index.checked_sub(1).map(|index| &passes[index]) {
+                output.push_str(&format!("  {:+}", pass.size as i64 - previous.size as i64));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod test {
+    use super::MinifyRunner;
+    use crate::{cli_command, CliCommand, CliRunResult, Runner};
+
+    fn test(args: &[&str]) -> String {
+        let CliCommand::Minify(options) = cli_command().run_inner(args).unwrap() else {
+            unreachable!()
+        };
+        match MinifyRunner::new(options).run() {
+            CliRunResult::MinifyResult { output, number_of_errors: 0 } => output,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn minify() {
+        let output = test(&["minify", "fixtures/minify/input.js"]);
+        assert!(!output.contains('\n'));
+        assert!(output.contains("!0"));
+    }
+
+    #[test]
+    fn env() {
+        let output = test(&[
+            "minify",
+            "--env-file",
+            "fixtures/minify/.env",
+            "--env-prefix",
+            "PUBLIC_",
+            "fixtures/minify/env.mjs",
+        ]);
+        assert!(output.contains("https://api.example.com"), "{output}");
+        assert!(output.contains("process.env.SECRET"), "{output}");
+
+        let CliCommand::Minify(options) =
+            cli_command().run_inner(&["minify", "fixtures/minify/env.mjs"][..]).unwrap()
+        else {
+            unreachable!()
+        };
+        assert!(matches!(
+            MinifyRunner::new(options).run(),
+            CliRunResult::MinifyResult { number_of_errors: 0, .. }
+        ));
+
+        let CliCommand::Minify(options) = cli_command()
+            .run_inner(&["minify", "--env-prefix", "PUBLIC_", "fixtures/minify/env.mjs"][..])
+            .unwrap()
+        else {
+            unreachable!()
+        };
+        assert!(matches!(
+            MinifyRunner::new(options).run(),
+            CliRunResult::MinifyResult { number_of_errors: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn debug_passes() {
+        let output = test(&["minify", "--debug-passes", "fixtures/minify/input.js"]);
+        let sections =
+            output.lines().filter(|line| line.starts_with("// =====")).collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            ["// ===== 0-input =====", "// ===== 1-prepass =====", "// ===== 2-compress ====="]
+        );
+        let sizes = output.lines().filter(|line| line.contains(" bytes")).collect::<Vec<_>>();
+        assert_eq!(sizes.len(), 3);
+        assert!(sizes[0].starts_with("0-input"));
+        assert!(sizes[2].starts_with("2-compress") && sizes[2].contains("  -"));
+    }
+
+    #[test]
+    fn debug_passes_dir() {
+        let dir = std::env::temp_dir().join("oxc_cli_minify_debug_passes");
+        let dir_arg = dir.to_string_lossy().to_string();
+        let output = test(&["minify", "--debug-passes-dir", &dir_arg, "fixtures/minify/input.js"]);
+        assert_eq!(output.lines().count(), 3);
+        for name in ["0-input.js", "1-prepass.js", "2-compress.js"] {
+            assert!(dir.join(name).exists(), "{name}");
+        }
+        let input = std::fs::read_to_string(dir.join("0-input.js")).unwrap();
+        assert!(input.contains("true"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,98 @@
+use std::{env, fs, path::Path, time::Instant};
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+
+use oxc_allocator::Allocator;
+use oxc_linter::organize_imports;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+
+use crate::{
+    command::{OrganizeImportsMode, OrganizeImportsOptions},
+    file_options::{FileOptions, FileOptionsResolver},
+    result::OrganizeImportsResult,
+    walk::Walk,
+    CliRunResult, Runner,
+};
+
+pub struct OrganizeImportsRunner {
+    options: OrganizeImportsOptions,
+}
+
+impl Runner for OrganizeImportsRunner {
+    type Options = OrganizeImportsOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let OrganizeImportsOptions { mode, ignore_options, mut paths, .. } = self.options;
+
+        let now = Instant::now();
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        if paths.is_empty() {
+            paths.push(cwd.clone());
+        }
+        let paths = Walk::new(&paths, &ignore_options).paths();
+        let file_options_resolver = FileOptionsResolver::new(cwd.clone());
+
+        let mut unorganized = paths
+            .par_iter()
+            .filter(|path| Self::organize(path, &file_options_resolver, mode))
+            .map(|path| path.strip_prefix(&cwd).unwrap_or(&**path).display().to_string())
+            .collect::<Vec<_>>();
+        unorganized.sort_unstable();
+
+        CliRunResult::OrganizeImportsResult(OrganizeImportsResult {
+            duration: now.elapsed(),
+            number_of_files: paths.len(),
+            unorganized,
+            write: mode == OrganizeImportsMode::Write,
+        })
+    }
+}
+
+impl OrganizeImportsRunner {
+    /// Whether the imports of `path` are not organized, they are organized in place with
+    /// `OrganizeImportsMode::Write`. Files with syntax errors are skipped.
+    fn organize(
+        path: &Path,
+        file_options_resolver: &FileOptionsResolver,
+        mode: OrganizeImportsMode,
+    ) -> bool {
+        let Ok(source_text) = fs::read_to_string(path) else { return false };
+        let Some(FileOptions { source_type, parse_options, .. }) =
+            file_options_resolver.resolve(path)
+        else {
+            return false;
+        };
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, &source_text, source_type).with_options(parse_options).parse();
+        if !ret.errors.is_empty() {
+            return false;
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program);
+        if !semantic_ret.errors.is_empty() {
+            return false;
+        }
+
+        let Some(fix) = organize_imports(program, &semantic_ret.semantic) else { return false };
+        if mode == OrganizeImportsMode::Write {
+            let mut organized = source_text.clone();
+            organized.replace_range(fix.span.start as usize..fix.span.end as usize, &fix.content);
+            if fs::write(path, organized).is_err() {
+                return false;
+            }
+        }
+        true
+    }
+}
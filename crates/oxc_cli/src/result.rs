@@ -4,14 +4,64 @@ use std::{
     time::Duration,
 };
 
+use oxc_semantic::Stats;
+
+use crate::lint::SuppressionReport;
+
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
-    InvalidOptions { message: String },
-    PathNotFound { paths: Vec<PathBuf> },
+    InvalidOptions {
+        message: String,
+    },
+    PathNotFound {
+        paths: Vec<PathBuf>,
+    },
     LintResult(LintResult),
     FormatResult(FormatResult),
-    TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
+    TypeCheckResult {
+        duration: Duration,
+        number_of_diagnostics: usize,
+    },
+    StatsResult {
+        duration: Duration,
+        stats: Stats,
+    },
+    SuppressionReportResult {
+        duration: Duration,
+        report: SuppressionReport,
+    },
+    UnusedExportsResult {
+        duration: Duration,
+        unused_exports: Vec<String>,
+    },
+    GraphResult {
+        duration: Duration,
+        number_of_cycles: usize,
+        output: String,
+    },
+    ClonesResult {
+        duration: Duration,
+        number_of_files: usize,
+        number_of_clones: usize,
+        output: String,
+    },
+    InitResult {
+        path: PathBuf,
+    },
+    FixSuggestionsResult {
+        output: String,
+    },
+    OrganizeImportsResult(OrganizeImportsResult),
+    MinifyResult {
+        output: String,
+        number_of_errors: usize,
+    },
+    ApiReportResult {
+        duration: Duration,
+        number_of_exports: usize,
+        output: String,
+    },
 }
 
 #[derive(Debug, Default)]
@@ -31,6 +81,15 @@ pub struct FormatResult {
     pub number_of_files: usize,
 }
 
+#[derive(Debug)]
+pub struct OrganizeImportsResult {
+    pub duration: Duration,
+    pub number_of_files: usize,
+    /// Files whose imports are not organized, or were organized with `write`
+    pub unorganized: Vec<String>,
+    pub write: bool,
+}
+
 impl Termination for CliRunResult {
     fn report(self) -> ExitCode {
         match self {
@@ -100,6 +159,105 @@ impl Termination for CliRunResult {
 
                 ExitCode::from(0)
             }
+            Self::StatsResult { duration, stats } => {
+                print!("{stats}");
+                let time = Self::get_execution_time(&duration);
+                println!("Finished in {time}.");
+                ExitCode::from(0)
+            }
+            Self::SuppressionReportResult { duration, report } => {
+                print!("{report}");
+                let time = Self::get_execution_time(&duration);
+                println!("Finished in {time}.");
+
+                let total = report.total();
+                let s = if total == 1 { "" } else { "s" };
+                println!("Found {total} suppression{s}.");
+                ExitCode::from(0)
+            }
+            Self::UnusedExportsResult { duration, unused_exports } => {
+                for unused_export in &unused_exports {
+                    println!("{unused_export}");
+                }
+                let time = Self::get_execution_time(&duration);
+                println!("Finished in {time}.");
+
+                let number_of_unused_exports = unused_exports.len();
+                let s = if number_of_unused_exports == 1 { "" } else { "s" };
+                println!("Found {number_of_unused_exports} unused export{s}.");
+                ExitCode::from(u8::from(number_of_unused_exports > 0))
+            }
+            Self::GraphResult { duration, number_of_cycles, output } => {
+                print!("{output}");
+                let time = Self::get_execution_time(&duration);
+                println!("Finished in {time}.");
+
+                let s = if number_of_cycles == 1 { "" } else { "s" };
+                println!("Found {number_of_cycles} cycle{s}.");
+                ExitCode::from(u8::from(number_of_cycles > 0))
+            }
+            Self::ClonesResult { duration, number_of_files, number_of_clones, output } => {
+                print!("{output}");
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_files == 1 { "" } else { "s" };
+                println!("Finished in {time} on {number_of_files} file{s}.");
+
+                let s = if number_of_clones == 1 { "" } else { "s" };
+                println!("Found {number_of_clones} clone{s}.");
+                ExitCode::from(u8::from(number_of_clones > 0))
+            }
+            Self::OrganizeImportsResult(OrganizeImportsResult {
+                duration,
+                number_of_files,
+                unorganized,
+                write,
+            }) => {
+                for path in &unorganized {
+                    println!("{path}");
+                }
+                let time = Self::get_execution_time(&duration);
+                let s = if number_of_files == 1 { "" } else { "s" };
+                println!("Finished in {time} on {number_of_files} file{s}.");
+
+                let number_of_unorganized = unorganized.len();
+                let s = if number_of_unorganized == 1 { "" } else { "s" };
+                if write {
+                    println!("Organized the imports of {number_of_unorganized} file{s}.");
+                    return ExitCode::from(0);
+                }
+                println!("Found {number_of_unorganized} file{s} with unorganized imports.");
+                ExitCode::from(u8::from(number_of_unorganized > 0))
+            }
+            Self::MinifyResult { output, number_of_errors } => {
+                if output.ends_with('\n') {
+                    print!("{output}");
+                } else {
+                    println!("{output}");
+                }
+                if number_of_errors > 0 {
+                    let s = if number_of_errors == 1 { "" } else { "s" };
+                    println!("Found {number_of_errors} syntax error{s}.");
+                    return ExitCode::from(1);
+                }
+                ExitCode::from(0)
+            }
+            Self::ApiReportResult { duration, number_of_exports, output } => {
+                print!("{output}");
+                let time = Self::get_execution_time(&duration);
+                println!("Finished in {time}.");
+
+                let s = if number_of_exports == 1 { "" } else { "s" };
+                println!("Found {number_of_exports} export{s}.");
+                ExitCode::from(0)
+            }
+            Self::FixSuggestionsResult { output } => {
+                println!("{output}");
+                ExitCode::from(0)
+            }
+            Self::InitResult { path } => {
+                println!("Wrote {}, pass it to `--config` to use it.", path.display());
+                ExitCode::from(0)
+            }
         }
     }
 }
@@ -1,15 +1,15 @@
 use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_ecmascript::shortest_digits;
 use oxc_syntax::{
     identifier::{LS, PS},
     keyword::is_keyword,
     operator::{BinaryOperator, UnaryOperator},
-    precedence::{GetPrecedence, Precedence},
-    NumberBase,
+    precedence::{is_mixed_coalesce, GetPrecedence, Precedence},
 };
 
-use super::{Codegen, Context, Operator, Separator};
+use super::{Codegen, Context, Operator};
 
 pub trait Gen<const MINIFY: bool> {
     fn gen(&self, _p: &mut Codegen<{ MINIFY }>, _ctx: Context) {}
@@ -102,6 +102,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Statement<'a> {
             Self::TryStatement(stmt) => stmt.gen(p, ctx),
             Self::WhileStatement(stmt) => stmt.gen(p, ctx),
             Self::WithStatement(stmt) => stmt.gen(p, ctx),
+            Self::ErrorStatement(stmt) => stmt.gen(p, ctx),
         }
     }
 }
@@ -470,6 +471,12 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for WithStatement<'a> {
     }
 }
 
+/// Nothing is printed for the source text which could not be parsed,
+/// so the output has the same syntax error as the input
+impl<const MINIFY: bool> Gen<MINIFY> for ErrorStatement {
+    fn gen(&self, _p: &mut Codegen<{ MINIFY }>, _ctx: Context) {}
+}
+
 impl<const MINIFY: bool> Gen<MINIFY> for DebuggerStatement {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.print_indent();
@@ -701,6 +708,10 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for FormalParameters<'a> {
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         p.print_str(b"import ");
+        if let Some(phase) = self.phase {
+            p.print_str(phase.as_str().as_bytes());
+            p.print_hard_space();
+        }
         if let Some(specifiers) = &self.specifiers {
             if specifiers.is_empty() {
                 p.print(b'\'');
@@ -793,7 +804,13 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for WithClause<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         self.attributes_keyword.gen(p, ctx);
         p.print_soft_space();
-        p.print_block(&self.with_entries, Separator::Comma, ctx);
+        p.print(b'{');
+        if !self.with_entries.is_empty() {
+            p.print_soft_space();
+            p.print_list(&self.with_entries, ctx);
+            p.print_soft_space();
+        }
+        p.print(b'}');
     }
 }
 
@@ -806,6 +823,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for ImportAttribute {
             ImportAttributeKey::StringLiteral(literal) => literal.gen(p, ctx),
         };
         p.print_colon();
+        p.print_soft_space();
         self.value.gen(p, ctx);
     }
 }
@@ -831,8 +849,12 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportNamedDeclaration<'a> {
                     p.print_str(b"from");
                     p.print_soft_space();
                     source.gen(p, ctx);
+                    if self.with_clause.is_some() {
+                        p.print_hard_space();
+                    }
+                    self.with_clause.gen(p, ctx);
                 }
-                p.needs_semicolon = true;
+                p.print_semicolon_after_statement();
             }
         }
     }
@@ -942,6 +964,10 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
             Self::TaggedTemplateExpression(expr) => expr.gen(p, ctx),
             Self::Super(sup) => sup.gen(p, ctx),
             Self::AwaitExpression(expr) => expr.gen_expr(p, precedence, ctx),
+            Self::PipelineExpression(expr) => expr.gen_expr(p, precedence, ctx),
+            Self::DoExpression(expr) => expr.gen(p, ctx),
+            Self::ThrowExpression(expr) => expr.gen_expr(p, precedence, ctx),
+            Self::ErrorExpression(expr) => expr.gen(p, ctx),
             Self::ChainExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::NewExpression(expr) => expr.gen_expr(p, precedence, ctx),
             Self::MetaProperty(expr) => expr.gen(p, ctx),
@@ -1030,9 +1056,9 @@ fn need_space_before_dot<const MINIFY: bool>(bytes: &[u8], p: &mut Codegen<{ MIN
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for NumericLiteral<'a> {
-    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        if self.value != f64::INFINITY && (MINIFY || self.raw.is_empty()) {
+        // Keep the source text of unchanged numbers, e.g. `1.50`
+        if self.value != f64::INFINITY && (MINIFY || !self.is_raw_exact()) {
             p.print_space_before_identifier();
             let abs_value = self.value.abs();
 
@@ -1041,26 +1067,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for NumericLiteral<'a> {
                 p.print_str(b"-");
             }
 
-            let result = if self.base == NumberBase::Float {
-                print_non_negative_float(abs_value, p)
-            } else {
-                let value = abs_value as u64;
-                // If integers less than 1000, we know that exponential notation will always be longer than
-                // the integer representation. This is not the case for 1000 which is "1e3".
-                if value < 1000 {
-                    format!("{value}")
-                } else if (1_000_000_000_000..=0xFFFF_FFFF_FFFF_F800).contains(&value) {
-                    let hex = format!("{value:#x}");
-                    let result = print_non_negative_float(abs_value, p);
-                    if hex.len() < result.len() {
-                        hex
-                    } else {
-                        result
-                    }
-                } else {
-                    print_non_negative_float(abs_value, p)
-                }
-            };
+            let result = print_non_negative_float(abs_value);
             let bytes = result.as_bytes();
             p.print_str(bytes);
             need_space_before_dot(bytes, p);
@@ -1072,58 +1079,55 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for NumericLiteral<'a> {
     }
 }
 
-// TODO: refactor this with less allocations
-fn print_non_negative_float<const MINIFY: bool>(value: f64, _p: &Codegen<{ MINIFY }>) -> String {
-    let mut result = value.to_string();
-    let chars = result.as_bytes();
-    let len = chars.len();
-    let dot = chars.iter().position(|&c| c == b'.');
-    let u8_to_string = |num: &[u8]| {
-        // SAFETY: criteria of `from_utf8_unchecked`.are met.
-        unsafe { String::from_utf8_unchecked(num.to_vec()) }
-    };
-
-    if dot == Some(1) && chars[0] == b'0' {
-        // Strip off the leading zero when minifying
-        // "0.5" => ".5"
-        let stripped_result = &chars[1..];
-        // after stripping the leading zero, the after dot position will be start from 1
-        let after_dot = 1;
-
-        // Try using an exponent
-        // "0.001" => "1e-3"
-        if stripped_result[after_dot] == b'0' {
-            let mut i = after_dot + 1;
-            while stripped_result[i] == b'0' {
-                i += 1;
-            }
-            let remaining = &stripped_result[i..];
-            let exponent = format!("-{}", remaining.len() - after_dot + i);
-
-            // Only switch if it's actually shorter
-            if stripped_result.len() > remaining.len() + 1 + exponent.len() {
-                result = format!("{}e{}", u8_to_string(remaining), exponent);
-            } else {
-                result = u8_to_string(stripped_result);
-            }
-        } else {
-            result = u8_to_string(stripped_result);
-        }
-    } else if chars[len - 1] == b'0' {
-        // Simplify numbers ending with "0" by trying to use an exponent
-        // "1000" => "1e3"
-        let mut i = len - 1;
-        while i > 0 && chars[i - 1] == b'0' {
-            i -= 1;
+/// Prints the shortest representation of a non-negative finite number which round-trips.
+///
+/// Candidates are the positional notation with the leading zero stripped ("0.5" => ".5"),
+/// the exponential notation with an integer mantissa ("1000" => "1e3", "0.00012" => "12e-5"),
+/// and the hexadecimal notation for large integers ("1000000000001" => "0xe8d4a51001").
+/// The positional notation is preferred on ties.
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+fn print_non_negative_float(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    // `value` is `0.digits × 10^n`
+    let (digits, n) = shortest_digits(value);
+    let k = i32::try_from(digits.len()).unwrap();
+
+    let mut result = String::with_capacity(digits.len() + 8);
+    if k <= n {
+        // "1200"
+        result.push_str(&digits);
+        result.extend(std::iter::repeat('0').take((n - k) as usize));
+    } else if 0 < n {
+        // "1.2"
+        let (integer, fraction) = digits.split_at(n as usize);
+        result.push_str(integer);
+        result.push('.');
+        result.push_str(fraction);
+    } else {
+        // ".0012"
+        result.push('.');
+        result.extend(std::iter::repeat('0').take((-n) as usize));
+        result.push_str(&digits);
+    }
+
+    let exponent = n - k;
+    if exponent != 0 {
+        // "12e3", "12e-5"
+        let exponential = format!("{digits}e{exponent}");
+        if exponential.len() < result.len() {
+            result = exponential;
         }
-        let remaining = &chars[0..i];
-        let exponent = format!("{}", chars.len() - i);
+    }
 
-        // Only switch if it's actually shorter
-        if chars.len() > remaining.len() + 1 + exponent.len() {
-            result = format!("{}e{}", u8_to_string(remaining), exponent);
-        } else {
-            result = u8_to_string(chars);
+    // Integers less than 10^12 are never shorter in hexadecimal.
+    // 0xFFFF_FFFF_FFFF_F800 is the largest double below 2^64.
+    if k <= n && (1e12..=18_446_744_073_709_549_568.0).contains(&value) {
+        let hex = format!("{:#x}", value as u64);
+        if hex.len() < result.len() {
+            result = hex;
         }
     }
 
@@ -1621,10 +1625,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PrivateInExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for LogicalExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         // Logical expressions and coalesce expressions cannot be mixed (Syntax Error).
-        let mixed = matches!(
-            (precedence, self.precedence()),
-            (Precedence::Coalesce, Precedence::LogicalAnd | Precedence::LogicalOr)
-        );
+        let mixed = is_mixed_coalesce(precedence, self.precedence());
         p.wrap(mixed || (precedence > self.precedence()), |p| {
             self.left.gen_expr(p, self.precedence(), ctx);
             p.print_soft_space();
@@ -1899,6 +1900,44 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for AwaitExpression<'a> {
     }
 }
 
+impl<'a, const MINIFY: bool> GenExpr<MINIFY> for PipelineExpression<'a> {
+    fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
+        p.wrap(precedence > self.precedence(), |p| {
+            self.left.gen_expr(p, self.precedence(), ctx);
+            p.print_soft_space();
+            p.print_str(b"|>");
+            p.print_soft_space();
+            self.right.gen_expr(p, Precedence::Coalesce, ctx);
+        });
+    }
+}
+
+impl<'a, const MINIFY: bool> Gen<MINIFY> for DoExpression<'a> {
+    fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        // `do` at the start of a statement is a do-while statement
+        let n = p.code_len();
+        p.wrap(p.start_of_stmt == n, |p| {
+            p.print_str(b"do");
+            p.print_soft_space();
+            p.print_block1(&self.body, ctx);
+        });
+    }
+}
+
+impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ThrowExpression<'a> {
+    fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
+        p.wrap(precedence > self.precedence(), |p| {
+            p.print_str(b"throw ");
+            self.argument.gen_expr(p, self.precedence(), ctx);
+        });
+    }
+}
+
+/// A missing expression is still missing
+impl<const MINIFY: bool> Gen<MINIFY> for ErrorExpression {
+    fn gen(&self, _p: &mut Codegen<{ MINIFY }>, _ctx: Context) {}
+}
+
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ChainExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         match &self.expression {
@@ -19,7 +19,7 @@ use std::str::from_utf8_unchecked;
 use oxc_ast::ast::*;
 use oxc_span::Atom;
 use oxc_syntax::{
-    identifier::is_identifier_part,
+    identifier::{is_asi_hazard_start, is_identifier_part},
     operator::{BinaryOperator, UnaryOperator, UpdateOperator},
     precedence::Precedence,
     symbol::SymbolId,
@@ -35,6 +35,18 @@ pub use crate::{
 #[derive(Debug, Default, Clone, Copy)]
 pub struct CodegenOptions {
     pub enable_typescript: bool,
+
+    /// Print output which is safe to be naively concatenated with other scripts, for bundler chunks:
+    /// * the hashbang is removed
+    /// * a leading `;` is printed if the first statement would continue the last statement of a previous script
+    /// * the last statement is always terminated by a `;`
+    pub concatenation_safe: bool,
+
+    /// Wrap the output in an immediately invoked arrow function `(() => { ... })();`,
+    /// so top level declarations and directives do not leak into other scripts.
+    ///
+    /// Ignored for programs with import or export declarations.
+    pub wrap_iife: bool,
 }
 
 pub struct Codegen<const MINIFY: bool> {
@@ -96,10 +108,63 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
     // }
 
     pub fn build(mut self, program: &Program<'_>) -> String {
-        program.gen(&mut self, Context::default());
+        if self.options.concatenation_safe || self.options.wrap_iife {
+            self.print_wrapped_program(program);
+        } else {
+            program.gen(&mut self, Context::default());
+        }
         self.into_code()
     }
 
+    fn print_wrapped_program(&mut self, program: &Program<'_>) {
+        let ctx = Context::default();
+        let wrap_iife = self.options.wrap_iife
+            && !program.body.iter().any(|stmt| matches!(stmt, Statement::ModuleDeclaration(_)));
+
+        // A hashbang is only valid at the start of a file
+        if !self.options.concatenation_safe {
+            if let Some(hashbang) = &program.hashbang {
+                hashbang.gen(self, ctx);
+                self.print(b'\n');
+            }
+        }
+        let start = self.code_len();
+
+        if wrap_iife {
+            self.print_str(b"(()");
+            self.print_soft_space();
+            self.print_str(b"=>");
+            self.print_soft_space();
+            self.print(b'{');
+            self.print_soft_newline();
+            self.indent();
+            self.print_directives_and_statements_with_semicolon_order(
+                Some(&program.directives),
+                &program.body,
+                ctx,
+                false,
+            );
+            self.dedent();
+            self.print_str(b"})();");
+            self.print_soft_newline();
+        } else {
+            self.print_directives_and_statements_with_semicolon_order(
+                Some(&program.directives),
+                &program.body,
+                ctx,
+                false,
+            );
+        }
+
+        if self.options.concatenation_safe {
+            self.print_semicolon_if_needed();
+            // `a \n (b)` is a call expression
+            if self.code.get(start).is_some_and(|&ch| is_asi_hazard_start(char::from(ch))) {
+                self.code.insert(start, b';');
+            }
+        }
+    }
+
     pub fn into_code(self) -> String {
         // SAFETY: criteria of `from_utf8_unchecked`.are met.
         unsafe { String::from_utf8_unchecked(self.code) }
@@ -208,19 +273,6 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.print(b'=');
     }
 
-    fn print_sequence<T: Gen<MINIFY>>(&mut self, items: &[T], separator: Separator, ctx: Context) {
-        let len = items.len();
-        for (index, item) in items.iter().enumerate() {
-            item.gen(self, ctx);
-            match separator {
-                Separator::Semicolon => self.print_semicolon(),
-                Separator::Comma => self.print(b','),
-                Separator::None => {}
-            }
-            if index != len - 1 {}
-        }
-    }
-
     fn print_block_start(&mut self) {
         self.print(b'{');
         self.print_soft_newline();
@@ -240,12 +292,6 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.needs_semicolon = false;
     }
 
-    fn print_block<T: Gen<MINIFY>>(&mut self, items: &[T], separator: Separator, ctx: Context) {
-        self.print_block_start();
-        self.print_sequence(items, separator, ctx);
-        self.print_block_end();
-    }
-
     fn print_list<T: Gen<MINIFY>>(&mut self, items: &[T], ctx: Context) {
         for (index, item) in items.iter().enumerate() {
             if index != 0 {
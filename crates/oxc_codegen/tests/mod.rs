@@ -20,9 +20,19 @@ fn test_ts(source_text: &str, expected: &str, is_typescript_definition: bool) {
         .with_module(true);
     let program = Parser::new(&allocator, source_text, source_type).parse().program;
     let program = allocator.alloc(program);
-    let result =
-        Codegen::<false>::new(source_text.len(), CodegenOptions { enable_typescript: true })
-            .build(program);
+    let options = CodegenOptions { enable_typescript: true, ..CodegenOptions::default() };
+    let result = Codegen::<false>::new(source_text.len(), options).build(program);
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+fn test_concatenation_safe<const MINIFY: bool>(source_text: &str, wrap_iife: bool, expected: &str) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let options =
+        CodegenOptions { concatenation_safe: true, wrap_iife, ..CodegenOptions::default() };
+    let result = Codegen::<MINIFY>::new(source_text.len(), options).build(program);
     assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
 }
 
@@ -115,12 +125,89 @@ fn template() {
     test("(new tag)`${x}`", "new tag()`${x}`;\n");
 }
 
+#[test]
+fn number() {
+    test("1.50", "1.50;\n");
+    test("1e21", "1e21;\n");
+    test("0xFF", "0xFF;\n");
+    test("1_000_000", "1_000_000;\n");
+    test(".10", ".10;\n");
+}
+
+#[test]
+fn changed_number() {
+    use oxc_ast::ast::{Expression, Statement};
+
+    let source_text = "1.50";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let Some(Statement::ExpressionStatement(stmt)) = program.body.first_mut() else {
+        unreachable!()
+    };
+    let Expression::NumericLiteral(literal) = &mut stmt.expression else { unreachable!() };
+    literal.value = 2.5;
+    let result = Codegen::<false>::new(source_text.len(), CodegenOptions::default()).build(program);
+    assert_eq!(result, "2.5;\n");
+}
+
+#[test]
+fn built_number() {
+    use oxc_ast::AstBuilder;
+    use oxc_span::SPAN;
+    use oxc_syntax::NumberBase;
+
+    // Literals created by transforms are printed from their raw text only if it has their value
+    let print = |value: f64, raw: &'static str, base: NumberBase| {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let literal = ast.literal_number_expression(ast.number_literal(SPAN, value, raw, base));
+        let body = ast.new_vec_single(ast.expression_statement(SPAN, literal));
+        let program = ast.program(SPAN, SourceType::default(), ast.new_vec(), None, body);
+        Codegen::<false>::new(0, CodegenOptions::default()).build(&program)
+    };
+    assert_eq!(print(10.0, "10", NumberBase::Decimal), "10;\n");
+    assert_eq!(print(255.0, "0xFF", NumberBase::Hex), "0xFF;\n");
+    assert_eq!(print(10.0, "1", NumberBase::Decimal), "10;\n");
+    assert_eq!(print(2.5, "", NumberBase::Float), "2.5;\n");
+}
+
 #[test]
 fn module_decl() {
     test("export * as foo from 'foo'", "export * as foo from 'foo';\n");
-    test("import x from './foo.js' with {}", "import x from './foo.js' with {\n};\n");
-    test("import {} from './foo.js' with {}", "import './foo.js' with {\n};\n");
-    test("export * from './foo.js' with {}", "export * from './foo.js' with {\n};\n");
+    test("import x from './foo.js' with {}", "import x from './foo.js' with {};\n");
+    test("import {} from './foo.js' with {}", "import './foo.js' with {};\n");
+    test("export * from './foo.js' with {}", "export * from './foo.js' with {};\n");
+    test(
+        "import x from './foo.json' with { type: 'json' }",
+        "import x from './foo.json' with { type: 'json' };\n",
+    );
+    test(
+        "export { x } from './foo.json' with { type: 'json' }",
+        "export { x } from './foo.json' with { type: 'json' };\n",
+    );
+    test(
+        "export * as ns from './foo.json' with { 'type': 'json' }",
+        "export * as ns from './foo.json' with { 'type': 'json' };\n",
+    );
+    test("import source x from './foo.wasm'", "import source x from './foo.wasm';\n");
+    test("import defer * as ns from './foo.js'", "import defer * as ns from './foo.js';\n");
+}
+
+#[test]
+fn concatenation_safe() {
+    test_concatenation_safe::<true>("foo(); bar()", false, "foo();bar();");
+    test_concatenation_safe::<true>("a = function() {}", false, "a=function(){};");
+    test_concatenation_safe::<true>("(a || b).c()", false, ";(a||b).c();");
+    test_concatenation_safe::<true>("[1, 2].forEach(f)", false, ";[1,2].forEach(f);");
+    test_concatenation_safe::<true>("`a`", false, ";`a`;");
+    test_concatenation_safe::<true>("-a", false, ";-a;");
+    test_concatenation_safe::<true>("#!/usr/bin/env node\nfoo()", false, "foo();");
+    test_concatenation_safe::<true>("var a = 1; a++", true, ";(()=>{var a=1;a++;})();");
+    test_concatenation_safe::<true>("", true, ";(()=>{})();");
+    test_concatenation_safe::<true>("export var a = 1", true, "export var a=1;");
+    test_concatenation_safe::<false>("foo()", true, ";(() => {\n\tfoo();\n})();\n");
+    test_concatenation_safe::<false>("(a || b).c()", false, ";(a || b).c();\n");
 }
 
 #[test]
@@ -128,6 +215,16 @@ fn new_expr() {
     test("new (foo()).bar();", "new (foo()).bar();\n");
 }
 
+#[test]
+fn proposals() {
+    test("x |> f |> g", "x |> f |> g;\n");
+    test("x |> (f |> g)", "x |> (f |> g);\n");
+    test("(x |> f) ?? y", "(x |> f) ?? y;\n");
+    test("x = do { 1 }", "x = do {\n\t1;\n};\n");
+    test("(do { 1 })", "(do {\n\t1;\n});\n");
+    test("f = () => throw new Error()", "f = () => throw new Error();\n");
+}
+
 #[test]
 fn typescript() {
     test_ts("let x: string = `\\x01`;", "let x: string = `\\x01`;\n", false);
@@ -0,0 +1,35 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Shared flag for abandoning an analysis which is no longer needed,
+/// e.g. when the language server receives a newer edit of the same file.
+///
+/// Clones share the same flag. The parser, semantic builder and linter
+/// check it at statement boundaries and stop early with [`Cancelled`].
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of all work holding this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Analysis was cancelled")]
+#[diagnostic()]
+pub struct Cancelled;
@@ -60,7 +60,7 @@ impl GraphicalReportHandler {
         }
     }
 
-    /// Set the displayed tab width in spaces.
+    /// Set the displayed tab width in spaces, tabs extend to the next multiple of it.
     pub fn tab_width(mut self, width: usize) -> Self {
         self.tab_width = width;
         self
@@ -582,18 +582,31 @@ impl GraphicalReportHandler {
         Ok(())
     }
 
-    /// Returns an iterator over the visual width of each character in a line.
+    /// Returns an iterator over the visual width of each character in a line,
+    /// i.e. the number of terminal columns it takes.
+    ///
+    /// Wide characters such as CJK take 2 columns. The characters of an emoji
+    /// sequence take the 2 columns of the emoji they are joined into.
     fn line_visual_char_width<'a>(&self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
         let mut column = 0;
-        let tab_width = self.tab_width;
+        let mut prev = None;
+        let mut prev_width = 0;
+        let tab_width = self.tab_width.max(1);
         text.chars().map(move |c| {
-            let width = if c == '\t' {
+            let width = match (prev, c) {
                 // Round up to the next multiple of tab_width
-                tab_width - column % tab_width
-            } else {
-                c.width().unwrap_or(0)
+                (_, '\t') => tab_width - column % tab_width,
+                // Joined into the emoji before by a zero width joiner, e.g. `👨‍👩‍👧`
+                (Some('\u{200D}'), _) => 0,
+                // The emoji presentation selector widens the symbol before, e.g. `❤️`
+                (Some(_), '\u{FE0F}') if prev_width == 1 => 1,
+                // Skin tone modifier of the emoji before, e.g. `👍🏽`
+                (Some(_), '\u{1F3FB}'..='\u{1F3FF}') if prev_width == 2 => 0,
+                _ => c.width().unwrap_or(0),
             };
             column += width;
+            prev = Some(c);
+            prev_width = width;
             width
         })
     }
@@ -604,7 +617,12 @@ impl GraphicalReportHandler {
         assert!(line_range.contains(&offset));
 
         let text_index = offset - line.offset;
-        let text = &line.text[..text_index.min(line.text.len())];
+        // A span starting or ending inside a character covers all of it
+        let mut end = text_index.min(line.text.len());
+        while !line.text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = &line.text[..end];
         let text_width = self.line_visual_char_width(text).sum();
         if text_index > line.text.len() {
             // Spans extending past the end of the line are always rendered as
@@ -883,3 +901,42 @@ impl FancySpan {
         self.span.len()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use miette::{Diagnostic, SourceSpan};
+    use thiserror::Error;
+
+    use super::{GraphicalReportHandler, GraphicalTheme};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("test")]
+    struct TestDiagnostic {
+        #[source_code]
+        source_code: String,
+        #[label("here")]
+        span: SourceSpan,
+    }
+
+    #[test]
+    fn unicode_width() {
+        let source_code = "let 中文 = '👨‍👩‍👧❤️👍🏽';\ta = x;";
+        let diagnostic = TestDiagnostic {
+            source_code: source_code.into(),
+            span: (source_code.find('x').unwrap(), 1).into(),
+        };
+        let handler = GraphicalReportHandler::new()
+            .with_theme(GraphicalTheme::unicode_nocolor())
+            .tab_width(4);
+        let mut output = String::new();
+        handler.render_report(&mut output, &diagnostic).unwrap();
+
+        let lines = output.lines().collect::<Vec<_>>();
+        let line = lines.iter().position(|line| line.ends_with("a = x;")).unwrap();
+        let (_, text) = lines[line].split_once("│ ").unwrap();
+        let (_, underline) = lines[line + 1].split_once("· ").unwrap();
+        // `中文` and each emoji take 2 columns, the tab at column 20 takes 4
+        assert_eq!(text, "let 中文 = '👨‍👩‍👧❤️👍🏽';    a = x;");
+        assert_eq!(underline, format!("{}┬", " ".repeat(28)));
+    }
+}
@@ -1,6 +1,7 @@
 //! Diagnostics Wrapper
 //! Exports `thiserror` and `miette`
 
+mod cancellation;
 mod graphic_reporter;
 mod graphical_theme;
 mod reporter;
@@ -8,6 +9,7 @@ mod service;
 
 use std::path::PathBuf;
 
+pub use crate::cancellation::{CancellationToken, Cancelled};
 pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
@@ -58,6 +58,15 @@ impl DiagnosticService {
         self
     }
 
+    /// Number of columns of a tab in the source code shown by the graphical reporter, 4 by default
+    #[must_use]
+    pub fn with_tab_width(mut self, width: usize) -> Self {
+        if let DiagnosticReporter::Graphical { handler, .. } = &mut self.reporter {
+            *handler = handler.clone().tab_width(width);
+        }
+        self
+    }
+
     #[must_use]
     pub fn with_max_warnings(mut self, max_warnings: Option<usize>) -> Self {
         self.max_warnings = max_warnings;
@@ -0,0 +1,407 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
+use oxc_ast::ast::{
+    BigintLiteral, BinaryExpression, Expression, IdentifierReference, LogicalExpression,
+    TemplateLiteral, UnaryExpression,
+};
+use oxc_syntax::{
+    operator::{BinaryOperator, LogicalOperator, UnaryOperator},
+    BigintBase,
+};
+
+use crate::{
+    equality::{is_loosely_equal, is_strictly_equal},
+    number::{string_to_big_int, to_int_32, to_uint_32},
+    ConstantValue,
+};
+
+/// Evaluates expressions to primitive values at compile time.
+///
+/// The evaluation does not consider whether an expression has side effects,
+/// e.g. `void foo()` evaluates to `undefined` and `[foo()]` evaluates to `true` in a boolean context.
+/// Callers which remove the expression need to check for side effects themselves.
+pub trait ConstantEvaluation<'a> {
+    /// Whether the identifier reference resolves to a global binding,
+    /// i.e. `undefined`, `NaN` and `Infinity` are not shadowed by a local declaration.
+    fn is_global_reference(&self, ident: &IdentifierReference) -> bool;
+
+    /// Value of an identifier which is replaced at compile time, e.g. a flag of conditional compilation.
+    fn eval_defined_identifier(
+        &self,
+        _ident: &IdentifierReference,
+    ) -> Option<ConstantValue<'static>> {
+        None
+    }
+
+    /// `ToBoolean` of the expression.
+    fn eval_to_boolean(&self, expr: &Expression<'a>) -> Option<bool> {
+        match expr {
+            // Objects are always truthy.
+            Expression::ArrayExpression(_)
+            | Expression::ObjectExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::NewExpression(_) => Some(true),
+            Expression::ParenthesizedExpression(paren) => self.eval_to_boolean(&paren.expression),
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                self.eval_to_boolean(&unary.argument).map(|b| !b)
+            }
+            Expression::LogicalExpression(logical) => {
+                let left = self.eval_to_boolean(&logical.left);
+                match logical.operator {
+                    LogicalOperator::And => {
+                        if left? {
+                            self.eval_to_boolean(&logical.right)
+                        } else {
+                            Some(false)
+                        }
+                    }
+                    LogicalOperator::Or => {
+                        if left? {
+                            Some(true)
+                        } else {
+                            self.eval_to_boolean(&logical.right)
+                        }
+                    }
+                    LogicalOperator::Coalesce => {
+                        self.eval_expression(expr).map(|value| value.to_boolean())
+                    }
+                }
+            }
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.last().and_then(|last| self.eval_to_boolean(last))
+            }
+            _ => self.eval_expression(expr).map(|value| value.to_boolean()),
+        }
+    }
+
+    /// `ToNumber` of the expression.
+    fn eval_to_number(&self, expr: &Expression<'a>) -> Option<f64> {
+        self.eval_expression(expr)?.to_number()
+    }
+
+    /// `ToString` of the expression.
+    fn eval_to_string<'b>(&self, expr: &'b Expression<'a>) -> Option<Cow<'b, str>> {
+        self.eval_expression(expr).map(|value| value.to_js_string())
+    }
+
+    /// `typeof` of the expression.
+    fn eval_type_of(&self, expr: &Expression<'a>) -> Option<&'static str> {
+        match expr {
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => Some("function"),
+            Expression::ArrayExpression(_)
+            | Expression::ObjectExpression(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::NewExpression(_) => Some("object"),
+            Expression::ParenthesizedExpression(paren) => self.eval_type_of(&paren.expression),
+            _ => self.eval_expression(expr).map(|value| value.type_of()),
+        }
+    }
+
+    /// Evaluates the expression to a primitive value, returns `None` if it cannot be determined
+    /// statically or if the evaluation would throw.
+    fn eval_expression<'b>(&self, expr: &'b Expression<'a>) -> Option<ConstantValue<'b>> {
+        match expr {
+            Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+            Expression::StringLiteral(lit) => {
+                Some(ConstantValue::String(Cow::Borrowed(lit.value.as_str())))
+            }
+            Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(ConstantValue::Null),
+            Expression::BigintLiteral(lit) => eval_big_int_literal(lit).map(ConstantValue::BigInt),
+            Expression::TemplateLiteral(lit) => self.eval_template_literal(lit),
+            Expression::Identifier(ident) => {
+                if let Some(value) = self.eval_defined_identifier(ident) {
+                    return Some(value);
+                }
+                match ident.name.as_str() {
+                    "undefined" if self.is_global_reference(ident) => {
+                        Some(ConstantValue::Undefined)
+                    }
+                    "NaN" if self.is_global_reference(ident) => {
+                        Some(ConstantValue::Number(f64::NAN))
+                    }
+                    "Infinity" if self.is_global_reference(ident) => {
+                        Some(ConstantValue::Number(f64::INFINITY))
+                    }
+                    _ => None,
+                }
+            }
+            Expression::ParenthesizedExpression(paren) => self.eval_expression(&paren.expression),
+            Expression::UnaryExpression(unary) => self.eval_unary_expression(unary),
+            Expression::BinaryExpression(binary) => self.eval_binary_expression(binary),
+            Expression::LogicalExpression(logical) => self.eval_logical_expression(logical),
+            Expression::ConditionalExpression(conditional) => {
+                if self.eval_to_boolean(&conditional.test)? {
+                    self.eval_expression(&conditional.consequent)
+                } else {
+                    self.eval_expression(&conditional.alternate)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn eval_template_literal<'b>(&self, lit: &'b TemplateLiteral<'a>) -> Option<ConstantValue<'b>> {
+        if lit.expressions.is_empty() {
+            let cooked = lit.quasis.first()?.value.cooked.as_ref()?;
+            return Some(ConstantValue::String(Cow::Borrowed(cooked.as_str())));
+        }
+        let mut result = String::new();
+        for (i, quasi) in lit.quasis.iter().enumerate() {
+            result.push_str(quasi.value.cooked.as_ref()?.as_str());
+            if let Some(expr) = lit.expressions.get(i) {
+                result.push_str(&self.eval_to_string(expr)?);
+            }
+        }
+        Some(ConstantValue::String(Cow::Owned(result)))
+    }
+
+    fn eval_unary_expression<'b>(
+        &self,
+        unary: &'b UnaryExpression<'a>,
+    ) -> Option<ConstantValue<'b>> {
+        match unary.operator {
+            UnaryOperator::Void => {
+                // Only fold `void` when the argument is a known value.
+                self.eval_expression(&unary.argument).map(|_| ConstantValue::Undefined)
+            }
+            UnaryOperator::LogicalNot => {
+                self.eval_to_boolean(&unary.argument).map(|b| ConstantValue::Boolean(!b))
+            }
+            UnaryOperator::Typeof => {
+                self.eval_type_of(&unary.argument).map(|s| ConstantValue::String(Cow::Borrowed(s)))
+            }
+            UnaryOperator::UnaryPlus => {
+                self.eval_to_number(&unary.argument).map(ConstantValue::Number)
+            }
+            UnaryOperator::UnaryNegation => {
+                match self.eval_expression(&unary.argument)?.to_numeric() {
+                    ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(-n)),
+                    ConstantValue::Number(n) => Some(ConstantValue::Number(-n)),
+                    _ => None,
+                }
+            }
+            UnaryOperator::BitwiseNot => {
+                match self.eval_expression(&unary.argument)?.to_numeric() {
+                    ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(!n)),
+                    ConstantValue::Number(n) => {
+                        Some(ConstantValue::Number(f64::from(!to_int_32(n))))
+                    }
+                    _ => None,
+                }
+            }
+            UnaryOperator::Delete => None,
+        }
+    }
+
+    fn eval_logical_expression<'b>(
+        &self,
+        logical: &'b LogicalExpression<'a>,
+    ) -> Option<ConstantValue<'b>> {
+        match logical.operator {
+            LogicalOperator::And => {
+                if self.eval_to_boolean(&logical.left)? {
+                    self.eval_expression(&logical.right)
+                } else {
+                    // falsy values are always primitives
+                    self.eval_expression(&logical.left)
+                }
+            }
+            LogicalOperator::Or => {
+                if self.eval_to_boolean(&logical.left)? {
+                    self.eval_expression(&logical.left)
+                } else {
+                    self.eval_expression(&logical.right)
+                }
+            }
+            LogicalOperator::Coalesce => {
+                let left = self.eval_expression(&logical.left)?;
+                if left.is_nullish() {
+                    self.eval_expression(&logical.right)
+                } else {
+                    Some(left)
+                }
+            }
+        }
+    }
+
+    fn eval_binary_expression<'b>(
+        &self,
+        binary: &'b BinaryExpression<'a>,
+    ) -> Option<ConstantValue<'b>> {
+        let operator = binary.operator;
+        if matches!(operator, BinaryOperator::In | BinaryOperator::Instanceof) {
+            return None;
+        }
+        let left = self.eval_expression(&binary.left)?;
+        let right = self.eval_expression(&binary.right)?;
+
+        let value = match operator {
+            BinaryOperator::Equality => ConstantValue::Boolean(is_loosely_equal(&left, &right)),
+            BinaryOperator::Inequality => ConstantValue::Boolean(!is_loosely_equal(&left, &right)),
+            BinaryOperator::StrictEquality => {
+                ConstantValue::Boolean(is_strictly_equal(&left, &right))
+            }
+            BinaryOperator::StrictInequality => {
+                ConstantValue::Boolean(!is_strictly_equal(&left, &right))
+            }
+            BinaryOperator::LessThan => {
+                ConstantValue::Boolean(compare(&left, &right)? == Some(Ordering::Less))
+            }
+            BinaryOperator::GreaterThan => {
+                ConstantValue::Boolean(compare(&left, &right)? == Some(Ordering::Greater))
+            }
+            BinaryOperator::LessEqualThan => ConstantValue::Boolean(matches!(
+                compare(&left, &right)?,
+                Some(Ordering::Less | Ordering::Equal)
+            )),
+            BinaryOperator::GreaterEqualThan => ConstantValue::Boolean(matches!(
+                compare(&left, &right)?,
+                Some(Ordering::Greater | Ordering::Equal)
+            )),
+            BinaryOperator::Addition => {
+                // Both operands are primitives, so `ToPrimitive` is the identity.
+                if left.is_string() || right.is_string() {
+                    let mut s = left.to_js_string().into_owned();
+                    s.push_str(&right.to_js_string());
+                    ConstantValue::String(Cow::Owned(s))
+                } else {
+                    match (left.to_numeric(), right.to_numeric()) {
+                        (ConstantValue::Number(l), ConstantValue::Number(r)) => {
+                            ConstantValue::Number(l + r)
+                        }
+                        (ConstantValue::BigInt(l), ConstantValue::BigInt(r)) => {
+                            ConstantValue::BigInt(l + r)
+                        }
+                        // Mixing BigInt and Number throws a TypeError.
+                        _ => return None,
+                    }
+                }
+            }
+            _ => match (left.to_numeric(), right.to_numeric()) {
+                (ConstantValue::Number(l), ConstantValue::Number(r)) => {
+                    ConstantValue::Number(match operator {
+                        BinaryOperator::Subtraction => l - r,
+                        BinaryOperator::Multiplication => l * r,
+                        BinaryOperator::Division => l / r,
+                        // `%` on f64 truncates towards zero, which is the same as the specification.
+                        BinaryOperator::Remainder => l % r,
+                        BinaryOperator::Exponential => exponentiate(l, r),
+                        BinaryOperator::ShiftLeft => {
+                            f64::from(to_int_32(l).wrapping_shl(to_uint_32(r) & 31))
+                        }
+                        BinaryOperator::ShiftRight => {
+                            f64::from(to_int_32(l).wrapping_shr(to_uint_32(r) & 31))
+                        }
+                        BinaryOperator::ShiftRightZeroFill => {
+                            f64::from(to_uint_32(l).wrapping_shr(to_uint_32(r) & 31))
+                        }
+                        BinaryOperator::BitwiseOR => f64::from(to_int_32(l) | to_int_32(r)),
+                        BinaryOperator::BitwiseXOR => f64::from(to_int_32(l) ^ to_int_32(r)),
+                        BinaryOperator::BitwiseAnd => f64::from(to_int_32(l) & to_int_32(r)),
+                        _ => return None,
+                    })
+                }
+                (ConstantValue::BigInt(l), ConstantValue::BigInt(r)) => {
+                    ConstantValue::BigInt(match operator {
+                        BinaryOperator::Subtraction => l - r,
+                        BinaryOperator::Multiplication => l * r,
+                        // Division by zero throws a RangeError.
+                        BinaryOperator::Division if !r.is_zero() => l / r,
+                        BinaryOperator::Remainder if !r.is_zero() => l % r,
+                        // A negative exponent throws a RangeError.
+                        BinaryOperator::Exponential if !r.is_negative() => l.pow(r.to_u32()?),
+                        BinaryOperator::BitwiseOR => l | r,
+                        BinaryOperator::BitwiseXOR => l ^ r,
+                        BinaryOperator::BitwiseAnd => l & r,
+                        _ => return None,
+                    })
+                }
+                // Mixing BigInt and Number throws a TypeError.
+                _ => return None,
+            },
+        };
+        Some(value)
+    }
+}
+
+/// `Number::exponentiate ( base, exponent )`
+///
+/// Differs from [f64::powf] for `1 ** NaN` and `(±1) ** ±Infinity`, which are `NaN` in JavaScript.
+///
+/// <https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate>
+#[allow(clippy::float_cmp)]
+fn exponentiate(base: f64, exponent: f64) -> f64 {
+    if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+        return f64::NAN;
+    }
+    base.powf(exponent)
+}
+
+/// `IsLessThan ( x, y, LeftFirst )` for primitives, as an ordering.
+///
+/// Returns `Some(None)` where the specification returns `undefined` (a `NaN` operand),
+/// and `None` when the comparison cannot be evaluated statically.
+///
+/// <https://tc39.es/ecma262/#sec-islessthan>
+#[allow(clippy::option_option)]
+fn compare(x: &ConstantValue, y: &ConstantValue) -> Option<Option<Ordering>> {
+    match (x, y) {
+        // Strings are compared by UTF-16 code units.
+        (ConstantValue::String(x), ConstantValue::String(y)) => {
+            Some(Some(x.encode_utf16().cmp(y.encode_utf16())))
+        }
+        (ConstantValue::BigInt(x), ConstantValue::String(y)) => {
+            Some(string_to_big_int(y).map(|y| x.cmp(&y)))
+        }
+        (ConstantValue::String(x), ConstantValue::BigInt(y)) => {
+            Some(string_to_big_int(x).map(|x| x.cmp(y)))
+        }
+        _ => match (x.to_numeric(), y.to_numeric()) {
+            (ConstantValue::Number(x), ConstantValue::Number(y)) => Some(x.partial_cmp(&y)),
+            (ConstantValue::BigInt(x), ConstantValue::BigInt(y)) => Some(Some(x.cmp(&y))),
+            (ConstantValue::BigInt(x), ConstantValue::Number(y)) => {
+                Some(compare_big_int_to_number(&x, y))
+            }
+            (ConstantValue::Number(x), ConstantValue::BigInt(y)) => {
+                Some(compare_big_int_to_number(&y, x).map(Ordering::reverse))
+            }
+            _ => None,
+        },
+    }
+}
+
+fn compare_big_int_to_number(x: &BigInt, y: f64) -> Option<Ordering> {
+    if y.is_nan() {
+        return None;
+    }
+    if y.is_infinite() {
+        return Some(if y > 0.0 { Ordering::Less } else { Ordering::Greater });
+    }
+    // Compare against the integer part first, then break ties with the fractional part.
+    let integer = BigInt::from_f64(y.trunc())?;
+    match x.cmp(&integer) {
+        Ordering::Equal if y.fract() > 0.0 => Some(Ordering::Less),
+        Ordering::Equal if y.fract() < 0.0 => Some(Ordering::Greater),
+        ordering => Some(ordering),
+    }
+}
+
+fn eval_big_int_literal(lit: &BigintLiteral) -> Option<BigInt> {
+    let raw = lit.raw.as_str().strip_suffix('n').unwrap_or(lit.raw.as_str());
+    let raw = raw.replace('_', "");
+    let (digits, radix) = match lit.base {
+        BigintBase::Decimal => (raw.as_str(), 10),
+        BigintBase::Binary => (&raw[2..], 2),
+        BigintBase::Octal => (&raw[2..], 8),
+        BigintBase::Hex => (&raw[2..], 16),
+    };
+    BigInt::parse_bytes(digits.as_bytes(), radix)
+}
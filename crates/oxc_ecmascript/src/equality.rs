@@ -0,0 +1,63 @@
+use num_bigint::BigInt;
+use num_traits::FromPrimitive;
+
+use crate::{number::string_to_big_int, ConstantValue};
+
+/// `IsStrictlyEqual ( x, y )`, the `===` operator
+///
+/// <https://tc39.es/ecma262/#sec-isstrictlyequal>
+#[allow(clippy::float_cmp)]
+pub fn is_strictly_equal(x: &ConstantValue, y: &ConstantValue) -> bool {
+    match (x, y) {
+        // NaN is not equal to NaN, +0 is equal to -0.
+        (ConstantValue::Number(x), ConstantValue::Number(y)) => x == y,
+        (ConstantValue::BigInt(x), ConstantValue::BigInt(y)) => x == y,
+        (ConstantValue::String(x), ConstantValue::String(y)) => x == y,
+        (ConstantValue::Boolean(x), ConstantValue::Boolean(y)) => x == y,
+        (ConstantValue::Null, ConstantValue::Null)
+        | (ConstantValue::Undefined, ConstantValue::Undefined) => true,
+        _ => false,
+    }
+}
+
+/// `IsLooselyEqual ( x, y )`, the `==` operator
+///
+/// <https://tc39.es/ecma262/#sec-islooselyequal>
+pub fn is_loosely_equal(x: &ConstantValue, y: &ConstantValue) -> bool {
+    match (x, y) {
+        // 2. If x is null and y is undefined, return true.
+        // 3. If x is undefined and y is null, return true.
+        (ConstantValue::Null | ConstantValue::Undefined, _)
+        | (_, ConstantValue::Null | ConstantValue::Undefined) => x.is_nullish() && y.is_nullish(),
+        // 5. If x is a Number and y is a String, return ! IsLooselyEqual(x, ! ToNumber(y)).
+        // 6. If x is a String and y is a Number, return ! IsLooselyEqual(! ToNumber(x), y).
+        (ConstantValue::Number(_), ConstantValue::String(_))
+        | (ConstantValue::String(_), ConstantValue::Number(_)) => {
+            is_strictly_equal(&x.to_numeric(), &y.to_numeric())
+        }
+        // 7. If x is a BigInt and y is a String, then
+        //   a. Let n be StringToBigInt(y).
+        //   b. If n is undefined, return false.
+        (ConstantValue::BigInt(n), ConstantValue::String(s))
+        | (ConstantValue::String(s), ConstantValue::BigInt(n)) => {
+            string_to_big_int(s).is_some_and(|m| *n == m)
+        }
+        // 9. If x is a Boolean, return ! IsLooselyEqual(! ToNumber(x), y).
+        // 10. If y is a Boolean, return ! IsLooselyEqual(x, ! ToNumber(y)).
+        (ConstantValue::Boolean(b), other) | (other, ConstantValue::Boolean(b))
+            if !matches!(other, ConstantValue::Boolean(_)) =>
+        {
+            let n = ConstantValue::Number(if *b { 1.0 } else { 0.0 });
+            is_loosely_equal(&n, other)
+        }
+        // 13. If x is a BigInt and y is a Number, or if x is a Number and y is a BigInt, then
+        //   a. If x is not finite or y is not finite, return false.
+        //   b. If ℝ(x) = ℝ(y), return true; otherwise return false.
+        (ConstantValue::BigInt(n), ConstantValue::Number(m))
+        | (ConstantValue::Number(m), ConstantValue::BigInt(n)) => {
+            m.is_finite() && m.fract() == 0.0 && BigInt::from_f64(*m).is_some_and(|m| *n == m)
+        }
+        // 1. If Type(x) is Type(y), return IsStrictlyEqual(x, y).
+        _ => is_strictly_equal(x, y),
+    }
+}
@@ -0,0 +1,25 @@
+//! ECMAScript abstract operations
+//!
+//! Implements the type conversions and comparisons from the specification
+//! (`ToNumber`, `ToString`, `ToBoolean`, `IsLooselyEqual`, `IsStrictlyEqual`, `Number::toString`)
+//! so that constant folding in the minifier, constant checks in the linter
+//! and conditional compilation in the transformer agree on the semantics.
+//!
+//! References:
+//! * <https://tc39.es/ecma262/#sec-type-conversion>
+//! * <https://tc39.es/ecma262/#sec-testing-and-comparison-operations>
+
+mod constant_evaluation;
+mod equality;
+mod number;
+mod value;
+
+pub use crate::{
+    constant_evaluation::ConstantEvaluation,
+    equality::{is_loosely_equal, is_strictly_equal},
+    number::{
+        is_js_whitespace, number_to_string, shortest_digits, string_to_big_int, string_to_number,
+        to_int_32, to_uint_32,
+    },
+    value::ConstantValue,
+};
@@ -0,0 +1,243 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::{ToPrimitive, Zero};
+
+/// `StrWhiteSpaceChar`: WhiteSpace and LineTerminator
+///
+/// This differs from [char::is_whitespace], which includes U+0085 (NEL) and excludes U+FEFF (ZWNBSP).
+///
+/// <https://tc39.es/ecma262/#prod-StrWhiteSpaceChar>
+pub fn is_js_whitespace(c: char) -> bool {
+    match c {
+        '\u{feff}' => true,
+        '\u{85}' => false,
+        c => c.is_whitespace(),
+    }
+}
+
+/// `StringToNumber ( str )`
+///
+/// <https://tc39.es/ecma262/#sec-stringtonumber>
+pub fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_js_whitespace);
+
+    if s.is_empty() {
+        return 0.0;
+    }
+
+    // StrUnsignedDecimalLiteral ::: Infinity
+    match s {
+        "Infinity" | "+Infinity" => return f64::INFINITY,
+        "-Infinity" => return f64::NEG_INFINITY,
+        _ => {}
+    }
+
+    // NonDecimalIntegerLiteral, which does not allow a sign nor numeric separators
+    if s.len() > 2 && s.starts_with('0') {
+        let radix = match s.as_bytes()[1] {
+            b'x' | b'X' => Some(16),
+            b'o' | b'O' => Some(8),
+            b'b' | b'B' => Some(2),
+            _ => None,
+        };
+        if let Some(radix) = radix {
+            let digits = &s[2..];
+            if !digits.chars().all(|c| c.is_digit(radix)) {
+                return f64::NAN;
+            }
+            return BigUint::parse_bytes(digits.as_bytes(), radix)
+                .and_then(|value| value.to_f64())
+                .unwrap_or(f64::NAN);
+        }
+    }
+
+    if !is_str_decimal_literal(s) {
+        return f64::NAN;
+    }
+
+    // The syntax is validated above, Rust's parser rounds correctly.
+    s.parse::<f64>().unwrap_or(f64::NAN)
+}
+
+/// `StrDecimalLiteral` without `Infinity`
+///
+/// ```text
+/// [+-]? ( Digits ( . Digits? )? | . Digits ) ( [eE] [+-]? Digits )?
+/// ```
+fn is_str_decimal_literal(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        i += 1;
+    }
+
+    let integer_start = i;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    let mut has_digits = i > integer_start;
+
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        let fraction_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        has_digits |= i > fraction_start;
+    }
+
+    if !has_digits {
+        return false;
+    }
+
+    if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        let exponent_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == exponent_start {
+            return false;
+        }
+    }
+
+    i == bytes.len()
+}
+
+/// `Number::toString ( x )` with radix 10
+///
+/// <https://tc39.es/ecma262/#sec-numeric-types-number-tostring>
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn number_to_string(x: f64) -> String {
+    // 1. If x is NaN, return "NaN".
+    if x.is_nan() {
+        return "NaN".to_string();
+    }
+    // 2. If x is either +0 or -0, return "0".
+    if x == 0.0 {
+        return "0".to_string();
+    }
+    // 3. If x < -0, return the string-concatenation of "-" and Number::toString(-x, radix).
+    if x < 0.0 {
+        return format!("-{}", number_to_string(-x));
+    }
+    // 4. If x is +∞, return "Infinity".
+    if x.is_infinite() {
+        return "Infinity".to_string();
+    }
+
+    // 5. Let n, k, and s be integers such that k ≥ 1, 10^(k-1) ≤ s < 10^k,
+    // s × 10^(n-k) is x, and k is as small as possible.
+    let (digits, n) = shortest_digits(x);
+    // At most 17 digits round-trip an f64
+    let k = digits.len() as i32;
+
+    let mut result = String::with_capacity(digits.len() + 8);
+    if k <= n && n <= 21 {
+        // 6. the k digits of s followed by n - k occurrences of "0"
+        result.push_str(&digits);
+        result.extend(std::iter::repeat('0').take((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        // 7. the most significant n digits, ".", then the remaining k - n digits
+        let (integer, fraction) = digits.split_at(n as usize);
+        result.push_str(integer);
+        result.push('.');
+        result.push_str(fraction);
+    } else if -6 < n && n <= 0 {
+        // 8. "0.", -n occurrences of "0", then the k digits
+        result.push_str("0.");
+        result.extend(std::iter::repeat('0').take((-n) as usize));
+        result.push_str(&digits);
+    } else {
+        // 9 - 12. exponential notation
+        let exponent = n - 1;
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        result.push_str(first);
+        if !rest.is_empty() {
+            result.push('.');
+            result.push_str(rest);
+        }
+        result.push('e');
+        result.push(sign);
+        result.push_str(&exponent.abs().to_string());
+    }
+    result
+}
+
+/// Returns the shortest round-trip decimal digits `s` and the exponent `n` of a positive finite number,
+/// such that `x = 0.s × 10^n`.
+///
+/// This is the `s`, `k` (the length of `s`) and `n` of [Number::toString], used for printing numbers.
+///
+/// [Number::toString]: https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+pub fn shortest_digits(x: f64) -> (String, i32) {
+    debug_assert!(x.is_finite() && x > 0.0);
+    // Rust's `{:e}` formatting prints the shortest representation which round-trips, e.g. `1.2345e3`.
+    let formatted = format!("{x:e}");
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+    let digits = mantissa.replace('.', "");
+    (digits, exponent + 1)
+}
+
+/// `ToInt32 ( argument )` for a Number
+///
+/// <https://tc39.es/ecma262/#sec-toint32>
+#[allow(clippy::cast_possible_wrap)]
+pub fn to_int_32(x: f64) -> i32 {
+    to_uint_32(x) as i32
+}
+
+/// `ToUint32 ( argument )` for a Number
+///
+/// <https://tc39.es/ecma262/#sec-touint32>
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn to_uint_32(x: f64) -> u32 {
+    // 2. If number is not finite or number is either +0 or -0, return +0.
+    if !x.is_finite() || x == 0.0 {
+        return 0;
+    }
+    // 3. Let int be truncate(ℝ(number)).
+    // 4. Let int32bit be int modulo 2^32.
+    let int32bit = x.trunc().rem_euclid(4_294_967_296.0);
+    int32bit as u32
+}
+
+/// `StringToBigInt ( str )`
+///
+/// Returns `None` where the specification returns `undefined`.
+///
+/// <https://tc39.es/ecma262/#sec-stringtobigint>
+pub fn string_to_big_int(s: &str) -> Option<BigInt> {
+    let s = s.trim_matches(is_js_whitespace);
+
+    if s.is_empty() {
+        return Some(BigInt::zero());
+    }
+
+    if s.len() > 2 && s.starts_with('0') {
+        let radix = match s.as_bytes()[1] {
+            b'x' | b'X' => Some(16),
+            b'o' | b'O' => Some(8),
+            b'b' | b'B' => Some(2),
+            _ => None,
+        };
+        if let Some(radix) = radix {
+            let digits = &s[2..];
+            if !digits.chars().all(|c| c.is_digit(radix)) {
+                return None;
+            }
+            return BigInt::parse_bytes(digits.as_bytes(), radix);
+        }
+    }
+
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    BigInt::parse_bytes(s.as_bytes(), 10)
+}
@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use crate::number::{number_to_string, string_to_number};
+
+/// A primitive value computed at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue<'a> {
+    Number(f64),
+    BigInt(BigInt),
+    String(Cow<'a, str>),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl<'a> ConstantValue<'a> {
+    pub fn is_number(&self) -> bool {
+        matches!(self, Self::Number(_))
+    }
+
+    pub fn is_big_int(&self) -> bool {
+        matches!(self, Self::BigInt(_))
+    }
+
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, Self::Null | Self::Undefined)
+    }
+
+    pub fn into_number(self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn into_string(self) -> Option<Cow<'a, str>> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The result of the `typeof` operator.
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::BigInt(_) => "bigint",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::Undefined => "undefined",
+        }
+    }
+
+    /// `ToBoolean ( argument )`
+    ///
+    /// <https://tc39.es/ecma262/#sec-toboolean>
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Self::Number(n) => !n.is_nan() && *n != 0.0,
+            Self::BigInt(n) => !n.is_zero(),
+            Self::String(s) => !s.is_empty(),
+            Self::Boolean(b) => *b,
+            Self::Null | Self::Undefined => false,
+        }
+    }
+
+    /// `ToNumber ( argument )`
+    ///
+    /// Returns `None` for BigInt, where the specification throws a `TypeError`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-tonumber>
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::BigInt(_) => None,
+            Self::String(s) => Some(string_to_number(s)),
+            Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Self::Null => Some(0.0),
+            Self::Undefined => Some(f64::NAN),
+        }
+    }
+
+    /// `ToString ( argument )`
+    ///
+    /// <https://tc39.es/ecma262/#sec-tostring>
+    pub fn to_js_string(&self) -> Cow<'a, str> {
+        match self {
+            Self::Number(n) => Cow::Owned(number_to_string(*n)),
+            Self::BigInt(n) => Cow::Owned(n.to_string()),
+            Self::String(s) => s.clone(),
+            Self::Boolean(true) => Cow::Borrowed("true"),
+            Self::Boolean(false) => Cow::Borrowed("false"),
+            Self::Null => Cow::Borrowed("null"),
+            Self::Undefined => Cow::Borrowed("undefined"),
+        }
+    }
+
+    /// `ToNumeric ( value )`, returns either a `Number` or a `BigInt`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-tonumeric>
+    #[must_use]
+    pub fn to_numeric(&self) -> Self {
+        match self {
+            Self::BigInt(n) => Self::BigInt(n.clone()),
+            _ => Self::Number(self.to_number().unwrap_or(f64::NAN)),
+        }
+    }
+}
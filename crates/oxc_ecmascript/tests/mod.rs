@@ -0,0 +1,229 @@
+//! Cases are derived from test262
+//! * test/built-ins/Number/S9.3.1_*.js
+//! * test/built-ins/Number/string-*.js
+//! * test/built-ins/Number/prototype/toString/numeric-literal-tostring-*.js
+//! * test/language/expressions/does-not-equals/*.js
+
+use std::borrow::Cow;
+
+use num_bigint::BigInt;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, IdentifierReference, Statement};
+use oxc_ecmascript::{
+    is_loosely_equal, is_strictly_equal, number_to_string, string_to_big_int, string_to_number,
+    to_int_32, to_uint_32, ConstantEvaluation, ConstantValue,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn to_number() {
+    let cases = [
+        ("", 0.0),
+        (" ", 0.0),
+        ("\t\u{b}\u{c} \u{a0}\u{feff}\n\r\u{2028}\u{2029}\u{1680}\u{2000}\u{3000}", 0.0),
+        (" 1 ", 1.0),
+        ("\u{2028}1\u{2029}", 1.0),
+        ("+1", 1.0),
+        ("-1", -1.0),
+        (".5", 0.5),
+        ("5.", 5.0),
+        ("-.5e-1", -0.05),
+        ("1e1000", f64::INFINITY),
+        ("Infinity", f64::INFINITY),
+        ("+Infinity", f64::INFINITY),
+        ("-Infinity", f64::NEG_INFINITY),
+        ("0x10", 16.0),
+        ("0X10", 16.0),
+        ("0o17", 15.0),
+        ("0b11", 3.0),
+        ("0xFFFFFFFFFFFFFFFFF", 295_147_905_179_352_830_000.0),
+        ("00", 0.0),
+        ("010", 10.0),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(string_to_number(source), expected, "{source:?}");
+    }
+
+    let nan_cases = [
+        ".", "e5", "1e", "1e+", "++1", "-0x10", "+0x10", "0x", "0xG", "0b2", "0o8", "1_000",
+        "0x1_0", "infinity", "INFINITY", "inf", "NaN", "1n", "\u{85}1", "1 1",
+    ];
+    for source in nan_cases {
+        assert!(string_to_number(source).is_nan(), "{source:?}");
+    }
+}
+
+#[test]
+fn to_big_int() {
+    assert_eq!(string_to_big_int(""), Some(BigInt::from(0)));
+    assert_eq!(string_to_big_int(" 10 "), Some(BigInt::from(10)));
+    assert_eq!(string_to_big_int("-10"), Some(BigInt::from(-10)));
+    assert_eq!(string_to_big_int("0x10"), Some(BigInt::from(16)));
+    assert_eq!(string_to_big_int("1.5"), None);
+    assert_eq!(string_to_big_int("1e3"), None);
+    assert_eq!(string_to_big_int("-0x10"), None);
+    assert_eq!(string_to_big_int("Infinity"), None);
+    assert_eq!(string_to_big_int("-"), None);
+}
+
+#[test]
+fn number_to_string_default_radix() {
+    let cases = [
+        (0.0, "0"),
+        (-0.0, "0"),
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "Infinity"),
+        (f64::NEG_INFINITY, "-Infinity"),
+        (1.0, "1"),
+        (-1.5, "-1.5"),
+        (100.0, "100"),
+        (0.1 + 0.2, "0.30000000000000004"),
+        (0.000_001, "0.000001"),
+        (0.000_000_1, "1e-7"),
+        (1.23e-18, "1.23e-18"),
+        (1e21, "1e+21"),
+        (1.5e21, "1.5e+21"),
+        (123_456_789_012_345_680_000.0, "123456789012345680000"),
+        (9_007_199_254_740_992.0, "9007199254740992"),
+        (5e-324, "5e-324"),
+        (f64::MAX, "1.7976931348623157e+308"),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(number_to_string(value), expected, "{value:?}");
+    }
+}
+
+#[test]
+fn to_int32() {
+    assert_eq!(to_int_32(f64::NAN), 0);
+    assert_eq!(to_int_32(f64::INFINITY), 0);
+    assert_eq!(to_int_32(-0.0), 0);
+    assert_eq!(to_int_32(1.9), 1);
+    assert_eq!(to_int_32(-1.9), -1);
+    assert_eq!(to_int_32(2_147_483_648.0), -2_147_483_648);
+    assert_eq!(to_int_32(4_294_967_295.0), -1);
+    assert_eq!(to_int_32(4_294_967_296.0), 0);
+    assert_eq!(to_int_32(-4_294_967_297.0), -1);
+    assert_eq!(to_uint_32(-1.0), 4_294_967_295);
+    assert_eq!(to_uint_32(4_294_967_297.0), 1);
+}
+
+#[test]
+fn equality() {
+    let number = ConstantValue::Number;
+    let string = |s: &'static str| ConstantValue::String(Cow::Borrowed(s));
+    let big_int = |n: i64| ConstantValue::BigInt(BigInt::from(n));
+
+    let loosely_equal = [
+        (ConstantValue::Null, ConstantValue::Undefined),
+        (number(1.0), string("1")),
+        (number(0.0), string("")),
+        (number(16.0), string("0x10")),
+        (number(0.0), number(-0.0)),
+        (big_int(1), string("1")),
+        (big_int(1), number(1.0)),
+        (ConstantValue::Boolean(true), string("1")),
+        (ConstantValue::Boolean(false), number(0.0)),
+        (ConstantValue::Boolean(true), big_int(1)),
+    ];
+    for (x, y) in &loosely_equal {
+        assert!(is_loosely_equal(x, y), "{x:?} == {y:?}");
+        assert!(is_loosely_equal(y, x), "{y:?} == {x:?}");
+    }
+
+    let not_loosely_equal = [
+        (ConstantValue::Null, number(0.0)),
+        (ConstantValue::Undefined, ConstantValue::Boolean(false)),
+        (number(f64::NAN), number(f64::NAN)),
+        (big_int(1), number(1.5)),
+        (big_int(1), string("1.0")),
+        (ConstantValue::Boolean(true), string("true")),
+    ];
+    for (x, y) in &not_loosely_equal {
+        assert!(!is_loosely_equal(x, y), "{x:?} != {y:?}");
+        assert!(!is_loosely_equal(y, x), "{y:?} != {x:?}");
+    }
+
+    assert!(is_strictly_equal(&number(0.0), &number(-0.0)));
+    assert!(!is_strictly_equal(&number(1.0), &string("1")));
+    assert!(!is_strictly_equal(&ConstantValue::Null, &ConstantValue::Undefined));
+}
+
+struct Evaluator;
+
+impl<'a> ConstantEvaluation<'a> for Evaluator {
+    fn is_global_reference(&self, _ident: &IdentifierReference) -> bool {
+        true
+    }
+}
+
+fn eval(source_text: &str) -> Option<String> {
+    let allocator = Allocator::default();
+    let source_text = format!("({source_text})");
+    let ret = Parser::new(&allocator, &source_text, SourceType::default()).parse();
+    assert!(ret.errors.is_empty(), "{source_text}");
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+        unreachable!()
+    };
+    let expr: &Expression = &stmt.expression;
+    Evaluator.eval_expression(expr).map(|value| match value {
+        ConstantValue::String(s) => format!("{s:?}"),
+        ConstantValue::BigInt(n) => format!("{n}n"),
+        value => value.to_js_string().into_owned(),
+    })
+}
+
+#[test]
+fn evaluation() {
+    let cases = [
+        ("1 + 2", "3"),
+        ("1 + '2'", "\"12\""),
+        ("'a' + null", "\"anull\""),
+        ("1 + true", "2"),
+        ("'3' * '4'", "12"),
+        ("1 / 0", "Infinity"),
+        ("-1 % 2", "-1"),
+        ("2 ** -1", "0.5"),
+        ("1 ** NaN", "NaN"),
+        ("1 << 31", "-2147483648"),
+        ("-1 >>> 0", "4294967295"),
+        ("-16 >> 2", "-4"),
+        ("~5", "-6"),
+        ("5 & 3 | 8 ^ 1", "9"),
+        ("2n ** 64n", "18446744073709551616n"),
+        ("-7n / 2n", "-3n"),
+        ("0x10n + 0b1n", "17n"),
+        ("typeof 1", "\"number\""),
+        ("typeof null", "\"object\""),
+        ("typeof (() => {})", "\"function\""),
+        ("typeof []", "\"object\""),
+        ("!''", "true"),
+        ("![]", "false"),
+        ("void 0", "undefined"),
+        ("null ?? 'x'", "\"x\""),
+        ("0 || 'x'", "\"x\""),
+        ("0 && 'x'", "0"),
+        ("[] ? 1 : 2", "1"),
+        ("`a${1}b${null}`", "\"a1bnull\""),
+        ("'b' > 'a'", "true"),
+        ("'10' < '9'", "true"),
+        ("10 < '9'", "false"),
+        ("1n < 1.5", "true"),
+        ("NaN <= NaN", "false"),
+        ("'1' == 1", "true"),
+        ("'1' === 1", "false"),
+        ("null == undefined", "true"),
+        ("1e21 + ''", "\"1e+21\""),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(eval(source).as_deref(), Some(expected), "{source}");
+    }
+
+    let unknown =
+        ["x", "1n + 1", "1n / 0n", "2n ** -1n", "'a' in {}", "void foo()", "+1n", "[] + 1"];
+    for source in unknown {
+        assert_eq!(eval(source), None, "{source}");
+    }
+}
@@ -32,7 +32,7 @@ pub enum Branch<'a> {
 /// The type which includes all leaf nodes.
 #[derive(Debug)]
 pub enum Leaf<'a> {
-    Backreference(Box<'a, Backreference<'a>>),
+    Backreference(Box<'a, Backreference>),
     BoundaryAssertion(Box<'a, BoundaryAssertion<'a>>),
     Character(Box<'a, Character>),
     CharacterSet(Box<'a, CharacterSet<'a>>),
@@ -50,7 +50,7 @@ pub enum Element<'a> {
 /// The type which includes all atom nodes that Quantifier node can have as children.
 #[derive(Debug)]
 pub enum QuantifiableElement<'a> {
-    Backreference(Box<'a, Backreference<'a>>),
+    Backreference(Box<'a, Backreference>),
     CapturingGroup(Box<'a, CapturingGroup<'a>>),
     Character(Box<'a, Character>),
     CharacterClass(Box<'a, CharacterClass<'a>>),
@@ -122,7 +122,6 @@ pub struct CapturingGroup<'a> {
     pub span: Span,
     pub name: Option<Atom>,
     pub alternatives: Vec<'a, Alternative<'a>>,
-    pub references: Vec<'a, Backreference<'a>>,
 }
 
 /// The lookaround assertion.
@@ -175,6 +174,7 @@ pub enum CharacterClass<'a> {
 #[derive(Debug)]
 pub struct ClassRangesCharacterClass<'a> {
     pub span: Span,
+    pub negate: bool,
     pub unicode_sets: bool,
     pub elements: Vec<'a, ClassRangesCharacterClassElement<'a>>,
 }
@@ -184,6 +184,7 @@ pub struct ClassRangesCharacterClass<'a> {
 #[derive(Debug)]
 pub struct UnicodeSetsCharacterClass<'a> {
     pub span: Span,
+    pub negate: bool,
     pub elements: Vec<'a, UnicodeSetsCharacterClassElement<'a>>,
 }
 
@@ -235,11 +236,18 @@ pub struct WordBoundaryAssertion {
 /// The character set.
 #[derive(Debug)]
 pub enum CharacterSet<'a> {
-    AnyCharacterSet,
+    AnyCharacterSet(Box<'a, AnyCharacterSet>),
     EscapeCharacterSet(Box<'a, EscapeCharacterSet>),
     UnicodePropertyCharacterSet(Box<'a, UnicodePropertyCharacterSet<'a>>),
 }
 
+/// The dot.
+/// E.g. `.`
+#[derive(Debug)]
+pub struct AnyCharacterSet {
+    pub span: Span,
+}
+
 /// The character class escape.
 /// E.g. `\d`, `\s`, `\w`, `\D`, `\S`, `\W`
 #[derive(Debug)]
@@ -354,22 +362,21 @@ pub struct StringAlternative<'a> {
 #[derive(Debug)]
 pub struct Character {
     pub span: Span,
-    pub value: u16, // UTF-16 code point
+    pub value: u32, // Unicode code point, or UTF-16 code unit of a lone surrogate
 }
 
 #[derive(Debug)]
 pub enum BackreferenceRef {
-    Number(i32),
+    Number(u32),
     Atom(Atom),
 }
 
 /// The backreference.
 /// E.g. `\1`, `\k<name>`
 #[derive(Debug)]
-pub struct Backreference<'a> {
+pub struct Backreference {
     pub span: Span,
     pub reference: BackreferenceRef,
-    pub resolved: CapturingGroup<'a>,
 }
 
 /// The flags.
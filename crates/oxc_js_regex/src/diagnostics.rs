@@ -0,0 +1,15 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: {0}")]
+#[diagnostic()]
+pub struct InvalidPattern(pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression flags: {0}")]
+#[diagnostic()]
+pub struct InvalidFlags(pub &'static str, #[label] pub Span);
@@ -1 +1,80 @@
 mod token;
+
+/// Reads the code points of a pattern, keeping their byte offsets for the spans
+pub struct Lexer<'a> {
+    source_text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text, offset: 0 }
+    }
+
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    /// Byte offset of the current code point
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn rewind(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    pub fn is_eof(&self) -> bool {
+        self.offset >= self.source_text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.source_text[self.offset..].chars().next()
+    }
+
+    pub fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source_text[self.offset..].chars().nth(n)
+    }
+
+    pub fn at(&self, c: char) -> bool {
+        self.peek() == Some(c)
+    }
+
+    pub fn at_str(&self, s: &str) -> bool {
+        self.source_text[self.offset..].starts_with(s)
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    pub fn eat(&mut self, c: char) -> bool {
+        if self.at(c) {
+            self.offset += c.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    pub fn eat_str(&mut self, s: &str) -> bool {
+        if self.at_str(s) {
+            self.offset += s.len();
+            return true;
+        }
+        false
+    }
+
+    /// Eat the leading digits in `radix`, at most `max` of them
+    pub fn eat_digits(&mut self, radix: u32, max: usize) -> Option<u32> {
+        let start = self.offset;
+        let mut value: u32 = 0;
+        for _ in 0..max {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(radix)) else { break };
+            value = value.saturating_mul(radix).saturating_add(digit);
+            self.offset += 1;
+        }
+        (self.offset > start).then_some(value)
+    }
+}
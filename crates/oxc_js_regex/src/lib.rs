@@ -1,4 +1,5 @@
 pub mod ast;
+mod diagnostics;
 mod lexer;
 pub mod parser;
 pub mod validator;
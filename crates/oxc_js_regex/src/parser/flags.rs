@@ -0,0 +1,58 @@
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+use crate::{ast::Flags, diagnostics};
+
+/// Parser of the flags of a regular expression, e.g. `gu` of `/a/gu`
+pub struct FlagsParser<'a> {
+    source_text: &'a str,
+    span_offset: u32,
+}
+
+impl<'a> FlagsParser<'a> {
+    /// `span_offset` is the offset of `source_text` in the file, for the spans
+    pub fn new(source_text: &'a str, span_offset: u32) -> Self {
+        Self { source_text, span_offset }
+    }
+
+    /// # Errors
+    /// Returns an error on an unknown or duplicated flag, or when both `u` and `v` are set.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn parse(self) -> Result<Flags> {
+        let mut flags = Flags {
+            span: Span::new(self.span_offset, self.span_offset + self.source_text.len() as u32),
+            dot_all: false,
+            global: false,
+            has_indices: false,
+            ignore_case: false,
+            multiline: false,
+            sticky: false,
+            unicode: false,
+            unicode_sets: false,
+        };
+        for (i, c) in self.source_text.char_indices() {
+            let start = self.span_offset + i as u32;
+            let span = Span::new(start, start + c.len_utf8() as u32);
+            let flag = match c {
+                'd' => &mut flags.has_indices,
+                'g' => &mut flags.global,
+                'i' => &mut flags.ignore_case,
+                'm' => &mut flags.multiline,
+                's' => &mut flags.dot_all,
+                'u' => &mut flags.unicode,
+                'v' => &mut flags.unicode_sets,
+                'y' => &mut flags.sticky,
+                _ => return Err(diagnostics::InvalidFlags("Unknown flag", span).into()),
+            };
+            if *flag {
+                return Err(diagnostics::InvalidFlags("Duplicated flag", span).into());
+            }
+            *flag = true;
+        }
+        if flags.unicode && flags.unicode_sets {
+            let message = "The `u` and `v` flags cannot be used together";
+            return Err(diagnostics::InvalidFlags(message, flags.span).into());
+        }
+        Ok(flags)
+    }
+}
@@ -0,0 +1,200 @@
+//! Parser of regular expression literals into the [`ast`](crate::ast)
+
+mod flags;
+mod pattern;
+mod unicode_property;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+pub use self::{flags::FlagsParser, pattern::PatternParser};
+use crate::{ast::RegExpLiteral, diagnostics};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParserOptions {
+    /// Offset of the source text in the file, added to the spans
+    pub span_offset: u32,
+    /// The `u` flag, set from the flags by [`Parser`]
+    pub unicode_mode: bool,
+    /// The `v` flag, set from the flags by [`Parser`]
+    pub unicode_sets_mode: bool,
+}
+
+/// Parser of a regular expression literal, e.g. `/a+/gu`
+///
+/// See [`PatternParser`] and [`FlagsParser`] for parsing a pattern or flags on their own,
+/// e.g. the arguments of `new RegExp("a+", "gu")`.
+pub struct Parser<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    options: ParserOptions,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, options: ParserOptions) -> Self {
+        Self { allocator, source_text, options }
+    }
+
+    /// # Errors
+    /// Returns the first syntax error of the flags or the pattern.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn parse(self) -> Result<RegExpLiteral<'a>> {
+        let span_offset = self.options.span_offset;
+        let span = Span::new(span_offset, span_offset + self.source_text.len() as u32);
+        let Some(end) =
+            self.source_text.rfind('/').filter(|end| *end > 0 && self.source_text.starts_with('/'))
+        else {
+            let message = "Unterminated regular expression";
+            return Err(diagnostics::InvalidPattern(message, span).into());
+        };
+        let flags = &self.source_text[end + 1..];
+        let flags = FlagsParser::new(flags, span_offset + end as u32 + 1).parse()?;
+        let options = ParserOptions {
+            span_offset: span_offset + 1,
+            unicode_mode: flags.unicode,
+            unicode_sets_mode: flags.unicode_sets,
+        };
+        let pattern = &self.source_text[1..end];
+        let pattern = PatternParser::new(self.allocator, pattern, options).parse()?;
+        Ok(RegExpLiteral { span, pattern, flags })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::Span;
+
+    use super::{Parser, ParserOptions};
+    use crate::ast::{
+        BackreferenceRef, CharacterClass, CharacterSet, Element, QuantifiableElement,
+        UnicodePropertyCharacterSet,
+    };
+
+    fn errors(sources: &[&str]) {
+        let allocator = Allocator::default();
+        for source in sources {
+            let ret = Parser::new(&allocator, source, ParserOptions::default()).parse();
+            assert!(ret.is_err(), "{source} should be invalid");
+        }
+    }
+
+    fn valid(sources: &[&str]) {
+        let allocator = Allocator::default();
+        for source in sources {
+            let ret = Parser::new(&allocator, source, ParserOptions::default()).parse();
+            assert!(ret.is_ok(), "{source} should be valid: {:?}", ret.err());
+        }
+    }
+
+    #[test]
+    fn literal() {
+        let allocator = Allocator::default();
+        let source = r"/(?<year>\d{4})-\k<year>|a+?/gu";
+        let options = ParserOptions { span_offset: 10, ..ParserOptions::default() };
+        let literal = Parser::new(&allocator, source, options).parse().unwrap();
+        assert_eq!(literal.span, Span::new(10, 41));
+        assert_eq!(literal.pattern.span, Span::new(11, 38));
+        assert_eq!(literal.flags.span, Span::new(39, 41));
+        assert!(literal.flags.global && literal.flags.unicode && !literal.flags.sticky);
+
+        let alternatives = &literal.pattern.alternatives;
+        assert_eq!(alternatives.len(), 2);
+        let elements = &alternatives[0].elements;
+        assert_eq!(elements.len(), 3);
+        let Element::QuantifiableElement(element) = &elements[0] else { panic!() };
+        let QuantifiableElement::CapturingGroup(group) = &**element else { panic!() };
+        assert_eq!(group.name.as_deref(), Some("year"));
+        let Element::QuantifiableElement(element) = &elements[2] else { panic!() };
+        let QuantifiableElement::Backreference(reference) = &**element else { panic!() };
+        assert!(matches!(&reference.reference, BackreferenceRef::Atom(name) if name == "year"));
+
+        let Element::Quantifier(quantifier) = &alternatives[1].elements[0] else { panic!() };
+        assert!(!quantifier.greedy);
+        assert!((quantifier.min - 1.0).abs() < f64::EPSILON && quantifier.max.is_infinite());
+    }
+
+    #[test]
+    fn unicode_property() {
+        let allocator = Allocator::default();
+        let source = r"/\p{Script=Greek}\P{L}[\p{ASCII}]/u";
+        let literal = Parser::new(&allocator, source, ParserOptions::default()).parse().unwrap();
+        let elements = &literal.pattern.alternatives[0].elements;
+        let Element::QuantifiableElement(element) = &elements[1] else { panic!() };
+        let QuantifiableElement::CharacterSet(set) = &**element else { panic!() };
+        let CharacterSet::UnicodePropertyCharacterSet(set) = &**set else { panic!() };
+        let UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(set) = &**set else {
+            panic!()
+        };
+        assert_eq!(set.key, "General_Category");
+        assert_eq!(set.value.as_deref(), Some("L"));
+        assert!(set.negate);
+
+        valid(&[r"/\p{Foo}/", r"/\p{RGI_Emoji}/v", r"/[\p{RGI_Emoji}--\q{a|bc}]/v"]);
+        errors(&[
+            r"/\p{Foo}/u",
+            r"/\p{General_Category=Greek}/u",
+            r"/\p{RGI_Emoji}/u",
+            r"/\P{RGI_Emoji}/v",
+            r"/[^\p{RGI_Emoji}]/v",
+            r"/\p{L/u",
+        ]);
+    }
+
+    #[test]
+    fn character_class() {
+        let allocator = Allocator::default();
+        let source = r"/[^a-z\d-]/";
+        let literal = Parser::new(&allocator, source, ParserOptions::default()).parse().unwrap();
+        let Element::QuantifiableElement(element) = &literal.pattern.alternatives[0].elements[0]
+        else {
+            panic!()
+        };
+        let QuantifiableElement::CharacterClass(class) = &**element else { panic!() };
+        let CharacterClass::ClassRangesCharacterClass(class) = &**class else { panic!() };
+        assert!(class.negate);
+        assert_eq!(class.elements.len(), 3);
+
+        valid(&[r"/[\d-z]/", r"/[[a-z]--[aeiou]]/v", r"/[\w&&\d]/v", r"/[\q{}]/v", r"/[]/"]);
+        errors(&[
+            r"/[z-a]/",
+            r"/[\d-z]/u",
+            r"/[a&&&b]/v",
+            r"/[a&&b--c]/v",
+            r"/[(]/v",
+            r"/[^\q{ab}]/v",
+            r"/[a/",
+        ]);
+    }
+
+    #[test]
+    fn annex_b() {
+        valid(&[r"/]/", r"/{/", r"/a{1/", r"/\1/", r"/\8/", r"/\c/", r"/\k/", r"/(?=a)*/"]);
+        errors(&[r"/]/u", r"/{/u", r"/a{1/u", r"/\1/u", r"/\c/u", r"/\k/u", r"/(?=a)*/u"]);
+    }
+
+    #[test]
+    fn errors_in_pattern() {
+        errors(&[
+            "/a**/",
+            "/?/",
+            "/a{2,1}/",
+            "/(a/",
+            "/a)/",
+            "/(?a)/",
+            "/(?<a>)(?<a>)/",
+            r"/\k<b>(?<a>)/",
+            r"/(?<1>)/",
+            r"/\u{110000}/u",
+            "/a",
+        ]);
+        valid(&["/(?<$a>)(?:b)(?<=c)(?<!d)/", r"/\k<a>(?<a>)/", r"/\u{1F600}😀/u"]);
+    }
+
+    #[test]
+    fn flags() {
+        valid(&["/a/dgimsuy", "/a/v"]);
+        errors(&["/a/gg", "/a/uv", "/a/x"]);
+    }
+}
@@ -0,0 +1,942 @@
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_diagnostics::{Error, Result};
+use oxc_span::{Atom, Span};
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
+
+use super::{unicode_property, ParserOptions};
+use crate::{
+    ast::{
+        Alternative, AnyCharacterSet, Assertion, Backreference, BackreferenceRef,
+        BoundaryAssertion, CapturingGroup, Character, CharacterClass, CharacterClassRange,
+        CharacterSet, CharacterUnicodePropertyCharacterSet, ClassIntersection,
+        ClassIntersectionLeft, ClassRangesCharacterClass, ClassRangesCharacterClassElement,
+        ClassSetOperand, ClassStringDisjunction, ClassSubtraction, ClassSubtractionLeft,
+        EdgeAssertion, EdgeAssertionKind, Element, EscapeCharacterSet, EscapeCharacterSetKind,
+        ExpressionCharacterClass, ExpressionCharacterClassExpr, Group, LookaheadAssertion,
+        LookaroundAssertion, LookbehindAssertion, Pattern, QuantifiableElement, Quantifier,
+        StringAlternative, StringsUnicodePropertyCharacterSet, UnicodePropertyCharacterSet,
+        UnicodeSetsCharacterClass, UnicodeSetsCharacterClassElement, WordBoundaryAssertion,
+    },
+    diagnostics,
+    lexer::Lexer,
+};
+
+/// Parser of a regular expression pattern, e.g. `a+` of `/a+/u`
+///
+/// Without the `u` and `v` flags, the pattern is parsed with the
+/// [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) extensions,
+/// e.g. `/]/` and `/\8/` are accepted as characters.
+pub struct PatternParser<'a> {
+    allocator: &'a Allocator,
+    lexer: Lexer<'a>,
+    options: ParserOptions,
+    /// Whether the pattern has a named group, which makes `\k<name>` a backreference
+    named_groups: bool,
+    group_count: u32,
+    group_names: std::vec::Vec<Atom>,
+    /// `\k<name>` backreferences, checked once all the groups are parsed
+    named_references: std::vec::Vec<(Atom, Span)>,
+}
+
+/// A character class with the `v` flag
+enum ClassSet<'a> {
+    Union(Box<'a, UnicodeSetsCharacterClass<'a>>),
+    Expression(Box<'a, ExpressionCharacterClass<'a>>),
+}
+
+impl<'a> PatternParser<'a> {
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, options: ParserOptions) -> Self {
+        Self {
+            allocator,
+            lexer: Lexer::new(source_text),
+            options,
+            named_groups: false,
+            group_count: 0,
+            group_names: vec![],
+            named_references: vec![],
+        }
+    }
+
+    /// # Errors
+    /// Returns the first syntax error of the pattern.
+    pub fn parse(mut self) -> Result<Pattern<'a>> {
+        self.scan_groups();
+        let alternatives = self.parse_disjunction()?;
+        if !self.lexer.is_eof() {
+            let start = self.lexer.offset();
+            self.lexer.bump();
+            return Err(self.error("Unmatched ')'", start));
+        }
+        if let Some((_, span)) =
+            self.named_references.iter().find(|(name, _)| !self.group_names.contains(name))
+        {
+            return Err(
+                diagnostics::InvalidPattern("Invalid named capture referenced", *span).into()
+            );
+        }
+        Ok(Pattern { span: self.span(0), alternatives })
+    }
+
+    /// Count the capturing groups before parsing, which decide the meaning of `\1` and `\k`
+    fn scan_groups(&mut self) {
+        let source_text = self.lexer.source_text();
+        let mut chars = source_text.char_indices();
+        let mut class_depth = 0;
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' if class_depth == 0 || self.options.unicode_sets_mode => class_depth += 1,
+                ']' if class_depth > 0 => class_depth -= 1,
+                '(' if class_depth == 0 => {
+                    let rest = &source_text[i + 1..];
+                    if !rest.starts_with('?') {
+                        self.group_count += 1;
+                    } else if rest.starts_with("?<")
+                        && !rest.starts_with("?<=")
+                        && !rest.starts_with("?<!")
+                    {
+                        self.group_count += 1;
+                        self.named_groups = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn unicode_mode(&self) -> bool {
+        self.options.unicode_mode || self.options.unicode_sets_mode
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span_of(&self, start: usize, end: usize) -> Span {
+        Span::new(self.options.span_offset + start as u32, self.options.span_offset + end as u32)
+    }
+
+    /// Span from `start` to the current offset
+    fn span(&self, start: usize) -> Span {
+        self.span_of(start, self.lexer.offset())
+    }
+
+    fn error(&self, message: &'static str, start: usize) -> Error {
+        diagnostics::InvalidPattern(message, self.span(start)).into()
+    }
+
+    fn alloc<T>(&self, value: T) -> Box<'a, T> {
+        Box(self.allocator.alloc(value))
+    }
+
+    fn parse_disjunction(&mut self) -> Result<Vec<'a, Alternative<'a>>> {
+        let mut alternatives = Vec::new_in(self.allocator);
+        loop {
+            alternatives.push(self.parse_alternative()?);
+            if !self.lexer.eat('|') {
+                return Ok(alternatives);
+            }
+        }
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative<'a>> {
+        let start = self.lexer.offset();
+        let mut elements = Vec::new_in(self.allocator);
+        while !self.lexer.is_eof() && !self.lexer.at('|') && !self.lexer.at(')') {
+            elements.push(self.parse_term()?);
+        }
+        Ok(Alternative { span: self.span(start), elements })
+    }
+
+    /// An assertion, or an atom with an optional quantifier
+    fn parse_term(&mut self) -> Result<Element<'a>> {
+        let start = self.lexer.offset();
+        let assertion = if self.lexer.eat_str("(?=") || self.lexer.eat_str("(?!") {
+            let negate = self.lexer.source_text()[start..].starts_with("(?!");
+            let alternatives = self.parse_group_body(start)?;
+            let assertion = LookaheadAssertion { span: self.span(start), negate, alternatives };
+            // Annex B: lookaheads can be quantified without the `u` and `v` flags
+            if !self.unicode_mode() && matches!(self.lexer.peek(), Some('*' | '+' | '?' | '{')) {
+                let element = QuantifiableElement::LookaheadAssertion(self.alloc(assertion));
+                return self.parse_quantifier(start, element);
+            }
+            let assertion = LookaroundAssertion::LookaheadAssertion(self.alloc(assertion));
+            Assertion::LookaroundAssertion(self.alloc(assertion))
+        } else if self.lexer.eat_str("(?<=") || self.lexer.eat_str("(?<!") {
+            let negate = self.lexer.source_text()[start..].starts_with("(?<!");
+            let alternatives = self.parse_group_body(start)?;
+            let assertion = LookbehindAssertion { span: self.span(start), negate, alternatives };
+            let assertion = LookaroundAssertion::LookbehindAssertion(self.alloc(assertion));
+            Assertion::LookaroundAssertion(self.alloc(assertion))
+        } else if self.lexer.eat('^') || self.lexer.eat('$') {
+            let kind = if self.lexer.source_text()[start..].starts_with('^') {
+                EdgeAssertionKind::Start
+            } else {
+                EdgeAssertionKind::End
+            };
+            let assertion = EdgeAssertion { span: self.span(start), kind };
+            let assertion = BoundaryAssertion::EdgeAssertion(self.alloc(assertion));
+            Assertion::BoundaryAssertion(self.alloc(assertion))
+        } else if self.lexer.eat_str("\\b") || self.lexer.eat_str("\\B") {
+            let negate = self.lexer.source_text()[start..].starts_with("\\B");
+            let assertion = WordBoundaryAssertion { span: self.span(start), negate };
+            let assertion = BoundaryAssertion::WordBoundaryAssertion(self.alloc(assertion));
+            Assertion::BoundaryAssertion(self.alloc(assertion))
+        } else {
+            let element = self.parse_atom()?;
+            return self.parse_quantifier(start, element);
+        };
+        Ok(Element::Assertion(self.alloc(assertion)))
+    }
+
+    /// `element` followed by `*`, `+`, `?` or `{n,m}`, which may be followed by `?` when lazy
+    fn parse_quantifier(
+        &mut self,
+        start: usize,
+        element: QuantifiableElement<'a>,
+    ) -> Result<Element<'a>> {
+        let range = match self.lexer.peek() {
+            Some('{') => self.parse_braced_quantifier()?,
+            Some(c @ ('*' | '+' | '?')) => {
+                self.lexer.bump();
+                let min = if c == '+' { 1.0 } else { 0.0 };
+                let max = if c == '?' { 1.0 } else { f64::INFINITY };
+                Some((min, max))
+            }
+            _ => None,
+        };
+        let Some((min, max)) = range else {
+            return Ok(Element::QuantifiableElement(self.alloc(element)));
+        };
+        let greedy = !self.lexer.eat('?');
+        let quantifier = Quantifier { span: self.span(start), min, max, greedy, element };
+        Ok(Element::Quantifier(self.alloc(quantifier)))
+    }
+
+    /// `{n}`, `{n,}` or `{n,m}`, or `None` when `{` is a character (Annex B).
+    /// The lexer is left after the quantifier, or at `{` when it returns `None`.
+    fn parse_braced_quantifier(&mut self) -> Result<Option<(f64, f64)>> {
+        let start = self.lexer.offset();
+        self.lexer.bump();
+        let range = self.eat_decimal().and_then(|min| {
+            let max =
+                if self.lexer.eat(',') { self.eat_decimal().unwrap_or(f64::INFINITY) } else { min };
+            self.lexer.eat('}').then_some((min, max))
+        });
+        match range {
+            Some((min, max)) if max < min => {
+                Err(self.error("Numbers out of order in {} quantifier", start))
+            }
+            Some(range) => Ok(Some(range)),
+            None if self.unicode_mode() => Err(self.error("Incomplete quantifier", start)),
+            None => {
+                self.lexer.rewind(start);
+                Ok(None)
+            }
+        }
+    }
+
+    fn eat_decimal(&mut self) -> Option<f64> {
+        let mut value = None;
+        while let Some(digit) = self.lexer.peek().and_then(|c| c.to_digit(10)) {
+            self.lexer.bump();
+            value = Some(value.unwrap_or(0.0) * 10.0 + f64::from(digit));
+        }
+        value
+    }
+
+    fn parse_atom(&mut self) -> Result<QuantifiableElement<'a>> {
+        let start = self.lexer.offset();
+        match self.lexer.peek() {
+            Some('.') => {
+                self.lexer.bump();
+                let set = AnyCharacterSet { span: self.span(start) };
+                let set = CharacterSet::AnyCharacterSet(self.alloc(set));
+                Ok(QuantifiableElement::CharacterSet(self.alloc(set)))
+            }
+            Some('\\') => self.parse_atom_escape(),
+            Some('[') => self.parse_character_class(),
+            Some('(') => self.parse_group(),
+            Some('*' | '+' | '?') => {
+                self.lexer.bump();
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some('{') => {
+                if self.parse_braced_quantifier()?.is_some() {
+                    return Err(self.error("Nothing to repeat", start));
+                }
+                self.lexer.bump();
+                Ok(self.character_element(start, u32::from('{')))
+            }
+            Some(']' | '}') if self.unicode_mode() => {
+                self.lexer.bump();
+                Err(self.error("Lone quantifier brackets", start))
+            }
+            Some(c) => {
+                self.lexer.bump();
+                Ok(self.character_element(start, u32::from(c)))
+            }
+            None => Err(self.error("Unexpected end of pattern", start)),
+        }
+    }
+
+    fn character(&self, start: usize, value: u32) -> Box<'a, Character> {
+        self.alloc(Character { span: self.span(start), value })
+    }
+
+    fn character_element(&self, start: usize, value: u32) -> QuantifiableElement<'a> {
+        QuantifiableElement::Character(self.character(start, value))
+    }
+
+    /// `(…)`, `(?<name>…)` or `(?:…)`
+    fn parse_group(&mut self) -> Result<QuantifiableElement<'a>> {
+        let start = self.lexer.offset();
+        self.lexer.bump();
+        if self.lexer.eat_str("?:") {
+            let alternatives = self.parse_group_body(start)?;
+            let group = Group { span: self.span(start), alternatives };
+            return Ok(QuantifiableElement::Group(self.alloc(group)));
+        }
+        let name = if self.lexer.eat_str("?<") {
+            let name = self.parse_group_name(start)?;
+            if self.group_names.contains(&name) {
+                return Err(self.error("Duplicate capture group name", start));
+            }
+            self.group_names.push(name.clone());
+            Some(name)
+        } else if self.lexer.eat('?') {
+            return Err(self.error("Invalid group", start));
+        } else {
+            None
+        };
+        let alternatives = self.parse_group_body(start)?;
+        let group = CapturingGroup { span: self.span(start), name, alternatives };
+        Ok(QuantifiableElement::CapturingGroup(self.alloc(group)))
+    }
+
+    /// The alternatives up to the `)` closing the group at `start`
+    fn parse_group_body(&mut self, start: usize) -> Result<Vec<'a, Alternative<'a>>> {
+        let alternatives = self.parse_disjunction()?;
+        if !self.lexer.eat(')') {
+            return Err(self.error("Unterminated group", start));
+        }
+        Ok(alternatives)
+    }
+
+    /// `name>` of `(?<name>` or `\k<name>`
+    fn parse_group_name(&mut self, start: usize) -> Result<Atom> {
+        let mut name = String::new();
+        loop {
+            let c = match self.lexer.bump() {
+                Some('>') if !name.is_empty() => return Ok(Atom::from(name)),
+                Some('\\') if self.lexer.eat('u') => {
+                    self.eat_unicode_escape(/* unicode_mode */ true).and_then(char::from_u32)
+                }
+                c => c,
+            };
+            let is_valid = c.is_some_and(|c| {
+                if name.is_empty() {
+                    is_identifier_start(c)
+                } else {
+                    is_identifier_part(c)
+                }
+            });
+            match c {
+                Some(c) if is_valid => name.push(c),
+                _ => return Err(self.error("Invalid capture group name", start)),
+            }
+        }
+    }
+
+    /// `\` followed by a backreference, a character class escape or a character escape
+    fn parse_atom_escape(&mut self) -> Result<QuantifiableElement<'a>> {
+        let start = self.lexer.offset();
+        self.lexer.bump();
+        match self.lexer.peek() {
+            Some('1'..='9') => {
+                let escape = self.lexer.offset();
+                let number = self.lexer.eat_digits(10, usize::MAX).unwrap_or_default();
+                if number <= self.group_count {
+                    let reference = BackreferenceRef::Number(number);
+                    let reference = Backreference { span: self.span(start), reference };
+                    return Ok(QuantifiableElement::Backreference(self.alloc(reference)));
+                }
+                if self.unicode_mode() {
+                    return Err(self.error("Invalid escape", start));
+                }
+                // Annex B: a legacy octal escape or an identity escape
+                self.lexer.rewind(escape);
+            }
+            Some('k') if self.unicode_mode() || self.named_groups => {
+                self.lexer.bump();
+                if !self.lexer.eat('<') {
+                    return Err(self.error("Invalid named reference", start));
+                }
+                let name = self.parse_group_name(start)?;
+                let span = self.span(start);
+                self.named_references.push((name.clone(), span));
+                let reference = Backreference { span, reference: BackreferenceRef::Atom(name) };
+                return Ok(QuantifiableElement::Backreference(self.alloc(reference)));
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                let set = self.parse_escape_character_set(start);
+                let set = CharacterSet::EscapeCharacterSet(self.alloc(set));
+                return Ok(QuantifiableElement::CharacterSet(self.alloc(set)));
+            }
+            Some('p' | 'P') if self.unicode_mode() => {
+                let set = self.parse_unicode_property(start)?;
+                let set = CharacterSet::UnicodePropertyCharacterSet(self.alloc(set));
+                return Ok(QuantifiableElement::CharacterSet(self.alloc(set)));
+            }
+            _ => {}
+        }
+        let value = self.parse_character_escape(start, /* in_class */ false)?;
+        Ok(self.character_element(start, value))
+    }
+
+    /// `d`, `s` or `w` after `\`, negated when uppercase
+    fn parse_escape_character_set(&mut self, start: usize) -> EscapeCharacterSet {
+        let c = self.lexer.bump().unwrap_or_default();
+        let kind = match c.to_ascii_lowercase() {
+            'd' => EscapeCharacterSetKind::Digit,
+            's' => EscapeCharacterSetKind::Space,
+            _ => EscapeCharacterSetKind::Word,
+        };
+        EscapeCharacterSet { span: self.span(start), kind, negate: c.is_ascii_uppercase() }
+    }
+
+    /// `{name}` or `{name=value}` after `\p` or `\P`,
+    /// which may be a property of strings with the `v` flag
+    fn parse_unicode_property(&mut self, start: usize) -> Result<UnicodePropertyCharacterSet<'a>> {
+        let negate = self.lexer.bump() == Some('P');
+        if !self.lexer.eat('{') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let name = self.eat_property_name();
+        let value = if self.lexer.eat('=') { Some(self.eat_property_name()) } else { None };
+        if !self.lexer.eat('}') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let span = self.span(start);
+        let (key, value) = match value {
+            Some(value)
+                if unicode_property::is_general_category_name(name)
+                    && unicode_property::is_general_category_value(value) =>
+            {
+                (name, Some(value))
+            }
+            Some(value)
+                if unicode_property::is_script_name(name)
+                    && unicode_property::is_valid_script_value(value) =>
+            {
+                (name, Some(value))
+            }
+            None if unicode_property::is_general_category_value(name) => {
+                ("General_Category", Some(name))
+            }
+            None if unicode_property::is_binary_property_name(name) => (name, None),
+            None if self.options.unicode_sets_mode
+                && !negate
+                && unicode_property::is_strings_property_name(name) =>
+            {
+                let set = StringsUnicodePropertyCharacterSet { span, key: Atom::from(name) };
+                return Ok(UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(
+                    self.alloc(set),
+                ));
+            }
+            _ => return Err(self.error("Invalid property name", start)),
+        };
+        let set = CharacterUnicodePropertyCharacterSet {
+            span,
+            key: Atom::from(key),
+            value: value.map(Atom::from),
+            negate,
+        };
+        Ok(UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(self.alloc(set)))
+    }
+
+    fn eat_property_name(&mut self) -> &'a str {
+        let start = self.lexer.offset();
+        while self.lexer.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+            self.lexer.bump();
+        }
+        &self.lexer.source_text()[start..self.lexer.offset()]
+    }
+
+    /// The value of the character escape after `\` at `start`
+    fn parse_character_escape(&mut self, start: usize, in_class: bool) -> Result<u32> {
+        let escape = self.lexer.offset();
+        let Some(c) = self.lexer.bump() else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        let value = match c {
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'b' if in_class => 0x08,
+            'c' => match self.lexer.peek() {
+                // Annex B: also `\c0` and `\c_` in a character class
+                Some(c)
+                    if c.is_ascii_alphabetic()
+                        || (in_class
+                            && !self.unicode_mode()
+                            && (c.is_ascii_digit() || c == '_')) =>
+                {
+                    self.lexer.bump();
+                    u32::from(c) % 32
+                }
+                _ if self.unicode_mode() => return Err(self.error("Invalid unicode escape", start)),
+                // Annex B: `\c` is a `\` followed by `c`
+                _ => {
+                    self.lexer.rewind(escape);
+                    u32::from('\\')
+                }
+            },
+            '0' if !self.lexer.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            // Annex B: a legacy octal escape, e.g. `\012`
+            '0'..='7' if !self.unicode_mode() => {
+                self.lexer.rewind(escape);
+                let max = if c <= '3' { 3 } else { 2 };
+                self.lexer.eat_digits(8, max).unwrap_or_default()
+            }
+            'x' => match self.eat_hex_digits(2) {
+                Some(value) => value,
+                None if self.unicode_mode() => return Err(self.error("Invalid escape", start)),
+                None => u32::from('x'),
+            },
+            'u' => match self.eat_unicode_escape(self.unicode_mode()) {
+                Some(value) => value,
+                None if self.unicode_mode() => {
+                    return Err(self.error("Invalid unicode escape", start));
+                }
+                None => u32::from('u'),
+            },
+            'k' if in_class && self.named_groups => {
+                return Err(self.error("Invalid escape", start));
+            }
+            // Annex B: any other character can be escaped
+            c if !self.unicode_mode() || self.is_identity_escape(c, in_class) => u32::from(c),
+            _ => return Err(self.error("Invalid escape", start)),
+        };
+        Ok(value)
+    }
+
+    /// Characters which can be escaped with the `u` or `v` flag
+    fn is_identity_escape(&self, c: char, in_class: bool) -> bool {
+        is_syntax_character(c)
+            || c == '/'
+            || (in_class && c == '-')
+            || (in_class && self.options.unicode_sets_mode && is_class_set_reserved_punctuator(c))
+    }
+
+    /// Exactly `count` hex digits
+    fn eat_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let start = self.lexer.offset();
+        let value = self.lexer.eat_digits(16, count);
+        if self.lexer.offset() - start == count {
+            return value;
+        }
+        self.lexer.rewind(start);
+        None
+    }
+
+    /// `XXXX` or `{X…}` after `\u`, joining an escaped surrogate pair in `unicode_mode`
+    fn eat_unicode_escape(&mut self, unicode_mode: bool) -> Option<u32> {
+        let start = self.lexer.offset();
+        if unicode_mode && self.lexer.eat('{') {
+            let value = self.lexer.eat_digits(16, usize::MAX);
+            if let Some(value) = value.filter(|value| *value <= 0x0010_FFFF) {
+                if self.lexer.eat('}') {
+                    return Some(value);
+                }
+            }
+            self.lexer.rewind(start);
+            return None;
+        }
+        let lead = self.eat_hex_digits(4)?;
+        if unicode_mode && (0xD800..=0xDBFF).contains(&lead) {
+            let end = self.lexer.offset();
+            if self.lexer.eat_str("\\u") {
+                if let Some(trail) =
+                    self.eat_hex_digits(4).filter(|c| (0xDC00..=0xDFFF).contains(c))
+                {
+                    return Some(0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                }
+            }
+            self.lexer.rewind(end);
+        }
+        Some(lead)
+    }
+
+    /// `[…]` or `[^…]`
+    fn parse_character_class(&mut self) -> Result<QuantifiableElement<'a>> {
+        let start = self.lexer.offset();
+        self.lexer.bump();
+        let negate = self.lexer.eat('^');
+        if !self.options.unicode_sets_mode {
+            let class = self.parse_class_ranges(start, negate)?;
+            let class = CharacterClass::ClassRangesCharacterClass(self.alloc(class));
+            return Ok(QuantifiableElement::CharacterClass(self.alloc(class)));
+        }
+        Ok(match self.parse_class_set(start, negate)?.0 {
+            ClassSet::Union(class) => {
+                let class = CharacterClass::UnicodeSetsCharacterClass(class);
+                QuantifiableElement::CharacterClass(self.alloc(class))
+            }
+            ClassSet::Expression(class) => QuantifiableElement::ExpressionCharacterClass(class),
+        })
+    }
+
+    /// The characters, ranges and escapes of a character class without the `v` flag
+    fn parse_class_ranges(
+        &mut self,
+        start: usize,
+        negate: bool,
+    ) -> Result<ClassRangesCharacterClass<'a>> {
+        let mut elements = Vec::new_in(self.allocator);
+        while !self.lexer.eat(']') {
+            if self.lexer.is_eof() {
+                return Err(self.error("Unterminated character class", start));
+            }
+            let atom_start = self.lexer.offset();
+            let min = self.parse_class_atom()?;
+            if !self.lexer.at('-') || matches!(self.lexer.peek_nth(1), Some(']') | None) {
+                elements.push(min);
+                continue;
+            }
+            let dash = self.lexer.offset();
+            self.lexer.bump();
+            let max = self.parse_class_atom()?;
+            match (min, max) {
+                (
+                    ClassRangesCharacterClassElement::Character(min),
+                    ClassRangesCharacterClassElement::Character(max),
+                ) => {
+                    if min.value > max.value {
+                        return Err(self.error("Range out of order in character class", atom_start));
+                    }
+                    let span = self.span(atom_start);
+                    let range = CharacterClassRange { span, min: min.unbox(), max: max.unbox() };
+                    elements.push(ClassRangesCharacterClassElement::CharacterClassRange(
+                        self.alloc(range),
+                    ));
+                }
+                _ if self.unicode_mode() => {
+                    return Err(self.error("Invalid character class", atom_start));
+                }
+                // Annex B: `[\d-z]` is `\d`, `-` and `z`
+                (min, max) => {
+                    let span = self.span_of(dash, dash + 1);
+                    let dash = Character { span, value: u32::from('-') };
+                    elements.push(min);
+                    elements.push(ClassRangesCharacterClassElement::Character(self.alloc(dash)));
+                    elements.push(max);
+                }
+            }
+        }
+        Ok(ClassRangesCharacterClass {
+            span: self.span(start),
+            negate,
+            unicode_sets: false,
+            elements,
+        })
+    }
+
+    fn parse_class_atom(&mut self) -> Result<ClassRangesCharacterClassElement<'a>> {
+        let start = self.lexer.offset();
+        match self.lexer.bump() {
+            Some('\\') => {}
+            Some(c) => {
+                return Ok(ClassRangesCharacterClassElement::Character(
+                    self.character(start, u32::from(c)),
+                ));
+            }
+            None => return Err(self.error("Unterminated character class", start)),
+        }
+        match self.lexer.peek() {
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                let set = self.parse_escape_character_set(start);
+                return Ok(ClassRangesCharacterClassElement::EscapeCharacterSet(self.alloc(set)));
+            }
+            Some('p' | 'P') if self.unicode_mode() => {
+                let UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(set) =
+                    self.parse_unicode_property(start)?
+                else {
+                    return Err(self.error("Invalid property name", start));
+                };
+                return Ok(ClassRangesCharacterClassElement::CharacterUnicodePropertyCharacterSet(
+                    set,
+                ));
+            }
+            _ => {}
+        }
+        let value = self.parse_character_escape(start, /* in_class */ true)?;
+        Ok(ClassRangesCharacterClassElement::Character(self.character(start, value)))
+    }
+
+    /// The rest of a character class with the `v` flag after `[` or `[^`,
+    /// and whether it may contain strings
+    fn parse_class_set(&mut self, start: usize, negate: bool) -> Result<(ClassSet<'a>, bool)> {
+        let operand_start = self.lexer.offset();
+        if self.lexer.eat(']') {
+            let elements = Vec::new_in(self.allocator);
+            let class = UnicodeSetsCharacterClass { span: self.span(start), negate, elements };
+            return Ok((ClassSet::Union(self.alloc(class)), false));
+        }
+        let (operand, strings) = self.parse_class_set_operand()?;
+        if self.lexer.at_str("&&") || self.lexer.at_str("--") {
+            let (expression, strings) = if self.lexer.at_str("&&") {
+                self.parse_class_intersection(operand_start, operand, strings)?
+            } else {
+                self.parse_class_subtraction(operand_start, operand, strings)?
+            };
+            if !self.lexer.eat(']') {
+                let start = self.lexer.offset();
+                self.lexer.bump();
+                return Err(self.error("Invalid set operation in character class", start));
+            }
+            if negate && strings {
+                return Err(self.error("Negated character class may contain strings", start));
+            }
+            let class = ExpressionCharacterClass { span: self.span(start), negate, expression };
+            return Ok((ClassSet::Expression(self.alloc(class)), strings));
+        }
+
+        let mut elements = Vec::new_in(self.allocator);
+        let mut may_contain_strings = false;
+        let mut next = (operand_start, operand, strings);
+        loop {
+            let (operand_start, operand, strings) = next;
+            may_contain_strings |= strings;
+            if self.lexer.at('-') && !self.lexer.at_str("--") {
+                self.lexer.bump();
+                let max = self.parse_class_set_operand()?.0;
+                let (ClassSetOperand::Character(min), ClassSetOperand::Character(max)) =
+                    (operand, max)
+                else {
+                    return Err(self.error("Invalid character class", operand_start));
+                };
+                if min.value > max.value {
+                    return Err(self.error("Range out of order in character class", operand_start));
+                }
+                let span = self.span(operand_start);
+                let range = CharacterClassRange { span, min: min.unbox(), max: max.unbox() };
+                elements
+                    .push(UnicodeSetsCharacterClassElement::CharacterClassRange(self.alloc(range)));
+            } else {
+                elements.push(union_element(operand));
+            }
+            if self.lexer.eat(']') {
+                break;
+            }
+            let operand_start = self.lexer.offset();
+            if self.lexer.at_str("&&") || self.lexer.at_str("--") {
+                self.lexer.eat_str("&&");
+                self.lexer.eat_str("--");
+                return Err(self.error("Invalid set operation in character class", operand_start));
+            }
+            let (operand, strings) = self.parse_class_set_operand()?;
+            next = (operand_start, operand, strings);
+        }
+        if negate && may_contain_strings {
+            return Err(self.error("Negated character class may contain strings", start));
+        }
+        let class = UnicodeSetsCharacterClass { span: self.span(start), negate, elements };
+        Ok((ClassSet::Union(self.alloc(class)), may_contain_strings))
+    }
+
+    /// `left&&right…`, which may contain strings when every operand may
+    fn parse_class_intersection(
+        &mut self,
+        start: usize,
+        left: ClassSetOperand<'a>,
+        strings: bool,
+    ) -> Result<(ExpressionCharacterClassExpr<'a>, bool)> {
+        let mut left = ClassIntersectionLeft::ClassSetOperand(self.alloc(left));
+        let mut strings = strings;
+        loop {
+            self.eat_class_set_operator("&&")?;
+            let (right, right_strings) = self.parse_class_set_operand()?;
+            strings &= right_strings;
+            let intersection = ClassIntersection { span: self.span(start), left, right };
+            if !self.lexer.at_str("&&") {
+                let expression =
+                    ExpressionCharacterClassExpr::ClassIntersection(self.alloc(intersection));
+                return Ok((expression, strings));
+            }
+            left = ClassIntersectionLeft::ClassIntersection(self.alloc(intersection));
+        }
+    }
+
+    /// `left--right…`, which may contain strings when `left` may
+    fn parse_class_subtraction(
+        &mut self,
+        start: usize,
+        left: ClassSetOperand<'a>,
+        strings: bool,
+    ) -> Result<(ExpressionCharacterClassExpr<'a>, bool)> {
+        let mut left = ClassSubtractionLeft::ClassSetOperand(self.alloc(left));
+        loop {
+            self.eat_class_set_operator("--")?;
+            let right = self.parse_class_set_operand()?.0;
+            let subtraction = ClassSubtraction { span: self.span(start), left, right };
+            if !self.lexer.at_str("--") {
+                let expression =
+                    ExpressionCharacterClassExpr::ClassSubtraction(self.alloc(subtraction));
+                return Ok((expression, strings));
+            }
+            left = ClassSubtractionLeft::ClassSubtraction(self.alloc(subtraction));
+        }
+    }
+
+    /// `&&` or `--`, which must not be followed by a third `&` or `-`
+    fn eat_class_set_operator(&mut self, operator: &str) -> Result<()> {
+        let start = self.lexer.offset();
+        self.lexer.eat_str(operator);
+        if self.lexer.at_str(&operator[..1]) {
+            self.lexer.bump();
+            return Err(self.error("Invalid set operation in character class", start));
+        }
+        Ok(())
+    }
+
+    /// A nested class, `\q{…}`, a character class escape or a character,
+    /// and whether it may contain strings
+    fn parse_class_set_operand(&mut self) -> Result<(ClassSetOperand<'a>, bool)> {
+        let start = self.lexer.offset();
+        match self.lexer.peek() {
+            Some('[') => {
+                self.lexer.bump();
+                let negate = self.lexer.eat('^');
+                let (class, strings) = self.parse_class_set(start, negate)?;
+                let operand = match class {
+                    ClassSet::Union(class) => ClassSetOperand::UnicodeSetsCharacterClass(class),
+                    ClassSet::Expression(class) => ClassSetOperand::ExpressionCharacterClass(class),
+                };
+                return Ok((operand, strings));
+            }
+            Some('\\') => {
+                self.lexer.bump();
+            }
+            Some(c)
+                if is_class_set_syntax_character(c) || self.at_class_set_double_punctuator() =>
+            {
+                self.lexer.bump();
+                return Err(self.error("Invalid character in character class", start));
+            }
+            Some(c) => {
+                self.lexer.bump();
+                return Ok((
+                    ClassSetOperand::Character(self.character(start, u32::from(c))),
+                    false,
+                ));
+            }
+            None => return Err(self.error("Unterminated character class", start)),
+        }
+        match self.lexer.peek() {
+            Some('q') if self.lexer.peek_nth(1) == Some('{') => {
+                self.parse_class_string_disjunction(start)
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                let set = self.parse_escape_character_set(start);
+                Ok((ClassSetOperand::EscapeCharacterSet(self.alloc(set)), false))
+            }
+            Some('p' | 'P') => {
+                let set = self.parse_unicode_property(start)?;
+                let strings = matches!(
+                    set,
+                    UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(_)
+                );
+                Ok((ClassSetOperand::UnicodePropertyCharacterSet(self.alloc(set)), strings))
+            }
+            _ => {
+                let value = self.parse_character_escape(start, /* in_class */ true)?;
+                Ok((ClassSetOperand::Character(self.character(start, value)), false))
+            }
+        }
+    }
+
+    /// `q{abc|d}` after `\`, which may contain strings unless every alternative is one character
+    fn parse_class_string_disjunction(
+        &mut self,
+        start: usize,
+    ) -> Result<(ClassSetOperand<'a>, bool)> {
+        self.lexer.eat_str("q{");
+        let mut alternatives = Vec::new_in(self.allocator);
+        let mut strings = false;
+        loop {
+            let alternative_start = self.lexer.offset();
+            let mut elements = Vec::new_in(self.allocator);
+            while !self.lexer.at('|') && !self.lexer.at('}') {
+                let character_start = self.lexer.offset();
+                let value = match self.lexer.bump() {
+                    Some('\\') => {
+                        self.parse_character_escape(character_start, /* in_class */ true)?
+                    }
+                    Some(c) if !is_class_set_syntax_character(c) => u32::from(c),
+                    Some(_) => {
+                        return Err(
+                            self.error("Invalid character in character class", character_start)
+                        );
+                    }
+                    None => return Err(self.error("Unterminated class string disjunction", start)),
+                };
+                elements.push(Character { span: self.span(character_start), value });
+            }
+            strings |= elements.len() != 1;
+            let alternative = StringAlternative { span: self.span(alternative_start), elements };
+            alternatives.push(alternative);
+            if self.lexer.eat('}') {
+                break;
+            }
+            self.lexer.bump();
+        }
+        let disjunction = ClassStringDisjunction { span: self.span(start), alternatives };
+        Ok((ClassSetOperand::ClassStringDisjunction(self.alloc(disjunction)), strings))
+    }
+
+    /// `&&`, `!!`, `##` and the other doubled punctuators reserved in character classes
+    fn at_class_set_double_punctuator(&self) -> bool {
+        match (self.lexer.peek(), self.lexer.peek_nth(1)) {
+            (Some(c), Some(next)) => c == next && "&!#$%*+,.:;<=>?@^`~".contains(c),
+            _ => false,
+        }
+    }
+}
+
+fn union_element(operand: ClassSetOperand<'_>) -> UnicodeSetsCharacterClassElement<'_> {
+    match operand {
+        ClassSetOperand::Character(c) => UnicodeSetsCharacterClassElement::Character(c),
+        ClassSetOperand::ClassStringDisjunction(disjunction) => {
+            UnicodeSetsCharacterClassElement::ClassStringDisjunction(disjunction)
+        }
+        ClassSetOperand::EscapeCharacterSet(set) => {
+            UnicodeSetsCharacterClassElement::EscapeCharacterSet(set)
+        }
+        ClassSetOperand::ExpressionCharacterClass(class) => {
+            UnicodeSetsCharacterClassElement::ExpressionCharacterClass(class)
+        }
+        ClassSetOperand::UnicodePropertyCharacterSet(set) => {
+            UnicodeSetsCharacterClassElement::UnicodePropertyCharacterSet(set)
+        }
+        ClassSetOperand::UnicodeSetsCharacterClass(class) => {
+            UnicodeSetsCharacterClassElement::UnicodeSetsCharacterClass(class)
+        }
+    }
+}
+
+/// `^$\.*+?()[]{}|`
+fn is_syntax_character(c: char) -> bool {
+    matches!(c, '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|')
+}
+
+/// Characters which must be escaped in a character class with the `v` flag
+fn is_class_set_syntax_character(c: char) -> bool {
+    matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|')
+}
+
+/// Characters which can be escaped in a character class with the `v` flag
+fn is_class_set_reserved_punctuator(c: char) -> bool {
+    matches!(c, '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~')
+}
@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{AstNodeId, CallGraph, CallGraphFunction, CallSite, SemanticBuilder};
+use oxc_span::{SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Position, Range,
+    SymbolKind, Url,
+};
+
+/// Call hierarchy of a document, built from the call graph of its module. Calls at the top
+/// level are attributed to an item for the whole file.
+pub struct CallHierarchy<'s> {
+    uri: &'s Url,
+    source_text: &'s str,
+    rope: Rope,
+    call_graph: CallGraph,
+}
+
+impl<'s> CallHierarchy<'s> {
+    /// Returns `None` for files which are not JavaScript or TypeScript.
+    pub fn new(uri: &'s Url, path: &Path, source_text: &'s str) -> Option<Self> {
+        let source_type = SourceType::from_path(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).error_recovery(true).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let call_graph = semantic.call_graph();
+        Some(Self { uri, source_text, rope: Rope::from_str(source_text), call_graph })
+    }
+
+    /// The function called at `position`, or the innermost function containing it
+    pub fn prepare(&self, position: Position) -> Option<Vec<CallHierarchyItem>> {
+        let function = self.call_graph.function_at(self.offset(position)?)?;
+        Some(vec![self.function_item(function)])
+    }
+
+    pub fn incoming_calls(&self, item: &CallHierarchyItem) -> Vec<CallHierarchyIncomingCall> {
+        let Some(callee) = self.find(item) else { return vec![] };
+        group_calls(self.call_graph.incoming_calls(callee), |call| call.caller)
+            .into_iter()
+            .map(|(caller, spans)| CallHierarchyIncomingCall {
+                from: self.item(caller),
+                from_ranges: spans.into_iter().map(|span| self.range(span)).collect(),
+            })
+            .collect()
+    }
+
+    pub fn outgoing_calls(&self, item: &CallHierarchyItem) -> Vec<CallHierarchyOutgoingCall> {
+        // The calls at the top level for the item of the file
+        let caller = if item.kind == SymbolKind::FILE {
+            None
+        } else {
+            let Some(caller) = self.find(item) else { return vec![] };
+            Some(caller)
+        };
+        group_calls(self.call_graph.outgoing_calls(caller), |call| Some(call.callee))
+            .into_iter()
+            .map(|(callee, spans)| CallHierarchyOutgoingCall {
+                to: self.item(callee),
+                from_ranges: spans.into_iter().map(|span| self.range(span)).collect(),
+            })
+            .collect()
+    }
+
+    /// The function of an item created by [`CallHierarchy::function_item`], `None` for the
+    /// item of the file
+    fn find(&self, item: &CallHierarchyItem) -> Option<AstNodeId> {
+        if item.kind == SymbolKind::FILE {
+            return None;
+        }
+        let offset = self.offset(item.selection_range.start)?;
+        self.call_graph
+            .functions()
+            .iter()
+            .find(|function| function.name_span.start == offset)
+            .map(|function| function.node_id)
+    }
+
+    fn item(&self, node_id: Option<AstNodeId>) -> CallHierarchyItem {
+        match node_id.and_then(|node_id| self.call_graph.function(node_id)) {
+            Some(function) => self.function_item(function),
+            None => self.file_item(),
+        }
+    }
+
+    fn function_item(&self, function: &CallGraphFunction) -> CallHierarchyItem {
+        CallHierarchyItem {
+            name: function.name.as_ref().map_or_else(|| "<anonymous>".into(), ToString::to_string),
+            kind: SymbolKind::FUNCTION,
+            tags: None,
+            detail: None,
+            uri: self.uri.clone(),
+            range: self.range(function.span),
+            selection_range: self.range(function.name_span),
+            data: None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn file_item(&self) -> CallHierarchyItem {
+        let name = self
+            .uri
+            .path_segments()
+            .and_then(Iterator::last)
+            .map_or_else(|| self.uri.to_string(), ToString::to_string);
+        CallHierarchyItem {
+            name,
+            kind: SymbolKind::FILE,
+            tags: None,
+            detail: None,
+            uri: self.uri.clone(),
+            range: self.range(Span::new(0, self.source_text.len() as u32)),
+            selection_range: self.range(Span::new(0, 0)),
+            data: None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn position(&self, offset: u32) -> Position {
+        let offset = (offset as usize).min(self.source_text.len());
+        let line = self.rope.byte_to_line(offset);
+        let column = self.rope.byte_to_char(offset) - self.rope.line_to_char(line);
+        Position::new(line as u32, column as u32)
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn offset(&self, position: Position) -> Option<u32> {
+        let line_start = self.rope.try_line_to_char(position.line as usize).ok()?;
+        let offset = self.rope.try_char_to_byte(line_start + position.character as usize).ok()?;
+        Some(offset as u32)
+    }
+}
+
+/// Spans of `calls` grouped by `key`, in the order of the first call of each group
+fn group_calls<'c>(
+    calls: impl Iterator<Item = &'c CallSite>,
+    key: impl Fn(&CallSite) -> Option<AstNodeId>,
+) -> Vec<(Option<AstNodeId>, Vec<Span>)> {
+    let mut groups: Vec<(Option<AstNodeId>, Vec<Span>)> = vec![];
+    for call in calls {
+        let key = key(call);
+        match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+            Some((_, spans)) => spans.push(call.span),
+            None => groups.push((key, vec![call.span])),
+        }
+    }
+    groups
+}
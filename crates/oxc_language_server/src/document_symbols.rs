@@ -0,0 +1,185 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{
+        BindingPatternKind, Expression, MethodDefinitionKind, PropertyKey, TSEnumMemberName,
+        TSMethodSignatureKind, VariableDeclarationKind,
+    },
+    AstKind, Visit,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{DocumentSymbol, Position, Range, SymbolKind};
+
+/// Hierarchical symbols of the declarations in `source_text`: functions, classes and their
+/// members, variables, interfaces, enums, type aliases and namespaces. Returns `None` for files
+/// which are not JavaScript or TypeScript.
+pub fn document_symbols(path: &Path, source_text: &str) -> Option<Vec<DocumentSymbol>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).error_recovery(true).parse();
+
+    let mut collector = DocumentSymbolCollector::new(source_text);
+    collector.visit_program(&ret.program);
+    Some(collector.symbols)
+}
+
+/// A symbol whose children are still being collected
+struct Frame {
+    name: String,
+    kind: SymbolKind,
+    span: Span,
+    selection_span: Span,
+    children: Vec<DocumentSymbol>,
+}
+
+struct DocumentSymbolCollector<'s> {
+    source_text: &'s str,
+    rope: Rope,
+    symbols: Vec<DocumentSymbol>,
+    frames: Vec<Frame>,
+    /// Whether each entered node pushed a frame, popped when the node is left
+    entered: Vec<bool>,
+}
+
+impl<'s> DocumentSymbolCollector<'s> {
+    fn new(source_text: &'s str) -> Self {
+        Self {
+            source_text,
+            rope: Rope::from_str(source_text),
+            symbols: vec![],
+            frames: vec![],
+            entered: vec![],
+        }
+    }
+
+    fn text(&self, span: Span) -> &'s str {
+        self.source_text.get(span.start as usize..span.end as usize).unwrap_or_default()
+    }
+
+    fn property_key_name(&self, key: &PropertyKey) -> String {
+        key.static_name().map_or_else(|| self.text(key.span()).to_string(), |name| name.to_string())
+    }
+
+    fn frame(&self, kind: AstKind) -> Option<Frame> {
+        let (name, symbol_kind, span, selection_span) = match kind {
+            AstKind::Function(func) if func.is_declaration() => {
+                let id = func.id.as_ref()?;
+                (id.name.to_string(), SymbolKind::FUNCTION, func.span, id.span)
+            }
+            AstKind::Class(class) if class.is_declaration() => {
+                let id = class.id.as_ref()?;
+                (id.name.to_string(), SymbolKind::CLASS, class.span, id.span)
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                    return None;
+                };
+                let symbol_kind = match &declarator.init {
+                    Some(
+                        Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_),
+                    ) => SymbolKind::FUNCTION,
+                    Some(Expression::ClassExpression(_)) => SymbolKind::CLASS,
+                    _ if declarator.kind == VariableDeclarationKind::Const => SymbolKind::CONSTANT,
+                    _ => SymbolKind::VARIABLE,
+                };
+                (id.name.to_string(), symbol_kind, declarator.span, id.span)
+            }
+            AstKind::MethodDefinition(def) => {
+                let symbol_kind = match def.kind {
+                    MethodDefinitionKind::Constructor => SymbolKind::CONSTRUCTOR,
+                    MethodDefinitionKind::Method => SymbolKind::METHOD,
+                    MethodDefinitionKind::Get | MethodDefinitionKind::Set => SymbolKind::PROPERTY,
+                };
+                (self.property_key_name(&def.key), symbol_kind, def.span, def.key.span())
+            }
+            AstKind::PropertyDefinition(def) => {
+                (self.property_key_name(&def.key), SymbolKind::PROPERTY, def.span, def.key.span())
+            }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::INTERFACE, decl.span, decl.id.span)
+            }
+            AstKind::TSPropertySignature(signature) => (
+                self.property_key_name(&signature.key),
+                SymbolKind::PROPERTY,
+                signature.span,
+                signature.key.span(),
+            ),
+            AstKind::TSMethodSignature(signature) => {
+                let symbol_kind = match signature.kind {
+                    TSMethodSignatureKind::Method => SymbolKind::METHOD,
+                    TSMethodSignatureKind::Get | TSMethodSignatureKind::Set => SymbolKind::PROPERTY,
+                };
+                (
+                    self.property_key_name(&signature.key),
+                    symbol_kind,
+                    signature.span,
+                    signature.key.span(),
+                )
+            }
+            AstKind::TSEnumDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::ENUM, decl.span, decl.id.span)
+            }
+            AstKind::TSEnumMember(member) => {
+                let name = match &member.id {
+                    TSEnumMemberName::Identifier(ident) => ident.name.to_string(),
+                    TSEnumMemberName::StringLiteral(lit) => lit.value.to_string(),
+                    _ => self.text(member.id.span()).to_string(),
+                };
+                (name, SymbolKind::ENUM_MEMBER, member.span, member.id.span())
+            }
+            AstKind::TSTypeAliasDeclaration(decl) => {
+                (decl.id.name.to_string(), SymbolKind::TYPE_PARAMETER, decl.span, decl.id.span)
+            }
+            AstKind::TSModuleDeclaration(decl) => {
+                (decl.id.name().to_string(), SymbolKind::NAMESPACE, decl.span, decl.id.span())
+            }
+            _ => return None,
+        };
+        Some(Frame { name, kind: symbol_kind, span, selection_span, children: vec![] })
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn position(&self, offset: u32) -> Position {
+        let offset = (offset as usize).min(self.source_text.len());
+        let line = self.rope.byte_to_line(offset);
+        let column = self.rope.byte_to_char(offset) - self.rope.line_to_char(line);
+        Position::new(line as u32, column as u32)
+    }
+
+    fn range(&self, span: Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+}
+
+impl<'a, 's> Visit<'a> for DocumentSymbolCollector<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let frame = self.frame(kind);
+        self.entered.push(frame.is_some());
+        self.frames.extend(frame);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        if self.entered.pop() != Some(true) {
+            return;
+        }
+        let Some(frame) = self.frames.pop() else { return };
+        #[allow(deprecated)]
+        let symbol = DocumentSymbol {
+            name: frame.name,
+            detail: None,
+            kind: frame.kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(frame.span),
+            selection_range: self.range(frame.selection_span),
+            children: if frame.children.is_empty() { None } else { Some(frame.children) },
+        };
+        match self.frames.last_mut() {
+            Some(parent) => parent.children.push(symbol),
+            None => self.symbols.push(symbol),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{ModuleDeclaration, Program, Statement},
+    AstKind, CommentKind, Visit,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind};
+
+/// Folding ranges of functions, classes, blocks, literals, imports, multi-line comments and
+/// `// #region` / `// #endregion` comments. Returns `None` for files which are not JavaScript or
+/// TypeScript.
+pub fn folding_ranges(path: &Path, source_text: &str) -> Option<Vec<FoldingRange>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).error_recovery(true).parse();
+
+    let mut collector = FoldingRangeCollector::new(source_text);
+    collector.visit_program(&ret.program);
+    collector.add_imports(&ret.program);
+    for (start, end, kind) in &ret.trivias.comments {
+        collector.add_comment(Span::new(*start, *end), *kind);
+    }
+
+    let mut ranges = collector.ranges;
+    // Clients fold a single range per line, keep the outermost one.
+    ranges.sort_unstable_by_key(|range| (range.start_line, std::cmp::Reverse(range.end_line)));
+    ranges.dedup_by_key(|range| range.start_line);
+    Some(ranges)
+}
+
+struct FoldingRangeCollector<'s> {
+    source_text: &'s str,
+    rope: Rope,
+    ranges: Vec<FoldingRange>,
+    /// Start lines of the unclosed `#region` comments
+    regions: Vec<u32>,
+}
+
+impl<'s> FoldingRangeCollector<'s> {
+    fn new(source_text: &'s str) -> Self {
+        Self { source_text, rope: Rope::from_str(source_text), ranges: vec![], regions: vec![] }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn line(&self, offset: u32) -> u32 {
+        self.rope.try_byte_to_line(offset as usize).unwrap_or_else(|_| self.rope.len_lines() - 1)
+            as u32
+    }
+
+    fn add(&mut self, start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>) {
+        if start_line < end_line {
+            self.ranges.push(FoldingRange {
+                start_line,
+                end_line,
+                kind,
+                ..FoldingRange::default()
+            });
+        }
+    }
+
+    fn add_span(&mut self, span: Span, kind: Option<FoldingRangeKind>) {
+        self.add(self.line(span.start), self.line(span.end), kind);
+    }
+
+    /// Consecutive import declarations fold together, a single import folds when it spans
+    /// multiple lines.
+    fn add_imports(&mut self, program: &Program) {
+        let mut group: Option<Span> = None;
+        for statement in &program.body {
+            let import_span = match statement {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ImportDeclaration(import) => Some(import.span),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match (import_span, group) {
+                (Some(span), Some(group_span)) => {
+                    group = Some(Span::new(group_span.start, span.end));
+                }
+                (Some(span), None) => group = Some(span),
+                (None, Some(group_span)) => {
+                    self.add_span(group_span, Some(FoldingRangeKind::Imports));
+                    group = None;
+                }
+                (None, None) => {}
+            }
+        }
+        if let Some(group_span) = group {
+            self.add_span(group_span, Some(FoldingRangeKind::Imports));
+        }
+    }
+
+    fn add_comment(&mut self, span: Span, kind: CommentKind) {
+        if kind == CommentKind::MultiLine {
+            self.add_span(span, Some(FoldingRangeKind::Comment));
+            return;
+        }
+        let Some(text) = self.source_text.get(span.start as usize..span.end as usize) else {
+            return;
+        };
+        let text = text.trim_start_matches('/').trim_start();
+        if text.starts_with("#region") {
+            self.regions.push(self.line(span.start));
+        } else if text.starts_with("#endregion") {
+            if let Some(start_line) = self.regions.pop() {
+                self.add(start_line, self.line(span.start), Some(FoldingRangeKind::Region));
+            }
+        }
+    }
+}
+
+impl<'a, 's> Visit<'a> for FoldingRangeCollector<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = match kind {
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::ClassBody(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::ObjectExpression(_)
+            | AstKind::ArrayExpression(_)
+            | AstKind::TemplateLiteral(_)
+            | AstKind::JSXElement(_)
+            | AstKind::TSModuleBlock(_)
+            | AstKind::TSTypeLiteral(_) => kind.span(),
+            AstKind::SwitchStatement(stmt) => stmt.body_span(),
+            AstKind::TSEnumDeclaration(decl) => decl.body_span(),
+            AstKind::TSInterfaceDeclaration(decl) => decl.body.span,
+            _ => return,
+        };
+        self.add_span(span, None);
+    }
+}
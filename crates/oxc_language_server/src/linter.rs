@@ -8,8 +8,9 @@ use std::{
 
 use miette::NamedSource;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{miette, Error, Severity};
+use oxc_diagnostics::{miette, CancellationToken, Error, Severity};
 use oxc_linter::{
+    organize_imports as organize_program_imports,
     partial_loader::{
         AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
         LINT_PARTIAL_LOADER_EXT,
@@ -18,8 +19,7 @@ use oxc_linter::{
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use ropey::Rope;
+use oxc_span::{SourceCode, SourceType, VALID_EXTENSIONS};
 use tower_lsp::lsp_types::{
     self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
 };
@@ -43,7 +43,7 @@ struct LabeledSpanWithPosition {
 impl ErrorWithPosition {
     pub fn new(
         error: Error,
-        text: &str,
+        source: &SourceCode,
         fixed_content: Option<FixedContent>,
         start: usize,
     ) -> Self {
@@ -51,11 +51,11 @@ impl ErrorWithPosition {
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset() + start, text)
+                start_pos: offset_to_position(labeled_span.offset() + start, source)
                     .unwrap_or_default(),
                 end_pos: offset_to_position(
                     labeled_span.offset() + start + labeled_span.len(),
-                    text,
+                    source,
                 )
                 .unwrap_or_default(),
                 message: labeled_span.label().map(ToString::to_string),
@@ -163,13 +163,21 @@ impl IsolatedLintHandler {
         Self { linter }
     }
 
+    /// Returns `None` for unsupported files, or when `cancellation_token` is cancelled
+    /// before linting finishes.
     pub fn run_single(
         &self,
         path: &Path,
         content: Option<String>,
+        cancellation_token: &CancellationToken,
     ) -> Option<Vec<DiagnosticReport>> {
         if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path, content).map_or(vec![], |(p, errors)| {
+            let result = Self::lint_path(&self.linter, path, content, cancellation_token);
+            if cancellation_token.is_cancelled() {
+                debug!("lint {path:?} cancelled");
+                return None;
+            }
+            Some(result.map_or(vec![], |(p, errors)| {
                 let mut diagnostics: Vec<DiagnosticReport> =
                     errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect();
                 // a diagnostics connected from related_info to original diagnostic
@@ -256,10 +264,12 @@ impl IsolatedLintHandler {
         linter: &Linter,
         path: &Path,
         source_text: Option<String>,
+        cancellation_token: &CancellationToken,
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
         let (source_type, original_source_text) =
             Self::get_source_type_and_text(path, source_text, ext)?;
+        let source_code = SourceCode::new(&original_source_text);
         let javascript_sources = Self::may_need_extract_js_content(&original_source_text, ext)
             .unwrap_or_else(|| {
                 vec![JavaScriptSource { source_text: &original_source_text, source_type, start: 0 }]
@@ -273,36 +283,47 @@ impl IsolatedLintHandler {
             let allocator = Allocator::default();
             let ret = Parser::new(&allocator, javascript_source_text, source_type)
                 .allow_return_outside_function(true)
+                .cancellation_token(cancellation_token)
                 .parse();
 
+            if cancellation_token.is_cancelled() {
+                return None;
+            }
+
             if !ret.errors.is_empty() {
                 let reports = ret
                     .errors
                     .into_iter()
                     .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                     .collect();
-                return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
+                return Some(Self::wrap_diagnostics(path, &source_code, reports, start));
             };
 
             let program = allocator.alloc(ret.program);
             let semantic_ret = SemanticBuilder::new(javascript_source_text, source_type)
                 .with_trivias(ret.trivias)
                 .with_check_syntax_error(true)
+                .with_cancellation_token(cancellation_token)
                 .build(program);
 
+            if cancellation_token.is_cancelled() {
+                return None;
+            }
+
             if !semantic_ret.errors.is_empty() {
                 let reports = semantic_ret
                     .errors
                     .into_iter()
                     .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                     .collect();
-                return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
+                return Some(Self::wrap_diagnostics(path, &source_code, reports, start));
             };
 
             let lint_ctx = LintContext::new(
                 path.to_path_buf().into_boxed_path(),
                 &Rc::new(semantic_ret.semantic),
-            );
+            )
+            .with_cancellation_token(cancellation_token);
 
             let result = linter.run(lint_ctx);
 
@@ -312,16 +333,10 @@ impl IsolatedLintHandler {
                     let fixed_content = msg.fix.map(|f| FixedContent {
                         code: f.content.to_string(),
                         range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
+                            start: offset_to_position(f.span.start as usize + start, &source_code)
+                                .unwrap_or_default(),
+                            end: offset_to_position(f.span.end as usize + start, &source_code)
+                                .unwrap_or_default(),
                         },
                     });
 
@@ -329,7 +344,7 @@ impl IsolatedLintHandler {
                 })
                 .collect::<Vec<ErrorReport>>();
             let (_, errors_with_position) =
-                Self::wrap_diagnostics(path, &original_source_text, reports, start);
+                Self::wrap_diagnostics(path, &source_code, reports, start);
             diagnostics.extend(errors_with_position);
         }
 
@@ -338,17 +353,18 @@ impl IsolatedLintHandler {
 
     fn wrap_diagnostics(
         path: &Path,
-        source_text: &str,
+        source_code: &SourceCode,
         reports: Vec<ErrorReport>,
         start: usize,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
-        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+        let source =
+            Arc::new(NamedSource::new(path.to_string_lossy(), source_code.text().to_owned()));
         let diagnostics = reports
             .into_iter()
             .map(|report| {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
-                    source_text,
+                    source_code,
                     report.fixed_content,
                     start,
                 )
@@ -366,15 +382,37 @@ fn get_valid_extensions() -> Vec<&'static str> {
         .collect::<Vec<&'static str>>()
 }
 
-#[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_byte_to_line(offset).ok()?;
-    let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    // Original offset is byte, but Rope uses char offset
-    let offset = rope.try_byte_to_char(offset).ok()?;
-    let column = offset - first_char_of_line;
-    Some(Position::new(line as u32, column as u32))
+/// The edit which organizes the imports of `source_text`, `None` if they are already organized.
+/// Only JavaScript and TypeScript files without syntax errors are organized.
+pub fn organize_imports(path: &Path, source_text: &str) -> Option<FixedContent> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic_ret =
+        SemanticBuilder::new(source_text, source_type).with_trivias(ret.trivias).build(program);
+    let fix = organize_program_imports(program, &semantic_ret.semantic)?;
+    let source_code = SourceCode::new(source_text);
+    Some(FixedContent {
+        code: fix.content.to_string(),
+        range: Range {
+            start: offset_to_position(fix.span.start as usize, &source_code)?,
+            end: offset_to_position(fix.span.end as usize, &source_code)?,
+        },
+    })
+}
+
+/// The position of the byte offset `offset`, with the column in UTF-16 code units as in LSP
+fn offset_to_position(offset: usize, source_code: &SourceCode) -> Option<Position> {
+    let text = source_code.text();
+    if !text.is_char_boundary(offset) {
+        return None;
+    }
+    let (line, column) = source_code.line_column(u32::try_from(offset).ok()?);
+    Some(Position::new(line, column))
 }
 
 #[derive(Debug)]
@@ -384,7 +422,8 @@ pub struct ServerLinter {
 
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::default().with_fix(true);
+        // Quick fixes are applied one at a time by the user, dangerous ones included
+        let linter = Linter::default().with_fix(true).with_fix_dangerously(true);
         Self { linter: Arc::new(linter) }
     }
 
@@ -392,9 +431,17 @@ impl ServerLinter {
         Self { linter: Arc::new(linter) }
     }
 
-    pub fn run_single(&self, uri: &Url, content: Option<String>) -> Option<Vec<DiagnosticReport>> {
-        IsolatedLintHandler::new(Arc::clone(&self.linter))
-            .run_single(&uri.to_file_path().unwrap(), content)
+    pub fn run_single(
+        &self,
+        uri: &Url,
+        content: Option<String>,
+        cancellation_token: &CancellationToken,
+    ) -> Option<Vec<DiagnosticReport>> {
+        IsolatedLintHandler::new(Arc::clone(&self.linter)).run_single(
+            &uri.to_file_path().unwrap(),
+            content,
+            cancellation_token,
+        )
     }
 }
 
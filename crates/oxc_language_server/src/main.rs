@@ -1,40 +1,60 @@
+mod call_hierarchy;
+mod document_symbols;
+mod folding_ranges;
 mod linter;
 mod options;
+mod semantic_tokens;
+mod workspace;
 
-use crate::linter::{DiagnosticReport, ServerLinter};
-use globset::Glob;
-use ignore::gitignore::Gitignore;
+use crate::call_hierarchy::CallHierarchy;
+use crate::linter::{organize_imports, DiagnosticReport, FixedContent};
+use crate::workspace::Workspace;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_diagnostics::CancellationToken;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use dashmap::DashMap;
 use futures::future::join_all;
-use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
-use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
+use tokio::sync::{Mutex, OnceCell, RwLock};
+use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
-    CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    CallHierarchyServerCapability, CodeAction, CodeActionKind, CodeActionOptions,
+    CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
+    ConfigurationItem, Diagnostic, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions,
+    DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, DocumentSymbolParams, DocumentSymbolResponse, FileSystemWatcher,
+    FoldingRange, FoldingRangeParams, FoldingRangeProviderCapability, GlobPattern,
+    InitializeParams, InitializeResult, InitializedParams, OneOf, Registration, SemanticTokens,
+    SemanticTokensFullOptions, SemanticTokensOptions, SemanticTokensParams, SemanticTokensResult,
+    SemanticTokensServerCapabilities, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Unregistration, Url, WorkDoneProgressOptions, WorkspaceEdit,
     WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
+/// Id of the registration of `workspace/didChangeWatchedFiles`
+const WATCHED_FILES_REGISTRATION_ID: &str = "oxc/watchedFiles";
+
 #[derive(Debug)]
 struct Backend {
     client: Client,
-    root_uri: OnceCell<Option<Url>>,
-    server_linter: RwLock<ServerLinter>,
+    /// Workspace folders, each with its own linter configuration and ignore files
+    workspaces: RwLock<Vec<Workspace>>,
+    /// Whether the client can watch the configuration and ignore files for us
+    watch_files: OnceCell<bool>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// Latest content of each open document, to lint it again when its configuration changes
+    documents: DashMap<String, String>,
+    /// Token of the latest lint run of each document, cancelled when a newer run starts
+    cancellation_tokens: DashMap<String, CancellationToken>,
     options: Mutex<Options>,
-    gitignore_glob: Mutex<Option<Gitignore>>,
 }
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
@@ -86,9 +106,6 @@ enum SyntheticRunLevel {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
-        self.init(params.root_uri)?;
-        self.init_ignore_glob().await;
-        self.init_linter_config().await;
         let options = params.initialization_options.and_then(|mut value| {
             let settings = value.get_mut("settings")?.take();
             serde_json::from_value::<Options>(settings).ok()
@@ -99,6 +116,25 @@ impl LanguageServer for Backend {
             info!("language server version: {:?}", env!("CARGO_PKG_VERSION"));
             *self.options.lock().await = value;
         }
+
+        let watch_files = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+            .and_then(|capability| capability.dynamic_registration)
+            .unwrap_or(false);
+        let _ = self.watch_files.set(watch_files);
+
+        // `root_uri` is deprecated in favor of `workspace_folders`, but some clients only send it.
+        #[allow(deprecated)]
+        let root_uris = match params.workspace_folders {
+            Some(folders) if !folders.is_empty() => {
+                folders.into_iter().map(|folder| folder.uri).collect()
+            }
+            _ => params.root_uri.into_iter().collect::<Vec<_>>(),
+        };
+        self.add_workspaces(root_uris).await;
         Ok(InitializeResult {
             server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
             offset_encoding: None,
@@ -115,13 +151,29 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+                        ]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
                         resolve_provider: None,
                     },
                 )),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                semantic_tokens_provider: Some(
+                    SemanticTokensServerCapabilities::SemanticTokensOptions(
+                        SemanticTokensOptions {
+                            work_done_progress_options: WorkDoneProgressOptions::default(),
+                            legend: semantic_tokens::legend(),
+                            range: None,
+                            full: Some(SemanticTokensFullOptions::Bool(true)),
+                        },
+                    ),
+                ),
                 ..ServerCapabilities::default()
             },
         })
@@ -169,11 +221,107 @@ impl LanguageServer for Backend {
                 .collect::<Vec<_>>();
             self.publish_all_diagnostics(&cleared_diagnostics).await;
         }
-        *self.options.lock().await = changed_options;
+        let config_path_changed = {
+            let mut options = self.options.lock().await;
+            let config_path_changed = options.config_path != changed_options.config_path;
+            *options = changed_options;
+            config_path_changed
+        };
+        if config_path_changed {
+            let config_path = self.options.lock().await.get_config_path();
+            for workspace in self.workspaces.write().await.iter_mut() {
+                workspace.reload_config(config_path.as_deref());
+            }
+            self.register_watched_files(true).await;
+            self.relint_documents(None).await;
+        }
     }
 
     async fn initialized(&self, _params: InitializedParams) {
         debug!("oxc initialized.");
+        self.register_watched_files(false).await;
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        let removed = params.event.removed;
+        if !removed.is_empty() {
+            let removed_paths = removed
+                .iter()
+                .filter_map(|folder| folder.uri.to_file_path().ok())
+                .collect::<Vec<_>>();
+            self.workspaces.write().await.retain(|workspace| {
+                !removed.iter().any(|folder| folder.uri == *workspace.root_uri())
+            });
+            // Documents of removed folders are no longer linted, unless another folder contains them
+            let cleared_diagnostics = self
+                .diagnostics_report_map
+                .iter()
+                .filter_map(|entry| Url::from_str(entry.key()).ok()?.to_file_path().ok())
+                .filter(|path| removed_paths.iter().any(|root| path.starts_with(root)))
+                .collect::<Vec<_>>();
+            let workspaces = self.workspaces.read().await;
+            let cleared_diagnostics = cleared_diagnostics
+                .into_iter()
+                .filter(|path| Workspace::find(&workspaces, path).is_none())
+                .map(|path| (path, vec![]))
+                .collect::<Vec<_>>();
+            drop(workspaces);
+            for (path, _) in &cleared_diagnostics {
+                if let Ok(uri) = Url::from_file_path(path) {
+                    self.diagnostics_report_map.remove(&uri.to_string());
+                }
+            }
+            self.publish_all_diagnostics(&cleared_diagnostics).await;
+        }
+
+        let added = params.event.added;
+        if !added.is_empty() {
+            let added_paths = added
+                .iter()
+                .filter_map(|folder| folder.uri.to_file_path().ok())
+                .collect::<Vec<_>>();
+            self.add_workspaces(added.into_iter().map(|folder| folder.uri).collect()).await;
+            for root in &added_paths {
+                self.relint_documents(Some(root)).await;
+            }
+        }
+    }
+
+    /// Configuration and ignore files changed on disk, reload them for the workspaces they belong to.
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let config_path = self.options.lock().await.get_config_path();
+        let mut changed_roots = vec![];
+        {
+            let mut workspaces = self.workspaces.write().await;
+            for change in params.changes {
+                let Ok(path) = change.uri.to_file_path() else {
+                    continue;
+                };
+                for workspace in workspaces.iter_mut() {
+                    if !path.starts_with(workspace.root_path()) {
+                        continue;
+                    }
+                    if workspace.is_config(&path, config_path.as_deref()) {
+                        debug!("config changed: {path:?}");
+                        workspace.reload_config(config_path.as_deref());
+                    } else if path
+                        .file_name()
+                        .is_some_and(|name| name == ".gitignore" || name == ".eslintignore")
+                    {
+                        debug!("ignore file changed: {path:?}");
+                        workspace.reload_ignore();
+                    } else {
+                        continue;
+                    }
+                    if !changed_roots.contains(&workspace.root_path().to_path_buf()) {
+                        changed_roots.push(workspace.root_path().to_path_buf());
+                    }
+                }
+            }
+        }
+        for root in &changed_roots {
+            self.relint_documents(Some(root)).await;
+        }
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -207,6 +355,9 @@ impl LanguageServer for Backend {
             return;
         }
         let content = params.content_changes.first().map(|c| c.text.clone());
+        if let Some(content) = &content {
+            self.documents.insert(uri.to_string(), content.clone());
+        }
         self.handle_file_update(
             params.text_document.uri,
             content,
@@ -216,6 +367,8 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents
+            .insert(params.text_document.uri.to_string(), params.text_document.text.clone());
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -230,10 +383,15 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
+        self.documents.remove(&uri);
+        if let Some((_, token)) = self.cancellation_tokens.remove(&uri) {
+            token.cancel();
+        }
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
+        let mut actions = vec![];
 
         if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
             if let Some(report) = value
@@ -248,13 +406,13 @@ impl LanguageServer for Backend {
 
                 let fixed_content = report.fixed_content.clone().unwrap();
 
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
                     title,
                     kind: Some(CodeActionKind::QUICKFIX),
                     is_preferred: Some(true),
                     edit: Some(WorkspaceEdit {
                         changes: Some(HashMap::from([(
-                            uri,
+                            uri.clone(),
                             vec![TextEdit {
                                 range: fixed_content.range,
                                 new_text: fixed_content.code,
@@ -266,55 +424,153 @@ impl LanguageServer for Backend {
                     data: None,
                     diagnostics: None,
                     command: None,
-                })]));
+                }));
             }
         }
 
-        Ok(None)
+        // Source actions are only computed when the client asks for them, e.g. on save.
+        let organize_imports_requested = params.context.only.as_ref().is_some_and(|only| {
+            only.iter().any(|kind| {
+                CodeActionKind::SOURCE_ORGANIZE_IMPORTS.as_str().starts_with(kind.as_str())
+            })
+        });
+        if organize_imports_requested {
+            if let Some(fixed_content) = self.organize_imports(&uri) {
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: "Organize imports".into(),
+                    kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(
+                            uri,
+                            vec![TextEdit {
+                                range: fixed_content.range,
+                                new_text: fixed_content.code,
+                            }],
+                        )])),
+                        ..WorkspaceEdit::default()
+                    }),
+                    ..CodeAction::default()
+                }));
+            }
+        }
+
+        Ok(if actions.is_empty() { None } else { Some(actions) })
     }
-}
 
-impl Backend {
-    fn init(&self, root_uri: Option<Url>) -> Result<()> {
-        self.root_uri.set(root_uri).map_err(|err| {
-            let message = match err {
-                SetError::AlreadyInitializedError(_) => "root uri already initialized".into(),
-                SetError::InitializingError(_) => "initializing error".into(),
-            };
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let uri = params.text_document.uri;
+        let Some(source_text) = self.document_text(&uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(folding_ranges::folding_ranges(&path, &source_text))
+    }
 
-            Error { code: ErrorCode::ParseError, message, data: None }
-        })?;
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri;
+        let Some(source_text) = self.document_text(&uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(document_symbols::document_symbols(&path, &source_text)
+            .map(DocumentSymbolResponse::Nested))
+    }
 
-        Ok(())
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        let Some(source_text) = self.document_text(&uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(CallHierarchy::new(&uri, &path, &source_text)
+            .and_then(|call_hierarchy| call_hierarchy.prepare(position)))
     }
 
-    async fn init_ignore_glob(&self) {
-        let uri = self
-            .root_uri
-            .get()
-            .expect("The root uri should be initialized already")
-            .as_ref()
-            .expect("should get uri");
-        let mut builder = globset::GlobSetBuilder::new();
-        // Collecting all ignore files
-        builder.add(Glob::new("**/.eslintignore").unwrap());
-        builder.add(Glob::new("**/.gitignore").unwrap());
-
-        let ignore_file_glob_set = builder.build().unwrap();
-
-        let mut gitignore_builder = ignore::gitignore::GitignoreBuilder::new(uri.path());
-        let walk = ignore::WalkBuilder::new(uri.path())
-            .ignore(true)
-            .hidden(false)
-            .git_global(false)
-            .build();
-        for entry in walk.flatten() {
-            if ignore_file_glob_set.is_match(entry.path()) {
-                gitignore_builder.add(entry.path());
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        let uri = &params.item.uri;
+        let Some(source_text) = self.document_text(uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(CallHierarchy::new(uri, &path, &source_text)
+            .map(|call_hierarchy| call_hierarchy.incoming_calls(&params.item)))
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let uri = &params.item.uri;
+        let Some(source_text) = self.document_text(uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(CallHierarchy::new(uri, &path, &source_text)
+            .map(|call_hierarchy| call_hierarchy.outgoing_calls(&params.item)))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let uri = params.text_document.uri;
+        let Some(source_text) = self.document_text(&uri) else { return Ok(None) };
+        let Ok(path) = uri.to_file_path() else { return Ok(None) };
+        Ok(semantic_tokens::semantic_tokens(&path, &source_text)
+            .map(|data| SemanticTokensResult::Tokens(SemanticTokens { result_id: None, data })))
+    }
+}
+
+impl Backend {
+    async fn add_workspaces(&self, root_uris: Vec<Url>) {
+        let config_path = self.options.lock().await.get_config_path();
+        let mut workspaces = self.workspaces.write().await;
+        for root_uri in root_uris {
+            if workspaces.iter().any(|workspace| *workspace.root_uri() == root_uri) {
+                continue;
+            }
+            debug!("add workspace {root_uri}");
+            if let Some(workspace) = Workspace::new(root_uri, config_path.as_deref()) {
+                workspaces.push(workspace);
+            }
+        }
+    }
+
+    /// Ask the client to watch the configuration and ignore files of all workspaces,
+    /// `reregister` replaces the watchers after the configuration path changed.
+    async fn register_watched_files(&self, reregister: bool) {
+        if !self.watch_files.get().copied().unwrap_or(false) {
+            return;
+        }
+        if reregister {
+            let unregistration = Unregistration {
+                id: WATCHED_FILES_REGISTRATION_ID.into(),
+                method: "workspace/didChangeWatchedFiles".into(),
+            };
+            if let Err(err) = self.client.unregister_capability(vec![unregistration]).await {
+                error!("failed to unregister watched files: {err}");
             }
         }
 
-        *self.gitignore_glob.lock().await = gitignore_builder.build().ok();
+        let mut globs = vec!["**/.gitignore".to_string(), "**/.eslintignore".to_string()];
+        if let Some(config_path) = self.options.lock().await.get_config_path() {
+            globs.push(format!("**/{}", config_path.to_string_lossy()));
+        }
+        let watchers = globs
+            .into_iter()
+            .map(|glob| FileSystemWatcher { glob_pattern: GlobPattern::String(glob), kind: None })
+            .collect();
+        let registration = Registration {
+            id: WATCHED_FILES_REGISTRATION_ID.into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+                watchers,
+            })
+            .ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            error!("failed to register watched files: {err}");
+        }
     }
 
     #[allow(clippy::ptr_arg)]
@@ -329,59 +585,89 @@ impl Backend {
         .await;
     }
 
-    async fn init_linter_config(&self) {
-        let Some(Some(uri)) = self.root_uri.get() else {
-            return;
-        };
-        let Ok(root_path) = uri.to_file_path() else {
+    /// Lint the open documents under `root` again, or all of them if `root` is `None`.
+    async fn relint_documents(&self, root: Option<&Path>) {
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level < SyntheticRunLevel::OnSave {
             return;
-        };
-        let mut config_path = None;
-        let config = root_path.join(self.options.lock().await.get_config_path().unwrap());
-        if config.exists() {
-            config_path = Some(config);
         }
-        if let Some(config_path) = config_path {
-            let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
-                )
-                .expect("should have initialized linter with new options"),
-            );
+        let documents = self
+            .documents
+            .iter()
+            .filter_map(|entry| {
+                let uri = Url::from_str(entry.key()).ok()?;
+                let path = uri.to_file_path().ok()?;
+                root.map_or(true, |root| path.starts_with(root))
+                    .then(|| (uri, entry.value().clone()))
+            })
+            .collect::<Vec<_>>();
+        for (uri, content) in documents {
+            if self.is_ignored(&uri).await {
+                self.diagnostics_report_map.remove(&uri.to_string());
+                self.client.publish_diagnostics(uri, vec![], None).await;
+                continue;
+            }
+            self.handle_file_update(uri, Some(content), None).await;
+        }
+    }
+
+    /// Organize the imports of the open document `uri`, or of the file on disk.
+    fn organize_imports(&self, uri: &Url) -> Option<FixedContent> {
+        let path = uri.to_file_path().ok()?;
+        let source_text = self.document_text(uri)?;
+        organize_imports(&path, &source_text)
+    }
+
+    /// The content of the open document `uri`, or of the file on disk.
+    fn document_text(&self, uri: &Url) -> Option<String> {
+        match self.documents.get(&uri.to_string()) {
+            Some(content) => Some(content.value().clone()),
+            None => std::fs::read_to_string(uri.to_file_path().ok()?).ok(),
         }
     }
 
     async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
-        if let Some(Some(_root_uri)) = self.root_uri.get() {
-            if let Some(diagnostics) = self.server_linter.read().await.run_single(&uri, content) {
-                self.client
-                    .publish_diagnostics(
-                        uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                        version,
-                    )
-                    .await;
+        let Ok(path) = uri.to_file_path() else {
+            return;
+        };
+        // Abandon the lint run of the previous snapshot, its result is outdated
+        let cancellation_token = CancellationToken::new();
+        if let Some(previous) =
+            self.cancellation_tokens.insert(uri.to_string(), cancellation_token.clone())
+        {
+            previous.cancel();
+        }
+        let diagnostics = {
+            let workspaces = self.workspaces.read().await;
+            // Files outside of all workspace folders are linted with the first one
+            let Some(workspace) = Workspace::find(&workspaces, &path).or(workspaces.first()) else {
+                return;
+            };
+            workspace.run_single(&uri, content, &cancellation_token)
+        };
+        if let Some(diagnostics) = diagnostics {
+            self.client
+                .publish_diagnostics(
+                    uri.clone(),
+                    diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                    version,
+                )
+                .await;
 
-                self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
-            }
+            self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
         }
     }
 
     async fn is_ignored(&self, uri: &Url) -> bool {
-        let Some(Some(root_uri)) = self.root_uri.get() else {
+        let Ok(path) = uri.to_file_path() else {
             return false;
         };
-
-        // The file is not under current workspace
-        if !uri.path().starts_with(root_uri.path()) {
-            return false;
-        }
-        let Some(ref gitignore_globs) = *self.gitignore_glob.lock().await else {
+        let workspaces = self.workspaces.read().await;
+        // The file is not under any workspace
+        let Some(workspace) = Workspace::find(&workspaces, &path) else {
             return false;
         };
-        let path = PathBuf::from(uri.path());
-        let ignored = gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore();
+        let ignored = workspace.is_ignored(&path);
         if ignored {
             debug!("ignored: {uri}");
         }
@@ -396,16 +682,16 @@ async fn main() {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let server_linter = ServerLinter::new();
     let diagnostics_report_map = DashMap::new();
 
     let (service, socket) = LspService::build(|client| Backend {
         client,
-        root_uri: OnceCell::new(),
-        server_linter: RwLock::new(server_linter),
+        workspaces: RwLock::new(vec![]),
+        watch_files: OnceCell::new(),
         diagnostics_report_map,
+        documents: DashMap::new(),
+        cancellation_tokens: DashMap::new(),
         options: Mutex::new(Options::default()),
-        gitignore_glob: Mutex::new(None),
     })
     .finish();
 
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_parser::Parser;
+use oxc_semantic::{Reference, Semantic, SemanticBuilder, SymbolFlags, SymbolId};
+use oxc_span::{SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensLegend,
+};
+
+/// Token types in the order of the legend, `TokenType as u32` is the index into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    TypeParameter,
+    Parameter,
+    Variable,
+    Function,
+    EnumMember,
+}
+
+const TOKEN_TYPES: [SemanticTokenType; 10] = [
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::ENUM_MEMBER,
+];
+
+/// Token modifiers in the order of the legend, each one is a bit of the modifiers bitset.
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 4] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::MODIFICATION,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+/// The symbol is written to after its declaration.
+const MODIFICATION: u32 = 1 << 2;
+/// Unresolved references, i.e. globals such as `window` or `Promise`.
+const DEFAULT_LIBRARY: u32 = 1 << 3;
+
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Semantic tokens of the declarations and references of all identifiers in `source_text`,
+/// classified with the symbol table. Returns `None` for files which are not JavaScript or
+/// TypeScript.
+pub fn semantic_tokens(path: &Path, source_text: &str) -> Option<Vec<SemanticToken>> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).error_recovery(true).parse();
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+
+    let mut tokens = collect_tokens(&semantic);
+    tokens.sort_unstable_by_key(|(span, _, _)| span.start);
+    tokens.dedup_by_key(|(span, _, _)| span.start);
+    Some(encode(source_text, &tokens))
+}
+
+fn collect_tokens(semantic: &Semantic) -> Vec<(Span, TokenType, u32)> {
+    let symbols = semantic.symbols();
+    let mut tokens = vec![];
+
+    for symbol_id in symbols.iter() {
+        let token_type = symbol_token_type(semantic, symbol_id);
+        let flag = symbols.get_flag(symbol_id);
+        let mut modifiers = 0;
+        if flag.is_const_variable() {
+            modifiers |= READONLY;
+        }
+        if symbols.get_resolved_references(symbol_id).any(Reference::is_write) {
+            modifiers |= MODIFICATION;
+        }
+
+        tokens.push((symbols.get_span(symbol_id), token_type, modifiers | DECLARATION));
+        for reference in symbols.get_resolved_references(symbol_id) {
+            // An import binding may be a type or a value, the reference tells which one it is.
+            let token_type = if flag.is_import_binding() && reference.is_type() {
+                TokenType::Type
+            } else {
+                token_type
+            };
+            tokens.push((reference.span(), token_type, modifiers));
+        }
+    }
+
+    for (_, reference) in symbols.iter_references() {
+        if reference.symbol_id().is_none() {
+            let token_type =
+                if reference.is_type() { TokenType::Type } else { TokenType::Variable };
+            tokens.push((reference.span(), token_type, DEFAULT_LIBRARY));
+        }
+    }
+
+    tokens
+}
+
+fn symbol_token_type(semantic: &Semantic, symbol_id: SymbolId) -> TokenType {
+    let flag = semantic.symbols().get_flag(symbol_id);
+    let declaration = semantic.symbols().get_declaration(symbol_id);
+    if matches!(semantic.nodes().kind(declaration), AstKind::FormalParameter(_)) {
+        TokenType::Parameter
+    } else if flag.contains(SymbolFlags::Class) {
+        TokenType::Class
+    } else if flag.contains(SymbolFlags::Interface) {
+        TokenType::Interface
+    } else if flag.intersects(SymbolFlags::Enum) {
+        TokenType::Enum
+    } else if flag.contains(SymbolFlags::EnumMember) {
+        TokenType::EnumMember
+    } else if flag.contains(SymbolFlags::TypeParameter) {
+        TokenType::TypeParameter
+    } else if flag.contains(SymbolFlags::TypeAlias) {
+        TokenType::Type
+    } else if flag.intersects(SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule) {
+        TokenType::Namespace
+    } else if flag.is_function() {
+        TokenType::Function
+    } else {
+        TokenType::Variable
+    }
+}
+
+/// Encode sorted tokens relative to the previous one, with positions in characters.
+#[allow(clippy::cast_possible_truncation)]
+fn encode(source_text: &str, tokens: &[(Span, TokenType, u32)]) -> Vec<SemanticToken> {
+    let rope = Rope::from_str(source_text);
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    let mut data = Vec::with_capacity(tokens.len());
+
+    for (span, token_type, modifiers) in tokens {
+        let Some(text) = source_text.get(span.start as usize..span.end as usize) else {
+            continue;
+        };
+        // Tokens may not span multiple lines.
+        if text.is_empty() || text.contains('\n') {
+            continue;
+        }
+        let Ok(line) = rope.try_byte_to_line(span.start as usize) else { continue };
+        let start = rope.byte_to_char(span.start as usize) - rope.line_to_char(line);
+
+        let delta_line = line - previous_line;
+        let delta_start = if delta_line == 0 { start - previous_start } else { start };
+        data.push(SemanticToken {
+            delta_line: delta_line as u32,
+            delta_start: delta_start as u32,
+            length: text.chars().count() as u32,
+            token_type: *token_type as u32,
+            token_modifiers_bitset: *modifiers,
+        });
+        previous_line = line;
+        previous_start = start;
+    }
+
+    data
+}
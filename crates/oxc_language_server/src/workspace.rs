@@ -0,0 +1,118 @@
+use std::path::{Path, PathBuf};
+
+use globset::Glob;
+use ignore::gitignore::Gitignore;
+use log::{debug, error};
+use oxc_diagnostics::CancellationToken;
+use oxc_linter::{LintOptions, Linter};
+use tower_lsp::lsp_types::Url;
+
+use crate::linter::{DiagnosticReport, ServerLinter};
+
+/// A workspace folder, linted with its own configuration and ignore files.
+#[derive(Debug)]
+pub struct Workspace {
+    root_uri: Url,
+    root_path: PathBuf,
+    server_linter: ServerLinter,
+    gitignore_glob: Option<Gitignore>,
+}
+
+impl Workspace {
+    /// Returns `None` if `root_uri` is not a file path.
+    pub fn new(root_uri: Url, config_path: Option<&Path>) -> Option<Self> {
+        let root_path = root_uri.to_file_path().ok()?;
+        let mut workspace =
+            Self { root_uri, root_path, server_linter: ServerLinter::new(), gitignore_glob: None };
+        workspace.reload_config(config_path);
+        workspace.reload_ignore();
+        Some(workspace)
+    }
+
+    pub fn root_uri(&self) -> &Url {
+        &self.root_uri
+    }
+
+    pub fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    /// The innermost workspace containing `path`, workspace folders may be nested.
+    pub fn find<'w>(workspaces: &'w [Self], path: &Path) -> Option<&'w Self> {
+        workspaces
+            .iter()
+            .filter(|workspace| path.starts_with(&workspace.root_path))
+            .max_by_key(|workspace| workspace.root_path.components().count())
+    }
+
+    /// Whether `path` is the configuration file of this workspace.
+    pub fn is_config(&self, path: &Path, config_path: Option<&Path>) -> bool {
+        config_path.is_some_and(|config_path| self.root_path.join(config_path) == path)
+    }
+
+    /// Rebuild the linter from `config_path`, relative to the root of the workspace.
+    /// The default linter is used when the configuration file is missing or invalid.
+    pub fn reload_config(&mut self, config_path: Option<&Path>) {
+        let config_path = config_path
+            .map(|config_path| self.root_path.join(config_path))
+            .filter(|config_path| config_path.exists());
+        self.server_linter = match config_path {
+            Some(config_path) => {
+                debug!("load config {config_path:?}");
+                match Linter::from_options(
+                    LintOptions::default()
+                        .with_fix(true)
+                        .with_fix_dangerously(true)
+                        .with_config_path(Some(config_path)),
+                ) {
+                    Ok(linter) => ServerLinter::new_with_linter(linter),
+                    Err(err) => {
+                        error!("invalid config of workspace {}: {err:?}", self.root_uri);
+                        ServerLinter::new()
+                    }
+                }
+            }
+            None => ServerLinter::new(),
+        };
+    }
+
+    /// Collect the `.gitignore` and `.eslintignore` files of the workspace.
+    pub fn reload_ignore(&mut self) {
+        let mut builder = globset::GlobSetBuilder::new();
+        // Collecting all ignore files
+        builder.add(Glob::new("**/.eslintignore").unwrap());
+        builder.add(Glob::new("**/.gitignore").unwrap());
+
+        let ignore_file_glob_set = builder.build().unwrap();
+
+        let mut gitignore_builder = ignore::gitignore::GitignoreBuilder::new(&self.root_path);
+        let walk = ignore::WalkBuilder::new(&self.root_path)
+            .ignore(true)
+            .hidden(false)
+            .git_global(false)
+            .build();
+        for entry in walk.flatten() {
+            if ignore_file_glob_set.is_match(entry.path()) {
+                gitignore_builder.add(entry.path());
+            }
+        }
+
+        self.gitignore_glob = gitignore_builder.build().ok();
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Some(gitignore_globs) = &self.gitignore_glob else {
+            return false;
+        };
+        gitignore_globs.matched_path_or_any_parents(path, path.is_dir()).is_ignore()
+    }
+
+    pub fn run_single(
+        &self,
+        uri: &Url,
+        content: Option<String>,
+        cancellation_token: &CancellationToken,
+    ) -> Option<Vec<DiagnosticReport>> {
+        self.server_linter.run_single(uri, content, cancellation_token)
+    }
+}
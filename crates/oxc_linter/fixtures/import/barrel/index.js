@@ -0,0 +1,4 @@
+export * from "./a";
+export { b as renamed, default as c } from "./b";
+export * as d from "./d";
+export * from "./nested";
@@ -0,0 +1 @@
+export { e } from "./e";
@@ -0,0 +1,2 @@
+export * from "./a";
+console.log("loaded");
@@ -3,7 +3,10 @@ use std::hash::{Hash, Hasher};
 use oxc_ast::AstKind;
 use oxc_semantic::AstNode;
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_syntax::{
+    identifier::{is_line_terminator, ZWNBSP},
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+};
 use rustc_hash::FxHasher;
 
 pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
@@ -405,3 +408,27 @@ pub fn is_global_require_call(call_expr: &CallExpression, ctx: &LintContext) ->
         false
     }
 }
+
+/// Skips whitespace and comments starting at `offset`.
+///
+/// Returns the start of the next token, and whether a line terminator was skipped.
+#[allow(clippy::cast_possible_truncation)]
+pub fn skip_trivia(source_text: &str, offset: u32) -> (u32, bool) {
+    let mut rest = &source_text[offset as usize..];
+    let mut has_line_terminator = false;
+    loop {
+        let trimmed = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ZWNBSP);
+        has_line_terminator |= rest[..rest.len() - trimmed.len()].contains(is_line_terminator);
+        rest = trimmed;
+        if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find(is_line_terminator).map_or("", |end| &comment[end..]);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment.find("*/").map_or(comment.len(), |end| end + 2);
+            has_line_terminator |= comment[..end].contains(is_line_terminator);
+            rest = &comment[end..];
+        } else {
+            break;
+        }
+    }
+    ((source_text.len() - rest.len()) as u32, has_line_terminator)
+}
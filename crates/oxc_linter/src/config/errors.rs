@@ -1,8 +1,9 @@
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
     Report,
 };
+use oxc_span::Span;
 use std::path::PathBuf;
 
 #[derive(Debug, Error, Diagnostic)]
@@ -20,6 +21,16 @@ pub struct FailedToParseConfigError(#[related] pub Vec<Report>);
 #[diagnostic()]
 pub struct FailedToParseConfigPropertyError(pub String);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid configuration at {0}")]
+#[diagnostic()]
+pub struct InvalidConfigError(pub String, pub String, #[label("{1}")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown rule {0:?}")]
+#[diagnostic()]
+pub struct UnknownRuleError(pub String, #[label("oxlint does not implement this rule")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Failed to rule value {0:?} with error {1:?}")]
 #[diagnostic()]
@@ -40,6 +51,16 @@ pub struct FailedToParseAllowWarnDenyFromNumberError(pub String);
 #[diagnostic()]
 pub struct FailedToParseAllowWarnDenyFromJsonValueError(pub String);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to resolve {0:?} extended by config {1:?}")]
+#[diagnostic(help("{2}"))]
+pub struct FailedToResolveConfigExtendsError(pub String, pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Config extends itself: {0}")]
+#[diagnostic()]
+pub struct ConfigExtendsCycleError(pub String);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Failed to parse jsonc file {0:?}")]
 #[diagnostic()]
@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+use oxc_diagnostics::{Error, Report};
+use oxc_resolver::{ResolveOptions, Resolver};
+use serde_json::{Map, Value};
+
+use super::{
+    errors::{
+        ConfigExtendsCycleError, FailedToParseConfigError, FailedToResolveConfigExtendsError,
+    },
+    read_config_json,
+};
+
+/// The JSON of the config file at `path` merged onto the configs it extends.
+///
+/// `extends` is a specifier or an array of specifiers of JSON configs, resolved from the
+/// directory of the config like imports: a path such as `"./base.json"`, or a package such as
+/// `"@company/oxlint-config"` whose `package.json` points to a JSON file with `main` or
+/// `exports`, or which has an `index.json`. Extended configs can extend others in turn,
+/// a config which extends itself is an error.
+///
+/// The extended configs are merged in order, then the config itself,
+/// each one onto the result of the ones before it:
+/// * `plugins` are combined
+/// * `rules` replace the rules of the same name, except that a severity alone keeps the options
+///   of the rule before it, as in ESLint
+/// * objects such as `categories`, `settings` and `env` are merged recursively
+/// * other values are replaced
+pub fn load_config(path: &Path) -> Result<Value, Report> {
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".json".into()],
+        main_files: vec!["index".into()],
+        ..ResolveOptions::default()
+    });
+    // Resolved paths are canonical, so must be the path of the config to detect cycles
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    load(&resolver, &path, &mut vec![])
+}
+
+/// `stack` holds the configs extending the config at `path`
+fn load(resolver: &Resolver, path: &Path, stack: &mut Vec<PathBuf>) -> Result<Value, Report> {
+    if let Some(i) = stack.iter().position(|extending| extending == path) {
+        let cycle =
+            stack[i..].iter().chain([&path.to_path_buf()]).map(|p| p.display()).join(" -> ");
+        return Err(error(ConfigExtendsCycleError(cycle)));
+    }
+    let Value::Object(mut config) = read_config_json(path)? else {
+        unreachable!("the config is validated to be an object")
+    };
+    let extends = match config.remove("extends") {
+        Some(Value::String(specifier)) => vec![specifier],
+        Some(Value::Array(specifiers)) => specifiers
+            .into_iter()
+            .filter_map(|specifier| specifier.as_str().map(String::from))
+            .collect(),
+        _ => vec![],
+    };
+
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let mut merged = Map::new();
+    stack.push(path.to_path_buf());
+    for specifier in extends {
+        let base_path = match resolver.resolve(dir, &specifier) {
+            Ok(resolution) => resolution.path().to_path_buf(),
+            Err(err) => {
+                let path = path.to_path_buf();
+                return Err(error(FailedToResolveConfigExtendsError(
+                    specifier,
+                    path,
+                    err.to_string(),
+                )));
+            }
+        };
+        if let Value::Object(base) = load(resolver, &base_path, stack)? {
+            merge_config(&mut merged, base);
+        }
+    }
+    stack.pop();
+    merge_config(&mut merged, config);
+    Ok(Value::Object(merged))
+}
+
+fn error<T: Into<Error>>(error: T) -> Report {
+    FailedToParseConfigError(vec![error.into()]).into()
+}
+
+/// Merge `config` onto `base`, see [`load_config`]
+fn merge_config(base: &mut Map<String, Value>, config: Map<String, Value>) {
+    for (key, value) in config {
+        let Some(base_value) = base.get_mut(&key) else {
+            base.insert(key, value);
+            continue;
+        };
+        match (key.as_str(), base_value, value) {
+            ("plugins", Value::Array(plugins), Value::Array(added)) => {
+                for plugin in added {
+                    if !plugins.contains(&plugin) {
+                        plugins.push(plugin);
+                    }
+                }
+            }
+            ("rules", Value::Object(rules), Value::Object(overrides)) => {
+                for (name, rule) in overrides {
+                    let severity_only = matches!(rule, Value::String(_) | Value::Number(_));
+                    let base_severity = rules
+                        .get_mut(&name)
+                        .and_then(Value::as_array_mut)
+                        .and_then(|base_rule| base_rule.first_mut());
+                    match base_severity {
+                        Some(severity) if severity_only => *severity = rule,
+                        _ => {
+                            rules.insert(name, rule);
+                        }
+                    }
+                }
+            }
+            (_, Value::Object(base_object), Value::Object(object)) => {
+                merge_objects(base_object, object);
+            }
+            (_, base_value, value) => *base_value = value,
+        }
+    }
+}
+
+fn merge_objects(base: &mut Map<String, Value>, object: Map<String, Value>) {
+    for (key, value) in object {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Object(base_object)), Value::Object(object)) => {
+                merge_objects(base_object, object);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use serde_json::{json, Value};
+
+    use super::{load_config, merge_config};
+
+    #[test]
+    fn merge() {
+        let Value::Object(mut base) = json!({
+            "plugins": ["import"],
+            "rules": { "eqeqeq": ["error", "always"], "no-debugger": "error" },
+            "settings": { "jsx-a11y": { "components": { "Link": "a" } } },
+            "env": { "browser": true }
+        }) else {
+            unreachable!()
+        };
+        let Value::Object(config) = json!({
+            "plugins": ["jest", "import"],
+            "rules": { "eqeqeq": "warn", "no-debugger": ["off"] },
+            "settings": { "jsx-a11y": { "polymorphicPropName": "as" } },
+            "env": { "browser": false, "node": true }
+        }) else {
+            unreachable!()
+        };
+        merge_config(&mut base, config);
+        assert_eq!(
+            Value::Object(base),
+            json!({
+                "plugins": ["import", "jest"],
+                "rules": { "eqeqeq": ["warn", "always"], "no-debugger": ["off"] },
+                "settings": { "jsx-a11y": { "components": { "Link": "a" }, "polymorphicPropName": "as" } },
+                "env": { "browser": false, "node": true }
+            })
+        );
+    }
+
+    #[test]
+    fn extends() {
+        let dir = env::current_dir().unwrap().join("fixtures/config_extends");
+        let config = load_config(&dir.join(".oxlintrc.json")).unwrap();
+        assert_eq!(
+            config,
+            json!({
+                "plugins": ["import", "jest"],
+                "rules": { "eqeqeq": ["warn", "always"], "no-debugger": "off", "no-console": "error" },
+                "settings": { "jsx-a11y": { "components": { "Link": "a" }, "polymorphicPropName": "as" } }
+            })
+        );
+
+        let err = load_config(&dir.join("cycle/a.json")).unwrap_err();
+        let related = err.related().unwrap().map(ToString::to_string).collect::<Vec<_>>();
+        assert!(related[0].starts_with("Config extends itself: "), "{related:?}");
+        assert!(related[0].ends_with("a.json"), "{related:?}");
+
+        let err = load_config(&dir.join("missing.json")).unwrap_err();
+        let related = err.related().unwrap().map(ToString::to_string).collect::<Vec<_>>();
+        assert!(related[0].starts_with("Failed to resolve \"@company/missing\""), "{related:?}");
+    }
+}
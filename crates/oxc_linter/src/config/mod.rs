@@ -1,26 +1,40 @@
 mod env;
 pub mod errors;
+mod extends;
 mod rules;
+mod schema;
 mod settings;
 
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
-use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
-use rustc_hash::FxHashSet;
+use oxc_diagnostics::{miette::NamedSource, Error, FailedToOpenFileError, Report};
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 
-use crate::{rules::RuleEnum, AllowWarnDeny};
+use crate::{rules::RuleEnum, AllowWarnDeny, RuleCategory};
 
 use self::errors::{
     FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
     FailedToParseJsonc,
 };
-pub use self::{env::ESLintEnv, rules::ESLintRules, settings::ESLintSettings};
+pub use self::{
+    env::ESLintEnv,
+    rules::ESLintRules,
+    schema::CONFIG_SCHEMA,
+    settings::{ESLintSettings, ImportMapResolution},
+};
 
 /// ESLint Config
 /// <https://eslint.org/docs/latest/use/configure/configuration-files-new#configuration-objects>
 #[derive(Debug, Deserialize)]
 pub struct ESLintConfig {
+    /// Plugins to enable in addition to the ones enabled by options, e.g. `["import", "jest"]`
+    #[serde(default)]
+    plugins: Vec<String>,
+    /// Severities of whole rule categories, e.g. `{ "suspicious": "warn" }`,
+    /// applied after the filter of options and before `rules`
+    #[serde(default)]
+    categories: FxHashMap<String, serde_json::Value>,
     #[serde(default)]
     rules: ESLintRules,
     #[serde(default)]
@@ -30,32 +44,16 @@ pub struct ESLintConfig {
 }
 
 impl ESLintConfig {
+    /// Load the config file at `path` merged onto the configs it `extends`,
+    /// see [`extends::load_config`] for how `extends` are resolved and merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a file cannot be read, parsed or resolved, does not match
+    /// [`CONFIG_SCHEMA`] or has unknown rules, the errors of the latter point into the file,
+    /// or if a config extends itself.
     pub fn from_file(path: &Path) -> Result<Self, Report> {
-        let mut string = std::fs::read_to_string(path).map_err(|e| {
-            FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(path.to_path_buf(), e))])
-        })?;
-
-        // jsonc support
-        json_strip_comments::strip(&mut string)
-            .map_err(|_| FailedToParseJsonc(path.to_path_buf()))?;
-
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
-            let guess = mime_guess::from_path(path);
-            let err = match guess.first() {
-                // syntax error
-                Some(mime) if mime.subtype() == "json" => err.to_string(),
-                Some(_) => "only json configuration is supported".to_string(),
-                None => {
-                    format!(
-                        "{err}, if the configuration is not a json file, please use json instead."
-                    )
-                }
-            };
-            FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
-                path.to_path_buf(),
-                err,
-            ))])
-        })?;
+        let json = extends::load_config(path)?;
 
         let config = Self::deserialize(&json).map_err(|err| {
             FailedToParseConfigError(vec![Error::new(FailedToParseConfigPropertyError(
@@ -66,6 +64,25 @@ impl ESLintConfig {
         Ok(config)
     }
 
+    pub fn plugins(&self) -> &[String] {
+        &self.plugins
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` for unknown categories and invalid severities.
+    pub fn categories(&self) -> Result<Vec<(AllowWarnDeny, RuleCategory)>, Error> {
+        self.categories
+            .iter()
+            .map(|(name, severity)| {
+                let category = RuleCategory::from(name).ok_or_else(|| {
+                    FailedToParseConfigPropertyError(format!("unknown category {name:?}"))
+                })?;
+                Ok((AllowWarnDeny::try_from(severity)?, category))
+            })
+            .collect()
+    }
+
     pub fn properties(self) -> (ESLintSettings, ESLintEnv) {
         (self.settings, self.env)
     }
@@ -137,9 +154,48 @@ impl ESLintConfig {
     }
 }
 
+/// The JSON of the config file at `path`, validated against [`CONFIG_SCHEMA`]
+fn read_config_json(path: &Path) -> Result<serde_json::Value, Report> {
+    let source_text = std::fs::read_to_string(path).map_err(|e| {
+        FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(path.to_path_buf(), e))])
+    })?;
+    let mut string = source_text.clone();
+
+    // jsonc support
+    json_strip_comments::strip(&mut string).map_err(|_| FailedToParseJsonc(path.to_path_buf()))?;
+
+    let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+        let guess = mime_guess::from_path(path);
+        let err = match guess.first() {
+            // syntax error
+            Some(mime) if mime.subtype() == "json" => err.to_string(),
+            Some(_) => "only json configuration is supported".to_string(),
+            None => {
+                format!("{err}, if the configuration is not a json file, please use json instead.")
+            }
+        };
+        FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
+            path.to_path_buf(),
+            err,
+        ))])
+    })?;
+
+    // Syntax errors are reported by serde_json above, the AST has the spans for these
+    let errors = schema::validate_config(&source_text);
+    if !errors.is_empty() {
+        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text));
+        let errors =
+            errors.into_iter().map(|error| error.with_source_code(Arc::clone(&source))).collect();
+        return Err(FailedToParseConfigError(errors).into());
+    }
+
+    Ok(json)
+}
+
 #[cfg(test)]
 mod test {
     use super::ESLintConfig;
+    use crate::{AllowWarnDeny, RuleCategory};
     use serde::Deserialize;
     use std::env;
 
@@ -181,9 +237,24 @@ mod test {
         }));
         assert!(config.is_ok());
 
-        let ESLintConfig { rules, settings, env } = config.unwrap();
+        let ESLintConfig { rules, settings, env, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
     }
+
+    #[test]
+    fn test_deserialize_plugins_and_categories() {
+        let config = ESLintConfig::deserialize(&serde_json::json!({
+            "plugins": ["import", "jest"],
+            "categories": { "suspicious": "warn" }
+        }))
+        .unwrap();
+        assert_eq!(config.plugins(), ["import", "jest"]);
+        assert_eq!(config.categories().unwrap(), [(AllowWarnDeny::Warn, RuleCategory::Suspicious)]);
+
+        let config =
+            ESLintConfig::deserialize(&serde_json::json!({ "categories": { "foo": "warn" } }));
+        assert!(config.unwrap().categories().is_err());
+    }
 }
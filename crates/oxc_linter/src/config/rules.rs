@@ -60,7 +60,7 @@ impl<'de> Deserialize<'de> for ESLintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -0,0 +1,364 @@
+use std::mem;
+
+use itertools::Itertools;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{JsonArray, JsonObject, JsonValue};
+use oxc_diagnostics::Error;
+use oxc_parser::{JsonDialect, Parser};
+use oxc_span::{GetSpan, SourceType, Span};
+use serde_json::Value;
+
+use super::{
+    errors::{InvalidConfigError, UnknownRuleError},
+    rules::parse_rule_key,
+};
+use crate::rules::RULES;
+
+/// [JSON schema](https://json-schema.org) of the configuration file, e.g. for completions
+/// in editors. The names of the rules are not part of it.
+pub const CONFIG_SCHEMA: &str = include_str!("schema.json");
+
+/// Validate the configuration file `source_text` against [`CONFIG_SCHEMA`],
+/// and the names of its rules against the rules of oxlint.
+///
+/// The errors have spans into `source_text`. Syntax errors are not reported.
+pub fn validate_config(source_text: &str) -> Vec<Error> {
+    let allocator = Allocator::default();
+    let ret =
+        Parser::new(&allocator, source_text, SourceType::default()).parse_json(JsonDialect::Jsonc);
+    if ret.panicked {
+        return vec![];
+    }
+    let schema = serde_json::from_str::<Value>(CONFIG_SCHEMA).expect("schema.json is valid JSON");
+    let mut validator = Validator { source_text, root: &schema, path: vec![], errors: vec![] };
+    validator.validate(&ret.value, &schema);
+
+    let rules =
+        ret.value.as_object().and_then(|config| config.get("rules")).and_then(JsonValue::as_object);
+    for property in rules.into_iter().flat_map(|rules| rules.properties.iter()) {
+        let (plugin_name, rule_name) = parse_rule_key(&property.key.value);
+        // typescript-eslint extends some core rules, e.g. `@typescript-eslint/no-loss-of-precision`
+        let known = RULES.iter().any(|rule| {
+            rule.name() == rule_name
+                && (rule.plugin_name() == plugin_name
+                    || plugin_name == "typescript" && rule.plugin_name() == "eslint")
+        });
+        if !known {
+            let name = property.key.value.to_string();
+            validator.errors.push(UnknownRuleError(name, property.key.span).into());
+        }
+    }
+    validator.errors
+}
+
+/// Validates JSON values against a subset of JSON schema draft 7: `$ref` to `#/definitions`,
+/// `type`, `enum`, `anyOf`, `properties`, `required`, `additionalProperties`, `propertyNames`,
+/// `items`, `additionalItems` and `minItems`.
+struct Validator<'s> {
+    source_text: &'s str,
+    root: &'s Value,
+    /// Property names and indices from the root to the value being validated
+    path: Vec<String>,
+    errors: Vec<Error>,
+}
+
+impl<'s> Validator<'s> {
+    fn validate(&mut self, value: &JsonValue, schema: &'s Value) {
+        let schema = self.resolve(schema);
+        if let Some(schemas) = schema.get("anyOf").and_then(Value::as_array) {
+            self.validate_any_of(value, schemas);
+        }
+        if let Some(types) = schema.get("type") {
+            let types = types_of(types).collect::<Vec<_>>();
+            if !types.iter().any(|ty| has_type(value, ty)) {
+                self.type_error(value, &types);
+                return;
+            }
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            if !values.iter().any(|expected| equals(value, expected)) {
+                let expected = values.iter().map(Value::to_string).collect::<Vec<_>>();
+                let found = self.describe(value);
+                let message = format!("expected {}, but found {found}", one_of(&expected));
+                self.error(value.span(), message);
+                return;
+            }
+        }
+        match value {
+            JsonValue::Object(object) => self.validate_object(object, schema),
+            JsonValue::Array(array) => self.validate_array(array, schema),
+            _ => {}
+        }
+    }
+
+    /// Reports the errors of the alternative of the same type as `value` if there is one,
+    /// e.g. of the severity strings for `"erorr"`, the expected types otherwise
+    fn validate_any_of(&mut self, value: &JsonValue, schemas: &'s [Value]) {
+        let mut alternatives = vec![];
+        for schema in schemas {
+            let errors = mem::take(&mut self.errors);
+            self.validate(value, schema);
+            let alternative_errors = mem::replace(&mut self.errors, errors);
+            if alternative_errors.is_empty() {
+                return;
+            }
+            alternatives.push((schema, alternative_errors));
+        }
+        let mut same_type = alternatives
+            .into_iter()
+            .filter(|(schema, _)| self.types(schema).iter().any(|ty| has_type(value, ty)))
+            .collect::<Vec<_>>();
+        if same_type.len() == 1 {
+            self.errors.extend(same_type.remove(0).1);
+        } else {
+            let types =
+                schemas.iter().flat_map(|schema| self.types(schema)).unique().collect::<Vec<_>>();
+            self.type_error(value, &types);
+        }
+    }
+
+    fn validate_object(&mut self, object: &JsonObject, schema: &'s Value) {
+        let required = schema.get("required").and_then(Value::as_array);
+        for name in required.into_iter().flatten().filter_map(Value::as_str) {
+            if object.get(name).is_none() {
+                self.error(object.span, format!("missing property {name:?}"));
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for property in &object.properties {
+            let name = property.key.value.as_str();
+            self.path.push(name.to_string());
+            if let Some(names) = schema.get("propertyNames") {
+                self.validate(&JsonValue::String(property.key.clone()), names);
+            }
+            let property_schema = properties
+                .and_then(|properties| properties.get(name))
+                .or_else(|| schema.get("additionalProperties"));
+            match property_schema {
+                Some(Value::Bool(false)) => {
+                    self.error(property.key.span, format!("unknown property {name:?}"));
+                }
+                Some(Value::Bool(true)) | None => {}
+                Some(property_schema) => self.validate(&property.value, property_schema),
+            }
+            self.path.pop();
+        }
+    }
+
+    fn validate_array(&mut self, array: &JsonArray, schema: &'s Value) {
+        let min_items = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+        if (array.elements.len() as u64) < min_items {
+            let items = if min_items == 1 { "item" } else { "items" };
+            self.error(array.span, format!("expected at least {min_items} {items}"));
+        }
+        for (i, element) in array.elements.iter().enumerate() {
+            let element_schema = match schema.get("items") {
+                Some(Value::Array(items)) => items.get(i).or_else(|| schema.get("additionalItems")),
+                items => items,
+            };
+            if let Some(element_schema) = element_schema.filter(|schema| !schema.is_boolean()) {
+                self.path.push(i.to_string());
+                self.validate(element, element_schema);
+                self.path.pop();
+            }
+        }
+    }
+
+    /// Follow `{ "$ref": "#/definitions/name" }`
+    fn resolve(&self, schema: &'s Value) -> &'s Value {
+        match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => {
+                let name = reference.trim_start_matches("#/definitions/");
+                let root = self.root;
+                self.resolve(&root["definitions"][name])
+            }
+            None => schema,
+        }
+    }
+
+    /// The types `schema` accepts, including the types of its alternatives
+    fn types(&self, schema: &'s Value) -> Vec<&'s str> {
+        let schema = self.resolve(schema);
+        let alternatives = schema.get("anyOf").and_then(Value::as_array);
+        schema
+            .get("type")
+            .map(types_of)
+            .into_iter()
+            .flatten()
+            .chain(alternatives.into_iter().flatten().flat_map(|schema| self.types(schema)))
+            .collect()
+    }
+
+    fn type_error(&mut self, value: &JsonValue, types: &[&str]) {
+        let expected = types.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let message = format!("expected {}, but found {}", one_of(&expected), type_name(value));
+        self.error(value.span(), message);
+    }
+
+    /// The source text of primitive values, the type of arrays and objects
+    fn describe(&self, value: &JsonValue) -> String {
+        match value {
+            JsonValue::Array(_) | JsonValue::Object(_) => type_name(value).to_string(),
+            _ => value.span().source_text(self.source_text).to_string(),
+        }
+    }
+
+    fn error(&mut self, span: Span, message: String) {
+        let path = if self.path.is_empty() { "the root".to_string() } else { self.path.join(".") };
+        self.errors.push(InvalidConfigError(path, message, span).into());
+    }
+}
+
+fn types_of(types: &Value) -> impl Iterator<Item = &str> {
+    let types = match types {
+        Value::Array(types) => types.as_slice(),
+        ty => std::slice::from_ref(ty),
+    };
+    types.iter().filter_map(Value::as_str)
+}
+
+#[allow(clippy::float_cmp)]
+fn has_type(value: &JsonValue, ty: &str) -> bool {
+    match (value, ty) {
+        (JsonValue::Number(number), "integer") => number.value.fract() == 0.0,
+        _ => type_name(value) == ty,
+    }
+}
+
+fn type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null(_) => "null",
+        JsonValue::Boolean(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+#[allow(clippy::float_cmp)]
+fn equals(value: &JsonValue, expected: &Value) -> bool {
+    match value {
+        JsonValue::Null(_) => expected.is_null(),
+        JsonValue::Boolean(boolean) => expected.as_bool() == Some(boolean.value),
+        JsonValue::Number(number) => expected.as_f64() == Some(number.value),
+        JsonValue::String(string) => expected.as_str() == Some(string.value.as_str()),
+        JsonValue::Array(_) | JsonValue::Object(_) => false,
+    }
+}
+
+/// `a`, `a or b`, `a, b or c`
+fn one_of(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [init @ .., last] => format!("{} or {last}", init.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::validate_config;
+
+    fn errors(source_text: &str) -> Vec<(String, &str)> {
+        validate_config(source_text)
+            .iter()
+            .map(|error| {
+                let label = error.labels().and_then(|mut labels| labels.next()).unwrap();
+                let span = &source_text[label.offset()..label.offset() + label.len()];
+                (format!("{error}: {}", label.label().unwrap_or_default()), span)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        let source_text = r#"{
+            // comment
+            "plugins": ["import", "jsx-a11y"],
+            "categories": { "suspicious": "warn" },
+            "rules": {
+                "no-console": "off",
+                "eqeqeq": ["error", "always", { "null": "ignore" }],
+                "@typescript-eslint/ban-types": 2,
+            },
+            "settings": {
+                "next": { "rootDir": ["app"] },
+                "react": { "linkComponents": ["Hyperlink", { "name": "Link", "linkAttribute": "to" }] },
+                "other-plugin": {}
+            },
+            "env": { "browser": true },
+            "root": true
+        }"#;
+        assert!(errors(source_text).is_empty());
+        assert!(errors("{").is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            errors(r#"{ "rules": { "no-console": "erorr", "eqeqeq": ["warn"] } }"#),
+            [(
+                "Invalid configuration at rules.no-console: expected \"allow\", \"off\", \"warn\", \"deny\" or \"error\", but found \"erorr\"".to_string(),
+                "\"erorr\""
+            )]
+        );
+        assert_eq!(
+            errors(r#"{ "rules": { "no-debugger": true, "eqeqeq": [3], "no-empty": [] } }"#),
+            [
+                (
+                    "Invalid configuration at rules.no-debugger: expected string, integer or array, but found boolean".to_string(),
+                    "true"
+                ),
+                (
+                    "Invalid configuration at rules.eqeqeq.0: expected 0, 1 or 2, but found 3".to_string(),
+                    "3"
+                ),
+                (
+                    "Invalid configuration at rules.no-empty: expected at least 1 item".to_string(),
+                    "[]"
+                ),
+            ]
+        );
+        assert_eq!(
+            errors(r#"{ "categories": { "suspicous": 1 }, "env": { "node": "yes" } }"#),
+            [
+                (
+                    "Invalid configuration at categories.suspicous: expected \"correctness\", \"suspicious\", \"pedantic\", \"perf\", \"style\", \"restriction\" or \"nursery\", but found \"suspicous\"".to_string(),
+                    "\"suspicous\""
+                ),
+                (
+                    "Invalid configuration at env.node: expected boolean, but found string".to_string(),
+                    "\"yes\""
+                ),
+            ]
+        );
+        assert_eq!(
+            errors(r#"{ "settings": { "react": { "formComponents": [{ "formAttribute": "a" }] } } }"#),
+            [(
+                "Invalid configuration at settings.react.formComponents.0: missing property \"name\"".to_string(),
+                "{ \"formAttribute\": \"a\" }"
+            )]
+        );
+        assert_eq!(
+            errors("[]"),
+            [(
+                "Invalid configuration at the root: expected object, but found array".to_string(),
+                "[]"
+            )]
+        );
+    }
+
+    #[test]
+    fn unknown_rule() {
+        assert_eq!(
+            errors(
+                r#"{ "rules": { "no-consol": "off", "jsx-a11y/alt-text": "warn", "@typescript-eslint/no-loss-of-precision": 2 } }"#
+            ),
+            [(
+                "Unknown rule \"no-consol\": oxlint does not implement this rule".to_string(),
+                "\"no-consol\""
+            )]
+        );
+    }
+}
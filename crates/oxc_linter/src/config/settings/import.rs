@@ -0,0 +1,197 @@
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path},
+};
+
+use serde::Deserialize;
+
+/// Settings of eslint-plugin-import and browser import maps.
+///
+/// ```json
+/// {
+///   "settings": {
+///     "import/core-modules": ["electron"],
+///     "import/import-map": {
+///       "imports": { "lit": "https://cdn.jsdelivr.net/npm/lit/+esm", "app/": "./src/" },
+///       "scopes": { "/vendor/": { "lit": "./vendor/lit.js" } }
+///     }
+///   }
+/// }
+/// ```
+///
+/// <https://github.com/import-js/eslint-plugin-import#importcore-modules>
+#[derive(Debug, Deserialize, Default)]
+pub struct ESLintSettingsImport {
+    /// Bare specifiers which resolve to modules outside of the file system,
+    /// e.g. modules provided by the runtime. Subpaths such as `electron/main` are included.
+    #[serde(default)]
+    #[serde(rename = "import/core-modules")]
+    core_modules: Vec<String>,
+    #[serde(default)]
+    #[serde(rename = "import/import-map")]
+    import_map: ImportMap,
+}
+
+/// [Import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+///
+/// Paths in targets and scopes are relative to the current working directory,
+/// which is treated as the root of the served site.
+#[derive(Debug, Deserialize, Default)]
+struct ImportMap {
+    #[serde(default)]
+    imports: BTreeMap<String, String>,
+    #[serde(default)]
+    scopes: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImportMapResolution {
+    /// Path relative to the current working directory, e.g. `./src/app.js`
+    Path(String),
+    /// A URL, the module is not on the file system
+    External,
+}
+
+impl ESLintSettingsImport {
+    pub fn is_core_module(&self, specifier: &str) -> bool {
+        self.core_modules.iter().any(|module| {
+            specifier
+                .strip_prefix(module.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+
+    /// Resolve `specifier` with the import map.
+    ///
+    /// `referrer` is the path of the importing module relative to the current working directory,
+    /// it selects the applicable `scopes`. Returns `None` if no entry of the import map matches.
+    pub fn resolve_import_map(
+        &self,
+        specifier: &str,
+        referrer: &Path,
+    ) -> Option<ImportMapResolution> {
+        let referrer_url = to_url_path(referrer);
+        let mut scopes = self
+            .import_map
+            .scopes
+            .iter()
+            .filter_map(|(scope, map)| {
+                let scope = scope.strip_prefix('.').unwrap_or(scope);
+                let matches = scope.starts_with('/')
+                    && if scope.ends_with('/') {
+                        referrer_url.starts_with(scope)
+                    } else {
+                        referrer_url == scope
+                    };
+                matches.then_some((scope, map))
+            })
+            .collect::<Vec<_>>();
+        // The most specific scope wins, then less specific ones, then the top level imports
+        scopes.sort_by_key(|(scope, _)| std::cmp::Reverse(scope.len()));
+        scopes
+            .into_iter()
+            .map(|(_, map)| map)
+            .chain(std::iter::once(&self.import_map.imports))
+            .find_map(|map| resolve_specifier_map(map, specifier))
+            .map(|target| {
+                if target.starts_with("./") || target.starts_with("../") {
+                    ImportMapResolution::Path(target)
+                } else if target.starts_with('/') {
+                    ImportMapResolution::Path(format!(".{target}"))
+                } else {
+                    ImportMapResolution::External
+                }
+            })
+    }
+}
+
+/// An exact match, or the longest prefix key ending with `/`.
+fn resolve_specifier_map(map: &BTreeMap<String, String>, specifier: &str) -> Option<String> {
+    if let Some(target) = map.get(specifier) {
+        return Some(target.clone());
+    }
+    map.iter()
+        .filter(|(key, target)| key.ends_with('/') && target.ends_with('/'))
+        .filter_map(|(key, target)| {
+            specifier.strip_prefix(key.as_str()).map(|rest| (key.len(), format!("{target}{rest}")))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, target)| target)
+}
+
+/// `src/app.js` -> `/src/app.js`
+fn to_url_path(path: &Path) -> String {
+    path.components().fold(String::new(), |mut url, component| {
+        if let Component::Normal(name) = component {
+            url.push('/');
+            url.push_str(&name.to_string_lossy());
+        }
+        url
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::{ESLintSettingsImport, ImportMapResolution};
+
+    #[test]
+    fn test_import_map() {
+        let settings = ESLintSettingsImport::deserialize(&serde_json::json!({
+            "import/core-modules": ["electron"],
+            "import/import-map": {
+                "imports": {
+                    "lit": "https://cdn.jsdelivr.net/npm/lit/+esm",
+                    "app/": "./src/",
+                    "app/config": "/config.js",
+                    "cdn/": "https://cdn.example.com/"
+                },
+                "scopes": {
+                    "/vendor/": { "lit": "./vendor/lit.js" },
+                    "/vendor/old/": { "lit": "./vendor/old-lit.js" }
+                }
+            }
+        }))
+        .unwrap();
+
+        let referrer = Path::new("src/index.js");
+        assert_eq!(
+            settings.resolve_import_map("lit", referrer),
+            Some(ImportMapResolution::External)
+        );
+        assert_eq!(
+            settings.resolve_import_map("app/utils/a.js", referrer),
+            Some(ImportMapResolution::Path("./src/utils/a.js".into()))
+        );
+        assert_eq!(
+            settings.resolve_import_map("app/config", referrer),
+            Some(ImportMapResolution::Path("./config.js".into()))
+        );
+        assert_eq!(
+            settings.resolve_import_map("cdn/x.js", referrer),
+            Some(ImportMapResolution::External)
+        );
+        assert_eq!(settings.resolve_import_map("react", referrer), None);
+        assert_eq!(settings.resolve_import_map("./lit", referrer), None);
+
+        assert_eq!(
+            settings.resolve_import_map("lit", Path::new("vendor/index.js")),
+            Some(ImportMapResolution::Path("./vendor/lit.js".into()))
+        );
+        assert_eq!(
+            settings.resolve_import_map("lit", Path::new("vendor/old/index.js")),
+            Some(ImportMapResolution::Path("./vendor/old-lit.js".into()))
+        );
+        assert_eq!(
+            settings.resolve_import_map("cdn/x.js", Path::new("vendor/index.js")),
+            Some(ImportMapResolution::External)
+        );
+
+        assert!(settings.is_core_module("electron"));
+        assert!(settings.is_core_module("electron/main"));
+        assert!(!settings.is_core_module("electron-store"));
+    }
+}
@@ -1,6 +1,12 @@
-use self::{jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext, react::ESLintSettingsReact};
+use self::{
+    import::ESLintSettingsImport, jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext,
+    react::ESLintSettingsReact,
+};
 use serde::Deserialize;
 
+pub use self::import::ImportMapResolution;
+
+mod import;
 mod jsx_a11y;
 mod next;
 mod react;
@@ -13,6 +19,9 @@ mod react;
 /// But each plugin extends this with their own properties.
 #[derive(Debug, Deserialize, Default)]
 pub struct ESLintSettings {
+    /// eslint-plugin-import uses flat `import/*` keys
+    #[serde(flatten)]
+    pub import: ESLintSettingsImport,
     #[serde(default)]
     #[serde(rename = "jsx-a11y")]
     pub jsx_a11y: ESLintSettingsJSXA11y,
@@ -1,7 +1,9 @@
 use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
+use oxc_ast::ast::IdentifierReference;
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{CancellationToken, Error};
+use oxc_ecmascript::ConstantEvaluation;
 use oxc_semantic::{AstNodes, JSDoc, ScopeTree, Semantic, SymbolTable};
 use oxc_span::SourceType;
 
@@ -9,7 +11,8 @@ use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{Fix, Message},
     javascript_globals::GLOBALS,
-    ESLintEnv, ESLintSettings,
+    rule::FixKind,
+    ESLintEnv, ESLintSettings, Tsconfig,
 };
 
 pub struct LintContext<'a> {
@@ -22,13 +25,24 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// Whether to also apply the fixes of rules with [`FixKind::Dangerous`].
+    fix_dangerously: bool,
+
     current_rule_name: &'static str,
 
+    current_rule_fix: FixKind,
+
     file_path: Box<Path>,
 
     settings: Arc<ESLintSettings>,
 
     env: Arc<ESLintEnv>,
+
+    /// The tsconfig of the project containing the file
+    tsconfig: Option<Arc<Tsconfig>>,
+
+    /// Linting stops at the next statement once this is cancelled.
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl<'a> LintContext<'a> {
@@ -40,10 +54,14 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix: false,
+            fix_dangerously: false,
             current_rule_name: "",
+            current_rule_fix: FixKind::None,
             file_path,
             settings: Arc::new(ESLintSettings::default()),
             env: Arc::new(ESLintEnv::default()),
+            tsconfig: None,
+            cancellation_token: None,
         }
     }
 
@@ -53,6 +71,12 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.fix_dangerously = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_settings(mut self, settings: &Arc<ESLintSettings>) -> Self {
         self.settings = Arc::clone(settings);
@@ -65,6 +89,22 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_tsconfig(mut self, tsconfig: Option<Arc<Tsconfig>>) -> Self {
+        self.tsconfig = tsconfig;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: &CancellationToken) -> Self {
+        self.cancellation_token = Some(token.clone());
+        self
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -77,6 +117,10 @@ impl<'a> LintContext<'a> {
         &self.settings
     }
 
+    pub fn tsconfig(&self) -> Option<&Tsconfig> {
+        self.tsconfig.as_deref()
+    }
+
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
     }
@@ -105,8 +149,9 @@ impl<'a> LintContext<'a> {
     }
 
     #[inline]
-    pub fn with_rule_name(&mut self, name: &'static str) {
+    pub fn with_rule(&mut self, name: &'static str, fix: FixKind) {
         self.current_rule_name = name;
+        self.current_rule_fix = fix;
     }
 
     /* Diagnostics */
@@ -130,7 +175,18 @@ impl<'a> LintContext<'a> {
         T: Into<Error>,
         F: FnOnce() -> Fix<'a>,
     {
-        if self.fix {
+        debug_assert_ne!(
+            self.current_rule_fix,
+            FixKind::None,
+            "{} has fixes, declare them in `declare_oxc_lint!`",
+            self.current_rule_name
+        );
+        let fixable = match self.current_rule_fix {
+            FixKind::Safe => self.fix,
+            FixKind::Dangerous => self.fix && self.fix_dangerously,
+            FixKind::None => false,
+        };
+        if fixable {
             self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
         } else {
             self.diagnostic(diagnostic);
@@ -159,3 +215,10 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc()
     }
 }
+
+/// Constant values of expressions, with `undefined`, `NaN` and `Infinity` only when not shadowed
+impl<'a> ConstantEvaluation<'a> for LintContext<'a> {
+    fn is_global_reference(&self, ident: &IdentifierReference) -> bool {
+        self.semantic().is_reference_to_global_variable(ident)
+    }
+}
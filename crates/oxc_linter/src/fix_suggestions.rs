@@ -0,0 +1,43 @@
+//! Fixes of the diagnostics of linted files, for editor extensions without a language server.
+
+use std::path::Path;
+
+use oxc_span::Span;
+
+use crate::Message;
+
+/// A fix of a diagnostic which is reported instead of applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixSuggestion {
+    /// Path of the file as it was passed to the linter
+    pub path: Box<Path>,
+    /// Message of the diagnostic, e.g. `eslint(no-debugger): debugger statement is not allowed`
+    pub message: String,
+    /// Help of the diagnostic, which often describes the fix
+    pub help: Option<String>,
+    /// Span of the replaced text in the file
+    pub span: Span,
+    /// The replacement, empty for deletions
+    pub content: String,
+}
+
+impl FixSuggestion {
+    /// Suggestions of the fixable `messages` of a source embedded at `start` of the file `path`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn from_messages(path: &Path, messages: &[Message], start: usize) -> Vec<Self> {
+        messages
+            .iter()
+            .filter_map(|message| {
+                let fix = message.fix.as_ref()?;
+                let start = start as u32;
+                Some(Self {
+                    path: path.into(),
+                    message: message.error.to_string(),
+                    help: message.error.help().map(|help| help.to_string()),
+                    span: Span::new(fix.span.start + start, fix.span.end + start),
+                    content: fix.content.to_string(),
+                })
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_diagnostics::Error;
-use oxc_span::Span;
+use oxc_span::{SourceCode, Span};
 
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
@@ -60,18 +60,18 @@ impl<'a> Message<'a> {
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
-    source_text: &'a str,
+    source_code: SourceCode<'a>,
     messages: Vec<Message<'a>>,
 }
 
 impl<'a> Fixer<'a> {
     pub fn new(source_text: &'a str, messages: Vec<Message<'a>>) -> Self {
-        Self { source_text, messages }
+        Self { source_code: SourceCode::new(source_text), messages }
     }
 
     /// # Panics
     pub fn fix(mut self) -> FixResult<'a> {
-        let source_text = self.source_text;
+        let source_text = self.source_code.text();
         if self.messages.iter().all(|m| m.fix.is_none()) {
             return FixResult {
                 fixed: false,
@@ -97,8 +97,8 @@ impl<'a> Fixer<'a> {
 
             m.fixed = true;
             fixed = true;
-            let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-            output.push_str(&source_text[offset..start as usize]);
+            let offset = u32::try_from(last_pos.max(0)).ok().unwrap();
+            output.push_str(self.source_code.snippet(Span::new(offset, start)));
             output.push_str(content);
             last_pos = i64::from(end);
         });
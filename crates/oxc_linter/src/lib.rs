@@ -8,14 +8,22 @@ mod ast_util;
 mod config;
 mod context;
 mod disable_directives;
+mod fix_suggestions;
 mod fixer;
 mod globals;
 mod javascript_globals;
+mod module_cycles;
 mod options;
+mod organize_imports;
+mod package_type;
 pub mod partial_loader;
+mod resolve_cache;
+mod result_cache;
 pub mod rule;
 mod rules;
 mod service;
+mod tsconfig;
+mod unused_exports;
 mod utils;
 
 use rustc_hash::FxHashMap;
@@ -23,6 +31,19 @@ use std::{io::Write, rc::Rc, sync::Arc};
 
 use oxc_diagnostics::Report;
 
+pub use crate::{
+    config::CONFIG_SCHEMA,
+    context::LintContext,
+    fix_suggestions::FixSuggestion,
+    module_cycles::{CycleImport, ModuleCycle},
+    options::{AllowWarnDeny, LintOptions},
+    organize_imports::organize_imports,
+    package_type::PackageTypeCache,
+    result_cache::{CacheBackend, CacheKey, DirectoryCache},
+    service::{LintService, ParseOptionsProvider},
+    tsconfig::{CompilerOptions, JsxMode, Tsconfig, TsconfigCache},
+    unused_exports::UnusedExport,
+};
 use crate::{
     config::{ESLintEnv, ESLintSettings},
     fixer::Fix,
@@ -30,11 +51,6 @@ use crate::{
     rule::RuleCategory,
     rules::{RuleEnum, RULES},
 };
-pub use crate::{
-    context::LintContext,
-    options::{AllowWarnDeny, LintOptions},
-    service::LintService,
-};
 use oxc_semantic::AstNode;
 
 #[cfg(target_pointer_width = "64")]
@@ -66,7 +82,7 @@ impl Linter {
     /// # Errors
     ///
     /// Returns `Err` if there are any errors parsing the configuration file.
-    pub fn from_options(options: LintOptions) -> Result<Self, Report> {
+    pub fn from_options(mut options: LintOptions) -> Result<Self, Report> {
         let (rules, settings, env) = options.derive_rules_and_settings_and_env()?;
         let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
         Ok(Self { rules, options, settings: Arc::new(settings), env: Arc::new(env) })
@@ -90,10 +106,27 @@ impl Linter {
         self
     }
 
+    pub fn settings(&self) -> &ESLintSettings {
+        &self.settings
+    }
+
     pub fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    /// Everything configured on the linter which affects its diagnostics, for keying cached results
+    pub(crate) fn fingerprint(&self) -> String {
+        let memory_limit = self.options.memory_limit;
+        let mut fingerprint =
+            format!("{:?}\n{:?}\n{:?}\n{memory_limit:?}", self.rules, self.settings, self.env);
+        // Results of rules which depend on the current date are only valid for the day
+        if self.rules.iter().any(|(_, rule)| matches!(rule, RuleEnum::NoExpiredTsSuppression(_))) {
+            fingerprint.push('\n');
+            fingerprint.push_str(&utils::today());
+        }
+        fingerprint
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
@@ -104,26 +137,38 @@ impl Linter {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.options.fix_dangerously = yes;
+        self
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx =
-            ctx.with_fix(self.options.fix).with_settings(&self.settings).with_env(&self.env);
+        let mut ctx = ctx
+            .with_fix(self.options.fix || self.options.fix_suggestions)
+            .with_fix_dangerously(self.options.fix_dangerously)
+            .with_settings(&self.settings)
+            .with_env(&self.env);
 
         for (rule_name, rule) in &self.rules {
-            ctx.with_rule_name(rule_name);
+            ctx.with_rule(rule_name, rule.fix());
             rule.run_once(&ctx);
         }
 
         for symbol in semantic.symbols().iter() {
             for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
+                ctx.with_rule(rule_name, rule.fix());
                 rule.run_on_symbol(symbol, &ctx);
             }
         }
 
         for node in semantic.nodes().iter() {
+            if node.kind().is_statement() && ctx.is_cancelled() {
+                break;
+            }
             for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
+                ctx.with_rule(rule_name, rule.fix());
                 rule.run(node, &ctx);
             }
         }
@@ -154,7 +199,12 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::{env, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::DiagnosticService;
+
+    use super::{AllowWarnDeny, LintOptions, LintService, Linter};
 
     #[test]
     fn print_rules() {
@@ -162,4 +212,28 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn fix_dangerously() {
+        // `no-zero-fractions` has safe fixes, `no-debugger` dangerous ones
+        let fixes = |fix_dangerously: bool| {
+            let options = LintOptions::default()
+                .with_filter(vec![
+                    (AllowWarnDeny::Deny, "no-debugger".into()),
+                    (AllowWarnDeny::Deny, "no-zero-fractions".into()),
+                ])
+                .with_fix(true)
+                .with_fix_dangerously(fix_dangerously);
+            let linter = Linter::from_options(options).unwrap();
+            let cwd = env::current_dir().unwrap().into_boxed_path();
+            let service = LintService::from_linter(cwd, &[Path::new("test.js").into()], linter);
+            let allocator = Allocator::default();
+            let diagnostic_service = DiagnosticService::default();
+            let messages =
+                service.run_source(&allocator, "debugger; 1.0", false, diagnostic_service.sender());
+            messages.into_iter().filter_map(|message| message.fix).count()
+        };
+        assert_eq!(fixes(false), 1);
+        assert_eq!(fixes(true), 2);
+    }
 }
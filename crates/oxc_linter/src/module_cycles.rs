@@ -0,0 +1,194 @@
+//! Circular dependencies between the linted files.
+
+use std::{collections::VecDeque, path::Path, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+use oxc_semantic::ModuleRecord;
+use oxc_span::{Atom, Span};
+
+use crate::unused_exports::canonical_path;
+
+/// A strongly connected component of the module graph, modules which depend on each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleCycle {
+    /// Paths of the modules in the component, sorted
+    pub modules: Vec<Box<Path>>,
+    /// One cycle through the component, starting and ending at the first module.
+    ///
+    /// The target of each import is the importer of the next one.
+    pub imports: Vec<CycleImport>,
+}
+
+/// An import of a module on a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleImport {
+    pub importer: Box<Path>,
+    /// The requested module, e.g. `./a`
+    pub specifier: Atom,
+    /// Spans of `specifier` in the import and export declarations of the importer
+    pub spans: Vec<Span>,
+    pub target: Box<Path>,
+}
+
+struct Edge {
+    target: usize,
+    specifier: Atom,
+    spans: Vec<Span>,
+}
+
+/// Find the circular dependencies between `modules` with Tarjan's algorithm.
+///
+/// Dependencies which are not in `modules`, e.g. packages, are left out of the graph.
+pub(crate) fn find_cycles(modules: &[(Box<Path>, Arc<ModuleRecord>)]) -> Vec<ModuleCycle> {
+    let indices = modules
+        .iter()
+        .enumerate()
+        .map(|(index, (path, _))| (canonical_path(path), index))
+        .collect::<FxHashMap<_, _>>();
+    let edges = modules
+        .iter()
+        .map(|(_, module)| {
+            module
+                .requested_modules
+                .iter()
+                .filter_map(|(specifier, spans)| {
+                    let target = module.loaded_modules.get(specifier)?;
+                    let target = *indices.get(&canonical_path(&target.resolved_absolute_path))?;
+                    Some(Edge { target, specifier: specifier.clone(), spans: spans.clone() })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut cycles = strongly_connected_components(&edges)
+        .into_iter()
+        .filter(|component| {
+            // A single module is a component on its own, it is only a cycle if it imports itself
+            component.len() > 1 || edges[component[0]].iter().any(|e| e.target == component[0])
+        })
+        .map(|mut component| {
+            component.sort_by(|a, b| modules[*a].0.cmp(&modules[*b].0));
+            let imports = shortest_cycle(&edges, &component)
+                .into_iter()
+                .map(|(from, edge)| CycleImport {
+                    importer: modules[from].0.clone(),
+                    specifier: edge.specifier.clone(),
+                    spans: edge.spans.clone(),
+                    target: modules[edge.target].0.clone(),
+                })
+                .collect();
+            let modules = component.iter().map(|index| modules[*index].0.clone()).collect();
+            ModuleCycle { modules, imports }
+        })
+        .collect::<Vec<_>>();
+    cycles.sort_by(|a, b| a.modules.cmp(&b.modules));
+    cycles
+}
+
+/// Tarjan's algorithm without recursion, so deep import chains do not overflow the stack.
+struct Tarjan<'e> {
+    edges: &'e [Vec<Edge>],
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next_index: usize,
+    components: Vec<Vec<usize>>,
+}
+
+fn strongly_connected_components(edges: &[Vec<Edge>]) -> Vec<Vec<usize>> {
+    let mut tarjan = Tarjan {
+        edges,
+        index: vec![None; edges.len()],
+        low_link: vec![0; edges.len()],
+        on_stack: vec![false; edges.len()],
+        stack: vec![],
+        next_index: 0,
+        components: vec![],
+    };
+    for root in 0..edges.len() {
+        if tarjan.index[root].is_none() {
+            tarjan.run(root);
+        }
+    }
+    tarjan.components
+}
+
+impl Tarjan<'_> {
+    fn run(&mut self, root: usize) {
+        // (node, position of the next edge to follow)
+        let edges = self.edges;
+        let mut call_stack = vec![(root, 0)];
+        self.visit(root);
+        while let Some((node, next)) = call_stack.last_mut() {
+            let node = *node;
+            if let Some(edge) = edges[node].get(*next) {
+                *next += 1;
+                let target_index = self.index[edge.target];
+                match target_index {
+                    None => {
+                        self.visit(edge.target);
+                        call_stack.push((edge.target, 0));
+                    }
+                    Some(index) if self.on_stack[edge.target] => {
+                        self.low_link[node] = self.low_link[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+            call_stack.pop();
+            if let Some((parent, _)) = call_stack.last() {
+                self.low_link[*parent] = self.low_link[*parent].min(self.low_link[node]);
+            }
+            if Some(self.low_link[node]) == self.index[node] {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+    }
+}
+
+/// The shortest cycle from the first module of `component` back to itself, found with a breadth
+/// first search, as the importer and the edge of each step.
+fn shortest_cycle<'e>(edges: &'e [Vec<Edge>], component: &[usize]) -> Vec<(usize, &'e Edge)> {
+    let start = component[0];
+    let mut parents = FxHashMap::<usize, (usize, &Edge)>::default();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for edge in &edges[node] {
+            if edge.target == start {
+                let mut cycle = vec![(node, edge)];
+                let mut current = node;
+                while current != start {
+                    let (parent, parent_edge) = parents[&current];
+                    cycle.push((parent, parent_edge));
+                    current = parent;
+                }
+                cycle.reverse();
+                return cycle;
+            }
+            if component.contains(&edge.target) && !parents.contains_key(&edge.target) {
+                parents.insert(edge.target, (node, edge));
+                queue.push_back(edge.target);
+            }
+        }
+    }
+    vec![]
+}
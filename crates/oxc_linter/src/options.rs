@@ -5,6 +5,7 @@ use crate::{
         errors::{
             FailedToParseAllowWarnDenyFromJsonValueError,
             FailedToParseAllowWarnDenyFromNumberError, FailedToParseAllowWarnDenyFromStringError,
+            FailedToParseConfigPropertyError,
         },
         ESLintConfig,
     },
@@ -21,7 +22,12 @@ pub struct LintOptions {
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
+    /// Apply the fixes of rules with [`crate::rule::FixKind::Safe`] fixes
     pub fix: bool,
+    /// Also apply, or suggest, the fixes of rules with [`crate::rule::FixKind::Dangerous`] fixes
+    pub fix_dangerously: bool,
+    /// Collect the fixes of diagnostics for `LintService::fix_suggestions`
+    pub fix_suggestions: bool,
     pub timing: bool,
     pub import_plugin: bool,
     pub jest_plugin: bool,
@@ -29,6 +35,10 @@ pub struct LintOptions {
     pub nextjs_plugin: bool,
     pub react_perf_plugin: bool,
     pub env: ESLintEnv,
+    /// Persist module resolutions of the import plugin to this file between runs
+    pub resolve_cache_path: Option<PathBuf>,
+    /// Stop linting a file with an error once its arena holds more than this many bytes
+    pub memory_limit: Option<usize>,
 }
 
 impl Default for LintOptions {
@@ -37,6 +47,8 @@ impl Default for LintOptions {
             filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
             config_path: None,
             fix: false,
+            fix_dangerously: false,
+            fix_suggestions: false,
             timing: false,
             import_plugin: false,
             jest_plugin: false,
@@ -44,6 +56,8 @@ impl Default for LintOptions {
             nextjs_plugin: false,
             react_perf_plugin: false,
             env: ESLintEnv::default(),
+            resolve_cache_path: None,
+            memory_limit: None,
         }
     }
 }
@@ -69,6 +83,18 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.fix_dangerously = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, yes: bool) -> Self {
+        self.fix_suggestions = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
@@ -110,6 +136,18 @@ impl LintOptions {
         self.env = ESLintEnv::from_vec(env);
         self
     }
+
+    #[must_use]
+    pub fn with_resolve_cache_path(mut self, path: Option<PathBuf>) -> Self {
+        self.resolve_cache_path = path;
+        self
+    }
+
+    #[must_use]
+    pub fn with_memory_limit(mut self, limit: Option<usize>) -> Self {
+        self.memory_limit = limit;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -167,6 +205,7 @@ impl TryFrom<&Number> for AllowWarnDeny {
     }
 }
 
+const IMPORT_PLUGIN_NAME: &str = "import";
 const JEST_PLUGIN_NAME: &str = "jest";
 const JSX_A11Y_PLUGIN_NAME: &str = "jsx_a11y";
 const NEXTJS_PLUGIN_NAME: &str = "nextjs";
@@ -176,12 +215,20 @@ impl LintOptions {
     /// # Errors
     ///
     /// * Returns `Err` if there are any errors parsing the configuration file.
+    ///
+    /// Plugins listed in the configuration file are enabled on `self`.
     pub fn derive_rules_and_settings_and_env(
-        &self,
+        &mut self,
     ) -> Result<(Vec<RuleEnum>, ESLintSettings, ESLintEnv), Error> {
         let config =
             self.config_path.as_ref().map(|path| ESLintConfig::from_file(path)).transpose()?;
 
+        if let Some(config) = &config {
+            for plugin in config.plugins() {
+                self.enable_plugin(plugin)?;
+            }
+        }
+
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
 
@@ -223,6 +270,15 @@ impl LintOptions {
         }
 
         if let Some(config) = &config {
+            for (allow_warn_deny, category) in config.categories()? {
+                if allow_warn_deny.is_warn_deny() {
+                    rules.extend(
+                        all_rules.iter().filter(|rule| rule.category() == category).cloned(),
+                    );
+                } else {
+                    rules.retain(|rule| rule.category() != category);
+                }
+            }
             config.override_rules(&mut rules, &all_rules);
         }
 
@@ -236,6 +292,23 @@ impl LintOptions {
         Ok((rules, settings, env))
     }
 
+    /// Enable a plugin by the name used in configuration files, e.g. `jsx-a11y`.
+    fn enable_plugin(&mut self, name: &str) -> Result<(), Error> {
+        match name.replace('-', "_").as_str() {
+            IMPORT_PLUGIN_NAME => self.import_plugin = true,
+            JEST_PLUGIN_NAME => self.jest_plugin = true,
+            JSX_A11Y_PLUGIN_NAME => self.jsx_a11y_plugin = true,
+            NEXTJS_PLUGIN_NAME => self.nextjs_plugin = true,
+            REACT_PERF_PLUGIN_NAME => self.react_perf_plugin = true,
+            _ => {
+                return Err(
+                    FailedToParseConfigPropertyError(format!("unknown plugin {name:?}")).into()
+                )
+            }
+        }
+        Ok(())
+    }
+
     // get final filtered rules by reading `self.jest_plugin` and `self.jsx_a11y_plugin`
     fn get_filtered_rules(&self) -> Vec<RuleEnum> {
         let mut rules = RULES.clone();
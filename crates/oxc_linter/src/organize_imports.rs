@@ -0,0 +1,394 @@
+//! Organize the imports at the top of a module, for editors and `oxc organize-imports`.
+
+use std::cmp::Ordering;
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::{
+    BindingIdentifier, ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, Program,
+    Statement,
+};
+use oxc_semantic::{Semantic, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::fixer::Fix;
+
+/// Organize the leading import declarations of `program`:
+///
+/// * unused specifiers are removed, and declarations which no longer import anything
+/// * declarations are sorted by module and those of the same module are merged
+/// * named specifiers are sorted
+/// * side effect imports such as `import "./polyfill"` stay in place,
+///   imports are only moved between them
+///
+/// `React` is considered used by JSX. Comments between declarations move with the
+/// declaration after them, comments on the line of a declaration move with it.
+///
+/// Returns `None` if the imports are already organized, or a declaration which has to be
+/// rewritten contains comments.
+pub fn organize_imports<'a>(program: &Program<'a>, semantic: &Semantic<'a>) -> Option<Fix<'a>> {
+    let source_text = semantic.source_text();
+    let declarations = program
+        .body
+        .iter()
+        .map_while(|statement| match statement {
+            Statement::ModuleDeclaration(decl) => match &**decl {
+                ModuleDeclaration::ImportDeclaration(decl) => Some(&**decl),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let first = declarations.first()?;
+    let last = declarations.last()?;
+
+    let used = Usage::new(program, semantic);
+    let line_break = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+
+    // Import declarations between side effect imports, which are kept in place.
+    let mut segments: Vec<Vec<Import>> = vec![vec![]];
+    let mut pending_comments = vec![];
+    let mut previous_end = first.span.start;
+    let mut end = last.span.end;
+    for (i, decl) in declarations.iter().enumerate() {
+        let leading = source_text[previous_end as usize..decl.span.start as usize].trim();
+        // The rest of the line after the declaration, if it is only a comment
+        let rest_of_line = source_text[decl.span.end as usize..].split('\n').next().unwrap_or("");
+        let trailing = rest_of_line.trim();
+        let is_comment = trailing.starts_with("//")
+            || (trailing.starts_with("/*") && trailing.find("*/") == Some(trailing.len() - 2));
+        let trailing = if is_comment { trailing } else { "" };
+        previous_end = decl.span.end;
+        if !trailing.is_empty() {
+            #[allow(clippy::cast_possible_truncation)]
+            let trailing_end = decl.span.end + rest_of_line.trim_end().len() as u32;
+            previous_end = trailing_end;
+            if i == declarations.len() - 1 {
+                end = trailing_end;
+            }
+        }
+        if !leading.is_empty() {
+            pending_comments.push(leading);
+        }
+
+        let Some(specifiers) = &decl.specifiers else {
+            let side_effect =
+                Import::verbatim(decl, std::mem::take(&mut pending_comments), trailing);
+            segments.push(vec![side_effect]);
+            segments.push(vec![]);
+            continue;
+        };
+        if specifiers.is_empty() {
+            // `import {} from "mod"` only has side effects too
+            let side_effect =
+                Import::verbatim(decl, std::mem::take(&mut pending_comments), trailing);
+            segments.push(vec![side_effect]);
+            segments.push(vec![]);
+            continue;
+        }
+
+        let kept = specifiers
+            .iter()
+            .filter(|specifier| used.is_used(specifier_local(specifier)))
+            .collect::<Vec<_>>();
+        if kept.is_empty() {
+            // The comments of a removed declaration stay with the next one.
+            if !trailing.is_empty() {
+                pending_comments.push(trailing);
+            }
+            continue;
+        }
+        let mut import = Import::verbatim(decl, std::mem::take(&mut pending_comments), trailing);
+        import.changed = kept.len() != specifiers.len();
+        import.specifiers = kept;
+        if let Some(segment) = segments.last_mut() {
+            segment.push(import);
+        }
+    }
+
+    let mut pieces = vec![];
+    for segment in segments {
+        for import in merge(segment) {
+            pieces.push(import.print(source_text, semantic, line_break)?);
+        }
+    }
+    pieces.extend(pending_comments.iter().map(ToString::to_string));
+
+    let span = Span::new(first.span.start, end);
+    let organized = pieces.join(line_break);
+    if organized == span.source_text(source_text) {
+        return None;
+    }
+    Some(Fix::new(organized, span))
+}
+
+/// Local bindings used by the module, besides their references
+struct Usage {
+    /// Local names of `export { name }`
+    exported: FxHashSet<Atom>,
+    /// `React` is used by JSX with the classic runtime
+    jsx: bool,
+    /// Symbols which are referenced or exported
+    references: FxHashSet<SymbolId>,
+}
+
+impl Usage {
+    fn new(program: &Program, semantic: &Semantic) -> Self {
+        let exported = program
+            .body
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                        Some(decl.specifiers.iter().map(|specifier| specifier.local.name().clone()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let symbols = semantic.symbols();
+        let references = symbols
+            .iter()
+            .filter(|symbol_id| {
+                !symbols.get_resolved_reference_ids(*symbol_id).is_empty()
+                    || symbols.get_flag(*symbol_id).is_export()
+            })
+            .collect();
+        Self { exported, jsx: semantic.source_type().is_jsx(), references }
+    }
+
+    fn is_used(&self, local: &BindingIdentifier) -> bool {
+        local.symbol_id.get().map_or(true, |symbol_id| self.references.contains(&symbol_id))
+            || self.exported.contains(&local.name)
+            || (self.jsx && local.name == "React")
+    }
+}
+
+fn specifier_local(specifier: &ImportDeclarationSpecifier) -> &BindingIdentifier {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+    }
+}
+
+/// An import declaration to print, with the specifiers it keeps
+struct Import<'s, 'a> {
+    decl: &'s ImportDeclaration<'a>,
+    specifiers: Vec<&'s ImportDeclarationSpecifier>,
+    leading_comments: Vec<&'a str>,
+    trailing_comments: Vec<&'a str>,
+    /// Whether the declaration has to be rewritten instead of copied
+    changed: bool,
+}
+
+impl<'s, 'a> Import<'s, 'a> {
+    fn verbatim(
+        decl: &'s ImportDeclaration<'a>,
+        leading_comments: Vec<&'a str>,
+        trailing_comment: &'a str,
+    ) -> Self {
+        let trailing_comments =
+            if trailing_comment.is_empty() { vec![] } else { vec![trailing_comment] };
+        Self { decl, specifiers: vec![], leading_comments, trailing_comments, changed: false }
+    }
+
+    /// Declarations of the same module can be merged unless they have attributes or a phase,
+    /// and the result has at most one default and no namespace specifier.
+    fn can_merge(&self, other: &Self) -> bool {
+        let is_plain = |import: &Self| {
+            import.decl.with_clause.is_none()
+                && import.decl.phase.is_none()
+                && !import.specifiers.iter().any(|specifier| {
+                    matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+                })
+        };
+        let is_default = |specifier: &&&ImportDeclarationSpecifier| {
+            matches!(specifier, ImportDeclarationSpecifier::ImportDefaultSpecifier(_))
+        };
+        self.decl.source.value == other.decl.source.value
+            && self.decl.import_kind == other.decl.import_kind
+            && is_plain(self)
+            && is_plain(other)
+            && self.specifiers.iter().filter(is_default).count()
+                + other.specifiers.iter().filter(is_default).count()
+                <= 1
+    }
+
+    fn print(self, source_text: &str, semantic: &Semantic, line_break: &str) -> Option<String> {
+        let mut named = self
+            .specifiers
+            .iter()
+            .filter_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let sorted = named.windows(2).all(|pair| {
+            compare_names(pair[0].imported.name(), pair[1].imported.name()) != Ordering::Greater
+        });
+
+        let decl = self.decl;
+        let code = if self.changed || !sorted {
+            if semantic.trivias().has_comments_between(decl.span) {
+                return None;
+            }
+            named.sort_by(|a, b| compare_names(a.imported.name(), b.imported.name()));
+            let mut clauses = self
+                .specifiers
+                .iter()
+                .filter(|specifier| {
+                    !matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+                })
+                .map(|specifier| specifier.span().source_text(source_text).to_string())
+                .collect::<Vec<_>>();
+            // The default specifier comes first
+            clauses.sort_by_key(|clause| clause.starts_with('*'));
+            if !named.is_empty() {
+                let named = named
+                    .iter()
+                    .map(|specifier| specifier.span.source_text(source_text))
+                    .collect::<Vec<_>>();
+                clauses.push(format!("{{ {} }}", named.join(", ")));
+            }
+
+            let mut code = String::from("import ");
+            if decl.import_kind.is_type() {
+                code.push_str("type ");
+            }
+            if let Some(phase) = decl.phase {
+                code.push_str(phase.as_str());
+                code.push(' ');
+            }
+            code.push_str(&clauses.join(", "));
+            code.push_str(" from ");
+            code.push_str(decl.source.span.source_text(source_text));
+            if let Some(with_clause) = &decl.with_clause {
+                code.push(' ');
+                code.push_str(with_clause.span.source_text(source_text));
+            }
+            if decl.span.source_text(source_text).ends_with(';') {
+                code.push(';');
+            }
+            code
+        } else {
+            decl.span.source_text(source_text).to_string()
+        };
+
+        let mut lines = self.leading_comments.iter().map(ToString::to_string).collect::<Vec<_>>();
+        if self.trailing_comments.is_empty() {
+            lines.push(code);
+        } else {
+            lines.push(format!("{code} {}", self.trailing_comments.join(" ")));
+        }
+        Some(lines.join(line_break))
+    }
+}
+
+/// Sort imports by module and merge those of the same module.
+fn merge<'s, 'a>(mut imports: Vec<Import<'s, 'a>>) -> Vec<Import<'s, 'a>> {
+    imports.sort_by(|a, b| compare_names(&a.decl.source.value, &b.decl.source.value));
+    let mut merged: Vec<Import<'s, 'a>> = Vec::with_capacity(imports.len());
+    for import in imports {
+        match merged.last_mut() {
+            Some(previous) if previous.can_merge(&import) => {
+                previous.specifiers.extend(import.specifiers);
+                previous.leading_comments.extend(import.leading_comments);
+                previous.trailing_comments.extend(import.trailing_comments);
+                previous.changed = true;
+            }
+            _ => merged.push(import),
+        }
+    }
+    merged
+}
+
+/// Case insensitive order, ties are broken by the case sensitive order.
+fn compare_names(a: &str, b: &str) -> Ordering {
+    a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::organize_imports;
+
+    fn organize(source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let source_type =
+            SourceType::default().with_module(true).with_jsx(true).with_typescript(true);
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        match organize_imports(program, &semantic) {
+            Some(fix) => {
+                let mut output = source_text.to_string();
+                output.replace_range(fix.span.start as usize..fix.span.end as usize, &fix.content);
+                output
+            }
+            None => source_text.to_string(),
+        }
+    }
+
+    #[test]
+    fn unused() {
+        assert_eq!(
+            organize("import a, { b, c as d } from 'a';\nimport e from 'e';\nb(d);"),
+            "import { b, c as d } from 'a';\nb(d);"
+        );
+        assert_eq!(
+            organize("import { a } from 'a';\nexport { a };"),
+            "import { a } from 'a';\nexport { a };"
+        );
+        assert_eq!(
+            organize("import React from 'react';\n<div />;"),
+            "import React from 'react';\n<div />;"
+        );
+        assert_eq!(
+            organize(
+                "import { A, B, C } from 'a';\nclass D implements A {}\ninterface E extends B {}"
+            ),
+            "import { A, B } from 'a';\nclass D implements A {}\ninterface E extends B {}"
+        );
+    }
+
+    #[test]
+    fn sort_and_merge() {
+        assert_eq!(
+            organize("import { z, y } from 'b';\nimport x from 'B';\nimport { w } from 'b';\nx(y, z, w);"),
+            "import x from 'B';\nimport { w, y, z } from 'b';\nx(y, z, w);"
+        );
+        assert_eq!(
+            organize("import { b } from 'a';\nimport * as ns from 'a';\nb(ns);"),
+            "import { b } from 'a';\nimport * as ns from 'a';\nb(ns);"
+        );
+    }
+
+    #[test]
+    fn side_effects() {
+        assert_eq!(
+            organize("import { b } from 'b';\nimport 'polyfill';\nimport { c } from 'c';\nimport { a } from 'a';\na(b, c);"),
+            "import { b } from 'b';\nimport 'polyfill';\nimport { a } from 'a';\nimport { c } from 'c';\na(b, c);"
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            organize("import { b } from 'b'; // b\n// a\nimport { a } from 'a';\na(b);"),
+            "// a\nimport { a } from 'a';\nimport { b } from 'b'; // b\na(b);"
+        );
+        let source_text = "import { b, /* a */ a } from 'a';\na(b);";
+        assert_eq!(organize(source_text), source_text);
+    }
+}
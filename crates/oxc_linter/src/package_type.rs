@@ -0,0 +1,60 @@
+//! `type` of the nearest `package.json` of each linted file.
+//!
+//! Node.js treats `.js` files as ES modules if the nearest `package.json` has `"type": "module"`,
+//! see [`oxc_span::SourceType::detect`].
+
+use std::{fs, path::Path};
+
+use dashmap::DashMap;
+
+const PACKAGE_JSON: &str = "package.json";
+
+#[derive(Debug, Default)]
+pub struct PackageTypeCache {
+    /// directory -> whether the nearest `package.json` in it or its ancestors is `"type": "module"`
+    is_module: DashMap<Box<Path>, bool>,
+}
+
+impl PackageTypeCache {
+    /// Whether the nearest `package.json` of the file at `path` has `"type": "module"`.
+    pub fn is_module(&self, path: &Path) -> bool {
+        path.parent().is_some_and(|dir| self.is_module_dir(dir))
+    }
+
+    fn is_module_dir(&self, dir: &Path) -> bool {
+        if let Some(is_module) = self.is_module.get(dir) {
+            return *is_module;
+        }
+        let package_json = dir.join(PACKAGE_JSON);
+        let is_module = if package_json.is_file() {
+            Self::read_type(&package_json).as_deref() == Some("module")
+        } else {
+            dir.parent().is_some_and(|parent| self.is_module_dir(parent))
+        };
+        self.is_module.insert(dir.to_path_buf().into_boxed_path(), is_module);
+        is_module
+    }
+
+    fn read_type(path: &Path) -> Option<String> {
+        let text = fs::read_to_string(path).ok()?;
+        let value = serde_json::from_str::<serde_json::Value>(&text).ok()?;
+        value.get("type")?.as_str().map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::PackageTypeCache;
+
+    #[test]
+    fn package_type() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/package_type");
+        let cache = PackageTypeCache::default();
+        assert!(cache.is_module(&root.join("esm/index.js")));
+        assert!(cache.is_module(&root.join("esm/lib/index.js")));
+        assert!(!cache.is_module(&root.join("esm/commonjs/index.js")));
+        assert!(!cache.is_module(&root.join("index.js")));
+    }
+}
@@ -0,0 +1,190 @@
+//! Cache of module resolutions shared by all threads of a lint run.
+//!
+//! Resolution dominates the runtime of the import plugin in import heavy monorepos,
+//! the same specifiers are resolved from the same directories over and over again.
+//! The cache can be persisted between runs, persisted entries are dropped when the mtime
+//! of the resolved file or of its description file (`package.json`) changed.
+
+use std::{
+    fs,
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use dashmap::DashMap;
+use oxc_resolver::Resolver;
+use serde::{Deserialize, Serialize};
+
+const DESCRIPTION_FILE: &str = "package.json";
+
+#[derive(Default)]
+pub struct ResolveCache {
+    /// `(directory, specifier)` -> resolution, `None` for unresolved specifiers
+    resolutions: DashMap<(Box<Path>, String), Option<CachedResolution>>,
+    /// path -> canonicalized path
+    realpaths: DashMap<Box<Path>, Box<Path>>,
+    /// directory -> path of the nearest `package.json`
+    description_files: DashMap<Box<Path>, Option<Box<Path>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResolution {
+    path: PathBuf,
+    mtime: Option<Mtime>,
+    description_file: Option<(PathBuf, Option<Mtime>)>,
+}
+
+/// Modification time as duration since the unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Mtime(u64, u32);
+
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+    dir: PathBuf,
+    specifier: String,
+    resolution: CachedResolution,
+}
+
+impl ResolveCache {
+    /// Load a cache persisted by [`ResolveCache::save`].
+    ///
+    /// A missing or unreadable file gives an empty cache, stale entries are dropped.
+    pub fn load(path: &Path) -> Self {
+        let cache = Self::default();
+        let Ok(file) = fs::File::open(path) else { return cache };
+        let Ok(entries) = serde_json::from_reader::<_, Vec<PersistedEntry>>(BufReader::new(file))
+        else {
+            return cache;
+        };
+        for PersistedEntry { dir, specifier, resolution } in entries {
+            if resolution.is_fresh() {
+                cache.resolutions.insert((dir.into_boxed_path(), specifier), Some(resolution));
+            }
+        }
+        cache
+    }
+
+    /// Persist all successful resolutions to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut entries = self
+            .resolutions
+            .iter()
+            .filter_map(|entry| {
+                let (dir, specifier) = entry.key();
+                entry.value().as_ref().map(|resolution| PersistedEntry {
+                    dir: dir.to_path_buf(),
+                    specifier: specifier.clone(),
+                    resolution: resolution.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        // Stable output for the same set of resolutions
+        entries.sort_unstable_by(|a, b| (&a.dir, &a.specifier).cmp(&(&b.dir, &b.specifier)));
+        let file = BufWriter::new(fs::File::create(path)?);
+        serde_json::to_writer(file, &entries).map_err(io::Error::from)
+    }
+
+    /// Resolve `specifier` from `dir` with `resolver`, or return the memoized result.
+    pub fn resolve(&self, resolver: &Resolver, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        let dir = self.realpath(dir);
+        let key = (dir, specifier.to_string());
+        if let Some(resolution) = self.resolutions.get(&key) {
+            return resolution.as_ref().map(|resolution| resolution.path.clone());
+        }
+        let resolution = resolver.resolve(&key.0, specifier).ok().map(|resolution| {
+            let path = resolution.path().to_path_buf();
+            let description_file = path
+                .parent()
+                .and_then(|dir| self.description_file(dir))
+                .map(|file| (file.to_path_buf(), mtime(&file)));
+            CachedResolution { mtime: mtime(&path), path, description_file }
+        });
+        let path = resolution.as_ref().map(|resolution| resolution.path.clone());
+        self.resolutions.insert(key, resolution);
+        path
+    }
+
+    /// Canonicalized `path`, or `path` itself if it cannot be canonicalized.
+    pub fn realpath(&self, path: &Path) -> Box<Path> {
+        if let Some(realpath) = self.realpaths.get(path) {
+            return realpath.clone();
+        }
+        let realpath = fs::canonicalize(path)
+            .map_or_else(|_| path.to_path_buf().into_boxed_path(), PathBuf::into_boxed_path);
+        self.realpaths.insert(path.to_path_buf().into_boxed_path(), realpath.clone());
+        realpath
+    }
+
+    /// The nearest `package.json` in `dir` or its ancestors.
+    pub fn description_file(&self, dir: &Path) -> Option<Box<Path>> {
+        if let Some(file) = self.description_files.get(dir) {
+            return file.clone();
+        }
+        let file = dir.join(DESCRIPTION_FILE);
+        let file = if file.is_file() {
+            Some(file.into_boxed_path())
+        } else {
+            dir.parent().and_then(|parent| self.description_file(parent))
+        };
+        self.description_files.insert(dir.to_path_buf().into_boxed_path(), file.clone());
+        file
+    }
+}
+
+impl CachedResolution {
+    /// Whether the resolved file and its description file are unchanged since resolution.
+    fn is_fresh(&self) -> bool {
+        self.mtime.is_some()
+            && mtime(&self.path) == self.mtime
+            && self
+                .description_file
+                .as_ref()
+                .map_or(true, |(file, file_mtime)| mtime(file) == *file_mtime)
+    }
+}
+
+fn mtime(path: &Path) -> Option<Mtime> {
+    let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+    let duration = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+    Some(Mtime(duration.as_secs(), duration.subsec_nanos()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_resolver::{ResolveOptions, Resolver};
+
+    use super::ResolveCache;
+
+    #[test]
+    fn test_resolve_cache() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: vec![".js".into()],
+            ..ResolveOptions::default()
+        });
+
+        let cache = ResolveCache::default();
+        let bar = cache.resolve(&resolver, &dir, "./bar").unwrap();
+        assert_eq!(bar, dir.join("bar.js").canonicalize().unwrap());
+        assert_eq!(cache.resolve(&resolver, &dir, "./bar"), Some(bar.clone()));
+        assert_eq!(cache.resolve(&resolver, &dir, "./does-not-exist"), None);
+        assert_eq!(cache.resolutions.len(), 2);
+
+        // Only successful resolutions are persisted
+        let cache_path = env::temp_dir().join("oxc_linter_resolve_cache_test.json");
+        cache.save(&cache_path).unwrap();
+        let loaded = ResolveCache::load(&cache_path);
+        assert_eq!(loaded.resolutions.len(), 1);
+        assert_eq!(loaded.resolve(&resolver, &dir, "./bar"), Some(bar));
+        std::fs::remove_file(&cache_path).unwrap();
+
+        assert!(ResolveCache::load(&cache_path).resolutions.is_empty());
+    }
+}
@@ -0,0 +1,319 @@
+//! Cache of lint results keyed by the content of the linted files.
+//!
+//! Results are stored under a content-addressed [`CacheKey`], the hash of the linter version and
+//! configuration and of the path and source text of the file, so one [`CacheBackend`] can be
+//! shared by all machines of a CI fleet.
+//! Results of the import plugin depend on other files and are never cached,
+//! neither are results while fixing, which need the fixes of the diagnostics.
+//! Results of rules which depend on the current date are keyed by the date too.
+
+use std::{
+    fmt::{self, Write},
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan},
+    thiserror::{self, Error},
+    Severity,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::Linter;
+
+/// Storage of cached results by key, e.g. a local directory or a remote HTTP cache.
+///
+/// Cached results only speed up linting, a failed `get` is treated as a miss
+/// and a failed `put` is ignored.
+pub trait CacheBackend: Send + Sync {
+    /// The value stored under `key`, `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the backend cannot be read.
+    fn get(&self, key: &CacheKey) -> io::Result<Option<Vec<u8>>>;
+
+    /// Store `value` under `key`, replacing any previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the backend cannot be written.
+    fn put(&self, key: &CacheKey, value: &[u8]) -> io::Result<()>;
+}
+
+/// Lowercase hex SHA-256 of the inputs of a cached value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// Hash `parts`, each is length prefixed so moving bytes between parts changes the key.
+    pub fn new(parts: &[&[u8]]) -> Self {
+        let mut hasher = Sha256::new();
+        for part in parts {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+        let hex = hasher.finalize().iter().fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+        Self(hex)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Cache in a local directory with one file per key
+pub struct DirectoryCache {
+    dir: PathBuf,
+}
+
+impl DirectoryCache {
+    /// The directory is created by the first `put`.
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+impl CacheBackend for DirectoryCache {
+    fn get(&self, key: &CacheKey) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.dir.join(key.as_str())) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, key: &CacheKey, value: &[u8]) -> io::Result<()> {
+        static TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+        fs::create_dir_all(&self.dir)?;
+        // Readers in other threads and processes never see a partially written value.
+        let temp_file =
+            format!("{key}.{}.{}.tmp", process::id(), TEMP_FILES.fetch_add(1, Ordering::Relaxed));
+        let temp_path = self.dir.join(temp_file);
+        fs::write(&temp_path, value)?;
+        let result = fs::rename(&temp_path, self.dir.join(key.as_str()));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+}
+
+/// Lint results of a `LintService` stored in a [`CacheBackend`]
+pub(crate) struct ResultCache {
+    backend: Box<dyn CacheBackend>,
+    /// Version and configuration of the linter
+    fingerprint: String,
+}
+
+impl ResultCache {
+    pub fn new(backend: Box<dyn CacheBackend>, linter: &Linter) -> Self {
+        let fingerprint = format!("{}\n{}", env!("CARGO_PKG_VERSION"), linter.fingerprint());
+        Self { backend, fingerprint }
+    }
+
+    /// `path` is relative to the working directory, so the key is the same on every machine.
+    /// `options` are the inputs of parsing and linting the file which are not in its path or
+    /// source text, e.g. its tsconfig.
+    pub fn key(&self, path: &Path, source_text: &str, options: &str) -> CacheKey {
+        CacheKey::new(&[
+            b"lint",
+            self.fingerprint.as_bytes(),
+            path.to_string_lossy().as_bytes(),
+            options.as_bytes(),
+            source_text.as_bytes(),
+        ])
+    }
+
+    /// The diagnostics of each JavaScript source of the file, see `PartialLoader`.
+    pub fn get(&self, key: &CacheKey) -> Option<Vec<Vec<oxc_diagnostics::Error>>> {
+        let value = self.backend.get(key).ok()??;
+        let sources = serde_json::from_slice::<Vec<Vec<CachedDiagnostic>>>(&value).ok()?;
+        Some(
+            sources
+                .into_iter()
+                .map(|diagnostics| {
+                    diagnostics.into_iter().map(oxc_diagnostics::Error::new).collect()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn put(&self, key: &CacheKey, sources: &[Vec<oxc_diagnostics::Error>]) {
+        let sources = sources
+            .iter()
+            .map(|diagnostics| diagnostics.iter().map(CachedDiagnostic::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let Ok(value) = serde_json::to_vec(&sources) else { return };
+        // The cache only speeds up the next run, failing to write it does not fail this one.
+        let _ = self.backend.put(key, &value);
+    }
+}
+
+/// A diagnostic without its source code, which is attached again after loading it.
+///
+/// Related diagnostics are not kept, lint rules do not report any.
+#[derive(Debug, Error, Serialize, Deserialize)]
+#[error("{message}")]
+struct CachedDiagnostic {
+    message: String,
+    code: Option<String>,
+    severity: Option<CachedSeverity>,
+    help: Option<String>,
+    url: Option<String>,
+    labels: Vec<CachedLabel>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+}
+
+impl From<&oxc_diagnostics::Error> for CachedDiagnostic {
+    fn from(error: &oxc_diagnostics::Error) -> Self {
+        Self {
+            message: error.to_string(),
+            code: error.code().map(|code| code.to_string()),
+            severity: error.severity().map(|severity| match severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            }),
+            help: error.help().map(|help| help.to_string()),
+            url: error.url().map(|url| url.to_string()),
+            labels: error
+                .labels()
+                .into_iter()
+                .flatten()
+                .map(|label| CachedLabel {
+                    label: label.label().map(ToString::to_string),
+                    offset: label.offset(),
+                    len: label.len(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Diagnostic for CachedDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.code.as_ref().map(|code| Box::new(code) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity.map(|severity| match severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        })
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help.as_ref().map(|help| Box::new(help) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.url.as_ref().map(|url| Box::new(url) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            return None;
+        }
+        Some(Box::new(
+            self.labels
+                .iter()
+                .map(|label| LabeledSpan::new(label.label.clone(), label.offset, label.len)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic},
+        thiserror::Error,
+    };
+    use oxc_span::Span;
+
+    use super::{CacheBackend, CacheKey, DirectoryCache, ResultCache};
+    use crate::{rules::RULES, utils::today, Linter};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(severity(warning), help("Delete this code."))]
+    struct NoDebuggerDiagnostic(#[label] pub Span);
+
+    #[test]
+    fn cache_key() {
+        let key = CacheKey::new(&[b"ab", b"c"]);
+        assert_eq!(key.as_str().len(), 64);
+        assert_eq!(key, CacheKey::new(&[b"ab", b"c"]));
+        assert_ne!(key, CacheKey::new(&[b"a", b"bc"]));
+    }
+
+    #[test]
+    fn date_dependent_rules() {
+        let rules = |names: &[&str]| {
+            RULES.iter().filter(|rule| names.contains(&rule.name())).cloned().collect()
+        };
+        let linter = Linter::default().with_rules(rules(&["no-debugger"]));
+        assert!(!linter.fingerprint().contains(&today()));
+        let linter =
+            Linter::default().with_rules(rules(&["no-debugger", "no-expired-ts-suppression"]));
+        assert!(linter.fingerprint().ends_with(&today()));
+    }
+
+    #[test]
+    fn directory_cache() {
+        let dir = env::temp_dir().join(format!("oxc_linter_result_cache_{}", std::process::id()));
+        let cache = DirectoryCache::new(dir.clone());
+        let key = CacheKey::new(&[b"key"]);
+        assert_eq!(cache.get(&key).unwrap(), None);
+        cache.put(&key, b"value").unwrap();
+        assert_eq!(cache.get(&key).unwrap().as_deref(), Some(&b"value"[..]));
+
+        let results =
+            ResultCache::new(Box::new(DirectoryCache::new(dir.clone())), &Linter::default());
+        let key = results.key("a.js".as_ref(), "debugger", "");
+        assert!(results.get(&key).is_none());
+        results.put(&key, &[vec![NoDebuggerDiagnostic(Span::new(0, 8)).into()]]);
+        let sources = results.get(&key).unwrap();
+        let diagnostic = &sources[0][0];
+        assert_eq!(
+            diagnostic.to_string(),
+            "eslint(no-debugger): `debugger` statement is not allowed"
+        );
+        assert_eq!(diagnostic.severity(), Some(miette::Severity::Warning));
+        assert_eq!(diagnostic.help().unwrap().to_string(), "Delete this code.");
+        let labels = diagnostic.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!((labels[0].offset(), labels[0].len()), (0, 8));
+        assert_ne!(key, results.key("b.js".as_ref(), "debugger", ""));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
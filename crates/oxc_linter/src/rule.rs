@@ -25,11 +25,28 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Declared by `declare_oxc_lint!` with `fix` or `dangerous_fix`
+    const FIX: FixKind = FixKind::None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
 }
 
+/// Whether the fixes of a rule preserve the behavior of the code.
+/// Only safe fixes are applied by `--fix`, dangerous ones need `--fix-dangerously`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixKind {
+    /// The rule has no fixes
+    None,
+    /// Fixes which do not change the behavior of the code, e.g. `==` to `===` between
+    /// literals of the same type, or formatting
+    Safe,
+    /// Fixes which may change the behavior of the code, e.g. removing `debugger`
+    /// or replacing `null` with `undefined`
+    Dangerous,
+}
+
 /// Rule categories defined by rust-clippy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RuleCategory {
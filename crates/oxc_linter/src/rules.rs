@@ -17,6 +17,7 @@ mod import {
     pub mod no_duplicates;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
+    pub mod no_restricted_paths;
     pub mod no_self_import;
     pub mod no_unresolved;
     pub mod no_unused_modules;
@@ -91,6 +92,7 @@ mod eslint {
     pub mod no_sparse_arrays;
     pub mod no_this_before_super;
     pub mod no_undef;
+    pub mod no_unexpected_multiline;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
@@ -101,6 +103,7 @@ mod eslint {
     pub mod no_var;
     pub mod no_void;
     pub mod require_yield;
+    pub mod semi;
     pub mod use_isnan;
     pub mod valid_typeof;
 }
@@ -112,12 +115,14 @@ mod typescript {
     pub mod ban_tslint_comment;
     pub mod ban_types;
     pub mod no_duplicate_enum_values;
+    pub mod no_duplicate_type_constituents;
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
+    pub mod no_redundant_type_constituents;
     pub mod no_this_alias;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
@@ -304,6 +309,12 @@ mod oxc {
     pub mod erasing_op;
     pub mod misrefactored_assign_op;
     pub mod no_accumulating_spread;
+    pub mod no_barrel_import;
+    pub mod no_expired_ts_suppression;
+    pub mod no_unsafe_eval;
+    pub mod no_unsafe_inline;
+    pub mod no_unsanitized;
+    pub mod no_unused_ts_suppression;
     pub mod only_used_in_recursion;
 }
 
@@ -393,6 +404,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
+    eslint::no_unexpected_multiline,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
@@ -403,6 +415,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_var,
     eslint::no_void,
     eslint::require_yield,
+    eslint::semi,
     eslint::use_isnan,
     eslint::valid_typeof,
     typescript::adjacent_overload_signatures,
@@ -411,12 +424,14 @@ oxc_macros::declare_all_lint_rules! {
     typescript::ban_tslint_comment,
     typescript::ban_types,
     typescript::no_duplicate_enum_values,
+    typescript::no_duplicate_type_constituents,
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_misused_new,
     typescript::no_namespace,
     typescript::no_non_null_asserted_optional_chain,
+    typescript::no_redundant_type_constituents,
     typescript::no_this_alias,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
@@ -562,6 +577,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_deprecated,
     import::no_named_as_default,
     import::no_named_as_default_member,
+    import::no_restricted_paths,
     import::no_self_import,
     import::no_unresolved,
     import::no_unused_modules,
@@ -598,6 +614,12 @@ oxc_macros::declare_all_lint_rules! {
     oxc::erasing_op,
     oxc::misrefactored_assign_op,
     oxc::no_accumulating_spread,
+    oxc::no_barrel_import,
+    oxc::no_expired_ts_suppression,
+    oxc::no_unsafe_eval,
+    oxc::no_unsafe_inline,
+    oxc::no_unsanitized,
+    oxc::no_unused_ts_suppression,
     oxc::only_used_in_recursion,
     nextjs::google_font_display,
     nextjs::google_font_preconnect,
@@ -4,6 +4,7 @@ use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
+use oxc_ecmascript::{ConstantEvaluation, ConstantValue};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
@@ -152,7 +153,7 @@ impl NoConstantBinaryExpression {
         non_nullish: bool,
         ctx: &LintContext<'a>,
     ) -> bool {
-        if non_nullish && (expr.is_null() || expr.evaluate_to_undefined()) {
+        if non_nullish && Self::is_null_or_undefined(expr, ctx) {
             return false;
         }
         match expr.get_inner_expression() {
@@ -191,11 +192,25 @@ impl NoConstantBinaryExpression {
                 .iter()
                 .last()
                 .map_or(false, |last| Self::has_constant_nullishness(last, non_nullish, ctx)),
-            Expression::Identifier(_) => expr.evaluate_to_undefined(),
+            Expression::Identifier(_) => Self::is_undefined(expr, ctx),
             _ => false,
         }
     }
 
+    /// `null`, `undefined` which is not shadowed, or `void` of anything
+    fn is_null_or_undefined<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        expr.is_null() || Self::is_undefined(expr, ctx)
+    }
+
+    fn is_undefined<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr.get_inner_expression() {
+            expr @ Expression::Identifier(_) => {
+                matches!(ctx.eval_expression(expr), Some(ConstantValue::Undefined))
+            }
+            expr => expr.is_void(),
+        }
+    }
+
     /// Checks if one operand will cause the result to be constant.
     fn find_binary_expression_constant_operand<'a>(
         a: &'a Expression<'a>,
@@ -205,7 +220,8 @@ impl NoConstantBinaryExpression {
     ) -> Option<&'a Expression<'a>> {
         match operator {
             BinaryOperator::Equality | BinaryOperator::Inequality => {
-                if (a.is_null_or_undefined() && Self::has_constant_nullishness(b, false, ctx))
+                if (Self::is_null_or_undefined(a, ctx)
+                    && Self::has_constant_nullishness(b, false, ctx))
                     || (ast_util::is_static_boolean(a, ctx)
                         && Self::has_constant_loose_boolean_comparison(b, ctx))
                 {
@@ -213,7 +229,8 @@ impl NoConstantBinaryExpression {
                 }
             }
             BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
-                if (a.is_null_or_undefined() && Self::has_constant_nullishness(b, false, ctx))
+                if (Self::is_null_or_undefined(a, ctx)
+                    && Self::has_constant_nullishness(b, false, ctx))
                     || (ast_util::is_static_boolean(a, ctx)
                         && Self::has_constant_strict_boolean_comparison(b, ctx))
                 {
@@ -267,7 +284,7 @@ impl NoConstantBinaryExpression {
                 Self::has_constant_loose_boolean_comparison(&paren_expr.expression, ctx)
             }
             expr if expr.is_literal() => true,
-            expr if expr.evaluate_to_undefined() => true,
+            expr if Self::is_undefined(expr, ctx) => true,
             _ => false,
         }
     }
@@ -298,9 +315,8 @@ impl NoConstantBinaryExpression {
             },
             Expression::CallExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
-                    if ident.name == "String"
-                        || ident.name == "Number"
-                            && ctx.semantic().is_reference_to_global_variable(ident)
+                    if (ident.name == "String" || ident.name == "Number")
+                        && ctx.semantic().is_reference_to_global_variable(ident)
                     {
                         return true;
                     }
@@ -332,7 +348,7 @@ impl NoConstantBinaryExpression {
             Expression::ParenthesizedExpression(paren_expr) => {
                 Self::has_constant_strict_boolean_comparison(&paren_expr.expression, ctx)
             }
-            Expression::Identifier(_) => expr.evaluate_to_undefined(),
+            Expression::Identifier(_) => Self::is_undefined(expr, ctx),
             _ => false,
         }
     }
@@ -402,17 +418,17 @@ fn test() {
         ("[n] == true", None),
         ("delete bar.baz === true", None),
         ("foo.Boolean(true) && foo", None),
-        // ("function Boolean(n) { return n; }; Boolean(x) ?? foo", None),
-        // ("function String(n) { return n; }; String(x) ?? foo", None),
-        // ("function Number(n) { return n; }; Number(x) ?? foo", None),
-        // ("function Boolean(n) { return Math.random(); }; Boolean(x) === 1", None),
-        // ("function Boolean(n) { return Math.random(); }; Boolean(1) == true", None),
+        ("function Boolean(n) { return n; }; Boolean(x) ?? foo", None),
+        ("function String(n) { return n; }; String(x) ?? foo", None),
+        ("function Number(n) { return n; }; Number(x) ?? foo", None),
+        ("function Boolean(n) { return Math.random(); }; Boolean(x) === 1", None),
+        ("function Boolean(n) { return Math.random(); }; Boolean(1) == true", None),
         ("new Foo() === x", None),
         ("x === new someObj.Promise()", None),
         ("Boolean(foo) === true", None),
-        // ("function foo(undefined) { undefined ?? bar;}", None),
-        // ("function foo(undefined) { undefined == true;}", None),
-        // ("function foo(undefined) { undefined === true;}", None),
+        ("function foo(undefined) { undefined ?? bar;}", None),
+        ("function foo(undefined) { undefined == true;}", None),
+        ("function foo(undefined) { undefined === true;}", None),
         ("[...arr, 1] == true", None),
         ("[,,,] == true", None),
         // { code: "new Foo() === bar;", globals: { Foo: "writable" } },
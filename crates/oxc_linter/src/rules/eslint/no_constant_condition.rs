@@ -1,10 +1,12 @@
-use oxc_ast::AstKind;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Visit};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
 use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 
@@ -15,7 +17,17 @@ struct NoConstantConditionDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: CheckLoops,
+}
+
+/// Which loops with a constant condition are reported
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CheckLoops {
+    All,
+    /// All but `while (true)`, which is a common idiom
+    #[default]
+    AllExceptWhileTrue,
+    None,
 }
 
 declare_oxc_lint!(
@@ -34,20 +46,28 @@ declare_oxc_lint!(
     ///    doSomethingUnfinished();
     /// }
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `checkLoops`: `"all"` (or `true`), `"allExceptWhileTrue"` (default) or `"none"` (or `false`).
+    /// Loops in generators are not reported when they yield, e.g. `while (true) { yield x; }`.
     NoConstantCondition,
     correctness
 );
 
 impl Rule for NoConstantCondition {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        let check_loops = value.get(0).and_then(|v| v.get("checkLoops")).map_or(
+            CheckLoops::default(),
+            |v| match v {
+                serde_json::Value::Bool(true) => CheckLoops::All,
+                serde_json::Value::Bool(false) => CheckLoops::None,
+                serde_json::Value::String(s) if s == "all" => CheckLoops::All,
+                serde_json::Value::String(s) if s == "none" => CheckLoops::None,
+                _ => CheckLoops::default(),
+            },
+        );
+        Self { check_loops }
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -62,13 +82,78 @@ impl Rule for NoConstantCondition {
                     ctx.diagnostic(NoConstantConditionDiagnostic(condition_expr.test.span()));
                 }
             }
+            AstKind::WhileStatement(stmt) => {
+                if self.check_loops == CheckLoops::AllExceptWhileTrue
+                    && matches!(&stmt.test, Expression::BooleanLiteral(lit) if lit.value)
+                {
+                    return;
+                }
+                self.check_loop(&stmt.test, ctx, |finder| {
+                    finder.visit_expression(&stmt.test);
+                    finder.visit_statement(&stmt.body);
+                });
+            }
+            AstKind::DoWhileStatement(stmt) => {
+                self.check_loop(&stmt.test, ctx, |finder| {
+                    finder.visit_statement(&stmt.body);
+                    finder.visit_expression(&stmt.test);
+                });
+            }
+            AstKind::ForStatement(stmt) => {
+                let Some(test) = &stmt.test else { return };
+                // A `yield` in the initialization is evaluated once, before the loop
+                self.check_loop(test, ctx, |finder| {
+                    finder.visit_expression(test);
+                    if let Some(update) = &stmt.update {
+                        finder.visit_expression(update);
+                    }
+                    finder.visit_statement(&stmt.body);
+                });
+            }
             _ => {}
         }
     }
 }
 
+impl NoConstantCondition {
+    /// Report the constant `test` of a loop, unless the loop yields,
+    /// as `visit_loop` finds when visiting the parts of the loop which are evaluated repeatedly.
+    fn check_loop<'a>(
+        &self,
+        test: &Expression<'a>,
+        ctx: &LintContext<'a>,
+        visit_loop: impl FnOnce(&mut YieldFinder),
+    ) {
+        if self.check_loops == CheckLoops::None || !test.is_constant(true, ctx) {
+            return;
+        }
+        let mut finder = YieldFinder { found: false };
+        visit_loop(&mut finder);
+        if !finder.found {
+            ctx.diagnostic(NoConstantConditionDiagnostic(test.span()));
+        }
+    }
+}
+
+/// Find a `yield` which does not belong to a nested function
+struct YieldFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for YieldFinder {
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+}
+
 #[test]
 fn test() {
+    use serde_json::json;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -187,10 +272,10 @@ fn test() {
         ("if (Boolean(a)) {}", None),
         ("if (Boolean(...args)) {}", None),
         ("if (foo.Boolean(1)) {}", None),
+        ("const undefined = 'lol'; if (undefined) {}", None),
+        ("function foo(Boolean) { if (Boolean(1)) {} }", None),
+        ("const Boolean = () => {}; if (Boolean(1)) {}", None),
         // TODO
-        // ("const undefined = 'lol'; if (undefined) {}", None),
-        // ("function foo(Boolean) { if (Boolean(1)) {} }", None),
-        // ("const Boolean = () => {}; if (Boolean(1)) {}", None),
         // "if (Boolean()) {}",
         // "if (undefined) {}",
         ("q > 0 ? 1 : 2;", None),
@@ -198,30 +283,32 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", None),
+        ("while(a = b);", None),
+        ("while(`${a}`);", None),
+        ("for(;x < 10;);", None),
+        ("for(;;);", None),
+        ("for(;`${a}`;);", None),
+        ("do{ }while(x)", None),
+        ("while(x += 3) {}", None),
+        ("while(tag`a`) {}", None),
+        ("while(tag`${a}`) {}", None),
+        ("while(`\\\n${a}`) {}", None),
+        ("while(true);", Some(json!([{"checkLoops": false}]))),
+        ("for(;true;);", Some(json!([{"checkLoops": false}]))),
+        ("do{}while(true)", Some(json!([{"checkLoops": false}]))),
+        ("function* foo(){while(true){yield 'foo';}}", None),
+        ("function* foo(){for(;true;){yield 'foo';}}", None),
+        ("function* foo(){do{yield 'foo';}while(true)}", None),
+        ("function* foo(){while (true) { while(true) {yield;}}}", None),
+        ("function* foo() {for (; yield; ) {}}", None),
+        ("function* foo() {for (; ; yield) {}}", None),
+        ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
+        ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
+        ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(true);", None),
+        ("while(true);", Some(json!([{"checkLoops": "none"}]))),
+        ("for(;true;);", Some(json!([{"checkLoops": "none"}]))),
     ];
 
     let fail = vec![
@@ -341,40 +428,55 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", None),
+        ("for(;``;);", None),
+        ("for(;`foo`;);", None),
+        ("for(;`foo${bar}`;);", None),
+        ("do{}while(true)", Some(json!([{"checkLoops": "all"}]))),
+        ("do{}while('1')", None),
+        ("do{}while(0)", None),
+        ("do{}while(t = -2)", None),
+        ("do{}while(``)", None),
+        ("do{}while(`foo`)", None),
+        ("do{}while(`foo${bar}`)", None),
+        ("while([]);", None),
+        ("while(~!0);", None),
+        ("while(x = 1);", None),
+        ("while(function(){});", None),
+        ("while(true);", Some(json!([{"checkLoops": "all"}]))),
+        ("while(1);", None),
+        ("while(() => {});", None),
+        ("while(`foo`);", None),
+        ("while(``);", None),
+        ("while(`${'foo'}`);", None),
+        ("while(`${'foo' + 'bar'}`);", None),
+        ("function* foo(){while(true){} yield 'foo';}", Some(json!([{"checkLoops": "all"}]))),
+        (
+            "function* foo(){while(true){if (true) {yield 'foo';}}}",
+            Some(json!([{"checkLoops": "all"}])),
+        ),
+        (
+            "function* foo(){while(true){yield 'foo';} while(true) {}}",
+            Some(json!([{"checkLoops": "all"}])),
+        ),
+        (
+            "var a = function* foo(){while(true){} yield 'foo';}",
+            Some(json!([{"checkLoops": "all"}])),
+        ),
+        ("while (true) { function* foo() {yield;}}", Some(json!([{"checkLoops": "all"}]))),
+        ("function* foo(){if (true) {yield 'foo';}}", None),
+        ("function* foo() {for (let foo = yield; true;) {}}", None),
+        ("function* foo() {for (foo = yield; true;) {}}", None),
+        (
+            "function foo() {while (true) {function* bar() {while (true) {yield;}}}}",
+            Some(json!([{"checkLoops": "all"}])),
+        ),
+        (
+            "function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}",
+            Some(json!([{"checkLoops": "all"}])),
+        ),
+        ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("while(true);", Some(json!([{"checkLoops": true}]))),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    dangerous_fix
 );
 
 impl Rule for NoDebugger {
@@ -1,3 +1,4 @@
+use oxc_ast::{IrregularCharacterContext, IrregularCharacterKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -12,8 +13,27 @@ use crate::{context::LintContext, rule::Rule};
 #[diagnostic(severity(warning), help("Try to remove the irregular whitespace"))]
 struct NoIrregularWhitespaceDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
-pub struct NoIrregularWhitespace;
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)] // named after the options of the rule
+pub struct NoIrregularWhitespace {
+    skip_strings: bool,
+    skip_comments: bool,
+    skip_reg_exps: bool,
+    skip_templates: bool,
+    skip_jsx_text: bool,
+}
+
+impl Default for NoIrregularWhitespace {
+    fn default() -> Self {
+        Self {
+            skip_strings: true,
+            skip_comments: false,
+            skip_reg_exps: false,
+            skip_templates: false,
+            skip_jsx_text: false,
+        }
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -23,6 +43,13 @@ declare_oxc_lint!(
     /// The use of irregular whitespaces can hinder code readability and
     /// create inconsistencies, making maintenance and collaboration more challenging.
     ///
+    /// ### Options
+    /// * `skipStrings` (default `true`): allow irregular whitespace in string literals
+    /// * `skipComments` (default `false`): allow irregular whitespace in comments
+    /// * `skipRegExps` (default `false`): allow irregular whitespace in regular expression literals
+    /// * `skipTemplates` (default `false`): allow irregular whitespace in template literals
+    /// * `skipJSXText` (default `false`): allow irregular whitespace in JSX text
+    ///
     /// ### Example
     /// ```javascript
     /// function  invalidExample  (  ) {
@@ -34,10 +61,40 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoIrregularWhitespace {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let option = |name: &str, default: bool| {
+            value
+                .get(0)
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self {
+            skip_strings: option("skipStrings", default.skip_strings),
+            skip_comments: option("skipComments", default.skip_comments),
+            skip_reg_exps: option("skipRegExps", default.skip_reg_exps),
+            skip_templates: option("skipTemplates", default.skip_templates),
+            skip_jsx_text: option("skipJSXText", default.skip_jsx_text),
+        }
+    }
+
     fn run_once(&self, ctx: &LintContext) {
-        let irregular_whitespaces = ctx.semantic().trivias().irregular_whitespaces();
-        for irregular_whitespace in irregular_whitespaces {
-            ctx.diagnostic(NoIrregularWhitespaceDiagnostic(*irregular_whitespace));
+        for character in ctx.semantic().trivias().irregular_characters() {
+            if character.kind != IrregularCharacterKind::IrregularWhitespace {
+                continue;
+            }
+            let skip = match character.context {
+                IrregularCharacterContext::Code => false,
+                IrregularCharacterContext::Comment => self.skip_comments,
+                IrregularCharacterContext::String => self.skip_strings,
+                IrregularCharacterContext::Template => self.skip_templates,
+                IrregularCharacterContext::RegExp => self.skip_reg_exps,
+                IrregularCharacterContext::JSXText => self.skip_jsx_text,
+            };
+            if !skip {
+                ctx.diagnostic(NoIrregularWhitespaceDiagnostic(character.span));
+            }
         }
     }
 }
@@ -224,7 +281,8 @@ fn test() {
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div> </div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
         (r"<div>　</div>;", Some(serde_json::json!([{ "skipJSXText": true }]))),
-        // (r"﻿console.log('hello BOM');", None),
+        (r"﻿console.log('hello BOM');", None),
+        (r"' ';", Some(serde_json::json!([{ "skipStrings": true }]))),
     ];
 
     let fail = vec![
@@ -66,7 +66,7 @@ declare_oxc_lint! {
 }
 
 fn is_global_obj(str: &Atom) -> bool {
-    NON_CALLABLE_GLOBALS.iter().any(|&n| str == &n)
+    NON_CALLABLE_GLOBALS.iter().any(|&n| str == n)
 }
 
 fn global_this_member(expr: &oxc_allocator::Box<'_, MemberExpression<'_>>) -> Option<Atom> {
@@ -0,0 +1,185 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{identifier::is_identifier_part, operator::BinaryOperator};
+
+use crate::{ast_util::skip_trivia, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnexpectedMultilineDiagnostic {
+    #[error("eslint(no-unexpected-multiline): Unexpected newline between function and ( of function call.")]
+    #[diagnostic(severity(warning), help("Add a semicolon to the end of the previous line"))]
+    Function(#[label] Span),
+    #[error("eslint(no-unexpected-multiline): Unexpected newline between object and [ of property access.")]
+    #[diagnostic(severity(warning), help("Add a semicolon to the end of the previous line"))]
+    Property(#[label] Span),
+    #[error("eslint(no-unexpected-multiline): Unexpected newline between template tag and template literal.")]
+    #[diagnostic(severity(warning), help("Add a semicolon to the end of the previous line"))]
+    TaggedTemplate(#[label] Span),
+    #[error("eslint(no-unexpected-multiline): Unexpected newline between numerator and division operator.")]
+    #[diagnostic(severity(warning), help("Add a semicolon to the end of the previous line"))]
+    Division(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnexpectedMultiline;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow confusing multiline expressions.
+    ///
+    /// ### Why is this bad?
+    /// A line starting with `(`, `[`, `` ` `` or `/` is not a new statement
+    /// when the previous line is not terminated by a semicolon.
+    /// Automatic Semicolon Insertion does not apply, and the lines are parsed as one expression.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = bar
+    /// (1 || 2).baz();
+    ///
+    /// var hello = 'world'
+    /// [1, 2, 3].forEach(addNumber);
+    ///
+    /// let x = function() {}
+    /// `hello`
+    ///
+    /// let x = foo
+    /// /regex/g.test(bar)
+    /// ```
+    NoUnexpectedMultiline,
+    correctness
+);
+
+impl Rule for NoUnexpectedMultiline {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::CallExpression(call) => {
+                if call.optional || call.arguments.is_empty() {
+                    return;
+                }
+                let end = call
+                    .type_parameters
+                    .as_ref()
+                    .map_or_else(|| call.callee.span().end, |params| params.span.end);
+                if let Some(span) = find_break_after(end, ctx) {
+                    ctx.diagnostic(NoUnexpectedMultilineDiagnostic::Function(span));
+                }
+            }
+            AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(expr)) => {
+                if expr.optional {
+                    return;
+                }
+                if let Some(span) = find_break_after(expr.object.span().end, ctx) {
+                    ctx.diagnostic(NoUnexpectedMultilineDiagnostic::Property(span));
+                }
+            }
+            AstKind::TaggedTemplateExpression(expr) => {
+                let end = expr
+                    .type_parameters
+                    .as_ref()
+                    .map_or_else(|| expr.tag.span().end, |params| params.span.end);
+                if let Some(span) = find_break_after(end, ctx) {
+                    ctx.diagnostic(NoUnexpectedMultilineDiagnostic::TaggedTemplate(span));
+                }
+            }
+            // `a \n /b/g` is parsed as `(a / b) / g`
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Division => {
+                let Expression::BinaryExpression(numerator) = &expr.left else { return };
+                if numerator.operator != BinaryOperator::Division {
+                    return;
+                }
+                // The second slash is directly followed by something like regular expression flags,
+                // e.g. `foo \n /bar/g.test(baz)`
+                let source_text = ctx.source_text();
+                let denominator_start = expr.right.span().start as usize;
+                let denominator = &source_text[denominator_start..];
+                let flags = denominator
+                    .find(|c: char| !is_identifier_part(c))
+                    .map_or(denominator, |end| &denominator[..end]);
+                if flags.is_empty()
+                    || !flags.chars().all(|c| "dgimsuyv".contains(c))
+                    || !source_text[..denominator_start].ends_with('/')
+                {
+                    return;
+                }
+                if let Some(span) = find_break_after(numerator.left.span().end, ctx) {
+                    ctx.diagnostic(NoUnexpectedMultilineDiagnostic::Division(span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the span of the token after `end` if there is a line break before it.
+/// Closing parentheses of the expression ending at `end` are skipped.
+fn find_break_after(end: u32, ctx: &LintContext) -> Option<Span> {
+    let source_text = ctx.source_text();
+    let (mut start, mut has_line_terminator) = skip_trivia(source_text, end);
+    while source_text[start as usize..].starts_with(')') {
+        (start, has_line_terminator) = skip_trivia(source_text, start + 1);
+    }
+    has_line_terminator.then(|| Span::new(start, start + 1))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("(x || y).aFunction()", None),
+        ("[a, b, c].forEach(doSomething)", None),
+        ("var a = b;\n(x || y).doSomething()", None),
+        ("var a = b\n;(x || y).doSomething()", None),
+        ("var a = b\nvoid (x || y).doSomething()", None),
+        ("var a = b;\n[1, 2, 3].forEach(console.log)", None),
+        ("var a = b\nvoid [1, 2, 3].forEach(console.log)", None),
+        ("\"abc\\\n(123)\"", None),
+        ("var a = (\n(123)\n)", None),
+        ("f(\n(x)\n)", None),
+        ("(\nfunction () {}\n)[1]", None),
+        ("let x = function() {};\n   `hello`", None),
+        ("let x = function() {}\nx `hello`", None),
+        ("String.raw `Hi\n${2+3}!`;", None),
+        ("x\n.y\nz `Multi-line template string`", None),
+        ("f(x\n)`Multi-line template string`", None),
+        ("tag `hello ${expression} world`", None),
+        ("var a = foo\n/ bar", None),
+        ("var a = foo /\n bar / g", None),
+        ("var a = foo\n/ bar / 2", None),
+        ("var a = foo\n/bar/ gi", None),
+        ("var a = foo\n/bar/gx", None),
+        ("var a = b\n?.(x || y).doSomething()", None),
+        ("var a = b\n?.[a, b, c].forEach(doSomething)", None),
+        ("var a = b?.\n(x || y).doSomething()", None),
+        ("var a = b?.\n[a, b, c].forEach(doSomething)", None),
+        ("foo\n()", None),
+    ];
+
+    let fail = vec![
+        ("var a = b\n(x || y).doSomething()", None),
+        ("var a = (a || b)\n(x || y).doSomething()", None),
+        ("var a = (a || b)\n(x).doSomething()", None),
+        ("var a = b\n[a, b, c].forEach(doSomething)", None),
+        ("var a = b\n    (x || y).doSomething()", None),
+        ("var a = b\n  [a, b, c].forEach(doSomething)", None),
+        ("let x = function() {}\n `hello`", None),
+        ("let x = function() {}\nx\n`hello`", None),
+        ("x\n.y\nz\n`Multi-line template string`", None),
+        ("foo\n/bar/g.test(baz)", None),
+        ("foo\n/bar/gimuy.test(baz)", None),
+        ("foo /* comment */\n/bar/g.test(baz)", None),
+        ("foo // comment\n(bar)", None),
+        ("((foo)\n)\n(bar)", None),
+    ];
+
+    Tester::new(NoUnexpectedMultiline::NAME, pass, fail).test_and_snapshot();
+}
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnsafeNegation,
-    correctness
+    correctness,
+    dangerous_fix
 );
 
 impl Rule for NoUnsafeNegation {
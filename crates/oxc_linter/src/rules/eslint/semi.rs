@@ -0,0 +1,302 @@
+use oxc_ast::{ast::ExportDefaultDeclarationKind, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_asi_hazard_start, is_line_terminator};
+
+use crate::{ast_util::skip_trivia, context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum SemiDiagnostic {
+    #[error("eslint(semi): Missing semicolon.")]
+    #[diagnostic(severity(warning))]
+    Missing(#[label] Span),
+    #[error("eslint(semi): Extra semicolon.")]
+    #[diagnostic(severity(warning))]
+    Extra(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Semi {
+    never: bool,
+    /// `"always"` only: ignore the last semicolon in a block which has its braces on the same line
+    omit_last_in_one_line_block: bool,
+    /// `"always"` only: ignore the last semicolon in a class body which has its braces on the same line
+    omit_last_in_one_line_class_body: bool,
+    /// `"never"` only
+    before_statement_continuation_chars: BeforeStatementContinuationChars,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require or disallow semicolons instead of relying on Automatic Semicolon Insertion.
+    ///
+    /// ### Why is this bad?
+    /// JavaScript inserts semicolons at the end of some lines automatically,
+    /// but a line starting with `(`, `[`, `` ` ``, `+`, `-` or `/` continues the previous statement,
+    /// which can lead to surprising behavior.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // "always" (default)
+    /// var name = "ESLint"
+    ///
+    /// // "never"
+    /// var name = "ESLint";
+    /// ```
+    Semi,
+    style,
+    fix
+);
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum BeforeStatementContinuationChars {
+    /// Ignore semicolons at the end of statements if the next line starts with `[`, `(`, `/`, `+`, or `-`
+    #[default]
+    Any,
+    /// Require semicolons at the end of statements if the next line starts with `[`, `(`, `/`, `+`, or `-`
+    Always,
+    /// Disallow semicolons as the end of statements even if they cause ASI hazards
+    Never,
+}
+
+impl BeforeStatementContinuationChars {
+    pub fn from(raw: &str) -> Self {
+        match raw {
+            "always" => Self::Always,
+            "never" => Self::Never,
+            _ => Self::Any,
+        }
+    }
+}
+
+impl Rule for Semi {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let never = value.get(0).and_then(serde_json::Value::as_str) == Some("never");
+        let options = value.get(1);
+        let option_bool = |name: &str| {
+            options.and_then(|v| v.get(name)).and_then(serde_json::Value::as_bool).unwrap_or(false)
+        };
+
+        Self {
+            never,
+            omit_last_in_one_line_block: option_bool("omitLastInOneLineBlock"),
+            omit_last_in_one_line_class_body: option_bool("omitLastInOneLineClassBody"),
+            before_statement_continuation_chars: options
+                .and_then(|v| v.get("beforeStatementContinuationChars"))
+                .and_then(serde_json::Value::as_str)
+                .map(BeforeStatementContinuationChars::from)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let span = match node.kind() {
+            AstKind::ExpressionStatement(stmt) => stmt.span,
+            AstKind::VariableDeclaration(decl) => decl.span,
+            AstKind::UsingDeclaration(decl) => decl.span,
+            AstKind::ReturnStatement(stmt) => stmt.span,
+            AstKind::ThrowStatement(stmt) => stmt.span,
+            AstKind::BreakStatement(stmt) => stmt.span,
+            AstKind::ContinueStatement(stmt) => stmt.span,
+            AstKind::DebuggerStatement(stmt) => stmt.span,
+            AstKind::DoWhileStatement(stmt) => stmt.span,
+            AstKind::PropertyDefinition(prop) => prop.span,
+            AstKind::ImportDeclaration(decl) => decl.span,
+            AstKind::ExportAllDeclaration(decl) => decl.span,
+            // `export var a = 1` is checked by the declaration
+            AstKind::ExportNamedDeclaration(decl) if decl.declaration.is_none() => decl.span,
+            AstKind::ExportDefaultDeclaration(decl)
+                if matches!(decl.declaration, ExportDefaultDeclarationKind::Expression(_)) =>
+            {
+                decl.span
+            }
+            _ => return,
+        };
+
+        if self.never {
+            self.check_never(span, ctx);
+        } else {
+            self.check_always(node, span, ctx);
+        }
+    }
+}
+
+impl Semi {
+    fn check_always<'a>(&self, node: &AstNode<'a>, span: Span, ctx: &LintContext<'a>) {
+        if !ctx.semantic().trivias().is_inserted_semicolon(span.end) {
+            return;
+        }
+        if self.is_last_in_one_line_block(node, span, ctx) {
+            return;
+        }
+        report_missing(span, ctx);
+    }
+
+    fn check_never(&self, span: Span, ctx: &LintContext) {
+        let source_text = ctx.source_text();
+        let (next_token, has_line_terminator) = skip_trivia(source_text, span.end);
+        let next_char = source_text[next_token as usize..].chars().next();
+        let is_followed_by_hazard = next_char.is_some_and(is_asi_hazard_start);
+
+        if ctx.semantic().trivias().is_inserted_semicolon(span.end) {
+            if is_followed_by_hazard
+                && self.before_statement_continuation_chars
+                    == BeforeStatementContinuationChars::Always
+            {
+                report_missing(span, ctx);
+            }
+            return;
+        }
+
+        if !source_text[..span.end as usize].ends_with(';') {
+            return;
+        }
+        let is_required = match next_char {
+            // The last statement in a block or program
+            None | Some('}') => false,
+            // `a; b`, the semicolon is required for statements on the same line
+            Some(_) if !has_line_terminator => true,
+            Some(_) => {
+                is_followed_by_hazard
+                    && self.before_statement_continuation_chars
+                        != BeforeStatementContinuationChars::Never
+            }
+        };
+        if is_required {
+            return;
+        }
+
+        let semicolon = Span::new(span.end - 1, span.end);
+        ctx.diagnostic_with_fix(SemiDiagnostic::Extra(semicolon), || Fix::delete(semicolon));
+    }
+
+    fn is_last_in_one_line_block<'a>(
+        &self,
+        node: &AstNode<'a>,
+        span: Span,
+        ctx: &LintContext<'a>,
+    ) -> bool {
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+        let block_span = match parent.kind() {
+            AstKind::BlockStatement(block) if self.omit_last_in_one_line_block => block.span,
+            AstKind::FunctionBody(body) if self.omit_last_in_one_line_block => body.span,
+            AstKind::StaticBlock(block) if self.omit_last_in_one_line_block => block.span,
+            AstKind::ClassBody(body) if self.omit_last_in_one_line_class_body => body.span,
+            _ => return false,
+        };
+        let source_text = ctx.source_text();
+        let (next_token, _) = skip_trivia(source_text, span.end);
+        next_token + 1 == block_span.end
+            && !block_span.source_text(source_text).contains(is_line_terminator)
+    }
+}
+
+fn report_missing(span: Span, ctx: &LintContext) {
+    let position = Span::new(span.end, span.end);
+    ctx.diagnostic_with_fix(SemiDiagnostic::Missing(position), || Fix::new(";", position));
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = 5;", None),
+        ("var x =5, y;", None),
+        ("foo();", None),
+        ("x = foo();", None),
+        ("setTimeout(function() {foo = \"bar\"; });", None),
+        ("setTimeout(function() {foo = \"bar\";});", None),
+        ("for (var a in b){}", None),
+        ("for (var i;;){}", None),
+        ("if (true) {}\n;[global, extended].forEach(function(){});", None),
+        ("throw new Error('foo');", None),
+        ("debugger;", None),
+        ("do{}while(true);", None),
+        ("while(true){ break; }", None),
+        ("class C { a = 1; }", None),
+        ("class C { a; static {} }", None),
+        ("import a from 'a';", None),
+        ("export * from 'foo';", None),
+        ("export { a };", None),
+        ("export default foo;", None),
+        ("export var a = 1;", None),
+        ("export function foo() {}", None),
+        ("export default class {}", None),
+        ("if (foo) { bar() }", Some(json!(["always", { "omitLastInOneLineBlock": true }]))),
+        ("if (foo) { bar(); baz() }", Some(json!(["always", { "omitLastInOneLineBlock": true }]))),
+        ("function f() { return }", Some(json!(["always", { "omitLastInOneLineBlock": true }]))),
+        ("class C { a = 1 }", Some(json!(["always", { "omitLastInOneLineClassBody": true }]))),
+        ("var x = 5", Some(json!(["never"]))),
+        ("foo()", Some(json!(["never"]))),
+        ("debugger", Some(json!(["never"]))),
+        ("for (;;){}", Some(json!(["never"]))),
+        ("do{}while(true)", Some(json!(["never"]))),
+        ("a; b", Some(json!(["never"]))),
+        ("var a = b;\n[1, 2].forEach(c)", Some(json!(["never"]))),
+        ("var a = b;\n(x || y).doSomething()", Some(json!(["never"]))),
+        ("var a = b;\n`foo`", Some(json!(["never"]))),
+        ("var a = b;\n-1", Some(json!(["never", { "beforeStatementContinuationChars": "any" }]))),
+        (
+            "var a = b;\n/foo/.test(c)",
+            Some(json!(["never", { "beforeStatementContinuationChars": "always" }])),
+        ),
+        (
+            "var a = b\n;[1, 2].forEach(c)",
+            Some(json!(["never", { "beforeStatementContinuationChars": "never" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("throw new Error('foo')", None),
+        ("function foo() { return [] }", None),
+        ("var x = 5", None),
+        ("var x = 5, y", None),
+        ("foo()", None),
+        ("for (var a in b) var i ", None),
+        ("debugger", None),
+        ("while(true){ break }", None),
+        ("while(true){ continue }", None),
+        ("do{}while(true)", None),
+        ("import a from 'a'", None),
+        ("export * from 'foo'", None),
+        ("export { a }", None),
+        ("export default foo", None),
+        ("export var a = 1", None),
+        ("class C { a = 1 }", None),
+        ("if (foo) { bar() }", None),
+        ("if (foo) {\n bar() }", Some(json!(["always", { "omitLastInOneLineBlock": true }]))),
+        ("class C { a = 1 }", Some(json!(["always", { "omitLastInOneLineBlock": true }]))),
+        ("var x = 5;", Some(json!(["never"]))),
+        ("foo();", Some(json!(["never"]))),
+        ("debugger;", Some(json!(["never"]))),
+        ("do{}while(true);", Some(json!(["never"]))),
+        ("class C { a = 1; }", Some(json!(["never"]))),
+        ("export { a };", Some(json!(["never"]))),
+        ("if (foo) { bar(); }", Some(json!(["never"]))),
+        ("var a = b;\nc()", Some(json!(["never"]))),
+        (
+            "var a = b;\n[1, 2].forEach(c)",
+            Some(json!(["never", { "beforeStatementContinuationChars": "never" }])),
+        ),
+        (
+            "var a = b\n++c",
+            Some(json!(["never", { "beforeStatementContinuationChars": "always" }])),
+        ),
+    ];
+
+    let fix = vec![
+        ("var x = 5", "var x = 5;", None),
+        ("if (foo) { bar() }", "if (foo) { bar(); }", None),
+        ("var x = 5;", "var x = 5", Some(json!(["never"]))),
+        ("foo();\nbar();", "foo()\nbar()", Some(json!(["never"]))),
+    ];
+
+    Tester::new(Semi::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -46,6 +46,7 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    dangerous_fix
 );
 
 impl Rule for ValidTypeof {
@@ -4,7 +4,7 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use oxc_syntax::module_record::ImportImportName;
+use oxc_syntax::module_record::{ImportImportName, ImportPhase};
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -42,6 +42,10 @@ impl Rule for Default {
             let ImportImportName::Default(default_span) = import_entry.import_name else {
                 continue;
             };
+            // `import source x from "x.wasm"` imports the source of the module, not its exports
+            if import_entry.phase == Some(ImportPhase::Source) {
+                continue;
+            }
 
             let specifier = import_entry.module_request.name();
             let Some(remote_module_record_ref) = module_record.loaded_modules.get(specifier) else {
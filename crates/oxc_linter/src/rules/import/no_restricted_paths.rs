@@ -0,0 +1,303 @@
+use std::path::{Component, Path, PathBuf};
+
+use globset::{Glob, GlobMatcher};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint-plugin-import(no-restricted-paths): Unexpected path {0:?} imported in restricted zone."
+)]
+#[diagnostic(severity(warning))]
+struct NoRestrictedPathsDiagnostic(Atom, #[help] Option<String>, #[label] Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-restricted-paths.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPaths(Box<NoRestrictedPathsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPathsConfig {
+    zones: Vec<Zone>,
+}
+
+impl std::ops::Deref for NoRestrictedPaths {
+    type Target = NoRestrictedPathsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Files matching `target` may not import files matching `from`, unless they match `except`.
+#[derive(Debug, Clone)]
+struct Zone {
+    target: Vec<PathPattern>,
+    from: Vec<PathPattern>,
+    except: Vec<PathPattern>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum PathPattern {
+    /// A file or a directory, matching every file inside of it
+    Path(PathBuf),
+    Glob(GlobMatcher),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Restricts which files can be imported in a given folder, e.g. to enforce the
+    /// architecture boundaries of an application or the packages of a monorepo.
+    ///
+    /// Each zone forbids the files matching `target` to import the files matching `from`,
+    /// except the files matching `except`. Paths and globs are relative to `basePath`,
+    /// which defaults to the current working directory; `except` paths are relative to `from`,
+    /// or to `basePath` if `from` is a glob.
+    ///
+    /// This rule requires the import plugin.
+    ///
+    /// ### Example
+    ///
+    /// ```json
+    /// {
+    ///   "import/no-restricted-paths": ["error", {
+    ///     "zones": [
+    ///       { "target": "./src/ui", "from": "./src/server", "message": "Use the API client instead." },
+    ///       { "target": "./packages/app", "from": "./packages/app/dist" }
+    ///     ]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// ```javascript
+    /// // src/ui/page.js
+    /// import { query } from "../server/db"; // reported
+    /// ```
+    NoRestrictedPaths,
+    restriction
+);
+
+impl Rule for NoRestrictedPaths {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let base_path = config
+            .and_then(|config| config.get("basePath"))
+            .and_then(Value::as_str)
+            .map_or_else(|| cwd.clone(), |base_path| cwd.join(base_path));
+        let zones = config
+            .and_then(|config| config.get("zones"))
+            .and_then(Value::as_array)
+            .map(|zones| zones.iter().filter_map(|zone| Zone::new(zone, &base_path)).collect())
+            .unwrap_or_default();
+        Self(Box::new(NoRestrictedPathsConfig { zones }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        if self.zones.is_empty() {
+            return;
+        }
+        let module_record = ctx.semantic().module_record();
+        let importer = &module_record.resolved_absolute_path;
+        let zones = self
+            .zones
+            .iter()
+            .filter(|zone| matches_any(&zone.target, importer))
+            .collect::<Vec<_>>();
+        if zones.is_empty() {
+            return;
+        }
+        for (request, spans) in &module_record.requested_modules {
+            let Some(remote_module_record_ref) = module_record.loaded_modules.get(request) else {
+                continue;
+            };
+            let imported_path = &remote_module_record_ref.value().resolved_absolute_path;
+            let Some(zone) = zones.iter().find(|zone| {
+                matches_any(&zone.from, imported_path) && !matches_any(&zone.except, imported_path)
+            }) else {
+                continue;
+            };
+            for span in spans {
+                ctx.diagnostic(NoRestrictedPathsDiagnostic(
+                    request.clone(),
+                    zone.message.clone(),
+                    *span,
+                ));
+            }
+        }
+    }
+}
+
+impl Zone {
+    fn new(zone: &Value, base_path: &Path) -> Option<Self> {
+        let patterns = |key: &str, base_path: &Path| -> Vec<PathPattern> {
+            match zone.get(key) {
+                Some(Value::String(path)) => {
+                    PathPattern::new(path, base_path).into_iter().collect()
+                }
+                Some(Value::Array(paths)) => paths
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|path| PathPattern::new(path, base_path))
+                    .collect(),
+                _ => vec![],
+            }
+        };
+        let target = patterns("target", base_path);
+        let from = patterns("from", base_path);
+        if target.is_empty() || from.is_empty() {
+            return None;
+        }
+        // Except paths are relative to each `from` path,
+        // except paths and globs of a `from` glob to the base path
+        let mut except = vec![];
+        for from in &from {
+            match from {
+                PathPattern::Path(from) => except.extend(
+                    patterns("except", from)
+                        .into_iter()
+                        .filter(|except| matches!(except, PathPattern::Path(_))),
+                ),
+                PathPattern::Glob(_) => except.extend(patterns("except", base_path)),
+            }
+        }
+        let message = zone.get("message").and_then(Value::as_str).map(ToString::to_string);
+        Some(Self { target, from, except, message })
+    }
+}
+
+impl PathPattern {
+    fn new(path: &str, base_path: &Path) -> Option<Self> {
+        let path = normalize(&base_path.join(path));
+        if path.to_str()?.contains(['*', '?', '[']) {
+            Glob::new(path.to_str()?).ok().map(|glob| Self::Glob(glob.compile_matcher()))
+        } else {
+            Some(Self::Path(path))
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            Self::Path(prefix) => path.starts_with(prefix),
+            Self::Glob(matcher) => matcher.is_match(path),
+        }
+    }
+}
+
+fn matches_any(patterns: &[PathPattern], path: &Path) -> bool {
+    patterns.iter().any(|pattern| pattern.matches(path))
+}
+
+/// Remove `.` and `..` components without accessing the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let zones = |zones: Value| Some(json!([{ "zones": zones }]));
+
+    {
+        let pass = vec![
+            (
+                r#"import a from "../client/a.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server",
+                    "from": "./fixtures/import/restricted-paths/other",
+                }])),
+            ),
+            (
+                r#"import a from "./one/a.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server",
+                    "from": "./fixtures/import/restricted-paths/server/one",
+                    "except": ["./a.js"],
+                }])),
+            ),
+            (
+                r#"import a from "../client/a.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/client",
+                    "from": "./fixtures/import/restricted-paths/server",
+                }])),
+            ),
+            (
+                r#"import a from "./one/a.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server/**/*",
+                    "from": "./fixtures/import/restricted-paths/server/one/*",
+                    "except": ["./fixtures/import/restricted-paths/server/one/a.js"],
+                }])),
+            ),
+            (r#"import a from "../client/a.js""#, None),
+        ];
+
+        let fail = vec![
+            (
+                r#"import a from "../client/a.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server",
+                    "from": "./fixtures/import/restricted-paths/client",
+                    "message": "Custom message",
+                }])),
+            ),
+            (
+                r#"export { a } from "../client/one/a.js""#,
+                zones(json!([{
+                    "target": ["./fixtures/import/restricted-paths/server"],
+                    "from": ["./fixtures/import/restricted-paths/client/one"],
+                }])),
+            ),
+            (
+                r#"import a from "./one/b.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server",
+                    "from": "./fixtures/import/restricted-paths/server/one",
+                    "except": ["./a.js"],
+                }])),
+            ),
+            (
+                r#"import a from "./one/b.js""#,
+                zones(json!([{
+                    "target": "./fixtures/import/restricted-paths/server/**/*",
+                    "from": "./fixtures/import/restricted-paths/server/one/*",
+                    "except": ["./fixtures/import/restricted-paths/server/one/a.js"],
+                }])),
+            ),
+            (
+                r#"import a from "../client/a.js""#,
+                Some(json!([{
+                    "basePath": "./fixtures/import/restricted-paths",
+                    "zones": [{ "target": "./server", "from": "./client" }],
+                }])),
+            ),
+        ];
+
+        Tester::new(NoRestrictedPaths::NAME, pass, fail)
+            .change_rule_path("restricted-paths/server/b.js")
+            .with_import_plugin(true)
+            .test_and_snapshot();
+    }
+}
@@ -33,7 +33,9 @@ impl Rule for NoUnresolved {
         let module_record = ctx.semantic().module_record();
 
         for (specifier, spans) in &module_record.requested_modules {
-            if module_record.loaded_modules.contains_key(specifier) {
+            if module_record.loaded_modules.contains_key(specifier)
+                || module_record.external_modules.contains(specifier)
+            {
                 continue;
             }
             let specifier_path = Path::new(specifier.as_str());
@@ -130,4 +132,36 @@ fn test() {
         .change_rule_path("index.js")
         .with_import_plugin(true)
         .test_and_snapshot();
+
+    // Import maps and core modules from the settings
+    let settings = serde_json::json!({
+        "import/core-modules": ["electron"],
+        "import/import-map": {
+            "imports": {
+                "lit": "https://cdn.jsdelivr.net/npm/lit/+esm",
+                "app/": "./bar/"
+            },
+            "scopes": {
+                "/": { "scoped": "./bar.js" }
+            }
+        }
+    });
+    let pass = vec![
+        (r#"import { LitElement } from "lit""#, None, Some(settings.clone())),
+        (r#"import "lit""#, None, Some(settings.clone())),
+        (r#"import bar from "app/index.js""#, None, Some(settings.clone())),
+        (r#"import scoped from "scoped""#, None, Some(settings.clone())),
+        (r#"import { app } from "electron""#, None, Some(settings.clone())),
+        (r#"import { app } from "electron/main""#, None, Some(settings.clone())),
+    ];
+    let fail = vec![
+        (r#"import bar from "app/does-not-exist.js""#, None, Some(settings.clone())),
+        (r#"import store from "electron-store""#, None, Some(settings.clone())),
+        (r#"import { LitElement } from "lit""#, None, None),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test();
 }
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// expect(a).toThrowError();
     /// ```
     NoAliasMethods,
-    style
+    style,
+    fix
 );
 
 impl Rule for NoAliasMethods {
@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    correctness
+    correctness,
+    dangerous_fix
 );
 
 impl Rule for NoFocusedTests {
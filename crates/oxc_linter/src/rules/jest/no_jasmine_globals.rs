@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoJasmineGlobals,
-    style
+    style,
+    fix
 );
 
 const NON_JASMINE_PROPERTY_NAMES: [&str; 4] = ["spyOn", "spyOnProperty", "fail", "pending"];
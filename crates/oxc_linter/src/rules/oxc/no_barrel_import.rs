@@ -0,0 +1,223 @@
+use std::{
+    path::{Component, Path},
+    sync::Arc,
+};
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::{ModuleRecord, ResolvedExport};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("oxc(no-barrel-import): `{0}` is a barrel file which only re-exports other modules.")]
+#[diagnostic(
+    severity(warning),
+    help("Import from the modules declaring the bindings instead, so every module re-exported by the barrel file does not have to be loaded.")
+)]
+struct NoBarrelImportDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoBarrelImport;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows importing from barrel files, modules which only re-export other modules
+    /// such as an `index.js` containing `export * from "./button"`.
+    /// The fix rewrites named imports to import from the modules declaring the bindings.
+    ///
+    /// This rule requires the import plugin.
+    ///
+    /// ### Why is this bad?
+    /// Importing a single binding from a barrel file loads every module it re-exports,
+    /// which slows down bundlers, test runners and dev servers which do not tree shake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // components/index.js
+    /// export * from "./button";
+    /// export * from "./dialog";
+    ///
+    /// // app.js
+    /// import { Button } from "./components"; // loads ./components/dialog as well
+    /// import { Button } from "./components/button"; // fixed
+    /// ```
+    NoBarrelImport,
+    restriction,
+    dangerous_fix
+);
+
+impl Rule for NoBarrelImport {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) = node.kind()
+        else {
+            return;
+        };
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let module_record = ctx.semantic().module_record();
+        let Some(barrel) = module_record.loaded_modules.get(&decl.source.value) else { return };
+        if !barrel.is_barrel() {
+            return;
+        }
+        let diagnostic = NoBarrelImportDiagnostic(decl.source.value.clone(), decl.source.span);
+        let importer = &module_record.resolved_absolute_path;
+        match direct_imports(decl, &barrel, importer, ctx.source_text()) {
+            Some(imports) => ctx.diagnostic_with_fix(diagnostic, || Fix::new(imports, decl.span)),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Imports of `decl` rewritten to the modules declaring the bindings, one per module.
+///
+/// Returns `None` if any binding can not be rewritten, e.g. namespace imports of the barrel file.
+fn direct_imports(
+    decl: &ImportDeclaration,
+    barrel: &Arc<ModuleRecord>,
+    importer: &Path,
+    source_text: &str,
+) -> Option<String> {
+    let specifiers = decl.specifiers.as_ref().filter(|specifiers| !specifiers.is_empty())?;
+    let keep_extension = Path::new(decl.source.value.as_str()).extension().is_some();
+    let importer_dir = importer.parent()?;
+
+    let mut imports: Vec<DirectImport> = vec![];
+    for specifier in specifiers {
+        let (imported_name, local) = match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                if specifier.import_kind.is_type() {
+                    return None;
+                }
+                (specifier.imported.name().as_str(), specifier.local.name.as_str())
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                ("default", specifier.local.name.as_str())
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+        };
+        let (module, name) = match ModuleRecord::resolve_export(barrel, imported_name)? {
+            ResolvedExport::Binding { module, name } => (module, Some(name)),
+            ResolvedExport::Namespace(module) => (module, None),
+        };
+        let source =
+            relative_specifier(importer_dir, &module.resolved_absolute_path, keep_extension)?;
+        let Some(name) = name else {
+            // `export * as ns from "mod"` can only be imported on its own or with a default import
+            imports.push(DirectImport {
+                source,
+                namespace: Some(local.to_string()),
+                ..DirectImport::default()
+            });
+            continue;
+        };
+        let index = imports
+            .iter()
+            .position(|import| import.source == source && import.namespace.is_none())
+            .unwrap_or_else(|| {
+                imports.push(DirectImport { source, ..DirectImport::default() });
+                imports.len() - 1
+            });
+        let import = &mut imports[index];
+        if name == "default" && import.default.is_none() {
+            import.default = Some(local.to_string());
+        } else if name == local {
+            import.named.push(local.to_string());
+        } else {
+            import.named.push(format!("{name} as {local}"));
+        }
+    }
+
+    let original = decl.span.source_text(source_text);
+    let quote = &source_text[decl.source.span.start as usize..=decl.source.span.start as usize];
+    let semicolon = if original.ends_with(';') { ";" } else { "" };
+    let imports = imports
+        .iter()
+        .map(|import| {
+            let mut clauses = vec![];
+            clauses.extend(import.default.clone());
+            if let Some(namespace) = &import.namespace {
+                clauses.push(format!("* as {namespace}"));
+            }
+            if !import.named.is_empty() {
+                clauses.push(format!("{{ {} }}", import.named.join(", ")));
+            }
+            format!("import {} from {quote}{}{quote}{semicolon}", clauses.join(", "), import.source)
+        })
+        .collect::<Vec<_>>();
+    Some(imports.join("\n"))
+}
+
+#[derive(Debug, Default)]
+struct DirectImport {
+    source: String,
+    default: Option<String>,
+    namespace: Option<String>,
+    named: Vec<String>,
+}
+
+/// Relative module specifier of `to` imported from a module in `from_dir`, e.g. `./button`
+fn relative_specifier(from_dir: &Path, to: &Path, keep_extension: bool) -> Option<String> {
+    let to = if keep_extension { to.to_path_buf() } else { to.with_extension("") };
+    let from = from_dir.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    // Different roots, e.g. other drives on Windows
+    if common == 0 {
+        return None;
+    }
+    let mut parts = vec![];
+    parts.extend(from[common..].iter().map(|_| "..".to_string()));
+    for component in &to[common..] {
+        let Component::Normal(name) = component else { return None };
+        parts.push(name.to_str()?.to_string());
+    }
+    let path = parts.join("/");
+    Some(if path.starts_with("..") { path } else { format!("./{path}") })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import { a } from "./barrel/a""#,
+        r#"import { a } from "./barrel/side-effect""#,
+        r#"import type { a } from "./barrel""#,
+        r#"import { foo } from "./unknown""#,
+    ];
+
+    let fail = vec![
+        r#"import { a } from "./barrel""#,
+        r#"import { e } from "./barrel/nested""#,
+        r#"import * as all from "./barrel""#,
+        r#"import { unknown } from "./barrel""#,
+    ];
+
+    let fix = vec![
+        (r#"import { a } from "./barrel";"#, r#"import { a } from "./barrel/a";"#, None),
+        (r#"import { a } from "./barrel/index.js";"#, r#"import { a } from "./barrel/a.js";"#, None),
+        (
+            r#"import { a, renamed, c as x, e } from "./barrel";"#,
+            "import { a } from \"./barrel/a\";\nimport x, { b as renamed } from \"./barrel/b\";\nimport { e } from \"./barrel/nested/e\";",
+            None,
+        ),
+        (r"import { d } from './barrel'", r"import * as d from './barrel/d'", None),
+        (r#"import * as all from "./barrel";"#, r#"import * as all from "./barrel";"#, None),
+    ];
+
+    Tester::new(NoBarrelImport::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,104 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::today};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("oxc(no-expired-ts-suppression): `{0}` expired on {1}.")]
+#[diagnostic(
+    severity(warning),
+    help("Fix the suppressed error and remove the comment, or extend the expiry date.")
+)]
+struct NoExpiredTsSuppressionDiagnostic(&'static str, String, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExpiredTsSuppression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows `@ts-expect-error` and `@ts-ignore` comments whose description contains an
+    /// expiry date in the past, written as `expires YYYY-MM-DD`, `expires: YYYY-MM-DD` or
+    /// `until YYYY-MM-DD`.
+    ///
+    /// ### Why is this bad?
+    /// Suppressions added as a temporary workaround, e.g. during a migration, tend to stay
+    /// forever. An expiry date turns them into a lint error once the workaround is overdue.
+    ///
+    /// This rule depends on the current date, so the same code passes today and fails tomorrow.
+    /// It is a restriction rule to be enabled explicitly, e.g. in a scheduled CI job.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // @ts-expect-error: untyped until the v2 migration, expires 2024-01-31
+    /// legacy.call(a);
+    /// ```
+    NoExpiredTsSuppression,
+    restriction
+);
+
+impl Rule for NoExpiredTsSuppression {
+    fn run_once(&self, ctx: &LintContext) {
+        let suppressions = ctx.semantic().trivias().ts_suppressions();
+        if suppressions.is_empty() {
+            return;
+        }
+        let today = today();
+        for suppression in suppressions {
+            let Some(date) = expiry_date(suppression.description(ctx.source_text())) else {
+                continue;
+            };
+            // ISO dates compare in chronological order
+            if date < today.as_str() {
+                ctx.diagnostic(NoExpiredTsSuppressionDiagnostic(
+                    suppression.kind.as_str(),
+                    date.to_string(),
+                    suppression.span,
+                ));
+            }
+        }
+    }
+}
+
+/// The `YYYY-MM-DD` date following `expires` or `until` in a description
+fn expiry_date(description: &str) -> Option<&str> {
+    let lowercase = description.to_ascii_lowercase();
+    ["expires", "until"].into_iter().find_map(|keyword| {
+        lowercase.match_indices(keyword).find_map(|(index, _)| {
+            let rest = &description[index + keyword.len()..];
+            let rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+            let date = rest.get(..10)?;
+            let is_date = date.bytes().enumerate().all(|(i, b)| match i {
+                4 | 7 => b == b'-',
+                _ => b.is_ascii_digit(),
+            });
+            is_date.then_some(date)
+        })
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// @ts-expect-error: expires 2999-12-31\nfoo(1);", None),
+        ("// @ts-ignore until 2999-01-01\nfoo(1);", None),
+        ("// @ts-ignore: fixed in 2000-01-01\nfoo(1);", None),
+        ("// @ts-ignore: expires soon\nfoo(1);", None),
+        ("// expires 2000-01-01\nfoo(1);", None),
+        ("// @ts-ignore\nfoo(1);", None),
+    ];
+
+    let fail = vec![
+        ("// @ts-expect-error: expires 2000-01-01\nfoo(1);", None),
+        ("// @ts-expect-error Expires: 2000-01-01\nfoo(1);", None),
+        ("// @ts-ignore untyped until 2000-01-01\nfoo(1);", None),
+        ("/*\n * @ts-ignore until 2000-01-01 */\nfoo(1);", None),
+    ];
+
+    Tester::new(NoExpiredTsSuppression::NAME, pass, fail).test_and_snapshot();
+}
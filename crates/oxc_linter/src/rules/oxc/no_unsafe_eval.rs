@@ -0,0 +1,204 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnsafeEvalDiagnostic {
+    #[error("oxc(no-unsafe-eval): `{0}` evaluates a string as code and is blocked by a strict Content Security Policy.")]
+    #[diagnostic(
+        severity(warning),
+        help("Avoid compiling code from strings, or allow `{0}` if the deployment sets `'unsafe-eval'`.")
+    )]
+    Eval(&'static str, #[label] Span),
+    #[error("oxc(no-unsafe-eval): Passing a string to `{0}` evaluates it as code and is blocked by a strict Content Security Policy.")]
+    #[diagnostic(severity(warning), help("Pass a function to `{0}` instead of a string."))]
+    StringCallback(&'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeEval(Box<NoUnsafeEvalConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeEvalConfig {
+    /// Global functions which are not reported.
+    ///
+    /// ```javascript
+    /// // allow: ['setTimeout']
+    /// setTimeout('foo()', 100); // will not error
+    /// eval('foo()'); // will error
+    /// ```
+    allow: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnsafeEval {
+    type Target = NoUnsafeEvalConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows constructs which compile strings into code at runtime:
+    /// `eval`, `Function`, and `setTimeout`, `setInterval` or `execScript` called with a string.
+    ///
+    /// ### Why is this bad?
+    /// A Content Security Policy without `'unsafe-eval'` in `script-src` blocks all of these,
+    /// so code using them breaks in CSP-enforced environments such as browser extensions
+    /// or sites with a strict policy.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// eval('foo()');
+    /// new Function('a', 'return a');
+    /// setTimeout('foo()', 100);
+    /// window.setInterval(`tick(${id})`, 100);
+    /// ```
+    NoUnsafeEval,
+    restriction
+);
+
+/// Functions which always compile their arguments as code.
+const EVAL_FUNCTIONS: [&str; 2] = ["eval", "Function"];
+
+/// Functions which compile their first argument as code when it is a string.
+const STRING_CALLBACK_FUNCTIONS: [&str; 3] = ["setTimeout", "setInterval", "execScript"];
+
+/// Global objects whose properties are the global functions above, e.g. `window.eval`.
+const GLOBAL_OBJECTS: [&str; 3] = ["window", "globalThis", "self"];
+
+impl Rule for NoUnsafeEval {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUnsafeEvalConfig {
+            allow: value
+                .get(0)
+                .and_then(|v| v.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments, call.span),
+            AstKind::NewExpression(new) => (&new.callee, &new.arguments, new.span),
+            _ => return,
+        };
+        let Some(name) = global_callee_name(callee, ctx) else { return };
+        if self.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+
+        if let Some(name) = EVAL_FUNCTIONS.into_iter().find(|f| *f == name) {
+            ctx.diagnostic(NoUnsafeEvalDiagnostic::Eval(name, span));
+            return;
+        }
+
+        let Some(name) = STRING_CALLBACK_FUNCTIONS.into_iter().find(|f| *f == name) else {
+            return;
+        };
+        if let Some(Argument::Expression(first)) = arguments.first() {
+            if is_string_like(first) {
+                ctx.diagnostic(NoUnsafeEvalDiagnostic::StringCallback(name, first.span()));
+            }
+        }
+    }
+}
+
+/// Returns the name of a global function referenced by `callee`, either directly (`eval`)
+/// or as a property of a global object (`window.eval`, `globalThis["eval"]`).
+fn global_callee_name<'a>(callee: &'a Expression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    match callee.without_parenthesized() {
+        Expression::Identifier(ident) if is_global_reference(ident, ctx) => {
+            Some(ident.name.as_str())
+        }
+        Expression::MemberExpression(member) => {
+            let Expression::Identifier(object) = member.object().without_parenthesized() else {
+                return None;
+            };
+            if !GLOBAL_OBJECTS.contains(&object.name.as_str()) || !is_global_reference(object, ctx)
+            {
+                return None;
+            }
+            member.static_property_name()
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` evaluates to a string: a string or template literal,
+/// or a concatenation involving one.
+fn is_string_like(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            is_string_like(&expr.left) || is_string_like(&expr.right)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("setTimeout(function() { foo() }, 100)", None),
+        ("setTimeout(() => foo(), 100)", None),
+        ("setInterval(foo, 100)", None),
+        ("window.setTimeout(foo, 100)", None),
+        ("setTimeout(callback, 100)", None),
+        ("foo.eval('bar')", None),
+        ("foo.setTimeout('bar()', 100)", None),
+        ("const eval = (s) => s; eval('foo')", None),
+        ("function f(Function) { new Function('a') }", None),
+        ("function f(setTimeout) { setTimeout('foo()') }", None),
+        ("function f(window) { window.eval('foo') }", None),
+        ("evaluate('foo')", None),
+        ("setTimeout('foo()', 100)", Some(json!([{ "allow": ["setTimeout"] }]))),
+        ("eval('foo')", Some(json!([{ "allow": ["eval"] }]))),
+        ("new Function('a', 'return a')", Some(json!([{ "allow": ["Function"] }]))),
+    ];
+
+    let fail = vec![
+        ("eval('foo')", None),
+        ("eval(code)", None),
+        ("(eval)('foo')", None),
+        ("window.eval('foo')", None),
+        ("globalThis['eval']('foo')", None),
+        ("self.eval('foo')", None),
+        ("new Function('a', 'return a')", None),
+        ("Function('return this')()", None),
+        ("new window.Function('a', 'return a')", None),
+        ("setTimeout('foo()', 100)", None),
+        ("setInterval('foo()', 100)", None),
+        ("execScript('foo()')", None),
+        ("setTimeout(`foo(${a})`, 100)", None),
+        ("setTimeout('foo(' + a + ')', 100)", None),
+        ("setTimeout(('foo()'), 100)", None),
+        ("window.setTimeout('foo()', 100)", None),
+        ("globalThis.setInterval('foo()', 100)", None),
+        ("setInterval('foo()', 100)", Some(json!([{ "allow": ["setTimeout"] }]))),
+    ];
+
+    Tester::new(NoUnsafeEval::NAME, pass, fail).test_and_snapshot();
+}
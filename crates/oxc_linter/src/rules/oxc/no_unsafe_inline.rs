@@ -0,0 +1,258 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName, JSXAttributeValue},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("oxc(no-unsafe-inline): Inline event handler `{0}` is blocked by a strict Content Security Policy.")]
+#[diagnostic(
+    severity(warning),
+    help("Pass a function to `{0}` instead of a string, or attach the listener with `addEventListener`.")
+)]
+struct NoUnsafeInlineDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeInline(Box<NoUnsafeInlineConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeInlineConfig {
+    /// Attribute names which are not reported.
+    ///
+    /// ```javascript
+    /// // allow: ['onclick']
+    /// <button onclick="foo()" />; // will not error
+    /// <button onmouseover="foo()" />; // will error
+    /// ```
+    allow: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnsafeInline {
+    type Target = NoUnsafeInlineConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows inline event handlers written as strings in JSX, e.g. `onclick="foo()"`.
+    ///
+    /// ### Why is this bad?
+    /// String event handlers are rendered as inline scripts, which a Content Security Policy
+    /// without `'unsafe-inline'` in `script-src` refuses to run.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <button onclick="handleClick()">Click</button>
+    ///
+    /// // Good
+    /// <button onClick={handleClick}>Click</button>
+    /// ```
+    NoUnsafeInline,
+    restriction
+);
+
+impl Rule for NoUnsafeInline {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUnsafeInlineConfig {
+            allow: value
+                .get(0)
+                .and_then(|v| v.get("allow"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() else {
+            return;
+        };
+        let JSXAttributeName::Identifier(ident) = &attr.name else { return };
+        let Some(JSXAttributeValue::StringLiteral(value)) = &attr.value else { return };
+
+        let name = ident.name.as_str();
+        if !is_event_handler_name(name) || self.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+        // An empty handler does not run any code
+        if value.value.trim().is_empty() {
+            return;
+        }
+        ctx.diagnostic(NoUnsafeInlineDiagnostic(name.to_string(), attr.span));
+    }
+}
+
+/// `onclick`, `onClick`, `onmouseover`, ... but not `one`
+fn is_event_handler_name(name: &str) -> bool {
+    name.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+        && EVENTS.binary_search(&name[2..].to_ascii_lowercase().as_str()).is_ok()
+}
+
+/// Events of the event handler attributes of HTML elements, sorted
+const EVENTS: [&str; 113] = [
+    "abort",
+    "afterprint",
+    "animationend",
+    "animationiteration",
+    "animationstart",
+    "auxclick",
+    "beforeinput",
+    "beforeprint",
+    "beforetoggle",
+    "beforeunload",
+    "blur",
+    "cancel",
+    "canplay",
+    "canplaythrough",
+    "change",
+    "click",
+    "close",
+    "contextlost",
+    "contextmenu",
+    "contextrestored",
+    "copy",
+    "cuechange",
+    "cut",
+    "dblclick",
+    "drag",
+    "dragend",
+    "dragenter",
+    "dragleave",
+    "dragover",
+    "dragstart",
+    "drop",
+    "durationchange",
+    "emptied",
+    "ended",
+    "error",
+    "focus",
+    "focusin",
+    "focusout",
+    "formdata",
+    "hashchange",
+    "input",
+    "invalid",
+    "keydown",
+    "keypress",
+    "keyup",
+    "languagechange",
+    "load",
+    "loadeddata",
+    "loadedmetadata",
+    "loadstart",
+    "message",
+    "messageerror",
+    "mousedown",
+    "mouseenter",
+    "mouseleave",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "offline",
+    "online",
+    "pagehide",
+    "pagereveal",
+    "pageshow",
+    "paste",
+    "pause",
+    "play",
+    "playing",
+    "pointercancel",
+    "pointerdown",
+    "pointerenter",
+    "pointerleave",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "popstate",
+    "progress",
+    "ratechange",
+    "rejectionhandled",
+    "reset",
+    "resize",
+    "scroll",
+    "scrollend",
+    "securitypolicyviolation",
+    "seeked",
+    "seeking",
+    "select",
+    "selectionchange",
+    "selectstart",
+    "slotchange",
+    "stalled",
+    "storage",
+    "submit",
+    "suspend",
+    "timeupdate",
+    "toggle",
+    "touchcancel",
+    "touchend",
+    "touchmove",
+    "touchstart",
+    "transitioncancel",
+    "transitionend",
+    "transitionrun",
+    "transitionstart",
+    "unhandledrejection",
+    "unload",
+    "volumechange",
+    "waiting",
+    "webkitanimationend",
+    "webkitanimationiteration",
+    "webkitanimationstart",
+    "wheel",
+];
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<button onClick={handleClick} />", None),
+        ("<button onClick={() => handleClick()} />", None),
+        ("<button onclick={handleClick} />", None),
+        ("<button onclick />", None),
+        ("<button onclick=\"\" />", None),
+        ("<button title=\"foo()\" />", None),
+        ("<button one=\"foo()\" />", None),
+        ("<button on=\"foo()\" />", None),
+        ("<Foo on-click=\"foo()\" />", None),
+        ("<button {...props} />", None),
+        ("<button onclick=\"foo()\" />", Some(json!([{ "allow": ["onclick"] }]))),
+    ];
+
+    let fail = vec![
+        ("<button onclick=\"foo()\" />", None),
+        ("<button onClick=\"foo()\" />", None),
+        ("<img onerror=\"alert(1)\" />", None),
+        ("<body onload='init()'></body>", None),
+        ("<div onmouseover=\"foo()\">bar</div>", Some(json!([{ "allow": ["onclick"] }]))),
+    ];
+
+    Tester::new(NoUnsafeInline::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_events_sorted() {
+    assert!(EVENTS.windows(2).all(|w| w[0] < w[1]));
+}
@@ -0,0 +1,434 @@
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentTarget, BindingPatternKind, Expression, MemberExpression,
+        SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnsanitizedDiagnostic {
+    #[error("oxc(no-unsanitized): Unsanitized value assigned to `{0}`.")]
+    #[diagnostic(
+        severity(warning),
+        help("Pass the value through a sanitizer such as `DOMPurify.sanitize`, or add yours to `sanitizers`.")
+    )]
+    Assignment(&'static str, #[label] Span),
+    #[error("oxc(no-unsanitized): Unsanitized value passed to `{0}`.")]
+    #[diagnostic(
+        severity(warning),
+        help("Pass the value through a sanitizer such as `DOMPurify.sanitize`, or add yours to `sanitizers`.")
+    )]
+    Call(&'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsanitized(Box<NoUnsanitizedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsanitizedConfig {
+    /// Functions whose return value is safe to use as HTML or code, in addition to
+    /// `DOMPurify.sanitize`.
+    ///
+    /// ```javascript
+    /// // sanitizers: ['escapeHtml', 'sanitizer.clean']
+    /// el.innerHTML = escapeHtml(input); // will not error
+    /// el.innerHTML = sanitizer.clean(input); // will not error
+    /// ```
+    sanitizers: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnsanitized {
+    type Target = NoUnsanitizedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows values which may contain user input flowing into DOM sinks which parse HTML,
+    /// `innerHTML`, `outerHTML`, `insertAdjacentHTML`, `document.write`, and into `eval`,
+    /// `Function`, `setTimeout` and `setInterval` which compile strings as code.
+    ///
+    /// A value is safe when it is built from literals only, or returned by a sanitizer.
+    /// Variables are followed through their declaration and every assignment,
+    /// parameters and other unknown values are unsafe.
+    ///
+    /// ### Why is this bad?
+    /// HTML or code built from user input is the most common source of cross-site scripting.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// el.innerHTML = location.hash;
+    /// document.write(`<p>${name}</p>`);
+    ///
+    /// // Good
+    /// el.innerHTML = '<p>Hello</p>';
+    /// el.innerHTML = DOMPurify.sanitize(html);
+    /// el.textContent = name;
+    /// ```
+    NoUnsanitized,
+    restriction
+);
+
+/// Properties which parse the assigned string as HTML.
+const HTML_PROPERTIES: [&str; 2] = ["innerHTML", "outerHTML"];
+
+/// Sanitizers which are always allowed.
+const DEFAULT_SANITIZERS: [&str; 1] = ["DOMPurify.sanitize"];
+
+impl Rule for NoUnsanitized {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUnsanitizedConfig {
+            sanitizers: value
+                .get(0)
+                .and_then(|v| v.get("sanitizers"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::AssignmentExpression(assign) => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &assign.left
+                else {
+                    return;
+                };
+                let Some(property) = member
+                    .static_property_name()
+                    .and_then(|name| HTML_PROPERTIES.into_iter().find(|p| *p == name))
+                else {
+                    return;
+                };
+                if !self.is_safe(&assign.right, ctx, &mut vec![]) {
+                    ctx.diagnostic(NoUnsanitizedDiagnostic::Assignment(
+                        property,
+                        assign.right.span(),
+                    ));
+                }
+            }
+            AstKind::CallExpression(call) => {
+                let Some((sink, arguments)) = call_sink(&call.callee, &call.arguments, ctx) else {
+                    return;
+                };
+                self.check_arguments(sink, arguments, ctx);
+            }
+            AstKind::NewExpression(new) => {
+                let Expression::Identifier(ident) = new.callee.without_parenthesized() else {
+                    return;
+                };
+                if ident.name == "Function" && is_global_reference(ident, ctx) {
+                    self.check_arguments("Function", new.arguments.iter().collect(), ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The sink called by `callee` and the arguments of `arguments` which are parsed as HTML or code
+fn call_sink<'a, 'b>(
+    callee: &'b Expression<'a>,
+    arguments: &'b oxc_allocator::Vec<'a, Argument<'a>>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'static str, Vec<&'b Argument<'a>>)> {
+    match callee.without_parenthesized() {
+        Expression::Identifier(ident) if is_global_reference(ident, ctx) => {
+            match ident.name.as_str() {
+                "eval" => Some(("eval", arguments.iter().take(1).collect())),
+                "Function" => Some(("Function", arguments.iter().collect())),
+                // A function callback is not compiled
+                name @ ("setTimeout" | "setInterval") => {
+                    let first = arguments.first().filter(|arg| match arg {
+                        Argument::Expression(expr) => is_string_like(expr),
+                        Argument::SpreadElement(_) => false,
+                    })?;
+                    let name = if name == "setTimeout" { "setTimeout" } else { "setInterval" };
+                    Some((name, vec![first]))
+                }
+                _ => None,
+            }
+        }
+        Expression::MemberExpression(member) => match member.static_property_name()? {
+            "insertAdjacentHTML" => {
+                Some(("insertAdjacentHTML", arguments.iter().skip(1).collect()))
+            }
+            "createContextualFragment" => {
+                Some(("createContextualFragment", arguments.iter().take(1).collect()))
+            }
+            name @ ("write" | "writeln") if is_document(member, ctx) => {
+                let name = if name == "write" { "document.write" } else { "document.writeln" };
+                Some((name, arguments.iter().collect()))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `document.write`, but not `stream.write`
+fn is_document(member: &MemberExpression, ctx: &LintContext) -> bool {
+    matches!(
+        member.object().without_parenthesized(),
+        Expression::Identifier(ident) if ident.name == "document" && is_global_reference(ident, ctx)
+    )
+}
+
+/// Whether `expr` evaluates to a string: a string or template literal,
+/// or a concatenation involving one.
+fn is_string_like(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+            is_string_like(&expr.left) || is_string_like(&expr.right)
+        }
+        _ => false,
+    }
+}
+
+impl NoUnsanitized {
+    fn check_arguments<'a>(
+        &self,
+        sink: &'static str,
+        arguments: Vec<&Argument<'a>>,
+        ctx: &LintContext<'a>,
+    ) {
+        for argument in arguments {
+            let safe = match argument {
+                Argument::Expression(expr) => self.is_safe(expr, ctx, &mut vec![]),
+                Argument::SpreadElement(_) => false,
+            };
+            if !safe {
+                ctx.diagnostic(NoUnsanitizedDiagnostic::Call(sink, argument.span()));
+            }
+        }
+    }
+
+    /// Whether `expr` is built from literals and sanitized values only.
+    ///
+    /// `visiting` holds the variables whose assignments are being checked, a variable which
+    /// is assigned from itself, e.g. `html = html + '<br>'`, is safe when its other
+    /// assignments are.
+    fn is_safe<'a>(
+        &self,
+        expr: &Expression<'a>,
+        ctx: &LintContext<'a>,
+        visiting: &mut Vec<SymbolId>,
+    ) -> bool {
+        match expr.without_parenthesized() {
+            Expression::StringLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            // Numbers and booleans
+            | Expression::UnaryExpression(_)
+            | Expression::UpdateExpression(_) => true,
+            Expression::TemplateLiteral(lit) => {
+                lit.expressions.iter().all(|expr| self.is_safe(expr, ctx, visiting))
+            }
+            Expression::BinaryExpression(expr) => {
+                expr.operator != BinaryOperator::Addition
+                    || (self.is_safe(&expr.left, ctx, visiting)
+                        && self.is_safe(&expr.right, ctx, visiting))
+            }
+            Expression::LogicalExpression(expr) => {
+                self.is_safe(&expr.left, ctx, visiting) && self.is_safe(&expr.right, ctx, visiting)
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.is_safe(&expr.consequent, ctx, visiting)
+                    && self.is_safe(&expr.alternate, ctx, visiting)
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.last().is_some_and(|expr| self.is_safe(expr, ctx, visiting))
+            }
+            Expression::CallExpression(call) => self.is_sanitizer(&call.callee),
+            Expression::TaggedTemplateExpression(expr) => self.is_sanitizer(&expr.tag),
+            Expression::Identifier(ident) => {
+                if ident.name == "undefined" && is_global_reference(ident, ctx) {
+                    return true;
+                }
+                let symbols = ctx.semantic().symbols();
+                let Some(symbol_id) = ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id())
+                else {
+                    return false;
+                };
+                self.is_safe_variable(symbol_id, ctx, visiting)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the declaration and every assignment of a variable are safe
+    fn is_safe_variable(
+        &self,
+        symbol_id: SymbolId,
+        ctx: &LintContext,
+        visiting: &mut Vec<SymbolId>,
+    ) -> bool {
+        if visiting.contains(&symbol_id) {
+            return true;
+        }
+        visiting.push(symbol_id);
+        let safe = self.is_safe_declaration(symbol_id, ctx, visiting)
+            && ctx.semantic().symbols().get_resolved_references(symbol_id).all(|reference| {
+                !reference.is_write() || self.is_safe_write(reference.node_id(), ctx, visiting)
+            });
+        visiting.pop();
+        safe
+    }
+
+    fn is_safe_declaration(
+        &self,
+        symbol_id: SymbolId,
+        ctx: &LintContext,
+        visiting: &mut Vec<SymbolId>,
+    ) -> bool {
+        let nodes = ctx.nodes();
+        let declaration = nodes.get_node(ctx.semantic().symbols().get_declaration(symbol_id));
+        // Parameters, imports, functions and classes are unknown values
+        let AstKind::VariableDeclarator(declarator) = declaration.kind() else { return false };
+        if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+            return false;
+        }
+        // `for (const x of values)`
+        let in_for_in_of = nodes.parent_id(declaration.id()).is_some_and(|declaration| {
+            matches!(
+                nodes.parent_kind(declaration),
+                Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+            )
+        });
+        if in_for_in_of {
+            return false;
+        }
+        declarator.init.as_ref().map_or(true, |init| self.is_safe(init, ctx, visiting))
+    }
+
+    /// Whether the value written by the reference at `node_id` is safe
+    fn is_safe_write(
+        &self,
+        node_id: AstNodeId,
+        ctx: &LintContext,
+        visiting: &mut Vec<SymbolId>,
+    ) -> bool {
+        for ancestor in ctx.nodes().ancestors(node_id).skip(1) {
+            match ctx.nodes().kind(ancestor) {
+                AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_) => {}
+                AstKind::AssignmentExpression(assign) => {
+                    return self.is_safe(&assign.right, ctx, visiting);
+                }
+                // `i++`
+                AstKind::UpdateExpression(_) => return true,
+                // Destructuring and `for (x of values)`
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn is_sanitizer(&self, callee: &Expression) -> bool {
+        let name = match callee.without_parenthesized() {
+            Expression::Identifier(ident) => ident.name.to_string(),
+            Expression::MemberExpression(member) => {
+                let Expression::Identifier(object) = member.object().without_parenthesized() else {
+                    return false;
+                };
+                let Some(property) = member.static_property_name() else { return false };
+                format!("{}.{property}", object.name)
+            }
+            _ => return false,
+        };
+        DEFAULT_SANITIZERS.contains(&name.as_str())
+            || self.sanitizers.iter().any(|sanitizer| *sanitizer == name)
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("el.innerHTML = '<p>Hello</p>'", None),
+        ("el.innerHTML = ''", None),
+        ("el.innerHTML = `<p>${1 + 2}</p>`", None),
+        ("el.innerHTML = cond ? '<b>' : '<i>'", None),
+        ("el.innerHTML = DOMPurify.sanitize(html)", None),
+        ("el.innerHTML = '<p>' + DOMPurify.sanitize(html) + '</p>'", None),
+        ("el.textContent = input", None),
+        ("el.innerText = input", None),
+        ("const html = '<p>Hello</p>'; el.innerHTML = html", None),
+        ("let html = '<ul>'; html += '<li>'; html = html + '</ul>'; el.innerHTML = html", None),
+        ("let html; html = `<p>${count++}</p>`; el.outerHTML = html", None),
+        ("el.insertAdjacentHTML('beforeend', '<br>')", None),
+        ("document.write('<p>Hello</p>')", None),
+        ("stream.write(input)", None),
+        ("function f(document) { document.write(input) }", None),
+        ("eval('1 + 1')", None),
+        ("setTimeout(callback, 100)", None),
+        ("setTimeout(() => run(input), 100)", None),
+        ("new Function('a', 'return a')", None),
+        ("el.innerHTML = escapeHtml(input)", Some(json!([{ "sanitizers": ["escapeHtml"] }]))),
+        (
+            "el.innerHTML = sanitizer.clean(input)",
+            Some(json!([{ "sanitizers": ["sanitizer.clean"] }])),
+        ),
+        ("el.innerHTML = html`<p>${input}</p>`", Some(json!([{ "sanitizers": ["html"] }]))),
+    ];
+
+    let fail = vec![
+        ("el.innerHTML = location.hash", None),
+        ("el.innerHTML = input", None),
+        ("el.outerHTML = input", None),
+        ("el.innerHTML += input", None),
+        ("el.innerHTML = `<p>${input}</p>`", None),
+        ("el.innerHTML = '<p>' + input + '</p>'", None),
+        ("el.innerHTML = cond ? input : ''", None),
+        ("el.innerHTML = escapeHtml(input)", None),
+        ("function f(html) { el.innerHTML = html }", None),
+        ("const html = `<p>${input}</p>`; el.innerHTML = html", None),
+        ("let html = ''; html = input; el.innerHTML = html", None),
+        ("let html = ''; html += input; el.innerHTML = html", None),
+        ("for (const html of list) { el.innerHTML = html }", None),
+        ("const { html } = data; el.innerHTML = html", None),
+        ("el.insertAdjacentHTML('beforeend', input)", None),
+        ("range.createContextualFragment(input)", None),
+        ("document.write(input)", None),
+        ("document.writeln('<p>' + input)", None),
+        ("eval(code)", None),
+        ("new Function('a', body)", None),
+        ("Function(body)()", None),
+        ("setTimeout('run(' + input + ')', 100)", None),
+        ("setInterval(`run(${input})`, 100)", None),
+        ("el.innerHTML = DOMPurify.sanitize(html) + input", None),
+        ("el.innerHTML = escapeHtml(input)", Some(json!([{ "sanitizers": ["escape"] }]))),
+    ];
+
+    Tester::new(NoUnsanitized::NAME, pass, fail).test_and_snapshot();
+}
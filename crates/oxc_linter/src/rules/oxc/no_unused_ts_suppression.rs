@@ -0,0 +1,80 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "oxc(no-unused-ts-suppression): `{0}` does not suppress anything, the next line has no code."
+)]
+#[diagnostic(
+    severity(warning),
+    help("Remove the comment, or move it to the line above the code it suppresses.")
+)]
+struct NoUnusedTsSuppressionDiagnostic(&'static str, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedTsSuppression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows `@ts-expect-error` and `@ts-ignore` comments which are not followed by a line
+    /// of code, e.g. because the code was moved or deleted and the comment was left behind.
+    ///
+    /// Without type information this rule can not tell whether the next line still has a type
+    /// error, it only reports suppressions whose next line is blank, a comment or the end of
+    /// the file.
+    ///
+    /// ### Why is this bad?
+    /// TypeScript only suppresses the errors of the line right after the comment. A stale
+    /// suppression suppresses nothing, and suppresses the wrong errors once code is added below.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// // @ts-ignore
+    ///
+    /// const a: string = 1;
+    /// ```
+    NoUnusedTsSuppression,
+    suspicious
+);
+
+impl Rule for NoUnusedTsSuppression {
+    fn run_once(&self, ctx: &LintContext) {
+        for target in ctx.semantic().ts_suppressions() {
+            if target.nodes.is_empty() {
+                ctx.diagnostic(NoUnusedTsSuppressionDiagnostic(
+                    target.suppression.kind.as_str(),
+                    target.suppression.span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("// @ts-ignore\nconst a: string = 1;", None),
+        ("// @ts-expect-error: reason\nfoo(1);", None),
+        ("/* @ts-expect-error */\nfoo(1);", None),
+        ("const a = {\n  // @ts-expect-error\n  b: foo,\n};", None),
+        ("foo();\n// ts-ignore\n", None),
+        ("// @ts-nocheck\n", None),
+    ];
+
+    let fail = vec![
+        ("// @ts-ignore\n\nconst a: string = 1;", None),
+        ("// @ts-ignore\n// @ts-expect-error\nfoo(1);", None),
+        ("foo();\n// @ts-expect-error", None),
+        ("if (a) {\n  foo();\n  // @ts-expect-error\n}", None),
+    ];
+
+    Tester::new(NoUnusedTsSuppression::NAME, pass, fail).test_and_snapshot();
+}
@@ -29,6 +29,7 @@ declare_oxc_lint!(
     /// ```
     ArrayType,
     style,
+    fix
 );
 
 #[derive(Debug, Diagnostic, Error)]
@@ -169,10 +170,7 @@ fn check(
 fn type_needs_parentheses(type_param: &TSType) -> bool {
     match type_param {
         TSType::TSTypeReference(node) => {
-            if let TSTypeName::IdentifierReference(identifier_reference) = &node.type_name {
-                return identifier_reference.name.as_str() == "ReadonlyArray";
-            }
-            true
+            node.type_name.is_qualified_name() || node.is_reference_to("ReadonlyArray")
         }
         TSType::TSUnionType(_)
         | TSType::TSFunctionType(_)
@@ -238,25 +236,19 @@ fn check_and_report_error_array(
     ts_type_reference: &TSTypeReference,
     ctx: &LintContext,
 ) {
-    let TSTypeName::IdentifierReference(ident_ref_type_name) = &ts_type_reference.type_name else {
-        return;
-    };
-
-    if ident_ref_type_name.name.as_str() != "ReadonlyArray"
-        && ident_ref_type_name.name.as_str() != "Array"
-    {
+    let is_readonly_array_type = ts_type_reference.is_reference_to("ReadonlyArray");
+    if !is_readonly_array_type && !ts_type_reference.is_reference_to("Array") {
         return;
     }
-    let is_readonly_array_type = ident_ref_type_name.name == "ReadonlyArray";
     let config = if is_readonly_array_type { readonly_config } else { default_config };
     if matches!(config, ArrayOption::Generic) {
         return;
     }
     let readonly_prefix: &str = if is_readonly_array_type { "readonly " } else { "" };
     let class_name = if is_readonly_array_type { "ReadonlyArray" } else { "Array" };
-    let type_params = &ts_type_reference.type_parameters;
+    let type_arguments = ts_type_reference.type_arguments();
 
-    if type_params.is_none() || type_params.as_ref().unwrap().params.len() == 0 {
+    if type_arguments.is_empty() {
         let diagnostic = match config {
             ArrayOption::Array => ArrayTypeDiagnostic::Array(
                 readonly_prefix.to_string(),
@@ -266,7 +258,7 @@ fn check_and_report_error_array(
             ),
             _ => ArrayTypeDiagnostic::ArraySimple(
                 readonly_prefix.to_string(),
-                ident_ref_type_name.name.to_string(),
+                class_name.to_string(),
                 "any".to_string(),
                 ts_type_reference.span,
             ),
@@ -276,10 +268,9 @@ fn check_and_report_error_array(
         });
         return;
     }
-    if type_params.as_ref().unwrap().params.len() != 1 {
+    let [first_type_param] = type_arguments else {
         return;
-    }
-    let first_type_param = type_params.as_ref().unwrap().params.first().unwrap();
+    };
     if matches!(config, ArrayOption::ArraySimple) && !is_simple_type(first_type_param) {
         return;
     }
@@ -318,7 +309,7 @@ fn check_and_report_error_array(
         ),
         _ => ArrayTypeDiagnostic::ArraySimple(
             readonly_prefix.to_string(),
-            ident_ref_type_name.name.to_string(),
+            class_name.to_string(),
             message_type.to_string(),
             ts_type_reference.span,
         ),
@@ -0,0 +1,138 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-duplicate-type-constituents): {0} type constituent is duplicated with {1}.")]
+#[diagnostic(severity(warning), help("Remove the duplicate constituent"))]
+struct NoDuplicateTypeConstituentsDiagnostic(&'static str, String, #[label] Span, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateTypeConstituents {
+    ignore_intersections: bool,
+    ignore_unions: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow duplicate constituents of union or intersection types.
+    ///
+    /// Constituents are compared by their source text, types which are the same
+    /// only after resolving aliases are not reported.
+    ///
+    /// ### Why is this bad?
+    /// A union or an intersection is the same without its duplicate constituents,
+    /// they are usually left over after a refactoring or are a typo of another type.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// type T1 = 'A' | 'A';
+    /// type T2 = A | A | B;
+    /// type T3 = { a: string } & { a: string };
+    /// ```
+    NoDuplicateTypeConstituents,
+    suspicious,
+    fix
+);
+
+impl Rule for NoDuplicateTypeConstituents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            ignore_intersections: config
+                .and_then(|config| config.get("ignoreIntersections"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignore_unions: config
+                .and_then(|config| config.get("ignoreUnions"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (kind, operator, types) = match node.kind() {
+            AstKind::TSUnionType(ty) if !self.ignore_unions => ("Union", "|", &ty.types),
+            AstKind::TSIntersectionType(ty) if !self.ignore_intersections => {
+                ("Intersection", "&", &ty.types)
+            }
+            _ => return,
+        };
+        let source_text = ctx.source_text();
+        for (i, ty) in types.iter().enumerate().skip(1) {
+            let text = ty.span().source_text(source_text);
+            let Some(previous) = types
+                .iter()
+                .take(i)
+                .find(|previous| previous.span().source_text(source_text) == text)
+            else {
+                continue;
+            };
+            let diagnostic = NoDuplicateTypeConstituentsDiagnostic(
+                kind,
+                text.to_string(),
+                previous.span(),
+                ty.span(),
+            );
+            // Remove the operator before the duplicate, unless there are parentheses or comments
+            let removed = Span::new(types[i - 1].span().end, ty.span().end);
+            let between = &source_text[removed.start as usize..ty.span().start as usize];
+            if between.trim() == operator {
+                ctx.diagnostic_with_fix(diagnostic, || Fix::delete(removed));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("type T = 1 | 2;", None),
+        ("type T = 'A' | 'B';", None),
+        ("type T = A | B;", None),
+        ("type T = A & B;", None),
+        ("type T = { a: string } | { b: string };", None),
+        ("type T = Array<A> | Array<B>;", None),
+        ("type T = (A | B) | (B | C);", None),
+        ("type T = A | B[] | B;", None),
+        ("let a: string | number;", None),
+        ("type T = A | A;", Some(json!([{ "ignoreUnions": true }]))),
+        ("type T = A & A;", Some(json!([{ "ignoreIntersections": true }]))),
+    ];
+
+    let fail = vec![
+        ("type T = 1 | 1;", None),
+        ("type T = 'A' | 'A';", None),
+        ("type T = A | A;", None),
+        ("type T = A | B | A;", None),
+        ("type T = A | A | A;", None),
+        ("type T = A & A;", None),
+        ("type T = { a: string } & { a: string };", None),
+        ("type T = Array<A> | Array<A>;", None),
+        ("type T = A | (A);", None),
+        ("let a: string | number | string;", None),
+        ("function f(a: string | string) {}", None),
+        ("type T = A & A;", Some(json!([{ "ignoreUnions": true }]))),
+    ];
+
+    let fix = vec![
+        ("type T = A | A;", "type T = A;", None),
+        ("type T = A | B | A;", "type T = A | B;", None),
+        ("type T = A & A & B;", "type T = A & B;", None),
+        ("type T =\n  | A\n  | A;", "type T =\n  | A;", None),
+        ("type T = A | (A);", "type T = A | (A);", None),
+    ];
+
+    Tester::new(NoDuplicateTypeConstituents::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,216 @@
+use oxc_ast::{
+    ast::{Expression, TSLiteral, TSType},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum NoRedundantTypeConstituentsDiagnostic {
+    #[error("typescript-eslint(no-redundant-type-constituents): '{0}' overrides all other types in this {1} type.")]
+    #[diagnostic(severity(warning))]
+    Overrides(&'static str, &'static str, #[label] Span),
+
+    #[error("typescript-eslint(no-redundant-type-constituents): '{0}' is overridden by other types in this {1} type.")]
+    #[diagnostic(severity(warning))]
+    Overridden(&'static str, &'static str, #[label] Span),
+
+    #[error("typescript-eslint(no-redundant-type-constituents): {0} is overridden by {1} in this union type.")]
+    #[diagnostic(severity(warning))]
+    LiteralOverridden(String, &'static str, #[label] Span),
+
+    #[error("typescript-eslint(no-redundant-type-constituents): {0} is overridden by the {1} in this intersection type.")]
+    #[diagnostic(severity(warning))]
+    PrimitiveOverridden(&'static str, String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRedundantTypeConstituents;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow members of unions and intersections that do nothing or override type information.
+    ///
+    /// Only the keyword and literal types written in the union or intersection are checked,
+    /// type aliases are not resolved.
+    ///
+    /// ### Why is this bad?
+    /// `any` and `unknown` in a union and `any` and `never` in an intersection swallow the other
+    /// types, while `never` in a union and `unknown` in an intersection do nothing. A literal type
+    /// in a union with its primitive type, or a primitive type in an intersection with one of its
+    /// literal types, is redundant.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// type T1 = string | any;
+    /// type T2 = number | never;
+    /// type T3 = 'a' | string;
+    /// type T4 = string & 'a';
+    /// type T5 = unknown & { a: string };
+    /// ```
+    NoRedundantTypeConstituents,
+    suspicious
+);
+
+impl Rule for NoRedundantTypeConstituents {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSUnionType(ty) => check_union(&ty.types, ctx),
+            AstKind::TSIntersectionType(ty) => check_intersection(&ty.types, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn check_union(types: &[TSType], ctx: &LintContext) {
+    let mut overridden = false;
+    for ty in types {
+        match ty {
+            TSType::TSAnyKeyword(_) | TSType::TSUnknownKeyword(_) => {
+                let name = if matches!(ty, TSType::TSAnyKeyword(_)) { "any" } else { "unknown" };
+                ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::Overrides(
+                    name,
+                    "union",
+                    ty.span(),
+                ));
+                overridden = true;
+            }
+            TSType::TSNeverKeyword(_) => {
+                ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::Overridden(
+                    "never",
+                    "union",
+                    ty.span(),
+                ));
+            }
+            _ => {}
+        }
+    }
+    if overridden {
+        return;
+    }
+    for ty in types {
+        let Some(primitive) = literal_primitive(ty) else { continue };
+        if types.iter().any(|other| keyword_primitive(other) == Some(primitive)) {
+            ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::LiteralOverridden(
+                ty.span().source_text(ctx.source_text()).to_string(),
+                primitive,
+                ty.span(),
+            ));
+        }
+    }
+}
+
+fn check_intersection(types: &[TSType], ctx: &LintContext) {
+    for ty in types {
+        let name = match ty {
+            TSType::TSAnyKeyword(_) => "any",
+            TSType::TSNeverKeyword(_) => "never",
+            _ => continue,
+        };
+        ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::Overrides(
+            name,
+            "intersection",
+            ty.span(),
+        ));
+        return;
+    }
+    for ty in types {
+        if matches!(ty, TSType::TSUnknownKeyword(_)) {
+            ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::Overridden(
+                "unknown",
+                "intersection",
+                ty.span(),
+            ));
+            continue;
+        }
+        let Some(primitive) = keyword_primitive(ty) else { continue };
+        if let Some(literal) =
+            types.iter().find(|other| literal_primitive(other) == Some(primitive))
+        {
+            ctx.diagnostic(NoRedundantTypeConstituentsDiagnostic::PrimitiveOverridden(
+                primitive,
+                literal.span().source_text(ctx.source_text()).to_string(),
+                ty.span(),
+            ));
+        }
+    }
+}
+
+/// The primitive type of a primitive keyword type, e.g. `string`
+fn keyword_primitive(ty: &TSType) -> Option<&'static str> {
+    match ty {
+        TSType::TSBigIntKeyword(_) => Some("bigint"),
+        TSType::TSBooleanKeyword(_) => Some("boolean"),
+        TSType::TSNumberKeyword(_) => Some("number"),
+        TSType::TSStringKeyword(_) => Some("string"),
+        _ => None,
+    }
+}
+
+/// The primitive type of a literal type, e.g. `string` for `'a'`
+fn literal_primitive(ty: &TSType) -> Option<&'static str> {
+    match ty {
+        TSType::TSTemplateLiteralType(_) => Some("string"),
+        TSType::TSLiteralType(ty) => match &ty.literal {
+            TSLiteral::BigintLiteral(_) => Some("bigint"),
+            TSLiteral::BooleanLiteral(_) => Some("boolean"),
+            TSLiteral::NumericLiteral(_) => Some("number"),
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => Some("string"),
+            TSLiteral::UnaryExpression(expr) => match &expr.argument {
+                Expression::BigintLiteral(_) => Some("bigint"),
+                Expression::NumericLiteral(_) => Some("number"),
+                _ => None,
+            },
+            TSLiteral::NullLiteral(_) | TSLiteral::RegExpLiteral(_) => None,
+        },
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "type T = string | number;",
+        "type T = 'a' | 'b';",
+        "type T = 'a' | number;",
+        "type T = 1n | number;",
+        "type T = string & number;",
+        "type T = { a: string } & { b: string };",
+        "type T = 'a' & 'b';",
+        "type T = string & {};",
+        "type T = null | undefined;",
+        "type T = any;",
+        "type T = (string | number)[] | string;",
+        "let a: string | undefined;",
+        "function f(a: unknown) {}",
+    ];
+
+    let fail = vec![
+        "type T = string | any;",
+        "type T = unknown | number;",
+        "type T = string | never;",
+        "type T = never | any;",
+        "type T = 'a' | string;",
+        "type T = `a` | string;",
+        "type T = 1 | -1 | number;",
+        "type T = 1n | bigint;",
+        "type T = true | boolean;",
+        "type T = string & any;",
+        "type T = never & string;",
+        "type T = unknown & { a: string };",
+        "type T = string & 'a';",
+        "type T = number & 1 & 2;",
+        "let a: 'a' | 'b' | string;",
+        "function f(a: boolean | false) {}",
+    ];
+
+    Tester::new(NoRedundantTypeConstituents::NAME, pass, fail).test_and_snapshot();
+}
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoConsoleSpaces,
-    style
+    style,
+    dangerous_fix
 );
 
 impl Rule for NoConsoleSpaces {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    pedantic
+    pedantic,
+    dangerous_fix
 );
 
 impl Rule for NoInstanceofArray {
@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// const foo = i > 5 ? (i < 100 ? true : false) : (i < 100 ? true : false);
     /// ```
     NoNestedTernary,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoNestedTernary {
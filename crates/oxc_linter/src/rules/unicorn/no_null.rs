@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// let foo
     /// ```
     NoNull,
-    style
+    style,
+    dangerous_fix
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// await await promise;
     /// ```
     NoUnnecessaryAwait,
-    correctness
+    correctness,
+    dangerous_fix
 );
 
 impl Rule for NoUnnecessaryAwait {
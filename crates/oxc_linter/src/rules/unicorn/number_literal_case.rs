@@ -64,7 +64,8 @@ declare_oxc_lint!(
     /// const foo = 2e+5;
     /// ```
     NumberLiteralCase,
-    style
+    style,
+    fix
 );
 
 impl Rule for NumberLiteralCase {
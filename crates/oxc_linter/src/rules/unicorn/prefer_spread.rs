@@ -39,7 +39,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSpread,
-    style
+    style,
+    dangerous_fix
 );
 
 impl Rule for PreferSpread {
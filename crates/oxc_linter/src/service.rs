@@ -2,9 +2,9 @@ use std::{
     collections::HashMap,
     ffi::OsStr,
     fs,
-    path::Path,
+    path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{mpsc, Arc, Condvar, Mutex},
 };
 
 use dashmap::DashMap;
@@ -13,14 +13,20 @@ use rustc_hash::FxHashSet;
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{Atom, SourceType, VALID_EXTENSIONS};
 
 use crate::{
+    config::ImportMapResolution,
+    fix_suggestions::FixSuggestion,
+    module_cycles::{find_cycles, ModuleCycle},
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    resolve_cache::ResolveCache,
+    result_cache::{CacheBackend, ResultCache},
+    unused_exports::{ExportUsage, UnusedExport},
+    Fixer, LintContext, Linter, Message, PackageTypeCache, Tsconfig, TsconfigCache,
 };
 
 #[derive(Clone)]
@@ -40,6 +46,35 @@ impl LintService {
         Self { runtime }
     }
 
+    /// Derive the parse options of each file with `provider` instead of the file extension alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the service has been cloned.
+    #[must_use]
+    pub fn with_parse_options_provider(mut self, provider: Box<dyn ParseOptionsProvider>) -> Self {
+        Arc::get_mut(&mut self.runtime)
+            .expect("the parse options provider must be set before cloning the service")
+            .parse_options_provider = Some(provider);
+        self
+    }
+
+    /// Reuse the results of files linted before with the same configuration from `backend`,
+    /// and store the results of all other files in it.
+    ///
+    /// Results are not cached with the import plugin or when fixing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the service has been cloned.
+    #[must_use]
+    pub fn with_result_cache(mut self, backend: Box<dyn CacheBackend>) -> Self {
+        let runtime = Arc::get_mut(&mut self.runtime)
+            .expect("the result cache must be set before cloning the service");
+        runtime.result_cache = Some(ResultCache::new(backend, &runtime.linter));
+        self
+    }
+
     pub fn linter(&self) -> &Linter {
         &self.runtime.linter
     }
@@ -55,9 +90,95 @@ impl LintService {
             .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        if let Some(cache_path) = &self.linter().options().resolve_cache_path {
+            // The cache only speeds up the next run, failing to write it does not fail this one.
+            let _ = self.runtime.resolve_cache.save(cache_path);
+        }
         tx_error.send(None).unwrap();
     }
 
+    /// Exports of the linted files which are not imported by any module, sorted by path.
+    ///
+    /// Exports of files matched by `is_public`, such as entry points and the public API of a
+    /// package, are never reported.
+    /// Imports are only known with the import plugin enabled, after [`LintService::run`].
+    /// Modules loaded with `import()` or `require()` are not taken into account.
+    pub fn unused_exports<F: Fn(&Path) -> bool>(&self, is_public: F) -> Vec<UnusedExport> {
+        let mut usage = ExportUsage::default();
+        let mut modules = vec![];
+        for entry in &self.runtime.module_map {
+            let ModuleState::Resolved(module) = entry.value() else { continue };
+            usage.add_module(module);
+            if self.runtime.paths.contains(entry.key()) && !is_public(entry.key()) {
+                modules.push((entry.key().clone(), Arc::clone(module)));
+            }
+        }
+        modules.sort_by(|(a, _), (b, _)| a.cmp(b));
+        modules.iter().flat_map(|(path, module)| usage.unused_exports(path, module)).collect()
+    }
+
+    /// Fixes of the diagnostics of the linted files, sorted by path and position.
+    ///
+    /// Fixes are only collected with `LintOptions::fix_suggestions`, after [`LintService::run`].
+    ///
+    /// # Panics
+    /// Panics if a thread panicked while adding the fixes of a file.
+    pub fn fix_suggestions(&self) -> Vec<FixSuggestion> {
+        let mut fix_suggestions = self.runtime.fix_suggestions.lock().unwrap().clone();
+        fix_suggestions.sort_by(|a, b| a.path.cmp(&b.path).then(a.span.start.cmp(&b.span.start)));
+        fix_suggestions
+    }
+
+    /// Circular dependencies between the linted files, with one concrete cycle each.
+    ///
+    /// Imports are only known with the import plugin enabled, after [`LintService::run`].
+    pub fn module_cycles(&self) -> Vec<ModuleCycle> {
+        let modules = self
+            .runtime
+            .module_map
+            .iter()
+            .filter(|entry| self.runtime.paths.contains(entry.key()))
+            .filter_map(|entry| match entry.value() {
+                ModuleState::Resolved(module) => Some((entry.key().clone(), Arc::clone(module))),
+                ModuleState::Ignored => None,
+            })
+            .collect::<Vec<_>>();
+        find_cycles(&modules)
+    }
+
+    /// The module record of the file at `path`, which is either linted or a dependency of one.
+    ///
+    /// Records are only kept with the import plugin enabled, after [`LintService::run`].
+    /// Dependencies are keyed by their resolved path.
+    pub fn module_record(&self, path: &Path) -> Option<Arc<ModuleRecord>> {
+        match self.runtime.module_map.get(path)?.value() {
+            ModuleState::Resolved(module) => Some(Arc::clone(module)),
+            ModuleState::Ignored => None,
+        }
+    }
+
+    /// Lint the source text of the file at `path` on its own, with the parse options and the
+    /// tsconfig of the file, e.g. for a daemon linting the files of its requests.
+    /// The import plugin must be disabled, imported files are not linted.
+    ///
+    /// Diagnostics are relative to `source_text`, not to the file of a partially loaded source.
+    pub fn lint_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Vec<Error> {
+        debug_assert!(!self.runtime.linter.options().import_plugin);
+        let allocator = Allocator::default();
+        // Only used to lint imported files
+        let (tx_error, _rx_error) = mpsc::channel();
+        self.runtime
+            .process_source(path, &allocator, source_text, source_type, true, &tx_error)
+            .into_iter()
+            .map(|message| message.error)
+            .collect()
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
@@ -86,6 +207,14 @@ impl LintService {
     }
 }
 
+/// Per-file parse options, e.g. derived from the tsconfig or Babel config governing each file
+/// in a monorepo where packages are compiled differently.
+pub trait ParseOptionsProvider: Send + Sync {
+    /// Adjust `source_type` (detected from the file extension and content) and `options`
+    /// for the file at `path`.
+    fn provide(&self, path: &Path, source_type: &mut SourceType, options: &mut ParseOptions);
+}
+
 /// `CacheState` and `CacheStateEntry` are used to fix the problem where
 /// there is a brief moment when a concurrent fetch can miss the cache.
 ///
@@ -118,20 +247,37 @@ pub struct Runtime {
     paths: FxHashSet<Box<Path>>,
     linter: Linter,
     resolver: Option<Resolver>,
+    resolve_cache: ResolveCache,
+    result_cache: Option<ResultCache>,
+    tsconfigs: TsconfigCache,
+    package_types: PackageTypeCache,
+    parse_options_provider: Option<Box<dyn ParseOptionsProvider>>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    fix_suggestions: Mutex<Vec<FixSuggestion>>,
 }
 
 impl Runtime {
     fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
         let resolver = linter.options().import_plugin.then(Self::get_resolver);
+        let resolve_cache = linter
+            .options()
+            .resolve_cache_path
+            .as_deref()
+            .map_or_else(ResolveCache::default, ResolveCache::load);
         Self {
             cwd,
             paths: paths.iter().cloned().collect(),
             linter,
             resolver,
+            resolve_cache,
+            result_cache: None,
+            tsconfigs: TsconfigCache::default(),
+            package_types: PackageTypeCache::default(),
+            parse_options_provider: None,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            fix_suggestions: Mutex::default(),
         }
     }
 
@@ -150,20 +296,25 @@ impl Runtime {
     }
 
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
-        let source_type = SourceType::from_path(path);
         let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext));
+            SourceType::from_path(path).is_err() && !LINT_PARTIAL_LOADER_EXT.contains(&ext);
         if not_supported_yet {
             return None;
         }
-        let source_type = source_type.unwrap_or_default();
         let file_result = fs::read_to_string(path)
             .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)));
         Some(match file_result {
-            Ok(source_text) => Ok((source_type, source_text)),
+            Ok(source_text) => {
+                // CommonJS `.js` files are parsed as scripts
+                let source_type =
+                    SourceType::detect(&source_text, path, || self.package_types.is_module(path))
+                        .unwrap_or_default();
+                Ok((source_type, source_text))
+            }
             Err(e) => Err(e),
         })
     }
@@ -178,7 +329,7 @@ impl Runtime {
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -202,40 +353,118 @@ impl Runtime {
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+        let relative_path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let options = self.linter.options();
+        let result_cache = self
+            .result_cache
+            .as_ref()
+            .filter(|_| !options.import_plugin && !options.fix && !options.fix_suggestions)
+            .map(|cache| {
+                let options = self.result_cache_options(path, source_type);
+                (cache, cache.key(relative_path, &source_text, &options))
+            });
+        let cached_results = result_cache
+            .as_ref()
+            .and_then(|(cache, key)| cache.get(key))
+            .filter(|results| results.len() == sources.len());
+
+        let source_texts = sources.iter().map(|source| source.source_text).collect::<Vec<_>>();
+        let results = if let Some(cached_results) = cached_results {
+            cached_results
+        } else {
+            let results = sources
+                .into_iter()
+                .map(|JavaScriptSource { source_text, source_type, start }| {
+                    // The reserved capacity, rounded up by the arena, counts towards the limit
+                    let memory_limit = self.linter.options().memory_limit;
+                    let capacity = Allocator::capacity_for_source_len(source_text.len());
+                    let mut allocator = Allocator::with_capacity(
+                        memory_limit.map_or(capacity, |limit| capacity.min(limit / 2)),
+                    );
+                    allocator.set_memory_limit(memory_limit);
+                    let mut messages = self.process_source(
+                        path,
+                        &allocator,
+                        source_text,
+                        source_type,
+                        true,
+                        tx_error,
+                    );
+
+                    if self.linter.options().fix_suggestions {
+                        let fix_suggestions = FixSuggestion::from_messages(path, &messages, start);
+                        self.fix_suggestions.lock().unwrap().extend(fix_suggestions);
+                    }
+
+                    // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
+                    if !is_processed_by_partial_loader && self.linter.options().fix {
+                        let fix_result = Fixer::new(source_text, messages).fix();
+                        fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+                        messages = fix_result.messages;
+                    }
 
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+                    messages.into_iter().map(|m| m.error).collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            if let Some((cache, key)) = &result_cache {
+                cache.put(key, &results);
             }
+            results
+        };
 
-            if !messages.is_empty() {
-                let errors = messages.into_iter().map(|m| m.error).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+        for (source_text, errors) in source_texts.into_iter().zip(results) {
+            if !errors.is_empty() {
+                let diagnostics =
+                    DiagnosticService::wrap_diagnostics(relative_path, source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
         }
     }
 
+    /// The parse options of the file at `path`,
+    /// `source_type` is detected from its extension and content.
+    fn parse_options(&self, path: &Path, source_type: &mut SourceType) -> ParseOptions {
+        let mut parse_options =
+            ParseOptions { allow_return_outside_function: true, ..ParseOptions::default() };
+        if let Some(provider) = &self.parse_options_provider {
+            provider.provide(path, source_type, &mut parse_options);
+        }
+        parse_options
+    }
+
+    /// Inputs of linting the file at `path` other than its path and source text,
+    /// which are part of the key of its cached results.
+    fn result_cache_options(&self, path: &Path, mut source_type: SourceType) -> String {
+        let parse_options = self.parse_options(path, &mut source_type);
+        // Only the options read by lint rules, the paths differ between machines.
+        let compiler_options = self.tsconfigs.find(&self.cwd.join(path)).map(|tsconfig| {
+            let options = tsconfig.compiler_options();
+            (
+                options.jsx,
+                options.jsx_factory.clone(),
+                options.jsx_fragment_factory.clone(),
+                options.jsx_import_source.clone(),
+                options.experimental_decorators,
+                options.emit_decorator_metadata,
+                options.target.clone(),
+            )
+        });
+        format!("{source_type:?}\n{parse_options:?}\n{compiler_options:?}")
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn process_source<'a>(
         &self,
         path: &Path,
         allocator: &'a Allocator,
         source_text: &'a str,
-        source_type: SourceType,
+        mut source_type: SourceType,
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
+        let parse_options = self.parse_options(path, &mut source_type);
+        let ret =
+            Parser::new(allocator, source_text, source_type).with_options(parse_options).parse();
 
         if !ret.errors.is_empty() {
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -250,6 +479,7 @@ impl Runtime {
             .with_check_syntax_error(check_syntax_errors)
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
+        let tsconfig = self.tsconfigs.find(&self.cwd.join(path));
 
         if self.linter.options().import_plugin {
             self.module_map.insert(
@@ -259,17 +489,17 @@ impl Runtime {
             self.update_cache_state(path);
 
             // Retrieve all dependency modules from this module.
-            let dir = path.parent().unwrap();
             module_record
                 .requested_modules
                 .keys()
                 .par_bridge()
                 .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    self.resolve(resolver, path, specifier, &module_record, tsconfig.as_deref())
+                        .map(|r| (specifier, r))
                 })
                 .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
+                .for_each_with(tx_error, |tx_error, (specifier, path)| {
+                    let path = path.as_path();
                     self.process_path(path, tx_error);
                     if let Some(target_module_record_ref) = self.module_map.get(path) {
                         if let ModuleState::Resolved(target_module_record) =
@@ -295,10 +525,54 @@ impl Runtime {
         };
 
         let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic))
+                .with_tsconfig(tsconfig);
         self.linter.run(lint_ctx)
     }
 
+    /// Resolve `specifier` requested by the module at `path`.
+    ///
+    /// The import map and core modules from the settings take precedence over the resolver,
+    /// followed by the `paths` of the tsconfig of the module.
+    /// Modules outside of the file system are recorded in `module_record` and return `None`.
+    fn resolve(
+        &self,
+        resolver: &Resolver,
+        path: &Path,
+        specifier: &Atom,
+        module_record: &ModuleRecord,
+        tsconfig: Option<&Tsconfig>,
+    ) -> Option<PathBuf> {
+        let settings = &self.linter.settings().import;
+        let referrer = path.strip_prefix(&self.cwd).unwrap_or(path);
+        match settings.resolve_import_map(specifier, referrer) {
+            Some(ImportMapResolution::Path(target)) => {
+                return self.resolve_cache.resolve(resolver, &self.cwd, &target);
+            }
+            Some(ImportMapResolution::External) => {
+                module_record.external_modules.insert(specifier.clone());
+                return None;
+            }
+            None => {}
+        }
+        if settings.is_core_module(specifier) {
+            module_record.external_modules.insert(specifier.clone());
+            return None;
+        }
+        if let Some(tsconfig) = tsconfig {
+            let resolution = tsconfig
+                .compiler_options()
+                .resolve_paths(specifier)
+                .iter()
+                .filter_map(|candidate| candidate.to_str())
+                .find_map(|candidate| self.resolve_cache.resolve(resolver, &self.cwd, candidate));
+            if resolution.is_some() {
+                return resolution;
+            }
+        }
+        self.resolve_cache.resolve(resolver, path.parent().unwrap(), specifier)
+    }
+
     fn init_cache_state(&self, path: &Path) -> bool {
         if !self.linter.options().import_plugin {
             return false;
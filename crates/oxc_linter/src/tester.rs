@@ -191,6 +191,7 @@ impl Tester {
             .map_or_else(ESLintSettings::default, |v| ESLintSettings::deserialize(v).unwrap());
         let options = LintOptions::default()
             .with_fix(is_fix)
+            .with_fix_dangerously(is_fix)
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
@@ -0,0 +1,439 @@
+//! Discovery of the tsconfig governing each linted file.
+//!
+//! A monorepo usually has many tsconfig files, often a solution style root config with
+//! `references` to one config per package, each `extends`-ing a shared base config.
+//! Like `tsserver`, the nearest `tsconfig.json` is looked up for a file,
+//! the first project of it or its `references` which includes the file wins,
+//! and ancestor directories are searched when none does.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use serde::Deserialize;
+
+const TSCONFIG: &str = "tsconfig.json";
+
+/// Directories excluded when `exclude` is not specified.
+const DEFAULT_EXCLUDE: [&str; 3] = ["node_modules", "bower_components", "jspm_packages"];
+
+/// Compiler options relevant to parsing and linting.
+///
+/// Paths are absolute, options missing in a config are inherited from the config it `extends`.
+///
+/// <https://www.typescriptlang.org/tsconfig#compilerOptions>
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    pub jsx: Option<JsxMode>,
+    pub jsx_factory: Option<String>,
+    pub jsx_fragment_factory: Option<String>,
+    pub jsx_import_source: Option<String>,
+    pub experimental_decorators: Option<bool>,
+    pub emit_decorator_metadata: Option<bool>,
+    pub target: Option<String>,
+    pub base_url: Option<PathBuf>,
+    pub paths: Option<BTreeMap<String, Vec<String>>>,
+    /// Directory the targets of `paths` are relative to,
+    /// `baseUrl` if specified, otherwise the directory of the config declaring `paths`.
+    #[serde(skip)]
+    pub paths_base: Option<PathBuf>,
+}
+
+/// <https://www.typescriptlang.org/tsconfig#jsx>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JsxMode {
+    Preserve,
+    React,
+    ReactJsx,
+    ReactJsxdev,
+    ReactNative,
+}
+
+#[derive(Debug)]
+pub struct Tsconfig {
+    path: PathBuf,
+    compiler_options: CompilerOptions,
+    /// Absolute paths of `files`
+    files: Option<Vec<PathBuf>>,
+    /// Absolute glob patterns of `include`
+    include: Option<Vec<PathBuf>>,
+    /// Absolute glob patterns of `exclude`
+    exclude: Option<Vec<PathBuf>>,
+    /// Paths of referenced tsconfig files
+    references: Vec<PathBuf>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RawTsconfig {
+    #[serde(default)]
+    extends: Extends,
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+    files: Option<Vec<String>>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    #[serde(default)]
+    references: Vec<Reference>,
+}
+
+/// `extends` is a string, or an array of strings since TypeScript 5.0
+#[derive(Deserialize, Default)]
+#[serde(untagged)]
+enum Extends {
+    #[default]
+    None,
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Deserialize)]
+struct Reference {
+    path: String,
+}
+
+impl Tsconfig {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn compiler_options(&self) -> &CompilerOptions {
+        &self.compiler_options
+    }
+
+    pub fn references(&self) -> &[PathBuf] {
+        &self.references
+    }
+
+    fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or(Path::new(""))
+    }
+
+    /// Whether the project of this config contains `path`.
+    pub fn includes(&self, path: &Path) -> bool {
+        if self.files.as_ref().is_some_and(|files| files.iter().any(|file| file == path)) {
+            return true;
+        }
+        let included = match &self.include {
+            Some(include) => include.iter().any(|pattern| matches_include(pattern, path)),
+            // Everything is included when neither `files` nor `include` is specified
+            None => self.files.is_none() && path.starts_with(self.dir()),
+        };
+        included
+            && match &self.exclude {
+                Some(exclude) => !exclude.iter().any(|pattern| matches_exclude(pattern, path)),
+                None => {
+                    !DEFAULT_EXCLUDE.iter().any(|dir| matches_exclude(&self.dir().join(dir), path))
+                }
+            }
+    }
+}
+
+impl CompilerOptions {
+    /// Fill the options missing from `self` with the ones of `base`.
+    fn inherit(&mut self, base: &Self) {
+        self.jsx = self.jsx.or(base.jsx);
+        self.jsx_factory = self.jsx_factory.take().or_else(|| base.jsx_factory.clone());
+        self.jsx_fragment_factory =
+            self.jsx_fragment_factory.take().or_else(|| base.jsx_fragment_factory.clone());
+        self.jsx_import_source =
+            self.jsx_import_source.take().or_else(|| base.jsx_import_source.clone());
+        self.experimental_decorators =
+            self.experimental_decorators.or(base.experimental_decorators);
+        self.emit_decorator_metadata =
+            self.emit_decorator_metadata.or(base.emit_decorator_metadata);
+        self.target = self.target.take().or_else(|| base.target.clone());
+        self.base_url = self.base_url.take().or_else(|| base.base_url.clone());
+        if self.paths.is_none() {
+            self.paths = base.paths.clone();
+            self.paths_base = base.paths_base.clone();
+        }
+    }
+
+    /// Candidate paths for `specifier` mapped by `paths`.
+    ///
+    /// An exact key wins, otherwise the pattern with the longest prefix before its `*`.
+    pub fn resolve_paths(&self, specifier: &str) -> Vec<PathBuf> {
+        let (Some(paths), Some(base)) = (&self.paths, &self.paths_base) else {
+            return vec![];
+        };
+        if let Some(targets) = paths.get(specifier) {
+            return targets.iter().map(|target| normalize(&base.join(target))).collect();
+        }
+        paths
+            .iter()
+            .filter_map(|(key, targets)| {
+                let (prefix, suffix) = key.split_once('*')?;
+                let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((prefix.len(), targets, matched))
+            })
+            .max_by_key(|(len, _, _)| *len)
+            .map(|(_, targets, matched)| {
+                targets
+                    .iter()
+                    .map(|target| normalize(&base.join(target.replacen('*', matched, 1))))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Cache of the tsconfig files of a lint run, shared by all threads.
+#[derive(Default)]
+pub struct TsconfigCache {
+    /// tsconfig path -> config with `extends` applied, `None` if it cannot be read
+    configs: DashMap<Box<Path>, Option<Arc<Tsconfig>>>,
+    /// directory -> nearest `tsconfig.json` in it or its ancestors
+    nearest: DashMap<Box<Path>, Option<Box<Path>>>,
+}
+
+impl TsconfigCache {
+    /// The tsconfig of the project containing the file at the absolute `path`.
+    pub fn find(&self, path: &Path) -> Option<Arc<Tsconfig>> {
+        let path = normalize(path);
+        let mut dir = path.parent().map(Path::to_path_buf);
+        while let Some(current) = dir {
+            let config_path = self.nearest_tsconfig(&current)?;
+            if let Some(config) = self.load(&config_path) {
+                if let Some(project) = self.project_of(config, &path, &mut vec![]) {
+                    return Some(project);
+                }
+            }
+            dir = config_path.parent().and_then(Path::parent).map(Path::to_path_buf);
+        }
+        None
+    }
+
+    /// `config` if it includes `path`, otherwise the first of its references which does.
+    fn project_of(
+        &self,
+        config: Arc<Tsconfig>,
+        path: &Path,
+        visited: &mut Vec<PathBuf>,
+    ) -> Option<Arc<Tsconfig>> {
+        if visited.contains(&config.path) {
+            return None;
+        }
+        visited.push(config.path.clone());
+        if config.includes(path) {
+            return Some(config);
+        }
+        config.references.iter().find_map(|reference| {
+            self.load(reference).and_then(|referenced| self.project_of(referenced, path, visited))
+        })
+    }
+
+    fn nearest_tsconfig(&self, dir: &Path) -> Option<Box<Path>> {
+        if let Some(config_path) = self.nearest.get(dir) {
+            return config_path.clone();
+        }
+        let config_path = dir.join(TSCONFIG);
+        let config_path = if config_path.is_file() {
+            Some(config_path.into_boxed_path())
+        } else {
+            dir.parent().and_then(|parent| self.nearest_tsconfig(parent))
+        };
+        self.nearest.insert(dir.to_path_buf().into_boxed_path(), config_path.clone());
+        config_path
+    }
+
+    /// Load the tsconfig at `path` with its `extends` chain applied.
+    pub fn load(&self, path: &Path) -> Option<Arc<Tsconfig>> {
+        if let Some(config) = self.configs.get(path) {
+            return config.clone();
+        }
+        let config = Self::load_extended(path, &mut vec![]).map(Arc::new);
+        self.configs.insert(path.to_path_buf().into_boxed_path(), config.clone());
+        config
+    }
+
+    fn load_extended(path: &Path, visited: &mut Vec<PathBuf>) -> Option<Tsconfig> {
+        if visited.iter().any(|visited| visited == path) {
+            return None;
+        }
+        visited.push(path.to_path_buf());
+
+        let mut string = fs::read_to_string(path).ok()?;
+        json_strip_comments::strip(&mut string).ok()?;
+        let raw = serde_json::from_str::<RawTsconfig>(&string).ok()?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let patterns = |patterns: Option<Vec<String>>| {
+            patterns.map(|patterns| {
+                patterns.iter().map(|pattern| normalize(&dir.join(pattern))).collect::<Vec<_>>()
+            })
+        };
+        let mut compiler_options = raw.compiler_options;
+        compiler_options.base_url = compiler_options.base_url.map(|url| normalize(&dir.join(url)));
+        if compiler_options.paths.is_some() {
+            compiler_options.paths_base = Some(dir.to_path_buf());
+        }
+        let mut config = Tsconfig {
+            path: path.to_path_buf(),
+            compiler_options,
+            files: patterns(raw.files),
+            include: patterns(raw.include),
+            exclude: patterns(raw.exclude),
+            references: raw
+                .references
+                .iter()
+                .map(|reference| {
+                    let path = normalize(&dir.join(&reference.path));
+                    if path.is_dir() {
+                        path.join(TSCONFIG)
+                    } else {
+                        path
+                    }
+                })
+                .collect(),
+        };
+
+        let extends = match raw.extends {
+            Extends::None => vec![],
+            Extends::One(extends) => vec![extends],
+            Extends::Many(extends) => extends,
+        };
+        // Later entries of an `extends` array take precedence over earlier ones
+        for extends in extends.iter().rev() {
+            let Some(base) = resolve_extends(dir, extends)
+                .and_then(|base_path| Self::load_extended(&base_path, visited))
+            else {
+                continue;
+            };
+            config.compiler_options.inherit(&base.compiler_options);
+            // `files`, `include` and `exclude` are inherited, `references` are not
+            if config.files.is_none() && config.include.is_none() {
+                config.files = base.files;
+                config.include = base.include;
+            }
+            if config.exclude.is_none() {
+                config.exclude = base.exclude;
+            }
+        }
+        if let Some(base_url) = &config.compiler_options.base_url {
+            if config.compiler_options.paths.is_some() {
+                config.compiler_options.paths_base = Some(base_url.clone());
+            }
+        }
+        Some(config)
+    }
+}
+
+/// Path of the config named by `extends`, relative or from `node_modules`.
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+    let with_json = |path: PathBuf| {
+        if path.is_file() {
+            Some(path)
+        } else if path.is_dir() {
+            Some(path.join(TSCONFIG)).filter(|path| path.is_file())
+        } else {
+            let mut path = path.into_os_string();
+            path.push(".json");
+            Some(PathBuf::from(path)).filter(|path| path.is_file())
+        }
+    };
+    if extends.starts_with("./") || extends.starts_with("../") || Path::new(extends).is_absolute() {
+        return with_json(normalize(&dir.join(extends)));
+    }
+    dir.ancestors().find_map(|dir| with_json(dir.join("node_modules").join(extends)))
+}
+
+/// Remove `.` and `..` components without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// `include` patterns without a wildcard or an extension in the last segment are directories.
+fn matches_include(pattern: &Path, path: &Path) -> bool {
+    let is_dir = pattern
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| !name.contains(['*', '?', '.']));
+    if is_dir {
+        path.starts_with(pattern)
+    } else {
+        matches_glob(&components(pattern), &components(path))
+    }
+}
+
+/// `exclude` patterns exclude the paths they match and everything below them.
+fn matches_exclude(pattern: &Path, path: &Path) -> bool {
+    let pattern = components(pattern);
+    let path = components(path);
+    (1..=path.len()).any(|len| matches_glob(&pattern, &path[..len]))
+}
+
+fn components(path: &Path) -> Vec<&str> {
+    path.components().filter_map(|component| component.as_os_str().to_str()).collect()
+}
+
+/// Match path segments against glob segments, `**` matches any number of directories.
+fn matches_glob(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| matches_glob(rest, &path[skip..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(name, path_rest)| {
+            matches_segment(segment.as_bytes(), name.as_bytes()) && matches_glob(rest, path_rest)
+        }),
+    }
+}
+
+/// Match a single segment with `*` and `?` wildcards.
+fn matches_segment(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| matches_segment(rest, &name[skip..])),
+        Some((b'?', rest)) => !name.is_empty() && matches_segment(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && matches_segment(rest, &name[1..]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use super::{JsxMode, TsconfigCache};
+
+    #[test]
+    fn test_tsconfig_discovery() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/tsconfig");
+        let cache = TsconfigCache::default();
+
+        // Solution style root config, the referenced `app` project extends the base config
+        let app = cache.find(&root.join("packages/app/src/index.tsx")).unwrap();
+        assert_eq!(app.path(), root.join("packages/app/tsconfig.json"));
+        let options = app.compiler_options();
+        assert_eq!(options.jsx, Some(JsxMode::ReactJsx));
+        assert_eq!(options.experimental_decorators, Some(true));
+        assert_eq!(options.target.as_deref(), Some("es2020"));
+        assert_eq!(options.base_url.as_deref(), Some(root.as_path()));
+        assert_eq!(options.resolve_paths("@lib/utils"), vec![root.join("packages/lib/src/utils")]);
+        assert!(options.resolve_paths("react").is_empty());
+
+        // `lib` only includes `.ts` files below `src` and excludes tests
+        let lib = cache.find(&root.join("packages/lib/src/index.ts")).unwrap();
+        assert_eq!(lib.path(), root.join("packages/lib/tsconfig.json"));
+        assert_eq!(lib.compiler_options().experimental_decorators, Some(false));
+        assert_eq!(lib.compiler_options().jsx, Some(JsxMode::React));
+        assert!(cache.find(&root.join("packages/lib/src/index.test.ts")).is_none());
+
+        // Not part of any project
+        assert!(cache.find(&root.join("scripts/build.ts")).is_none());
+    }
+}
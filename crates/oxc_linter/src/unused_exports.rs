@@ -0,0 +1,146 @@
+//! Project wide detection of exports which are never imported.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashSet;
+
+use oxc_semantic::ModuleRecord;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ImportPhase,
+};
+
+/// An export of a linted file which is not imported by any module of the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedExport {
+    /// Path of the file as it was passed to the linter
+    pub path: Box<Path>,
+    /// The exported name, `default` for default exports
+    pub name: Atom,
+    /// Span of the exported name
+    pub span: Span,
+}
+
+/// Imported names of each module, keyed by canonicalized path.
+#[derive(Default)]
+pub(crate) struct ExportUsage {
+    used: FxHashSet<(PathBuf, Atom)>,
+    /// Modules whose exports are all used, e.g. by a namespace import
+    fully_used: FxHashSet<PathBuf>,
+}
+
+impl ExportUsage {
+    /// Record the imports and re-exports of `module`.
+    ///
+    /// Named imports of a re-exported binding mark the re-export and the original export as used,
+    /// namespace imports and `export * as ns from` mark every export of the module as used.
+    pub(crate) fn add_module(&mut self, module: &ModuleRecord) {
+        for entry in &module.import_entries {
+            if entry.phase == Some(ImportPhase::Source) {
+                continue;
+            }
+            let Some(target) = loaded_module(module, entry.module_request.name()) else { continue };
+            match &entry.import_name {
+                ImportImportName::Name(name) => self.mark(&target, name.name()),
+                ImportImportName::Default(_) => self.mark(&target, &Atom::from("default")),
+                ImportImportName::NamespaceObject => self.mark_all(&target),
+            }
+        }
+        for entry in &module.indirect_export_entries {
+            if !entry.import_name.is_all() {
+                continue;
+            }
+            let Some(request) = &entry.module_request else { continue };
+            if let Some(target) = loaded_module(module, request.name()) {
+                self.mark_all(&target);
+            }
+        }
+    }
+
+    /// Exports of `module` which are not used, in source order.
+    pub(crate) fn unused_exports(&self, path: &Path, module: &ModuleRecord) -> Vec<UnusedExport> {
+        let key = canonical_path(&module.resolved_absolute_path);
+        if self.fully_used.contains(&key) {
+            return vec![];
+        }
+        let mut unused = module
+            .local_export_entries
+            .iter()
+            .chain(&module.indirect_export_entries)
+            .filter_map(|entry| {
+                let (name, span) = match &entry.export_name {
+                    ExportExportName::Name(name) => (name.name().clone(), name.span()),
+                    ExportExportName::Default(span) => (Atom::from("default"), *span),
+                    ExportExportName::Null => return None,
+                };
+                let is_used = self.used.contains(&(key.clone(), name.clone()));
+                (!is_used).then(|| UnusedExport { path: path.into(), name, span })
+            })
+            .collect::<Vec<_>>();
+        unused.sort_by_key(|export| export.span.start);
+        unused
+    }
+
+    fn mark(&mut self, module: &Arc<ModuleRecord>, name: &Atom) {
+        let key = canonical_path(&module.resolved_absolute_path);
+        if self.fully_used.contains(&key) || !self.used.insert((key, name.clone())) {
+            return;
+        }
+        // Follow re-exports to the module declaring the binding
+        let mut is_exported =
+            module.local_export_entries.iter().any(|e| e.export_name.matches(name));
+        for entry in &module.indirect_export_entries {
+            if !entry.export_name.matches(name) {
+                continue;
+            }
+            is_exported = true;
+            let Some(request) = &entry.module_request else { continue };
+            let Some(target) = loaded_module(module, request.name()) else { continue };
+            match &entry.import_name {
+                ExportImportName::Name(imported) => self.mark(&target, imported.name()),
+                ExportImportName::All => self.mark_all(&target),
+                ExportImportName::AllButDefault | ExportImportName::Null => {}
+            }
+        }
+        // `export *` never forwards the default export
+        if is_exported || *name == "default" {
+            return;
+        }
+        for entry in &module.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            if let Some(target) = loaded_module(module, request.name()) {
+                self.mark(&target, name);
+            }
+        }
+    }
+
+    fn mark_all(&mut self, module: &Arc<ModuleRecord>) {
+        if !self.fully_used.insert(canonical_path(&module.resolved_absolute_path)) {
+            return;
+        }
+        for entry in module.indirect_export_entries.iter().chain(&module.star_export_entries) {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(target) = loaded_module(module, request.name()) else { continue };
+            match &entry.import_name {
+                ExportImportName::Name(imported) => self.mark(&target, imported.name()),
+                ExportImportName::All | ExportImportName::AllButDefault => self.mark_all(&target),
+                ExportImportName::Null => {}
+            }
+        }
+    }
+}
+
+/// The module loaded for `request`, cloned so the map is not locked while following re-exports
+fn loaded_module(module: &ModuleRecord, request: &Atom) -> Option<Arc<ModuleRecord>> {
+    module.loaded_modules.get(request).map(|target| Arc::clone(target.value()))
+}
+
+/// Linted files are recorded with the path passed to the linter,
+/// resolved dependencies with the absolute path returned by the resolver.
+pub(crate) fn canonical_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
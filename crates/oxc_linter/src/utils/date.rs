@@ -0,0 +1,28 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Today's date in UTC as `YYYY-MM-DD`
+pub fn today() -> String {
+    let secs =
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+    let days = i64::try_from(secs / 86_400).unwrap_or(0);
+    // Convert days since 1970-01-01 to a date of the proleptic Gregorian calendar, with years
+    // starting in March so leap days are at the end of the year
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[test]
+fn test_today() {
+    let today = today();
+    assert_eq!(today.len(), 10);
+    assert!(today.as_str() > "2023-01-01");
+}
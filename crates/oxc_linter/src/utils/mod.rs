@@ -1,3 +1,4 @@
+mod date;
 mod jest;
 mod nextjs;
 mod node;
@@ -5,4 +6,4 @@ mod react;
 mod react_perf;
 mod unicorn;
 
-pub use self::{jest::*, nextjs::*, node::*, react::*, react_perf::*, unicorn::*};
+pub use self::{date::*, jest::*, nextjs::*, node::*, react::*, react_perf::*, unicorn::*};
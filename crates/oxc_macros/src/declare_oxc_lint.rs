@@ -9,6 +9,8 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// `fix` or `dangerous_fix` if the rule has fixes
+    fix: Option<Ident>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -33,16 +35,22 @@ impl Parse for LintRuleMeta {
         let struct_name = input.parse()?;
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
+        let fix = if input.peek(Token!(,)) && input.peek2(Ident) {
+            input.parse::<Token!(,)>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
         // Ignore the rest
         input.parse::<TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, fix, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, documentation, used_in_test } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -54,12 +62,20 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
+    let fix = fix.map(|fix| match fix.to_string().as_str() {
+        "fix" => quote! { FixKind::Safe },
+        "dangerous_fix" => quote! { FixKind::Dangerous },
+        _ => panic!("invalid rule fix, expected `fix` or `dangerous_fix`"),
+    });
 
     let import_statement = if used_in_test {
         None
+    } else if fix.is_some() {
+        Some(quote! { use crate::rule::{FixKind, RuleCategory, RuleMeta}; })
     } else {
         Some(quote! { use crate::rule::{RuleCategory, RuleMeta}; })
     };
+    let fix = fix.map(|fix| quote! { const FIX: FixKind = #fix; });
 
     let output = quote! {
         #import_statement
@@ -69,6 +85,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #fix
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
@@ -10,6 +10,9 @@ mod declare_oxc_lint;
 /// 1. The documentation
 /// 2. The lint's struct
 ///
+/// followed by the category of the lint and, if the lint has fixes, either `fix` for fixes which
+/// preserve the behavior of the code, or `dangerous_fix` for fixes which may change it.
+///
 /// # Example
 ///
 /// ```
@@ -32,7 +35,9 @@ mod declare_oxc_lint;
 ///     /// ```
 ///     ///
 ///     /// ```
-///     pub struct NoDebugger
+///     NoDebugger,
+///     correctness,
+///     dangerous_fix
 /// }
 /// ```
 #[proc_macro]
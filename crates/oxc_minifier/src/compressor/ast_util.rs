@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
+use oxc_ecmascript::{number_to_string, string_to_number};
 use oxc_semantic::ReferenceFlag;
 use oxc_syntax::operator::{AssignmentOperator, LogicalOperator, UnaryOperator};
 
@@ -370,11 +371,20 @@ pub fn get_number_value(expr: &Expression) -> Option<NumberValue> {
             "NaN" | "undefined" => Some(NumberValue::NaN),
             _ => None,
         },
-        // TODO: will be implemented in next PR, just for test pass now.
-        Expression::StringLiteral(string_literal) => string_literal
-            .value
-            .parse::<f64>()
-            .map_or(Some(NumberValue::NaN), |num| Some(NumberValue::Number(num))),
+        Expression::StringLiteral(string_literal) => {
+            let num = string_to_number(string_literal.value.as_str());
+            Some(if num.is_nan() {
+                NumberValue::NaN
+            } else if num.is_infinite() {
+                if num.is_sign_positive() {
+                    NumberValue::PositiveInfinity
+                } else {
+                    NumberValue::NegativeInfinity
+                }
+            } else {
+                NumberValue::Number(num)
+            })
+        }
         _ => None,
     }
 }
@@ -581,7 +591,7 @@ pub fn get_string_value<'a>(expr: &'a Expression) -> Option<Cow<'a, str>> {
             }
         }
         Expression::NumericLiteral(number_literal) => {
-            Some(Cow::Owned(number_literal.value.to_string()))
+            Some(Cow::Owned(number_to_string(number_literal.value)))
         }
         Expression::BigintLiteral(big_int_literal) => {
             Some(Cow::Owned(big_int_literal.raw.to_string()))
@@ -0,0 +1,123 @@
+//! Facts about expressions computed by analyses, cached so that passes do not recompute them.
+//!
+//! AST nodes have no ids, so facts are keyed by the address of the node, which is allocated in the
+//! arena and does not move when the expression holding it is moved. A replaced expression is a new
+//! allocation with a new address, so the facts of the old one are never looked up again.
+//! Passes which mutate a node in place must [`NodeFacts::invalidate`] it, and the compressor
+//! invalidates the ancestors of a changed expression as it leaves them.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use rustc_hash::FxHashMap;
+
+use super::ast_util::{IsLiteralValue, MayHaveSideEffects};
+
+/// Facts of a node, `None` until computed
+#[derive(Debug, Default, Clone, Copy)]
+struct Facts {
+    is_constant: Option<bool>,
+    is_pure: Option<bool>,
+}
+
+#[derive(Debug, Default)]
+pub struct NodeFacts {
+    facts: FxHashMap<usize, Facts>,
+}
+
+impl NodeFacts {
+    /// [`IsLiteralValue::is_literal_value`] including functions,
+    /// i.e. evaluates to the same value wherever it is evaluated.
+    pub fn is_constant(&mut self, expr: &Expression) -> bool {
+        let facts = self.facts.entry(expr.node_address()).or_default();
+        *facts.is_constant.get_or_insert_with(|| expr.is_literal_value(true))
+    }
+
+    /// Not [`MayHaveSideEffects::may_have_side_effects`]
+    pub fn is_pure<'a, 'b, T>(&mut self, node: &T) -> bool
+    where
+        T: NodeAddress + MayHaveSideEffects<'a, 'b>,
+    {
+        let facts = self.facts.entry(node.node_address()).or_default();
+        *facts.is_pure.get_or_insert_with(|| !node.may_have_side_effects())
+    }
+
+    /// Forget the facts of `node`, after it has been mutated in place
+    pub fn invalidate<T: NodeAddress>(&mut self, node: &T) {
+        self.facts.remove(&node.node_address());
+    }
+}
+
+/// Key of a node in [`NodeFacts`]
+pub trait NodeAddress {
+    fn node_address(&self) -> usize;
+}
+
+fn address<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+impl<'a> NodeAddress for Expression<'a> {
+    /// Address of the boxed node, which is the same as the address of the unboxed node,
+    /// e.g. of the [`UnaryExpression`] of an [`Expression::UnaryExpression`]
+    fn node_address(&self) -> usize {
+        macro_rules! address {
+            ($($variant:ident),* $(,)?) => {
+                match self {
+                    $(Self::$variant(node) => address(&**node),)*
+                }
+            };
+        }
+        address!(
+            BooleanLiteral,
+            NullLiteral,
+            NumericLiteral,
+            BigintLiteral,
+            RegExpLiteral,
+            StringLiteral,
+            TemplateLiteral,
+            Identifier,
+            MetaProperty,
+            Super,
+            ArrayExpression,
+            ArrowFunctionExpression,
+            AssignmentExpression,
+            AwaitExpression,
+            BinaryExpression,
+            CallExpression,
+            ChainExpression,
+            ClassExpression,
+            ConditionalExpression,
+            FunctionExpression,
+            ImportExpression,
+            LogicalExpression,
+            MemberExpression,
+            NewExpression,
+            ObjectExpression,
+            ParenthesizedExpression,
+            SequenceExpression,
+            TaggedTemplateExpression,
+            ThisExpression,
+            UnaryExpression,
+            UpdateExpression,
+            YieldExpression,
+            PrivateInExpression,
+            PipelineExpression,
+            DoExpression,
+            ThrowExpression,
+            ErrorExpression,
+            JSXElement,
+            JSXFragment,
+            TSAsExpression,
+            TSSatisfiesExpression,
+            TSTypeAssertion,
+            TSNonNullExpression,
+            TSInstantiationExpression,
+        )
+    }
+}
+
+impl<'a> NodeAddress for UnaryExpression<'a> {
+    fn node_address(&self) -> usize {
+        address(self)
+    }
+}
@@ -15,8 +15,7 @@ use oxc_syntax::{
 
 use super::ast_util::{
     get_boolean_value, get_number_value, get_side_free_bigint_value, get_side_free_number_value,
-    get_side_free_string_value, get_string_value, is_exact_int64, IsLiteralValue,
-    MayHaveSideEffects, NumberValue,
+    get_side_free_string_value, get_string_value, is_exact_int64, NumberValue,
 };
 use super::Compressor;
 
@@ -220,7 +219,7 @@ impl<'a> Compressor<'a> {
                 | UnaryOperator::UnaryNegation
                 | UnaryOperator::LogicalNot
                 | UnaryOperator::BitwiseNot
-                    if !unary_expr.may_have_side_effects() =>
+                    if self.facts.is_pure(&**unary_expr) =>
                 {
                     self.try_fold_unary_operator(unary_expr)
                 }
@@ -247,7 +246,7 @@ impl<'a> Compressor<'a> {
         right: &'b Expression<'a>,
     ) -> Option<Expression<'a>> {
         // skip any potentially dangerous compressions
-        if left.may_have_side_effects() || right.may_have_side_effects() {
+        if !self.facts.is_pure(left) || !self.facts.is_pure(right) {
             return None;
         }
 
@@ -277,8 +276,8 @@ impl<'a> Compressor<'a> {
                 let Ok(value) = TryInto::<f64>::try_into(left_number + right_number) else { return None };
                 // Float if value has a fractional part, otherwise Decimal
                 let number_base = if is_exact_int64(value) { NumberBase::Decimal } else { NumberBase::Float };
-                let number_literal = self.ast.number_literal(span, value, "", number_base);
-                // todo: add raw &str
+                let raw = self.ast.new_str(value.to_string().as_str());
+                let number_literal = self.ast.number_literal(span, value, raw, number_base);
                 Some(self.ast.literal_number_expression(number_literal))
             },
             _ => None
@@ -307,7 +306,7 @@ impl<'a> Compressor<'a> {
         left: &'b Expression<'a>,
         right: &'b Expression<'a>,
     ) -> Tri {
-        if left.may_have_side_effects() || right.may_have_side_effects() {
+        if !self.facts.is_pure(left) || !self.facts.is_pure(right) {
             return Tri::Unknown;
         }
 
@@ -351,6 +350,11 @@ impl<'a> Compressor<'a> {
             if matches!((left, right), (Ty::Number, Ty::Str)) || matches!(right, Ty::Boolean) {
                 let right_number = get_side_free_number_value(right_expr);
 
+                // No number is equal to NaN, e.g. `1 == 'NaN'`
+                if let Some(NumberValue::NaN) = right_number {
+                    return Tri::False;
+                }
+
                 if let Some(NumberValue::Number(num)) = right_number {
                     let raw = self.ast.new_str(num.to_string().as_str());
 
@@ -371,6 +375,11 @@ impl<'a> Compressor<'a> {
             if matches!((left, right), (Ty::Str, Ty::Number)) || matches!(left, Ty::Boolean) {
                 let left_number = get_side_free_number_value(left_expr);
 
+                // No number is equal to NaN, e.g. `1 == 'NaN'`
+                if let Some(NumberValue::NaN) = left_number {
+                    return Tri::False;
+                }
+
                 if let Some(NumberValue::Number(num)) = left_number {
                     let raw = self.ast.new_str(num.to_string().as_str());
 
@@ -563,7 +572,7 @@ impl<'a> Compressor<'a> {
         span: Span,
         argument: &'b Expression<'a>,
     ) -> Option<Expression<'a>> {
-        if argument.is_literal_value(true) {
+        if self.facts.is_constant(argument) {
             let type_name = match argument {
                 Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
                     Some("function")
@@ -695,10 +704,11 @@ impl<'a> Compressor<'a> {
                         if number_literal.value.fract() == 0.0 {
                             let int_value =
                                 NumericLiteral::ecmascript_to_int32(number_literal.value);
+                            let value = !int_value;
                             let literal = self.ast.number_literal(
                                 unary_expr.span,
-                                f64::from(!int_value),
-                                number_literal.raw,
+                                f64::from(value),
+                                self.ast.new_str(value.to_string().as_str()),
                                 NumberBase::Decimal, // since it be converted to i32, it should always be decimal.
                             );
                             return Some(self.ast.literal_number_expression(literal));
@@ -758,7 +768,7 @@ impl<'a> Compressor<'a> {
     fn try_reduce_void(&mut self, unary_expr: &UnaryExpression<'a>) -> Option<Expression<'a>> {
         let can_replace = match &unary_expr.argument {
             Expression::NumericLiteral(number_literal) => number_literal.value != 0_f64,
-            _ => !unary_expr.may_have_side_effects(),
+            _ => self.facts.is_pure(unary_expr),
         };
 
         if can_replace {
@@ -843,7 +853,7 @@ impl<'a> Compressor<'a> {
                 || (!boolean_value && op == LogicalOperator::And)
             {
                 return Some(self.move_out_expression(&mut logic_expr.left));
-            } else if !logic_expr.left.may_have_side_effects() {
+            } else if self.facts.is_pure(&logic_expr.left) {
                 // (FALSE || x) => x
                 // (TRUE && x) => x
                 return Some(self.move_out_expression(&mut logic_expr.right));
@@ -863,7 +873,7 @@ impl<'a> Compressor<'a> {
                 let left_child_right_boolean = get_boolean_value(&left_child.right);
                 let left_child_op = left_child.operator;
                 if let Some(right_boolean) = left_child_right_boolean {
-                    if !left_child.right.may_have_side_effects() {
+                    if self.facts.is_pure(&left_child.right) {
                         // a || false || b => a || b
                         // a && true && b => a && b
                         if !right_boolean && left_child_op == LogicalOperator::Or
@@ -943,7 +953,7 @@ impl<'a> Compressor<'a> {
     fn try_minimize_not(&mut self, expr: &mut Expression<'a>) -> bool {
         let span = &mut expr.span();
 
-        match expr {
+        let minimized = match expr {
             Expression::BinaryExpression(binary_expr) => {
                 let new_op = binary_expr.0.operator.equality_inverse_operator();
 
@@ -958,6 +968,10 @@ impl<'a> Compressor<'a> {
                 }
             }
             _ => false,
+        };
+        if minimized {
+            self.facts.invalidate(expr);
         }
+        minimized
     }
 }
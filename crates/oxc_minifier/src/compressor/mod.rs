@@ -1,6 +1,7 @@
 #![allow(clippy::unused_self)]
 
 mod ast_util;
+mod facts;
 mod fold;
 mod options;
 mod prepass;
@@ -17,25 +18,52 @@ use oxc_syntax::{
 };
 
 pub use self::options::CompressOptions;
-use self::prepass::Prepass;
+use self::{
+    facts::{NodeAddress, NodeFacts},
+    prepass::Prepass,
+};
 
 pub struct Compressor<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
 
     prepass: Prepass<'a>,
+
+    /// Facts about expressions, kept across passes
+    facts: NodeFacts,
+
+    /// Whether the expression being visited has changed, see [`NodeFacts`]
+    changed: bool,
 }
 
 const SPAN: Span = Span::new(0, 0);
 
 impl<'a> Compressor<'a> {
     pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
-        Self { ast: AstBuilder::new(allocator), options, prepass: Prepass::new(allocator) }
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            prepass: Prepass::new(allocator),
+            facts: NodeFacts::default(),
+            changed: false,
+        }
+    }
+
+    pub fn build(self, program: &mut Program<'a>) {
+        self.build_with_pass_inspector(program, |_, _| {});
     }
 
-    pub fn build(mut self, program: &mut Program<'a>) {
+    /// Like [`Compressor::build`], calling `inspect` with the name of each pass and the program
+    /// after the pass.
+    pub fn build_with_pass_inspector<F: FnMut(&'static str, &Program<'a>)>(
+        mut self,
+        program: &mut Program<'a>,
+        mut inspect: F,
+    ) {
         self.prepass.build(program);
+        inspect("prepass", program);
         self.visit_program(program);
+        inspect("compress", program);
     }
 
     /* Utilities */
@@ -202,9 +230,9 @@ impl<'a> Compressor<'a> {
 
     /// Transforms `typeof foo == "undefined"` into `foo === void 0`
     /// Enabled by `compress.typeofs`
-    fn compress_typeof_undefined(&self, expr: &mut BinaryExpression<'a>) {
+    fn compress_typeof_undefined(&self, expr: &mut BinaryExpression<'a>) -> bool {
         if !self.options.typeofs {
-            return;
+            return false;
         }
         match expr.operator {
             BinaryOperator::Equality | BinaryOperator::StrictEquality => {
@@ -234,10 +262,12 @@ impl<'a> Compressor<'a> {
                     let right = self.ast.identifier_reference_expression(id_ref);
                     let cmp = BinaryExpression { span, left, operator, right };
                     *expr = cmp;
+                    return true;
                 }
             }
             _ => {}
         };
+        false
     }
 
     fn commutative_pair<A, F, G, RetF: 'a, RetG: 'a>(
@@ -349,18 +379,28 @@ impl<'a> VisitMut<'a> for Compressor<'a> {
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let siblings_changed = std::mem::replace(&mut self.changed, false);
         self.visit_expression_match(expr);
+        // The facts of an expression depend on its children
+        if self.changed {
+            self.facts.invalidate(expr);
+        }
+        let address = expr.node_address();
         self.compress_console(expr);
         self.fold_expression(expr);
         if !self.compress_undefined(expr) {
             self.compress_boolean(expr);
         }
+        self.changed |= expr.node_address() != address;
+        self.changed |= siblings_changed;
     }
 
     fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
         self.visit_expression(&mut expr.left);
         self.visit_expression(&mut expr.right);
 
-        self.compress_typeof_undefined(expr);
+        if self.compress_typeof_undefined(expr) {
+            self.changed = true;
+        }
     }
 }
@@ -8,7 +8,7 @@ use oxc_ast::ast::Program;
 
 pub use crate::{
     compressor::{CompressOptions, Compressor},
-    mangler::ManglerBuilder,
+    mangler::{parse_reserved_names, ManglerBuilder},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -33,9 +33,21 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
-        Compressor::new(allocator, self.options.compress).build(program);
+        self.build_with_pass_inspector(allocator, program, |_, _| {});
+    }
+
+    /// Like [`Minifier::build`], calling `inspect` with the name of each pass and the program
+    /// after the pass, e.g. to print the intermediate programs while debugging a pass.
+    pub fn build_with_pass_inspector<'a, F: FnMut(&'static str, &Program<'a>)>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        inspect: F,
+    ) {
+        Compressor::new(allocator, self.options.compress)
+            .build_with_pass_inspector(program, inspect);
         // if self.options.mangle {
-        // let mangler = ManglerBuilder.build(program);
+        // let mangler = ManglerBuilder::default().build(program);
         // printer.with_mangler(mangler);
         // }
     }
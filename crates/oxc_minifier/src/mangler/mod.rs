@@ -1,9 +1,11 @@
 use itertools::Itertools;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_ast::{AstKind, CommentKind, Trivias};
 use oxc_index::{index_vec, IndexVec};
-use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolId, SymbolTable};
-use oxc_span::Atom;
+use oxc_semantic::{ReferenceId, ScopeTree, Semantic, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{Atom, GetSpan};
+use rustc_hash::FxHashSet;
 
 type Slot = usize;
 
@@ -64,13 +66,48 @@ impl Mangler {
 ///     }
 /// }
 /// ```
-pub struct ManglerBuilder;
+///
+/// ## Keeping names
+///
+/// Applications which reflect on names, e.g. with `Function.prototype.name`, keep them with
+/// [`ManglerBuilder::reserved`], or case by case with a `/* @__NO_MANGLE__ */` or
+/// `/* #__NO_MANGLE__ */` annotation before the declaration or the binding, which requires
+/// [`ManglerBuilder::annotations`]:
+///
+/// ```javascript
+/// /* @__NO_MANGLE__ */ function Component() {}
+/// function f(/* #__NO_MANGLE__ */ name) {}
+/// ```
+///
+/// Mangled names never collide with kept names.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ManglerBuilder<'s> {
+    reserved: &'s [String],
+    annotations: Option<(&'s str, &'s Trivias)>,
+}
+
+impl<'s> ManglerBuilder<'s> {
+    /// Names which are neither mangled nor used as mangled names,
+    /// see [`parse_reserved_names`] for reading them from a file.
+    #[must_use]
+    pub fn reserved(mut self, names: &'s [String]) -> Self {
+        self.reserved = names;
+        self
+    }
+
+    /// Keep the names of the symbols annotated with `@__NO_MANGLE__` in the comments of
+    /// `source_text`.
+    #[must_use]
+    pub fn annotations(mut self, source_text: &'s str, trivias: &'s Trivias) -> Self {
+        self.annotations = Some((source_text, trivias));
+        self
+    }
 
-impl ManglerBuilder {
     #[must_use]
     pub fn build<'a>(self, program: &'a Program<'a>) -> Mangler {
         let semantic_ret = SemanticBuilder::new("", program.source_type).build(program);
         let semantic = semantic_ret.semantic;
+        let kept_symbols = self.kept_symbols(&semantic);
 
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
@@ -108,12 +145,31 @@ impl ManglerBuilder {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots);
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            &scope_tree,
+            total_number_of_slots,
+            &slots,
+            &kept_symbols,
+        );
+
+        // Identifiers inside `with` statements may refer to a symbol or to a property of the object,
+        // symbols with these names must keep their names.
+        let with_references = scope_tree.iter_with_references().map(|(name, _)| name);
+
+        let kept_names = kept_symbols
+            .iter()
+            .map(|symbol_id| symbol_table.get_name(*symbol_id).clone())
+            .collect::<Vec<_>>();
+        let reserved = self.reserved.iter().map(String::as_str);
 
         let unresolved_references = scope_tree
             .root_unresolved_references()
             .keys()
+            .chain(with_references)
+            .chain(&kept_names)
+            .map(Atom::as_str)
+            .chain(reserved)
             // It is unlike to get a 5 letter mangled identifier, which is a lot of slots.
             // .filter(|name| name.len() < 5)
             .collect::<Vec<_>>();
@@ -125,8 +181,9 @@ impl ManglerBuilder {
             names.push(loop {
                 let name = Atom::base54(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
-                if !is_keyword(&name) && !unresolved_references.iter().any(|n| **n == name) {
+                // Do not use keywords, unresolved references and kept names
+                if !is_keyword(&name) && !unresolved_references.iter().any(|n| *n == name.as_str())
+                {
                     break name;
                 }
             });
@@ -179,14 +236,64 @@ impl ManglerBuilder {
         Mangler { symbol_table }
     }
 
+    /// Symbols with reserved names or a `@__NO_MANGLE__` annotation
+    fn kept_symbols(&self, semantic: &Semantic) -> Vec<SymbolId> {
+        let symbols = semantic.symbols();
+        let mut kept = symbols
+            .iter()
+            .filter(|symbol_id| {
+                self.reserved.iter().any(|name| symbols.get_name(*symbol_id) == name)
+            })
+            .collect::<Vec<_>>();
+        let Some((source_text, trivias)) = self.annotations else { return kept };
+        // Start of the code after each annotation
+        let annotated = trivias
+            .comments
+            .iter()
+            .filter(|(start, end, _)| {
+                let text = source_text[*start as usize..*end as usize].trim();
+                matches!(text, "@__NO_MANGLE__" | "#__NO_MANGLE__")
+            })
+            .map(|(_, end, kind)| {
+                let end = if *kind == CommentKind::MultiLine { *end + 2 } else { *end };
+                let rest = &source_text[end as usize..];
+                end + u32::try_from(rest.len() - rest.trim_start().len()).unwrap()
+            })
+            .collect::<Vec<_>>();
+        if annotated.is_empty() {
+            return kept;
+        }
+        let nodes = semantic.nodes();
+        for symbol_id in symbols.iter() {
+            let declaration = symbols.get_declaration(symbol_id);
+            let mut starts =
+                vec![symbols.get_span(symbol_id).start, nodes.kind(declaration).span().start];
+            // `/* @__NO_MANGLE__ */ const a = 1`
+            if let Some(parent @ AstKind::VariableDeclaration(_)) = nodes.parent_kind(declaration) {
+                starts.push(parent.span().start);
+            }
+            if starts.iter().any(|start| annotated.contains(start)) {
+                kept.push(symbol_id);
+            }
+        }
+        kept
+    }
+
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
+        scope_tree: &ScopeTree,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
+        kept_symbols: &[SymbolId],
     ) -> Vec<SlotFrequency> {
+        let with_references =
+            scope_tree.iter_with_references().map(|(name, _)| name).collect::<FxHashSet<_>>();
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if !symbol_table.get_flag(symbol_id).is_variable() {
+            if !symbol_table.get_flag(symbol_id).is_variable()
+                || with_references.contains(&symbol_table.get_name(symbol_id))
+                || kept_symbols.contains(&symbol_id)
+            {
                 continue;
             }
             let index = *slot;
@@ -207,6 +314,15 @@ struct SlotFrequency {
     pub symbol_ids: Vec<SymbolId>,
 }
 
+/// Names of a reserved names file, one per line, ignoring blank lines and `#` comments.
+pub fn parse_reserved_names(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.split_once('#').map_or(line, |(name, _)| name).trim())
+        .filter(|name| !name.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
 #[rustfmt::skip]
 fn is_keyword(s: &str) -> bool {
     matches!(s, "as" | "do" | "if" | "in" | "is" | "of" | "any" | "for" | "get"
@@ -214,3 +330,46 @@ fn is_keyword(s: &str) -> bool {
             | "enum" | "from" | "meta" | "null" | "this" | "true" | "type"
             | "void" | "with")
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SymbolTable;
+    use oxc_span::{SourceType, Span};
+
+    use super::{parse_reserved_names, ManglerBuilder};
+
+    /// Name of the symbol declared at the first occurrence of `name` after mangling
+    fn mangled_name<'a>(source_text: &str, symbols: &'a SymbolTable, name: &str) -> &'a str {
+        let start = u32::try_from(source_text.find(name).unwrap()).unwrap();
+        let span = Span::new(start, start + u32::try_from(name.len()).unwrap());
+        symbols.get_name(symbols.get_symbol_id_from_span(&span).unwrap()).as_str()
+    }
+
+    #[test]
+    fn keep_names() {
+        let allocator = Allocator::default();
+        let source_text = "function f(/* @__NO_MANGLE__ */ first, second, third) {}
+            /* #__NO_MANGLE__ */ const Component = () => {}; let other;";
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let program = allocator.alloc(ret.program);
+        let reserved = vec!["third".to_string()];
+        let mangler = ManglerBuilder::default()
+            .reserved(&reserved)
+            .annotations(source_text, &ret.trivias)
+            .build(program);
+        let symbols = &mangler.symbol_table;
+        assert_eq!(mangled_name(source_text, symbols, "first"), "first");
+        assert_eq!(mangled_name(source_text, symbols, "third"), "third");
+        assert_eq!(mangled_name(source_text, symbols, "Component"), "Component");
+        assert_ne!(mangled_name(source_text, symbols, "second"), "second");
+        assert_ne!(mangled_name(source_text, symbols, "other"), "other");
+    }
+
+    #[test]
+    fn reserved_names_file() {
+        let names = parse_reserved_names("# names used by the router\nHome\n\n  About # page\n");
+        assert_eq!(names, vec!["Home", "About"]);
+    }
+}
@@ -14,6 +14,12 @@ fn typeof_folding() {
     test("'undefined' === typeof x", "void 0===x;");
 }
 
+#[test]
+fn folding_after_children_changed() {
+    test("x = void (1 + 1)", "x=void 0;");
+    test("x = foo() || (1 + 1 === 3) || bar()", "x=foo()||bar();");
+}
+
 #[test]
 fn addition_folding_snapshots() {
     test_snapshot(
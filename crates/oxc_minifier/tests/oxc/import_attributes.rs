@@ -0,0 +1,26 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{minify, MinifierOptions};
+
+/// Minify `source_text` as a module, and check the output still has the attributes and is stable.
+fn test_module(source_text: &str) {
+    let source_type = SourceType::default().with_module(true);
+    let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+    let minified = minify(source_text, source_type, options);
+    assert!(minified.contains("with{type:"), "for source {source_text}, got {minified}");
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &minified, source_type).parse();
+    assert!(ret.errors.is_empty(), "for source {source_text}, got {minified}");
+    assert_eq!(minified, minify(&minified, source_type, options), "for source {source_text}");
+}
+
+#[test]
+fn import_attributes() {
+    test_module("import data from './data.json' with { type: 'json' }; console.log(data)");
+    test_module("import './style.css' with { type: 'css' }");
+    test_module("export { default } from './data.json' with { type: 'json' }");
+    test_module("export * from './data.json' with { type: 'json' }");
+}
@@ -1,3 +1,6 @@
 mod code_removal;
 mod folding;
+mod import_attributes;
+mod number;
+mod passes;
 mod precedence;
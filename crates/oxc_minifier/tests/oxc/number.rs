@@ -0,0 +1,21 @@
+use crate::test;
+
+#[test]
+fn shortest_representation() {
+    test("x = 0.5", "x=.5;");
+    test("x = 100.0", "x=100;");
+    test("x = 1_000_000", "x=1e6;");
+    test("x = 0b1111101000", "x=1e3;");
+    test("x = 0o1750", "x=1e3;");
+    test("x = 0.000001", "x=1e-6;");
+    test("x = 1.5e-7", "x=15e-8;");
+    test("x = 1.5e21", "x=15e20;");
+    test("x = 1e21", "x=1e21;");
+    test("x = 123.456", "x=123.456;");
+    test("x = 0.30000000000000004", "x=.30000000000000004;");
+    test("x = 5e-324", "x=5e-324;");
+    test("x = 1.7976931348623157e308", "x=17976931348623157e292;");
+    test("x = 4294967295", "x=4294967295;");
+    test("x = 0xFFFFFFFFFFFF", "x=0xffffffffffff;");
+    test("x = -0.5", "x=-.5;");
+}
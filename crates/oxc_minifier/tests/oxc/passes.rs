@@ -0,0 +1,30 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::minify;
+
+#[test]
+fn pass_inspector() {
+    let source_text = "if (true) { (a); }";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+    let mut passes = vec![];
+    Minifier::new(options).build_with_pass_inspector(&allocator, program, |pass, program| {
+        let printed =
+            Codegen::<true>::new(source_text.len(), CodegenOptions::default()).build(program);
+        passes.push((pass, printed));
+    });
+
+    let names = passes.iter().map(|(pass, _)| *pass).collect::<Vec<_>>();
+    assert_eq!(names, ["prepass", "compress"]);
+    // Booleans are compressed after the prepass
+    assert!(passes[0].1.contains("true"));
+    assert!(!passes[1].1.contains("true"));
+    assert_eq!(passes[1].1, minify(source_text, source_type, options));
+}
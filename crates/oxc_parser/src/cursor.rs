@@ -25,6 +25,7 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn end_span(&self, span: Span) -> Span {
+        self.node_count.set(self.node_count.get().saturating_add(1));
         let mut span = span;
         span.end = self.prev_token_end;
         span
@@ -111,16 +112,28 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.add_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
+        self.token_count = self.token_count.saturating_add(1);
+    }
+
+    /// Record the current token for [`crate::Parser::lossless`], before moving past it
+    fn add_token(&mut self) {
+        let span = self.token.span();
+        if span.start < span.end {
+            self.lexer.trivia_builder.add_token(span);
+        }
     }
 
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.add_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
+        self.token_count = self.token_count.saturating_add(1);
     }
 
     /// Advance and return true if we are at `Kind`, return false otherwise
@@ -158,10 +171,17 @@ impl<'a> ParserImpl<'a> {
         }
         if self.at(Kind::Semicolon) {
             self.advance(Kind::Semicolon);
+        } else {
+            self.add_inserted_semicolon();
         }
         Ok(())
     }
 
+    /// Record a semicolon inserted after the previous token
+    pub(crate) fn add_inserted_semicolon(&mut self) {
+        self.lexer.trivia_builder.add_inserted_semicolon(self.prev_token_end);
+    }
+
     pub(crate) fn can_insert_semicolon(&self) -> bool {
         let kind = self.cur_kind();
         if kind == Kind::Semicolon {
@@ -268,7 +288,9 @@ impl<'a> ParserImpl<'a> {
     ) -> Result<T> {
         let checkpoint = self.checkpoint();
         let ctx = self.ctx;
+        self.speculation_depth += 1;
         let result = func(self);
+        self.speculation_depth -= 1;
         if result.is_err() {
             self.ctx = ctx;
             self.rewind(checkpoint);
@@ -276,9 +298,62 @@ impl<'a> ParserImpl<'a> {
         result
     }
 
+    /// Run `func` one nesting level deeper.
+    ///
+    /// Recursive descent uses the native stack, so deeply nested input such as
+    /// a minified 10k-deep ternary would overflow it.
+    /// Bail out with an error when `max_nesting_depth` is exceeded instead.
+    ///
+    /// Every statement, expression, binding pattern, TypeScript type and JSX element passes through here,
+    /// so the token count, node count, time budget and memory limits are checked here as well,
+    /// see [`oxc_allocator::Allocator::with_memory_limit`] for the latter.
+    pub(crate) fn nested<T>(
+        &mut self,
+        func: impl FnOnce(&mut ParserImpl<'a>) -> Result<T>,
+    ) -> Result<T> {
+        self.check_limits()?;
+        if self.nesting_depth >= self.max_nesting_depth {
+            return Err(diagnostics::NestingTooDeep(
+                self.max_nesting_depth,
+                self.cur_token().span(),
+            )
+            .into());
+        }
+        self.nesting_depth += 1;
+        let result = func(self);
+        self.nesting_depth -= 1;
+        result
+    }
+
+    fn check_limits(&mut self) -> Result<()> {
+        if let Err(exceeded) = self.ast.allocator.check_memory_limit() {
+            return Err(diagnostics::MemoryLimitExceeded(exceeded, self.cur_token().span()).into());
+        }
+        if self.token_count > self.max_token_count {
+            return Err(
+                diagnostics::TooManyTokens(self.max_token_count, self.cur_token().span()).into()
+            );
+        }
+        if self.node_count.get() > self.max_node_count {
+            return Err(
+                diagnostics::TooManyNodes(self.max_node_count, self.cur_token().span()).into()
+            );
+        }
+        if self.token_count >= self.next_time_budget_check {
+            self.next_time_budget_check =
+                self.token_count.saturating_add(crate::TIME_BUDGET_CHECK_INTERVAL);
+            if self.time_budget.is_some_and(|is_over_budget| is_over_budget()) {
+                return Err(diagnostics::TimeBudgetExceeded(self.cur_token().span()).into());
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn lookahead<U>(&mut self, predicate: impl Fn(&mut ParserImpl<'a>) -> U) -> U {
         let checkpoint = self.checkpoint();
+        self.speculation_depth += 1;
         let answer = predicate(self);
+        self.speculation_depth -= 1;
         self.rewind(checkpoint);
         answer
     }
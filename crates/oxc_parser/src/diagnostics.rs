@@ -9,6 +9,43 @@ use oxc_span::Span;
 #[diagnostic()]
 pub struct OverlongSource;
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Source length exceeds the limit of {0} bytes")]
+#[diagnostic()]
+pub struct SourceTooLong(pub usize);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Token count exceeds the limit of {0} tokens")]
+#[diagnostic()]
+pub struct TooManyTokens(pub u32, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Node count exceeds the limit of {0} nodes")]
+#[diagnostic()]
+pub struct TooManyNodes(pub u32, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Memory limit exceeded, {0}")]
+#[diagnostic()]
+pub struct MemoryLimitExceeded(pub oxc_allocator::MemoryLimitExceeded, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Parsing exceeded its time budget")]
+#[diagnostic()]
+pub struct TimeBudgetExceeded(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Nesting too deep, exceeds the limit of {0} levels")]
+#[diagnostic(help(
+    "Raise the limit with `Parser::max_nesting_depth` if the stack is large enough"
+))]
+pub struct NestingTooDeep(pub u32, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The {0} proposal is not enabled")]
+#[diagnostic(help("Enable it with `Parser::{1}`"))]
+pub struct ProposalNotEnabled(pub &'static str, pub &'static str, #[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Flow is not supported")]
 #[diagnostic()]
@@ -19,6 +56,16 @@ pub struct Flow(#[label] pub Span);
 #[diagnostic()]
 pub struct UnexpectedToken(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("JSON does not allow {0}")]
+#[diagnostic()]
+pub struct InvalidJson(pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unexpected input after the end of the fragment")]
+#[diagnostic()]
+pub struct TrailingInput(#[label("Remove this input")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Expected `{0}` but found `{1}`")]
 #[diagnostic()]
@@ -292,6 +339,26 @@ pub struct ReturnStatementOnlyInFunctionBody(#[label] pub Span);
 #[diagnostic(help("Did you mean to write an array?"))]
 pub struct JSXExpressionsMayNotUseTheCommaOperator(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Expected corresponding JSX closing tag for `{0}`")]
+#[diagnostic()]
+pub struct UnclosedJSXElement(pub String, #[label("opened here")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Expected corresponding closing tag for JSX fragment")]
+#[diagnostic()]
+pub struct UnclosedJSXFragment(#[label("opened here")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Expected an expression or `}}` in JSX expression container")]
+#[diagnostic()]
+pub struct JSXExpressionExpected(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Expected a JSX attribute value")]
+#[diagnostic(help("Attribute values are strings, `{{expression}}` or JSX elements"))]
+pub struct JSXAttributeValueExpected(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Line terminator not permitted before using declaration.")]
 #[diagnostic()]
@@ -302,6 +369,11 @@ pub struct LineTerminatorBeforeUsingDeclaration(#[label] pub Span);
 #[diagnostic()]
 pub struct AwaitInUsingDeclaration(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Await using declarations are only allowed in async functions and modules.")]
+#[diagnostic()]
+pub struct AwaitUsingDeclarationNotAllowed(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Using declarations may not have binding patterns.")]
 #[diagnostic()]
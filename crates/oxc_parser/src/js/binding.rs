@@ -69,6 +69,10 @@ impl<'a> ParserImpl<'a> {
     ///     `SingleNameBinding`
     ///     `BindingPattern`[?Yield, ?Await] `Initializer`[+In, ?Yield, ?Await]opt
     pub(crate) fn parse_binding_pattern(&mut self) -> Result<BindingPattern<'a>> {
+        self.nested(Self::parse_binding_pattern_impl)
+    }
+
+    fn parse_binding_pattern_impl(&mut self) -> Result<BindingPattern<'a>> {
         let span = self.start_span();
         let pattern = self.parse_binding()?.0;
         self.with_context(Context::In, |p| p.parse_initializer(span, pattern))
@@ -458,9 +458,14 @@ impl<'a> ParserImpl<'a> {
         let has_yield = self.ctx.has_yield();
         let has_return = self.ctx.has_return();
         self.ctx = self.ctx.and_await(true).and_yield(false).and_return(false);
-        let block = self.parse_block()?;
+        let body = if self.declaration_file {
+            self.skip_block()?;
+            self.ast.new_vec()
+        } else {
+            self.parse_block()?.unbox().body
+        };
         self.ctx = self.ctx.and_await(has_await).and_yield(has_yield).and_return(has_return);
-        Ok(self.ast.static_block(self.end_span(span), block.unbox().body))
+        Ok(self.ast.static_block(self.end_span(span), body))
     }
 
     /// <https://github.com/tc39/proposal-decorators>
@@ -46,11 +46,36 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
-    pub(crate) fn parse_using(&mut self) -> Result<Statement<'a>> {
+    /// `using [no LineTerminator here] BindingIdentifier` or
+    /// `await [no LineTerminator here] using [no LineTerminator here] BindingIdentifier`,
+    /// otherwise `using` is an identifier, e.g. `using\nx = 1` is two expression statements.
+    pub(crate) fn at_using_declaration(&mut self) -> bool {
+        match self.cur_kind() {
+            Kind::Using => {
+                let binding = self.peek_token();
+                binding.kind.is_binding_identifier() && !binding.is_on_new_line
+            }
+            Kind::Await => {
+                let using = self.peek_token();
+                let binding = self.nth(2);
+                using.kind == Kind::Using
+                    && !using.is_on_new_line
+                    && binding.kind.is_binding_identifier()
+                    && !binding.is_on_new_line
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn parse_using(&mut self, stmt_ctx: StatementContext) -> Result<Statement<'a>> {
         let using_decl = self.parse_using_declaration(StatementContext::StatementList)?;
 
         self.asi()?;
 
+        if stmt_ctx.is_single_statement() {
+            self.error(diagnostics::LexicalDeclarationSingleStatement(using_decl.span));
+        }
+
         Ok(Statement::Declaration(Declaration::UsingDeclaration(self.ast.alloc(using_decl))))
     }
 
@@ -125,6 +150,9 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
 
         let is_await = self.eat(Kind::Await);
+        if is_await && !self.ctx.has_await() {
+            self.error(diagnostics::AwaitUsingDeclarationNotAllowed(self.end_span(span)));
+        }
 
         self.expect(Kind::Using)?;
 
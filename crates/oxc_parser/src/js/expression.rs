@@ -168,6 +168,7 @@ impl<'a> ParserImpl<'a> {
                 self.parse_template_literal_expression(false)
             }
             Kind::New => self.parse_new_expression(),
+            Kind::Do => self.parse_do_expression(),
             Kind::Super => Ok(self.parse_super()),
             Kind::Import => {
                 let span = self.start_span();
@@ -185,10 +186,36 @@ impl<'a> ParserImpl<'a> {
             }
             // JSXElement, JSXFragment
             Kind::LAngle if self.source_type.is_jsx() => self.parse_jsx_expression(),
+            _ if self.at_missing_expression() => Ok(self.parse_error_expression()),
             _ => self.parse_identifier_expression(),
         }
     }
 
+    /// Whether an expression is expected before a token which ends one, e.g. in `a = ;`,
+    /// and is recovered from, see [`crate::Parser::error_recovery`]
+    fn at_missing_expression(&self) -> bool {
+        self.error_recovery
+            && self.speculation_depth == 0
+            && matches!(
+                self.cur_kind(),
+                Kind::Semicolon
+                    | Kind::Comma
+                    | Kind::Colon
+                    | Kind::RParen
+                    | Kind::RBrack
+                    | Kind::RCurly
+                    | Kind::Eof
+            )
+    }
+
+    /// Report the missing expression and stand in for it without consuming the current token
+    fn parse_error_expression(&mut self) -> Expression<'a> {
+        let error = self.unexpected();
+        self.error(error);
+        let start = self.cur_token().start;
+        self.ast.error_expression(Span::new(start, start))
+    }
+
     fn parse_parenthesized_expression(&mut self, span: Span) -> Result<Expression<'a>> {
         let has_in = self.ctx.has_in();
         self.ctx = self.ctx.and_in(true);
@@ -334,9 +361,10 @@ impl<'a> ParserImpl<'a> {
             return Err(self.unexpected());
         }
         let value = self.cur_string();
+        let escaped = self.cur_token().escaped();
         let span = self.start_span();
         self.bump_any();
-        Ok(StringLiteral { span: self.end_span(span), value: value.into() })
+        Ok(StringLiteral { span: self.end_span(span), value: value.into(), escaped })
     }
 
     /// Section [Array Expression](https://tc39.es/ecma262/#prod-ArrayLiteral)
@@ -377,7 +405,7 @@ impl<'a> ParserImpl<'a> {
                 // TemplateHead Expression[+In, ?Yield, ?Await]
                 let expr = self.with_context(Context::In, Self::parse_expression)?;
                 expressions.push(expr);
-                self.re_lex_template_substitution_tail();
+                self.expect_template_substitution_end()?;
                 loop {
                     match self.cur_kind() {
                         Kind::Eof => self.expect(Kind::TemplateTail)?,
@@ -392,7 +420,7 @@ impl<'a> ParserImpl<'a> {
                             // TemplateMiddle Expression[+In, ?Yield, ?Await]
                             let expr = self.with_context(Context::In, Self::parse_expression)?;
                             expressions.push(expr);
-                            self.re_lex_template_substitution_tail();
+                            self.expect_template_substitution_end()?;
                         }
                     }
                 }
@@ -402,6 +430,16 @@ impl<'a> ParserImpl<'a> {
         Ok(TemplateLiteral { span: self.end_span(span), quasis, expressions })
     }
 
+    /// The `}` ending the expression of a substitution, re-lexed as the rest of the template,
+    /// e.g. not `c` in `${b c}`
+    fn expect_template_substitution_end(&mut self) -> Result<()> {
+        if !matches!(self.cur_kind(), Kind::RCurly | Kind::Eof) {
+            return Err(self.unexpected());
+        }
+        self.re_lex_template_substitution_tail();
+        Ok(())
+    }
+
     fn parse_template_literal_expression(&mut self, tagged: bool) -> Result<Expression<'a>> {
         self.parse_template_literal(tagged)
             .map(|template_literal| self.ast.template_literal_expression(template_literal))
@@ -462,7 +500,10 @@ impl<'a> ParserImpl<'a> {
         TemplateElement {
             span,
             tail,
-            value: TemplateElementValue { raw, cooked: cooked.map(Atom::from) },
+            value: TemplateElementValue {
+                raw,
+                cooked: if self.cooked_templates { cooked.map(Atom::from) } else { None },
+            },
         }
     }
 
@@ -792,6 +833,22 @@ impl<'a> ParserImpl<'a> {
         ))
     }
 
+    /// Do expressions proposal
+    /// `PrimaryExpression` : do `Block`
+    fn parse_do_expression(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        if !self.do_expressions {
+            self.error(diagnostics::ProposalNotEnabled(
+                "do expressions",
+                "do_expressions",
+                self.cur_token().span(),
+            ));
+        }
+        self.bump_any(); // bump `do`
+        let body = self.parse_block()?;
+        Ok(self.ast.do_expression(self.end_span(span), body))
+    }
+
     /// Section 13.4 Update Expression
     fn parse_update_expression(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
@@ -808,6 +865,10 @@ impl<'a> ParserImpl<'a> {
 
     /// Section 13.5 Unary Expression
     pub(crate) fn parse_unary_expression_base(&mut self, lhs_span: Span) -> Result<Expression<'a>> {
+        self.nested(|p| p.parse_unary_expression_base_impl(lhs_span))
+    }
+
+    fn parse_unary_expression_base_impl(&mut self, lhs_span: Span) -> Result<Expression<'a>> {
         // [+Await] AwaitExpression
         if self.is_await_expression() {
             return self.parse_await_expression(lhs_span);
@@ -839,6 +900,10 @@ impl<'a> ParserImpl<'a> {
             return self.parse_unary_expression();
         }
 
+        if self.at(Kind::Throw) {
+            return self.parse_throw_expression();
+        }
+
         self.parse_update_expression()
     }
 
@@ -850,6 +915,22 @@ impl<'a> ParserImpl<'a> {
         Ok(self.ast.unary_expression(self.end_span(span), operator, argument))
     }
 
+    /// Throw expressions proposal
+    /// `UnaryExpression` : throw `UnaryExpression`
+    fn parse_throw_expression(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        if !self.throw_expressions {
+            self.error(diagnostics::ProposalNotEnabled(
+                "throw expressions",
+                "throw_expressions",
+                self.cur_token().span(),
+            ));
+        }
+        self.bump_any(); // bump `throw`
+        let argument = self.parse_unary_expression_base(span)?;
+        Ok(self.ast.throw_expression(self.end_span(span), argument))
+    }
+
     fn parse_binary_or_logical_expression_base(
         &mut self,
         lhs_precedence: Precedence,
@@ -922,6 +1003,14 @@ impl<'a> ParserImpl<'a> {
                 continue;
             }
 
+            if kind == Kind::PipeGt && !self.pipeline_operator {
+                self.error(diagnostics::ProposalNotEnabled(
+                    "pipeline operator",
+                    "pipeline_operator",
+                    self.cur_token().span(),
+                ));
+            }
+
             self.bump_any(); // bump operator
             let rhs = self.parse_binary_or_logical_expression_base(left_precedence)?;
 
@@ -932,6 +1021,8 @@ impl<'a> ParserImpl<'a> {
                     map_logical_operator(kind),
                     rhs,
                 )
+            } else if kind == Kind::PipeGt {
+                self.ast.pipeline_expression(self.end_span(lhs_span), lhs, rhs)
             } else if kind.is_binary_operator() {
                 self.ast.binary_expression(
                     self.end_span(lhs_span),
@@ -969,6 +1060,10 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_assignment_expression_base(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_assignment_expression_base_impl)
+    }
+
+    fn parse_assignment_expression_base_impl(&mut self) -> Result<Expression<'a>> {
         match self.is_parenthesized_arrow_function() {
             IsParenthesizedArrowFunction::True => {
                 return self.parse_parenthesized_arrow_function();
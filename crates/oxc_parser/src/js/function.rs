@@ -65,6 +65,71 @@ impl<'a> ParserImpl<'a> {
         Ok(self.ast.function_body(self.end_span(span), directives, statements))
     }
 
+    /// Skip over a function body by matching its braces, see [`crate::Parser::lazy_function_bodies`]
+    /// and [`crate::Parser::declaration_file`]
+    fn skip_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+        let span = self.skip_block()?;
+        // Bodies of a declaration file are never parsed
+        if !self.declaration_file {
+            self.deferred_bodies.push(span);
+        }
+        Ok(self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec()))
+    }
+
+    /// Skip over a `{ ... }` block by matching its braces
+    pub(crate) fn skip_block(&mut self) -> Result<Span> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+
+        // Whether each open brace is the `${` of a template literal
+        let mut braces = vec![];
+        let mut prev_kind = Kind::LCurly;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof => return Err(self.unexpected()),
+                Kind::LCurly => braces.push(false),
+                Kind::TemplateHead => braces.push(true),
+                Kind::RCurly => match braces.pop() {
+                    None => break,
+                    Some(true) => {
+                        self.re_lex_template_substitution_tail();
+                        if self.at(Kind::TemplateMiddle) {
+                            braces.push(true);
+                        }
+                    }
+                    Some(false) => {}
+                },
+                Kind::Slash | Kind::SlashEq if !Self::ends_operand(prev_kind) => {
+                    self.read_regex();
+                }
+                _ => {}
+            }
+            prev_kind = self.cur_kind();
+            self.bump_any();
+        }
+
+        self.expect(Kind::RCurly)?;
+        Ok(self.end_span(span))
+    }
+
+    /// Whether `/` after a token of `kind` is a division rather than a regular expression
+    pub(crate) fn ends_operand(kind: Kind) -> bool {
+        kind.is_literal()
+            || kind.is_identifier()
+            || matches!(
+                kind,
+                Kind::This
+                    | Kind::Super
+                    | Kind::RParen
+                    | Kind::RBrack
+                    | Kind::RCurly
+                    | Kind::NoSubstitutionTemplate
+                    | Kind::TemplateTail
+                    | Kind::Plus2
+                    | Kind::Minus2
+            )
+    }
+
     pub(crate) fn parse_formal_parameters(
         &mut self,
         params_kind: FormalParameterKind,
@@ -96,7 +161,13 @@ impl<'a> ParserImpl<'a> {
 
         let return_type = self.parse_ts_return_type_annotation()?;
 
-        let body = if self.at(Kind::LCurly) { Some(self.parse_function_body()?) } else { None };
+        let body = if !self.at(Kind::LCurly) {
+            None
+        } else if self.lazy_function_bodies || self.declaration_file {
+            Some(self.skip_function_body()?)
+        } else {
+            Some(self.parse_function_body()?)
+        };
 
         self.ctx =
             self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
@@ -245,6 +316,8 @@ impl<'a> ParserImpl<'a> {
             let span = expr.span();
             let expr_stmt = self.ast.expression_statement(span, expr);
             self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(expr_stmt))
+        } else if self.declaration_file {
+            self.skip_function_body()?
         } else {
             self.parse_function_body()?
         };
@@ -509,6 +582,8 @@ impl<'a> ParserImpl<'a> {
             let span = expr.span();
             let expr_stmt = self.ast.expression_statement(span, expr);
             self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(expr_stmt))
+        } else if self.declaration_file {
+            self.skip_function_body()?
         } else {
             self.parse_function_body()?
         };
@@ -2,6 +2,7 @@ use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
 use oxc_diagnostics::Result;
 use oxc_span::Span;
+use oxc_syntax::module_record::ImportPhase;
 
 use super::{
     function::FunctionKind,
@@ -49,11 +50,26 @@ impl<'a> ParserImpl<'a> {
         // `import type ...`
         let import_kind = self.parse_import_or_export_kind();
 
-        let specifiers = if self.at(Kind::Str) {
+        let phase = if import_kind.is_value() { self.parse_import_phase() } else { None };
+
+        let specifiers = match phase {
+            // import source x from "source"
+            Some(ImportPhase::Source) => {
+                let mut specifiers = self.ast.new_vec();
+                specifiers.push(self.parse_import_default_specifier()?);
+                self.expect(Kind::From)?;
+                Some(specifiers)
+            }
+            // import defer * as ns from "source"
+            Some(ImportPhase::Defer) => {
+                let mut specifiers = self.ast.new_vec();
+                specifiers.push(self.parse_import_namespace_specifier()?);
+                self.expect(Kind::From)?;
+                Some(specifiers)
+            }
             // import "source"
-            None
-        } else {
-            Some(self.parse_import_declaration_specifiers()?)
+            None if self.at(Kind::Str) => None,
+            None => Some(self.parse_import_declaration_specifiers()?),
         };
 
         let source = self.parse_literal_string()?;
@@ -66,10 +82,32 @@ impl<'a> ParserImpl<'a> {
             source,
             with_clause,
             import_kind,
+            phase,
         ));
         Ok(self.ast.module_declaration(decl))
     }
 
+    /// [Source Phase Imports](https://github.com/tc39/proposal-source-phase-imports) and
+    /// [Deferred Imports](https://github.com/tc39/proposal-defer-import-eval)
+    fn parse_import_phase(&mut self) -> Option<ImportPhase> {
+        match self.cur_kind() {
+            // `import source from "x"` imports the default export as `source`,
+            // `import source from from "x"` imports the source of "x" as `from`
+            Kind::Source
+                if self.peek_kind().is_binding_identifier()
+                    && (!self.peek_at(Kind::From) || self.nth_at(2, Kind::From)) =>
+            {
+                self.bump_any();
+                Some(ImportPhase::Source)
+            }
+            Kind::Defer if self.peek_at(Kind::Star) => {
+                self.bump_any();
+                Some(ImportPhase::Defer)
+            }
+            _ => None,
+        }
+    }
+
     // Full Syntax: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#syntax>
     fn parse_import_declaration_specifiers(
         &mut self,
@@ -234,11 +272,11 @@ impl<'a> ParserImpl<'a> {
         let specifiers = ExportNamedSpecifiers::parse(self)?.elements;
         self.ctx = ctx;
 
-        let source = if self.eat(Kind::From) && self.cur_kind().is_literal() {
+        let (source, with_clause) = if self.eat(Kind::From) && self.cur_kind().is_literal() {
             let source = self.parse_literal_string()?;
-            Some(source)
+            (Some(source), self.parse_import_attributes()?)
         } else {
-            None
+            (None, None)
         };
 
         // ExportDeclaration : export NamedExports ;
@@ -274,7 +312,14 @@ impl<'a> ParserImpl<'a> {
 
         self.asi()?;
         let span = self.end_span(span);
-        Ok(self.ast.export_named_declaration(span, None, specifiers, source, export_kind))
+        Ok(self.ast.export_named_declaration(
+            span,
+            None,
+            specifiers,
+            source,
+            with_clause,
+            export_kind,
+        ))
     }
 
     // export Declaration
@@ -299,6 +344,7 @@ impl<'a> ParserImpl<'a> {
             Some(declaration),
             self.ast.new_vec(),
             None,
+            None,
             ImportOrExportKind::Value,
         ))
     }
@@ -2,32 +2,19 @@ use oxc_syntax::{
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
     },
-    precedence::Precedence,
+    precedence::{GetPrecedence, Precedence},
 };
 
 use crate::lexer::Kind;
 
+/// Precedence of the binary operator `kind`, from the precedence of the operator in `oxc_syntax`
 pub fn kind_to_precedence(kind: Kind) -> Option<Precedence> {
     match kind {
-        Kind::Question2 => Some(Precedence::Coalesce),
-        Kind::Pipe2 => Some(Precedence::LogicalOr),
-        Kind::Amp2 => Some(Precedence::LogicalAnd),
-        Kind::Pipe => Some(Precedence::BitwiseOr),
-        Kind::Caret => Some(Precedence::BitwiseXor),
-        Kind::Amp => Some(Precedence::BitwiseAnd),
-        Kind::Eq2 | Kind::Eq3 | Kind::Neq | Kind::Neq2 => Some(Precedence::Equality),
-        Kind::LAngle
-        | Kind::RAngle
-        | Kind::LtEq
-        | Kind::GtEq
-        | Kind::Instanceof
-        | Kind::In
-        | Kind::As
-        | Kind::Satisfies => Some(Precedence::Relational),
-        Kind::ShiftLeft | Kind::ShiftRight | Kind::ShiftRight3 => Some(Precedence::Shift),
-        Kind::Plus | Kind::Minus => Some(Precedence::Add),
-        Kind::Star | Kind::Slash | Kind::Percent => Some(Precedence::Multiply),
-        Kind::Star2 => Some(Precedence::Exponential),
+        Kind::PipeGt => Some(Precedence::Pipeline),
+        // TypeScript `as` and `satisfies` bind like relational operators
+        Kind::As | Kind::Satisfies => Some(Precedence::Relational),
+        _ if kind.is_logical_operator() => Some(map_logical_operator(kind).precedence()),
+        _ if kind.is_binary_operator() => Some(map_binary_operator(kind).precedence()),
         _ => None,
     }
 }
@@ -1,6 +1,6 @@
 use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
-use oxc_diagnostics::Result;
+use oxc_diagnostics::{CancellationToken, Cancelled, Result};
 use oxc_span::{Atom, Span};
 
 use super::{
@@ -36,18 +36,29 @@ impl<'a> ParserImpl<'a> {
     ) -> Result<(Vec<'a, Directive>, Vec<'a, Statement<'a>>)> {
         let mut directives = self.ast.new_vec();
         let mut statements = self.ast.new_vec();
+        self.parse_directives_and_statements_into(is_top_level, &mut directives, &mut statements)?;
+        Ok((directives, statements))
+    }
 
-        let mut expecting_directives = true;
+    /// Continue parsing a statement list after `statements`, see [`ParserImpl::reparse`].
+    /// Directives are only expected before the first statement.
+    pub(crate) fn parse_directives_and_statements_into(
+        &mut self,
+        is_top_level: bool,
+        directives: &mut Vec<'a, Directive>,
+        statements: &mut Vec<'a, Statement<'a>>,
+    ) -> Result<()> {
+        let mut expecting_directives = statements.is_empty();
         while !self.at(Kind::Eof) {
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
-                    let stmt = self.parse_import_declaration()?;
+                    let stmt = self.recover_statement(ParserImpl::parse_import_declaration)?;
                     statements.push(stmt);
                     expecting_directives = false;
                 }
                 Kind::Export => {
-                    let stmt = self.parse_export_declaration()?;
+                    let stmt = self.recover_statement(ParserImpl::parse_export_declaration)?;
                     statements.push(stmt);
                     expecting_directives = false;
                 }
@@ -57,7 +68,9 @@ impl<'a> ParserImpl<'a> {
                     continue;
                 }
                 _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+                    let stmt = self.recover_statement(|p| {
+                        p.parse_statement_list_item(StatementContext::StatementList)
+                    })?;
 
                     // Section 11.2.1 Directive Prologue
                     // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -87,7 +100,7 @@ impl<'a> ParserImpl<'a> {
             };
         }
 
-        Ok((directives, statements))
+        Ok(())
     }
 
     /// `StatementListItem`[Yield, Await, Return] :
@@ -96,6 +109,16 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        if self.cancellation_token.is_some_and(CancellationToken::is_cancelled) {
+            return Err(Cancelled.into());
+        }
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -128,12 +151,7 @@ impl<'a> ParserImpl<'a> {
                 self.parse_variable_statement(stmt_ctx)
             }
             Kind::Let if !self.cur_token().escaped() => self.parse_let(stmt_ctx),
-            Kind::Await
-                if self.peek_kind() == Kind::Using && self.nth_kind(2).is_binding_identifier() =>
-            {
-                self.parse_using()
-            }
-            Kind::Using if self.peek_kind().is_binding_identifier() => self.parse_using(),
+            Kind::Await | Kind::Using if self.at_using_declaration() => self.parse_using(stmt_ctx),
             _ if self.at_function_with_async() => self.parse_function_declaration(stmt_ctx),
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
@@ -163,7 +181,9 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.recover_statement(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            })?;
             body.push(stmt);
         }
         self.expect(Kind::RCurly)?;
@@ -231,7 +251,9 @@ impl<'a> ParserImpl<'a> {
         let body = self.parse_statement_list_item(StatementContext::Do)?;
         self.expect(Kind::While)?;
         let test = self.parse_paren_expression()?;
-        self.bump(Kind::Semicolon);
+        if !self.eat(Kind::Semicolon) {
+            self.add_inserted_semicolon();
+        }
         Ok(self.ast.do_while_statement(self.end_span(span), body, test))
     }
 
@@ -268,9 +290,8 @@ impl<'a> ParserImpl<'a> {
             return self.parse_variable_declaration_for_statement(span, r#await);
         }
 
-        if (self.cur_kind() == Kind::Await && self.peek_kind() == Kind::Using)
-            || (self.cur_kind() == Kind::Using && self.peek_kind() == Kind::Ident)
-        {
+        // `for (using of x)` iterates into the variable `using`
+        if self.at_using_declaration() && !(self.at(Kind::Using) && self.peek_at(Kind::Of)) {
             return self.parse_using_declaration_for_statement(span, r#await);
         }
 
@@ -430,7 +451,10 @@ impl<'a> ParserImpl<'a> {
     fn parse_return_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
         self.bump_any(); // advance `return`
-        let argument = if self.eat(Kind::Semicolon) || self.can_insert_semicolon() {
+        let argument = if self.eat(Kind::Semicolon) {
+            None
+        } else if self.can_insert_semicolon() {
+            self.add_inserted_semicolon();
             None
         } else {
             let expr = self.with_context(Context::In, ParserImpl::parse_expression)?;
@@ -486,7 +510,9 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::Colon)?;
         let mut consequent = self.ast.new_vec();
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly | Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.recover_statement(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            })?;
             consequent.push(stmt);
         }
         Ok(self.ast.switch_case(self.end_span(span), test, consequent))
@@ -0,0 +1,375 @@
+//! JSDoc type expressions, see [`Parser::parse_jsdoc_type`](crate::Parser::parse_jsdoc_type)
+//!
+//! The Closure Compiler syntax is parsed into the TypeScript type nodes TypeScript uses for
+//! JavaScript files, types without a TypeScript equivalent are approximated:
+//!
+//! * `?T` and `T?` are [`JSDocNullableType`](oxc_ast::ast::JSDocNullableType), `?` alone is
+//!   [`JSDocUnknownType`](oxc_ast::ast::JSDocUnknownType) and `*` is `any`
+//! * `!T` is `T`, types are not nullable by default
+//! * `T=` of an optional parameter is `T | undefined` and `...T` of a rest parameter is `T[]`
+//! * `Array.<T>` is `Array<T>`
+//! * `function(this:T, number, string=): boolean` is a function type with the parameters
+//!   `arg0` and `arg1`, `function(new:T)` is a constructor type returning `T`,
+//!   the return type is `any` when it is missing
+
+use oxc_allocator::Box;
+use oxc_ast::ast::{
+    BindingIdentifier, FormalParameterKind, IdentifierName, IdentifierReference, TSQualifiedName,
+    TSType, TSTypeName, TSTypeParameterInstantiation,
+};
+use oxc_diagnostics::Result;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    lexer::Kind,
+    list::{NormalList, SeparatedList},
+    ParserImpl, TypeReturn,
+};
+
+impl<'a> ParserImpl<'a> {
+    /// See [`Parser::parse_jsdoc_type`](crate::Parser::parse_jsdoc_type)
+    pub(crate) fn parse_single_jsdoc_type(mut self) -> TypeReturn<'a> {
+        let (type_annotation, panicked) = match self.parse_fragment(Self::parse_jsdoc_type) {
+            Some(type_annotation) => (type_annotation, false),
+            None => (self.ast.js_doc_unknown_type(Span::default()), true),
+        };
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        let trivias = self.lexer.trivia_builder.build(self.source_text);
+        TypeReturn { type_annotation, errors, trivias, panicked }
+    }
+
+    /// `A|B`
+    pub(crate) fn parse_jsdoc_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        let first = self.parse_jsdoc_postfix_type()?;
+        if !self.at(Kind::Pipe) {
+            return Ok(first);
+        }
+        let mut types = self.ast.new_vec_single(first);
+        while self.eat(Kind::Pipe) {
+            types.push(self.parse_jsdoc_postfix_type()?);
+        }
+        Ok(self.ast.ts_union_type(self.end_span(span), types))
+    }
+
+    /// `T=`, `T?`, `T!` and `T[]`
+    fn parse_jsdoc_postfix_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        let mut ty = self.parse_jsdoc_prefix_type()?;
+        loop {
+            match self.cur_kind() {
+                Kind::Eq => {
+                    self.bump_any();
+                    let undefined = self.ast.ts_undefined_keyword(self.end_span(span));
+                    let mut types = self.ast.new_vec_single(ty);
+                    types.push(undefined);
+                    ty = self.ast.ts_union_type(self.end_span(span), types);
+                }
+                Kind::Question => {
+                    self.bump_any();
+                    ty = self.ast.js_doc_nullable_type(
+                        self.end_span(span),
+                        ty,
+                        /* postfix */ true,
+                    );
+                }
+                Kind::Bang => self.bump_any(),
+                Kind::LBrack if self.peek_at(Kind::RBrack) => {
+                    self.bump_any();
+                    self.bump_any();
+                    ty = self.ast.ts_array_type(self.end_span(span), ty);
+                }
+                _ => return Ok(ty),
+            }
+        }
+    }
+
+    /// `?T`, `?`, `!T`, `...T`, `*`, `function(...)`, `(T)` and the TypeScript types
+    fn parse_jsdoc_prefix_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        match self.cur_kind() {
+            Kind::Question => {
+                self.bump_any();
+                if matches!(
+                    self.cur_kind(),
+                    Kind::Eof
+                        | Kind::Comma
+                        | Kind::RCurly
+                        | Kind::RParen
+                        | Kind::RAngle
+                        | Kind::RBrack
+                        | Kind::Eq
+                        | Kind::Pipe
+                ) {
+                    return Ok(self.ast.js_doc_unknown_type(self.end_span(span)));
+                }
+                let ty = self.parse_jsdoc_prefix_type()?;
+                Ok(self.ast.js_doc_nullable_type(self.end_span(span), ty, /* postfix */ false))
+            }
+            Kind::Bang => {
+                self.bump_any();
+                self.parse_jsdoc_prefix_type()
+            }
+            Kind::Dot3 => {
+                self.bump_any();
+                let ty = self.parse_jsdoc_prefix_type()?;
+                Ok(self.ast.ts_array_type(self.end_span(span), ty))
+            }
+            Kind::Star => {
+                self.bump_any();
+                Ok(self.ast.ts_any_keyword(self.end_span(span)))
+            }
+            Kind::Function if self.peek_at(Kind::LParen) => self.parse_jsdoc_function_type(),
+            Kind::LParen => {
+                self.bump_any();
+                let ty = self.parse_jsdoc_type()?;
+                self.expect(Kind::RParen)?;
+                Ok(ty)
+            }
+            kind if kind.is_identifier_name()
+                && !kind.is_literal()
+                && !matches!(kind, Kind::Void | Kind::This | Kind::Typeof | Kind::Import) =>
+            {
+                if !self.peek_at(Kind::Dot) {
+                    if let Some(keyword) = self.parse_ts_keyword_type() {
+                        return Ok(keyword);
+                    }
+                }
+                self.parse_jsdoc_reference_type()
+            }
+            _ => self.parse_ts_basic_type(),
+        }
+    }
+
+    /// `Array.<T>`, `Array<T>` and `ns.Type`
+    fn parse_jsdoc_reference_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        let ident = self.parse_identifier_name()?;
+        let ident = IdentifierReference::new(ident.span, ident.name);
+        let mut type_name = TSTypeName::IdentifierReference(self.ast.alloc(ident));
+        while self.at(Kind::Dot) && !self.peek_at(Kind::LAngle) {
+            self.bump_any();
+            let right = self.parse_identifier_name()?;
+            type_name = TSTypeName::QualifiedName(self.ast.alloc(TSQualifiedName {
+                span: self.end_span(span),
+                left: type_name,
+                right,
+            }));
+        }
+        if self.at(Kind::Dot) {
+            self.bump_any();
+        }
+        let type_parameters = self.parse_jsdoc_type_arguments()?;
+        Ok(self.ast.ts_type_reference(self.end_span(span), type_name, type_parameters))
+    }
+
+    fn parse_jsdoc_type_arguments(
+        &mut self,
+    ) -> Result<Option<Box<'a, TSTypeParameterInstantiation<'a>>>> {
+        self.re_lex_ts_l_angle();
+        if !self.at(Kind::LAngle) {
+            return Ok(None);
+        }
+        let span = self.start_span();
+        let params = JSDocTypeArgumentList::parse(self)?.params;
+        Ok(Some(self.ast.ts_type_arguments(self.end_span(span), params)))
+    }
+
+    /// `function(this:T, number, string=, ...boolean): void` and `function(new:T)`
+    fn parse_jsdoc_function_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `function`
+        let params_span = self.start_span();
+        let mut list = JSDocParameterList { this: None, new: None, params: vec![] };
+        list.parse(self)?;
+        let return_span = self.start_span();
+        let return_type = if self.eat(Kind::Colon) {
+            self.parse_jsdoc_postfix_type()?
+        } else {
+            self.ast.ts_any_keyword(self.end_span(return_span))
+        };
+        let params_span = self.end_span(params_span);
+
+        let mut items = self.ast.new_vec();
+        let mut rest = None;
+        for (index, param) in list.params.into_iter().enumerate() {
+            let name = BindingIdentifier::new(param.span, format!("arg{index}").into());
+            let type_annotation = Some(self.ast.ts_type_annotation(param.span, param.ty));
+            let pattern = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(name),
+                type_annotation,
+                param.optional,
+            );
+            if param.rest {
+                rest = Some(self.ast.rest_element(param.span, pattern));
+            } else {
+                items.push(self.ast.formal_parameter(
+                    param.span,
+                    pattern,
+                    None,
+                    false,
+                    self.ast.new_vec(),
+                ));
+            }
+        }
+        let params =
+            self.ast.formal_parameters(params_span, FormalParameterKind::Signature, items, rest);
+
+        if let Some(new) = list.new {
+            let return_type = self.ast.ts_type_annotation(new.span(), new);
+            return Ok(self.ast.ts_constructor_type(
+                self.end_span(span),
+                false,
+                params,
+                return_type,
+                None,
+            ));
+        }
+        let this_param = list.this.map(|(this_span, ty)| {
+            let this = IdentifierName::new(this_span, "this".into());
+            let type_annotation = self.ast.ts_type_annotation(ty.span(), ty);
+            self.ast.ts_this_parameter(this_span, this, Some(type_annotation))
+        });
+        let return_type = self.ast.ts_type_annotation(self.end_span(return_span), return_type);
+        Ok(self.ast.ts_function_type(self.end_span(span), this_param, params, return_type, None))
+    }
+}
+
+struct JSDocTypeArgumentList<'a> {
+    params: oxc_allocator::Vec<'a, TSType<'a>>,
+}
+
+impl<'a> SeparatedList<'a> for JSDocTypeArgumentList<'a> {
+    fn new(p: &ParserImpl<'a>) -> Self {
+        Self { params: p.ast.new_vec() }
+    }
+
+    fn open(&self) -> Kind {
+        Kind::LAngle
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RAngle
+    }
+
+    fn parse_element(&mut self, p: &mut ParserImpl<'a>) -> Result<()> {
+        let ty = p.parse_jsdoc_type()?;
+        self.params.push(ty);
+        Ok(())
+    }
+}
+
+/// The parameters of `function(this:T, new:T, ...)`
+struct JSDocParameterList<'a> {
+    this: Option<(Span, TSType<'a>)>,
+    new: Option<TSType<'a>>,
+    params: Vec<JSDocParameter<'a>>,
+}
+
+struct JSDocParameter<'a> {
+    span: Span,
+    ty: TSType<'a>,
+    /// `...T`
+    rest: bool,
+    /// `T=`
+    optional: bool,
+}
+
+impl<'a> NormalList<'a> for JSDocParameterList<'a> {
+    fn open(&self) -> Kind {
+        Kind::LParen
+    }
+
+    fn close(&self) -> Kind {
+        Kind::RParen
+    }
+
+    fn parse_element(&mut self, p: &mut ParserImpl<'a>) -> Result<()> {
+        let span = p.start_span();
+        match p.cur_kind() {
+            Kind::This if p.peek_at(Kind::Colon) => {
+                p.bump_any();
+                p.bump_any();
+                let ty = p.parse_jsdoc_type()?;
+                self.this = Some((p.end_span(span), ty));
+            }
+            Kind::New if p.peek_at(Kind::Colon) => {
+                p.bump_any();
+                p.bump_any();
+                self.new = Some(p.parse_jsdoc_type()?);
+            }
+            _ => {
+                let rest = p.at(Kind::Dot3);
+                let ty = p.parse_jsdoc_type()?;
+                let span = p.end_span(span);
+                let optional = span.source_text(p.source_text).ends_with('=');
+                self.params.push(JSDocParameter { span, ty, rest, optional });
+            }
+        }
+        if !p.at(Kind::RParen) {
+            p.expect(Kind::Comma)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::TSType;
+    use oxc_span::SourceType;
+
+    use crate::Parser;
+
+    #[test]
+    fn jsdoc_types() {
+        let allocator = Allocator::default();
+        let parse = |source_text: &'static str| {
+            let ret =
+                Parser::new(&allocator, source_text, SourceType::default()).parse_jsdoc_type();
+            assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+            ret.type_annotation
+        };
+
+        let TSType::JSDocNullableType(ty) = parse("?string") else { unreachable!() };
+        assert!(!ty.postfix);
+        assert!(matches!(ty.type_annotation, TSType::TSStringKeyword(_)));
+        assert!(matches!(parse("string?"), TSType::JSDocNullableType(ty) if ty.postfix));
+        assert!(matches!(parse("?"), TSType::JSDocUnknownType(_)));
+        assert!(matches!(parse("*"), TSType::TSAnyKeyword(_)));
+        assert!(matches!(parse("!Object"), TSType::TSTypeReference(_)));
+        assert!(matches!(parse("number="), TSType::TSUnionType(ty) if ty.types.len() == 2));
+        assert!(matches!(parse("(string|number)[]"), TSType::TSArrayType(_)));
+        assert!(matches!(parse("{a: number, b}"), TSType::TSTypeLiteral(_)));
+
+        let TSType::TSTypeReference(ty) = parse("Array.<?string>") else { unreachable!() };
+        let params = &ty.type_parameters.as_ref().unwrap().params;
+        assert!(matches!(params[0], TSType::JSDocNullableType(_)));
+        let TSType::TSTypeReference(ty) = parse("Object<string, Array.<number>>") else {
+            unreachable!()
+        };
+        assert_eq!(ty.type_parameters.as_ref().unwrap().params.len(), 2);
+
+        let TSType::TSFunctionType(ty) =
+            parse("function(this:Window, number, string=, ...boolean): boolean")
+        else {
+            unreachable!()
+        };
+        assert!(ty.this_param.is_some());
+        assert_eq!(ty.params.items.len(), 2);
+        assert!(ty.params.items[1].pattern.optional);
+        assert!(ty.params.rest.is_some());
+        assert!(matches!(ty.return_type.type_annotation, TSType::TSBooleanKeyword(_)));
+        assert!(matches!(parse("function(new:Foo)"), TSType::TSConstructorType(_)));
+    }
+
+    #[test]
+    fn jsdoc_type_errors() {
+        let allocator = Allocator::default();
+        for source_text in ["function(", "Array.<string", "string string"] {
+            let ret =
+                Parser::new(&allocator, source_text, SourceType::default()).parse_jsdoc_type();
+            assert!(!ret.errors.is_empty(), "{source_text}");
+        }
+    }
+}
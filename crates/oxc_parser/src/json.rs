@@ -0,0 +1,220 @@
+//! JSON, JSONC and JSON5 documents, see [`Parser::parse_json`](crate::Parser::parse_json)
+//!
+//! The JavaScript lexer scans the tokens, which are a superset of those of all three dialects.
+//! Syntax of JavaScript which is not valid in the dialect is reported as a recoverable error.
+
+use oxc_ast::ast::{
+    IdentifierName, JsonArray, JsonObject, JsonProperty, JsonValue, NullLiteral, NumericLiteral,
+    StringLiteral,
+};
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+use oxc_syntax::NumberBase;
+
+use crate::{diagnostics, lexer::Kind, JsonReturn, ParserImpl};
+
+/// The dialect of JSON accepted by [`Parser::parse_json`](crate::Parser::parse_json)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonDialect {
+    /// [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259), e.g. `package.json`
+    #[default]
+    Json,
+    /// JSON with comments and trailing commas, e.g. `tsconfig.json`
+    Jsonc,
+    /// [JSON5](https://spec.json5.org): unquoted keys, single quoted strings,
+    /// hexadecimal numbers, `Infinity`, `NaN`, comments and trailing commas
+    Json5,
+}
+
+impl<'a> ParserImpl<'a> {
+    /// See [`Parser::parse_json`](crate::Parser::parse_json)
+    pub(crate) fn parse_json(mut self, dialect: JsonDialect) -> JsonReturn<'a> {
+        // Comments are collected to report them in strict JSON
+        self.lexer.trivia_builder.enabled = true;
+        let (value, panicked) = match self.parse_fragment(|p| p.parse_json_value(dialect)) {
+            Some(value) => (value, false),
+            None => (JsonValue::Null(NullLiteral::new(Span::default())), true),
+        };
+        let trivias = self.lexer.trivia_builder.build(self.source_text);
+        if dialect == JsonDialect::Json {
+            for &(start, end, _) in &trivias.comments {
+                self.errors
+                    .push(diagnostics::InvalidJson("comments", Span::new(start, end)).into());
+            }
+        }
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        JsonReturn { value, errors, trivias, panicked }
+    }
+
+    fn parse_json_value(&mut self, dialect: JsonDialect) -> Result<JsonValue<'a>> {
+        self.nested(|p| match p.cur_kind() {
+            Kind::Null => Ok(JsonValue::Null(p.parse_literal_null())),
+            Kind::True | Kind::False => p.parse_literal_boolean().map(JsonValue::Boolean),
+            Kind::Str => p.parse_json_string(dialect).map(JsonValue::String),
+            Kind::LBrack => p.parse_json_array(dialect),
+            Kind::LCurly => p.parse_json_object(dialect),
+            _ => p.parse_json_number(dialect).map(JsonValue::Number),
+        })
+    }
+
+    fn parse_json_string(&mut self, dialect: JsonDialect) -> Result<StringLiteral> {
+        if dialect != JsonDialect::Json5 && self.at(Kind::Str) {
+            if let Some(syntax) = non_json_string_syntax(self.cur_src()) {
+                self.error(diagnostics::InvalidJson(syntax, self.cur_token().span()));
+            }
+        }
+        self.parse_literal_string()
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_json_number(&mut self, dialect: JsonDialect) -> Result<NumericLiteral<'a>> {
+        let span = self.start_span();
+        let negative = self.at(Kind::Minus);
+        if negative || (dialect == JsonDialect::Json5 && self.at(Kind::Plus)) {
+            self.bump_any();
+            if self.prev_token_end != self.cur_token().start {
+                let span = Span::new(self.prev_token_end, self.cur_token().start);
+                self.error(diagnostics::InvalidJson("whitespace after a sign", span));
+            }
+        }
+        let (value, base) = if dialect == JsonDialect::Json5
+            && self.at(Kind::Ident)
+            && matches!(self.cur_src(), "Infinity" | "NaN")
+        {
+            let value = if self.cur_src() == "NaN" { f64::NAN } else { f64::INFINITY };
+            self.bump_any();
+            (value, NumberBase::Float)
+        } else if self.cur_kind().is_number() {
+            let is_valid = match dialect {
+                JsonDialect::Json | JsonDialect::Jsonc => is_json_number(self.cur_src()),
+                JsonDialect::Json5 => is_json5_number(self.cur_src()),
+            };
+            if !is_valid {
+                self.error(diagnostics::InvalidJson("this number syntax", self.cur_token().span()));
+            }
+            let literal = self.parse_literal_number()?;
+            (literal.value, literal.base)
+        } else {
+            return Err(self.unexpected());
+        };
+        let span = self.end_span(span);
+        let raw = &self.source_text[span.start as usize..span.end as usize];
+        Ok(NumericLiteral::new(span, if negative { -value } else { value }, raw, base))
+    }
+
+    fn parse_json_array(&mut self, dialect: JsonDialect) -> Result<JsonValue<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LBrack)?;
+        let mut elements = self.ast.new_vec();
+        while !self.at(Kind::RBrack) {
+            elements.push(self.parse_json_value(dialect)?);
+            if !self.eat(Kind::Comma) {
+                break;
+            }
+            self.check_json_trailing_comma(dialect, Kind::RBrack);
+        }
+        self.expect(Kind::RBrack)?;
+        let array = JsonArray { span: self.end_span(span), elements };
+        Ok(JsonValue::Array(self.ast.alloc(array)))
+    }
+
+    fn parse_json_object(&mut self, dialect: JsonDialect) -> Result<JsonValue<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let mut properties = self.ast.new_vec();
+        while !self.at(Kind::RCurly) {
+            let property_span = self.start_span();
+            let key = self.parse_json_key(dialect)?;
+            self.expect(Kind::Colon)?;
+            let value = self.parse_json_value(dialect)?;
+            properties.push(JsonProperty { span: self.end_span(property_span), key, value });
+            if !self.eat(Kind::Comma) {
+                break;
+            }
+            self.check_json_trailing_comma(dialect, Kind::RCurly);
+        }
+        self.expect(Kind::RCurly)?;
+        let object = JsonObject { span: self.end_span(span), properties };
+        Ok(JsonValue::Object(self.ast.alloc(object)))
+    }
+
+    fn parse_json_key(&mut self, dialect: JsonDialect) -> Result<StringLiteral> {
+        if dialect == JsonDialect::Json5 && self.cur_kind().is_identifier_name() {
+            let escaped = self.cur_token().escaped();
+            let IdentifierName { span, name } = self.parse_identifier_name()?;
+            return Ok(StringLiteral { span, value: name, escaped });
+        }
+        self.parse_json_string(dialect)
+    }
+
+    /// Report the comma just eaten if it is followed by `close` in strict JSON.
+    fn check_json_trailing_comma(&mut self, dialect: JsonDialect, close: Kind) {
+        if dialect == JsonDialect::Json && self.at(close) {
+            let span = Span::new(self.prev_token_end - 1, self.prev_token_end);
+            self.error(diagnostics::InvalidJson("trailing commas", span));
+        }
+    }
+}
+
+/// The JavaScript syntax used by `raw`, a string token, which is not valid in a JSON string
+fn non_json_string_syntax(raw: &str) -> Option<&'static str> {
+    if !raw.starts_with('"') {
+        return Some("single quoted strings");
+    }
+    let mut bytes = raw[1..raw.len() - 1].bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'\\' => match bytes.next() {
+                Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {}
+                Some(b'u')
+                    if (0..4).all(|_| bytes.next().is_some_and(|b| b.is_ascii_hexdigit())) => {}
+                _ => return Some("this escape sequence"),
+            },
+            0..=0x1F => return Some("unescaped control characters"),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether `raw`, a number token after the sign, is a JSON number, e.g. `0`, `12.5` or `1e-3`
+fn is_json_number(raw: &str) -> bool {
+    fn eat_digits(bytes: &mut &[u8]) -> usize {
+        let count = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        *bytes = &bytes[count..];
+        count
+    }
+    let mut bytes = raw.as_bytes();
+    let integer_digits = eat_digits(&mut bytes);
+    if integer_digits == 0 || (integer_digits > 1 && raw.starts_with('0')) {
+        return false;
+    }
+    if let [b'.', rest @ ..] = bytes {
+        bytes = rest;
+        if eat_digits(&mut bytes) == 0 {
+            return false;
+        }
+    }
+    if let [b'e' | b'E', rest @ ..] = bytes {
+        bytes = rest;
+        if let [b'+' | b'-', rest @ ..] = bytes {
+            bytes = rest;
+        }
+        if eat_digits(&mut bytes) == 0 {
+            return false;
+        }
+    }
+    bytes.is_empty()
+}
+
+/// Whether `raw`, a number token after the sign, is a JSON5 number,
+/// which excludes the octal, binary, `BigInt` and numeric separator syntax of JavaScript.
+fn is_json5_number(raw: &str) -> bool {
+    if let Some(digits) = raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        return !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit());
+    }
+    let integer_digits = raw.bytes().take_while(u8::is_ascii_digit).count();
+    let is_legacy_octal = integer_digits > 1 && raw.starts_with('0');
+    !is_legacy_octal
+        && raw.bytes().all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'))
+}
@@ -5,7 +5,7 @@
 use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
 use oxc_diagnostics::Result;
-use oxc_span::{Atom, Span};
+use oxc_span::{Atom, GetSpan, Span};
 
 use crate::{diagnostics, lexer::Kind, Context, ParserImpl};
 
@@ -21,10 +21,19 @@ impl<'a> ParserImpl<'a> {
     /// `JSXFragment` :
     ///   < > `JSXChildren_opt` < / >
     fn parse_jsx_fragment(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXFragment<'a>>> {
+        self.nested(|p| p.parse_jsx_fragment_impl(in_jsx_child))
+    }
+
+    fn parse_jsx_fragment_impl(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXFragment<'a>>> {
         let span = self.start_span();
         let opening_fragment = self.parse_jsx_opening_fragment(span)?;
-        let children = self.parse_jsx_children()?;
-        let closing_fragment = self.parse_jsx_closing_fragment(in_jsx_child)?;
+        let children = self.parse_jsx_children_of("")?;
+        let closing_fragment = if self.is_unclosed_jsx("") {
+            self.error(diagnostics::UnclosedJSXFragment(opening_fragment.span));
+            self.ast.jsx_closing_fragment(Span::new(self.prev_token_end, self.prev_token_end))
+        } else {
+            self.parse_jsx_closing_fragment(in_jsx_child)?
+        };
         Ok(self.ast.jsx_fragment(self.end_span(span), opening_fragment, closing_fragment, children))
     }
 
@@ -55,16 +64,24 @@ impl<'a> ParserImpl<'a> {
     ///     used for telling `JSXClosingElement` to parse the next jsx child or not
     ///     true when inside jsx element, false when at top level expression
     fn parse_jsx_element(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXElement<'a>>> {
+        self.nested(|p| p.parse_jsx_element_impl(in_jsx_child))
+    }
+
+    fn parse_jsx_element_impl(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXElement<'a>>> {
         let span = self.start_span();
         let opening_element = self.parse_jsx_opening_element(span, in_jsx_child)?;
-        let children = if opening_element.self_closing {
-            self.ast.new_vec()
+        if opening_element.self_closing {
+            let children = self.ast.new_vec();
+            return Ok(self.ast.jsx_element(self.end_span(span), opening_element, None, children));
+        }
+        let name = opening_element.name.span().source_text(self.source_text);
+        let children = self.parse_jsx_children_of(name)?;
+        let closing_element = if self.is_unclosed_jsx(name) {
+            self.error(diagnostics::UnclosedJSXElement(name.to_string(), opening_element.span));
+            None
         } else {
-            self.parse_jsx_children()?
+            Some(self.parse_jsx_closing_element(in_jsx_child)?)
         };
-        let closing_element = (!opening_element.self_closing)
-            .then(|| self.parse_jsx_closing_element(in_jsx_child))
-            .transpose()?;
         Ok(self.ast.jsx_element(self.end_span(span), opening_element, closing_element, children))
     }
 
@@ -89,7 +106,10 @@ impl<'a> ParserImpl<'a> {
         };
         let attributes = self.parse_jsx_attributes()?;
         let self_closing = self.eat(Kind::Slash);
-        if !self_closing || in_jsx_child {
+        if !self_closing && self.at(Kind::Arrow) {
+            // `<div className=>` is lexed with `=>`, the attribute reported the missing value
+            self.expect_jsx_child(Kind::Arrow)?;
+        } else if !self_closing || in_jsx_child {
             self.expect_jsx_child(Kind::RAngle)?;
         } else {
             self.expect(Kind::RAngle)?;
@@ -178,6 +198,40 @@ impl<'a> ParserImpl<'a> {
         Err(self.unexpected())
     }
 
+    /// The children of the element `name`, or of a fragment when `name` is empty
+    fn parse_jsx_children_of(&mut self, name: &'a str) -> Result<Vec<'a, JSXChild<'a>>> {
+        self.state.jsx_open_elements.push(name);
+        let children = self.parse_jsx_children();
+        self.state.jsx_open_elements.pop();
+        children
+    }
+
+    /// Whether the children of the element `name` end without its closing tag, i.e. at the end
+    /// of the file or at the closing tag of an enclosing element, e.g. `</div>` in
+    /// `<div><span></div>`. The element is then recovered without a closing tag, so incomplete
+    /// JSX still produces a program.
+    fn is_unclosed_jsx(&mut self, name: &str) -> bool {
+        if self.at(Kind::Eof) {
+            return true;
+        }
+        if self.state.jsx_open_elements.is_empty()
+            || !(self.at(Kind::LAngle) && self.peek_at(Kind::Slash))
+        {
+            return false;
+        }
+        let closing_name = self.lookahead(|p| {
+            p.bump_any(); // bump `<`
+            p.bump_any(); // bump `/`
+            if p.at(Kind::RAngle) {
+                return Some("");
+            }
+            p.parse_jsx_element_name().ok().map(|name| name.span().source_text(p.source_text))
+        });
+        closing_name.is_some_and(|closing_name| {
+            closing_name != name && self.state.jsx_open_elements.contains(&closing_name)
+        })
+    }
+
     /// `JSXChildren` :
     ///   `JSXChild` `JSXChildren_opt`
     fn parse_jsx_children(&mut self) -> Result<Vec<'a, JSXChild<'a>>> {
@@ -237,6 +291,14 @@ impl<'a> ParserImpl<'a> {
                 let span = self.start_span();
                 JSXExpression::EmptyExpression(self.ast.jsx_empty_expression(self.end_span(span)))
             }
+            // `{` without an expression nor `}` while typing, e.g. `<div>{</div>` or `<div a={>`
+            Kind::Eof => return Ok(self.recover_jsx_expression_container(span)),
+            Kind::LAngle if in_jsx_child && self.peek_at(Kind::Slash) => {
+                return Ok(self.recover_jsx_expression_container(span));
+            }
+            Kind::RAngle if !in_jsx_child => {
+                return Ok(self.recover_jsx_expression_container(span));
+            }
             // {expr}
             _ => self.parse_jsx_assignment_expression().map(JSXExpression::Expression)?,
         };
@@ -248,6 +310,14 @@ impl<'a> ParserImpl<'a> {
         Ok(self.ast.jsx_expression_container(self.end_span(span), expr))
     }
 
+    /// An expression container holding an empty expression, the `}` is missing
+    fn recover_jsx_expression_container(&mut self, span: Span) -> JSXExpressionContainer<'a> {
+        self.error(diagnostics::JSXExpressionExpected(self.cur_token().span()));
+        let empty = Span::new(self.prev_token_end, self.prev_token_end);
+        let expr = JSXExpression::EmptyExpression(self.ast.jsx_empty_expression(empty));
+        self.ast.jsx_expression_container(self.end_span(span), expr)
+    }
+
     fn parse_jsx_assignment_expression(&mut self) -> Result<Expression<'a>> {
         let ctx = self.ctx;
         self.ctx = Context::default().and_await(ctx.has_await());
@@ -275,7 +345,10 @@ impl<'a> ParserImpl<'a> {
     ///   `JSXAttribute` `JSXAttributes_opt`
     fn parse_jsx_attributes(&mut self) -> Result<Vec<'a, JSXAttributeItem<'a>>> {
         let mut attributes = self.ast.new_vec();
-        while !matches!(self.cur_kind(), Kind::Eof | Kind::LAngle | Kind::RAngle | Kind::Slash) {
+        while !matches!(
+            self.cur_kind(),
+            Kind::Eof | Kind::LAngle | Kind::RAngle | Kind::Slash | Kind::Arrow
+        ) {
             let attribute = match self.cur_kind() {
                 Kind::LCurly => {
                     self.parse_jsx_spread_attribute().map(JSXAttributeItem::SpreadAttribute)
@@ -292,9 +365,23 @@ impl<'a> ParserImpl<'a> {
     fn parse_jsx_attribute(&mut self) -> Result<Box<'a, JSXAttribute<'a>>> {
         let span = self.start_span();
         let name = self.parse_jsx_attribute_name()?;
-        let value = if self.at(Kind::Eq) {
+        let value = if self.at(Kind::Arrow) {
+            // `<div className=>`, the opening element eats the `=>`
+            let start = self.cur_token().start;
+            self.error(diagnostics::JSXAttributeValueExpected(Span::new(start, start + 1)));
+            None
+        } else if self.at(Kind::Eq) {
             self.expect_jsx_attribute_value(Kind::Eq)?;
-            Some(self.parse_jsx_attribute_value()?)
+            if matches!(self.cur_kind(), Kind::Str | Kind::LCurly | Kind::LAngle) {
+                Some(self.parse_jsx_attribute_value()?)
+            } else {
+                // `<div className= >` or `<div className= id="a">` while typing,
+                // the lexer has reported an invalid token, e.g. an unterminated string
+                if !self.at(Kind::Undetermined) {
+                    self.error(diagnostics::JSXAttributeValueExpected(self.cur_token().span()));
+                }
+                None
+            }
         } else {
             None
         };
@@ -339,11 +339,17 @@ ascii_byte_handler!(SLH(lexer) {
     match lexer.peek() {
         Some('/') => {
             lexer.consume_char();
-            lexer.skip_single_line_comment()
+            let kind = lexer.skip_single_line_comment();
+            lexer.trivia_builder.add_ts_suppression(lexer.source.whole());
+            lexer.trivia_builder.add_irregular_characters_in_comment(lexer.source.whole());
+            kind
         }
         Some('*') => {
             lexer.consume_char();
-            lexer.skip_multi_line_comment()
+            let kind = lexer.skip_multi_line_comment();
+            lexer.trivia_builder.add_ts_suppression(lexer.source.whole());
+            lexer.trivia_builder.add_irregular_characters_in_comment(lexer.source.whole());
+            kind
         }
         _ => {
             // regex is handled separately, see `next_regex`
@@ -487,6 +493,8 @@ ascii_byte_handler!(PIP(lexer) {
         }
     } else if lexer.next_eq('=') {
         Kind::PipeEq
+    } else if lexer.next_eq('>') {
+        Kind::PipeGt
     } else {
         Kind::Pipe
     }
@@ -539,6 +547,7 @@ ascii_identifier_handler!(L_D(id_without_first_char) match id_without_first_char
     "eclare" => Kind::Declare,
     "efault" => Kind::Default,
     "ebugger" => Kind::Debugger,
+    "efer" => Kind::Defer,
     _ => Kind::Ident,
 });
 
@@ -634,6 +643,7 @@ ascii_identifier_handler!(L_S(id_without_first_char) match id_without_first_char
     "ymbol" => Kind::Symbol,
     "tring" => Kind::String,
     "atisfies" => Kind::Satisfies,
+    "ource" => Kind::Source,
     _ => Kind::Ident,
 });
 
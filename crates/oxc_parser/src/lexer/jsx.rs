@@ -1,4 +1,4 @@
-use super::{AutoCow, Kind, Lexer, Token};
+use super::{Kind, Lexer, Token};
 use crate::diagnostics;
 
 use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
@@ -14,20 +14,13 @@ impl<'a> Lexer<'a> {
     ///   `JSXStringCharacter` but not '
     /// `JSXStringCharacter` ::
     ///   `SourceCharacter` but not one of `HTMLCharacterReference`
+    ///
+    /// JSX strings have no escapes, so their value is always a slice of the source text.
     pub(super) fn read_jsx_string_literal(&mut self, delimiter: char) -> Kind {
-        let mut builder = AutoCow::new(self);
         loop {
             match self.next_char() {
-                Some(c @ ('"' | '\'')) => {
-                    if c == delimiter {
-                        self.save_string(builder.has_escape(), builder.finish_without_push(self));
-                        return Kind::Str;
-                    }
-                    builder.push_matching(c);
-                }
-                Some(other) => {
-                    builder.push_matching(other);
-                }
+                Some(c) if c == delimiter => return Kind::Str,
+                Some(_) => {}
                 None => {
                     self.error(diagnostics::UnterminatedString(self.unterminated_range()));
                     return Kind::Undetermined;
@@ -44,6 +37,8 @@ impl<'a> Lexer<'a> {
 
     /// Expand the current token for `JSXIdentifier`
     pub(crate) fn next_jsx_identifier(&mut self, start_offset: u32) -> Token {
+        // The identifier continues the current token, e.g. `data-` of `data-foo`
+        self.token.start = start_offset;
         let kind = self.read_jsx_identifier(start_offset);
         self.lookahead.clear();
         self.finish_next(kind)
@@ -57,6 +57,8 @@ pub enum Kind {
     Set,
     Target,   // new.target
     Accessor, // keyword from https://github.com/tc39/proposal-decorators
+    Source,   // import source, https://github.com/tc39/proposal-source-phase-imports
+    Defer,    // import defer, https://github.com/tc39/proposal-defer-import-eval
     // TypeScript Contextual Keywords
     Abstract,
     As,
@@ -131,6 +133,7 @@ pub enum Kind {
     Pipe2,
     Pipe2Eq,
     PipeEq,
+    PipeGt, // `|>` of the pipeline operator proposal
     Plus,
     Plus2,
     PlusEq,
@@ -336,10 +339,11 @@ impl Kind {
 
     #[rustfmt::skip]
     pub fn is_contextual_keyword(self) -> bool {
-        matches!(self, Async | From | Get | Meta | Of | Set | Target | Accessor | Abstract | As | Asserts
-            | Assert | Any | Boolean | Constructor | Declare | Infer | Intrinsic | Is | KeyOf | Module
-            | Namespace | Never | Out | Readonly | Require | Number | Object | Satisfies | String
-            | Symbol | Type | Undefined | Unique | Unknown | Using | Global | BigInt | Override)
+        matches!(self, Async | From | Get | Meta | Of | Set | Target | Accessor | Source | Defer
+            | Abstract | As | Asserts | Assert | Any | Boolean | Constructor | Declare | Infer
+            | Intrinsic | Is | KeyOf | Module | Namespace | Never | Out | Readonly | Require | Number
+            | Object | Satisfies | String | Symbol | Type | Undefined | Unique | Unknown | Using
+            | Global | BigInt | Override)
     }
 
     #[rustfmt::skip]
@@ -375,103 +379,132 @@ impl Kind {
         Self::match_keyword_impl(s)
     }
 
+    /// Keywords are grouped by length, then matched as byte arrays of that length.
+    /// Unlike a `match` on `&str`, which compares the string with each keyword in turn,
+    /// this compiles to a jump on the length followed by a decision tree on the bytes.
     fn match_keyword_impl(s: &str) -> Self {
-        match s {
-            "as" => As,
-            "do" => Do,
-            "if" => If,
-            "in" => In,
-            "is" => Is,
-            "of" => Of,
-
-            "any" => Any,
-            "for" => For,
-            "get" => Get,
-            "let" => Let,
-            "new" => New,
-            "out" => Out,
-            "set" => Set,
-            "try" => Try,
-            "var" => Var,
-
-            "case" => Case,
-            "else" => Else,
-            "enum" => Enum,
-            "from" => From,
-            "meta" => Meta,
-            "null" => Null,
-            "this" => This,
-            "true" => True,
-            "type" => Type,
-            "void" => Void,
-            "with" => With,
-
-            "async" => Async,
-            "await" => Await,
-            "break" => Break,
-            "catch" => Catch,
-            "class" => Class,
-            "const" => Const,
-            "false" => False,
-            "infer" => Infer,
-            "keyof" => KeyOf,
-            "never" => Never,
-            "super" => Super,
-            "throw" => Throw,
-            "using" => Using,
-            "while" => While,
-            "yield" => Yield,
-
-            "assert" => Assert,
-            "bigint" => BigInt,
-            "delete" => Delete,
-            "export" => Export,
-            "global" => Global,
-            "import" => Import,
-            "module" => Module,
-            "number" => Number,
-            "object" => Object,
-            "public" => Public,
-            "return" => Return,
-            "static" => Static,
-            "string" => String,
-            "switch" => Switch,
-            "symbol" => Symbol,
-            "target" => Target,
-            "typeof" => Typeof,
-            "unique" => Unique,
-
-            "asserts" => Asserts,
-            "boolean" => Boolean,
-            "declare" => Declare,
-            "default" => Default,
-            "extends" => Extends,
-            "finally" => Finally,
-            "package" => Package,
-            "private" => Private,
-            "require" => Require,
-            "unknown" => Unknown,
-
-            "abstract" => Abstract,
-            "accessor" => Accessor,
-            "continue" => Continue,
-            "debugger" => Debugger,
-            "function" => Function,
-            "override" => Override,
-            "readonly" => Readonly,
-
-            "interface" => Interface,
-            "intrinsic" => Intrinsic,
-            "namespace" => Namespace,
-            "protected" => Protected,
-            "satisfies" => Satisfies,
-            "undefined" => Undefined,
-
-            "implements" => Implements,
-            "instanceof" => Instanceof,
-
-            "constructor" => Constructor,
-            _ => Ident,
+        macro_rules! match_keyword {
+            ($($len:literal => [$($keyword:literal => $kind:ident,)*],)*) => {
+                match s.len() {
+                    $(
+                        $len => match <&[u8; $len]>::try_from(s.as_bytes()) {
+                            $(Ok($keyword) => $kind,)*
+                            _ => Ident,
+                        },
+                    )*
+                    _ => Ident,
+                }
+            };
+        }
+
+        match_keyword! {
+            2 => [
+                b"as" => As,
+                b"do" => Do,
+                b"if" => If,
+                b"in" => In,
+                b"is" => Is,
+                b"of" => Of,
+            ],
+            3 => [
+                b"any" => Any,
+                b"for" => For,
+                b"get" => Get,
+                b"let" => Let,
+                b"new" => New,
+                b"out" => Out,
+                b"set" => Set,
+                b"try" => Try,
+                b"var" => Var,
+            ],
+            4 => [
+                b"case" => Case,
+                b"else" => Else,
+                b"enum" => Enum,
+                b"from" => From,
+                b"meta" => Meta,
+                b"null" => Null,
+                b"this" => This,
+                b"true" => True,
+                b"type" => Type,
+                b"void" => Void,
+                b"with" => With,
+            ],
+            5 => [
+                b"async" => Async,
+                b"await" => Await,
+                b"break" => Break,
+                b"catch" => Catch,
+                b"class" => Class,
+                b"const" => Const,
+                b"defer" => Defer,
+                b"false" => False,
+                b"infer" => Infer,
+                b"keyof" => KeyOf,
+                b"never" => Never,
+                b"super" => Super,
+                b"throw" => Throw,
+                b"using" => Using,
+                b"while" => While,
+                b"yield" => Yield,
+            ],
+            6 => [
+                b"assert" => Assert,
+                b"bigint" => BigInt,
+                b"delete" => Delete,
+                b"source" => Source,
+                b"export" => Export,
+                b"global" => Global,
+                b"import" => Import,
+                b"module" => Module,
+                b"number" => Number,
+                b"object" => Object,
+                b"public" => Public,
+                b"return" => Return,
+                b"static" => Static,
+                b"string" => String,
+                b"switch" => Switch,
+                b"symbol" => Symbol,
+                b"target" => Target,
+                b"typeof" => Typeof,
+                b"unique" => Unique,
+            ],
+            7 => [
+                b"asserts" => Asserts,
+                b"boolean" => Boolean,
+                b"declare" => Declare,
+                b"default" => Default,
+                b"extends" => Extends,
+                b"finally" => Finally,
+                b"package" => Package,
+                b"private" => Private,
+                b"require" => Require,
+                b"unknown" => Unknown,
+            ],
+            8 => [
+                b"abstract" => Abstract,
+                b"accessor" => Accessor,
+                b"continue" => Continue,
+                b"debugger" => Debugger,
+                b"function" => Function,
+                b"override" => Override,
+                b"readonly" => Readonly,
+            ],
+            9 => [
+                b"interface" => Interface,
+                b"intrinsic" => Intrinsic,
+                b"namespace" => Namespace,
+                b"protected" => Protected,
+                b"satisfies" => Satisfies,
+                b"undefined" => Undefined,
+            ],
+            10 => [
+                b"implements" => Implements,
+                b"instanceof" => Instanceof,
+            ],
+            11 => [
+                b"constructor" => Constructor,
+            ],
         }
     }
 
@@ -526,6 +559,8 @@ impl Kind {
             Set => "set",
             Asserts => "asserts",
             Accessor => "accessor",
+            Source => "source",
+            Defer => "defer",
             Abstract => "abstract",
             Readonly => "readonly",
             Declare => "declare",
@@ -572,6 +607,7 @@ impl Kind {
             Pipe2 => "||",
             Pipe2Eq => "||=",
             PipeEq => "|=",
+            PipeGt => "|>",
             Plus => "+",
             Plus2 => "++",
             PlusEq => "+=",
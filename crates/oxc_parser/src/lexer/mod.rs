@@ -31,12 +31,13 @@ use rustc_hash::FxHashMap;
 use std::collections::VecDeque;
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::RegExpFlags;
+use oxc_ast::{ast::RegExpFlags, IrregularCharacterContext};
 use oxc_diagnostics::Error;
-use oxc_span::{SourceType, Span};
+use oxc_span::{LineIndex, SourceType, Span};
 
 use self::{
     byte_handlers::handle_byte,
+    search::SimdSupport,
     source::{Source, SourcePosition},
     string_builder::AutoCow,
     trivia_builder::TriviaBuilder,
@@ -48,6 +49,10 @@ pub use self::{
 };
 use crate::{diagnostics, UniquePromise};
 
+/// Lexer state saved by [`Lexer::checkpoint`] and restored by [`Lexer::rewind`].
+///
+/// Saving and restoring are O(1): lookahead tokens are not saved,
+/// they are dropped on rewind and lexed again when asked for.
 #[derive(Debug, Clone, Copy)]
 pub struct LexerCheckpoint<'a> {
     /// Current position in source
@@ -55,7 +60,13 @@ pub struct LexerCheckpoint<'a> {
 
     token: Token,
 
+    context: LexerContext,
+
     errors_pos: usize,
+
+    inserted_semicolons_pos: usize,
+
+    tokens_pos: usize,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -98,6 +109,11 @@ pub struct Lexer<'a> {
 
     /// `memchr` Finder for end of multi-line comments. Created lazily when first used.
     multi_line_comment_end_finder: Option<memchr::memmem::Finder<'static>>,
+
+    /// Extended up to the end of each token when `Some`, see [`crate::Parser::line_index`]
+    pub(crate) line_index: Option<LineIndex>,
+    /// SIMD instructions for searching the source text, detected once
+    simd: SimdSupport,
 }
 
 #[allow(clippy::unused_self)]
@@ -128,6 +144,8 @@ impl<'a> Lexer<'a> {
             escaped_strings: FxHashMap::default(),
             escaped_templates: FxHashMap::default(),
             multi_line_comment_end_finder: None,
+            line_index: None,
+            simd: SimdSupport::detect(),
         }
     }
 
@@ -154,18 +172,50 @@ impl<'a> Lexer<'a> {
         LexerCheckpoint {
             position: self.source.position(),
             token: self.token,
+            context: self.context,
             errors_pos: self.errors.len(),
+            inserted_semicolons_pos: self.trivia_builder.inserted_semicolons_len(),
+            tokens_pos: self.trivia_builder.tokens_len(),
         }
     }
 
     /// Rewinds the lexer to the same state as when the passed in `checkpoint` was created.
     pub fn rewind(&mut self, checkpoint: LexerCheckpoint<'a>) {
         self.errors.truncate(checkpoint.errors_pos);
+        self.trivia_builder.truncate_inserted_semicolons(checkpoint.inserted_semicolons_pos);
+        self.trivia_builder.truncate_tokens(checkpoint.tokens_pos);
         self.source.set_position(checkpoint.position);
         self.token = checkpoint.token;
+        self.context = checkpoint.context;
         self.lookahead.clear();
     }
 
+    /// Rewinds the lexer to `checkpoint`, which may have been created by another lexer.
+    ///
+    /// # Panics
+    /// Panics if the position of `checkpoint` is not within the source text of this lexer.
+    pub fn rewind_checked(&mut self, mut checkpoint: LexerCheckpoint<'a>) {
+        checkpoint.position = self
+            .source
+            .checked_position(checkpoint.position)
+            .expect("Checkpoint is not within the source text of this lexer");
+        self.rewind(checkpoint);
+    }
+
+    /// Rewinds the lexer to `offset`, which must be the start of the source or the end of a token.
+    /// Errors found so far are kept.
+    pub fn seek(&mut self, offset: u32) {
+        let checkpoint = LexerCheckpoint {
+            position: self.source.position_at(offset),
+            token: if offset == 0 { Token::new_on_new_line() } else { Token::default() },
+            context: self.context,
+            errors_pos: self.errors.len(),
+            inserted_semicolons_pos: self.trivia_builder.inserted_semicolons_len(),
+            tokens_pos: self.trivia_builder.tokens_len(),
+        };
+        self.rewind(checkpoint);
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> Token {
         let n = n as usize;
@@ -218,12 +268,34 @@ impl<'a> Lexer<'a> {
         self.token.kind = kind;
         self.token.end = self.offset();
         debug_assert!(self.token.start <= self.token.end);
+        if self.trivia_builder.enabled {
+            self.add_irregular_characters_in_token();
+        }
+        if let Some(line_index) = &mut self.line_index {
+            line_index.index_to(self.source.whole(), self.token.end);
+        }
         let token = self.token;
         self.token = Token::default();
         token
     }
 
     // ---------- Private Methods ---------- //
+    /// Find irregular characters in the current token, if it has text which is not code
+    fn add_irregular_characters_in_token(&mut self) {
+        let context = match self.token.kind {
+            Kind::Str => IrregularCharacterContext::String,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => IrregularCharacterContext::Template,
+            Kind::RegExp => IrregularCharacterContext::RegExp,
+            Kind::JSXText => IrregularCharacterContext::JSXText,
+            _ => return,
+        };
+        let span = Span::new(self.token.start, self.token.end);
+        self.trivia_builder.add_irregular_characters(self.source.whole(), span, context);
+    }
+
     fn error<T: Into<Error>>(&mut self, error: T) {
         self.errors.push(error.into());
     }
@@ -53,6 +53,8 @@ impl<'a> Lexer<'a> {
     }
 
     pub(crate) fn next_right_angle(&mut self) -> Token {
+        // Continue the current `>` token
+        self.token.start = self.offset() - 1;
         let kind = self.read_right_angle();
         self.lookahead.clear();
         self.finish_next(kind)
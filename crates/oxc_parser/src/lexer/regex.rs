@@ -1,7 +1,8 @@
 use super::{Kind, Lexer, RegExpFlags, Token};
 use crate::diagnostics;
 
-use oxc_syntax::identifier::is_line_terminator;
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_identifier_part, is_line_terminator};
 
 impl<'a> Lexer<'a> {
     /// Re-tokenize the current `/` or `/=` and return `RegExp`
@@ -58,16 +59,20 @@ impl<'a> Lexer<'a> {
         let pattern_end = self.offset() - 1; // -1 to exclude `/`
         let mut flags = RegExpFlags::empty();
 
-        while let Some(ch @ ('$' | '_' | 'a'..='z' | 'A'..='Z' | '0'..='9')) = self.peek() {
+        // `RegularExpressionFlags` are `IdentifierPartChar`s, invalid flags are reported below
+        while let Some(ch) = self.peek().filter(|ch| is_identifier_part(*ch)) {
+            let start = self.offset();
             self.consume_char();
+            // The flag only, which may be a multi-byte character, e.g. `é` of `/a/gé`
+            let span = Span::new(start, self.offset());
             let flag = if let Ok(flag) = RegExpFlags::try_from(ch) {
                 flag
             } else {
-                self.error(diagnostics::RegExpFlag(ch, self.current_offset()));
+                self.error(diagnostics::RegExpFlag(ch, span));
                 continue;
             };
             if flags.contains(flag) {
-                self.error(diagnostics::RegExpFlagTwice(ch, self.current_offset()));
+                self.error(diagnostics::RegExpFlagTwice(ch, span));
                 continue;
             }
             flags |= flag;
@@ -3,6 +3,8 @@
 //! * `ByteMatchTable` and `SafeByteMatchTable` are lookup table types for byte values.
 //! * `byte_match_table!` and `safe_byte_match_table!` macros create those tables at compile time.
 //! * `byte_search!` macro searches source text for first byte matching a byte table.
+//! * `NibbleMasks` searches a batch of bytes for the first byte matching a byte table,
+//!   with SIMD instructions where available.
 
 /// Batch size for searching
 pub const SEARCH_BATCH_SIZE: usize = 32;
@@ -39,13 +41,13 @@ pub const SEARCH_BATCH_SIZE: usize = 32;
 /// ```
 // TODO: Delete this type + `byte_match_table!` macro if not used
 #[repr(C, align(64))]
-pub struct ByteMatchTable([bool; 256]);
+pub struct ByteMatchTable([bool; 256], NibbleMasks);
 
 #[allow(dead_code)]
 impl ByteMatchTable {
     // Create new `ByteMatchTable`.
     pub const fn new(bytes: [bool; 256]) -> Self {
-        let mut table = Self([false; 256]);
+        let mut table = Self([false; 256], NibbleMasks::new(&bytes));
         let mut i = 0;
         loop {
             table.0[i] = bytes[i];
@@ -70,6 +72,16 @@ impl ByteMatchTable {
     pub const fn matches(&self, b: u8) -> bool {
         self.0[b as usize]
     }
+
+    /// Find index of first byte in `batch` which matches this `ByteMatchTable`.
+    #[inline]
+    pub fn find_in_batch(
+        &self,
+        simd: SimdSupport,
+        batch: &[u8; SEARCH_BATCH_SIZE],
+    ) -> Option<usize> {
+        self.1.find_in_batch(simd, &self.0, batch)
+    }
 }
 
 /// Macro to create a `ByteMatchTable` at compile time.
@@ -159,12 +171,12 @@ pub(crate) use byte_match_table;
 /// }
 /// ```
 #[repr(C, align(64))]
-pub struct SafeByteMatchTable([bool; 256]);
+pub struct SafeByteMatchTable([bool; 256], NibbleMasks);
 
 impl SafeByteMatchTable {
     // Create new `SafeByteMatchTable`.
     pub const fn new(bytes: [bool; 256]) -> Self {
-        let mut table = Self([false; 256]);
+        let mut table = Self([false; 256], NibbleMasks::new(&bytes));
 
         // Check if contains either:
         // 1. `true` for all byte values 192..248
@@ -212,6 +224,16 @@ impl SafeByteMatchTable {
     pub const fn matches(&self, b: u8) -> bool {
         self.0[b as usize]
     }
+
+    /// Find index of first byte in `batch` which matches this `SafeByteMatchTable`.
+    #[inline]
+    pub fn find_in_batch(
+        &self,
+        simd: SimdSupport,
+        batch: &[u8; SEARCH_BATCH_SIZE],
+    ) -> Option<usize> {
+        self.1.find_in_batch(simd, &self.0, batch)
+    }
 }
 
 /// Macro to create a `SafeByteMatchTable` at compile time.
@@ -245,9 +267,201 @@ macro_rules! safe_byte_match_table {
 }
 pub(crate) use safe_byte_match_table;
 
+/// SIMD instructions available for searching batches of bytes, see [`NibbleMasks`].
+///
+/// Detected once when creating a `Lexer` rather than for every batch.
+/// SSSE3 is known at compile time when enabled with e.g. `-C target-cpu=native`,
+/// and detected at runtime otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct SimdSupport {
+    #[cfg(target_arch = "x86_64")]
+    ssse3: bool,
+}
+
+impl SimdSupport {
+    pub fn detect() -> Self {
+        Self {
+            #[cfg(target_arch = "x86_64")]
+            ssse3: cfg!(target_feature = "ssse3") || std::arch::is_x86_feature_detected!("ssse3"),
+        }
+    }
+
+    /// Search batches without the SIMD instructions which are detected at runtime
+    #[cfg(test)]
+    pub fn none() -> Self {
+        Self {
+            #[cfg(target_arch = "x86_64")]
+            ssse3: false,
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline]
+    fn ssse3(self) -> bool {
+        // Always true when enabled at compile time, so the check is optimized away
+        cfg!(target_feature = "ssse3") || self.ssse3
+    }
+}
+
+/// A byte match table in the form used to search a batch of bytes with SIMD instructions.
+///
+/// Each byte is split into its high nibble `h` and low nibble `l`.
+/// Bit `h` of `ascii[l]` is set if byte `h << 4 | l` matches, for `h < 8`,
+/// and bit `h - 8` of `non_ascii[l]` is set if it matches, for `h >= 8`.
+/// So searching a batch takes a couple of shuffles (16 parallel lookups of a 16-byte table)
+/// per 16 bytes, with any table.
+///
+/// SIMD is used on `x86_64` when SSSE3 is available (see [`SimdSupport`]),
+/// and on aarch64, where NEON is always available. Elsewhere, bytes are tested one by one.
+#[derive(Debug, Clone, Copy)]
+pub struct NibbleMasks {
+    ascii: [u8; 16],
+    non_ascii: [u8; 16],
+}
+
+impl NibbleMasks {
+    /// Create `NibbleMasks` for a byte match table.
+    pub const fn new(bytes: &[bool; 256]) -> Self {
+        let mut masks = Self { ascii: [0; 16], non_ascii: [0; 16] };
+        let mut i = 0;
+        loop {
+            if bytes[i] {
+                let low = i & 0x0F;
+                let high = i >> 4;
+                if high < 8 {
+                    masks.ascii[low] |= 1 << high;
+                } else {
+                    masks.non_ascii[low] |= 1 << (high - 8);
+                }
+            }
+            i += 1;
+            if i == 256 {
+                break;
+            }
+        }
+        masks
+    }
+
+    /// Find index of first byte in `batch` which matches.
+    /// `bytes` is the table these masks were created from, used when SIMD is not available.
+    #[cfg(not(target_arch = "aarch64"))]
+    #[inline]
+    pub fn find_in_batch(
+        &self,
+        simd: SimdSupport,
+        bytes: &[bool; 256],
+        batch: &[u8; SEARCH_BATCH_SIZE],
+    ) -> Option<usize> {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if simd.ssse3() {
+                // SAFETY: SSSE3 is available
+                return unsafe { self.find_in_batch_ssse3(batch) };
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        let _ = simd;
+        batch.iter().position(|&b| bytes[b as usize])
+    }
+
+    /// Find index of first byte in `batch` which matches.
+    #[cfg(target_arch = "aarch64")]
+    #[inline]
+    pub fn find_in_batch(
+        &self,
+        _simd: SimdSupport,
+        _bytes: &[bool; 256],
+        batch: &[u8; SEARCH_BATCH_SIZE],
+    ) -> Option<usize> {
+        // SAFETY: NEON is always available on aarch64
+        unsafe { self.find_in_batch_neon(batch) }
+    }
+
+    /// # SAFETY
+    /// Caller must ensure SSSE3 is available.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "ssse3")]
+    unsafe fn find_in_batch_ssse3(&self, batch: &[u8; SEARCH_BATCH_SIZE]) -> Option<usize> {
+        use std::arch::x86_64::{
+            _mm_and_si128, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_or_si128,
+            _mm_set1_epi8, _mm_setr_epi8, _mm_setzero_si128, _mm_shuffle_epi8, _mm_srli_epi16,
+            _mm_xor_si128,
+        };
+
+        let ascii = _mm_loadu_si128(self.ascii.as_ptr().cast());
+        let non_ascii = _mm_loadu_si128(self.non_ascii.as_ptr().cast());
+        let bits = _mm_setr_epi8(1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128);
+        let high_bit = _mm_set1_epi8(-128);
+        let low_3_bits = _mm_set1_epi8(0x07);
+
+        let mut not_matched = 0u32;
+        for half in 0..SEARCH_BATCH_SIZE / 16 {
+            let bytes = _mm_loadu_si128(batch.as_ptr().add(half * 16).cast());
+            // Shuffle looks up with the low nibble, and gives 0 for indexes with the high bit set.
+            // So look up the ASCII bytes in `ascii`, and the others in `non_ascii`.
+            let row = _mm_or_si128(
+                _mm_shuffle_epi8(ascii, bytes),
+                _mm_shuffle_epi8(non_ascii, _mm_xor_si128(bytes, high_bit)),
+            );
+            // Bit for the high nibble, ignoring its high bit
+            let column = _mm_and_si128(_mm_srli_epi16(bytes, 4), low_3_bits);
+            let bit = _mm_shuffle_epi8(bits, column);
+            let is_not_match = _mm_cmpeq_epi8(_mm_and_si128(row, bit), _mm_setzero_si128());
+            #[allow(clippy::cast_sign_loss)]
+            let half_not_matched = _mm_movemask_epi8(is_not_match) as u32;
+            not_matched |= half_not_matched << (half * 16);
+        }
+
+        let matched = !not_matched;
+        (matched != 0).then(|| matched.trailing_zeros() as usize)
+    }
+
+    /// # SAFETY
+    /// Always safe on aarch64, where NEON is always available.
+    #[cfg(target_arch = "aarch64")]
+    unsafe fn find_in_batch_neon(&self, batch: &[u8; SEARCH_BATCH_SIZE]) -> Option<usize> {
+        use std::arch::aarch64::{
+            vandq_u8, vbslq_u8, vcltzq_s8, vdupq_n_u8, vget_lane_u64, vld1q_u8, vqtbl1q_u8,
+            vreinterpret_u64_u8, vreinterpretq_s8_u8, vreinterpretq_u16_u8, vshlq_u8, vshrn_n_u16,
+            vshrq_n_u8, vtstq_u8,
+        };
+
+        let ascii = vld1q_u8(self.ascii.as_ptr());
+        let non_ascii = vld1q_u8(self.non_ascii.as_ptr());
+        let low_nibble = vdupq_n_u8(0x0F);
+        let low_3_bits = vdupq_n_u8(0x07);
+        let one = vdupq_n_u8(1);
+
+        for half in 0..SEARCH_BATCH_SIZE / 16 {
+            let bytes = vld1q_u8(batch.as_ptr().add(half * 16));
+            // Table lookup gives 0 for indexes out of bounds, so look up with the low nibble,
+            // and select the lookup in `non_ascii` for bytes with the high bit set
+            let low = vandq_u8(bytes, low_nibble);
+            let row = vbslq_u8(
+                vcltzq_s8(vreinterpretq_s8_u8(bytes)),
+                vqtbl1q_u8(non_ascii, low),
+                vqtbl1q_u8(ascii, low),
+            );
+            // Bit for the high nibble, ignoring its high bit
+            let column = vandq_u8(vshrq_n_u8::<4>(bytes), low_3_bits);
+            let bit = vshlq_u8(one, vreinterpretq_s8_u8(column));
+            let is_match = vtstq_u8(row, bit);
+            // Narrow to 4 bits per byte
+            let matched = vget_lane_u64::<0>(vreinterpret_u64_u8(vshrn_n_u16::<4>(
+                vreinterpretq_u16_u8(is_match),
+            )));
+            if matched != 0 {
+                return Some(half * 16 + (matched.trailing_zeros() / 4) as usize);
+            }
+        }
+        None
+    }
+}
+
 /// Macro to search for first byte matching a `ByteMatchTable` or `SafeByteMatchTable`.
 ///
-/// Search processes source in batches of `SEARCH_BATCH_SIZE` bytes for speed.
+/// Search processes source in batches of `SEARCH_BATCH_SIZE` bytes for speed,
+/// using SIMD instructions where available (see `NibbleMasks`).
 /// When not enough bytes remaining in source for a batch, search source byte by byte.
 ///
 /// This is a macro rather than a function for 2 reasons:
@@ -484,32 +698,24 @@ macro_rules! byte_search {
             if $pos.addr() <= $lexer.source.end_for_batch_search_addr() {
                 // Search a batch of `SEARCH_BATCH_SIZE` bytes.
                 //
-                // `'inner: loop {}` is not a real loop - it always exits on first turn.
-                // Only using `loop {}` so that can use `break 'inner` to get out of it.
-                // This allows complex logic of `$should_continue` and `$match_handler` to be
-                // outside the `for` loop, keeping it as minimal as possible, to encourage
-                // compiler to unroll it.
-                //
                 // SAFETY:
                 // `$pos.addr() <= lexer.source.end_for_batch_search_addr()` check above ensures
                 // there are at least `SEARCH_BATCH_SIZE` bytes remaining in `lexer.source`.
-                // So calls to `$pos.read()` and `$pos.add(1)` in this loop cannot go out of bounds.
-                let $match_byte = 'inner: loop {
-                    for _i in 0..crate::lexer::search::SEARCH_BATCH_SIZE {
-                        // SAFETY: `$pos` cannot go out of bounds in this loop (see above)
-                        let byte = unsafe { $pos.read() };
-                        if $table.matches(byte) {
-                            break 'inner byte;
-                        }
-
-                        // No match - continue searching batch.
-                        // SAFETY: `$pos` cannot go out of bounds in this loop (see above).
-                        // Also see above about UTF-8 character boundaries invariant.
-                        $pos = unsafe { $pos.add(1) };
-                    }
-                    // No match in batch - search next batch
+                // So reading the batch, and advancing `$pos` by up to `SEARCH_BATCH_SIZE` bytes,
+                // cannot go out of bounds.
+                let batch = unsafe { $pos.read_batch() };
+                let Some(index) = $table.find_in_batch($lexer.simd, batch) else {
+                    // No match in batch - search next batch.
+                    // SAFETY: `$pos` cannot go out of bounds (see above).
+                    // Also see above about UTF-8 character boundaries invariant.
+                    $pos = unsafe { $pos.add(crate::lexer::search::SEARCH_BATCH_SIZE) };
                     continue 'outer;
                 };
+                // SAFETY: `index` is within the batch (see above).
+                // Also see above about UTF-8 character boundaries invariant.
+                $pos = unsafe { $pos.add(index) };
+                // SAFETY: `$pos` is within the batch, so not at end of source
+                let $match_byte = unsafe { $pos.read() };
 
                 // Found match. Check if should continue.
                 {
@@ -580,3 +786,42 @@ macro_rules! byte_search {
     }};
 }
 pub(crate) use byte_search;
+
+#[cfg(test)]
+mod test {
+    use super::{safe_byte_match_table, SafeByteMatchTable, SimdSupport, SEARCH_BATCH_SIZE};
+
+    #[test]
+    fn find_in_batch() {
+        static NOT_WHITESPACE: SafeByteMatchTable =
+            safe_byte_match_table!(|b| !matches!(b, b' ' | b'\t' | b'\r' | b'\n'));
+        static NOT_ID_CHAR: SafeByteMatchTable =
+            safe_byte_match_table!(|b| !(b.is_ascii_alphanumeric() || b == b'_' || b == b'$'));
+        static QUOTE_OR_LINE_BREAK: SafeByteMatchTable =
+            safe_byte_match_table!(|b| matches!(b, b'"' | b'\r' | b'\n' | b'\\' | 0xE2));
+        static NOT_ASCII: SafeByteMatchTable = safe_byte_match_table!(|b| !b.is_ascii());
+
+        for (simd, table) in
+            [SimdSupport::detect(), SimdSupport::none()].into_iter().flat_map(|simd| {
+                [&NOT_WHITESPACE, &NOT_ID_CHAR, &QUOTE_OR_LINE_BREAK, &NOT_ASCII]
+                    .map(|table| (simd, table))
+            })
+        {
+            let filler = (0..=255).find(|&b| !table.matches(b)).unwrap();
+            assert_eq!(table.find_in_batch(simd, &[filler; SEARCH_BATCH_SIZE]), None);
+            for byte in 0..=255 {
+                for index in 0..SEARCH_BATCH_SIZE {
+                    let mut batch = [filler; SEARCH_BATCH_SIZE];
+                    batch[index] = byte;
+                    let expected = table.matches(byte).then_some(index);
+                    assert_eq!(table.find_in_batch(simd, &batch), expected, "{byte} at {index}");
+                    // Only the first match is found
+                    batch[SEARCH_BATCH_SIZE - 1] = 0x80;
+                    if expected.is_none() && table.matches(0x80) {
+                        assert_eq!(table.find_in_batch(simd, &batch), Some(SEARCH_BATCH_SIZE - 1));
+                    }
+                }
+            }
+        }
+    }
+}
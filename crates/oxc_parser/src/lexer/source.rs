@@ -165,6 +165,35 @@ impl<'a> Source<'a> {
         unsafe { SourcePosition::new(self.ptr) }
     }
 
+    /// Get position at `offset` from start of source.
+    ///
+    /// # Panic
+    /// Panics if `offset` is out of bounds or not on a UTF-8 character boundary.
+    pub(super) fn position_at(&self, offset: u32) -> SourcePosition<'a> {
+        self.checked_position_at(offset as usize)
+            .expect("Offset is out of bounds or not on a UTF-8 character boundary")
+    }
+
+    /// Get `pos` as a position in this `Source`.
+    ///
+    /// Unlike `Source::set_position`, `pos` may have been created by another `Source`.
+    /// Returns `None` if it is not within bounds of this `Source` or not on a UTF-8 character
+    /// boundary.
+    pub(super) fn checked_position(&self, pos: SourcePosition) -> Option<SourcePosition<'a>> {
+        let offset = pos.addr().checked_sub(self.start as usize)?;
+        self.checked_position_at(offset)
+    }
+
+    fn checked_position_at(&self, offset: usize) -> Option<SourcePosition<'a>> {
+        if !self.whole().is_char_boundary(offset) {
+            return None;
+        }
+        // SAFETY: `is_char_boundary` checks that `offset` is within `start` - `end` (inclusive),
+        // and on a UTF-8 character boundary.
+        // The pointer is derived from `start`, so it has the provenance of this `Source`.
+        Some(unsafe { SourcePosition::new(self.start.add(offset)) })
+    }
+
     /// Move current position.
     #[inline]
     pub(super) fn set_position(&mut self, pos: SourcePosition) {
@@ -565,6 +594,21 @@ impl<'a> SourcePosition<'a> {
         let p = self.ptr as *const [u8; 2];
         *p.as_ref().unwrap_unchecked()
     }
+
+    /// Read `SEARCH_BATCH_SIZE` bytes from this `SourcePosition`, for `byte_search!`.
+    ///
+    /// # SAFETY
+    /// Caller must ensure `SourcePosition` is no later than `SEARCH_BATCH_SIZE` bytes before end
+    /// of source text, i.e. its address is no later than `Source::end_for_batch_search_addr`.
+    #[inline]
+    pub(super) unsafe fn read_batch(self) -> &'a [u8; SEARCH_BATCH_SIZE] {
+        // SAFETY: Caller guarantees `self` is no later than `SEARCH_BATCH_SIZE` bytes before end
+        // of source text. See `read` and `read2` above for the other invariants.
+        debug_assert!(!self.ptr.is_null());
+        #[allow(clippy::ptr_as_ptr)]
+        let p = self.ptr as *const [u8; SEARCH_BATCH_SIZE];
+        p.as_ref().unwrap_unchecked()
+    }
 }
 
 /// Return if byte is a UTF-8 continuation byte.
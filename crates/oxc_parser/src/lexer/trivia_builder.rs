@@ -1,27 +1,247 @@
-use oxc_ast::{CommentKind, Trivias};
+use oxc_ast::{
+    CommentKind, IrregularCharacter, IrregularCharacterContext, IrregularCharacterKind,
+    SourcePiece, SourcePieceKind, Trivias, TsSuppression, TsSuppressionKind,
+};
 use oxc_span::Span;
+use oxc_syntax::identifier::{
+    is_irregular_line_terminator, is_irregular_whitespace, is_line_terminator, TAB,
+};
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct TriviaBuilder {
     trivias: Trivias,
+    /// Nothing is collected when `false`, see [`crate::Parser::trivias`]
+    pub(crate) enabled: bool,
+    /// Collect [`Trivias::pieces`], see [`crate::Parser::lossless`]
+    pub(crate) lossless: bool,
+    /// Spans of the tokens consumed by the parser, in source order
+    tokens: Vec<Span>,
+}
+
+impl Default for TriviaBuilder {
+    fn default() -> Self {
+        Self { trivias: Trivias::default(), enabled: true, lossless: false, tokens: vec![] }
+    }
 }
 
 impl TriviaBuilder {
-    pub fn build(self) -> Trivias {
+    pub fn build(mut self, source_text: &str) -> Trivias {
+        if self.lossless {
+            self.trivias.pieces = self.build_pieces(source_text);
+        }
         self.trivias
     }
 
+    /// The tokens, with the comments and whitespace between them
+    #[allow(clippy::cast_possible_truncation)]
+    fn build_pieces(&self, source_text: &str) -> Vec<SourcePiece> {
+        // Comments are lexed again after a rewind
+        let mut comments = self
+            .trivias
+            .comments
+            .iter()
+            .map(|&(start, end, kind)| match kind {
+                CommentKind::SingleLine => Span::new(start - 2, end),
+                CommentKind::MultiLine => Span::new(start - 2, end + 2),
+            })
+            .collect::<Vec<_>>();
+        comments.sort_unstable_by_key(|span| span.start);
+        comments.dedup();
+        let mut comments = comments.into_iter().peekable();
+
+        let mut pieces = vec![];
+        let mut end = 0;
+        let source_end = Span::new(source_text.len() as u32, source_text.len() as u32);
+        for &token in self.tokens.iter().chain([&source_end]) {
+            // Comments between the tokens, and the text around them
+            while let Some(comment) = comments.next_if(|comment| comment.start < token.start) {
+                if comment.start < end || comment.end > token.start {
+                    continue;
+                }
+                Self::add_text_between_tokens(
+                    &mut pieces,
+                    source_text,
+                    Span::new(end, comment.start),
+                );
+                pieces.push(SourcePiece { kind: SourcePieceKind::Comment, span: comment });
+                end = comment.end;
+            }
+            Self::add_text_between_tokens(&mut pieces, source_text, Span::new(end, token.start));
+            if token.start < token.end {
+                pieces.push(SourcePiece { kind: SourcePieceKind::Token, span: token });
+            }
+            end = token.end;
+        }
+        pieces
+    }
+
+    /// Split `span` without comments or tokens into whitespace runs and unparsed text
+    #[allow(clippy::cast_possible_truncation)]
+    fn add_text_between_tokens(pieces: &mut Vec<SourcePiece>, source_text: &str, span: Span) {
+        let is_whitespace =
+            |c: char| c == ' ' || c == TAB || is_irregular_whitespace(c) || is_line_terminator(c);
+        let mut start = span.start;
+        while start < span.end {
+            let rest = &source_text[start as usize..span.end as usize];
+            let whitespace = rest.starts_with(is_whitespace);
+            let len = rest.find(|c| is_whitespace(c) != whitespace).unwrap_or(rest.len());
+            let kind =
+                if whitespace { SourcePieceKind::Whitespace } else { SourcePieceKind::Unparsed };
+            pieces.push(SourcePiece { kind, span: Span::new(start, start + len as u32) });
+            start += len as u32;
+        }
+    }
+
+    /// Record a token consumed by the parser
+    pub fn add_token(&mut self, span: Span) {
+        if self.lossless {
+            self.tokens.push(span);
+        }
+    }
+
+    pub fn tokens_len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Drop the tokens consumed by a parse which has been rewound
+    pub fn truncate_tokens(&mut self, len: usize) {
+        self.tokens.truncate(len);
+    }
+
+    /// Keep the trivias of a previous parse which are before `offset`, see [`crate::Parser::reparse`]
+    pub fn restore(&mut self, mut trivias: Trivias, offset: u32) {
+        if !self.enabled {
+            return;
+        }
+        trivias.comments.retain(|&(start, _, _)| start < offset);
+        trivias.irregular_characters.retain(|c| c.span.start < offset);
+        trivias.inserted_semicolons.retain(|&position| position <= offset);
+        trivias.ts_suppressions.retain(|suppression| suppression.span.start < offset);
+        if self.lossless {
+            self.tokens = trivias
+                .pieces
+                .iter()
+                .filter(|piece| piece.kind == SourcePieceKind::Token && piece.span.end <= offset)
+                .map(|piece| piece.span)
+                .collect();
+        }
+        trivias.pieces.clear();
+        self.trivias = trivias;
+    }
+
     /// skip leading `//`
     pub fn add_single_line_comment(&mut self, start: u32, end: u32) {
-        self.trivias.comments.push((start + 2, end, CommentKind::SingleLine));
+        if self.enabled {
+            self.trivias.comments.push((start + 2, end, CommentKind::SingleLine));
+        }
     }
 
     /// skip leading `/*` and trailing `*/`
     pub fn add_multi_line_comment(&mut self, start: u32, end: u32) {
-        self.trivias.comments.push((start + 2, end - 2, CommentKind::MultiLine));
+        if self.enabled {
+            self.trivias.comments.push((start + 2, end - 2, CommentKind::MultiLine));
+        }
+    }
+
+    /// Record the last comment if it is a `@ts-expect-error` or `@ts-ignore` directive,
+    /// called after each comment which is not an HTML-like comment.
+    pub fn add_ts_suppression(&mut self, source_text: &str) {
+        if !self.enabled {
+            return;
+        }
+        let Some(&(start, end, kind)) = self.trivias.comments.last() else { return };
+        // Comments are lexed again after a rewind
+        if self.trivias.ts_suppressions.last().is_some_and(|last| last.span.start >= start) {
+            return;
+        }
+        let Some(text) = source_text.get(start as usize..end as usize) else { return };
+        if let Some(suppression_kind) = TsSuppressionKind::from_comment(text, kind) {
+            self.trivias
+                .ts_suppressions
+                .push(TsSuppression { kind: suppression_kind, span: Span::new(start, end) });
+        }
     }
 
+    /// Irregular whitespace between tokens
     pub fn add_irregular_whitespace(&mut self, start: u32, end: u32) {
-        self.trivias.irregular_whitespaces.push(Span::new(start, end));
+        self.add_irregular_character(
+            IrregularCharacterKind::IrregularWhitespace,
+            IrregularCharacterContext::Code,
+            Span::new(start, end),
+        );
+    }
+
+    pub fn add_irregular_character(
+        &mut self,
+        kind: IrregularCharacterKind,
+        context: IrregularCharacterContext,
+        span: Span,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        // Tokens are lexed again after a rewind, and lone surrogates are found before the other
+        // characters of their string, so insert in source order and skip the ones already found
+        let characters = &mut self.trivias.irregular_characters;
+        if let Err(index) = characters.binary_search_by_key(&span.start, |c| c.span.start) {
+            characters.insert(index, IrregularCharacter { kind, context, span });
+        }
+    }
+
+    /// Find irregular whitespace and null characters in `span` of `source_text`,
+    /// the text of a comment, string, template, regular expression or JSX text.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn add_irregular_characters(
+        &mut self,
+        source_text: &str,
+        span: Span,
+        context: IrregularCharacterContext,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let text = &source_text[span.start as usize..span.end as usize];
+        // All irregular characters are either one of these or not ASCII
+        if text.bytes().all(|b| b.is_ascii() && !matches!(b, b'\0' | 0x0B | 0x0C)) {
+            return;
+        }
+        for (offset, c) in text.char_indices() {
+            let kind = if c == '\0' {
+                IrregularCharacterKind::Null
+            } else if is_irregular_whitespace(c) || is_irregular_line_terminator(c) {
+                IrregularCharacterKind::IrregularWhitespace
+            } else {
+                continue;
+            };
+            let start = span.start + offset as u32;
+            self.add_irregular_character(
+                kind,
+                context,
+                Span::new(start, start + c.len_utf8() as u32),
+            );
+        }
+    }
+
+    /// Find irregular characters in the last comment, called after each comment.
+    pub fn add_irregular_characters_in_comment(&mut self, source_text: &str) {
+        if let Some(&(start, end, _)) = self.trivias.comments.last() {
+            let span = Span::new(start, end);
+            self.add_irregular_characters(source_text, span, IrregularCharacterContext::Comment);
+        }
+    }
+
+    pub fn add_inserted_semicolon(&mut self, position: u32) {
+        if self.enabled {
+            self.trivias.inserted_semicolons.push(position);
+        }
+    }
+
+    pub fn inserted_semicolons_len(&self) -> usize {
+        self.trivias.inserted_semicolons.len()
+    }
+
+    /// Drop the semicolons inserted by a parse which has been rewound
+    pub fn truncate_inserted_semicolons(&mut self, len: usize) {
+        self.trivias.inserted_semicolons.truncate(len);
     }
 }
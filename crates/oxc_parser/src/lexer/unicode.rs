@@ -2,9 +2,10 @@ use super::{Kind, Lexer, Span};
 use crate::diagnostics;
 
 use oxc_allocator::String;
+use oxc_ast::{IrregularCharacterContext, IrregularCharacterKind};
 use oxc_syntax::identifier::{
     is_identifier_part, is_identifier_start, is_identifier_start_unicode,
-    is_irregular_line_terminator, is_irregular_whitespace, CR, FF, LF, LS, PS, TAB, VT,
+    is_irregular_line_terminator, is_irregular_whitespace, CR, FF, LF, LS, PS, TAB, VT, ZWNBSP,
 };
 
 enum SurrogatePair {
@@ -26,6 +27,15 @@ impl<'a> Lexer<'a> {
                 self.identifier_tail_after_unicode(start_pos);
                 Kind::Ident
             }
+            ZWNBSP if self.token.start == 0 => {
+                self.consume_char();
+                self.trivia_builder.add_irregular_character(
+                    IrregularCharacterKind::ByteOrderMark,
+                    IrregularCharacterContext::Code,
+                    Span::new(0, self.offset()),
+                );
+                Kind::Skip
+            }
             c if is_irregular_whitespace(c) => {
                 self.consume_char();
                 self.trivia_builder.add_irregular_whitespace(self.token.start, self.offset());
@@ -107,8 +117,11 @@ impl<'a> Lexer<'a> {
     fn string_unicode_escape_sequence(
         &mut self,
         text: &mut String<'a>,
+        in_template: bool,
         is_valid_escape_sequence: &mut bool,
     ) {
+        // After `\u`
+        let start = self.offset() - 2;
         let value = match self.peek() {
             Some('{') => self.unicode_code_point(),
             _ => self.surrogate_pair(),
@@ -127,11 +140,13 @@ impl<'a> Lexer<'a> {
                 if let Ok(ch) = char::try_from(code_point) {
                     text.push(ch);
                 } else {
+                    self.add_lone_surrogate(start, in_template);
                     text.push_str("\\u");
                     text.push_str(format!("{code_point:x}").as_str());
                 }
             }
             SurrogatePair::HighLow(high, low) => {
+                self.add_lone_surrogate(start, in_template);
                 text.push_str("\\u");
                 text.push_str(format!("{high:x}").as_str());
                 text.push_str("\\u");
@@ -140,6 +155,19 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    fn add_lone_surrogate(&mut self, start: u32, in_template: bool) {
+        let context = if in_template {
+            IrregularCharacterContext::Template
+        } else {
+            IrregularCharacterContext::String
+        };
+        self.trivia_builder.add_irregular_character(
+            IrregularCharacterKind::LoneSurrogate,
+            context,
+            Span::new(start, self.offset()),
+        );
+    }
+
     fn unicode_code_point(&mut self) -> Option<SurrogatePair> {
         if !self.next_eq('{') {
             return None;
@@ -263,7 +291,11 @@ impl<'a> Lexer<'a> {
                 }
                 // UnicodeEscapeSequence
                 'u' => {
-                    self.string_unicode_escape_sequence(text, is_valid_escape_sequence);
+                    self.string_unicode_escape_sequence(
+                        text,
+                        in_template,
+                        is_valid_escape_sequence,
+                    );
                 }
                 // 0 [lookahead ∉ DecimalDigit]
                 '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => text.push('\0'),
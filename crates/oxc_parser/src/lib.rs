@@ -4,7 +4,7 @@
 //!
 //! The following optimization techniques are used:
 //! * AST is allocated in a memory arena ([bumpalo](https://docs.rs/bumpalo)) for fast AST drop
-//! * Short strings are inlined by [CompactString](https://github.com/ParkMyCar/compact_str)
+//! * Short strings are inlined by [oxc_span::Atom], long strings are borrowed from the source text
 //! * No other heap allocations are done except the above two
 //! * [oxc_span::Span] offsets uses `u32` instead of `usize`
 //! * Scope binding, symbol resolution and complicated syntax errors are not done in the parser,
@@ -71,6 +71,12 @@ mod jsx;
 mod ts;
 
 mod diagnostics;
+mod jsdoc;
+mod json;
+mod recovery;
+mod reparse;
+mod stats;
+mod tokenizer;
 
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
@@ -79,18 +85,29 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-pub use crate::lexer::Kind; // re-export for codegen
+pub use crate::{
+    json::JsonDialect,
+    lexer::{Kind, Token}, // re-export for codegen
+    reparse::TextEdit,
+    stats::ParserStats,
+    tokenizer::{tokenize, TokenDiff, Tokenizer, TokenizerCheckpoint, Tokens},
+};
+
+use std::cell::Cell;
 
 use context::{Context, StatementContext};
-use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, AstBuilder, Trivias};
-use oxc_diagnostics::{Error, Result};
-use oxc_span::{ModuleKind, SourceType, Span};
-
-use crate::{
-    lexer::{Lexer, Token},
-    state::ParserState,
+use oxc_allocator::{Allocator, Box};
+use oxc_ast::{
+    ast::{
+        Directive, Expression, Function, FunctionBody, Hashbang, JsonValue, NullLiteral, Program,
+        Statement, TSType,
+    },
+    AstBuilder, CommentsMap, Trivias,
 };
+use oxc_diagnostics::{CancellationToken, Error, Result};
+use oxc_span::{LineIndex, ModuleKind, SourceType, Span};
+
+use crate::{lexer::Lexer, state::ParserState, stats::StatsRecorder};
 
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
@@ -106,28 +123,215 @@ pub const MAX_LEN: usize = if std::mem::size_of::<usize>() >= 8 {
     isize::MAX as usize
 };
 
+/// Default maximum nesting depth of statements and expressions, see [`Parser::max_nesting_depth`].
+pub const DEFAULT_MAX_NESTING_DEPTH: u32 = 512;
+
 /// Return value of parser consisting of AST, errors and comments
 ///
 /// The parser always return a valid AST.
 /// When `panicked = true`, then program will always be empty.
-/// When `errors.len() > 0`, then program may or may not be empty due to error recovery.
+/// When `errors.len() > 0`, then program may or may not be empty due to error recovery,
+/// see [`Parser::error_recovery`] for keeping the statements around a syntax error.
 pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<Error>,
     pub trivias: Trivias,
     pub panicked: bool,
+    /// Spans of the function bodies skipped by [`Parser::lazy_function_bodies`], in source order
+    pub deferred_bodies: Vec<Span>,
+    /// Comments of each node, empty unless [`Parser::attach_comments`] is enabled
+    pub comments: CommentsMap,
+    /// Line starts and non-ASCII characters of the source text,
+    /// `None` unless [`Parser::line_index`] is enabled
+    pub line_index: Option<LineIndex>,
+    /// `None` unless [`Parser::stats`] is enabled
+    pub stats: Option<ParserStats>,
+}
+
+/// Return value of [`Parser::parse_deferred_body`]
+pub struct FunctionBodyReturn<'a> {
+    /// An empty body when `panicked = true`
+    pub body: Box<'a, FunctionBody<'a>>,
+    pub errors: Vec<Error>,
+    pub panicked: bool,
+    /// Spans of the nested function bodies skipped by [`Parser::lazy_function_bodies`]
+    pub deferred_bodies: Vec<Span>,
+}
+
+/// Return value of [`Parser::parse_expression`]
+pub struct ExpressionReturn<'a> {
+    /// A `null` literal when `panicked = true`
+    pub expression: Expression<'a>,
+    pub errors: Vec<Error>,
+    pub trivias: Trivias,
+    pub panicked: bool,
+}
+
+/// Return value of [`Parser::parse_statement`]
+pub struct StatementReturn<'a> {
+    /// An empty statement when `panicked = true`
+    pub statement: Statement<'a>,
+    pub errors: Vec<Error>,
+    pub trivias: Trivias,
+    pub panicked: bool,
+}
+
+/// Return value of [`Parser::parse_jsdoc_type`]
+pub struct TypeReturn<'a> {
+    /// A [`TSType::JSDocUnknownType`] when `panicked = true`
+    pub type_annotation: TSType<'a>,
+    pub errors: Vec<Error>,
+    pub trivias: Trivias,
+    pub panicked: bool,
+}
+
+/// Return value of [`Parser::parse_json`]
+pub struct JsonReturn<'a> {
+    /// `null` when `panicked = true`
+    pub value: JsonValue<'a>,
+    pub errors: Vec<Error>,
+    /// Comments are always collected
+    pub trivias: Trivias,
+    pub panicked: bool,
+}
+
+/// How many tokens are parsed between calls to the [`Parser::time_budget`] hook.
+const TIME_BUDGET_CHECK_INTERVAL: u32 = 1024;
+
+/// Options of the language accepted by the parser
+///
+/// Unlike the resource limits, these can differ between files of a project,
+/// e.g. when derived from the tsconfig or Babel config governing each file.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseOptions {
+    /// See [`Parser::allow_return_outside_function`]
+    pub allow_return_outside_function: bool,
+    /// See [`Parser::preserve_parens`]
+    pub preserve_parens: bool,
+    /// See [`Parser::pipeline_operator`]
+    pub pipeline_operator: bool,
+    /// See [`Parser::do_expressions`]
+    pub do_expressions: bool,
+    /// See [`Parser::throw_expressions`]
+    pub throw_expressions: bool,
+    /// See [`Parser::trivias`]
+    pub trivias: bool,
+    /// See [`Parser::cooked_templates`]
+    pub cooked_templates: bool,
+    /// See [`Parser::lazy_function_bodies`]
+    pub lazy_function_bodies: bool,
+    /// See [`Parser::error_recovery`]
+    pub error_recovery: bool,
+    /// See [`Parser::lossless`]
+    pub lossless: bool,
+    /// See [`Parser::attach_comments`]
+    pub attach_comments: bool,
+    /// See [`Parser::line_index`]
+    pub line_index: bool,
+    /// See [`Parser::stats`]
+    pub stats: bool,
+    /// See [`Parser::declaration_file`]
+    pub declaration_file: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            allow_return_outside_function: false,
+            preserve_parens: true,
+            pipeline_operator: false,
+            do_expressions: false,
+            throw_expressions: false,
+            trivias: true,
+            cooked_templates: true,
+            lazy_function_bodies: false,
+            error_recovery: false,
+            lossless: false,
+            attach_comments: false,
+            line_index: false,
+            stats: false,
+            declaration_file: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Options for consumers which only need the core AST, e.g. a minifier in a build pipeline.
+    ///
+    /// Parentheses are not preserved, and no trivias or cooked template strings are built,
+    /// the proposals and `allow_return_outside_function` are left at their defaults.
+    pub fn minimal() -> Self {
+        Self { preserve_parens: false, trivias: false, cooked_templates: false, ..Self::default() }
+    }
+
+    /// Options for prototyping against proposals, all of which are enabled:
+    /// the pipeline operator, do expressions and throw expressions.
+    pub fn experimental() -> Self {
+        Self {
+            pipeline_operator: true,
+            do_expressions: true,
+            throw_expressions: true,
+            ..Self::default()
+        }
+    }
+
+    /// Options for extracting the type surface of declaration files, e.g. a tool scanning
+    /// `node_modules/**/*.d.ts`: [`Parser::declaration_file`] without trivias, parentheses or
+    /// cooked template strings.
+    pub fn declarations() -> Self {
+        Self { declaration_file: true, ..Self::minimal() }
+    }
 }
 
 /// Parser options
 #[derive(Clone, Copy)]
-struct ParserOptions {
+struct ParserOptions<'a> {
     pub allow_return_outside_function: bool,
     pub preserve_parens: bool,
+    pub pipeline_operator: bool,
+    pub do_expressions: bool,
+    pub throw_expressions: bool,
+    pub trivias: bool,
+    pub cooked_templates: bool,
+    pub lazy_function_bodies: bool,
+    pub error_recovery: bool,
+    pub lossless: bool,
+    pub attach_comments: bool,
+    pub line_index: bool,
+    pub stats: bool,
+    pub declaration_file: bool,
+    pub max_nesting_depth: u32,
+    pub max_source_length: usize,
+    pub max_token_count: u32,
+    pub max_node_count: u32,
+    pub time_budget: Option<&'a dyn Fn() -> bool>,
+    pub cancellation_token: Option<&'a CancellationToken>,
 }
 
-impl Default for ParserOptions {
+impl<'a> Default for ParserOptions<'a> {
     fn default() -> Self {
-        Self { allow_return_outside_function: false, preserve_parens: true }
+        Self {
+            allow_return_outside_function: false,
+            preserve_parens: true,
+            pipeline_operator: false,
+            do_expressions: false,
+            throw_expressions: false,
+            trivias: true,
+            cooked_templates: true,
+            lazy_function_bodies: false,
+            error_recovery: false,
+            lossless: false,
+            attach_comments: false,
+            line_index: false,
+            stats: false,
+            declaration_file: false,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            max_source_length: MAX_LEN,
+            max_token_count: u32::MAX,
+            max_node_count: u32::MAX,
+            time_budget: None,
+            cancellation_token: None,
+        }
     }
 }
 
@@ -138,7 +342,7 @@ pub struct Parser<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     source_type: SourceType,
-    options: ParserOptions,
+    options: ParserOptions<'a>,
 }
 
 impl<'a> Parser<'a> {
@@ -167,6 +371,233 @@ impl<'a> Parser<'a> {
         self.options.preserve_parens = allow;
         self
     }
+
+    /// Parse the pipeline operator `a |> f` of the minimal proposal as a `PipelineExpression`.
+    ///
+    /// `|>` raises an error by default.
+    #[must_use]
+    pub fn pipeline_operator(mut self, allow: bool) -> Self {
+        self.options.pipeline_operator = allow;
+        self
+    }
+
+    /// Parse `do { ... }` in expression position as a `DoExpression`.
+    ///
+    /// Do expressions raise an error by default.
+    #[must_use]
+    pub fn do_expressions(mut self, allow: bool) -> Self {
+        self.options.do_expressions = allow;
+        self
+    }
+
+    /// Parse `throw` in expression position, e.g. `x ?? throw new Error()`, as a `ThrowExpression`.
+    ///
+    /// Throw expressions raise an error by default.
+    #[must_use]
+    pub fn throw_expressions(mut self, allow: bool) -> Self {
+        self.options.throw_expressions = allow;
+        self
+    }
+
+    /// Collect comments, irregular whitespaces and inserted semicolons into
+    /// [`ParserReturn::trivias`].
+    ///
+    /// Disable when the trivias are not used, they are collected by default.
+    #[must_use]
+    pub fn trivias(mut self, collect: bool) -> Self {
+        self.options.trivias = collect;
+        self
+    }
+
+    /// Set `TemplateElementValue::cooked`, the template strings with escapes applied.
+    ///
+    /// When disabled `cooked` is always `None`, which prevents evaluating template literals,
+    /// but invalid escape sequences are still reported. Enabled by default.
+    #[must_use]
+    pub fn cooked_templates(mut self, cook: bool) -> Self {
+        self.options.cooked_templates = cook;
+        self
+    }
+
+    /// Skip over the bodies of functions and methods, which are parsed on demand with
+    /// [`Parser::parse_deferred_body`], for consumers which only need the top-level structure,
+    /// e.g. a module scanner.
+    ///
+    /// A skipped body is empty, its span is recorded in [`ParserReturn::deferred_bodies`],
+    /// and its syntax errors are only reported when it is parsed.
+    /// Braces are matched without parsing, with a regular expression assumed wherever `/` does
+    /// not follow an operand, so a regular expression with a brace after `)` may be misread,
+    /// e.g. `if (x) /}/.test(y)`. Bodies are never skipped in JSX. Disabled by default.
+    #[must_use]
+    pub fn lazy_function_bodies(mut self, lazy: bool) -> Self {
+        self.options.lazy_function_bodies = lazy;
+        self
+    }
+
+    /// Keep parsing after a syntax error, for tools which work on files being edited,
+    /// e.g. a language server.
+    ///
+    /// A statement which cannot be parsed becomes an `ErrorStatement` spanning the tokens skipped
+    /// up to the next statement, i.e. after a `;`, before the `}` closing its block, or before a
+    /// keyword starting a statement on a new line. A missing expression, e.g. in `a = ;`,
+    /// becomes an empty `ErrorExpression`. The syntax errors are reported in
+    /// [`ParserReturn::errors`] as usual.
+    ///
+    /// Resource limits and cancellation still stop parsing with an empty program.
+    /// Disabled by default, where the first syntax error which cannot be recovered from
+    /// stops parsing.
+    #[must_use]
+    pub fn error_recovery(mut self, recover: bool) -> Self {
+        self.options.error_recovery = recover;
+        self
+    }
+
+    /// Record every token, comment and whitespace run into [`Trivias::pieces`] of
+    /// [`ParserReturn::trivias`], in source order, for tools which rewrite the source text
+    /// and must keep everything they do not change byte for byte, e.g. codemods and formatters.
+    ///
+    /// The pieces are contiguous and cover the whole source text, the text after a syntax error
+    /// which stopped parsing is an `Unparsed` piece. The other trivias are collected as well,
+    /// even when [`Parser::trivias`] is disabled. Disabled by default.
+    #[must_use]
+    pub fn lossless(mut self, lossless: bool) -> Self {
+        self.options.lossless = lossless;
+        self
+    }
+
+    /// Attach the comments to the nodes next to them into [`ParserReturn::comments`],
+    /// for transforms and printers which keep comments such as `/* #__PURE__ */` and license
+    /// comments next to their node. See [`CommentsMap::new`] for how comments are attached.
+    ///
+    /// The comments are collected even when [`Parser::trivias`] is disabled. Disabled by default.
+    #[must_use]
+    pub fn attach_comments(mut self, attach: bool) -> Self {
+        self.options.attach_comments = attach;
+        self
+    }
+
+    /// Record the line starts and non-ASCII characters of the source text into
+    /// [`ParserReturn::line_index`] while lexing, for consumers which need UTF-16 offsets or
+    /// line / column positions, e.g. a language server or an ESTree serializer,
+    /// without scanning the source text again. Disabled by default.
+    #[must_use]
+    pub fn line_index(mut self, line_index: bool) -> Self {
+        self.options.line_index = line_index;
+        self
+    }
+
+    /// Collect [`ParserStats`] into [`ParserReturn::stats`]: the number of nodes of each kind,
+    /// the number of tokens, the growth of the arena and the time taken by each phase,
+    /// for tracking the performance of the parser on a code base.
+    ///
+    /// Counting the nodes walks the AST once more, which is not part of the timings.
+    /// Disabled by default.
+    #[must_use]
+    pub fn stats(mut self, stats: bool) -> Self {
+        self.options.stats = stats;
+        self
+    }
+
+    /// Parse as a declaration file, for consumers which only need the types and signatures
+    /// of the declarations, e.g. a tool scanning `node_modules/**/*.d.ts`.
+    ///
+    /// The source is parsed in an ambient context as if it had a `.d.ts` extension, and the
+    /// bodies of functions, methods, arrow functions and class static blocks are skipped by
+    /// matching their braces like [`Parser::lazy_function_bodies`] and left empty, their spans
+    /// are not recorded and their syntax errors are not reported.
+    /// Bodies are never skipped in JSX. Disabled by default, see [`ParseOptions::declarations`].
+    #[must_use]
+    pub fn declaration_file(mut self, declaration_file: bool) -> Self {
+        self.options.declaration_file = declaration_file;
+        self
+    }
+
+    /// Set all [`ParseOptions`] at once.
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options.allow_return_outside_function = options.allow_return_outside_function;
+        self.options.preserve_parens = options.preserve_parens;
+        self.options.pipeline_operator = options.pipeline_operator;
+        self.options.do_expressions = options.do_expressions;
+        self.options.throw_expressions = options.throw_expressions;
+        self.options.trivias = options.trivias;
+        self.options.cooked_templates = options.cooked_templates;
+        self.options.lazy_function_bodies = options.lazy_function_bodies;
+        self.options.error_recovery = options.error_recovery;
+        self.options.lossless = options.lossless;
+        self.options.attach_comments = options.attach_comments;
+        self.options.line_index = options.line_index;
+        self.options.stats = options.stats;
+        self.options.declaration_file = options.declaration_file;
+        self
+    }
+
+    /// Maximum nesting depth of statements and expressions.
+    ///
+    /// The parser is recursive, deeper input (e.g. 10k nested ternaries from a minifier)
+    /// stops parsing with a "Nesting too deep" error instead of overflowing the stack.
+    /// The default is [`DEFAULT_MAX_NESTING_DEPTH`],
+    /// only raise it when parsing on a thread with a larger stack.
+    #[must_use]
+    pub fn max_nesting_depth(mut self, depth: u32) -> Self {
+        self.options.max_nesting_depth = depth;
+        self
+    }
+
+    /// Maximum length of source text in bytes.
+    ///
+    /// Longer source is not parsed, an error is returned instead.
+    /// The default and upper bound is [`MAX_LEN`].
+    #[must_use]
+    pub fn max_source_length(mut self, length: usize) -> Self {
+        self.options.max_source_length = length.min(MAX_LEN);
+        self
+    }
+
+    /// Maximum number of tokens to lex, including tokens lexed again on backtracking.
+    ///
+    /// Parsing stops with an error once the limit is reached. Unlimited by default.
+    #[must_use]
+    pub fn max_token_count(mut self, count: u32) -> Self {
+        self.options.max_token_count = count;
+        self
+    }
+
+    /// Maximum number of AST nodes to build, including nodes built again on backtracking.
+    ///
+    /// Bounds the memory taken by the AST of untrusted input together with
+    /// [`Parser::max_source_length`]. Parsing stops with an error once the limit is reached. Unlimited by default.
+    /// To bound the memory itself, parse with [`Allocator::with_memory_limit`].
+    #[must_use]
+    pub fn max_node_count(mut self, count: u32) -> Self {
+        self.options.max_node_count = count;
+        self
+    }
+
+    /// Hook for bounding parse time.
+    ///
+    /// The hook is called periodically while parsing,
+    /// parsing stops with an error once it returns `true`, e.g.
+    ///
+    /// ```ignore
+    /// let deadline = Instant::now() + Duration::from_millis(100);
+    /// let is_over_budget = || Instant::now() > deadline;
+    /// let ret = Parser::new(&allocator, source_text, source_type).time_budget(&is_over_budget).parse();
+    /// ```
+    #[must_use]
+    pub fn time_budget(mut self, hook: &'a dyn Fn() -> bool) -> Self {
+        self.options.time_budget = Some(hook);
+        self
+    }
+
+    /// Stop parsing with a "cancelled" error once `token` is cancelled.
+    ///
+    /// The token is checked before each statement.
+    #[must_use]
+    pub fn cancellation_token(mut self, token: &'a CancellationToken) -> Self {
+        self.options.cancellation_token = Some(token);
+        self
+    }
 }
 
 mod parser_parse {
@@ -182,6 +613,10 @@ mod parser_parse {
     /// `Parser::parse` can create one, and it only calls `ParserImpl::new` once.
     /// This enforces the invariant throughout the entire parser.
     ///
+    /// `Tokenizer::new` creates one as well. A `Tokenizer` can exist alongside a parser, or other
+    /// tokenizers, because it only rewinds to its checkpoints with `Lexer::rewind_checked`,
+    /// which checks that a `SourcePosition` from another `Source` is within bounds of its own.
+    ///
     /// `UniquePromise` is a zero-sized type and has no runtime cost. It's purely for the type-checker.
     ///
     /// `UniquePromise::new_for_tests` is a backdoor for unit tests and benchmarks, so they can create a
@@ -221,6 +656,122 @@ mod parser_parse {
             );
             parser.parse()
         }
+
+        /// Parse the source text of this parser, which is the source text of `old` after `edit`,
+        /// reusing the top-level statements of `old` before the edit.
+        /// The statements from the edit to the end are parsed again.
+        ///
+        /// `old` must have been parsed with the same allocator, source type and options.
+        /// The whole source text is parsed when nothing can be reused,
+        /// including when `old` has errors.
+        pub fn reparse(self, old: ParserReturn<'a>, edit: TextEdit) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.reparse(old, edit)
+        }
+
+        /// Parse the body of `function`, skipped by [`Parser::lazy_function_bodies`]
+        /// when the source text of this parser was parsed with the same source type and options.
+        ///
+        /// # Panics
+        /// Panics if `function` has no body.
+        pub fn parse_deferred_body(self, function: &Function<'a>) -> FunctionBodyReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_deferred_body(function)
+        }
+
+        /// Parse the source text as a single expression, e.g. a template placeholder.
+        ///
+        /// Input after the expression is reported as an error,
+        /// the spans are relative to the source text as with [`Parser::parse`].
+        pub fn parse_expression(self) -> ExpressionReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_single_expression()
+        }
+
+        /// Parse the source text as a single statement or declaration, including
+        /// import and export declarations.
+        ///
+        /// Input after the statement is reported as an error,
+        /// the spans are relative to the source text as with [`Parser::parse`].
+        pub fn parse_statement(self) -> StatementReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_single_statement()
+        }
+
+        /// Parse the source text as a JSDoc type expression without the braces,
+        /// e.g. `?string` of `@param {?string} name`. The Closure Compiler syntax without
+        /// a TypeScript equivalent is approximated, e.g. `number=` is `number | undefined`.
+        ///
+        /// Input after the type is reported as an error,
+        /// the spans are relative to the source text as with [`Parser::parse`].
+        pub fn parse_jsdoc_type(self) -> TypeReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_single_jsdoc_type()
+        }
+
+        /// Parse the source text as a JSON document of `dialect`, e.g. `package.json`.
+        ///
+        /// The source type of this parser is ignored, the resource limits apply.
+        /// Syntax the dialect does not allow, such as comments in strict JSON,
+        /// is reported as a recoverable error.
+        pub fn parse_json(self, dialect: JsonDialect) -> JsonReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                SourceType::default(),
+                self.options,
+                unique,
+            );
+            parser.parse_json(dialect)
+        }
+    }
+
+    impl<'a> Tokenizer<'a> {
+        /// Create a tokenizer for `source_text`, see [`Tokenizer`] for driving it like the parser.
+        pub fn new(
+            allocator: &'a Allocator,
+            source_text: &'a str,
+            source_type: SourceType,
+        ) -> Self {
+            let unique = UniquePromise::new();
+            Self::with_lexer(Lexer::new(allocator, source_text, source_type, unique))
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -258,6 +809,68 @@ struct ParserImpl<'a> {
     /// Emit `ParenthesizedExpression` in AST.
     /// Default: `true`
     preserve_parens: bool,
+
+    /// Parse `|>`, `do { ... }` and `throw` expressions of stage proposals.
+    /// Default: `false`
+    pipeline_operator: bool,
+    do_expressions: bool,
+    throw_expressions: bool,
+
+    /// Set `TemplateElementValue::cooked`
+    /// Default: `true`
+    cooked_templates: bool,
+
+    /// Current nesting depth of statements and expressions
+    nesting_depth: u32,
+
+    /// Default: [`DEFAULT_MAX_NESTING_DEPTH`]
+    max_nesting_depth: u32,
+
+    /// Default: [`MAX_LEN`]
+    max_source_length: usize,
+
+    /// Number of tokens lexed so far
+    token_count: u32,
+
+    /// Default: unlimited
+    max_token_count: u32,
+
+    /// Number of nodes built so far, counted when their span is ended
+    node_count: Cell<u32>,
+
+    /// Default: unlimited
+    max_node_count: u32,
+
+    /// Token count at which the `time_budget` hook is called next
+    next_time_budget_check: u32,
+
+    /// Returns `true` when parsing should stop
+    time_budget: Option<&'a dyn Fn() -> bool>,
+
+    /// Checked before each statement
+    cancellation_token: Option<&'a CancellationToken>,
+
+    /// See [`Parser::lazy_function_bodies`]
+    lazy_function_bodies: bool,
+
+    /// Spans of the skipped function bodies
+    deferred_bodies: Vec<Span>,
+
+    /// See [`Parser::declaration_file`]
+    declaration_file: bool,
+
+    /// See [`Parser::error_recovery`]
+    error_recovery: bool,
+
+    /// See [`Parser::attach_comments`]
+    attach_comments: bool,
+
+    /// See [`Parser::stats`]
+    stats: Option<StatsRecorder>,
+
+    /// Depth of [`ParserImpl::try_parse`] and [`ParserImpl::lookahead`],
+    /// where errors must not be recovered from so that another parse can be tried
+    speculation_depth: u32,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -270,11 +883,16 @@ impl<'a> ParserImpl<'a> {
         allocator: &'a Allocator,
         source_text: &'a str,
         source_type: SourceType,
-        options: ParserOptions,
+        options: ParserOptions<'a>,
         unique: UniquePromise,
     ) -> Self {
+        let mut lexer = Lexer::new(allocator, source_text, source_type, unique);
+        lexer.trivia_builder.enabled =
+            options.trivias || options.lossless || options.attach_comments;
+        lexer.trivia_builder.lossless = options.lossless;
+        lexer.line_index = options.line_index.then(LineIndex::default);
         Self {
-            lexer: Lexer::new(allocator, source_text, source_type, unique),
+            lexer,
             source_type,
             source_text,
             errors: vec![],
@@ -284,6 +902,27 @@ impl<'a> ParserImpl<'a> {
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             preserve_parens: options.preserve_parens,
+            pipeline_operator: options.pipeline_operator,
+            do_expressions: options.do_expressions,
+            throw_expressions: options.throw_expressions,
+            cooked_templates: options.cooked_templates,
+            nesting_depth: 0,
+            max_nesting_depth: options.max_nesting_depth,
+            max_source_length: options.max_source_length,
+            token_count: 0,
+            max_token_count: options.max_token_count,
+            node_count: Cell::new(0),
+            max_node_count: options.max_node_count,
+            next_time_budget_check: TIME_BUDGET_CHECK_INTERVAL,
+            time_budget: options.time_budget,
+            cancellation_token: options.cancellation_token,
+            lazy_function_bodies: options.lazy_function_bodies && !source_type.is_jsx(),
+            deferred_bodies: vec![],
+            declaration_file: options.declaration_file && !source_type.is_jsx(),
+            error_recovery: options.error_recovery,
+            attach_comments: options.attach_comments,
+            stats: options.stats.then(|| StatsRecorder::start(allocator)),
+            speculation_depth: 0,
         }
     }
 
@@ -294,7 +933,7 @@ impl<'a> ParserImpl<'a> {
         allocator: &'a Allocator,
         source_text: &'a str,
         source_type: SourceType,
-        options: ParserOptions,
+        options: ParserOptions<'a>,
     ) -> Self {
         let unique = UniquePromise::new_for_tests();
         Self::new(allocator, source_text, source_type, options, unique)
@@ -306,7 +945,18 @@ impl<'a> ParserImpl<'a> {
     /// Recoverable errors are stored inside `errors`.
     #[inline]
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let (program, panicked) = match self.parse_program() {
+        let result = if self.source_text.len() > self.max_source_length {
+            Err(diagnostics::SourceTooLong(self.max_source_length).into())
+        } else {
+            self.parse_program()
+        };
+        self.finish(result)
+    }
+
+    /// Collect the errors and trivias into the return value
+    #[allow(clippy::cast_possible_truncation)]
+    fn finish(mut self, result: Result<Program<'a>>) -> ParserReturn<'a> {
+        let (program, panicked) = match result {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(
@@ -322,26 +972,152 @@ impl<'a> ParserImpl<'a> {
                 (program, true)
             }
         };
+        if let Some(stats) = &mut self.stats {
+            stats.parsed();
+        }
+        let deferred_bodies = self.take_deferred_bodies();
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
-        let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let trivias = self.lexer.trivia_builder.build(self.source_text);
+        let comments = if self.attach_comments {
+            CommentsMap::new(self.source_text, &program, &trivias.comments)
+        } else {
+            CommentsMap::default()
+        };
+        let too_long = self.source_text.len() > self.max_source_length;
+        let mut line_index = self.lexer.line_index.filter(|_| !too_long);
+        if let Some(line_index) = &mut line_index {
+            // The text after a syntax error which stopped parsing is not lexed
+            line_index.index_to(self.source_text, self.source_text.len() as u32);
+        }
+        let stats =
+            self.stats.map(|stats| stats.finish(&program, self.token_count, self.ast.allocator));
+        ParserReturn {
+            program,
+            errors,
+            trivias,
+            panicked,
+            deferred_bodies,
+            comments,
+            line_index,
+            stats,
+        }
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    fn parse_program(&mut self) -> Result<Program<'a>> {
-        // initialize cur_token and prev_token by moving onto the first token
+    /// Parse the body of `function` on its own, see [`Parser::parse_deferred_body`]
+    fn parse_deferred_body(mut self, function: &Function<'a>) -> FunctionBodyReturn<'a> {
+        let span = function.body.as_ref().expect("Function has no body").span;
+        self.lexer.trivia_builder.enabled = false;
+        self.lexer.trivia_builder.lossless = false;
+        self.lexer.seek(span.start);
         self.bump_any();
+        self.prev_token_end = span.start;
+        self.ctx = self.ctx.and_in(true).and_await(function.r#async).and_yield(function.generator);
 
-        let hashbang = self.parse_hashbang();
-        let (directives, statements) =
-            self.parse_directives_and_statements(/* is_top_level */ true)?;
+        let (body, panicked) = match self.parse_function_body() {
+            Ok(body) => (body, false),
+            Err(error) => {
+                self.error(error);
+                (self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec()), true)
+            }
+        };
+        let deferred_bodies = self.take_deferred_bodies();
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        FunctionBodyReturn { body, errors, panicked, deferred_bodies }
+    }
+
+    /// See [`Parser::parse_expression`]
+    fn parse_single_expression(mut self) -> ExpressionReturn<'a> {
+        let (expression, panicked) = match self.parse_fragment(Self::parse_expression) {
+            Some(expression) => (expression, false),
+            None => (self.ast.literal_null_expression(NullLiteral::new(Span::default())), true),
+        };
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        let trivias = self.lexer.trivia_builder.build(self.source_text);
+        ExpressionReturn { expression, errors, trivias, panicked }
+    }
+
+    /// See [`Parser::parse_statement`]
+    fn parse_single_statement(mut self) -> StatementReturn<'a> {
+        let parse = |p: &mut Self| p.parse_statement_list_item(StatementContext::StatementList);
+        let (statement, panicked) = match self.parse_fragment(parse) {
+            Some(statement) => (statement, false),
+            None => (self.ast.empty_statement(Span::default()), true),
+        };
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        let trivias = self.lexer.trivia_builder.build(self.source_text);
+        StatementReturn { statement, errors, trivias, panicked }
+    }
+
+    /// Parse the whole source text with `parse`, reporting any input left after it.
+    /// Returns `None` on unrecoverable error.
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_fragment<T, F: FnOnce(&mut Self) -> Result<T>>(&mut self, parse: F) -> Option<T> {
+        if self.source_text.len() > self.max_source_length {
+            self.error(diagnostics::SourceTooLong(self.max_source_length));
+            return None;
+        }
+        self.bump_any();
+        match parse(self) {
+            Ok(node) => {
+                if !self.at(Kind::Eof) {
+                    let span = Span::new(self.cur_token().start, self.source_text.len() as u32);
+                    self.error(diagnostics::TrailingInput(span));
+                }
+                Some(node)
+            }
+            Err(error) => {
+                self.error(self.overlong_error().unwrap_or(error));
+                None
+            }
+        }
+    }
+
+    /// In source order, without the duplicates of bodies skipped again after a rewind
+    fn take_deferred_bodies(&mut self) -> Vec<Span> {
+        let mut deferred_bodies = std::mem::take(&mut self.deferred_bodies);
+        deferred_bodies.sort_unstable_by_key(|span| span.start);
+        deferred_bodies.dedup();
+        deferred_bodies
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_program(&mut self) -> Result<Program<'a>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+
+        let hashbang = self.parse_hashbang();
+        let (directives, statements) =
+            self.parse_directives_and_statements(/* is_top_level */ true)?;
 
         let span = Span::new(0, self.source_text.len() as u32);
         Ok(self.ast.program(span, self.source_type, directives, hashbang, statements))
     }
 
-    fn default_context(source_type: SourceType, options: ParserOptions) -> Context {
-        let mut ctx = Context::default().and_ambient(source_type.is_typescript_definition());
+    /// Continue parsing the top-level statements after the last of `statements`,
+    /// see [`ParserImpl::reparse`]
+    #[allow(clippy::cast_possible_truncation)]
+    fn parse_program_after(
+        &mut self,
+        offset: u32,
+        mut directives: oxc_allocator::Vec<'a, Directive>,
+        hashbang: Option<Hashbang>,
+        mut statements: oxc_allocator::Vec<'a, Statement<'a>>,
+    ) -> Result<Program<'a>> {
+        self.bump_any();
+        self.prev_token_end = offset;
+        self.parse_directives_and_statements_into(
+            /* is_top_level */ true,
+            &mut directives,
+            &mut statements,
+        )?;
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        Ok(self.ast.program(span, self.source_type, directives, hashbang, statements))
+    }
+
+    fn default_context(source_type: SourceType, options: ParserOptions<'a>) -> Context {
+        let mut ctx = Context::default()
+            .and_ambient(source_type.is_typescript_definition() || options.declaration_file);
         if source_type.module_kind() == ModuleKind::Module {
             // for [top-level-await](https://tc39.es/proposal-top-level-await/)
             ctx = ctx.and_await(true);
@@ -400,6 +1176,9 @@ impl<'a> ParserImpl<'a> {
 #[cfg(test)]
 mod test {
 
+    use oxc_ast::ast::Declaration;
+    use oxc_span::GetSpan;
+
     use super::*;
 
     #[test]
@@ -443,6 +1222,242 @@ mod test {
         }
     }
 
+    #[test]
+    fn inserted_semicolons() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources: [(&str, &[u32]); 6] = [
+            ("a;\nb;", &[]),
+            ("a\nb", &[1, 3]),
+            ("{ a }", &[3]),
+            ("do {} while (a)\nb", &[15, 17]),
+            ("function f() { return\n1 }", &[21, 23]),
+            ("class A { a = 1\nb }", &[15, 17]),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert_eq!(ret.trivias.inserted_semicolons, expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn ts_suppressions() {
+        use oxc_ast::TsSuppressionKind::{self, ExpectError, Ignore};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let sources: [(&str, &[TsSuppressionKind]); 6] = [
+            ("// @ts-expect-error\na;", &[ExpectError]),
+            ("/// @ts-ignore: reason\na;", &[Ignore]),
+            ("a; //   @ts-ignore\nb;", &[Ignore]),
+            ("/*\n * description\n * @ts-expect-error */\na;", &[ExpectError]),
+            ("/* @ts-ignore\n * description */\na;", &[]),
+            ("// see @ts-ignore\n// @ts-check\na;", &[]),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            let kinds = ret
+                .trivias
+                .ts_suppressions
+                .iter()
+                .map(|suppression| suppression.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(kinds, expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn irregular_characters() {
+        use oxc_ast::{
+            IrregularCharacterContext::{Code, Comment, JSXText, RegExp, String, Template},
+            IrregularCharacterKind::{ByteOrderMark, IrregularWhitespace, LoneSurrogate, Null},
+        };
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let sources: [(&str, &[_]); 9] = [
+            ("a = b;", &[]),
+            ("\u{feff}a;", &[(ByteOrderMark, Code, 0, 3)]),
+            ("a\u{feff};", &[(IrregularWhitespace, Code, 1, 4)]),
+            (
+                "a;\u{a0}// \u{a0}",
+                &[(IrregularWhitespace, Code, 2, 4), (IrregularWhitespace, Comment, 7, 9)],
+            ),
+            ("/* \u{3000} */", &[(IrregularWhitespace, Comment, 3, 6)]),
+            (
+                "'\u{b}\0'; `\u{2028}`",
+                &[
+                    (IrregularWhitespace, String, 1, 2),
+                    (Null, String, 2, 3),
+                    (IrregularWhitespace, Template, 7, 10),
+                ],
+            ),
+            (
+                "'a\\uD800b'; `\\uDC00`",
+                &[(LoneSurrogate, String, 2, 8), (LoneSurrogate, Template, 13, 19)],
+            ),
+            ("'\\uD83D\\uDE00'", &[]),
+            (
+                "/\u{a0}/; <a>\u{a0}</a>",
+                &[(IrregularWhitespace, RegExp, 1, 3), (IrregularWhitespace, JSXText, 9, 11)],
+            ),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let characters = ret
+                .trivias
+                .irregular_characters
+                .iter()
+                .map(|c| (c.kind, c.context, c.span.start, c.span.end))
+                .collect::<Vec<_>>();
+            assert_eq!(characters, expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn import_phase() {
+        use oxc_ast::ast::{ModuleDeclaration, Statement};
+        use oxc_syntax::module_record::ImportPhase;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let sources = [
+            ("import source x from 'x.wasm'", Some(ImportPhase::Source)),
+            ("import source from from 'x.wasm'", Some(ImportPhase::Source)),
+            ("import defer * as ns from 'x'", Some(ImportPhase::Defer)),
+            // Default imports named `source` and `defer`
+            ("import source from 'x'", None),
+            ("import defer from 'x'", None),
+            ("import source, { x } from 'x'", None),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let Some(Statement::ModuleDeclaration(decl)) = ret.program.body.first() else {
+                panic!("{source}");
+            };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { panic!("{source}") };
+            assert_eq!(decl.phase, expected, "{source}");
+        }
+
+        for source in ["import source { x } from 'x'", "import defer x from 'x'"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn proposals() {
+        use oxc_ast::ast::{Expression, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = [
+            "x |> f",
+            "x = do { if (y) { 1 } else { 2 } }",
+            "f = () => throw new Error()",
+            "x ?? throw y",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert!(ret.errors[0].to_string().contains("proposal is not enabled"), "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type)
+                .pipeline_operator(true)
+                .do_expressions(true)
+                .throw_expressions(true)
+                .parse();
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type)
+                .with_options(ParseOptions::experimental())
+                .parse();
+            assert!(ret.errors.is_empty(), "{source}");
+        }
+
+        let ret = Parser::new(&allocator, "x |> f |> g ?? h", source_type)
+            .pipeline_operator(true)
+            .parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else { panic!() };
+        // `(x |> f) |> (g ?? h)`
+        let Expression::PipelineExpression(expr) = &stmt.expression else { panic!() };
+        assert!(matches!(expr.left, Expression::PipelineExpression(_)));
+        assert!(matches!(expr.right, Expression::LogicalExpression(_)));
+
+        // `do` and `throw` statements are not affected
+        let source = "do { x } while (y); throw x";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn using_declarations() {
+        use oxc_ast::ast::{Declaration, Statement};
+
+        let allocator = Allocator::default();
+        let module = SourceType::default().with_module(true);
+        let sources = [
+            "using x = f()",
+            "await using x = f()",
+            "async function f() { await using x = g() }",
+            "for (using x of y);",
+            "for (await using x of y);",
+            // `using` and `await using` are identifiers otherwise
+            "using\nx = f()",
+            "await using\nx = f()",
+            "for (using of y);",
+            "using[x] = f()",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, module).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+        }
+
+        let ret = Parser::new(&allocator, "using\nx = f()", module).parse();
+        assert_eq!(ret.program.body.len(), 2);
+        assert!(matches!(ret.program.body[0], Statement::ExpressionStatement(_)));
+        let ret = Parser::new(&allocator, "await using x = f()", module).parse();
+        let Statement::Declaration(Declaration::UsingDeclaration(decl)) = &ret.program.body[0]
+        else {
+            panic!()
+        };
+        assert!(decl.is_await);
+
+        let sources =
+            ["function f() { await using x = g() }", "if (x) using y = f()", "for (using x in y);"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, module).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn minimal() {
+        use oxc_ast::ast::{Expression, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "// comment\nx = (`a\\n${b}`)\ny";
+        let ret = Parser::new(&allocator, source, source_type)
+            .with_options(ParseOptions::minimal())
+            .parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.trivias.inserted_semicolons.is_empty());
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else { panic!() };
+        let Expression::AssignmentExpression(expr) = &stmt.expression else { panic!() };
+        let Expression::TemplateLiteral(lit) = &expr.right else { panic!() };
+        assert!(lit.quasis.iter().all(|quasi| quasi.value.cooked.is_none()));
+
+        // Invalid escapes are still reported
+        let ret = Parser::new(&allocator, "`\\u`", source_type)
+            .with_options(ParseOptions::minimal())
+            .parse();
+        assert!(!ret.errors.is_empty());
+    }
+
     #[test]
     fn memory_leak() {
         let allocator = Allocator::default();
@@ -454,6 +1469,389 @@ mod test {
         }
     }
 
+    #[test]
+    fn nesting_too_deep() {
+        let allocator = Allocator::default();
+        let js = SourceType::default();
+        let ts = SourceType::default().with_typescript(true);
+        let jsx = SourceType::default().with_jsx(true);
+        let sources = [
+            ("a ? b : ".repeat(10_000) + "c", js),
+            ("(".repeat(10_000) + &")".repeat(10_000), js),
+            ("[".repeat(10_000) + &"]".repeat(10_000), js),
+            ("!".repeat(10_000) + "a", js),
+            ("{".repeat(10_000) + &"}".repeat(10_000), js),
+            ("let ".to_string() + &"[".repeat(10_000) + "a" + &"]".repeat(10_000) + " = x", js),
+            ("let a: ".to_string() + &"A<".repeat(10_000) + "b" + &">".repeat(10_000), ts),
+            ("<a>".repeat(10_000) + &"</a>".repeat(10_000), jsx),
+        ];
+        for (source, source_type) in &sources {
+            let ret = Parser::new(&allocator, source, *source_type).max_nesting_depth(100).parse();
+            assert!(ret.program.is_empty());
+            assert!(ret.panicked);
+            assert_eq!(
+                ret.errors.first().unwrap().to_string(),
+                "Nesting too deep, exceeds the limit of 100 levels"
+            );
+        }
+    }
+
+    #[test]
+    fn nesting_within_limit() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a ? b : ".repeat(20) + "c";
+        let ret = Parser::new(&allocator, &source, source_type).max_nesting_depth(100).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 1);
+    }
+
+    #[test]
+    fn source_too_long() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let x = 1;";
+        let ret = Parser::new(&allocator, source, source_type).max_source_length(5).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(
+            ret.errors.first().unwrap().to_string(),
+            "Source length exceeds the limit of 5 bytes"
+        );
+        let ret = Parser::new(&allocator, source, source_type).max_source_length(10).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn too_many_tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a;".repeat(1000);
+        let ret = Parser::new(&allocator, &source, source_type).max_token_count(100).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(
+            ret.errors.first().unwrap().to_string(),
+            "Token count exceeds the limit of 100 tokens"
+        );
+        let ret = Parser::new(&allocator, &source, source_type).max_token_count(2001).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn too_many_nodes() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = format!("[{}];", "a, ".repeat(1000));
+        let ret = Parser::new(&allocator, &source, source_type).max_node_count(100).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(
+            ret.errors.first().unwrap().to_string(),
+            "Node count exceeds the limit of 100 nodes"
+        );
+
+        let source = "a;".repeat(1000);
+        let ret = Parser::new(&allocator, &source, source_type).max_node_count(10_000).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn memory_limit() {
+        let source_type = SourceType::default();
+        let source = "a;".repeat(100_000);
+        let allocator = Allocator::with_memory_limit(64 * 1024);
+        let ret = Parser::new(&allocator, &source, source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.errors.first().unwrap().to_string().starts_with("Memory limit exceeded"));
+
+        let allocator = Allocator::with_memory_limit(64 * 1024 * 1024);
+        let ret = Parser::new(&allocator, &source, source_type).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn parse_expression() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, " a + b ", source_type).parse_expression();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        let Expression::BinaryExpression(expr) = &ret.expression else { panic!() };
+        assert_eq!(expr.span, Span::new(1, 6));
+
+        let ret = Parser::new(&allocator, "a + b; c", source_type).parse_expression();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(
+            ret.errors.first().unwrap().to_string(),
+            "Unexpected input after the end of the fragment"
+        );
+
+        let ret = Parser::new(&allocator, "a +", source_type).parse_expression();
+        assert!(ret.panicked);
+        assert!(matches!(ret.expression, Expression::NullLiteral(_)));
+    }
+
+    #[test]
+    fn parse_statement() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let ret = Parser::new(&allocator, "export const a = 1;", source_type).parse_statement();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        assert!(matches!(ret.statement, Statement::ModuleDeclaration(_)));
+
+        let ret = Parser::new(&allocator, "if (a) b // c", source_type).parse_statement();
+        assert!(ret.errors.is_empty());
+        assert!(matches!(ret.statement, Statement::IfStatement(_)));
+        assert_eq!(ret.trivias.comments.len(), 1);
+
+        let ret = Parser::new(&allocator, "a\nb", source_type).parse_statement();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+
+        let ret = Parser::new(&allocator, "", source_type).parse_statement();
+        assert!(ret.panicked);
+        assert!(matches!(ret.statement, Statement::EmptyStatement(_)));
+    }
+
+    #[test]
+    fn parse_json() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = r#"{ "name": "oxc", "private": true, "files": [1, -2.5e3, null] }"#;
+        let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Json);
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        let object = ret.value.as_object().unwrap();
+        assert_eq!(object.get("name").and_then(JsonValue::as_str), Some("oxc"));
+        assert_eq!(object.get("private").and_then(JsonValue::as_bool), Some(true));
+        let files = object.get("files").and_then(JsonValue::as_array).unwrap();
+        assert_eq!(files.elements[1].as_f64(), Some(-2500.0));
+        assert_eq!(files.elements[1].span(), Span::new(47, 53));
+        assert!(files.elements[2].is_null());
+
+        let source = "{\n  // comment\n  \"a\": [1,],\n}";
+        let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Jsonc);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.trivias.comments.len(), 1);
+        let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Json);
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 3);
+
+        let source = "{ unquoted: 'single', hex: 0xFF, inf: -Infinity, leading: .5, }";
+        let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Json5);
+        assert!(ret.errors.is_empty());
+        let object = ret.value.as_object().unwrap();
+        assert_eq!(object.get("unquoted").and_then(JsonValue::as_str), Some("single"));
+        assert_eq!(object.get("hex").and_then(JsonValue::as_f64), Some(255.0));
+        assert_eq!(object.get("inf").and_then(JsonValue::as_f64), Some(f64::NEG_INFINITY));
+        assert_eq!(object.get("leading").and_then(JsonValue::as_f64), Some(0.5));
+
+        for source in
+            ["01", "1.", ".5", "+1", "0x1", "'a'", r#""\x41""#, "1_000", "{a: 1}", "[1 2]", ""]
+        {
+            let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Json);
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+        for source in ["0o7", "1n", "01", "1_000"] {
+            let ret = Parser::new(&allocator, source, source_type).parse_json(JsonDialect::Json5);
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn time_budget() {
+        use std::cell::Cell;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a;".repeat(10_000);
+        let calls = Cell::new(0);
+        let is_over_budget = || {
+            calls.set(calls.get() + 1);
+            calls.get() > 2
+        };
+        let ret =
+            Parser::new(&allocator, &source, source_type).time_budget(&is_over_budget).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Parsing exceeded its time budget");
+        assert_eq!(calls.get(), 3);
+
+        let never = || false;
+        let ret = Parser::new(&allocator, &source, source_type).time_budget(&never).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.program.body.len(), 10_000);
+    }
+
+    #[test]
+    fn cancelled() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a; b;";
+        let token = CancellationToken::new();
+        let ret = Parser::new(&allocator, source, source_type).cancellation_token(&token).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.program.body.len(), 2);
+
+        token.cancel();
+        let ret = Parser::new(&allocator, source, source_type).cancellation_token(&token).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Analysis was cancelled");
+    }
+
+    #[test]
+    fn incomplete_jsx() {
+        use oxc_ast::ast::{Expression, JSXChild, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let unclosed_element = "Expected corresponding JSX closing tag for `span`";
+        let expression_expected = "Expected an expression or `}` in JSX expression container";
+        let value_expected = "Expected a JSX attribute value";
+        let sources = [
+            ("<div><span></div>;", unclosed_element),
+            ("<div><span>", unclosed_element),
+            ("<><span></>;", unclosed_element),
+            ("<div><span>text</div>;", unclosed_element),
+            ("<>", "Expected corresponding closing tag for JSX fragment"),
+            ("<div>{</div>;", expression_expected),
+            ("<div a={>text</div>;", expression_expected),
+            ("<div a= >text</div>;", value_expected),
+            ("<div a= b='c'>text</div>;", value_expected),
+            ("<div a=>text</div>;", value_expected),
+        ];
+        for (source, error) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.program.body.len(), 1, "{source}");
+            assert_eq!(ret.errors.first().unwrap().to_string(), error, "{source}");
+        }
+
+        let ret = Parser::new(&allocator, "<div><span></div>;", source_type).parse();
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::JSXElement(div) = &stmt.expression else { unreachable!() };
+        assert!(div.closing_element.is_some());
+        let JSXChild::Element(span) = &div.children[0] else { unreachable!() };
+        assert!(span.closing_element.is_none());
+    }
+
+    #[test]
+    fn regex_flag_spans() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = [
+            ("/a/gx;", "Unexpected flag x in regular expression literal", 4, 1),
+            ("/a/gé;", "Unexpected flag é in regular expression literal", 4, 2),
+            ("/a/gig;", "Flag g is mentioned twice in regular expression literal", 5, 1),
+        ];
+        for (source, message, offset, len) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            let error = ret.errors.first().unwrap();
+            assert_eq!(error.to_string(), message, "{source}");
+            let label = error.labels().unwrap().next().unwrap();
+            assert_eq!((label.offset(), label.len()), (offset, len), "{source}");
+            assert!(source.is_char_boundary(label.offset() + label.len()), "{source}");
+        }
+    }
+
+    #[test]
+    fn escaped_string_literals() {
+        use oxc_ast::ast::{Expression, JSXAttributeItem, JSXAttributeValue, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let sources = [
+            ("'abc'", "abc", false),
+            ("'a\\x62c'", "abc", true),
+            ("'a\\\nbc'", "abc", true),
+            ("\"a'b\"", "a'b", false),
+        ];
+        for (source, value, escaped) in sources {
+            // Not a directive
+            let source = format!("x = {source}");
+            let ret = Parser::new(&allocator, &source, source_type).parse();
+            let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+            let Expression::AssignmentExpression(assignment) = &stmt.expression else {
+                unreachable!()
+            };
+            let Expression::StringLiteral(lit) = &assignment.right else { unreachable!() };
+            assert_eq!((lit.value.as_str(), lit.escaped), (value, escaped), "{source}");
+        }
+
+        let source = "<a b='\\x62' />";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        let JSXAttributeItem::Attribute(attr) = &element.opening_element.attributes[0] else {
+            unreachable!()
+        };
+        let Some(JSXAttributeValue::StringLiteral(lit)) = &attr.value else { unreachable!() };
+        assert_eq!((lit.value.as_str(), lit.escaped), ("\\x62", false));
+    }
+
+    #[test]
+    fn node_spans() {
+        use oxc_ast::ast::{ClassElement, Declaration, Expression, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let source = "@dec\nclass Foo { @a static async bar(x: number): void {} @b baz = 1; }
+function qux(a): void {}
+const f = async (a): void => a;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let text = |span: Span| span.source_text(source);
+
+        let Statement::Declaration(Declaration::ClassDeclaration(class)) = &ret.program.body[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(text(class.name_span().unwrap()), "Foo");
+        assert!(text(class.body_span()).starts_with("{ @a"));
+        assert!(text(class.span_without_decorators(source)).starts_with("class Foo"));
+        let ClassElement::MethodDefinition(method) = &class.body.body[0] else { unreachable!() };
+        assert_eq!(text(method.name_span()), "bar");
+        assert_eq!(text(method.body_span().unwrap()), "{}");
+        assert_eq!(
+            text(method.span_without_decorators(source)),
+            "static async bar(x: number): void {}"
+        );
+        let ClassElement::PropertyDefinition(property) = &class.body.body[1] else {
+            unreachable!()
+        };
+        assert_eq!(text(property.name_span()), "baz");
+        // The span of a property definition includes its semicolon
+        assert_eq!(text(property.span_without_decorators(source)), "baz = 1;");
+
+        let Statement::Declaration(Declaration::FunctionDeclaration(function)) =
+            &ret.program.body[1]
+        else {
+            unreachable!()
+        };
+        assert_eq!(text(function.name_span().unwrap()), "qux");
+        assert_eq!(text(function.body_span().unwrap()), "{}");
+        assert_eq!(text(function.signature_span()), "function qux(a): void");
+
+        let Statement::Declaration(Declaration::VariableDeclaration(decl)) = &ret.program.body[2]
+        else {
+            unreachable!()
+        };
+        let Some(Expression::ArrowFunctionExpression(arrow)) = &decl.declarations[0].init else {
+            unreachable!()
+        };
+        assert_eq!(text(arrow.signature_span()), "async (a): void");
+        assert_eq!(text(arrow.body_span()), "a");
+    }
+
     // Source with length MAX_LEN + 1 fails to parse.
     // Skip this test on 32-bit systems as impossible to allocate a string longer than `isize::MAX`.
     #[cfg(target_pointer_width = "64")]
@@ -482,6 +1880,224 @@ mod test {
         assert_eq!(ret.errors.first().unwrap().to_string(), "Source length exceeds 4 GiB limit");
     }
 
+    fn function_declaration<'a, 'b>(program: &'b Program<'a>, index: usize) -> &'b Function<'a> {
+        match &program.body[index] {
+            Statement::Declaration(Declaration::FunctionDeclaration(func)) => func,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn lazy_function_bodies() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "function f(a) { if (a) { return `${ {b: a} }}` } }
+async function g() { return /}/ }
+class A { m() { return a / b / c } }
+let h = () => { return 1 };";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 4);
+        assert_eq!(ret.deferred_bodies.len(), 3);
+        let f = function_declaration(&ret.program, 0);
+        let body = f.body.as_ref().unwrap();
+        assert!(body.statements.is_empty());
+        assert_eq!(ret.deferred_bodies[0], body.span);
+
+        let expected = Parser::new(&allocator, source, source_type).parse();
+        assert!(expected.deferred_bodies.is_empty());
+        for index in [0, 1] {
+            let func = function_declaration(&ret.program, index);
+            let deferred = Parser::new(&allocator, source, source_type).parse_deferred_body(func);
+            assert!(deferred.errors.is_empty());
+            assert!(!deferred.panicked);
+            let expected = function_declaration(&expected.program, index).body.as_ref().unwrap();
+            assert_eq!(format!("{:?}", deferred.body), format!("{expected:?}"));
+        }
+    }
+
+    #[test]
+    fn lazy_function_bodies_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "function f() { function g() {} a b }";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        let deferred = Parser::new(&allocator, source, source_type)
+            .lazy_function_bodies(true)
+            .parse_deferred_body(function_declaration(&ret.program, 0));
+        assert!(deferred.panicked);
+        assert_eq!(deferred.errors.len(), 1);
+
+        let ret = Parser::new(&allocator, "function f() { a", source_type)
+            .lazy_function_bodies(true)
+            .parse();
+        assert!(ret.panicked);
+
+        let source_type = source_type.with_jsx(true);
+        let source = "function f() { return <a>{'}'}</a> }";
+        let ret = Parser::new(&allocator, source, source_type).lazy_function_bodies(true).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.deferred_bodies.is_empty());
+    }
+
+    #[test]
+    fn declaration_file() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let source = "export const c: number;
+export declare function f(a: string): number;
+function g(x: number): string { a b }
+class A { static { init() } m(): void { return `${ {} }` } }
+let h = (): void => { /}/ };";
+        let ret = Parser::new(&allocator, source, source_type)
+            .with_options(ParseOptions::declarations())
+            .parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 5);
+        assert!(ret.deferred_bodies.is_empty());
+        let g = function_declaration(&ret.program, 2);
+        assert!(g.body.as_ref().unwrap().statements.is_empty());
+        assert!(g.return_type.is_some());
+
+        // Without the option, `c` has no initializer and `g` has a syntax error
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.errors.is_empty());
+    }
+
+    #[test]
+    fn attach_comments() {
+        use oxc_ast::CommentPosition::{Inner, Leading, Trailing};
+
+        let allocator = Allocator::default();
+        let source =
+            "// license\nconst a = /* #__PURE__ */ f(); // a\n\nfunction g() {\n  /* empty */\n}\n";
+        let ret = Parser::new(&allocator, source, SourceType::default())
+            .trivias(false)
+            .attach_comments(true)
+            .parse();
+        let comments = ret
+            .comments
+            .iter()
+            .flat_map(|(node, comments)| {
+                comments.iter().map(move |comment| {
+                    (node.source_text(source), comment.position, comment.text(source))
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            comments,
+            [
+                ("const a = /* #__PURE__ */ f();", Leading, " license"),
+                ("const a = /* #__PURE__ */ f();", Trailing, " a"),
+                ("f()", Leading, " #__PURE__ "),
+                ("{\n  /* empty */\n}", Inner, " empty "),
+            ]
+        );
+
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        assert!(ret.comments.is_empty());
+    }
+
+    #[test]
+    fn line_index() {
+        let allocator = Allocator::default();
+        let sources = [
+            "const a = '中文';\r\n/* 😀\n */ let b = `\n${a}`;\u{2028}c",
+            "<div>\n  é\n</div>;",
+            "a = 😀; b\n c",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, SourceType::default().with_jsx(true))
+                .line_index(true)
+                .parse();
+            assert_eq!(ret.line_index, Some(oxc_span::LineIndex::new(source)), "{source}");
+        }
+
+        let ret = Parser::new(&allocator, sources[0], SourceType::default()).parse();
+        assert!(ret.line_index.is_none());
+    }
+
+    #[test]
+    fn stats() {
+        let allocator = Allocator::default();
+        let source = "if (a) { b(1, 2); }";
+        let ret = Parser::new(&allocator, source, SourceType::default()).stats(true).parse();
+        let stats = ret.stats.unwrap();
+        assert_eq!(stats.node_counts.get("IfStatement"), Some(&1));
+        assert_eq!(stats.node_counts.get("IdentifierReference"), Some(&2));
+        assert_eq!(stats.node_counts.get("NumericLiteral"), Some(&2));
+        // `if ( a ) { b ( 1 , 2 ) ; }` and EOF
+        assert_eq!(stats.token_count, 14);
+        assert!(stats.allocated_bytes > 0);
+
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        assert!(ret.stats.is_none());
+    }
+
+    #[test]
+    fn lossless() {
+        use oxc_ast::SourcePieceKind::{self, Comment, Token, Unparsed, Whitespace};
+
+        fn pieces(source: &str, source_type: SourceType) -> Vec<(SourcePieceKind, &str)> {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source, source_type).lossless(true).parse();
+            let mut end = 0;
+            let pieces = ret
+                .trivias
+                .pieces
+                .iter()
+                .map(|piece| {
+                    assert_eq!(piece.span.start, end, "{source}");
+                    end = piece.span.end;
+                    (piece.kind, &source[piece.span.start as usize..piece.span.end as usize])
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(end as usize, source.len(), "{source}");
+            assert_eq!(ret.panicked, pieces.iter().any(|(kind, _)| *kind == Unparsed), "{source}");
+            pieces
+        }
+
+        let source_type = SourceType::default();
+        assert_eq!(
+            pieces("a /* b */ +\n  c; // d", source_type),
+            [
+                (Token, "a"),
+                (Whitespace, " "),
+                (Comment, "/* b */"),
+                (Whitespace, " "),
+                (Token, "+"),
+                (Whitespace, "\n  "),
+                (Token, "c"),
+                (Token, ";"),
+                (Whitespace, " "),
+                (Comment, "// d"),
+            ]
+        );
+        assert_eq!(
+            pieces("a b;\n", source_type),
+            [(Token, "a"), (Whitespace, " "), (Unparsed, "b;"), (Whitespace, "\n")]
+        );
+        assert_eq!(pieces("", source_type), []);
+
+        let sources = [
+            (source_type, "#!/usr/bin/env node\nlet a = 1;\n"),
+            (source_type, "\u{feff}a;\u{a0}b;\r\n"),
+            (source_type, "let re = /a}/g; `a${b}c${ {d} }e`;"),
+            (source_type, "if (a) b; else /* c */ { d }\n// e"),
+            (source_type.with_jsx(true), "<a b=\"c\">{d} e {/* f */}</a>;"),
+            (source_type.with_typescript(true), "let a: Array<Array<number>> = f<T>(1 >> 2);"),
+            (
+                source_type.with_typescript(true),
+                "let f = (a: number): string => a; g(<T,>(b) => b);",
+            ),
+        ];
+        for (source_type, source) in sources {
+            let pieces = pieces(source, source_type);
+            assert!(pieces.iter().all(|(kind, _)| *kind != Unparsed), "{source}");
+        }
+    }
+
     // Source with length MAX_LEN parses OK.
     // This test takes over 1 minute on an M1 Macbook Pro unless compiled in release mode.
     // `not(debug_assertions)` is a proxy for detecting release mode.
@@ -0,0 +1,247 @@
+//! Error recovery for files being edited, see [`crate::Parser::error_recovery`].
+
+use oxc_ast::ast::Statement;
+use oxc_diagnostics::{Cancelled, Error, Result};
+
+use crate::{diagnostics, lexer::Kind, ParserImpl};
+
+impl<'a> ParserImpl<'a> {
+    /// Parse a statement of a statement list with `parse`.
+    ///
+    /// In error recovery mode, a statement with a syntax error is replaced by an `ErrorStatement`
+    /// spanning the tokens up to the next statement, and the error is reported.
+    pub(crate) fn recover_statement(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Statement<'a>>,
+    ) -> Result<Statement<'a>> {
+        if !self.error_recovery || self.speculation_depth > 0 {
+            return parse(self);
+        }
+
+        let span = self.start_span();
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        let deferred_bodies_len = self.deferred_bodies.len();
+        let jsx_open_elements_len = self.state.jsx_open_elements.len();
+
+        let error = match parse(self) {
+            // Only a missing expression is parsed without consuming a token, e.g. `)` in `a\n)`
+            Ok(_) if self.cur_token().start == span.start => self.unexpected(),
+            Ok(stmt) => return Ok(stmt),
+            Err(error) if is_unrecoverable(&error) => return Err(error),
+            Err(error) => error,
+        };
+
+        // The tokens are skipped from the start of the statement to match its brackets,
+        // and the errors of the statement other than `error` are dropped with its nodes
+        let error_start = self.cur_token().start;
+        self.rewind(checkpoint);
+        self.ctx = ctx;
+        self.deferred_bodies.truncate(deferred_bodies_len);
+        self.state.jsx_open_elements.truncate(jsx_open_elements_len);
+        self.state.decorators.clear();
+        let lexer_errors_len = self.lexer.errors.len();
+        self.skip_to_next_statement(error_start);
+        self.lexer.errors.truncate(lexer_errors_len);
+
+        self.error(error);
+        Ok(self.ast.error_statement(self.end_span(span)))
+    }
+
+    /// Skip the tokens of a statement with a syntax error at `error_start`.
+    ///
+    /// After the error, stop after a `;`, before the `}` closing the enclosing block,
+    /// or before a token on a new line which is outside of brackets or a keyword starting
+    /// a statement. At least one token is skipped.
+    fn skip_to_next_statement(&mut self, error_start: u32) {
+        let start = self.cur_token().start;
+        // Closing kinds of the open brackets, `TemplateHead` for the `${` of a template literal
+        let mut brackets = vec![];
+        let mut prev_kind = Kind::Undetermined;
+        loop {
+            let token = self.cur_token();
+            let after_error = token.start >= error_start && token.start > start;
+            match token.kind {
+                Kind::Eof => break,
+                Kind::RCurly if after_error && brackets.is_empty() => break,
+                kind if token.start > error_start
+                    && token.is_on_new_line
+                    && (brackets.is_empty() || Self::starts_statement(kind)) =>
+                {
+                    break;
+                }
+                Kind::Semicolon if after_error => {
+                    self.bump_any();
+                    break;
+                }
+                Kind::LParen => brackets.push(Kind::RParen),
+                Kind::LBrack => brackets.push(Kind::RBrack),
+                Kind::LCurly => brackets.push(Kind::RCurly),
+                Kind::TemplateHead => brackets.push(Kind::TemplateHead),
+                kind @ (Kind::RParen | Kind::RBrack) => {
+                    if brackets.last() == Some(&kind) {
+                        brackets.pop();
+                    }
+                }
+                Kind::RCurly => {
+                    if brackets.pop() == Some(Kind::TemplateHead) {
+                        self.re_lex_template_substitution_tail();
+                        if self.at(Kind::TemplateMiddle) {
+                            brackets.push(Kind::TemplateHead);
+                        }
+                    }
+                }
+                Kind::Slash | Kind::SlashEq if !Self::ends_operand(prev_kind) => {
+                    self.read_regex();
+                }
+                _ => {}
+            }
+            prev_kind = self.cur_kind();
+            self.bump_any();
+        }
+    }
+
+    fn starts_statement(kind: Kind) -> bool {
+        matches!(
+            kind,
+            Kind::Var
+                | Kind::Let
+                | Kind::Const
+                | Kind::Function
+                | Kind::Class
+                | Kind::If
+                | Kind::For
+                | Kind::While
+                | Kind::Do
+                | Kind::Return
+                | Kind::Switch
+                | Kind::Case
+                | Kind::Default
+                | Kind::Throw
+                | Kind::Try
+                | Kind::Break
+                | Kind::Continue
+                | Kind::Debugger
+                | Kind::With
+                | Kind::Import
+                | Kind::Export
+        )
+    }
+}
+
+/// Errors which stop parsing even in error recovery mode
+fn is_unrecoverable(error: &Error) -> bool {
+    error.is::<Cancelled>()
+        || error.is::<diagnostics::NestingTooDeep>()
+        || error.is::<diagnostics::TooManyTokens>()
+        || error.is::<diagnostics::TooManyNodes>()
+        || error.is::<diagnostics::TimeBudgetExceeded>()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Declaration, Expression, Statement};
+    use oxc_span::{GetSpan, SourceType};
+
+    use crate::Parser;
+
+    /// Source text of the top-level statements, and the number of errors
+    fn parse(source: &str) -> (Vec<String>, usize) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let ret = Parser::new(&allocator, source, source_type).error_recovery(true).parse();
+        assert!(!ret.panicked, "{source}");
+        let statements = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| {
+                let span = stmt.span();
+                let text = &source[span.start as usize..span.end as usize];
+                match stmt {
+                    Statement::ErrorStatement(_) => format!("error: {text}"),
+                    _ => text.to_string(),
+                }
+            })
+            .collect();
+        (statements, ret.errors.len())
+    }
+
+    #[test]
+    fn error_statements() {
+        let sources: [(&str, &[&str]); 8] = [
+            ("a b;\nc;", &["error: a b;", "c;"]),
+            ("a b\nlet c = 1;", &["error: a b", "let c = 1;"]),
+            ("foo({ a: 1 b: 2 });\nbar();", &["error: foo({ a: 1 b: 2 });", "bar();"]),
+            ("let x = foo(;\nbar();", &["error: let x = foo(;", "bar();"]),
+            ("}\na;", &["error: }", "a;"]),
+            ("a = `${b c}`; d;", &["error: a = `${b c}`;", "d;"]),
+            ("if (a) { b c } d;", &["if (a) { b c }", "d;"]),
+            (
+                "import { a from 'a';\nexport const b = 1;",
+                &["error: import { a from 'a';", "export const b = 1;"],
+            ),
+        ];
+        for (source, expected) in sources {
+            let (statements, errors) = parse(source);
+            assert_eq!(statements, expected, "{source}");
+            assert_eq!(errors, 1, "{source}");
+        }
+    }
+
+    #[test]
+    fn error_statement_in_block() {
+        let allocator = Allocator::default();
+        let source = "function f() {\n  a b;\n  return 1;\n}";
+        let ret =
+            Parser::new(&allocator, source, SourceType::default()).error_recovery(true).parse();
+        assert_eq!(ret.errors.len(), 1);
+        let Some(Statement::Declaration(decl)) = ret.program.body.first() else { panic!() };
+        let Declaration::FunctionDeclaration(func) = decl else { panic!() };
+        let body = &func.body.as_ref().unwrap().statements;
+        assert!(matches!(body[0], Statement::ErrorStatement(_)));
+        assert!(matches!(body[1], Statement::ReturnStatement(_)));
+    }
+
+    #[test]
+    fn error_expressions() {
+        let allocator = Allocator::default();
+        let source = "a = ;";
+        let ret =
+            Parser::new(&allocator, source, SourceType::default()).error_recovery(true).parse();
+        assert_eq!(ret.errors.len(), 1);
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else { panic!() };
+        let Expression::AssignmentExpression(expr) = &stmt.expression else { panic!() };
+        let Expression::ErrorExpression(error) = &expr.right else { panic!() };
+        assert_eq!((error.span.start, error.span.end), (4, 4));
+
+        for source in ["f(a, ;", "if () {}", "x = [1, 2 + ];", "({ a: });", "a ? : b;"] {
+            let ret =
+                Parser::new(&allocator, source, SourceType::default()).error_recovery(true).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn unrecoverable() {
+        let allocator = Allocator::default();
+        let source = "a b; ((((((((c))))))));";
+        let ret = Parser::new(&allocator, source, SourceType::default())
+            .error_recovery(true)
+            .max_nesting_depth(4)
+            .parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+    }
+
+    #[test]
+    fn disabled() {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, "a b;\nc;", SourceType::default()).parse();
+        assert!(ret.panicked);
+        let ret = Parser::new(&allocator, "a = ;", SourceType::default()).parse();
+        assert!(ret.panicked);
+    }
+}
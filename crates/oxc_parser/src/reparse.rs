@@ -0,0 +1,130 @@
+//! Incremental parsing for editors, see [`crate::Parser::reparse`].
+
+use oxc_ast::ast::Program;
+use oxc_span::{GetSpan, Span};
+
+use crate::{tokenizer::MAX_LOOKAHEAD, ParserImpl, ParserReturn};
+
+/// A change of source text: `span` of the old source text is replaced by `new_len` bytes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_len: u32,
+}
+
+impl TextEdit {
+    pub fn new(span: Span, new_len: u32) -> Self {
+        Self { span, new_len }
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Parse the source text after the top-level statements of `old` which are unaffected by `edit`,
+    /// and append the statements to them.
+    ///
+    /// The source text before `edit` is unchanged, so are the statements which end before it,
+    /// except that whether a statement ends, e.g. by automatic semicolon insertion,
+    /// depends on the first token of the next statement.
+    /// A statement is reused when the statement after it ends before the edit as well.
+    pub(crate) fn reparse(mut self, old: ParserReturn<'a>, edit: TextEdit) -> ParserReturn<'a> {
+        let Some(reused) = Self::reusable_statements(&old, edit) else { return self.parse() };
+        if old.program.source_type != self.source_type
+            || self.source_text.len() > self.max_source_length
+        {
+            return self.parse();
+        }
+
+        let ParserReturn { program, trivias, .. } = old;
+        let Program { directives, hashbang, body: mut statements, .. } = program;
+        let offset = statements[reused - 1].span().end;
+        statements.truncate(reused);
+
+        self.lexer.trivia_builder.restore(trivias, offset);
+        self.lexer.seek(offset);
+        let result = self.parse_program_after(offset, directives, hashbang, statements);
+        self.finish(result)
+    }
+
+    /// Number of top-level statements of `old` to reuse, `None` if there are none
+    fn reusable_statements(old: &ParserReturn<'a>, edit: TextEdit) -> Option<usize> {
+        // Errors are not attributed to statements, so they cannot be kept
+        if old.panicked || !old.errors.is_empty() {
+            return None;
+        }
+        let body = &old.program.body;
+        let before_edit =
+            body.partition_point(|stmt| stmt.span().end + MAX_LOOKAHEAD < edit.span.start);
+        before_edit.checked_sub(1).filter(|&reused| reused > 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    #[allow(clippy::wildcard_imports)]
+    use oxc_ast::ast::*;
+    use oxc_span::{SourceType, Span};
+
+    use super::TextEdit;
+    use crate::Parser;
+
+    fn address(stmt: &Statement) -> Option<usize> {
+        match stmt {
+            Statement::ExpressionStatement(stmt) => Some(std::ptr::addr_of!(**stmt) as usize),
+            _ => None,
+        }
+    }
+
+    /// Reparse `old_source` after replacing `start..end` with `text`, check that the result is
+    /// the same as parsing the new source text from scratch, and return the number of reused
+    /// expression statements and the number of statements.
+    fn reparse(old_source: &str, start: u32, end: u32, text: &str) -> (usize, usize) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let new_source =
+            format!("{}{text}{}", &old_source[..start as usize], &old_source[end as usize..]);
+
+        let old = Parser::new(&allocator, old_source, source_type).parse();
+        let old_addresses: Vec<_> = old.program.body.iter().map(address).collect();
+        #[allow(clippy::cast_possible_truncation)]
+        let edit = TextEdit::new(Span::new(start, end), text.len() as u32);
+        let ret = Parser::new(&allocator, &new_source, source_type).reparse(old, edit);
+        let expected = Parser::new(&allocator, &new_source, source_type).parse();
+
+        assert_eq!(format!("{:?}", ret.program), format!("{:?}", expected.program));
+        assert_eq!(format!("{:?}", ret.trivias), format!("{:?}", expected.trivias));
+        assert_eq!(ret.errors.len(), expected.errors.len());
+        assert_eq!(ret.panicked, expected.panicked);
+
+        let reused = ret
+            .program
+            .body
+            .iter()
+            .zip(old_addresses)
+            .take_while(|(stmt, old_address)| {
+                old_address.is_some() && address(stmt) == *old_address
+            })
+            .count();
+        (reused, ret.program.body.len())
+    }
+
+    #[test]
+    fn reuse_statements_before_edit() {
+        // `c` continues as a call
+        assert_eq!(reparse("a;\nb;\nc\nd;", 8, 9, "(d)"), (1, 3));
+        assert_eq!(reparse("a\nb\nc\nd", 6, 7, "+ e"), (1, 3));
+        assert_eq!(reparse("/* a */ a;\nb;\n// c\nc;\nd;", 22, 23, "e"), (1, 4));
+        assert_eq!(reparse("a;\nb;\nc;\n@dec class A {}", 10, 13, "de"), (1, 4));
+        assert_eq!(reparse("a;\nb;\nc;", 8, 8, "\nd;"), (1, 4));
+    }
+
+    #[test]
+    fn parse_all_without_reusable_statements() {
+        assert_eq!(reparse("'use strict';\na;\nb;", 17, 18, "c"), (0, 2));
+        assert_eq!(reparse("a;\nb;", 0, 1, "c"), (0, 2));
+        // The old parse has errors
+        assert_eq!(reparse("a;\nb;\nc;\nd)", 10, 11, ";"), (0, 4));
+        // The new parse has errors
+        assert_eq!(reparse("a;\nb;\nc;\nd;", 9, 10, "("), (0, 0));
+    }
+}
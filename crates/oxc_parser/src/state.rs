@@ -9,6 +9,9 @@ pub struct ParserState<'a> {
     pub not_parenthesized_arrow: HashSet<u32>,
 
     pub decorators: Vec<'a, Decorator<'a>>,
+
+    /// Names of the JSX elements whose children are being parsed, outermost first
+    pub jsx_open_elements: std::vec::Vec<&'a str>,
 }
 
 impl<'a> ParserState<'a> {
@@ -17,6 +20,7 @@ impl<'a> ParserState<'a> {
             allocator,
             not_parenthesized_arrow: HashSet::new(),
             decorators: Vec::new_in(allocator),
+            jsx_open_elements: vec![],
         }
     }
 
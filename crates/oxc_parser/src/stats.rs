@@ -0,0 +1,86 @@
+//! Parser statistics, see [`crate::Parser::stats`].
+
+use std::time::{Duration, Instant};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, AstKind, Visit};
+use rustc_hash::FxHashMap;
+
+/// Statistics of a parse, for tracking the performance of the parser on a code base
+#[derive(Debug, Default, Clone)]
+pub struct ParserStats {
+    /// Number of nodes of each kind in the AST, by [`AstKind::type_name`]
+    pub node_counts: FxHashMap<&'static str, u32>,
+    /// Number of tokens consumed by the parser, tokens lexed again after a rewind included
+    pub token_count: u32,
+    /// Bytes the arena grew by while parsing. The arena grows in whole chunks,
+    /// this is 0 if it was created with enough capacity, see `Allocator::with_capacity`.
+    pub allocated_bytes: usize,
+    /// Time taken to lex and parse the source text
+    pub parse_time: Duration,
+    /// Time taken to collect the trivias, attach the comments and finish the line index
+    pub trivias_time: Duration,
+}
+
+impl ParserStats {
+    /// Total number of nodes in the AST
+    pub fn node_count(&self) -> u32 {
+        self.node_counts.values().sum()
+    }
+}
+
+/// Measurements taken when parsing starts and when the AST is complete
+pub(crate) struct StatsRecorder {
+    start: Option<Instant>,
+    parsed: Option<Instant>,
+    allocated_bytes: usize,
+}
+
+impl StatsRecorder {
+    pub(crate) fn start(allocator: &Allocator) -> Self {
+        Self { start: now(), parsed: None, allocated_bytes: allocator.allocated_bytes() }
+    }
+
+    /// The AST is complete, the trivias are collected next
+    pub(crate) fn parsed(&mut self) {
+        self.parsed = now();
+    }
+
+    pub(crate) fn finish(
+        self,
+        program: &Program,
+        token_count: u32,
+        allocator: &Allocator,
+    ) -> ParserStats {
+        let end = now();
+        let elapsed = |from: Option<Instant>, to: Option<Instant>| match (from, to) {
+            (Some(from), Some(to)) => to.duration_since(from),
+            _ => Duration::ZERO,
+        };
+        let mut counter = NodeCounter::default();
+        counter.visit_program(program);
+        ParserStats {
+            node_counts: counter.counts,
+            token_count,
+            allocated_bytes: allocator.allocated_bytes().saturating_sub(self.allocated_bytes),
+            parse_time: elapsed(self.start, self.parsed),
+            trivias_time: elapsed(self.parsed, end),
+        }
+    }
+}
+
+/// `Instant::now` panics on `wasm32-unknown-unknown`, the timings are 0 there
+fn now() -> Option<Instant> {
+    (!cfg!(target_arch = "wasm32")).then(Instant::now)
+}
+
+#[derive(Default)]
+struct NodeCounter {
+    counts: FxHashMap<&'static str, u32>,
+}
+
+impl<'a> Visit<'a> for NodeCounter {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        *self.counts.entry(kind.type_name()).or_default() += 1;
+    }
+}
@@ -0,0 +1,548 @@
+//! Token level access to the lexer, for syntax highlighters and incremental parsers.
+
+use std::ops::Range;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::Error;
+use oxc_span::{SourceType, Span};
+
+use crate::lexer::{Kind, Lexer, LexerCheckpoint, LexerContext, Token};
+
+/// The lexer looks at most this many bytes past the end of a token to find where it ends,
+/// for `?.` followed by a digit.
+pub(crate) const MAX_LOOKAHEAD: u32 = 2;
+
+/// A lexer which leaves context-sensitive decisions to its consumer.
+///
+/// Some tokens cannot be told apart without knowing the syntactic context.
+/// The parser re-lexes them once it knows better, consumers of `Tokenizer` do the same with:
+///
+/// * [`Tokenizer::re_lex_regex`]: `/` and `/=` are lexed as divisions,
+///   re-lex them as a regular expression where an expression starts.
+/// * [`Tokenizer::re_lex_template_continuation`]: `}` is lexed as a punctuator,
+///   re-lex it as the continuation of a template literal where it closes a `${` substitution.
+/// * [`Tokenizer::re_lex_right_angle`]: `>` is lexed on its own for type arguments,
+///   re-lex it as `>>`, `>=`, ... in expressions.
+/// * [`Tokenizer::re_lex_l_angle`]: `<<`, `<=` and `<<=` are lexed as operators,
+///   re-lex them as `<` where TypeScript type parameters start, e.g. `f<<T>(x: T) => T>()`.
+/// * [`Tokenizer::next_jsx_child`]: lex JSX text after the `>` of an opening tag
+///   or the `}` of an expression container.
+/// * [`Tokenizer::re_lex_jsx_identifier`]: extend an identifier to a JSX name, e.g. `data-id`.
+/// * [`Tokenizer::next_jsx_attribute_value`]: lex a JSX string, which has no escapes, after `=`.
+///
+/// Re-lexing methods apply to the last returned token,
+/// they return it unchanged if it is not of the kind they re-lex.
+///
+/// To try lexing ahead, save the state with [`Tokenizer::checkpoint`]
+/// and go back with [`Tokenizer::rewind`], as the parser does for speculative parsing.
+///
+/// After an edit, [`Tokenizer::relex_edit`] re-lexes only the tokens the edit invalidated.
+pub struct Tokenizer<'a> {
+    lexer: Lexer<'a>,
+    token: Token,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn with_lexer(lexer: Lexer<'a>) -> Self {
+        Self { lexer, token: Token::default() }
+    }
+
+    /// The next token, with `/` as a division and `}` as a punctuator.
+    /// Whitespace and comments are skipped, the last token is `Kind::Eof`.
+    pub fn next_token(&mut self) -> Token {
+        self.token = self.lexer.next_token();
+        self.token
+    }
+
+    /// Re-lex the last `/` or `/=` as a `Kind::RegExp` token.
+    pub fn re_lex_regex(&mut self) -> Token {
+        if matches!(self.token.kind, Kind::Slash | Kind::SlashEq) {
+            let (token, _, _) = self.lexer.next_regex(self.token.kind);
+            self.replace_token(token);
+        }
+        self.token
+    }
+
+    /// Re-lex the last `}` as a `Kind::TemplateMiddle` or `Kind::TemplateTail` token.
+    pub fn re_lex_template_continuation(&mut self) -> Token {
+        if self.token.kind == Kind::RCurly {
+            let token = self.lexer.next_template_substitution_tail();
+            self.replace_token(token);
+        }
+        self.token
+    }
+
+    /// Re-lex the last `>` together with the following `>` and `=` characters.
+    pub fn re_lex_right_angle(&mut self) -> Token {
+        if self.token.kind == Kind::RAngle {
+            let token = self.lexer.next_right_angle();
+            self.replace_token(token);
+        }
+        self.token
+    }
+
+    /// Re-lex the last `<<`, `<=` or `<<=` as `<`.
+    pub fn re_lex_l_angle(&mut self) -> Token {
+        if matches!(self.token.kind, Kind::ShiftLeft | Kind::ShiftLeftEq | Kind::LtEq) {
+            let token = self.lexer.re_lex_as_typescript_l_angle(self.token.kind);
+            self.replace_token(token);
+        }
+        self.token
+    }
+
+    /// The next JSX child: `Kind::JSXText`, `<` or `{`.
+    pub fn next_jsx_child(&mut self) -> Token {
+        self.token = self.lexer.next_jsx_child();
+        self.token
+    }
+
+    /// Re-lex the last identifier or keyword as a JSX identifier, which may contain `-`.
+    pub fn re_lex_jsx_identifier(&mut self) -> Token {
+        if self.token.kind.is_identifier_name() {
+            let token = self.lexer.next_jsx_identifier(self.token.start);
+            self.replace_token(token);
+        }
+        self.token
+    }
+
+    /// The next token as a JSX attribute value, a `Kind::Str` without escapes or any other token.
+    pub fn next_jsx_attribute_value(&mut self) -> Token {
+        self.lexer.set_context(LexerContext::JsxAttributeValue);
+        self.token = self.lexer.next_token();
+        self.lexer.set_context(LexerContext::Regular);
+        self.token
+    }
+
+    /// The unescaped value of a string or identifier token with escapes.
+    pub fn escaped_string(&self, token: Token) -> Option<&'a str> {
+        if token.escaped {
+            self.lexer.escaped_strings.get(&token.start).copied()
+        } else {
+            None
+        }
+    }
+
+    /// Syntax errors found so far.
+    pub fn errors(&self) -> &[Error] {
+        &self.lexer.errors
+    }
+
+    /// Save the state of the tokenizer, to [`Tokenizer::rewind`] to it later. This is O(1).
+    pub fn checkpoint(&self) -> TokenizerCheckpoint<'a> {
+        TokenizerCheckpoint { lexer: self.lexer.checkpoint(), token: self.token }
+    }
+
+    /// Restore the state saved by [`Tokenizer::checkpoint`]. This is O(1).
+    ///
+    /// Tokens and errors found after the checkpoint are discarded.
+    ///
+    /// # Panics
+    /// Panics if `checkpoint` was saved by a tokenizer for another source text.
+    pub fn rewind(&mut self, checkpoint: TokenizerCheckpoint<'a>) {
+        self.lexer.rewind_checked(checkpoint.lexer);
+        self.token = checkpoint.token;
+    }
+
+    /// Re-lex the tokens invalidated by replacing `edit` of the old source text
+    /// with `new_len` bytes, giving the source text of this tokenizer.
+    ///
+    /// `old_tokens` are the tokens of the old source text as returned by [`Tokenizer::next_token`],
+    /// ending with `Kind::Eof`. Re-lexing starts at the last token unaffected by the edit
+    /// and stops as soon as a token lines up with an old token again,
+    /// so only the returned tokens need to be re-lexed as in context and repainted.
+    pub fn relex_edit(&mut self, old_tokens: &[Token], edit: Span, new_len: u32) -> TokenDiff {
+        let edit_end = edit.start + new_len;
+        let delta = i64::from(new_len) - i64::from(edit.size());
+
+        let start = old_tokens.partition_point(|token| token.end + MAX_LOOKAHEAD <= edit.start);
+        let offset = start.checked_sub(1).map_or(0, |index| old_tokens[index].end);
+        self.lexer.seek(offset);
+
+        let mut old_index = start;
+        let mut tokens = vec![];
+        loop {
+            let token = self.next_token();
+            if token.start >= edit_end {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let old_start = (i64::from(token.start) - delta) as u32;
+                while old_index < old_tokens.len() && old_tokens[old_index].start < old_start {
+                    old_index += 1;
+                }
+                if old_tokens.get(old_index).is_some_and(|old| {
+                    old.start == old_start
+                        && old.kind == token.kind
+                        && old.span().size() == token.span().size()
+                        && old.is_on_new_line == token.is_on_new_line
+                }) {
+                    break;
+                }
+            }
+            tokens.push(token);
+            if token.kind == Kind::Eof {
+                old_index = old_tokens.len();
+                break;
+            }
+        }
+
+        let end = tokens.last().map_or(offset, |token| token.end).max(edit_end);
+        TokenDiff { old_tokens: start..old_index, tokens, span: Span::new(offset, end) }
+    }
+
+    /// Re-lexed tokens start at the re-lexed token, on the same line.
+    fn replace_token(&mut self, mut token: Token) {
+        token.start = self.token.start;
+        token.is_on_new_line = self.token.is_on_new_line;
+        self.token = token;
+    }
+}
+
+/// A saved [`Tokenizer`] state, see [`Tokenizer::checkpoint`].
+#[derive(Debug, Clone, Copy)]
+pub struct TokenizerCheckpoint<'a> {
+    lexer: LexerCheckpoint<'a>,
+    token: Token,
+}
+
+/// The tokens invalidated by an edit, see [`Tokenizer::relex_edit`].
+#[derive(Debug)]
+pub struct TokenDiff {
+    /// Indices of the invalidated old tokens.
+    /// Old tokens after them are valid once shifted by the size change of the edit.
+    pub old_tokens: Range<usize>,
+    /// Tokens replacing the invalidated old tokens, in the new source text.
+    pub tokens: Vec<Token>,
+    /// Span of the new source text to repaint, covering the edit and the re-lexed tokens.
+    pub span: Span,
+}
+
+/// The tokens of `source_text` without building an AST, for syntax highlighters.
+///
+/// Unlike [`Tokenizer::next_token`], context-sensitive tokens are re-lexed by looking at
+/// the previous token, as most highlighters do:
+///
+/// * `/` and `/=` are regular expressions unless they follow a token which ends an expression,
+///   e.g. an identifier, a literal, `)` or `]`.
+/// * `}` closing a `${` substitution continues its template literal.
+/// * `>` is combined with the following `>` and `=` characters, except in TypeScript
+///   where it may close type arguments, e.g. `Array<Array<T>>`.
+///
+/// JSX is not recognized, drive a [`Tokenizer`] for JSX or to follow the grammar exactly.
+/// The iterator ends before `Kind::Eof`.
+pub fn tokenize<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> Tokens<'a> {
+    Tokens {
+        tokenizer: Tokenizer::new(allocator, source_text, source_type),
+        typescript: source_type.is_typescript(),
+        prev_kind: None,
+        templates: vec![],
+    }
+}
+
+/// Iterator over the tokens of a source text, see [`tokenize`].
+pub struct Tokens<'a> {
+    tokenizer: Tokenizer<'a>,
+    typescript: bool,
+    prev_kind: Option<Kind>,
+    /// Number of unclosed `{` in each open template substitution, innermost last
+    templates: Vec<u32>,
+}
+
+impl<'a> Tokens<'a> {
+    /// The underlying tokenizer, for [`Tokenizer::escaped_string`] and [`Tokenizer::errors`].
+    pub fn tokenizer(&self) -> &Tokenizer<'a> {
+        &self.tokenizer
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let mut token = self.tokenizer.next_token();
+        match token.kind {
+            Kind::Eof => return None,
+            Kind::Slash | Kind::SlashEq if !self.prev_kind.is_some_and(ends_expression) => {
+                token = self.tokenizer.re_lex_regex();
+            }
+            Kind::RAngle if !self.typescript => token = self.tokenizer.re_lex_right_angle(),
+            Kind::LCurly => {
+                if let Some(depth) = self.templates.last_mut() {
+                    *depth += 1;
+                }
+            }
+            Kind::RCurly => match self.templates.last_mut() {
+                Some(0) => {
+                    token = self.tokenizer.re_lex_template_continuation();
+                    if token.kind == Kind::TemplateTail {
+                        self.templates.pop();
+                    }
+                }
+                Some(depth) => *depth -= 1,
+                None => {}
+            },
+            Kind::TemplateHead => self.templates.push(0),
+            _ => {}
+        }
+        self.prev_kind = Some(token.kind);
+        Some(token)
+    }
+}
+
+/// Whether a `/` after a token of `kind` is a division
+fn ends_expression(kind: Kind) -> bool {
+    kind.is_literal()
+        || (kind.is_identifier_name() && !kind.is_reserved_keyword())
+        || matches!(
+            kind,
+            Kind::This
+                | Kind::Super
+                | Kind::PrivateIdentifier
+                | Kind::RParen
+                | Kind::RBrack
+                | Kind::RCurly
+                | Kind::Plus2
+                | Kind::Minus2
+                | Kind::NoSubstitutionTemplate
+                | Kind::TemplateTail
+        )
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use super::Tokenizer;
+    use crate::lexer::{Kind, Token};
+
+    fn source_of<'a>(source_text: &'a str, tokenizer: &mut Tokenizer, relex: bool) -> &'a str {
+        let token = tokenizer.next_token();
+        let token = if relex { tokenizer.re_lex_regex() } else { token };
+        &source_text[token.start as usize..token.end as usize]
+    }
+
+    #[test]
+    fn regex() {
+        let allocator = Allocator::default();
+        let source_text = "a / b /g";
+        let mut tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        assert_eq!(source_of(source_text, &mut tokenizer, false), "a");
+        assert_eq!(source_of(source_text, &mut tokenizer, true), "/ b /g");
+        assert_eq!(tokenizer.next_token().kind, Kind::Eof);
+
+        let mut tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        let kinds = std::iter::from_fn(|| Some(tokenizer.next_token().kind))
+            .take_while(|kind| *kind != Kind::Eof)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [Kind::Ident, Kind::Slash, Kind::Ident, Kind::Slash, Kind::Ident]);
+    }
+
+    #[test]
+    fn template() {
+        let allocator = Allocator::default();
+        let source_text = "`a${b}c`";
+        let mut tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        assert_eq!(tokenizer.next_token().kind, Kind::TemplateHead);
+        assert_eq!(tokenizer.next_token().kind, Kind::Ident);
+        assert_eq!(tokenizer.next_token().kind, Kind::RCurly);
+        let token = tokenizer.re_lex_template_continuation();
+        assert_eq!(token.kind, Kind::TemplateTail);
+        assert_eq!(&source_text[token.start as usize..token.end as usize], "}c`");
+    }
+
+    #[test]
+    fn right_angle() {
+        let allocator = Allocator::default();
+        let mut tokenizer = Tokenizer::new(&allocator, "a >>= b", SourceType::default());
+        tokenizer.next_token();
+        assert_eq!(tokenizer.next_token().kind, Kind::RAngle);
+        let token = tokenizer.re_lex_right_angle();
+        assert_eq!((token.kind, token.start, token.end), (Kind::ShiftRightEq, 2, 5));
+    }
+
+    #[test]
+    fn jsx() {
+        let allocator = Allocator::default();
+        let source_text = "<a data-id=\"x\">text</a>";
+        let mut tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        assert_eq!(tokenizer.next_token().kind, Kind::LAngle);
+        assert_eq!(tokenizer.next_token().kind, Kind::Ident);
+        tokenizer.next_token();
+        let token = tokenizer.re_lex_jsx_identifier();
+        assert_eq!(&source_text[token.start as usize..token.end as usize], "data-id");
+        assert_eq!(tokenizer.next_token().kind, Kind::Eq);
+        assert_eq!(tokenizer.next_jsx_attribute_value().kind, Kind::Str);
+        assert_eq!(tokenizer.next_token().kind, Kind::RAngle);
+        let token = tokenizer.next_jsx_child();
+        assert_eq!(token.kind, Kind::JSXText);
+        assert_eq!(&source_text[token.start as usize..token.end as usize], "text");
+    }
+
+    #[test]
+    fn tokenize() {
+        let allocator = Allocator::default();
+        let source_text = "a / b; /c/g.test(x) ? `t${ { d: 1 }.d }u${`v${e}`}` : f >>= 2";
+        let tokens = super::tokenize(&allocator, source_text, SourceType::default())
+            .map(|token| &source_text[token.start as usize..token.end as usize])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                "a", "/", "b", ";", "/c/g", ".", "test", "(", "x", ")", "?", "`t${", "{", "d", ":",
+                "1", "}", ".", "d", "}u${", "`v${", "e", "}`", "}`", ":", "f", ">>=", "2"
+            ]
+        );
+
+        let source_text = "let a: Array<Array<T>> = b";
+        let source_type = SourceType::default().with_typescript(true);
+        let kinds = super::tokenize(&allocator, source_text, source_type)
+            .map(|token| token.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds.iter().filter(|kind| **kind == Kind::RAngle).count(), 2);
+        assert_eq!(kinds.len(), 12);
+
+        let kinds = super::tokenize(&allocator, "return /a/; (b) / c", SourceType::default())
+            .map(|token| token.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                Kind::Return,
+                Kind::RegExp,
+                Kind::Semicolon,
+                Kind::LParen,
+                Kind::Ident,
+                Kind::RParen,
+                Kind::Slash,
+                Kind::Ident
+            ]
+        );
+    }
+
+    #[test]
+    fn checkpoint() {
+        let allocator = Allocator::default();
+        let source_text = "(a, b) => a / b / c";
+        let mut tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        assert_eq!(tokenizer.next_token().kind, Kind::LParen);
+        let checkpoint = tokenizer.checkpoint();
+        assert_eq!(tokenizer.next_token().kind, Kind::Ident);
+        assert_eq!(tokenizer.next_token().kind, Kind::Comma);
+        tokenizer.rewind(checkpoint);
+        let token = tokenizer.next_token();
+        assert_eq!((token.kind, token.start, token.end), (Kind::Ident, 1, 2));
+
+        // Rewind a re-lexed token
+        for _ in 0..5 {
+            tokenizer.next_token();
+        }
+        let checkpoint = tokenizer.checkpoint();
+        assert_eq!(tokenizer.next_token().kind, Kind::Slash);
+        let token = tokenizer.re_lex_regex();
+        assert_eq!((token.kind, token.end), (Kind::RegExp, 17));
+        tokenizer.rewind(checkpoint);
+        assert_eq!(tokenizer.next_token().kind, Kind::Slash);
+        assert_eq!(tokenizer.next_token().kind, Kind::Ident);
+
+        // Errors found after the checkpoint are discarded
+        let mut tokenizer = Tokenizer::new(&allocator, "a 'b", SourceType::default());
+        tokenizer.next_token();
+        let checkpoint = tokenizer.checkpoint();
+        tokenizer.next_token();
+        assert_eq!(tokenizer.errors().len(), 1);
+        tokenizer.rewind(checkpoint);
+        assert!(tokenizer.errors().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Checkpoint is not within the source text of this lexer")]
+    fn checkpoint_of_another_tokenizer() {
+        let allocator = Allocator::default();
+        // Slices of the same string, so the other source text lies after this one in memory
+        let source_text = "a b c d";
+        let mut tokenizer = Tokenizer::new(&allocator, &source_text[..3], SourceType::default());
+        let other = Tokenizer::new(&allocator, &source_text[4..], SourceType::default());
+        let checkpoint = other.checkpoint();
+        tokenizer.rewind(checkpoint);
+    }
+
+    fn tokens(tokenizer: &mut Tokenizer) -> Vec<(Kind, u32, u32, bool)> {
+        let mut tokens = vec![];
+        loop {
+            let token = tokenizer.next_token();
+            tokens.push((token.kind, token.start, token.end, token.is_on_new_line));
+            if token.kind == Kind::Eof {
+                return tokens;
+            }
+        }
+    }
+
+    /// Replace `start..end` of `source_text` with `text`, and check that patching the old tokens
+    /// with the re-lexed ones gives the tokens of the new source text.
+    /// Returns the re-lexed source text.
+    fn relex(source_text: &str, start: u32, end: u32, text: &str) -> String {
+        let allocator = Allocator::default();
+        let mut old_tokenizer = Tokenizer::new(&allocator, source_text, SourceType::default());
+        let mut old_tokens = vec![];
+        loop {
+            let token = old_tokenizer.next_token();
+            old_tokens.push(token);
+            if token.kind == Kind::Eof {
+                break;
+            }
+        }
+
+        let mut new_text = source_text.to_string();
+        new_text.replace_range(start as usize..end as usize, text);
+        #[allow(clippy::cast_possible_truncation)]
+        let new_len = text.len() as u32;
+        let mut tokenizer = Tokenizer::new(&allocator, &new_text, SourceType::default());
+        let diff = tokenizer.relex_edit(&old_tokens, Span::new(start, end), new_len);
+
+        let delta = i64::from(new_len) - i64::from(end - start);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let shift = |token: &Token| {
+            let start = (i64::from(token.start) + delta) as u32;
+            let end = (i64::from(token.end) + delta) as u32;
+            (token.kind, start, end, token.is_on_new_line)
+        };
+        let patched = old_tokens[..diff.old_tokens.start]
+            .iter()
+            .map(|token| (token.kind, token.start, token.end, token.is_on_new_line))
+            .chain(
+                diff.tokens
+                    .iter()
+                    .map(|token| (token.kind, token.start, token.end, token.is_on_new_line)),
+            )
+            .chain(old_tokens[diff.old_tokens.end..].iter().map(shift))
+            .collect::<Vec<_>>();
+        let mut tokenizer = Tokenizer::new(&allocator, &new_text, SourceType::default());
+        assert_eq!(patched, tokens(&mut tokenizer), "{new_text}");
+
+        diff.span.source_text(&new_text).to_string()
+    }
+
+    #[test]
+    fn relex_edit() {
+        // rename
+        assert_eq!(relex("let a = b + c;", 8, 9, "foo"), " = foo");
+        // extend the token before the edit
+        assert_eq!(relex("let a = b + c;", 9, 9, "ar"), " bar");
+        // delete tokens
+        assert_eq!(relex("f(a, b, c);", 4, 7, ""), "a,");
+        // open a comment, which swallows the rest of the line
+        assert_eq!(relex("a; b; c;\nd;", 3, 3, "//"), "; //");
+        // a new line changes the next token
+        assert_eq!(relex("a b c", 2, 2, "\n"), "a \nb");
+        // `?.` is lexed as `?` before a digit
+        assert_eq!(relex("a ?.b : c", 4, 5, "5"), " ?.5");
+        assert_eq!(relex("a ?.5 : c", 4, 5, "b"), " ?.b");
+        // edits at the start and the end
+        assert_eq!(relex("a b", 0, 0, "x"), "xa");
+        assert_eq!(relex("a b", 3, 3, "c"), " bc");
+        assert_eq!(relex("a b", 3, 3, " "), " b ");
+    }
+}
@@ -235,7 +235,7 @@ impl<'a> ParserImpl<'a> {
             self.expect(Kind::LCurly)?;
 
             while !self.eat(Kind::RCurly) && !self.at(Kind::Eof) {
-                let stmt = self.parse_ts_module_item()?;
+                let stmt = self.recover_statement(ParserImpl::parse_ts_module_item)?;
                 statements.push(stmt);
             }
         }
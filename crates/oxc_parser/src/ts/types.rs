@@ -108,6 +108,10 @@ impl ModifierFlags {
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> Result<TSType<'a>> {
         if self.is_at_constructor_type() {
             return self.parse_ts_constructor_type();
         }
@@ -358,7 +362,7 @@ impl<'a> ParserImpl<'a> {
     // type I = undefined;
     // type J = null;
     // type K = never
-    fn parse_ts_basic_type(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_ts_basic_type(&mut self) -> Result<TSType<'a>> {
         match self.cur_kind() {
             Kind::LParen => {
                 self.bump_any();
@@ -410,7 +414,7 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
-    fn parse_ts_keyword_type(&mut self) -> Option<TSType<'a>> {
+    pub(crate) fn parse_ts_keyword_type(&mut self) -> Option<TSType<'a>> {
         let span = self.start_span();
         match self.cur_kind() {
             Kind::Any => {
@@ -549,12 +553,13 @@ impl<'a> ParserImpl<'a> {
             return false;
         }
 
-        let checkpoint = self.checkpoint();
+        self.lookahead(Self::is_at_function_type_worker)
+    }
 
+    fn is_at_function_type_worker(&mut self) -> bool {
         self.bump_any(); // bump (
 
         if self.at(Kind::RParen) || self.at(Kind::Dot3) {
-            self.rewind(checkpoint);
             return true;
         }
 
@@ -573,16 +578,12 @@ impl<'a> ParserImpl<'a> {
             is_function_parameter_start = true;
         }
 
-        let result = if is_function_parameter_start {
+        if is_function_parameter_start {
             matches!(self.cur_kind(), Kind::Colon | Kind::Eq | Kind::Comma | Kind::Question)
                 || (self.at(Kind::RParen) && self.peek_at(Kind::Arrow))
         } else {
             false
-        };
-
-        self.rewind(checkpoint);
-
-        result
+        }
     }
 
     fn is_at_mapped_type(&mut self) -> bool {
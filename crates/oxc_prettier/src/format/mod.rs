@@ -123,6 +123,7 @@ impl<'a> Format<'a> for Statement<'a> {
             Self::TryStatement(stmt) => stmt.format(p),
             Self::WhileStatement(stmt) => stmt.format(p),
             Self::WithStatement(stmt) => stmt.format(p),
+            Self::ErrorStatement(stmt) => stmt.format(p),
             Self::Declaration(decl) => decl.format(p),
         }
     }
@@ -530,6 +531,13 @@ impl<'a> Format<'a> for WithStatement<'a> {
     }
 }
 
+/// The source text which could not be parsed is kept as is
+impl<'a> Format<'a> for ErrorStatement {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        Doc::Str(&p.source_text[self.span.start as usize..self.span.end as usize])
+    }
+}
+
 impl<'a> Format<'a> for DebuggerStatement {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
@@ -1035,6 +1043,10 @@ impl<'a> Format<'a> for ImportDeclaration<'a> {
         if self.import_kind.is_type() {
             parts.push(ss!(" type"));
         }
+        if let Some(phase) = self.phase {
+            parts.push(ss!(" "));
+            parts.push(ss!(phase.as_str()));
+        }
         if let Some(specifiers) = &self.specifiers {
             let is_default = specifiers.first().is_some_and(|x| {
                 matches!(x, ImportDeclarationSpecifier::ImportDefaultSpecifier(_))
@@ -1212,6 +1224,10 @@ impl<'a> Format<'a> for Expression<'a> {
             Self::TaggedTemplateExpression(expr) => expr.format(p),
             Self::Super(sup) => sup.format(p),
             Self::AwaitExpression(expr) => expr.format(p),
+            Self::PipelineExpression(expr) => expr.format(p),
+            Self::DoExpression(expr) => expr.format(p),
+            Self::ThrowExpression(expr) => expr.format(p),
+            Self::ErrorExpression(expr) => expr.format(p),
             Self::ChainExpression(expr) => expr.format(p),
             Self::NewExpression(expr) => expr.format(p),
             Self::MetaProperty(expr) => expr.format(p),
@@ -1915,6 +1931,34 @@ impl<'a> Format<'a> for AwaitExpression<'a> {
     }
 }
 
+impl<'a> Format<'a> for PipelineExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, PipelineExpression, {
+            let left = format!(p, self.left);
+            let right = format!(p, self.right);
+            group!(p, left, indent!(p, line!(), ss!("|> "), right))
+        })
+    }
+}
+
+impl<'a> Format<'a> for DoExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, DoExpression, { array!(p, ss!("do "), format!(p, self.body)) })
+    }
+}
+
+impl<'a> Format<'a> for ErrorExpression {
+    fn format(&self, _p: &mut Prettier<'a>) -> Doc<'a> {
+        Doc::Str("")
+    }
+}
+
+impl<'a> Format<'a> for ThrowExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, ThrowExpression, { array!(p, ss!("throw "), format!(p, self.argument)) })
+    }
+}
+
 impl<'a> Format<'a> for ChainExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, ChainExpression, { format!(p, self.expression) })
@@ -54,6 +54,46 @@ impl<'a> Binder for VariableDeclarator<'a> {
             let span = ident.span;
             let name = &ident.name;
 
+            // `try {} catch (e) { var e = 1 }`
+            // The initializer assigns to the catch parameter,
+            // but `e` is still declared in the enclosing var scope.
+            // Only allowed for a simple catch parameter, `catch ({ e }) { var e }` is a redeclaration.
+            // https://tc39.es/ecma262/#sec-variablestatements-in-catch-blocks
+            let simple_catch_parameter =
+                SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable;
+            let catch_scope_index =
+                std::iter::once(&current_scope_id).chain(&var_scope_ids).position(|scope_id| {
+                    builder.scope.get_flags(*scope_id).is_catch_clause()
+                        && builder.scope.get_binding(*scope_id, name).is_some_and(|symbol_id| {
+                            builder.symbols.get_flag(symbol_id).contains(simple_catch_parameter)
+                        })
+                });
+            if let Some(index) = catch_scope_index {
+                let catch_scope_id =
+                    if index == 0 { current_scope_id } else { var_scope_ids[index - 1] };
+                let catch_symbol_id = builder.scope.get_binding(catch_scope_id, name).unwrap();
+                ident.symbol_id.set(Some(catch_symbol_id));
+                builder.add_redeclared_variables(VariableInfo { span, symbol_id: catch_symbol_id });
+
+                // Scopes between the catch clause and the var scope
+                let outer_scope_ids = &var_scope_ids[index..];
+                if let Some(var_scope_id) = outer_scope_ids.last() {
+                    if !builder.scope.has_binding(*var_scope_id, name) {
+                        let symbol_id = builder.declare_symbol_on_scope(
+                            span,
+                            name,
+                            *var_scope_id,
+                            includes,
+                            excludes,
+                        );
+                        for scope_id in outer_scope_ids {
+                            builder.scope.add_binding(*scope_id, name.clone(), symbol_id);
+                        }
+                    }
+                }
+                return;
+            }
+
             for scope_id in &var_scope_ids {
                 if let Some(symbol_id) =
                     builder.check_redeclaration(*scope_id, span, name, excludes, true)
@@ -115,18 +155,20 @@ impl<'a> Binder for Function<'a> {
                 let parent_scope_id = builder.scope.get_parent_id(current_scope_id).unwrap();
                 let parent_flags = builder.scope.get_flags(parent_scope_id);
 
-                let (includes, excludes) =
+                let (includes, excludes, var_scoped) =
                     if (parent_flags.is_strict_mode() || self.r#async || self.generator)
                         && !function_as_var(parent_flags, builder.source_type)
                     {
                         (
                             SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
                             SymbolFlags::BlockScopedVariableExcludes,
+                            false,
                         )
                     } else {
                         (
                             SymbolFlags::FunctionScopedVariable,
                             SymbolFlags::FunctionScopedVariableExcludes,
+                            true,
                         )
                     };
 
@@ -138,6 +180,10 @@ impl<'a> Binder for Function<'a> {
                     excludes,
                 );
                 ident.symbol_id.set(Some(symbol_id));
+
+                if var_scoped && !parent_flags.is_var() {
+                    builder.add_annex_b_function(parent_scope_id, &ident.name, symbol_id);
+                }
             } else if self.r#type == FunctionType::FunctionExpression {
                 // https://tc39.es/ecma262/#sec-runtime-semantics-instantiateordinaryfunctionexpression
                 // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
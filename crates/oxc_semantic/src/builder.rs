@@ -4,8 +4,8 @@ use std::{cell::RefCell, path::PathBuf, rc::Rc, sync::Arc};
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, TriviasMap, Visit};
-use oxc_diagnostics::Error;
-use oxc_span::{Atom, SourceType, Span};
+use oxc_diagnostics::{CancellationToken, Cancelled, Error};
+use oxc_span::{Atom, GetSpan, SourceType, Span};
 use oxc_syntax::{
     module_record::{ExportLocalName, ModuleRecord},
     operator::AssignmentOperator,
@@ -62,8 +62,9 @@ pub struct SemanticBuilder<'a> {
     // and when we reach a value declaration we set it
     // to value like
     pub namespace_stack: Vec<SymbolId>,
-    /// If true, the current node is in the type definition
-    in_type_definition: bool,
+    /// Number of enclosing type annotations and type declarations,
+    /// references in them are [`ReferenceFlag::Type`]
+    type_depth: u32,
     current_reference_flag: ReferenceFlag,
 
     // builders
@@ -79,13 +80,27 @@ pub struct SemanticBuilder<'a> {
 
     check_syntax_error: bool,
 
+    /// Checked before each statement, statements are skipped once it is cancelled.
+    cancellation_token: Option<CancellationToken>,
+
     redeclare_variables: RedeclareVariables,
 
+    /// Sloppy mode function declarations in blocks, which are hoisted to their var scope
+    /// when it is left. See [`SemanticBuilder::add_annex_b_function`].
+    annex_b_functions: Vec<AnnexBFunction>,
+
     pub cfg: ControlFlowGraph,
 
     pub class_table_builder: ClassTableBuilder,
 }
 
+struct AnnexBFunction {
+    block_scope_id: ScopeId,
+    var_scope_id: ScopeId,
+    name: Atom,
+    symbol_id: SymbolId,
+}
+
 pub struct SemanticBuilderReturn<'a> {
     pub semantic: Semantic<'a>,
     pub errors: Vec<Error>,
@@ -105,7 +120,7 @@ impl<'a> SemanticBuilder<'a> {
             current_node_id: AstNodeId::new(0),
             current_node_flags: NodeFlags::empty(),
             current_symbol_flags: SymbolFlags::empty(),
-            in_type_definition: false,
+            type_depth: 0,
             current_reference_flag: ReferenceFlag::empty(),
             current_scope_id,
             function_stack: vec![],
@@ -117,7 +132,9 @@ impl<'a> SemanticBuilder<'a> {
             label_builder: LabelBuilder::default(),
             jsdoc: JSDocBuilder::new(source_text, &trivias),
             check_syntax_error: false,
+            cancellation_token: None,
             redeclare_variables: RedeclareVariables { variables: vec![] },
+            annex_b_functions: vec![],
             cfg: ControlFlowGraph::new(),
             class_table_builder: ClassTableBuilder::new(),
         }
@@ -136,6 +153,15 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Stop visiting statements once `token` is cancelled.
+    ///
+    /// The returned semantic is incomplete and `errors` contains a "cancelled" error.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: &CancellationToken) -> Self {
+        self.cancellation_token = Some(token.clone());
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -166,6 +192,10 @@ impl<'a> SemanticBuilder<'a> {
             }
         }
 
+        if self.is_cancelled() {
+            self.error(Cancelled);
+        }
+
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -205,6 +235,10 @@ impl<'a> SemanticBuilder<'a> {
         self.errors.borrow_mut().push(error.into());
     }
 
+    fn is_cancelled(&self) -> bool {
+        self.cancellation_token.as_ref().is_some_and(CancellationToken::is_cancelled)
+    }
+
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
         let mut flags = self.current_node_flags;
         if self.jsdoc.retrieve_attached_jsdoc(&kind) {
@@ -238,6 +272,35 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Set `flag` for a `this`, `arguments` or `super` at `span` on the function it belongs to,
+    /// and on the arrow functions in between, which inherit them from that function.
+    /// A class static block or property value, which has its own `this`, ends the search.
+    fn set_this_node_flag(&mut self, flag: NodeFlags, span: Span) {
+        let mut node_id = self.current_node_id;
+        while let Some(parent_id) = self.nodes.parent_id(node_id) {
+            node_id = parent_id;
+            let is_arrow = match self.nodes.kind(node_id) {
+                AstKind::ArrowFunctionExpression(_) => true,
+                AstKind::Function(_) => false,
+                AstKind::StaticBlock(_) => return,
+                AstKind::PropertyDefinition(def) => {
+                    let value_span = def.value.as_ref().map(GetSpan::span);
+                    if value_span
+                        .is_some_and(|value| value.start <= span.start && span.end <= value.end)
+                    {
+                        return;
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            *self.nodes.get_node_mut(node_id).flags_mut() |= flag;
+            if !is_arrow {
+                return;
+            }
+        }
+    }
+
     /// Declares a `Symbol` for the node, adds it to symbol table, and binds it to the scope.
     ///
     /// includes: the `SymbolFlags` that node has in addition to its declaration type (eg: export, ambient, etc.)
@@ -317,15 +380,30 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     fn resolve_references_for_current_scope(&mut self) {
+        let parent_scope_id =
+            self.scope.get_parent_id(self.current_scope_id).unwrap_or(self.current_scope_id);
+
+        // An identifier inside `with` may refer to a property of the object at runtime,
+        // so its references are kept in the `with` scope. They are also resolved as maybe references
+        // to the binding they refer to when the object has no such property.
+        if self.current_scope_flags().is_with() {
+            let all_references =
+                self.scope.get_unresolved_references(self.current_scope_id).clone();
+            for (name, reference_ids) in all_references {
+                for reference_id in &reference_ids {
+                    self.symbols.references[*reference_id].add_flag(ReferenceFlag::MaybeWith);
+                }
+                self.scope.extend_unresolved_reference(parent_scope_id, name, reference_ids);
+            }
+            return;
+        }
+
         let all_references = self
             .scope
             .unresolved_references_mut(self.current_scope_id)
-            .drain()
+            .drain(..)
             .collect::<Vec<(Atom, Vec<ReferenceId>)>>();
 
-        let parent_scope_id =
-            self.scope.get_parent_id(self.current_scope_id).unwrap_or(self.current_scope_id);
-
         for (name, reference_ids) in all_references {
             if let Some(symbol_id) = self.scope.get_binding(self.current_scope_id, &name) {
                 for reference_id in &reference_ids {
@@ -338,6 +416,57 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// A sloppy mode function declaration inside a block is also bound in the enclosing var scope,
+    /// unless this would conflict with a lexical declaration of the same name.
+    /// ```javascript
+    /// { function f() {} }
+    /// f(); // references the function declared in the block
+    /// ```
+    /// The binding is added when the var scope is left,
+    /// so declarations following the block are taken into account.
+    /// <https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics>
+    pub fn add_annex_b_function(
+        &mut self,
+        block_scope_id: ScopeId,
+        name: &Atom,
+        symbol_id: SymbolId,
+    ) {
+        let var_scope_id = self
+            .scope
+            .ancestors(block_scope_id)
+            .find(|scope_id| self.scope.get_flags(*scope_id).is_var())
+            .unwrap_or_else(|| self.scope.root_scope_id());
+        self.annex_b_functions.push(AnnexBFunction {
+            block_scope_id,
+            var_scope_id,
+            name: name.clone(),
+            symbol_id,
+        });
+    }
+
+    fn hoist_annex_b_functions(&mut self) {
+        let current_scope_id = self.current_scope_id;
+        let functions = std::mem::take(&mut self.annex_b_functions);
+        let (hoisted, pending): (Vec<_>, Vec<_>) =
+            functions.into_iter().partition(|f| f.var_scope_id == current_scope_id);
+        self.annex_b_functions = pending;
+
+        for AnnexBFunction { block_scope_id, var_scope_id, name, symbol_id } in hoisted {
+            // Either a lexical declaration blocks hoisting,
+            // or the var scope already has a binding, e.g. `var f; { function f() {} }`
+            let is_conflicting = self
+                .scope
+                .ancestors(block_scope_id)
+                .skip(1)
+                .take_while(|scope_id| *scope_id != var_scope_id)
+                .chain(std::iter::once(var_scope_id))
+                .any(|scope_id| self.scope.has_binding(scope_id, &name));
+            if !is_conflicting {
+                self.scope.add_binding(var_scope_id, name, symbol_id);
+            }
+        }
+    }
+
     pub fn add_redeclared_variables(&mut self, variable: VariableInfo) {
         self.redeclare_variables.variables.push(variable);
     }
@@ -392,6 +521,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     }
 
     fn leave_scope(&mut self) {
+        if self.current_scope_flags().is_var() {
+            self.hoist_annex_b_functions();
+        }
         self.resolve_references_for_current_scope();
         if let Some(parent_id) = self.scope.get_parent_id(self.current_scope_id) {
             self.current_scope_id = parent_id;
@@ -415,6 +547,13 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.pop_ast_node();
     }
 
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.is_cancelled() {
+            return;
+        }
+        self.visit_statement_match(stmt);
+    }
+
     fn visit_program(&mut self, program: &Program<'a>) {
         let kind = AstKind::Program(self.alloc(program));
         self.enter_scope({
@@ -1486,7 +1625,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         let body_graph_ix = self.cfg.new_basic_block();
         /* cfg */
 
+        self.enter_scope(ScopeFlags::With);
         self.visit_statement(&stmt.body);
+        self.leave_scope();
 
         /* cfg - after body basic block */
         let after_body_graph_ix = self.cfg.new_basic_block();
@@ -1594,6 +1735,11 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&class.body);
 
         /* cfg */
@@ -1691,6 +1837,9 @@ impl<'a> SemanticBuilder<'a> {
                     body,
                     self.current_node_id,
                     &self.nodes,
+                    &self.scope,
+                    &self.symbols,
+                    self.current_scope_id,
                 );
             }
             AstKind::PrivateIdentifier(ident) => {
@@ -1716,24 +1865,24 @@ impl<'a> SemanticBuilder<'a> {
                     .get_bindings(self.current_scope_id)
                     .get(module_declaration.id.name());
                 self.namespace_stack.push(*symbol_id.unwrap());
-                self.in_type_definition = true;
             }
             AstKind::TSTypeAliasDeclaration(type_alias_declaration) => {
                 type_alias_declaration.bind(self);
-                self.in_type_definition = true;
+                self.type_depth += 1;
             }
             AstKind::TSInterfaceDeclaration(interface_declaration) => {
                 interface_declaration.bind(self);
-                self.in_type_definition = true;
+                self.type_depth += 1;
             }
             AstKind::TSEnumDeclaration(enum_declaration) => {
                 enum_declaration.bind(self);
                 // TODO: const enum?
                 self.make_all_namespaces_valuelike();
-                self.in_type_definition = true;
             }
-            AstKind::TSTypeParameterInstantiation(_) | AstKind::TSTypeAnnotation(_) => {
-                self.in_type_definition = true;
+            AstKind::TSTypeParameterDeclaration(_)
+            | AstKind::TSTypeParameterInstantiation(_)
+            | AstKind::TSTypeAnnotation(_) => {
+                self.type_depth += 1;
             }
             AstKind::TSEnumMember(enum_member) => {
                 enum_member.bind(self);
@@ -1743,6 +1892,9 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::IdentifierReference(ident) => {
                 self.reference_identifier(ident);
+                if ident.name == "arguments" {
+                    self.set_this_node_flag(NodeFlags::HasArguments, ident.span);
+                }
             }
             AstKind::UpdateExpression(_) => {
                 if self.is_not_expression_statement_parent() {
@@ -1778,6 +1930,23 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::YieldExpression(_) => {
                 self.set_function_node_flag(NodeFlags::HasYield);
             }
+            AstKind::AwaitExpression(_) => {
+                self.set_function_node_flag(NodeFlags::HasAwait);
+            }
+            AstKind::ForOfStatement(stmt) if stmt.r#await => {
+                self.set_function_node_flag(NodeFlags::HasAwait);
+            }
+            AstKind::ThisExpression(expr) => {
+                self.set_this_node_flag(NodeFlags::HasThis, expr.span);
+            }
+            AstKind::JSXMemberExpressionObject(JSXMemberExpressionObject::Identifier(ident))
+                if ident.name == "this" =>
+            {
+                self.set_this_node_flag(NodeFlags::HasThis, ident.span);
+            }
+            AstKind::Super(expr) => {
+                self.set_this_node_flag(NodeFlags::HasSuper, expr.span);
+            }
             _ => {}
         }
     }
@@ -1809,13 +1978,12 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::TSModuleBlock(_) => {
                 self.namespace_stack.pop();
             }
-            AstKind::TSEnumDeclaration(_)
-            | AstKind::TSTypeAliasDeclaration(_)
+            AstKind::TSTypeAliasDeclaration(_)
             | AstKind::TSInterfaceDeclaration(_)
-            | AstKind::TSModuleDeclaration(_)
+            | AstKind::TSTypeParameterDeclaration(_)
             | AstKind::TSTypeParameterInstantiation(_)
             | AstKind::TSTypeAnnotation(_) => {
-                self.in_type_definition = false;
+                self.type_depth -= 1;
             }
             AstKind::UpdateExpression(_) => {
                 if self.is_not_expression_statement_parent() {
@@ -1858,7 +2026,7 @@ impl<'a> SemanticBuilder<'a> {
 
     /// Resolve reference flags for the current ast node.
     fn resolve_reference_usages(&self) -> ReferenceFlag {
-        if self.in_type_definition {
+        if self.type_depth > 0 || self.is_type_name_reference() {
             ReferenceFlag::Type
         } else if self.current_reference_flag.is_write() {
             self.current_reference_flag
@@ -1867,6 +2035,19 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Whether the current identifier is a type name outside of a type annotation,
+    /// e.g. `A` in `x as A` or `implements A`, but not in `import B = A.C`
+    fn is_type_name_reference(&self) -> bool {
+        let mut ancestors = self.nodes.iter_parents(self.current_node_id).skip(1);
+        if !matches!(ancestors.next().map(AstNode::kind), Some(AstKind::TSTypeName(_))) {
+            return false;
+        }
+        ancestors
+            .map(AstNode::kind)
+            .find(|kind| !matches!(kind, AstKind::TSTypeName(_) | AstKind::TSQualifiedName(_)))
+            .map_or(true, |kind| !matches!(kind, AstKind::TSImportEqualsDeclaration(_)))
+    }
+
     fn reference_jsx_element_name(&mut self, elem: &JSXElementName) {
         if matches!(
             self.nodes.parent_kind(self.current_node_id),
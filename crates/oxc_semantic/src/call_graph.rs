@@ -0,0 +1,223 @@
+use oxc_ast::{ast::BindingPatternKind, AstKind};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{AstNode, AstNodeId, Reference, Semantic, SymbolId};
+
+/// A function or arrow function of the [`CallGraph`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallGraphFunction {
+    /// The `Function` or `ArrowFunctionExpression` node
+    pub node_id: AstNodeId,
+    /// Name of the function, or of the variable, method or property it is assigned to
+    pub name: Option<Atom>,
+    /// The binding the function is called through. `None` for methods, callbacks and bindings
+    /// which are reassigned, whose calls can not be resolved.
+    pub symbol_id: Option<SymbolId>,
+    /// Span of the function including its variable declarator, method or property
+    pub span: Span,
+    /// Span of the name, empty at the start of the function when it has none
+    pub name_span: Span,
+}
+
+/// A call or `new` expression whose callee resolves to a function of the [`CallGraph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallSite {
+    /// The innermost function containing the call, `None` at the top level
+    pub caller: Option<AstNodeId>,
+    pub callee: AstNodeId,
+    /// Span of the callee identifier
+    pub span: Span,
+}
+
+/// Calls between the functions of a module, resolved through the symbol table.
+///
+/// Only calls of identifiers bound to a function are resolved, e.g. `f()` for
+/// `function f() {}` or `const f = () => {}`. Member calls such as `this.f()` and calls of
+/// imported functions are not part of the graph.
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    functions: Vec<CallGraphFunction>,
+    /// Index into `functions` of each function node
+    function_indices: FxHashMap<AstNodeId, usize>,
+    /// Sorted by span
+    calls: Vec<CallSite>,
+    /// Functions which are used other than by calling their binding, e.g. passed as a callback
+    /// or exported
+    escaping: FxHashSet<AstNodeId>,
+}
+
+impl CallGraph {
+    pub fn new(semantic: &Semantic) -> Self {
+        let mut graph = Self::default();
+        let mut functions_by_symbol = FxHashMap::default();
+
+        for node in semantic.nodes().iter() {
+            let Some(function) = describe_function(semantic, node) else { continue };
+            if let Some(symbol_id) = function.symbol_id {
+                functions_by_symbol.insert(symbol_id, node.id());
+            }
+            if let Some(name) = &function.name {
+                if semantic.module_record().exported_bindings.contains_key(name) {
+                    graph.escaping.insert(node.id());
+                }
+            }
+            if matches!(
+                semantic.nodes().parent_kind(node.id()),
+                Some(AstKind::ExportDefaultDeclaration(_))
+            ) {
+                graph.escaping.insert(node.id());
+            }
+            graph.function_indices.insert(node.id(), graph.functions.len());
+            graph.functions.push(function);
+        }
+
+        for (symbol_id, function_id) in functions_by_symbol {
+            for reference in semantic.symbols().get_resolved_references(symbol_id) {
+                let nodes = semantic.nodes();
+                let callee = match nodes.parent_kind(reference.node_id()) {
+                    Some(AstKind::CallExpression(call)) => &call.callee,
+                    Some(AstKind::NewExpression(new)) => &new.callee,
+                    _ => {
+                        graph.escaping.insert(function_id);
+                        continue;
+                    }
+                };
+                if callee.span() != reference.span() {
+                    // An argument of the call
+                    graph.escaping.insert(function_id);
+                    continue;
+                }
+                let calling_function = nodes
+                    .ancestors(reference.node_id())
+                    .skip(1)
+                    .find(|node_id| graph.function_indices.contains_key(node_id));
+                graph.calls.push(CallSite {
+                    caller: calling_function,
+                    callee: function_id,
+                    span: reference.span(),
+                });
+            }
+        }
+        graph.calls.sort_unstable_by_key(|call| (call.span.start, call.span.end));
+
+        graph
+    }
+
+    /// Functions in source order
+    pub fn functions(&self) -> &[CallGraphFunction] {
+        &self.functions
+    }
+
+    pub fn function(&self, node_id: AstNodeId) -> Option<&CallGraphFunction> {
+        self.function_indices.get(&node_id).map(|index| &self.functions[*index])
+    }
+
+    /// Call sites in source order
+    pub fn calls(&self) -> &[CallSite] {
+        &self.calls
+    }
+
+    /// Call sites of `callee`
+    pub fn incoming_calls(&self, callee: AstNodeId) -> impl Iterator<Item = &CallSite> + '_ {
+        self.calls.iter().filter(move |call| call.callee == callee)
+    }
+
+    /// Call sites in the body of `caller`, or at the top level for `None`, excluding the calls in
+    /// nested functions
+    pub fn outgoing_calls(
+        &self,
+        caller: Option<AstNodeId>,
+    ) -> impl Iterator<Item = &CallSite> + '_ {
+        self.calls.iter().filter(move |call| call.caller == caller)
+    }
+
+    /// The function called at `offset`, or the innermost function containing `offset`
+    pub fn function_at(&self, offset: u32) -> Option<&CallGraphFunction> {
+        let contains = |span: Span| span.start <= offset && offset <= span.end;
+        if let Some(call) = self.calls.iter().find(|call| contains(call.span)) {
+            return self.function(call.callee);
+        }
+        self.functions
+            .iter()
+            .filter(|function| contains(function.span))
+            .min_by_key(|function| function.span.size())
+    }
+
+    /// Functions which can not be reached by calls from the top level, exported functions or
+    /// functions used other than by calling them. Functions without a binding are always
+    /// considered reachable, their calls can not be resolved.
+    pub fn unreachable_functions(&self) -> Vec<&CallGraphFunction> {
+        let mut reachable = FxHashSet::default();
+        let mut stack = self
+            .functions
+            .iter()
+            .filter(|function| {
+                function.symbol_id.is_none() || self.escaping.contains(&function.node_id)
+            })
+            .map(|function| function.node_id)
+            .chain(self.outgoing_calls(None).map(|call| call.callee))
+            .collect::<Vec<_>>();
+        while let Some(node_id) = stack.pop() {
+            if reachable.insert(node_id) {
+                stack.extend(self.outgoing_calls(Some(node_id)).map(|call| call.callee));
+            }
+        }
+        self.functions.iter().filter(|function| !reachable.contains(&function.node_id)).collect()
+    }
+}
+
+fn describe_function(semantic: &Semantic, node: &AstNode) -> Option<CallGraphFunction> {
+    let (id, span) = match node.kind() {
+        AstKind::Function(func) => (func.id.as_ref(), func.span),
+        AstKind::ArrowFunctionExpression(arrow) => (None, arrow.span),
+        _ => return None,
+    };
+    let mut function = CallGraphFunction {
+        node_id: node.id(),
+        name: id.map(|id| id.name.clone()),
+        symbol_id: id.and_then(|id| id.symbol_id.get()),
+        span,
+        name_span: id.map_or(Span::new(span.start, span.start), |id| id.span),
+    };
+
+    match semantic.nodes().parent_kind(node.id()) {
+        // The function is the init of the declarator, it can not be part of the binding.
+        Some(AstKind::VariableDeclarator(declarator)) => {
+            if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                function.name = Some(ident.name.clone());
+                function.symbol_id = ident.symbol_id.get();
+                function.span = declarator.span;
+                function.name_span = ident.span;
+            }
+        }
+        Some(AstKind::MethodDefinition(def)) => {
+            function.name = def.key.static_name();
+            function.symbol_id = None;
+            function.span = def.span;
+            function.name_span = def.key.span();
+        }
+        Some(AstKind::PropertyDefinition(def)) => {
+            function.name = def.key.static_name();
+            function.symbol_id = None;
+            function.span = def.span;
+            function.name_span = def.key.span();
+        }
+        Some(AstKind::ObjectProperty(prop)) => {
+            function.name = prop.key.static_name();
+            function.symbol_id = None;
+            function.span = prop.span;
+            function.name_span = prop.key.span();
+        }
+        _ => {}
+    }
+
+    // Calls through a binding which is reassigned may call another function.
+    if let Some(symbol_id) = function.symbol_id {
+        if semantic.symbols().get_resolved_references(symbol_id).any(Reference::is_write) {
+            function.symbol_id = None;
+        }
+    }
+
+    Some(function)
+}
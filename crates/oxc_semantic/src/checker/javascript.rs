@@ -55,6 +55,7 @@ impl EarlyErrorJavaScript {
                 check_with_statement(stmt, ctx);
             }
             AstKind::SwitchStatement(stmt) => check_switch_statement(stmt, ctx),
+            AstKind::UsingDeclaration(decl) => check_using_declaration(decl, node, ctx),
             AstKind::BreakStatement(stmt) => check_break_statement(stmt, node, ctx),
             AstKind::ContinueStatement(stmt) => check_continue_statement(stmt, node, ctx),
             AstKind::LabeledStatement(stmt) => {
@@ -619,6 +620,30 @@ fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticBuilder<
     }
 }
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Using declarations are not allowed at the top level of scripts.")]
+#[diagnostic()]
+struct UsingDeclarationInScript(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Using declarations are not allowed in case or default clauses.")]
+#[diagnostic(help("Wrap this declaration in a block statement"))]
+struct UsingDeclarationInSwitchCase(#[label] Span);
+
+fn check_using_declaration<'a>(
+    decl: &UsingDeclaration<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    match ctx.nodes.parent_kind(node.id()) {
+        Some(AstKind::Program(_)) if ctx.source_type.is_script() => {
+            ctx.error(UsingDeclarationInScript(decl.span));
+        }
+        Some(AstKind::SwitchCase(_)) => ctx.error(UsingDeclarationInSwitchCase(decl.span)),
+        _ => {}
+    }
+}
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Jump target cannot cross function boundary.")]
 #[diagnostic()]
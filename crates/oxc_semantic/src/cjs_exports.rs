@@ -0,0 +1,254 @@
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentTarget, CallExpression, Expression, IdentifierReference,
+        ObjectExpression, ObjectPropertyKind, PropertyKind, SimpleAssignmentTarget, Statement,
+    },
+    AstKind,
+};
+use oxc_span::{Atom, GetSpan};
+
+use crate::{Semantic, SymbolId};
+
+/// Named exports of a CommonJS module, detected with the heuristics of
+/// [cjs-module-lexer](https://github.com/nodejs/cjs-module-lexer) so bundlers can generate the
+/// same ESM interop facades as Node.js.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CjsExports {
+    /// Exported names in source order, without duplicates
+    pub exports: Vec<Atom>,
+    /// Specifiers of the re-exported modules, e.g. `module.exports = require("./a")`
+    pub reexports: Vec<Atom>,
+}
+
+impl CjsExports {
+    /// Detect the exports of the patterns recognized by cjs-module-lexer:
+    ///
+    /// * `exports.a = ...`, `module.exports.a = ...` and `exports["a"] = ...`
+    /// * `Object.defineProperty(exports, "a", { value: ... })` and getters returning an identifier
+    ///   or a member expression, e.g. `{ enumerable: true, get: function () { return m.a; } }`
+    /// * `module.exports = { a, b: c, "d": e, ...require("./f") }` up to the first other property
+    /// * `module.exports = require("./a")`
+    /// * `__exportStar(require("./a"), exports)` and `__export(require("./a"))` of TypeScript
+    /// * `Object.keys(_a).forEach(...)` of Babel, where `_a` is `require("./a")`
+    ///
+    /// `exports` and `module` must not be shadowed by a local binding.
+    pub fn new(semantic: &Semantic) -> Self {
+        let mut cjs_exports = Self::default();
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::AssignmentExpression(assign) => {
+                    let AssignmentTarget::SimpleAssignmentTarget(
+                        SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                    ) = &assign.left
+                    else {
+                        continue;
+                    };
+                    let Some(name) = member.static_property_name() else { continue };
+                    if is_exports_object(semantic, member.object()) {
+                        cjs_exports.add_export(name.into());
+                    } else if name == "exports" && is_global(semantic, member.object(), "module") {
+                        cjs_exports.add_module_exports(&assign.right);
+                    }
+                }
+                AstKind::CallExpression(call) => cjs_exports.add_call(semantic, call),
+                _ => {}
+            }
+        }
+        cjs_exports
+    }
+
+    fn add_export(&mut self, name: Atom) {
+        if !self.exports.contains(&name) {
+            self.exports.push(name);
+        }
+    }
+
+    fn add_reexport(&mut self, specifier: Atom) {
+        if !self.reexports.contains(&specifier) {
+            self.reexports.push(specifier);
+        }
+    }
+
+    /// `module.exports = ...`
+    fn add_module_exports(&mut self, value: &Expression) {
+        match value.get_inner_expression() {
+            Expression::CallExpression(call) => {
+                if let Some(specifier) = require_specifier(call) {
+                    self.add_reexport(specifier);
+                }
+            }
+            Expression::ObjectExpression(object) => self.add_object_literal(object),
+            _ => {}
+        }
+    }
+
+    /// `{ a, b: c, "d": e, ...require("./f") }`, only identifiers are supported as values
+    fn add_object_literal(&mut self, object: &ObjectExpression) {
+        for property in &object.properties {
+            match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    if property.computed
+                        || property.method
+                        || !matches!(property.kind, PropertyKind::Init)
+                        || !matches!(property.value, Expression::Identifier(_))
+                    {
+                        return;
+                    }
+                    let Some(name) = property.key.static_name() else { return };
+                    self.add_export(name);
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    let Expression::CallExpression(call) = &spread.argument else { return };
+                    let Some(specifier) = require_specifier(call) else { return };
+                    self.add_reexport(specifier);
+                }
+            }
+        }
+    }
+
+    fn add_call<'a>(&mut self, semantic: &Semantic<'a>, call: &'a CallExpression<'a>) {
+        let Expression::MemberExpression(callee) = call.callee.get_inner_expression() else {
+            // `__exportStar(require("./a"), exports)` or `__export(require("./a"))`
+            if let Expression::Identifier(callee) = &call.callee {
+                if matches!(callee.name.as_str(), "__exportStar" | "__export") {
+                    self.add_export_star_helper(call);
+                }
+            }
+            return;
+        };
+        match callee.static_property_name() {
+            Some("defineProperty") if callee.object().is_specific_id("Object") => {
+                self.add_define_property(semantic, call);
+            }
+            // `tslib.__exportStar(require("./a"), exports)`
+            Some("__exportStar" | "__export") => self.add_export_star_helper(call),
+            // `Object.keys(_a).forEach(function (key) { ... exports[key] = _a[key] ... })`
+            Some("forEach") => {
+                let Expression::CallExpression(keys) = callee.object().get_inner_expression()
+                else {
+                    return;
+                };
+                if !keys.callee.is_specific_member_access("Object", "keys") {
+                    return;
+                }
+                let Some(Argument::Expression(Expression::Identifier(ident))) =
+                    keys.arguments.first()
+                else {
+                    return;
+                };
+                let Some(Argument::Expression(callback)) = call.arguments.first() else { return };
+                let source_text = semantic.source_text();
+                if !callback.span().source_text(source_text).contains("exports") {
+                    return;
+                }
+                if let Some(specifier) = required_binding(semantic, ident) {
+                    self.add_reexport(specifier);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_export_star_helper(&mut self, call: &CallExpression) {
+        let Some(Argument::Expression(Expression::CallExpression(require))) =
+            call.arguments.first()
+        else {
+            return;
+        };
+        if let Some(specifier) = require_specifier(require) {
+            self.add_reexport(specifier);
+        }
+    }
+
+    /// `Object.defineProperty(exports, "a", { value: ... })` or with a getter
+    fn add_define_property(&mut self, semantic: &Semantic, call: &CallExpression) {
+        let [object, name, descriptor] = call.arguments.as_slice() else { return };
+        let (
+            Argument::Expression(object),
+            Argument::Expression(Expression::StringLiteral(name)),
+            Argument::Expression(Expression::ObjectExpression(descriptor)),
+        ) = (object, name, descriptor)
+        else {
+            return;
+        };
+        if !is_exports_object(semantic, object) {
+            return;
+        }
+        let is_supported = descriptor.properties.iter().any(|property| {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+            match property.key.static_name().as_deref() {
+                Some("value") => true,
+                // `get: function () { return a; }` or `get() { return a.b; }`
+                Some("get") => {
+                    let Expression::FunctionExpression(func) = &property.value else {
+                        return false;
+                    };
+                    let Some(body) = &func.body else { return false };
+                    let [Statement::ReturnStatement(ret)] = body.statements.as_slice() else {
+                        return false;
+                    };
+                    matches!(
+                        ret.argument,
+                        Some(Expression::Identifier(_) | Expression::MemberExpression(_))
+                    )
+                }
+                _ => false,
+            }
+        });
+        if is_supported {
+            self.add_export(name.value.clone());
+        }
+    }
+}
+
+/// `exports` or `module.exports`
+fn is_exports_object(semantic: &Semantic, expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::MemberExpression(member) => {
+            member.static_property_name() == Some("exports")
+                && is_global(semantic, member.object(), "module")
+        }
+        expr => is_global(semantic, expr, "exports"),
+    }
+}
+
+/// An identifier named `name` which is not bound to a local variable
+fn is_global(semantic: &Semantic, expr: &Expression, name: &str) -> bool {
+    let Expression::Identifier(ident) = expr.get_inner_expression() else { return false };
+    ident.name == name && resolve(semantic, ident).is_none()
+}
+
+fn resolve(semantic: &Semantic, ident: &IdentifierReference) -> Option<SymbolId> {
+    let reference_id = ident.reference_id.get()?;
+    semantic.symbols().get_reference(reference_id).symbol_id()
+}
+
+/// `"./a"` of `require("./a")`
+fn require_specifier(call: &CallExpression) -> Option<Atom> {
+    if !call.is_require_call() {
+        return None;
+    }
+    match call.arguments.first() {
+        Some(Argument::Expression(Expression::StringLiteral(literal))) => {
+            Some(literal.value.clone())
+        }
+        _ => None,
+    }
+}
+
+/// `"./a"` if `ident` is bound to `require("./a")`, possibly wrapped in a helper call such as
+/// `_interopRequireWildcard(require("./a"))`
+fn required_binding(semantic: &Semantic, ident: &IdentifierReference) -> Option<Atom> {
+    let symbol_id = resolve(semantic, ident)?;
+    let AstKind::VariableDeclarator(declarator) = semantic.symbol_declaration(symbol_id).kind()
+    else {
+        return None;
+    };
+    let Expression::CallExpression(call) = declarator.init.as_ref()?.get_inner_expression() else {
+        return None;
+    };
+    require_specifier(call).or_else(|| match call.arguments.first() {
+        Some(Argument::Expression(Expression::CallExpression(call))) => require_specifier(call),
+        _ => None,
+    })
+}
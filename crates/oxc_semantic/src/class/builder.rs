@@ -1,14 +1,15 @@
 use oxc_ast::{
     ast::{
-        AccessorProperty, ClassBody, ClassElement, MethodDefinition, MethodDefinitionKind,
-        PrivateIdentifier, PropertyDefinition,
+        AccessorProperty, BindingPatternKind, ClassBody, ClassElement, Expression,
+        IdentifierReference, MethodDefinition, MethodDefinitionKind, PrivateIdentifier,
+        PropertyDefinition, PropertyKey,
     },
     AstKind,
 };
-use oxc_span::GetSpan;
+use oxc_span::{Atom, GetSpan};
 use oxc_syntax::class::{ClassId, ElementKind};
 
-use crate::{AstNodeId, AstNodes};
+use crate::{AstNodeId, AstNodes, ScopeId, ScopeTree, SymbolFlags, SymbolTable};
 
 use super::{
     table::{Element, PrivateIdentifierReference},
@@ -30,35 +31,45 @@ impl ClassTableBuilder {
         self.classes
     }
 
+    /// Declare the class of `class` and its elements.
+    /// `scope`, `symbols` and `scope_id` are used to resolve computed keys which are constants.
     pub fn declare_class_body(
         &mut self,
         class: &ClassBody,
         current_node_id: AstNodeId,
         nodes: &AstNodes,
+        scope: &ScopeTree,
+        symbols: &SymbolTable,
+        scope_id: ScopeId,
     ) {
         let parent_id = nodes.parent_id(current_node_id).unwrap_or_else(|| unreachable!());
         self.current_class_id = Some(self.classes.declare_class(self.current_class_id, parent_id));
 
+        let resolve_constant =
+            |ident: &IdentifierReference| constant_value(ident, nodes, scope, symbols, scope_id);
         for element in &class.body {
             match element {
                 ClassElement::PropertyDefinition(definition) => {
-                    self.declare_class_property(definition.0);
+                    self.declare_class_property(definition.0, &resolve_constant);
                 }
                 ClassElement::MethodDefinition(definition) => {
-                    self.declare_class_method(definition.0);
+                    self.declare_class_method(definition.0, &resolve_constant);
                 }
                 ClassElement::AccessorProperty(definition) => {
-                    self.declare_class_accessor(definition.0);
+                    self.declare_class_accessor(definition.0, &resolve_constant);
                 }
                 _ => {}
             }
         }
     }
 
-    pub fn declare_class_accessor(&mut self, property: &AccessorProperty) {
+    pub fn declare_class_accessor(
+        &mut self,
+        property: &AccessorProperty,
+        resolve_constant: &dyn Fn(&IdentifierReference) -> Option<Atom>,
+    ) {
         let is_private = property.key.is_private_identifier();
-        let name =
-            if is_private { property.key.private_name() } else { property.key.static_name() };
+        let name = element_name(&property.key, resolve_constant);
 
         if let Some(name) = name {
             if let Some(class_id) = self.current_class_id {
@@ -69,6 +80,7 @@ impl ClassTableBuilder {
                         property.key.span(),
                         property.r#static,
                         is_private,
+                        property.computed,
                         ElementKind::Accessor,
                     ),
                 );
@@ -76,10 +88,13 @@ impl ClassTableBuilder {
         }
     }
 
-    pub fn declare_class_property(&mut self, property: &PropertyDefinition) {
+    pub fn declare_class_property(
+        &mut self,
+        property: &PropertyDefinition,
+        resolve_constant: &dyn Fn(&IdentifierReference) -> Option<Atom>,
+    ) {
         let is_private = property.key.is_private_identifier();
-        let name =
-            if is_private { property.key.private_name() } else { property.key.static_name() };
+        let name = element_name(&property.key, resolve_constant);
 
         if let Some(name) = name {
             if let Some(class_id) = self.current_class_id {
@@ -90,6 +105,7 @@ impl ClassTableBuilder {
                         property.key.span(),
                         property.r#static,
                         is_private,
+                        property.computed,
                         ElementKind::Property,
                     ),
                 );
@@ -122,12 +138,16 @@ impl ClassTableBuilder {
         }
     }
 
-    pub fn declare_class_method(&mut self, method: &MethodDefinition) {
+    pub fn declare_class_method(
+        &mut self,
+        method: &MethodDefinition,
+        resolve_constant: &dyn Fn(&IdentifierReference) -> Option<Atom>,
+    ) {
         if method.kind.is_constructor() || method.value.is_typescript_syntax() {
             return;
         }
         let is_private = method.key.is_private_identifier();
-        let name = if is_private { method.key.private_name() } else { method.key.static_name() };
+        let name = element_name(&method.key, resolve_constant);
 
         if let Some(name) = name {
             if let Some(class_id) = self.current_class_id {
@@ -138,6 +158,7 @@ impl ClassTableBuilder {
                         method.key.span(),
                         method.r#static,
                         is_private,
+                        method.computed,
                         match method.kind {
                             MethodDefinitionKind::Method => ElementKind::Method,
                             MethodDefinitionKind::Get => ElementKind::Method | ElementKind::Getter,
@@ -158,3 +179,45 @@ impl ClassTableBuilder {
             .and_then(|current_class_id| self.classes.parent_ids.get(&current_class_id).copied());
     }
 }
+
+/// Name of a class element: the name of a private or static key, or for a computed key which is an
+/// identifier, the value of the `const` it refers to, e.g. `a` of `[key]` after `const key = "a"`
+fn element_name(
+    key: &PropertyKey,
+    resolve_constant: &dyn Fn(&IdentifierReference) -> Option<Atom>,
+) -> Option<Atom> {
+    match key {
+        PropertyKey::Expression(Expression::Identifier(ident)) => resolve_constant(ident),
+        _ => key.name(),
+    }
+}
+
+/// Value of the `const` declared so far which `ident` refers to, if it is initialized with a
+/// string, number or template literal without expressions
+fn constant_value(
+    ident: &IdentifierReference,
+    nodes: &AstNodes,
+    scope: &ScopeTree,
+    symbols: &SymbolTable,
+    scope_id: ScopeId,
+) -> Option<Atom> {
+    let symbol_id =
+        scope.ancestors(scope_id).find_map(|scope_id| scope.get_binding(scope_id, &ident.name))?;
+    if !symbols.get_flag(symbol_id).contains(SymbolFlags::ConstVariable) {
+        return None;
+    }
+    let AstKind::VariableDeclarator(decl) = nodes.kind(symbols.get_declaration(symbol_id)) else {
+        return None;
+    };
+    if !matches!(decl.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+        return None;
+    }
+    match decl.init.as_ref()? {
+        Expression::StringLiteral(lit) => Some(lit.value.clone()),
+        Expression::NumericLiteral(lit) => Some(Atom::from(lit.value.to_string())),
+        Expression::TemplateLiteral(lit) => {
+            lit.expressions.is_empty().then(|| lit.quasi()).flatten().cloned()
+        }
+        _ => None,
+    }
+}
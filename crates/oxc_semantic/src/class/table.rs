@@ -11,6 +11,8 @@ pub struct Element {
     pub span: Span,
     pub is_private: bool,
     pub r#static: bool,
+    /// The key is computed, e.g. `['a']`, or `[key]` where `key` is a constant
+    pub computed: bool,
     pub kind: ElementKind,
 }
 
@@ -20,9 +22,10 @@ impl Element {
         span: Span,
         r#static: bool,
         is_private: bool,
+        computed: bool,
         kind: ElementKind,
     ) -> Self {
-        Self { name, span, is_private, r#static, kind }
+        Self { name, span, is_private, r#static, computed, kind }
     }
 }
 
@@ -0,0 +1,96 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::symbol::SymbolFlags;
+
+use crate::Semantic;
+
+/// Tags of the GraphQL documents to extract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphqlOptions {
+    /// Identifiers or member expressions used as tags, defaults to `gql` and `graphql`
+    pub tags: Vec<String>,
+}
+
+impl Default for GraphqlOptions {
+    fn default() -> Self {
+        Self { tags: vec!["gql".to_string(), "graphql".to_string()] }
+    }
+}
+
+/// A GraphQL document in a tagged template, e.g. ``gql`query { viewer { id } }` ``
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphqlTemplate {
+    /// Span of the tagged template expression
+    pub span: Span,
+    /// The matched tag, e.g. `gql`
+    pub tag: String,
+    /// The static parts of the template joined together, interpolations are left out
+    pub content: String,
+    /// Spans of the static parts in the source
+    pub quasis: Vec<Span>,
+    /// Interpolated expressions, typically fragments
+    pub interpolations: Vec<GraphqlInterpolation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphqlInterpolation {
+    /// Span of the interpolated expression in the source
+    pub span: Span,
+    /// Byte offset in [`GraphqlTemplate::content`] the interpolation is located at
+    pub offset: usize,
+}
+
+impl GraphqlTemplate {
+    /// Find the tagged templates of `options.tags` in source order.
+    ///
+    /// Tags bound to a local variable, rather than an import or a global, are ignored.
+    pub fn extract(semantic: &Semantic, options: &GraphqlOptions) -> Vec<Self> {
+        let mut templates = vec![];
+        for node in semantic.nodes().iter() {
+            let AstKind::TaggedTemplateExpression(expr) = node.kind() else { continue };
+            let Some(tag) = tag_name(semantic, &expr.tag) else { continue };
+            if !options.tags.iter().any(|t| *t == tag) {
+                continue;
+            }
+
+            let mut content = String::new();
+            let mut quasis = vec![];
+            let mut interpolations = vec![];
+            for (index, quasi) in expr.quasi.quasis.iter().enumerate() {
+                if index > 0 {
+                    let span = expr.quasi.expressions[index - 1].span();
+                    interpolations.push(GraphqlInterpolation { span, offset: content.len() });
+                }
+                // Invalid escape sequences are kept as written
+                let value = quasi.value.cooked.as_ref().unwrap_or(&quasi.value.raw);
+                content.push_str(value);
+                quasis.push(quasi.span);
+            }
+            templates.push(Self { span: expr.span, tag, content, quasis, interpolations });
+        }
+        templates
+    }
+}
+
+/// Name of `tag` if it is an identifier or a static member expression, e.g. `graphql.experimental`
+fn tag_name(semantic: &Semantic, tag: &Expression) -> Option<String> {
+    match tag.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            let symbols = semantic.symbols();
+            let symbol_id = ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| symbols.get_reference(reference_id).symbol_id());
+            let is_local = symbol_id.is_some_and(|symbol_id| {
+                !symbols.get_flag(symbol_id).contains(SymbolFlags::ImportBinding)
+            });
+            (!is_local).then(|| ident.name.to_string())
+        }
+        Expression::MemberExpression(member) => {
+            let object = tag_name(semantic, member.object())?;
+            let property = member.static_property_name()?;
+            Some(format!("{object}.{property}"))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,244 @@
+use oxc_ast::{
+    ast::{
+        Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild,
+        JSXElement, JSXElementName, JSXExpression, ObjectExpression, ObjectPropertyKind,
+    },
+    AstKind,
+};
+use oxc_span::Span;
+
+use crate::Semantic;
+
+/// Call patterns of the messages to extract.
+///
+/// Names are identifiers or member expressions, e.g. `i18n.t`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct I18nOptions {
+    /// Functions taking the message key as the first argument and optionally a default message,
+    /// either as a string or as the `defaultValue` of an options object, e.g. `t("key", "Hello")`
+    pub functions: Vec<String>,
+    /// Functions taking a message descriptor with `id`, `defaultMessage` / `message` and
+    /// `description` / `comment`, e.g. `formatMessage({ id: "key" })`
+    pub descriptor_functions: Vec<String>,
+    /// Functions taking an object of message descriptors, e.g. `defineMessages({ a: { id: "key" } })`
+    pub descriptors_functions: Vec<String>,
+    /// JSX components taking the message key as `id` or `i18nKey` and the default message as
+    /// `defaultMessage` / `message` or as their text content, e.g. `<Trans id="key" />`
+    pub components: Vec<String>,
+}
+
+impl Default for I18nOptions {
+    fn default() -> Self {
+        let names = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        Self {
+            functions: names(&["t", "i18n.t", "i18next.t", "$t"]),
+            descriptor_functions: names(&[
+                "defineMessage",
+                "formatMessage",
+                "intl.formatMessage",
+                "i18n._",
+                "msg",
+            ]),
+            descriptors_functions: names(&["defineMessages"]),
+            components: names(&["Trans", "FormattedMessage"]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum I18nMessageKind {
+    /// A call of one of [`I18nOptions::functions`]
+    Function,
+    /// A message descriptor passed to one of [`I18nOptions::descriptor_functions`]
+    /// or [`I18nOptions::descriptors_functions`]
+    Descriptor,
+    /// An element of one of [`I18nOptions::components`]
+    Component,
+}
+
+/// A translatable message, e.g. `t("greeting", "Hello")`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct I18nMessage {
+    pub kind: I18nMessageKind,
+    /// Span of the call, descriptor or element
+    pub span: Span,
+    /// The message key
+    pub key: String,
+    /// Span of the string the key is read from
+    pub key_span: Span,
+    pub default_message: Option<String>,
+    /// Description for translators
+    pub description: Option<String>,
+}
+
+impl I18nMessage {
+    /// Find the messages of the configured call patterns in source order.
+    ///
+    /// Only messages with a static key are extracted, keys built at runtime can not be cataloged.
+    pub fn extract(semantic: &Semantic, options: &I18nOptions) -> Vec<Self> {
+        let is_any = |names: &[String], name: &str| names.iter().any(|n| n == name);
+        let mut messages = vec![];
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::CallExpression(call) => {
+                    let Some(name) = expression_name(&call.callee) else { continue };
+                    let Some(Argument::Expression(first)) = call.arguments.first() else {
+                        continue;
+                    };
+                    if is_any(&options.functions, &name) {
+                        messages.extend(Self::from_function(call.span, first, &call.arguments));
+                    } else if is_any(&options.descriptor_functions, &name) {
+                        if let Expression::ObjectExpression(object) = first {
+                            messages.extend(Self::from_descriptor(object));
+                        }
+                    } else if is_any(&options.descriptors_functions, &name) {
+                        let Expression::ObjectExpression(object) = first else { continue };
+                        for property in &object.properties {
+                            if let ObjectPropertyKind::ObjectProperty(property) = property {
+                                if let Expression::ObjectExpression(descriptor) = &property.value {
+                                    messages.extend(Self::from_descriptor(descriptor));
+                                }
+                            }
+                        }
+                    }
+                }
+                AstKind::JSXElement(element) => {
+                    let JSXElementName::Identifier(name) = &element.opening_element.name else {
+                        continue;
+                    };
+                    if is_any(&options.components, &name.name) {
+                        messages.extend(Self::from_component(element));
+                    }
+                }
+                _ => {}
+            }
+        }
+        messages
+    }
+
+    /// `t("key", "default")` or `t("key", { defaultValue: "default" })`
+    fn from_function(span: Span, key: &Expression, arguments: &[Argument]) -> Option<Self> {
+        let (key, key_span) = static_string(key)?;
+        let default_message = match arguments.get(1) {
+            Some(Argument::Expression(Expression::ObjectExpression(object))) => {
+                object_property(object, &["defaultValue"]).map(|(value, _)| value)
+            }
+            Some(Argument::Expression(expr)) => static_string(expr).map(|(value, _)| value),
+            _ => None,
+        };
+        Some(Self {
+            kind: I18nMessageKind::Function,
+            span,
+            key,
+            key_span,
+            default_message,
+            description: None,
+        })
+    }
+
+    /// `{ id: "key", defaultMessage: "default", description: "description" }`
+    fn from_descriptor(object: &ObjectExpression) -> Option<Self> {
+        let (key, key_span) = object_property(object, &["id"])?;
+        Some(Self {
+            kind: I18nMessageKind::Descriptor,
+            span: object.span,
+            key,
+            key_span,
+            default_message: object_property(object, &["defaultMessage", "message"])
+                .map(|(value, _)| value),
+            description: object_property(object, &["description", "comment"])
+                .map(|(value, _)| value),
+        })
+    }
+
+    /// `<Trans id="key" message="default" />` or `<Trans i18nKey="key">default</Trans>`
+    fn from_component(element: &JSXElement) -> Option<Self> {
+        let attribute = |names: &[&str]| {
+            element.opening_element.attributes.iter().find_map(|attribute| {
+                let JSXAttributeItem::Attribute(attribute) = attribute else { return None };
+                let JSXAttributeName::Identifier(name) = &attribute.name else { return None };
+                if !names.contains(&name.name.as_str()) {
+                    return None;
+                }
+                match attribute.value.as_ref()? {
+                    JSXAttributeValue::StringLiteral(literal) => {
+                        Some((literal.value.to_string(), literal.span))
+                    }
+                    JSXAttributeValue::ExpressionContainer(container) => {
+                        let JSXExpression::Expression(expr) = &container.expression else {
+                            return None;
+                        };
+                        static_string(expr)
+                    }
+                    _ => None,
+                }
+            })
+        };
+        let (key, key_span) = attribute(&["id", "i18nKey"])?;
+        let default_message = attribute(&["defaultMessage", "message"])
+            .map(|(value, _)| value)
+            .or_else(|| text_content(&element.children));
+        Some(Self {
+            kind: I18nMessageKind::Component,
+            span: element.span,
+            key,
+            key_span,
+            default_message,
+            description: attribute(&["description", "comment"]).map(|(value, _)| value),
+        })
+    }
+}
+
+/// Name of `expr` if it is an identifier or a static member expression, e.g. `i18n.t`
+fn expression_name(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        Expression::MemberExpression(member) => {
+            let object = expression_name(member.object())?;
+            let property = member.static_property_name()?;
+            Some(format!("{object}.{property}"))
+        }
+        _ => None,
+    }
+}
+
+/// Value and span of a string literal or a template literal without substitutions
+fn static_string(expr: &Expression) -> Option<(String, Span)> {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(literal) => Some((literal.value.to_string(), literal.span)),
+        Expression::TemplateLiteral(template) if template.is_no_substitution_template() => {
+            Some((template.quasi()?.to_string(), template.span))
+        }
+        _ => None,
+    }
+}
+
+/// The static string value of the first property of `names` in `object`
+fn object_property(object: &ObjectExpression, names: &[&str]) -> Option<(String, Span)> {
+    names.iter().find_map(|name| {
+        object.properties.iter().find_map(|property| {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+            if property.key.static_name()? != *name {
+                return None;
+            }
+            static_string(&property.value)
+        })
+    })
+}
+
+/// Text of JSX children consisting of text only, with whitespace collapsed
+fn text_content(children: &[JSXChild]) -> Option<String> {
+    let mut text = String::new();
+    for child in children {
+        match child {
+            JSXChild::Text(child) => text.push_str(&child.value),
+            JSXChild::ExpressionContainer(container) => {
+                let JSXExpression::Expression(expr) = &container.expression else { continue };
+                text.push_str(&static_string(expr)?.0);
+            }
+            _ => return None,
+        }
+    }
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
@@ -121,7 +121,7 @@ impl<'a> JSDocBuilder<'a> {
         }
 
         // Should remove the very first `*`?
-        Some(JSDocComment::new(comment_content))
+        Some(JSDocComment::new(comment_content, comment_span))
     }
 }
 
@@ -131,7 +131,10 @@ mod test {
     use oxc_parser::Parser;
     use oxc_span::{SourceType, Span};
 
-    use crate::{jsdoc::JSDocComment, Semantic, SemanticBuilder};
+    use crate::{
+        jsdoc::{JSDocComment, JSDocTagKind},
+        Semantic, SemanticBuilder,
+    };
 
     fn build_semantic<'a>(
         allocator: &'a Allocator,
@@ -315,4 +318,27 @@ mod test {
         );
         assert_eq!(semantic.jsdoc().iter_all().count(), 7);
     }
+
+    #[test]
+    fn tags_spans() {
+        let allocator = Allocator::default();
+        let source_text = "
+            /**
+             * Adds numbers.
+             * @param {number} a
+             * @returns {number}
+             */
+            function foo(a) {}
+        ";
+        let jsdocs = get_jsdoc(&allocator, source_text, "function foo(a) {}", None).unwrap();
+        let jsdoc = &jsdocs[0];
+        assert_eq!(jsdoc.description(), "Adds numbers.");
+        let tags = jsdoc.tags();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].span.source_text(source_text), "@param {number} a");
+        let JSDocTagKind::Param(param) = tags[0].kind else { unreachable!() };
+        assert_eq!(param.name_span.source_text(source_text), "a");
+        assert_eq!(param.r#type.unwrap().span.source_text(source_text), "number");
+        assert_eq!(tags[1].span.source_text(source_text), "@returns {number}");
+    }
 }
@@ -6,7 +6,7 @@ pub use builder::JSDocBuilder;
 use oxc_span::{GetSpan, Span};
 
 use self::parser::JSDocParser;
-pub use self::parser::JSDocTag;
+pub use self::parser::{JSDocParam, JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind};
 use crate::AstNode;
 
 mod parser;
@@ -20,7 +20,9 @@ pub struct JSDoc<'a> {
 
 #[derive(Debug, Clone)]
 pub struct JSDocComment<'a> {
+    /// Text of the comment without `/*` and `*/`
     comment: &'a str,
+    span: Span,
     /// Cached JSDocTags
     tags: OnceCell<Vec<JSDocTag<'a>>>,
 }
@@ -62,11 +64,21 @@ impl<'a> JSDoc<'a> {
 }
 
 impl<'a> JSDocComment<'a> {
-    pub fn new(comment: &'a str) -> JSDocComment<'a> {
-        Self { comment, tags: OnceCell::new() }
+    pub fn new(comment: &'a str, span: Span) -> JSDocComment<'a> {
+        Self { comment, span, tags: OnceCell::new() }
+    }
+
+    /// Span of the comment without `/*` and `*/`, the spans of the tags are in the same file.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The text before the first tag.
+    pub fn description(&self) -> &'a str {
+        JSDocParser::new(self.comment, self.span.start).description()
     }
 
     pub fn tags<'b>(&'b self) -> &'b Vec<JSDocTag<'a>> {
-        self.tags.get_or_init(|| JSDocParser::new(self.comment).parse())
+        self.tags.get_or_init(|| JSDocParser::new(self.comment, self.span.start).parse())
     }
 }
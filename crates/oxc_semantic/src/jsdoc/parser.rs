@@ -1,67 +1,69 @@
-use std::str::FromStr;
+use oxc_span::Span;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ParamTypeKind {
+pub enum JSDocTypeKind {
     Any,
     Repeated,
 }
 
+/// A type in braces, e.g. `{string}` of `@param {string} a`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParamType<'a> {
-    value: &'a str,
+pub struct JSDocType<'a> {
+    /// Span of the type without the braces
+    pub span: Span,
+    pub value: &'a str,
 }
 
-impl<'a> ParamType<'a> {
-    #[allow(unused)]
-    pub fn kind(&self) -> Option<ParamTypeKind> {
-        ParamTypeKind::from_str(self.value).map(Option::Some).unwrap_or_default()
-    }
-}
-
-impl FromStr for ParamTypeKind {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl<'a> JSDocType<'a> {
+    pub fn kind(&self) -> Option<JSDocTypeKind> {
         // TODO: This might be inaccurate if the type is listed as {....string} or some variant
-        if s.len() > 3 && &s[0..3] == "..." {
-            return Ok(Self::Repeated);
+        if self.value.len() > 3 && self.value.starts_with("...") {
+            return Some(JSDocTypeKind::Repeated);
         }
-
-        if s == "*" {
-            return Ok(Self::Any);
+        if self.value == "*" {
+            return Some(JSDocTypeKind::Any);
         }
-
-        Err(())
+        None
     }
 }
 
+/// `@param {type} name`, `@param {type} [name]` and `@param {type} [name=default]`
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Param<'a> {
-    name: &'a str,
-    r#type: Option<ParamType<'a>>,
+pub struct JSDocParam<'a> {
+    pub name: &'a str,
+    pub name_span: Span,
+    pub r#type: Option<JSDocType<'a>>,
+    /// The name is in brackets
+    pub optional: bool,
+    pub default: Option<&'a str>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum JSDocTagKind<'a> {
     Deprecated,
-    Param(Param<'a>),
-}
-
-impl<'a> FromStr for JSDocTagKind<'a> {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "deprecated" => Ok(Self::Deprecated),
-            "param" => Ok(Self::Param(Param::default())),
-            _ => Err(()),
-        }
-    }
+    /// `@param`, `@arg` and `@argument`
+    Param(JSDocParam<'a>),
+    /// `@property` and `@prop`
+    Property(JSDocParam<'a>),
+    /// `@returns` and `@return`
+    Returns(Option<JSDocType<'a>>),
+    /// `@type`
+    Type(Option<JSDocType<'a>>),
+    /// `@throws` and `@exception`
+    Throws(Option<JSDocType<'a>>),
+    /// Any other tag, e.g. `@example`, see [`JSDocTag::name`]
+    Unknown,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JSDocTag<'a> {
+    /// Span from the `@` to the end of the description
+    pub span: Span,
+    /// Name of the tag without the `@`, e.g. `param`
+    pub name: &'a str,
     pub kind: JSDocTagKind<'a>,
+    /// Text after the name and type of the tag, the `*` starting its lines are kept,
+    /// see [`JSDocTag::description_lines`]
     pub description: &'a str,
 }
 
@@ -69,238 +71,309 @@ impl<'a> JSDocTag<'a> {
     pub fn is_deprecated(&self) -> bool {
         matches!(self.kind, JSDocTagKind::Deprecated)
     }
+
+    /// Lines of the description without the leading `*` of the comment.
+    pub fn description_lines(&self) -> impl Iterator<Item = &'a str> {
+        description_lines(self.description)
+    }
 }
 
+/// Parses the tags of a JSDoc comment.
+///
+/// Tags start a line of the comment, `{@link foo}` in the middle of a line is part of a description.
 #[derive(Debug)]
 pub struct JSDocParser<'a> {
+    /// Text of the comment without `/*` and `*/`
     source_text: &'a str,
-    current: usize,
+    /// Offset of the text in the file
+    offset: u32,
 }
 
 impl<'a> JSDocParser<'a> {
-    pub fn new(source_text: &'a str) -> Self {
-        Self { source_text, current: 0 }
+    pub fn new(source_text: &'a str, offset: u32) -> Self {
+        Self { source_text, offset }
     }
 
-    pub fn parse(mut self) -> Vec<JSDocTag<'a>> {
-        self.parse_comment(self.source_text)
+    /// The text before the first tag.
+    pub fn description(&self) -> &'a str {
+        let end = self.tag_starts().next().unwrap_or(self.source_text.len());
+        trim_end(&self.source_text[..end])
+            .trim_start_matches(|c: char| c.is_whitespace() || c == '*')
     }
 
-    fn advance(&mut self) {
-        if self.current < self.source_text.len() {
-            self.current += 1;
-        }
+    pub fn parse(self) -> Vec<JSDocTag<'a>> {
+        let starts = self.tag_starts().collect::<Vec<_>>();
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, start)| {
+                let end = starts.get(i + 1).copied().unwrap_or(self.source_text.len());
+                self.parse_tag(*start, end)
+            })
+            .collect()
     }
 
-    fn at(&mut self, c: char) -> bool {
-        let Some(ch) = self.source_text.chars().nth(self.current) else { return false };
-        if ch == c {
-            self.advance();
-            true
-        } else {
-            false
-        }
+    /// Offsets of the `@` of the tags.
+    fn tag_starts(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut line_start = 0;
+        self.source_text.split_inclusive('\n').filter_map(move |line| {
+            let start = line_start;
+            line_start += line.len();
+            let content = line.trim_start().trim_start_matches('*').trim_start();
+            content.starts_with('@').then(|| start + line.len() - content.len())
+        })
     }
 
-    fn take_until(&mut self, s: &'a str, predicate: fn(char) -> bool) -> &'a str {
-        let start = self.current;
-        while let Some(c) = s.chars().nth(self.current) {
-            if predicate(c) {
-                break;
-            }
-            self.current += 1;
+    fn parse_tag(&self, start: usize, end: usize) -> JSDocTag<'a> {
+        let source = trim_end(&self.source_text[..end]);
+        let mut pos = start + 1;
+        let name = take_while(source, &mut pos, |c| !c.is_whitespace() && c != '{');
+        let kind = match name {
+            "deprecated" => JSDocTagKind::Deprecated,
+            "param" | "arg" | "argument" => JSDocTagKind::Param(self.parse_param(source, &mut pos)),
+            "property" | "prop" => JSDocTagKind::Property(self.parse_param(source, &mut pos)),
+            "returns" | "return" => JSDocTagKind::Returns(self.parse_type(source, &mut pos)),
+            "type" => JSDocTagKind::Type(self.parse_type(source, &mut pos)),
+            "throws" | "exception" => JSDocTagKind::Throws(self.parse_type(source, &mut pos)),
+            _ => JSDocTagKind::Unknown,
+        };
+        take_while(source, &mut pos, char::is_whitespace);
+        if matches!(kind, JSDocTagKind::Param(_) | JSDocTagKind::Property(_))
+            && source[pos..].starts_with('-')
+        {
+            pos += 1;
+            take_while(source, &mut pos, char::is_whitespace);
         }
-        &s[start..self.current]
+        JSDocTag { span: self.span(start, source.len()), name, kind, description: &source[pos..] }
     }
 
-    fn skip_whitespace(&mut self, s: &'a str) {
-        while let Some(c) = s.chars().nth(self.current) {
-            if c != ' ' {
-                break;
-            }
-            self.current += 1;
+    fn parse_type(&self, source: &'a str, pos: &mut usize) -> Option<JSDocType<'a>> {
+        take_while(source, pos, char::is_whitespace);
+        if !source[*pos..].starts_with('{') {
+            return None;
         }
+        let start = *pos + 1;
+        let mut depth = 0;
+        let close = source[start..].char_indices().find_map(|(i, c)| match c {
+            '{' => {
+                depth += 1;
+                None
+            }
+            '}' if depth == 0 => Some(i),
+            '}' => {
+                depth -= 1;
+                None
+            }
+            _ => None,
+        });
+        let end = if let Some(i) = close {
+            *pos = start + i + 1;
+            start + i
+        } else {
+            // An unclosed type ends at the first whitespace
+            *pos = start;
+            take_while(source, pos, |c| !c.is_whitespace());
+            *pos
+        };
+        Some(JSDocType { span: self.span(start, end), value: &source[start..end] })
     }
 
-    fn parse_comment(&mut self, comment: &'a str) -> Vec<JSDocTag<'a>> {
-        let mut tags = vec![];
-
-        while let Some(c) = comment.chars().nth(self.current) {
-            match c {
-                '@' => {
-                    self.current += 1;
-                    let Some(tag) = self.parse_tag(comment) else { break };
-                    self.current += tag.description.len();
-                    tags.push(tag);
-                }
-                _ => {
-                    self.current += 1;
-                }
-            }
+    fn parse_param(&self, source: &'a str, pos: &mut usize) -> JSDocParam<'a> {
+        let r#type = self.parse_type(source, pos);
+        take_while(source, pos, char::is_whitespace);
+        let optional = source[*pos..].starts_with('[');
+        if !optional {
+            let name_start = *pos;
+            let name = take_while(source, pos, |c| !c.is_whitespace());
+            let name_span = self.span(name_start, *pos);
+            return JSDocParam { name, name_span, r#type, optional, default: None };
         }
 
-        tags
-    }
-
-    fn parse_tag(&mut self, comment: &'a str) -> Option<JSDocTag<'a>> {
-        let tag = self.take_until(comment, |c| c == ' ' || c == '\n');
-        JSDocTagKind::from_str(tag).map_or(None, |kind| match kind {
-            JSDocTagKind::Deprecated => Some(self.parse_deprecated_tag(comment)),
-            JSDocTagKind::Param { .. } => Some(self.parse_param_tag(comment)),
-        })
+        *pos += 1;
+        take_while(source, pos, char::is_whitespace);
+        let name_start = *pos;
+        let name = take_while(source, pos, |c| !c.is_whitespace() && c != '=' && c != ']');
+        let name_span = self.span(name_start, *pos);
+        take_while(source, pos, char::is_whitespace);
+        let default = source[*pos..].starts_with('=').then(|| {
+            *pos += 1;
+            take_while(source, pos, |c| c != ']').trim()
+        });
+        if source[*pos..].starts_with(']') {
+            *pos += 1;
+        }
+        JSDocParam { name, name_span, r#type, optional, default }
     }
 
-    fn parse_deprecated_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
-        JSDocTag { kind: JSDocTagKind::Deprecated, description }
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.offset + start as u32, self.offset + end as u32)
     }
+}
 
-    fn parse_param_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-
-        let mut r#type = None;
-
-        if self.at('{') {
-            // If we hit a space, then treat it as the end of the type annotation.
-            let type_annotation = self.take_until(comment, |c| c == '}' || c == ' ');
-            r#type = Some(ParamType { value: type_annotation });
-            if self.at('}') {
-                self.skip_whitespace(comment);
-            }
-            self.skip_whitespace(comment);
-        }
-
-        let name = self.take_until(comment, |c| c == ' ' || c == '\n');
-
-        self.skip_whitespace(comment);
-        if self.at('-') {
-            self.skip_whitespace(comment);
-        }
+/// Lines of a description without the leading `*` of the comment.
+pub fn description_lines(description: &str) -> impl Iterator<Item = &str> {
+    description.lines().map(|line| {
+        let line = line.trim_start();
+        line.strip_prefix('*').unwrap_or(line).trim()
+    })
+}
 
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
+fn take_while<'a>(source: &'a str, pos: &mut usize, predicate: impl Fn(char) -> bool) -> &'a str {
+    let start = *pos;
+    let len = source[start..].find(|c| !predicate(c)).unwrap_or(source.len() - start);
+    *pos = start + len;
+    &source[start..*pos]
+}
 
-        JSDocTag { kind: JSDocTagKind::Param(Param { name, r#type }), description }
+/// Strip the trailing whitespace and the last line if it only holds the `*` before `*/`.
+fn trim_end(text: &str) -> &str {
+    let text = text.trim_end();
+    match text.rfind('\n') {
+        Some(i) if text[i..].trim().chars().all(|c| c == '*') => text[..i].trim_end(),
+        _ => text,
     }
 }
 
 #[cfg(test)]
 mod test {
+    use oxc_span::Span;
+
     use super::JSDocParser;
-    use crate::jsdoc::parser::{JSDocTag, JSDocTagKind, Param, ParamType, ParamTypeKind};
+    use crate::jsdoc::parser::{JSDocParam, JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind};
+
+    fn parse(source: &str) -> Vec<JSDocTag<'_>> {
+        JSDocParser::new(source, 0).parse()
+    }
+
+    fn r#type<'a>(source: &'a str, value: &str) -> JSDocType<'a> {
+        let start = source.find(value).unwrap();
+        let end = start + value.len();
+        let span = Span::new(u32::try_from(start).unwrap(), u32::try_from(end).unwrap());
+        JSDocType { span, value: &source[start..end] }
+    }
 
     #[test]
-    fn deduces_correct_param_kind() {
-        let param = Param { name: "a", r#type: Some(ParamType { value: "string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), None);
+    fn deduces_correct_type_kind() {
+        let r#type = JSDocType { span: Span::default(), value: "string" };
+        assert_eq!(r#type.kind(), None);
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "...string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Repeated));
+        let r#type = JSDocType { span: Span::default(), value: "...string" };
+        assert_eq!(r#type.kind(), Some(JSDocTypeKind::Repeated));
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "*" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Any));
+        let r#type = JSDocType { span: Span::default(), value: "*" };
+        assert_eq!(r#type.kind(), Some(JSDocTypeKind::Any));
     }
 
     #[test]
     fn parses_single_line_jsdoc() {
-        let source = "/** @deprecated */";
+        let source = "* @deprecated ";
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
+        let tags = parse(source);
+        assert_eq!(
+            tags,
+            vec![JSDocTag {
+                span: Span::new(2, 13),
+                name: "deprecated",
+                kind: JSDocTagKind::Deprecated,
+                description: ""
+            }]
+        );
     }
 
     #[test]
     fn parses_multi_line_disjoint_jsdoc() {
-        let source = r"/** @deprecated
-        */
+        let source = "* @deprecated
         ";
 
-        let tags = JSDocParser::new(source).parse();
+        let tags = parse(source);
         assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
-    }
-
-    #[test]
-    fn parses_multiline_jsdoc() {
-        let source = r"/**
-        * @param a
-        * @deprecated
-        */
-       ";
-
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 2);
-        assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "" },
-            ]
-        );
+        assert!(tags[0].is_deprecated());
+        assert_eq!(tags[0].description, "");
     }
 
     #[test]
     fn parses_multiline_jsdoc_with_descriptions() {
-        let source = r"/**
+        let source = "*
+        * Adds numbers.
         * @param a
         * @deprecated since version 1.0
-        */
-       ";
+        *   use `sum` instead
+        ";
 
-        let tags = JSDocParser::new(source).parse();
+        let parser = JSDocParser::new(source, 0);
+        assert_eq!(parser.description(), "Adds numbers.");
+        let tags = parser.parse();
         assert_eq!(tags.len(), 2);
+        let JSDocTagKind::Param(param) = tags[0].kind else { unreachable!() };
+        assert_eq!(param.name, "a");
+        assert_eq!(&source[param.name_span.start as usize..param.name_span.end as usize], "a");
+        assert_eq!(tags[0].description, "");
         assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "since version 1.0" },
-            ]
+            tags[1].description_lines().collect::<Vec<_>>(),
+            vec!["since version 1.0", "use `sum` instead"]
+        );
+        assert_eq!(
+            &source[tags[1].span.start as usize..tags[1].span.end as usize],
+            "@deprecated since version 1.0
+        *   use `sum` instead"
         );
     }
 
     #[test]
     fn parses_param_type_annotation() {
-        let source = r"/**
+        let source = "*
         * @param {string} a
         * @param {string b
-        * @param {string} c - description
-        */
-       ";
+        * @arg {Object<string, number>} c - description
+        * @param {number} [d]
+        * @param {number} [e = 1] - optional
+        ";
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 3);
-        assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "a",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "b",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "c",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: "description"
-                },
-            ]
-        );
+        let params = parse(source)
+            .into_iter()
+            .map(|tag| match tag.kind {
+                JSDocTagKind::Param(param) => (param, tag.description),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(params.len(), 5);
+        assert_eq!(params[0].0.r#type, Some(r#type(source, "string")));
+        assert_eq!(params[0].0.name, "a");
+        assert_eq!(params[1].0.r#type.map(|t| t.value), Some("string"));
+        assert_eq!(params[1].0.name, "b");
+        assert_eq!(params[2].0.r#type, Some(r#type(source, "Object<string, number>")));
+        assert_eq!(params[2].0.name, "c");
+        assert_eq!(params[2].1, "description");
+        assert!(matches!(params[3].0, JSDocParam { name: "d", optional: true, default: None, .. }));
+        assert!(matches!(
+            params[4].0,
+            JSDocParam { name: "e", optional: true, default: Some("1"), .. }
+        ));
+        assert_eq!(params[4].1, "optional");
+    }
+
+    #[test]
+    fn parses_other_tags() {
+        let source = "*
+        * See {@link foo} for details.
+        * @returns {Promise<void>} when done
+        * @type {number}
+        * @throws {TypeError}
+        * @example
+        * foo();
+        ";
+
+        let parser = JSDocParser::new(source, 0);
+        assert_eq!(parser.description(), "See {@link foo} for details.");
+        let tags = parser.parse();
+        assert_eq!(tags.len(), 4);
+        assert_eq!(tags[0].kind, JSDocTagKind::Returns(Some(r#type(source, "Promise<void>"))));
+        assert_eq!(tags[0].description, "when done");
+        assert_eq!(tags[1].kind, JSDocTagKind::Type(Some(r#type(source, "number"))));
+        assert_eq!(tags[2].kind, JSDocTagKind::Throws(Some(r#type(source, "TypeError"))));
+        assert_eq!(tags[3].name, "example");
+        assert_eq!(tags[3].kind, JSDocTagKind::Unknown);
+        assert_eq!(tags[3].description_lines().collect::<Vec<_>>(), vec!["foo();"]);
     }
 }
@@ -1,9 +1,14 @@
+use std::hash::BuildHasherDefault;
+
+use indexmap::IndexSet;
 use oxc_ast::ast::LabeledStatement;
 use oxc_span::Span;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHasher;
 
 use crate::AstNodeId;
 
+pub type UnusedLabels = IndexSet<AstNodeId, BuildHasherDefault<FxHasher>>;
+
 #[derive(Debug)]
 pub struct Label<'a> {
     pub id: AstNodeId,
@@ -42,7 +47,8 @@ impl<'a> Label<'a> {
 pub struct LabelBuilder<'a> {
     pub labels: Vec<Vec<Label<'a>>>,
     depth: usize,
-    pub unused_node_ids: FxHashSet<AstNodeId>,
+    /// In the order the labeled statements are left
+    pub unused_node_ids: UnusedLabels,
 }
 
 impl<'a> LabelBuilder<'a> {
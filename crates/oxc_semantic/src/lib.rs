@@ -1,9 +1,13 @@
 mod binder;
 mod builder;
+mod call_graph;
 mod checker;
+mod cjs_exports;
 mod class;
 mod control_flow;
 mod diagnostics;
+mod graphql;
+mod i18n;
 mod jsdoc;
 mod label;
 mod module_record;
@@ -11,7 +15,9 @@ mod node;
 pub mod pg;
 mod reference;
 mod scope;
+mod stats;
 mod symbol;
+mod ts_suppression;
 
 use std::{rc::Rc, sync::Arc};
 
@@ -19,7 +25,10 @@ pub use petgraph;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 use class::ClassTable;
-pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use jsdoc::{
+    JSDoc, JSDocComment, JSDocParam, JSDocTag, JSDocTagKind, JSDocType, JSDocTypeKind,
+};
+use label::UnusedLabels;
 use oxc_ast::{ast::IdentifierReference, AstKind, TriviasMap};
 use oxc_span::SourceType;
 pub use oxc_syntax::{
@@ -27,20 +36,25 @@ pub use oxc_syntax::{
     scope::{ScopeFlags, ScopeId},
     symbol::{SymbolFlags, SymbolId},
 };
-use rustc_hash::FxHashSet;
 
 pub use crate::{
     builder::VariableInfo,
+    call_graph::{CallGraph, CallGraphFunction, CallSite},
+    cjs_exports::CjsExports,
     control_flow::{
         print_basic_block, AssignmentValue, BasicBlockElement, BinaryAssignmentValue, BinaryOp,
         CallType, CalleeWithArgumentsAssignmentValue, CollectionAssignmentValue, ControlFlowGraph,
         EdgeType, ObjectPropertyAccessAssignmentValue, Register, UnaryExpressioneAssignmentValue,
         UpdateAssignmentValue,
     },
-    node::{AstNode, AstNodeId, AstNodes},
+    graphql::{GraphqlInterpolation, GraphqlOptions, GraphqlTemplate},
+    i18n::{I18nMessage, I18nMessageKind, I18nOptions},
+    node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
+    stats::Stats,
     symbol::SymbolTable,
+    ts_suppression::TsSuppressionTarget,
 };
 
 pub struct Semantic<'a> {
@@ -62,7 +76,7 @@ pub struct Semantic<'a> {
 
     jsdoc: JSDoc<'a>,
 
-    unused_labels: FxHashSet<AstNodeId>,
+    unused_labels: UnusedLabels,
 
     redeclare_variables: Vec<VariableInfo>,
 
@@ -114,7 +128,7 @@ impl<'a> Semantic<'a> {
         &self.symbols
     }
 
-    pub fn unused_labels(&self) -> &FxHashSet<AstNodeId> {
+    pub fn unused_labels(&self) -> &UnusedLabels {
         &self.unused_labels
     }
 
@@ -151,6 +165,37 @@ impl<'a> Semantic<'a> {
     pub fn redeclare_variables(&self) -> &Vec<VariableInfo> {
         &self.redeclare_variables
     }
+
+    /// Collect size and shape statistics of the program
+    pub fn stats(&self) -> Stats {
+        Stats::new(self)
+    }
+
+    /// Build the graph of calls between the functions of the module, see [`CallGraph`]
+    pub fn call_graph(&self) -> CallGraph {
+        CallGraph::new(self)
+    }
+
+    /// Detect the named exports of a CommonJS module, see [`CjsExports::new`]
+    pub fn cjs_exports(&self) -> CjsExports {
+        CjsExports::new(self)
+    }
+
+    /// Extract the GraphQL documents of tagged templates, see [`GraphqlTemplate::extract`]
+    pub fn graphql_templates(&self, options: &GraphqlOptions) -> Vec<GraphqlTemplate> {
+        GraphqlTemplate::extract(self, options)
+    }
+
+    /// Extract the translatable messages, see [`I18nMessage::extract`]
+    pub fn i18n_messages(&self, options: &I18nOptions) -> Vec<I18nMessage> {
+        I18nMessage::extract(self, options)
+    }
+
+    /// The `@ts-expect-error` and `@ts-ignore` comments with the nodes of the line they suppress,
+    /// see [`TsSuppressionTarget::collect`]
+    pub fn ts_suppressions(&self) -> Vec<TsSuppressionTarget> {
+        TsSuppressionTarget::collect(self)
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +290,45 @@ mod tests {
         assert!(semantic.symbols().references.len() == 1);
     }
 
+    #[test]
+    fn type_references() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true).with_module(true);
+        // Whether the references to `A` are type references
+        let sources: [(&str, &[bool]); 13] = [
+            ("type A = 1; type B = { a: A } | A", &[true, true]),
+            ("interface A {} let b = {} as A; b satisfies A", &[true, true]),
+            ("interface A {} class B implements A {} interface C extends A {}", &[true, true]),
+            // parameters, body, type parameters, return type
+            (
+                "class A {} function f<T extends A = A>(a: A): A { return new A() }",
+                &[true, false, true, true, true],
+            ),
+            ("const A = 1; type B = typeof A; let c: Array<typeof A>", &[true, true]),
+            ("const A = 1; namespace B { export const c = A }", &[false]),
+            ("const A = 1; enum B { C = A }", &[false]),
+            ("namespace A { export const b = 1 }\nimport C = A.b", &[false]),
+            ("class A {} let b = <A>{}; b = A", &[true, false]),
+            ("function A<T>() {} A<A>()", &[false, true]),
+            ("type A = 1; type B<T> = T extends Array<infer U extends A> ? U : never", &[true]),
+            ("type A = 'a'; type B = `b${A}`", &[true]),
+            ("type A = 1; type B = import('c').D<A>", &[true]),
+        ];
+        for (source, expected) in sources {
+            let semantic = get_semantic(&allocator, source, source_type);
+            let symbol_id = semantic.scopes().get_root_binding(&Atom::from("A")).unwrap();
+            let is_type =
+                semantic.symbol_references(symbol_id).map(Reference::is_type).collect::<Vec<_>>();
+            assert_eq!(is_type, expected, "{source}");
+            let only_types = expected.iter().all(|is_type| *is_type);
+            assert_eq!(
+                semantic.symbols().is_referenced_only_as_type(symbol_id),
+                only_types,
+                "{source}"
+            );
+        }
+    }
+
     #[test]
     fn test_reference_resolutions_simple_read_write() {
         let alloc = Allocator::default();
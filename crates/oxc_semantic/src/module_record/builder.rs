@@ -26,9 +26,11 @@ impl ModuleRecordBuilder {
                     self.visit_module_declaration(module_decl);
                 }
                 Statement::ExpressionStatement(expr_stmt) => {
+                    self.module_record.has_other_statements = true;
                     self.handle_cjs_export(&expr_stmt.expression);
                 }
                 stmt => {
+                    self.module_record.has_other_statements = true;
                     self.search_top_level_cjs_require(stmt);
                 }
             }
@@ -51,6 +53,21 @@ impl ModuleRecordBuilder {
             .push(name_span.span());
     }
 
+    fn add_module_request_attributes(&mut self, name_span: &NameSpan, with_clause: &WithClause) {
+        let attributes = with_clause
+            .with_entries
+            .iter()
+            .map(|attribute| ImportAttributeEntry {
+                key: NameSpan::new(attribute.key.as_atom(), attribute.key.span()),
+                value: NameSpan::new(attribute.value.value.clone(), attribute.value.span),
+            })
+            .collect();
+        self.module_record
+            .requested_module_attributes
+            .entry(name_span.name().clone())
+            .or_insert(attributes);
+    }
+
     fn add_import_entry(&mut self, entry: ImportEntry) {
         self.module_record.import_entries.push(entry);
     }
@@ -203,10 +220,14 @@ impl ModuleRecordBuilder {
                     module_request: module_request.clone(),
                     import_name,
                     local_name,
+                    phase: decl.phase,
                 });
             }
         }
         self.add_module_request(&module_request);
+        if let Some(with_clause) = &decl.with_clause {
+            self.add_module_request_attributes(&module_request, with_clause);
+        }
     }
 
     fn visit_export_all_declaration(&mut self, decl: &ExportAllDeclaration) {
@@ -230,6 +251,9 @@ impl ModuleRecordBuilder {
             self.add_export_binding(exported_name.name().clone(), exported_name.span());
         }
         self.add_module_request(&module_request);
+        if let Some(with_clause) = &decl.with_clause {
+            self.add_module_request_attributes(&module_request, with_clause);
+        }
     }
 
     fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration) {
@@ -273,6 +297,9 @@ impl ModuleRecordBuilder {
 
         if let Some(module_request) = &module_request {
             self.add_module_request(module_request);
+            if let Some(with_clause) = &decl.with_clause {
+                self.add_module_request_attributes(module_request, with_clause);
+            }
         }
 
         if let Some(decl) = &decl.declaration {
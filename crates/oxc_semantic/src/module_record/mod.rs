@@ -35,11 +35,23 @@ mod module_record_tests {
             module_request: NameSpan::new("mod".into(), Span::new(14, 19)),
             import_name: ImportImportName::Default(Span::new(7, 8)),
             local_name: NameSpan::new("v".into(), Span::new(7, 8)),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
     }
 
+    #[test]
+    fn import_phase() {
+        let module_record =
+            build("import source s from 'mod.wasm'; import defer * as ns from 'mod';");
+        let phases =
+            module_record.import_entries.iter().map(|entry| entry.phase).collect::<Vec<_>>();
+        assert_eq!(phases, vec![Some(ImportPhase::Source), Some(ImportPhase::Defer)]);
+        assert!(module_record.import_entries[0].import_name.is_default());
+        assert!(module_record.import_entries[1].import_name.is_namespace_object());
+    }
+
     #[test]
     fn import_namespace() {
         let module_record = build("import * as ns from 'mod'");
@@ -47,6 +59,7 @@ mod module_record_tests {
             module_request: NameSpan::new("mod".into(), Span::new(20, 25)),
             import_name: ImportImportName::NamespaceObject,
             local_name: NameSpan::new("ns".into(), Span::new(12, 14)),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -59,6 +72,7 @@ mod module_record_tests {
             module_request: NameSpan::new("mod".into(), Span::new(18, 23)),
             import_name: ImportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             local_name: NameSpan::new("x".into(), Span::new(9, 10)),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -71,6 +85,7 @@ mod module_record_tests {
             module_request: NameSpan::new("mod".into(), Span::new(23, 28)),
             import_name: ImportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             local_name: NameSpan::new("v".into(), Span::new(14, 15)),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -82,6 +97,21 @@ mod module_record_tests {
         assert!(module_record.import_entries.is_empty());
     }
 
+    #[test]
+    fn import_attributes() {
+        let module_record = build("import v from 'mod' with { type: 'json' }");
+        let attributes = vec![ImportAttributeEntry {
+            key: NameSpan::new("type".into(), Span::new(27, 31)),
+            value: NameSpan::new("json".into(), Span::new(33, 39)),
+        }];
+        assert_eq!(module_record.requested_module_attributes.get("mod"), Some(&attributes));
+
+        let module_record =
+            build("export { v } from 'mod' with { type: 'json' }; import 'mod'; import 'other'");
+        assert_eq!(module_record.requested_module_attributes.len(), 1);
+        assert_eq!(module_record.requested_module_attributes["mod"][0].value.name(), "json");
+    }
+
     // Table 57 gives examples of the ExportEntry record fields used to represent the syntactic export forms
     // `https://tc39.es/ecma262/#table-export-forms-mapping-to-exportentry-records`
 
@@ -17,6 +17,10 @@ pub struct Reference {
     symbol_id: Option<SymbolId>,
     /// Describes how this referenced is used by other AST nodes. References can
     /// be reads, writes, or both.
+    ///
+    /// References inside a `with` statement also have [`ReferenceFlag::MaybeWith`].
+    /// They are resolved to the binding they refer to when the `with` object has no
+    /// such property, but may refer to the property at runtime.
     flag: ReferenceFlag,
 }
 
@@ -45,6 +49,10 @@ impl Reference {
         self.symbol_id = Some(symbol_id);
     }
 
+    pub(crate) fn add_flag(&mut self, flag: ReferenceFlag) {
+        self.flag |= flag;
+    }
+
     /// Returns `true` if the identifier value was read. This is not mutually
     /// exclusive with [`#is_write`]
     pub fn is_read(&self) -> bool {
@@ -60,4 +68,10 @@ impl Reference {
     pub fn is_type(&self) -> bool {
         self.flag.is_type()
     }
+
+    /// Returns `true` if the identifier is inside a `with` statement, so it may refer to
+    /// a property of the `with` object instead of the resolved symbol.
+    pub fn is_maybe_with(&self) -> bool {
+        self.flag.is_maybe_with()
+    }
 }
@@ -12,11 +12,15 @@ use crate::{reference::ReferenceId, symbol::SymbolId, AstNodeId};
 type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
 
 type Bindings = FxIndexMap<Atom, SymbolId>;
-type UnresolvedReferences = FxHashMap<Atom, Vec<ReferenceId>>;
+type UnresolvedReferences = FxIndexMap<Atom, Vec<ReferenceId>>;
 
 /// Scope Tree
 ///
 /// `SoA` (Struct of Arrays) for memory efficiency.
+///
+/// Scopes are numbered in the order they are entered during the AST traversal,
+/// and bindings and unresolved references are kept in insertion order,
+/// so identical input always produces identical ids and iteration order.
 #[derive(Debug, Default)]
 pub struct ScopeTree {
     /// Maps a scope to the parent scope it belongs in
@@ -71,6 +75,7 @@ impl ScopeTree {
         self.child_ids.get(&scope_id)
     }
 
+    /// Iterate over all scopes in ascending [`ScopeId`] order, i.e. the order they appear in the source.
     pub fn descendants_from_root(&self) -> impl Iterator<Item = ScopeId> + '_ {
         self.parent_ids.iter_enumerated().map(|(scope_id, _)| scope_id)
     }
@@ -83,10 +88,28 @@ impl ScopeTree {
         self.flags[self.root_scope_id()]
     }
 
+    /// References to undeclared variables, keyed by name in order of first occurrence.
     pub fn root_unresolved_references(&self) -> &UnresolvedReferences {
         &self.unresolved_references[self.root_scope_id()]
     }
 
+    /// References which were not resolved within the scope.
+    ///
+    /// After semantic analysis, these are only kept by the root scope,
+    /// and by `with` scopes for references which may refer to a property of the `with` object.
+    pub fn get_unresolved_references(&self, scope_id: ScopeId) -> &UnresolvedReferences {
+        &self.unresolved_references[scope_id]
+    }
+
+    /// References inside `with` statements, which may refer to a property of the `with` object
+    /// instead of the binding they are resolved to.
+    pub fn iter_with_references(&self) -> impl Iterator<Item = (&Atom, &Vec<ReferenceId>)> + '_ {
+        self.flags
+            .iter_enumerated()
+            .filter(|(_, flags)| flags.is_with())
+            .flat_map(|(scope_id, _)| self.unresolved_references[scope_id].iter())
+    }
+
     pub fn get_flags(&self, scope_id: ScopeId) -> ScopeFlags {
         self.flags[scope_id]
     }
@@ -120,6 +143,7 @@ impl ScopeTree {
         self.node_ids[&scope_id]
     }
 
+    /// Iterate over all bindings, ordered by [`ScopeId`] and then by declaration order within a scope.
     pub fn iter_bindings(&self) -> impl Iterator<Item = (ScopeId, SymbolId, Atom)> + '_ {
         self.bindings.iter_enumerated().flat_map(|(scope_id, bindings)| {
             bindings.iter().map(move |(name, symbol_id)| (scope_id, *symbol_id, name.clone()))
@@ -0,0 +1,130 @@
+use std::{collections::BTreeMap, fmt};
+
+use oxc_ast::AstKind;
+use oxc_index::IndexVec;
+
+use crate::{node::AstNodeId, Semantic};
+
+/// Size and shape statistics of a program.
+///
+/// Statistics of multiple files can be aggregated with [`Stats::merge`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of files the statistics were collected from
+    pub files: usize,
+    /// Source size in bytes
+    pub source_bytes: usize,
+    /// Number of source lines
+    pub source_lines: usize,
+    /// Number of AST nodes
+    pub nodes: usize,
+    /// Number of AST nodes by kind, e.g. `"CallExpression"`
+    pub node_kinds: BTreeMap<String, usize>,
+    /// Number of functions, including arrow functions
+    pub functions: usize,
+    /// Number of classes
+    pub classes: usize,
+    /// Deepest nesting of AST nodes, the `Program` node has depth 1
+    pub max_depth: usize,
+    /// Deepest nesting of scopes, the root scope has depth 1
+    pub max_scope_depth: usize,
+    pub scopes: usize,
+    pub symbols: usize,
+    pub references: usize,
+}
+
+impl Stats {
+    pub fn new(semantic: &Semantic) -> Self {
+        let source_text = semantic.source_text();
+        let mut stats = Self {
+            files: 1,
+            source_bytes: source_text.len(),
+            source_lines: source_text.lines().count(),
+            scopes: semantic.scopes().len(),
+            symbols: semantic.symbols().len(),
+            references: semantic.symbols().references.len(),
+            ..Self::default()
+        };
+
+        let nodes = semantic.nodes();
+        // Parents are always added before their children, so their depth is known when visiting a node.
+        let mut depths: IndexVec<AstNodeId, usize> = IndexVec::new();
+        for node in nodes.iter() {
+            let depth = nodes.parent_id(node.id()).map_or(1, |parent_id| depths[parent_id] + 1);
+            depths.push(depth);
+            stats.max_depth = stats.max_depth.max(depth);
+
+            let kind = node.kind();
+            match kind {
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => stats.functions += 1,
+                AstKind::Class(_) => stats.classes += 1,
+                _ => {}
+            }
+            // Strip details such as identifier names from `IdentifierReference(foo)`
+            let name = kind.debug_name();
+            let name = name.split('(').next().unwrap_or_default();
+            if let Some(count) = stats.node_kinds.get_mut(name) {
+                *count += 1;
+            } else {
+                stats.node_kinds.insert(name.to_string(), 1);
+            }
+        }
+        stats.nodes = depths.len();
+
+        let scopes = semantic.scopes();
+        stats.max_scope_depth = scopes
+            .descendants_from_root()
+            .map(|scope_id| scopes.ancestors(scope_id).count())
+            .max()
+            .unwrap_or_default();
+
+        stats
+    }
+
+    /// Aggregate the statistics of another file into this one.
+    ///
+    /// Counts are summed and depths keep the maximum.
+    pub fn merge(&mut self, other: &Self) {
+        self.files += other.files;
+        self.source_bytes += other.source_bytes;
+        self.source_lines += other.source_lines;
+        self.nodes += other.nodes;
+        for (name, count) in &other.node_kinds {
+            *self.node_kinds.entry(name.clone()).or_default() += count;
+        }
+        self.functions += other.functions;
+        self.classes += other.classes;
+        self.max_depth = self.max_depth.max(other.max_depth);
+        self.max_scope_depth = self.max_scope_depth.max(other.max_scope_depth);
+        self.scopes += other.scopes;
+        self.symbols += other.symbols;
+        self.references += other.references;
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Files:           {}", self.files)?;
+        writeln!(f, "Source bytes:    {}", self.source_bytes)?;
+        writeln!(f, "Source lines:    {}", self.source_lines)?;
+        writeln!(f, "AST nodes:       {}", self.nodes)?;
+        writeln!(f, "Functions:       {}", self.functions)?;
+        writeln!(f, "Classes:         {}", self.classes)?;
+        writeln!(f, "Max depth:       {}", self.max_depth)?;
+        writeln!(f, "Max scope depth: {}", self.max_scope_depth)?;
+        writeln!(f, "Scopes:          {}", self.scopes)?;
+        writeln!(f, "Symbols:         {}", self.symbols)?;
+        writeln!(f, "References:      {}", self.references)?;
+
+        let mut node_kinds = self.node_kinds.iter().collect::<Vec<_>>();
+        // Most frequent first, ties in alphabetical order
+        node_kinds.sort_by(|(a_name, a_count), (b_name, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+        });
+        writeln!(f, "Nodes by kind:")?;
+        for (name, count) in node_kinds {
+            writeln!(f, "  {name:<32} {count}")?;
+        }
+        Ok(())
+    }
+}
@@ -19,13 +19,16 @@ use serde::Serialize;
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type IndexVec<I, T> = Array<T>;
-"#;
+";
 
 /// Symbol Table
 ///
 /// `SoA` (Struct of Arrays) for memory efficiency.
+///
+/// Symbols and references are numbered in the order they are declared or referenced
+/// during the AST traversal, so identical input always produces identical ids.
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 #[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
@@ -49,6 +52,7 @@ impl SymbolTable {
         self.len() == 0
     }
 
+    /// Iterate over all symbols in ascending [`SymbolId`] order.
     pub fn iter(&self) -> impl Iterator<Item = SymbolId> + '_ {
         self.spans.iter_enumerated().map(|(symbol_id, _)| symbol_id)
     }
@@ -127,6 +131,11 @@ impl SymbolTable {
         self.references.push(reference)
     }
 
+    /// Iterate over all references in ascending [`ReferenceId`] order.
+    pub fn iter_references(&self) -> impl Iterator<Item = (ReferenceId, &Reference)> + '_ {
+        self.references.iter_enumerated()
+    }
+
     pub fn get_reference(&self, reference_id: ReferenceId) -> &Reference {
         &self.references[reference_id]
     }
@@ -152,6 +161,22 @@ impl SymbolTable {
             .map(|reference_id| &self.references[*reference_id])
     }
 
+    /// Resolved references of `symbol_id` which are not in a type position,
+    /// i.e. which are not [`Reference::is_type`]
+    pub fn get_value_references(
+        &self,
+        symbol_id: SymbolId,
+    ) -> impl Iterator<Item = &Reference> + '_ {
+        self.get_resolved_references(symbol_id).filter(|reference| !reference.is_type())
+    }
+
+    /// Whether `symbol_id` is referenced, but only in type positions,
+    /// e.g. an import which can be a type-only import.
+    pub fn is_referenced_only_as_type(&self, symbol_id: SymbolId) -> bool {
+        !self.resolved_references[symbol_id].is_empty()
+            && self.get_value_references(symbol_id).next().is_none()
+    }
+
     /// Determine whether evaluating the specific input `node` is a consequenceless reference. ie.
     /// evaluating it won't result in potentially arbitrary code from being ran. The following are
     /// allowed and determined not to cause side effects:
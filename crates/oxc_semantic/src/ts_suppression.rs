@@ -0,0 +1,66 @@
+use oxc_ast::TsSuppression;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNodeId, Semantic};
+
+/// A `@ts-expect-error` or `@ts-ignore` comment and the line it suppresses the errors of
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsSuppressionTarget {
+    pub suppression: TsSuppression,
+    /// Span of the line after the comment, without its line break. Empty at the end of the
+    /// source text when the comment is on the last line.
+    pub line: Span,
+    /// The outermost nodes starting on `line`, empty when the line has no code, e.g. when it is
+    /// blank or another comment
+    pub nodes: Vec<AstNodeId>,
+}
+
+impl TsSuppressionTarget {
+    /// Associate each suppression comment with the nodes of the following line
+    pub fn collect(semantic: &Semantic) -> Vec<Self> {
+        let source_text = semantic.source_text();
+        let mut targets = semantic
+            .trivias()
+            .ts_suppressions()
+            .iter()
+            .map(|suppression| Self {
+                suppression: *suppression,
+                line: next_line(source_text, suppression.span.end),
+                nodes: vec![],
+            })
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return targets;
+        }
+
+        let nodes = semantic.nodes();
+        for node in nodes.iter() {
+            let start = node.kind().span().start;
+            let parent_start =
+                nodes.parent_node(node.id()).map(|parent| parent.kind().span().start);
+            for target in &mut targets {
+                let on_line = |start: u32| target.line.start <= start && start < target.line.end;
+                if on_line(start) && !parent_start.is_some_and(on_line) {
+                    target.nodes.push(node.id());
+                }
+            }
+        }
+        targets
+    }
+}
+
+/// The line after the line containing `offset`
+#[allow(clippy::cast_possible_truncation)]
+fn next_line(source_text: &str, offset: u32) -> Span {
+    let is_line_break = |c: char| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}');
+    let rest = &source_text[offset as usize..];
+    let Some((index, c)) = rest.char_indices().find(|(_, c)| is_line_break(*c)) else {
+        return Span::new(source_text.len() as u32, source_text.len() as u32);
+    };
+    let mut start = offset as usize + index + c.len_utf8();
+    if c == '\r' && source_text[start..].starts_with('\n') {
+        start += 1;
+    }
+    let end = source_text[start..].find(is_line_break).map_or(source_text.len(), |end| start + end);
+    Span::new(start as u32, end as u32)
+}
@@ -0,0 +1,106 @@
+mod util;
+
+use oxc_semantic::CallGraph;
+pub use util::SemanticTester;
+
+/// Name of the function of `node_id`, or `<top level>` for `None`
+fn name(graph: &CallGraph, node_id: Option<oxc_semantic::AstNodeId>) -> String {
+    node_id.map_or_else(
+        || "<top level>".to_string(),
+        |node_id| {
+            graph
+                .function(node_id)
+                .and_then(|function| function.name.as_ref())
+                .map_or_else(|| "<anonymous>".to_string(), ToString::to_string)
+        },
+    )
+}
+
+/// Calls as `caller -> callee` in source order
+fn calls(graph: &CallGraph) -> Vec<String> {
+    graph
+        .calls()
+        .iter()
+        .map(|call| format!("{} -> {}", name(graph, call.caller), name(graph, Some(call.callee))))
+        .collect()
+}
+
+fn unreachable_functions(graph: &CallGraph) -> Vec<String> {
+    graph
+        .unreachable_functions()
+        .iter()
+        .map(|function| name(graph, Some(function.node_id)))
+        .collect()
+}
+
+#[test]
+fn test_call_graph_calls() {
+    let tester = SemanticTester::js(
+        "
+function a() { b(); c(); }
+function b() { const d = () => c(); d(); }
+const c = function () { return new E(); };
+function E() {}
+a();
+[1].map(() => b());
+obj.method();
+",
+    );
+    let graph = tester.build().call_graph();
+    assert_eq!(
+        calls(&graph),
+        ["a -> b", "a -> c", "d -> c", "b -> d", "c -> E", "<top level> -> a", "<anonymous> -> b"]
+    );
+    assert_eq!(graph.functions().len(), 6);
+}
+
+#[test]
+fn test_call_graph_unresolved_calls() {
+    let tester = SemanticTester::js(
+        "
+let f = () => {};
+f = () => {};
+f();
+class A { m() { this.m(); } }
+",
+    );
+    let graph = tester.build().call_graph();
+    assert!(graph.calls().is_empty());
+    assert!(graph.functions().iter().all(|function| function.symbol_id.is_none()));
+}
+
+#[test]
+fn test_call_graph_unreachable_functions() {
+    let tester = SemanticTester::js(
+        "
+function used() { helper(); }
+function helper() {}
+function unused() { onlyFromUnused(); }
+function onlyFromUnused() {}
+function recursive() { recursive(); }
+function exported() {}
+function callback() {}
+export { exported };
+export default function () { used(); }
+setTimeout(callback);
+",
+    );
+    let graph = tester.build().call_graph();
+    assert_eq!(unreachable_functions(&graph), ["unused", "onlyFromUnused", "recursive"]);
+}
+
+#[test]
+fn test_call_graph_function_at() {
+    let source = "function a() { b(); }\n\nfunction b() {}\n";
+    let tester = SemanticTester::js(source);
+    let graph = tester.build().call_graph();
+    let offset = |text: &str| u32::try_from(source.find(text).unwrap()).unwrap();
+
+    let function = graph.function_at(offset("b()")).unwrap();
+    assert_eq!(function.name.as_deref(), Some("b"));
+    assert_eq!(function.span.start, offset("function b"));
+
+    let function = graph.function_at(offset("{ b")).unwrap();
+    assert_eq!(function.name.as_deref(), Some("a"));
+    assert!(graph.function_at(offset("\nfunction b")).is_none());
+}
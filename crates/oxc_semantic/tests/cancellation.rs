@@ -0,0 +1,29 @@
+use oxc_allocator::Allocator;
+use oxc_diagnostics::CancellationToken;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[test]
+fn test_cancellation() {
+    let allocator = Allocator::default();
+    let source_text = "let a = 1; function foo() { return a; }";
+    let source_type = SourceType::default();
+    let program =
+        allocator.alloc(Parser::new(&allocator, source_text, source_type).parse().program);
+
+    let token = CancellationToken::new();
+    let ret = SemanticBuilder::new(source_text, source_type)
+        .with_cancellation_token(&token)
+        .build(program);
+    assert!(ret.errors.is_empty());
+    assert_eq!(ret.semantic.symbols().len(), 2);
+
+    token.cancel();
+    let ret = SemanticBuilder::new(source_text, source_type)
+        .with_cancellation_token(&token)
+        .build(program);
+    assert_eq!(ret.errors.len(), 1);
+    assert_eq!(ret.errors[0].to_string(), "Analysis was cancelled");
+    assert_eq!(ret.semantic.symbols().len(), 0);
+}
@@ -0,0 +1,59 @@
+mod util;
+
+pub use util::SemanticTester;
+
+fn cjs_exports(source: &'static str) -> (Vec<String>, Vec<String>) {
+    let tester = SemanticTester::js(source);
+    let cjs_exports = tester.build().cjs_exports();
+    let names = |atoms: Vec<oxc_span::Atom>| atoms.iter().map(ToString::to_string).collect();
+    (names(cjs_exports.exports), names(cjs_exports.reexports))
+}
+
+#[test]
+fn test_cjs_exports_assignments() {
+    let (exports, reexports) = cjs_exports(
+        r#"
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.a = 1;
+module.exports.b = function () {};
+exports["c"] = 3;
+Object.defineProperty(exports, "a", { value: 4 });
+Object.defineProperty(exports, "d", { enumerable: true, get: function () { return _d.d; } });
+Object.defineProperty(exports, "e", { enumerable: true, get() { return compute(); } });
+function f(exports) { exports.g = 1; }
+"#,
+    );
+    assert_eq!(exports, ["__esModule", "a", "b", "c", "d"]);
+    assert!(reexports.is_empty());
+}
+
+#[test]
+fn test_cjs_exports_object_literal() {
+    let (exports, reexports) = cjs_exports(
+        r#"
+module.exports = { a, b: c, "d": e, ...require("./f"), g: h(), i };
+"#,
+    );
+    assert_eq!(exports, ["a", "b", "d"]);
+    assert_eq!(reexports, ["./f"]);
+}
+
+#[test]
+fn test_cjs_exports_reexports() {
+    let (exports, reexports) = cjs_exports(
+        r#"
+module.exports = require("./a");
+__exportStar(require("./b"), exports);
+tslib.__exportStar(require("./c"), exports);
+var _d = _interopRequireWildcard(require("./d"));
+Object.keys(_d).forEach(function (key) {
+  if (key === "default" || key === "__esModule") return;
+  exports[key] = _d[key];
+});
+var _e = require("./e");
+Object.keys(_e).forEach(function (key) { console.log(key); });
+"#,
+    );
+    assert!(exports.is_empty());
+    assert_eq!(reexports, ["./a", "./b", "./c", "./d"]);
+}
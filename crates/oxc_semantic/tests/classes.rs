@@ -41,3 +41,33 @@ fn test_class_with_ts() {
     .has_accessor("ap")
     .has_accessor("pap");
 }
+
+#[test]
+fn test_class_with_computed_keys() {
+    SemanticTester::js(
+        "
+      const a = 'a';
+      const b = 1;
+      const c = `c`;
+      let d = 'd';
+      const [e] = 'e';
+      const f = 'f' + '';
+      class Foo {
+        [a]() {}
+        [b]() {}
+        [c]() {}
+        ['g']() {}
+        [d]() {} // not a constant
+        [e]() {} // not a constant
+        [f]() {} // not a literal
+        [h]() {} // not declared
+      }
+    ",
+    )
+    .has_class("Foo")
+    .has_number_of_elements(4)
+    .has_computed_method("a")
+    .has_computed_method("1")
+    .has_computed_method("c")
+    .has_computed_method("g");
+}
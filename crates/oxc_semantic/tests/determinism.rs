@@ -0,0 +1,149 @@
+mod util;
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+use oxc_semantic::{ReferenceId, Semantic};
+use oxc_span::SourceType;
+use rayon::prelude::*;
+pub use util::SemanticTester;
+
+const SOURCE: &str = "
+import { a } from 'a';
+let b = a + c;
+function foo(x, y) {
+    label: for (const z of x) {
+        if (z) { zed: { undeclared1(z, b); } }
+    }
+    return class { m() { return y + undeclared2 + undeclared1; } };
+}
+{ let b = () => foo(b, undeclared3); }
+export default foo(undeclared2);
+";
+
+/// Serialize all ids assigned by the semantic builder in iteration order.
+fn fingerprint(semantic: &Semantic) -> String {
+    let mut out = String::new();
+    let scopes = semantic.scopes();
+    let symbols = semantic.symbols();
+    for scope_id in scopes.descendants_from_root() {
+        writeln!(out, "scope {scope_id:?} {:?}", scopes.get_parent_id(scope_id)).unwrap();
+    }
+    for (scope_id, symbol_id, name) in scopes.iter_bindings() {
+        writeln!(out, "binding {scope_id:?} {symbol_id:?} {name}").unwrap();
+    }
+    for symbol_id in symbols.iter() {
+        writeln!(
+            out,
+            "symbol {symbol_id:?} {} {:?} {:?}",
+            symbols.get_name(symbol_id),
+            symbols.get_span(symbol_id),
+            symbols.get_resolved_reference_ids(symbol_id)
+        )
+        .unwrap();
+    }
+    for (reference_id, reference) in symbols.iter_references() {
+        writeln!(
+            out,
+            "reference {reference_id:?} {} {:?} {:?}",
+            reference.name(),
+            reference.span(),
+            reference.symbol_id()
+        )
+        .unwrap();
+    }
+    for (name, reference_ids) in scopes.root_unresolved_references() {
+        writeln!(out, "unresolved {name} {reference_ids:?}").unwrap();
+    }
+    for node_id in semantic.unused_labels() {
+        writeln!(out, "unused label {node_id:?}").unwrap();
+    }
+    out
+}
+
+fn build_fingerprint() -> String {
+    let tester = SemanticTester::js(SOURCE);
+    let semantic = tester.build();
+    fingerprint(&semantic)
+}
+
+#[test]
+fn test_ids_are_deterministic_across_thread_pools() {
+    let expected = build_fingerprint();
+    for num_threads in [1, 2, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+        let results = pool
+            .install(|| (0..16).into_par_iter().map(|_| build_fingerprint()).collect::<Vec<_>>());
+        for result in results {
+            assert_eq!(result, expected, "{num_threads} threads");
+        }
+    }
+}
+
+#[test]
+fn test_ids_follow_insertion_order() {
+    let names = (0..32).map(|i| format!("undeclared{i}")).collect::<Vec<_>>();
+    for names in [names.clone(), names.into_iter().rev().collect()] {
+        // The references are moved from the function scope to the root scope when it is left
+        let source = format!(
+            "function f() {{ {} }}",
+            names.iter().map(|name| format!("{name}();")).join(" ")
+        );
+        let tester = SemanticTester::new(&source, SourceType::default().with_module(true));
+        let semantic = tester.build();
+
+        let unresolved = semantic.scopes().root_unresolved_references();
+        assert_eq!(unresolved.keys().map(oxc_span::Atom::as_str).collect::<Vec<_>>(), names);
+
+        let symbols = semantic.symbols();
+        for (index, (name, reference_ids)) in unresolved.iter().enumerate() {
+            assert_eq!(reference_ids, &[ReferenceId::new(index)]);
+            assert_eq!(symbols.get_reference(reference_ids[0]).name(), name);
+        }
+    }
+}
+
+#[test]
+fn test_iteration_order_follows_source_order() {
+    let tester = SemanticTester::js(SOURCE);
+    let semantic = tester.build();
+
+    let unresolved = semantic
+        .scopes()
+        .root_unresolved_references()
+        .keys()
+        .map(oxc_span::Atom::as_str)
+        .collect::<Vec<_>>();
+    assert_eq!(unresolved, ["c", "undeclared1", "undeclared2", "undeclared3"]);
+
+    // The arguments of a call are visited before its callee
+    let references = semantic
+        .symbols()
+        .iter_references()
+        .map(|(_, reference)| reference.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        references,
+        [
+            "a",
+            "c",
+            "x",
+            "z",
+            "z",
+            "b",
+            "undeclared1",
+            "y",
+            "undeclared2",
+            "undeclared1",
+            "b",
+            "undeclared3",
+            "foo",
+            "undeclared2",
+            "foo"
+        ]
+    );
+
+    let unused_labels = semantic.unused_labels().iter().collect::<Vec<_>>();
+    assert_eq!(unused_labels.len(), 2);
+    assert!(unused_labels[0] < unused_labels[1]);
+}
@@ -0,0 +1,64 @@
+mod util;
+
+use oxc_ast::AstKind;
+use oxc_semantic::NodeFlags;
+pub use util::SemanticTester;
+
+/// Flags of the functions and arrow functions in source order
+fn function_flags(source: &'static str) -> Vec<NodeFlags> {
+    let tester = SemanticTester::js(source);
+    let semantic = tester.build();
+    let mask = NodeFlags::HasYield
+        | NodeFlags::HasAwait
+        | NodeFlags::HasThis
+        | NodeFlags::HasArguments
+        | NodeFlags::HasSuper;
+    semantic
+        .nodes()
+        .iter()
+        .filter(|node| {
+            matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .map(|node| node.flags() & mask)
+        .collect()
+}
+
+#[test]
+fn test_await_and_yield() {
+    assert_eq!(function_flags("function f() { return 1 }"), [NodeFlags::empty()]);
+    assert_eq!(function_flags("async function f() { await x }"), [NodeFlags::HasAwait]);
+    assert_eq!(function_flags("async function f() { for await (x of y); }"), [NodeFlags::HasAwait]);
+    assert_eq!(function_flags("function* f() { yield }"), [NodeFlags::HasYield]);
+    // Only the innermost function is flagged
+    assert_eq!(
+        function_flags("async function f() { async () => await x }"),
+        [NodeFlags::empty(), NodeFlags::HasAwait]
+    );
+    assert_eq!(
+        function_flags("function* f() { function* g() { yield } }"),
+        [NodeFlags::empty(), NodeFlags::HasYield]
+    );
+}
+
+#[test]
+fn test_this_arguments_and_super() {
+    assert_eq!(function_flags("function f() { this.x }"), [NodeFlags::HasThis]);
+    assert_eq!(function_flags("function f() { arguments[0] }"), [NodeFlags::HasArguments]);
+    assert_eq!(function_flags("({ m() { super.m() } })"), [NodeFlags::HasSuper]);
+    assert_eq!(function_flags("() => this"), [NodeFlags::HasThis]);
+    // Arrow functions inherit them from the enclosing function
+    assert_eq!(
+        function_flags("function f() { () => () => this }"),
+        [NodeFlags::HasThis, NodeFlags::HasThis, NodeFlags::HasThis]
+    );
+    assert_eq!(
+        function_flags("function f() { function g() { () => arguments } }"),
+        [NodeFlags::empty(), NodeFlags::HasArguments, NodeFlags::HasArguments]
+    );
+    // Class static blocks and property values have their own `this`
+    assert_eq!(
+        function_flags("function f() { class A { static { this } x = () => this } }"),
+        [NodeFlags::empty(), NodeFlags::HasThis]
+    );
+    assert_eq!(function_flags("function f() { class A { [this.x] = 1 } }"), [NodeFlags::HasThis]);
+}
@@ -0,0 +1,47 @@
+mod util;
+
+use oxc_semantic::{GraphqlInterpolation, GraphqlOptions};
+pub use util::SemanticTester;
+
+#[test]
+fn test_graphql_templates() {
+    let source = "import gql from 'graphql-tag';
+const fragment = gql`fragment User on User { id }`;
+const query = gql`
+  query { viewer { ...User } }
+  ${fragment}
+`;
+const other = css`color: red;`;
+";
+    let tester = SemanticTester::js(source).with_module(true);
+    let semantic = tester.build();
+    let templates = semantic.graphql_templates(&GraphqlOptions::default());
+    assert_eq!(templates.len(), 2);
+
+    assert_eq!(templates[0].tag, "gql");
+    assert_eq!(templates[0].content, "fragment User on User { id }");
+    assert_eq!(templates[0].quasis[0].source_text(source), "fragment User on User { id }");
+    assert!(templates[0].interpolations.is_empty());
+
+    let query = &templates[1];
+    assert_eq!(query.content, "\n  query { viewer { ...User } }\n  \n");
+    assert_eq!(query.quasis.len(), 2);
+    let GraphqlInterpolation { span, offset } = query.interpolations[0];
+    assert_eq!(span.source_text(source), "fragment");
+    assert_eq!(offset, "\n  query { viewer { ...User } }\n  ".len());
+    assert_eq!(query.span.source_text(source).lines().next(), Some("gql`"));
+}
+
+#[test]
+fn test_graphql_templates_tags() {
+    let source = "graphql.experimental`query { a }`;
+graphql`query { b }`;
+function f(gql) { return gql`query { c }`; }
+";
+    let options =
+        GraphqlOptions { tags: vec!["gql".to_string(), "graphql.experimental".to_string()] };
+    let templates = SemanticTester::js(source).build().graphql_templates(&options);
+    let contents = templates.iter().map(|template| template.content.as_str()).collect::<Vec<_>>();
+    // `graphql` is not configured and `gql` is a parameter
+    assert_eq!(contents, ["query { a }"]);
+}
@@ -0,0 +1,82 @@
+mod util;
+
+use oxc_semantic::I18nOptions;
+pub use util::SemanticTester;
+
+/// Messages formatted as `kind key default_message description`
+fn extract(source: &'static str) -> Vec<String> {
+    SemanticTester::js(source)
+        .with_jsx(true)
+        .build()
+        .i18n_messages(&I18nOptions::default())
+        .into_iter()
+        .map(|message| {
+            format!(
+                "{:?} {} {:?} {:?}",
+                message.kind, message.key, message.default_message, message.description
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_i18n_functions() {
+    let messages = extract(
+        "t('greeting');
+i18n.t('farewell', 'Bye');
+t(`count`, { defaultValue: 'Count', count: 1 });
+t(key);
+other('ignored');",
+    );
+    assert_eq!(
+        messages,
+        [
+            "Function greeting None None",
+            r#"Function farewell Some("Bye") None"#,
+            r#"Function count Some("Count") None"#,
+        ]
+    );
+}
+
+#[test]
+fn test_i18n_descriptors() {
+    let messages = extract(
+        "const messages = defineMessages({
+  title: { id: 'app.title', defaultMessage: 'Title', description: 'Page title' },
+  dynamic: { id: prefix + 'x' },
+});
+intl.formatMessage({ id: 'app.save', defaultMessage: 'Save' });",
+    );
+    assert_eq!(
+        messages,
+        [
+            r#"Descriptor app.title Some("Title") Some("Page title")"#,
+            r#"Descriptor app.save Some("Save") None"#,
+        ]
+    );
+}
+
+#[test]
+fn test_i18n_components() {
+    let source = "<div>
+  <Trans i18nKey=\"welcome\">
+    Welcome to {'our'} app
+  </Trans>
+  <FormattedMessage id={'bye'} defaultMessage=\"Bye\" />
+  <Trans id=\"user\">Hello <b>{name}</b></Trans>
+</div>";
+    assert_eq!(
+        extract(source),
+        [
+            r#"Component welcome Some("Welcome to our app") None"#,
+            r#"Component bye Some("Bye") None"#,
+            "Component user None None",
+        ]
+    );
+
+    let tester = SemanticTester::js(source).with_jsx(true);
+    let semantic = tester.build();
+    let message = &semantic.i18n_messages(&I18nOptions::default())[0];
+    assert_eq!(message.key_span.source_text(source), "\"welcome\"");
+    assert!(message.span.source_text(source).starts_with("<Trans"));
+}
@@ -90,3 +90,34 @@ fn test_function_level_strict() {
         .test();
     tester.has_some_symbol("foo").is_not_in_scope(ScopeFlags::StrictMode).test();
 }
+
+#[test]
+fn test_with_statement() {
+    let tester = SemanticTester::js("let x = 1; with (o) { x; y; function f() { z } } x;")
+        .with_module(false);
+
+    // The reference inside `with` is bound to `x` too, as it refers to `x` if `o` has no `x`
+    tester.has_root_symbol("x").has_number_of_references(2).test();
+
+    let semantic = tester.build();
+    let scopes = semantic.scopes();
+    let with_scope_id =
+        scopes.descendants_from_root().find(|scope_id| scopes.get_flags(*scope_id).is_with());
+    assert!(with_scope_id.is_some());
+
+    let with_references =
+        scopes.iter_with_references().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    assert_eq!(with_references, ["x", "y", "z"]);
+
+    let maybe_with = semantic
+        .symbols()
+        .iter_references()
+        .filter(|(_, reference)| reference.is_maybe_with())
+        .map(|(_, reference)| reference.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(maybe_with, ["x", "y", "z"]);
+
+    let unresolved =
+        scopes.root_unresolved_references().keys().map(oxc_span::Atom::as_str).collect::<Vec<_>>();
+    assert_eq!(unresolved, ["o", "y", "z"]);
+}
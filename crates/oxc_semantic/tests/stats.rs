@@ -0,0 +1,48 @@
+mod util;
+
+use oxc_semantic::Stats;
+pub use util::SemanticTester;
+
+#[test]
+fn test_stats() {
+    let tester = SemanticTester::js(
+        "function foo(a) { return a + 1; }\nclass A {}\nconst f = () => foo(1);\n",
+    );
+    let stats = tester.build().stats();
+    assert_eq!(stats.files, 1);
+    assert_eq!(stats.source_lines, 3);
+    assert_eq!(stats.source_bytes, 69);
+    assert_eq!(stats.functions, 2);
+    assert_eq!(stats.classes, 1);
+    assert_eq!(stats.symbols, 4);
+    assert_eq!(stats.references, 2);
+    assert_eq!(stats.node_kinds.get("CallExpression"), Some(&1));
+    assert_eq!(stats.node_kinds.get("Program"), Some(&1));
+    assert_eq!(stats.node_kinds.values().sum::<usize>(), stats.nodes);
+}
+
+#[test]
+fn test_stats_nesting() {
+    let flat = SemanticTester::js("function a() {}").build().stats();
+    let nested =
+        SemanticTester::js("function a() { function b() { function c() {} } }").build().stats();
+    assert_eq!(flat.max_scope_depth, 2);
+    assert_eq!(nested.max_scope_depth, 4);
+    assert!(nested.max_depth > flat.max_depth);
+}
+
+#[test]
+fn test_stats_merge() {
+    let a = SemanticTester::js("function a() {}").build().stats();
+    let b = SemanticTester::js("class B { m() { return function () {} } }").build().stats();
+
+    let mut total = Stats::default();
+    total.merge(&a);
+    total.merge(&b);
+    assert_eq!(total.files, 2);
+    assert_eq!(total.functions, 3);
+    assert_eq!(total.classes, 1);
+    assert_eq!(total.nodes, a.nodes + b.nodes);
+    assert_eq!(total.max_depth, a.max_depth.max(b.max_depth));
+    assert_eq!(total.node_kinds.get("Function"), Some(&3));
+}
@@ -1,6 +1,6 @@
 mod util;
 
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{ScopeFlags, SymbolFlags};
 pub use util::SemanticTester;
 
 #[test]
@@ -103,3 +103,52 @@ fn test_export_flag() {
     tester.has_root_symbol("b").contains_flags(SymbolFlags::Export).test();
     tester.has_root_symbol("c").contains_flags(SymbolFlags::Export).test();
 }
+
+#[test]
+fn test_annex_b_block_function() {
+    // Sloppy mode function declarations in blocks are also bound in the var scope
+    SemanticTester::js("{ function f() {} } f();")
+        .with_module(false)
+        .has_root_symbol("f")
+        .contains_flags(SymbolFlags::FunctionScopedVariable)
+        .has_number_of_references(1)
+        .test();
+
+    // Not hoisted when it conflicts with a lexical declaration
+    SemanticTester::js("let f = 1; { function f() {} } f;")
+        .with_module(false)
+        .has_root_symbol("f")
+        .contains_flags(SymbolFlags::BlockScopedVariable)
+        .has_number_of_references(1)
+        .test();
+
+    SemanticTester::js("{ function f() {} } let f = 1;")
+        .with_module(false)
+        .has_root_symbol("f")
+        .contains_flags(SymbolFlags::BlockScopedVariable)
+        .test();
+
+    // Not hoisted in strict mode
+    let tester = SemanticTester::js("{ function f() {} } f();");
+    let semantic = tester.build();
+    assert!(semantic.scopes().get_root_binding(&"f".into()).is_none());
+    assert!(semantic.scopes().root_unresolved_references().contains_key("f"));
+}
+
+#[test]
+fn test_annex_b_catch_parameter() {
+    SemanticTester::js("try {} catch (e) {}")
+        .has_some_symbol("e")
+        .contains_flags(SymbolFlags::CatchVariable)
+        .is_in_scope(ScopeFlags::CatchClause)
+        .test();
+
+    // `var e` assigns to the catch parameter, but also declares `e` in the var scope
+    SemanticTester::js("try {} catch (e) { var e = 1; } e;")
+        .with_module(false)
+        .has_root_symbol("e")
+        .contains_flags(SymbolFlags::FunctionScopedVariable)
+        .is_in_scope(ScopeFlags::Top)
+        .has_number_of_references(1)
+        .test();
+}
@@ -0,0 +1,71 @@
+mod util;
+
+use oxc_ast::TsSuppressionKind;
+use oxc_span::GetSpan;
+pub use util::SemanticTester;
+
+#[test]
+fn test_ts_suppressions_lines() {
+    let source = "
+// @ts-expect-error
+const a: string = 1;
+const b = {
+    /* @ts-ignore */
+    c: d(), e,
+};
+
+// @ts-ignore
+
+f();
+// @ts-ignore";
+    let tester = SemanticTester::ts(source);
+    let semantic = tester.build();
+    let targets = semantic.ts_suppressions();
+
+    let lines = targets
+        .iter()
+        .map(|target| {
+            (target.suppression.kind, &source[target.line.start as usize..target.line.end as usize])
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            (TsSuppressionKind::ExpectError, "const a: string = 1;"),
+            (TsSuppressionKind::Ignore, "    c: d(), e,"),
+            (TsSuppressionKind::Ignore, ""),
+            (TsSuppressionKind::Ignore, ""),
+        ]
+    );
+
+    let nodes = targets
+        .iter()
+        .map(|target| {
+            target
+                .nodes
+                .iter()
+                .map(|node_id| {
+                    let span = semantic.nodes().kind(*node_id).span();
+                    &source[span.start as usize..span.end as usize]
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(nodes[0], ["const a: string = 1;"]);
+    assert_eq!(nodes[1], ["c: d()", "e"]);
+    assert!(nodes[2].is_empty());
+    assert!(nodes[3].is_empty());
+}
+
+#[test]
+fn test_ts_suppressions_comment_line() {
+    let source = "// @ts-ignore\n// @ts-expect-error: next line\r\nfoo();\n";
+    let tester = SemanticTester::ts(source);
+    let targets = tester.build().ts_suppressions();
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0].suppression.description(source), "");
+    assert_eq!(targets[1].suppression.description(source), "next line");
+    assert!(targets[0].nodes.is_empty());
+    assert_eq!(targets[1].nodes.len(), 1);
+    assert_eq!(&source[targets[1].line.start as usize..targets[1].line.end as usize], "foo();");
+}
@@ -49,6 +49,14 @@ impl<'a> ClassTester<'a> {
         self
     }
 
+    pub fn has_computed_method(&self, name: &str) -> &Self {
+        let method = self.semantic.classes().elements[self.class_id]
+            .iter()
+            .find(|m| m.kind.is_method() && m.computed && m.name == name);
+        debug_assert!(method.is_some(), "Expected computed method `{name}` not found");
+        self
+    }
+
     pub fn has_accessor(&self, name: &str) -> &Self {
         let method = self.semantic.classes().elements[self.class_id]
             .iter()
@@ -40,7 +40,7 @@ impl<'a> SymbolTester<'a> {
         target: &str,
     ) -> Self {
         let symbols_with_target_name: Vec<_> =
-            semantic.scopes().iter_bindings().filter(|(_, _, name)| name == &target).collect();
+            semantic.scopes().iter_bindings().filter(|(_, _, name)| *name == target).collect();
         let data = match symbols_with_target_name.len() {
             0 => Err(miette!("Could not find declaration for {target}")),
             1 => Ok(symbols_with_target_name.iter().map(|(_, symbol_id, _)| *symbol_id).next().unwrap()),
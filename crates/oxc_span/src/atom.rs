@@ -2,42 +2,67 @@ use std::{
     borrow::{Borrow, Cow},
     fmt, hash,
     ops::Deref,
+    ptr,
 };
 
 #[cfg(feature = "serde")]
 use serde::{Serialize, Serializer};
 
-use inlinable_string::inline_string::{InlineString, INLINE_STRING_CAPACITY};
-
 const BASE54_CHARS: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
 
+/// Strings of up to this length are stored inline, so `Atom` stays 32 bytes.
+const INLINE_CAPACITY: usize = 30;
+
 #[cfg_attr(
     all(feature = "serde", feature = "wasm"),
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type Atom = string;
-"#;
+";
 
 /// An inlinable string for oxc_allocator.
 ///
+/// Strings of up to 30 bytes, which are most identifiers, are always stored inline,
+/// comparing two of them is a fixed size comparison without branching on their content.
+///
 /// SAFETY: It is unsafe to use this string after the allocator is dropped.
 ///
-#[derive(Clone, Eq, Hash)]
+#[derive(Clone)]
 pub struct Atom(AtomImpl);
 
-/// Immutable Inlinable String
-///
-/// https://github.com/fitzgen/inlinable_string/blob/master/src/lib.rs
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone)]
 enum AtomImpl {
-    /// A arena heap-allocated string.
+    /// A arena heap-allocated string, longer than `INLINE_CAPACITY`.
     Arena(&'static str),
-    /// A heap-allocated string.
+    /// A heap-allocated string, longer than `INLINE_CAPACITY`.
     Heap(Box<str>),
     /// A small string stored inline.
-    Inline(InlineString),
+    Inline(InlineStr),
+}
+
+/// The bytes after `len` are always zero, so equal strings have equal representations.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct InlineStr {
+    len: u8,
+    bytes: [u8; INLINE_CAPACITY],
+}
+
+impl InlineStr {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(s: &str) -> Self {
+        debug_assert!(s.len() <= INLINE_CAPACITY);
+        let mut bytes = [0; INLINE_CAPACITY];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Self { len: s.len() as u8, bytes }
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        // SAFETY: `bytes[..len]` is copied from a `str` in `InlineStr::new`.
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.len as usize]) }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -51,8 +76,13 @@ impl Serialize for Atom {
 }
 
 impl Atom {
+    /// Create an atom without borrowing `s`, e.g. from a temporary string.
     pub fn new_inline(s: &str) -> Self {
-        Self(AtomImpl::Inline(InlineString::from(s)))
+        if s.len() <= INLINE_CAPACITY {
+            Self(AtomImpl::Inline(InlineStr::new(s)))
+        } else {
+            Self(AtomImpl::Heap(s.into()))
+        }
     }
 
     #[inline]
@@ -60,7 +90,7 @@ impl Atom {
         match &self.0 {
             AtomImpl::Arena(s) => s,
             AtomImpl::Heap(s) => s,
-            AtomImpl::Inline(s) => s.as_ref(),
+            AtomImpl::Inline(s) => s.as_str(),
         }
     }
 
@@ -68,8 +98,8 @@ impl Atom {
     pub fn into_string(self) -> String {
         match self.0 {
             AtomImpl::Arena(s) => String::from(s),
-            AtomImpl::Heap(s) => s.to_string(),
-            AtomImpl::Inline(s) => s.to_string(),
+            AtomImpl::Heap(s) => s.into_string(),
+            AtomImpl::Inline(s) => s.as_str().to_string(),
         }
     }
 
@@ -91,14 +121,14 @@ impl Atom {
             ret.push(BASE54_CHARS[num % base] as char);
             num /= base;
         }
-        Self(AtomImpl::Heap(ret.into_boxed_str()))
+        Self::from(ret)
     }
 }
 
 impl<'a> From<&'a str> for Atom {
     fn from(s: &'a str) -> Self {
-        if s.len() <= INLINE_STRING_CAPACITY {
-            Self(AtomImpl::Inline(InlineString::from(s)))
+        if s.len() <= INLINE_CAPACITY {
+            Self(AtomImpl::Inline(InlineStr::new(s)))
         } else {
             // SAFETY: It is unsafe to use this string after the allocator is dropped.
             Self(AtomImpl::Arena(unsafe { std::mem::transmute(s) }))
@@ -108,8 +138,8 @@ impl<'a> From<&'a str> for Atom {
 
 impl From<String> for Atom {
     fn from(s: String) -> Self {
-        if s.len() <= INLINE_STRING_CAPACITY {
-            Self(AtomImpl::Inline(InlineString::from(s.as_str())))
+        if s.len() <= INLINE_CAPACITY {
+            Self(AtomImpl::Inline(InlineStr::new(&s)))
         } else {
             Self(AtomImpl::Heap(s.into_boxed_str()))
         }
@@ -118,8 +148,8 @@ impl From<String> for Atom {
 
 impl From<Cow<'_, str>> for Atom {
     fn from(s: Cow<'_, str>) -> Self {
-        if s.len() <= INLINE_STRING_CAPACITY {
-            Self(AtomImpl::Inline(InlineString::from(s.borrow())))
+        if s.len() <= INLINE_CAPACITY {
+            Self(AtomImpl::Inline(InlineStr::new(&s)))
         } else {
             Self(AtomImpl::Heap(s.into()))
         }
@@ -148,9 +178,43 @@ impl Borrow<str> for Atom {
     }
 }
 
-impl<T: AsRef<str>> PartialEq<T> for Atom {
-    fn eq(&self, other: &T) -> bool {
-        self.as_str() == other.as_ref()
+impl PartialEq for Atom {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (AtomImpl::Inline(a), AtomImpl::Inline(b)) => a == b,
+            // Only strings longer than `INLINE_CAPACITY` are not inline
+            (AtomImpl::Inline(_), _) | (_, AtomImpl::Inline(_)) => false,
+            _ => {
+                let (a, b) = (self.as_str(), other.as_str());
+                ptr::eq(a, b) || a == b
+            }
+        }
+    }
+}
+
+impl Eq for Atom {}
+
+/// Comparisons with other strings
+macro_rules! impl_partial_eq {
+    ($($ty:ty),*) => {
+        $(
+            impl PartialEq<$ty> for Atom {
+                #[inline]
+                fn eq(&self, other: &$ty) -> bool {
+                    self.as_str() == AsRef::<str>::as_ref(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq!(str, &str, &&str, String, &String, Cow<'_, str>, &Cow<'_, str>, Box<str>);
+
+impl PartialEq<&Atom> for Atom {
+    #[inline]
+    fn eq(&self, other: &&Atom) -> bool {
+        self == *other
     }
 }
 
@@ -160,14 +224,11 @@ impl PartialEq<Atom> for &str {
     }
 }
 
-impl hash::Hash for AtomImpl {
+/// Hashes like `str`, as required by `Borrow<str>`
+impl hash::Hash for Atom {
     #[inline]
     fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
-        match self {
-            Self::Arena(s) => s.hash(hasher),
-            Self::Heap(s) => s.hash(hasher),
-            Self::Inline(s) => s.hash(hasher),
-        }
+        self.as_str().hash(hasher);
     }
 }
 
@@ -3,11 +3,15 @@
 //! <https://doc.rust-lang.org/beta/nightly-rustc/rustc_span>
 
 mod atom;
+mod line_index;
+mod source_code;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
+    line_index::LineIndex,
+    source_code::{SourceCode, SourceLines},
     source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
     span::{GetSpan, Span, SPAN},
 };
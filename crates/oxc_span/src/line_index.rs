@@ -0,0 +1,151 @@
+/// Line starts and non-ASCII characters of a source text, to convert the UTF-8 byte offsets of
+/// [`crate::Span`] to the UTF-16 offsets and line / column positions used by LSP and ESTree
+/// in O(log n).
+///
+/// Lines are separated by the ECMAScript line terminators: `\n`, `\r\n`, `\r`, `\u{2028}` and
+/// `\u{2029}`. Lines and columns start at 0, columns are in UTF-16 code units.
+///
+/// Built while lexing with `Parser::line_index`, or from any text with [`LineIndex::new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Byte offsets of the starts of the lines
+    line_starts: Vec<u32>,
+    /// Byte offsets after each non-ASCII character, with the number of UTF-8 bytes
+    /// more than UTF-16 code units of the text up to there
+    wide_chars: Vec<(u32, u32)>,
+    /// Length of the text indexed so far
+    indexed_len: u32,
+}
+
+impl Default for LineIndex {
+    fn default() -> Self {
+        Self { line_starts: vec![0], wide_chars: vec![], indexed_len: 0 }
+    }
+}
+
+impl LineIndex {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_text: &str) -> Self {
+        let mut index = Self::default();
+        index.index_to(source_text, source_text.len() as u32);
+        index
+    }
+
+    /// Index `source_text` up to the byte offset `end`, continuing where the last call stopped.
+    /// Does nothing if `end` has been indexed already.
+    ///
+    /// # Panics
+    /// Panics if `end` is not on a char boundary of `source_text`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn index_to(&mut self, source_text: &str, end: u32) {
+        if end <= self.indexed_len {
+            return;
+        }
+        let start = self.indexed_len as usize;
+        let bytes = source_text.as_bytes();
+        let mut extra = self.wide_chars.last().map_or(0, |&(_, extra)| extra);
+        for (i, c) in source_text[start..end as usize].char_indices() {
+            let offset = start + i;
+            let line_break = match c {
+                '\n' | '\u{2028}' | '\u{2029}' => true,
+                // The line starts after the `\n` of `\r\n`
+                '\r' => bytes.get(offset + 1) != Some(&b'\n'),
+                _ => false,
+            };
+            let next = (offset + c.len_utf8()) as u32;
+            if line_break {
+                self.line_starts.push(next);
+            }
+            if !c.is_ascii() {
+                extra += (c.len_utf8() - c.len_utf16()) as u32;
+                self.wide_chars.push((next, extra));
+            }
+        }
+        self.indexed_len = end;
+    }
+
+    /// Number of lines of the indexed text
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Byte offset of the start of `line`, `None` if the line does not exist
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        self.line_starts.get(line as usize).copied()
+    }
+
+    /// UTF-16 offset of the byte offset `offset`
+    pub fn utf16_offset(&self, offset: u32) -> u32 {
+        let i = self.wide_chars.partition_point(|&(end, _)| end <= offset);
+        offset - i.checked_sub(1).map_or(0, |i| self.wide_chars[i].1)
+    }
+
+    /// `(line, column)` of the byte offset `offset`, the column in UTF-16 code units
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_column(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let column = self.utf16_offset(offset) - self.utf16_offset(line_start);
+        (line as u32, column)
+    }
+
+    /// Byte offset of `line` and the UTF-16 `column` in it,
+    /// `None` if the line does not exist or is shorter than `column`.
+    pub fn offset(&self, line: u32, column: u32) -> Option<u32> {
+        let line_start = *self.line_starts.get(line as usize)?;
+        let line_end = self.line_starts.get(line as usize + 1).copied().unwrap_or(self.indexed_len);
+        let target = self.utf16_offset(line_start) + column;
+        let i = self.wide_chars.partition_point(|&(end, extra)| end - extra <= target);
+        let offset = target + i.checked_sub(1).map_or(0, |i| self.wide_chars[i].1);
+        (offset <= line_end).then_some(offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LineIndex;
+
+    #[test]
+    fn ascii() {
+        let index = LineIndex::new("a\nbc\r\nd\re");
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_column(0), (0, 0));
+        assert_eq!(index.line_column(3), (1, 1));
+        assert_eq!(index.line_column(6), (2, 0));
+        assert_eq!(index.line_column(8), (3, 0));
+        assert_eq!(index.utf16_offset(8), 8);
+        assert_eq!(index.offset(1, 1), Some(3));
+        assert_eq!(index.offset(3, 1), Some(9));
+        assert_eq!(index.offset(3, 2), None);
+        assert_eq!(index.offset(4, 0), None);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn multi_byte_chars() {
+        // `é` is 2 bytes, `中` 3 bytes and `😀` 4 bytes, the last 2 UTF-16 code units
+        let text = "é中😀x\u{2028}😀y";
+        let index = LineIndex::new(text);
+        assert_eq!(index.utf16_offset(2), 1);
+        assert_eq!(index.utf16_offset(5), 2);
+        assert_eq!(index.utf16_offset(9), 4);
+        assert_eq!(index.line_column(9), (0, 4));
+        assert_eq!(index.line_column(13), (1, 0));
+        assert_eq!(index.line_column(17), (1, 2));
+        assert_eq!(index.offset(0, 4), Some(9));
+        assert_eq!(index.offset(1, 2), Some(17));
+        assert_eq!(index.utf16_offset(18), text.encode_utf16().count() as u32);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn incremental() {
+        let text = "a\r\nb é\n中";
+        let mut index = LineIndex::default();
+        // Stopping between `\r` and `\n` does not start a line after `\r`
+        for end in [2, 1, 3, 7, 8, text.len() as u32] {
+            index.index_to(text, end);
+        }
+        assert_eq!(index, LineIndex::new(text));
+    }
+}
@@ -0,0 +1,120 @@
+use std::cell::OnceCell;
+
+use crate::{GetSpan, LineIndex, Span};
+
+/// A source text with the [`LineIndex`] of it built on first use, to get the text of spans and
+/// nodes and the lines around them without offset math at every consumer.
+///
+/// # Panics
+/// Methods taking a span panic if it is out of bounds of the text or not on char boundaries,
+/// as slicing the text would.
+#[derive(Debug)]
+pub struct SourceCode<'a> {
+    text: &'a str,
+    line_index: OnceCell<LineIndex>,
+}
+
+/// Whole lines of a [`SourceCode`], see [`SourceCode::lines_around`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLines<'a> {
+    /// Line of the first line, starting at 0
+    pub line: u32,
+    /// Span of the lines, without the line terminator of the last line
+    pub span: Span,
+    /// Text of the lines, without the line terminator of the last line
+    pub text: &'a str,
+}
+
+impl<'a> SourceCode<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, line_index: OnceCell::new() }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line_index(&self) -> &LineIndex {
+        self.line_index.get_or_init(|| LineIndex::new(self.text))
+    }
+
+    /// Text of `span`
+    pub fn snippet(&self, span: Span) -> &'a str {
+        span.source_text(self.text)
+    }
+
+    /// Text of the span of `node`
+    pub fn text_of<T: GetSpan>(&self, node: &T) -> &'a str {
+        self.snippet(node.span())
+    }
+
+    /// `(line, column)` of the byte offset `offset`, see [`LineIndex::line_column`]
+    pub fn line_column(&self, offset: u32) -> (u32, u32) {
+        self.line_index().line_column(offset)
+    }
+
+    /// The lines of `span` with up to `n` lines before and after them.
+    /// A line terminator at the end of `span` does not make the next line part of it.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn lines_around(&self, span: Span, n: u32) -> SourceLines<'a> {
+        let index = self.line_index();
+        let last_offset = if span.end > span.start { span.end - 1 } else { span.start };
+        let (first_line, _) = index.line_column(span.start);
+        let (last_line, _) = index.line_column(last_offset);
+        let first_line = first_line.saturating_sub(n);
+        let last_line = last_line.saturating_add(n).min(index.line_count() as u32 - 1);
+
+        let start = index.line_start(first_line).unwrap_or_default();
+        let end = index.line_start(last_line + 1).map_or(self.text.len() as u32, |next_start| {
+            let line = &self.text[..next_start as usize];
+            let terminator = if line.ends_with("\r\n") {
+                2
+            } else {
+                line.chars().next_back().map_or(0, char::len_utf8)
+            };
+            next_start - terminator as u32
+        });
+        let span = Span::new(start, end);
+        SourceLines { line: first_line, span, text: self.snippet(span) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SourceCode, SourceLines};
+    use crate::{GetSpan, Span};
+
+    #[test]
+    fn snippets() {
+        struct Node(Span);
+        impl GetSpan for Node {
+            fn span(&self) -> Span {
+                self.0
+            }
+        }
+
+        let source = SourceCode::new("let a = é;\nlet b;");
+        assert_eq!(source.snippet(Span::new(4, 5)), "a");
+        assert_eq!(source.text_of(&Node(Span::new(8, 10))), "é");
+        assert_eq!(source.line_column(16), (1, 4));
+    }
+
+    #[test]
+    fn lines_around() {
+        let source = SourceCode::new("a\nbc\r\nd\u{2028}e");
+        let lines = |start, end, n| {
+            let SourceLines { line, span, text } = source.lines_around(Span::new(start, end), n);
+            assert_eq!(span.source_text(source.text()), text);
+            (line, text)
+        };
+        assert_eq!(lines(2, 4, 0), (1, "bc"));
+        assert_eq!(lines(2, 4, 1), (0, "a\nbc\r\nd"));
+        assert_eq!(lines(2, 4, 5), (0, "a\nbc\r\nd\u{2028}e"));
+        // The line terminator at the end of the span is not the start of the next line
+        assert_eq!(lines(0, 2, 0), (0, "a"));
+        assert_eq!(lines(3, 6, 0), (1, "bc"));
+        assert_eq!(lines(1, 3, 0), (0, "a\nbc"));
+        assert_eq!(lines(11, 11, 1), (2, "d\u{2028}e"));
+        assert_eq!(lines(0, 0, 0), (0, "a"));
+    }
+}
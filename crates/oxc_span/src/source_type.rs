@@ -191,4 +191,184 @@ impl SourceType {
 
         Ok(Self { language, module_kind: ModuleKind::Module, variant, always_strict: false })
     }
+
+    /// Detects the source type of a file from its path and its content.
+    ///
+    /// Language and JSX come from the file extension as in [`SourceType::from_path`].
+    /// `.mjs` and `.mts` files are modules and `.cjs` and `.cts` files are scripts,
+    /// declaration files are modules.
+    /// Other files are modules if they contain `import` or `export` declarations or `import.meta`,
+    /// or if `is_module_package` returns `true`, e.g. the nearest `package.json` has
+    /// `"type": "module"`, and scripts otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownExtension`] as [`SourceType::from_path`] does.
+    pub fn detect<P, F>(
+        source_text: &str,
+        path: P,
+        is_module_package: F,
+    ) -> Result<Self, UnknownExtension>
+    where
+        P: AsRef<Path>,
+        F: FnOnce() -> bool,
+    {
+        let path = path.as_ref();
+        let source_type = Self::from_path(path)?;
+        let is_module = match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some("mjs" | "mts") => true,
+            Some("cjs" | "cts") => false,
+            _ => {
+                source_type.is_typescript_definition()
+                    || has_module_syntax(source_text)
+                    || is_module_package()
+            }
+        };
+        Ok(source_type.with_module(is_module))
+    }
+}
+
+/// Whether `source_text` contains `import` or `export` declarations or `import.meta`.
+///
+/// The keywords are searched outside of comments and string and template literals.
+/// Regular expression literals and JSX text are not skipped.
+fn has_module_syntax(source_text: &str) -> bool {
+    let bytes = source_text.as_bytes();
+    let mut i = 0;
+    // Last byte which is not whitespace or part of a comment
+    let mut previous = b';';
+    while i < bytes.len() {
+        let byte = bytes[i];
+        match byte {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = position_or_end(bytes, i, b'\n');
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = source_text[i + 2..].find("*/").map_or(bytes.len(), |end| i + 2 + end + 2);
+                continue;
+            }
+            b'\'' | b'"' | b'`' => {
+                i = skip_string(bytes, i);
+                previous = byte;
+                continue;
+            }
+            _ if byte.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            _ if is_identifier_byte(byte) => {
+                let start = i;
+                while i < bytes.len() && is_identifier_byte(bytes[i]) {
+                    i += 1;
+                }
+                let word = &source_text[start..i];
+                // Reserved words, except for member names such as `a.import`
+                if previous != b'.' && matches!(word, "import" | "export") {
+                    let rest = source_text[i..].trim_start();
+                    let next = rest.bytes().next();
+                    let is_declaration = match word {
+                        // Not `import(...)`, `import: ...` or `import = ...`
+                        "import" => {
+                            rest.starts_with(".meta")
+                                || matches!(next, Some(b'{' | b'*' | b'\'' | b'"'))
+                                || next.is_some_and(|next| {
+                                    is_identifier_byte(next) && !next.is_ascii_digit()
+                                })
+                        }
+                        _ => {
+                            matches!(next, Some(b'{' | b'*'))
+                                || next.is_some_and(|next| {
+                                    is_identifier_byte(next) && !next.is_ascii_digit()
+                                })
+                        }
+                    };
+                    if is_declaration {
+                        return true;
+                    }
+                }
+                previous = bytes[i - 1];
+                continue;
+            }
+            _ => {}
+        }
+        previous = byte;
+        i += 1;
+    }
+    false
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$') || !byte.is_ascii()
+}
+
+fn position_or_end(bytes: &[u8], start: usize, needle: u8) -> usize {
+    bytes[start..].iter().position(|byte| *byte == needle).map_or(bytes.len(), |end| start + end)
+}
+
+/// Offset after the string or template literal starting at `start`,
+/// substitutions of template literals are skipped as if they were part of the literal.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            byte if byte == quote => return i + 1,
+            // Unterminated string literal
+            b'\n' if quote != b'`' => return i,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{has_module_syntax, SourceType};
+
+    #[test]
+    fn module_syntax() {
+        for source_text in [
+            "import a from 'a'",
+            "import 'a'",
+            "import * as a from 'a'",
+            "import{a}from'a'",
+            "const a = 1;\nexport { a }",
+            "export default 1",
+            "console.log(import.meta.url)",
+        ] {
+            assert!(has_module_syntax(source_text), "{source_text}");
+        }
+        for source_text in [
+            "const a = require('a')",
+            "import('a')",
+            "module.exports = { import: 1 }",
+            "exports.export = 1",
+            "// import a from 'a'",
+            "/* export default 1 */",
+            "const s = 'import a from \"a\"'",
+            "const s = `\nexport default 1\n`",
+        ] {
+            assert!(!has_module_syntax(source_text), "{source_text}");
+        }
+    }
+
+    #[test]
+    fn detect() {
+        let detect = |source_text, path, is_module_package| {
+            SourceType::detect(source_text, path, || is_module_package)
+        };
+        let script = "module.exports = 1";
+        assert!(detect(script, "a.js", false).unwrap().is_script());
+        assert!(detect(script, "a.js", true).unwrap().is_module());
+        assert!(detect(script, "a.mjs", false).unwrap().is_module());
+        assert!(detect(script, "a.cjs", true).unwrap().is_script());
+        assert!(detect("export default 1", "a.js", false).unwrap().is_module());
+        assert!(detect("export default 1", "a.cjs", false).unwrap().is_script());
+        assert!(detect("declare const a: 1", "a.d.ts", false).unwrap().is_module());
+        let tsx = detect("export default <div />", "a.tsx", false).unwrap();
+        assert!(tsx.is_typescript() && tsx.is_jsx() && tsx.is_module());
+        assert!(detect(script, "a.txt", false).is_err());
+    }
 }
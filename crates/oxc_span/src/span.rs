@@ -39,6 +39,16 @@ impl Span {
     pub fn source_text<'a>(&self, source_text: &'a str) -> &'a str {
         &source_text[self.start as usize..self.end as usize]
     }
+
+    /// Shrink the span to exclude leading and trailing whitespace
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn trim(&self, source_text: &str) -> Self {
+        let text = self.source_text(source_text);
+        let start = self.start + (text.len() - text.trim_start().len()) as u32;
+        let end = self.end - (text.len() - text.trim_end().len()) as u32;
+        Self::new(start, end.max(start))
+    }
 }
 
 impl Hash for Span {
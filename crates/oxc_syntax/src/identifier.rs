@@ -1,4 +1,4 @@
-use unicode_id_start::{is_id_continue_unicode, is_id_start_unicode};
+use crate::unicode_tables::{ID_CONTINUE, ID_START, SPACE_SEPARATOR};
 
 pub const EOF: char = '\0';
 
@@ -29,12 +29,16 @@ pub const FF: char = '\u{c}';
 /// U+00A0 NON-BREAKING SPACE, abbreviated `<NBSP>`.
 pub const NBSP: char = '\u{a0}';
 
+/// U+0020 SPACE, the only regular whitespace besides `<TAB>`.
+const SP: char = ' ';
+
 pub fn is_irregular_whitespace(c: char) -> bool {
-    matches!(
-        c,
-        VT | FF | NBSP | ZWNBSP | '\u{85}' | '\u{1680}' | '\u{2000}'
-            ..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}'
-    )
+    matches!(c, VT | FF | ZWNBSP | '\u{85}') || (c != SP && is_space_separator(c))
+}
+
+/// `<USP>`: any code point in the `Space_Separator` general category, including `<NBSP>`.
+pub fn is_space_separator(c: char) -> bool {
+    SPACE_SEPARATOR.contains(c)
 }
 
 // 11.3 Line Terminators
@@ -63,6 +67,19 @@ pub fn is_line_terminator(c: char) -> bool {
     is_regular_line_terminator(c) || is_irregular_line_terminator(c)
 }
 
+/// A statement starting with one of these characters continues the previous line
+/// when the previous statement is not terminated by a semicolon, e.g.
+///
+/// ```javascript
+/// let a = b
+/// (c || d).foo()
+/// ```
+///
+/// <https://tc39.es/ecma262/#sec-hazards-of-automatic-semicolon-insertion>
+pub fn is_asi_hazard_start(c: char) -> bool {
+    matches!(c, '(' | '[' | '`' | '+' | '-' | '/')
+}
+
 const XX: bool = true;
 const __: bool = false;
 
@@ -113,7 +130,7 @@ pub fn is_identifier_start_ascii(c: char) -> bool {
 
 #[inline]
 pub fn is_identifier_start_unicode(c: char) -> bool {
-    is_id_start_unicode(c)
+    ID_START.contains(c)
 }
 
 /// Section 12.7 Detect `IdentifierPartChar`
@@ -133,10 +150,57 @@ pub fn is_identifier_part_ascii(c: char) -> bool {
 
 #[inline]
 pub fn is_identifier_part_unicode(c: char) -> bool {
-    is_id_continue_unicode(c) || c == ZWNJ || c == ZWJ
+    ID_CONTINUE.contains(c) || c == ZWNJ || c == ZWJ
+}
+
+/// A set of code points generated in [`crate::unicode_tables`].
+///
+/// A two-level trie of bitmaps: `index` maps each 512 code points to one of `chunks`,
+/// which maps each 64 of them to one of the bitmaps in `leaves`.
+/// Equal chunks and bitmaps are shared, and code points past the end of `index` are not in the set.
+pub struct CharTable {
+    pub(crate) index: &'static [u8],
+    pub(crate) chunks: &'static [[u16; 8]],
+    pub(crate) leaves: &'static [u64],
+}
+
+impl CharTable {
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as usize;
+        self.index.get(c >> 9).is_some_and(|&chunk| {
+            let leaf = self.chunks[chunk as usize][(c >> 6) & 7];
+            self.leaves[leaf as usize] & (1 << (c & 63)) != 0
+        })
+    }
 }
 
 pub fn is_identifier_name(name: &str) -> bool {
     let mut chars = name.chars();
     chars.next().is_some_and(is_identifier_start) && chars.all(is_identifier_part)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unicode_tables() {
+        for table in [&ID_START, &ID_CONTINUE, &SPACE_SEPARATOR] {
+            assert!(table.index.iter().all(|&chunk| usize::from(chunk) < table.chunks.len()));
+            assert!(table
+                .chunks
+                .iter()
+                .flatten()
+                .all(|&leaf| usize::from(leaf) < table.leaves.len()));
+        }
+        assert!(is_identifier_start('\u{33479}') && !is_identifier_start('\u{3347a}'));
+        assert!(is_identifier_part('\u{e01ef}') && !is_identifier_part('\u{e01f0}'));
+        assert!(!is_identifier_part(char::MAX));
+        assert!(is_identifier_start('ಠ') && is_identifier_part('ಠ'));
+        assert!(!is_identifier_start('\u{0301}') && is_identifier_part('\u{0301}'));
+        assert!(!is_identifier_start('😀') && !is_identifier_part('😀'));
+        assert!(is_irregular_whitespace(NBSP) && is_irregular_whitespace('\u{3000}'));
+        assert!(!is_irregular_whitespace(SP) && !is_irregular_whitespace('a'));
+    }
+}
@@ -11,8 +11,11 @@ pub mod precedence;
 pub mod reference;
 pub mod scope;
 pub mod symbol;
+mod unicode_tables;
 pub mod xml_entities;
 
+pub use unicode_tables::UNICODE_VERSION;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum NumberBase {
     Float,
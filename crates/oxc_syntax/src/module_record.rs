@@ -2,10 +2,12 @@
 
 use std::{fmt, hash::BuildHasherDefault, path::PathBuf, sync::Arc};
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use indexmap::IndexMap;
 use oxc_span::{Atom, Span};
 use rustc_hash::{FxHashMap, FxHasher};
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 /// Module Record
 ///
@@ -28,12 +30,22 @@ pub struct ModuleRecord {
     /// Keyed by ModuleSpecifier, valued by all node occurrences
     pub requested_modules: IndexMap<Atom, Vec<Span>, BuildHasherDefault<FxHasher>>,
 
+    /// `[[Attributes]]` of the ModuleRequest Records
+    ///
+    /// Keyed by ModuleSpecifier, valued by the attributes of its first request with a
+    /// `with` clause, e.g. `type: "json"` of `import data from "./data.json" with { type: "json" }`
+    pub requested_module_attributes: FxHashMap<Atom, Vec<ImportAttributeEntry>>,
+
     /// `[[LoadedModules]]`
     ///
     /// A map from the specifier strings used by the module represented by this record to request the importation of a module to the resolved Module Record.
     /// The list does not contain two different Records with the same `[[Specifier]]`.
     pub loaded_modules: DashMap<Atom, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// Specifiers which resolve to modules outside of the file system,
+    /// e.g. import map entries pointing to a URL. These are never loaded.
+    pub external_modules: DashSet<Atom, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
@@ -64,12 +76,112 @@ pub struct ModuleRecord {
 
     pub export_default: Option<Span>,
     pub export_default_duplicated: Vec<Span>,
+
+    /// Whether the module has top level statements other than import and export declarations
+    pub has_other_statements: bool,
 }
 
 impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// Whether the module is a barrel file, a module which only re-exports bindings of other
+    /// modules, e.g. an `index.js` containing `export * from "./a"; export { b } from "./b";`
+    pub fn is_barrel(&self) -> bool {
+        let reexports_namespaces_only = self.local_export_entries.iter().all(|entry| {
+            let ExportLocalName::Name(local_name) = &entry.local_name else { return false };
+            self.import_entries.iter().any(|import_entry| {
+                import_entry.import_name.is_namespace_object()
+                    && import_entry.local_name.name() == local_name.name()
+            })
+        });
+        !self.has_other_statements
+            && reexports_namespaces_only
+            && !(self.indirect_export_entries.is_empty()
+                && self.star_export_entries.is_empty()
+                && self.local_export_entries.is_empty())
+    }
+
+    /// Follow re-exports of `export_name` from `module` to the module declaring the binding.
+    ///
+    /// Returns `None` if a module on the way is not loaded or the re-exports form a cycle.
+    /// The first match of `export *` declarations wins, ambiguous names are not detected.
+    pub fn resolve_export(module: &Arc<Self>, export_name: &str) -> Option<ResolvedExport> {
+        Self::resolve_export_impl(module, export_name, &mut vec![])
+    }
+
+    /// [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport)
+    fn resolve_export_impl(
+        module: &Arc<Self>,
+        export_name: &str,
+        resolve_set: &mut Vec<(PathBuf, Atom)>,
+    ) -> Option<ResolvedExport> {
+        let key = (module.resolved_absolute_path.clone(), Atom::from(export_name));
+        if resolve_set.contains(&key) {
+            return None;
+        }
+        resolve_set.push(key);
+
+        let loaded_module = |request: &Option<NameSpan>| {
+            let request = request.as_ref()?;
+            module.loaded_modules.get(request.name()).map(|module| Arc::clone(module.value()))
+        };
+
+        for entry in &module.local_export_entries {
+            if !entry.export_name.matches(export_name) {
+                continue;
+            }
+            // `import * as ns from "mod"; export { ns };`
+            if let ExportLocalName::Name(local_name) = &entry.local_name {
+                let namespace_import = module.import_entries.iter().find(|import_entry| {
+                    import_entry.import_name.is_namespace_object()
+                        && import_entry.local_name.name() == local_name.name()
+                });
+                if let Some(import_entry) = namespace_import {
+                    let request = Some(import_entry.module_request.clone());
+                    return loaded_module(&request).map(ResolvedExport::Namespace);
+                }
+            }
+            return Some(ResolvedExport::Binding {
+                module: Arc::clone(module),
+                name: Atom::from(export_name),
+            });
+        }
+
+        for entry in &module.indirect_export_entries {
+            if !entry.export_name.matches(export_name) {
+                continue;
+            }
+            let imported_module = loaded_module(&entry.module_request)?;
+            return match &entry.import_name {
+                // `export * as ns from "mod"`
+                ExportImportName::All => Some(ResolvedExport::Namespace(imported_module)),
+                ExportImportName::Name(name) => {
+                    Self::resolve_export_impl(&imported_module, name.name(), resolve_set)
+                }
+                ExportImportName::AllButDefault | ExportImportName::Null => None,
+            };
+        }
+
+        // `export *` does not re-export the default export
+        if export_name == "default" {
+            return None;
+        }
+        module.star_export_entries.iter().find_map(|entry| {
+            let imported_module = loaded_module(&entry.module_request)?;
+            Self::resolve_export_impl(&imported_module, export_name, resolve_set)
+        })
+    }
+}
+
+/// The origin of an exported binding, see [`ModuleRecord::resolve_export`]
+#[derive(Debug, Clone)]
+pub enum ResolvedExport {
+    /// A binding declared by `module` and exported as `name`
+    Binding { module: Arc<ModuleRecord>, name: Atom },
+    /// The namespace object of a module
+    Namespace(Arc<ModuleRecord>),
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -85,7 +197,9 @@ impl fmt::Debug for ModuleRecord {
         f.debug_struct("ModuleRecord")
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
+            .field("requested_module_attributes", &self.requested_module_attributes)
             .field("loaded_modules", &loaded_modules)
+            .field("external_modules", &self.external_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -94,6 +208,7 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings_duplicated", &self.exported_bindings_duplicated)
             .field("export_default", &self.export_default)
             .field("export_default_duplicated", &self.export_default_duplicated)
+            .field("has_other_statements", &self.has_other_statements)
             .finish()
     }
 }
@@ -118,6 +233,13 @@ impl NameSpan {
     }
 }
 
+/// An entry of the `[[Attributes]]` of a ModuleRequest Record, e.g. `type: "json"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttributeEntry {
+    pub key: NameSpan,
+    pub value: NameSpan,
+}
+
 /// [`ImportEntry`](https://tc39.es/ecma262/#importentry-record)
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImportEntry {
@@ -129,6 +251,32 @@ pub struct ImportEntry {
 
     /// The name that is used to locally access the imported value from within the importing module.
     pub local_name: NameSpan,
+
+    /// `source` of `import source x from "mod.wasm"` and `defer` of `import defer * as ns from "mod"`.
+    ///
+    /// The import name of a source phase import is [`ImportImportName::Default`].
+    pub phase: Option<ImportPhase>,
+}
+
+/// Phase of [source phase imports](https://github.com/tc39/proposal-source-phase-imports)
+/// and [deferred imports](https://github.com/tc39/proposal-defer-import-eval)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+#[cfg_attr(all(feature = "serde", feature = "wasm"), derive(tsify::Tsify))]
+pub enum ImportPhase {
+    /// `import source x from "mod.wasm"`, imports the compiled module without evaluating it
+    Source,
+    /// `import defer * as ns from "mod"`, evaluates the module on first access of the namespace
+    Defer,
+}
+
+impl ImportPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Defer => "defer",
+        }
+    }
 }
 
 /// `ImportName` For `ImportEntry`
@@ -221,6 +369,15 @@ impl ExportExportName {
             Self::Null => None,
         }
     }
+
+    /// Whether the binding is exported as `name`, `default` for the default export
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Name(export_name) => export_name.name().as_str() == name,
+            Self::Default(_) => name == "default",
+            Self::Null => false,
+        }
+    }
 }
 
 /// `LocalName` for `ExportEntry`
@@ -10,14 +10,18 @@ define_index_type! {
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type AstNodeId = number;
 export type NodeFlags = {
     JSDoc: 1,
     Class: 2,
-    HasYield: 4
+    HasYield: 4,
+    HasAwait: 8,
+    HasThis: 16,
+    HasArguments: 32,
+    HasSuper: 64
 };
-"#;
+";
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -25,7 +29,12 @@ bitflags! {
         const JSDoc    = 1 << 0; // If the Node has a JSDoc comment attached
         const Class    = 1 << 1; // If Node is inside a class
         const HasYield = 1 << 2; // If function has yield statement
-
+        const HasAwait = 1 << 3; // If function has await expression or `for await`
+        // If function uses `this`, `arguments` or `super` of its own, including in nested
+        // arrow functions, which are flagged as well
+        const HasThis      = 1 << 4;
+        const HasArguments = 1 << 5;
+        const HasSuper     = 1 << 6;
     }
 }
 
@@ -41,4 +50,20 @@ impl NodeFlags {
     pub fn has_yield(&self) -> bool {
         self.contains(Self::HasYield)
     }
+
+    pub fn has_await(&self) -> bool {
+        self.contains(Self::HasAwait)
+    }
+
+    pub fn has_this(&self) -> bool {
+        self.contains(Self::HasThis)
+    }
+
+    pub fn has_arguments(&self) -> bool {
+        self.contains(Self::HasArguments)
+    }
+
+    pub fn has_super(&self) -> bool {
+        self.contains(Self::HasSuper)
+    }
 }
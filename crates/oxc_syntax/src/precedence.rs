@@ -2,6 +2,60 @@ pub trait GetPrecedence {
     fn precedence(&self) -> Precedence;
 }
 
+/// Precedence of an operator or an expression, e.g. `precedence(&BinaryOperator::Addition)`
+pub fn precedence<T: GetPrecedence + ?Sized>(node: &T) -> Precedence {
+    node.precedence()
+}
+
+/// Side of an operand in a binary, logical or assignment expression
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`
+    Right,
+    /// Has no operands of the same precedence, e.g. prefix operators
+    None,
+}
+
+/// Whether an operand of precedence `child` at `side` of an expression of precedence `parent`
+/// must be wrapped in parentheses to keep its meaning.
+///
+/// Besides precedence and associativity, this covers the two places where the grammar forbids
+/// operands of a higher precedence:
+/// * `??` cannot be mixed with `||` or `&&`, i.e. `(a || b) ?? c`
+/// * the left operand of `**` cannot be a prefix expression, i.e. `(-a) ** b`
+pub fn needs_parens(parent: Precedence, child: Precedence, side: Side) -> bool {
+    if is_mixed_coalesce(parent, child) {
+        return true;
+    }
+    if parent == Precedence::Exponential && side == Side::Left && child == Precedence::Prefix {
+        return true;
+    }
+    match child.cmp(&parent) {
+        std::cmp::Ordering::Less => true,
+        std::cmp::Ordering::Greater => false,
+        std::cmp::Ordering::Equal => match parent.associativity() {
+            Associativity::Left => side == Side::Right,
+            Associativity::Right => side == Side::Left,
+            Associativity::None => false,
+        },
+    }
+}
+
+/// `??` and `||` or `&&` as each other's operand, a syntax error without parentheses
+pub fn is_mixed_coalesce(parent: Precedence, child: Precedence) -> bool {
+    let is_logical = |p| matches!(p, Precedence::LogicalOr | Precedence::LogicalAnd);
+    (parent == Precedence::Coalesce && is_logical(child))
+        || (is_logical(parent) && child == Precedence::Coalesce)
+}
+
 /// Operator Precedence
 ///
 /// The following values are meaningful relative position, not their individual values.
@@ -18,6 +72,8 @@ pub enum Precedence {
     Arrow,
     Yield,
     Conditional,
+    /// `|>` of the pipeline operator proposal, below all binary operators
+    Pipeline,
     Coalesce,
     LogicalOr,
     LogicalAnd,
@@ -43,6 +99,16 @@ impl Precedence {
         Self::Comma
     }
 
+    pub fn associativity(&self) -> Associativity {
+        if self.is_right_associative() {
+            Associativity::Right
+        } else if self.is_left_associative() {
+            Associativity::Left
+        } else {
+            Associativity::None
+        }
+    }
+
     pub fn is_right_associative(&self) -> bool {
         matches!(self, Self::Exponential | Self::Conditional | Self::Arrow | Self::Assign)
     }
@@ -62,7 +128,48 @@ impl Precedence {
                 | Self::LogicalAnd
                 | Self::LogicalOr
                 | Self::Coalesce
+                | Self::Pipeline
                 | Self::Comma
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{needs_parens, precedence, Precedence, Side};
+    use crate::operator::{BinaryOperator, LogicalOperator};
+
+    #[test]
+    fn operator_precedence() {
+        assert_eq!(precedence(&BinaryOperator::Addition), Precedence::Add);
+        assert_eq!(precedence(&BinaryOperator::Instanceof), Precedence::Relational);
+        assert_eq!(precedence(&LogicalOperator::Coalesce), Precedence::Coalesce);
+        assert!(
+            precedence(&BinaryOperator::Multiplication) > precedence(&BinaryOperator::Addition)
+        );
+        assert!(precedence(&BinaryOperator::BitwiseOR) > precedence(&LogicalOperator::And));
+    }
+
+    #[test]
+    fn parens() {
+        // (a + b) * c
+        assert!(needs_parens(Precedence::Multiply, Precedence::Add, Side::Left));
+        // a + b * c
+        assert!(!needs_parens(Precedence::Add, Precedence::Multiply, Side::Right));
+        // a - b - c, a - (b - c)
+        assert!(!needs_parens(Precedence::Add, Precedence::Add, Side::Left));
+        assert!(needs_parens(Precedence::Add, Precedence::Add, Side::Right));
+        // (a ** b) ** c, a ** b ** c
+        assert!(needs_parens(Precedence::Exponential, Precedence::Exponential, Side::Left));
+        assert!(!needs_parens(Precedence::Exponential, Precedence::Exponential, Side::Right));
+        // (-a) ** b, a ** -b
+        assert!(needs_parens(Precedence::Exponential, Precedence::Prefix, Side::Left));
+        assert!(!needs_parens(Precedence::Exponential, Precedence::Prefix, Side::Right));
+        // (a || b) ?? c, a ?? (b && c), (a ?? b) || c
+        assert!(needs_parens(Precedence::Coalesce, Precedence::LogicalOr, Side::Left));
+        assert!(needs_parens(Precedence::Coalesce, Precedence::LogicalAnd, Side::Right));
+        assert!(needs_parens(Precedence::LogicalOr, Precedence::Coalesce, Side::Left));
+        // a = b = c
+        assert!(!needs_parens(Precedence::Assign, Precedence::Assign, Side::Right));
+    }
+}
@@ -12,16 +12,17 @@ define_index_type! {
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type ReferenceId = number;
 export type ReferenceFlag = {
     None: 0,
     Read: 0b1,
     Write: 0b10,
     Type: 0b100,
+    MaybeWith: 0b1000,
     ReadWrite: 0b11
 }
-"#;
+";
 
 bitflags! {
     #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
@@ -32,6 +33,8 @@ bitflags! {
         const Write = 1 << 1;
         // Used in type definitions.
         const Type = 1 << 2;
+        // Inside a `with` statement, may refer to a property of the `with` object.
+        const MaybeWith = 1 << 3;
         const ReadWrite = Self::Read.bits() | Self::Write.bits();
     }
 }
@@ -78,4 +81,9 @@ impl ReferenceFlag {
     pub const fn is_type(&self) -> bool {
         self.contains(Self::Type)
     }
+
+    /// The identifier is inside a `with` statement and may refer to a property of the `with` object.
+    pub const fn is_maybe_with(&self) -> bool {
+        self.contains(Self::MaybeWith)
+    }
 }
@@ -10,9 +10,9 @@ define_index_type! {
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type ScopeId = number;
-"#;
+";
 
 bitflags! {
     #[derive(Debug, Clone, Copy)]
@@ -26,6 +26,8 @@ bitflags! {
         const Constructor      = 1 << 6;
         const GetAccessor      = 1 << 7;
         const SetAccessor      = 1 << 8;
+        const CatchClause      = 1 << 9; // try {} catch (e) {}
+        const With             = 1 << 10; // with (object) {}
         const Var = Self::Top.bits() | Self::Function.bits() | Self::ClassStaticBlock.bits() | Self::TsModuleBlock.bits();
         const Modifiers = Self::Constructor.bits() | Self::GetAccessor.bits() | Self::SetAccessor.bits();
     }
@@ -69,6 +71,14 @@ impl ScopeFlags {
         self.contains(Self::TsModuleBlock)
     }
 
+    pub fn is_catch_clause(&self) -> bool {
+        self.contains(Self::CatchClause)
+    }
+
+    pub fn is_with(&self) -> bool {
+        self.contains(Self::With)
+    }
+
     pub fn is_var(&self) -> bool {
         self.intersects(Self::Var)
     }
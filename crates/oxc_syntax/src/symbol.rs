@@ -13,10 +13,10 @@ define_index_type! {
     wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)
 )]
 #[allow(dead_code)]
-const TS_APPEND_CONTENT: &'static str = r#"
+const TS_APPEND_CONTENT: &str = r"
 export type SymbolId = number;
 export type SymbolFlags = unknown;
-"#;
+";
 
 bitflags! {
     #[derive(Debug, Clone, Copy)]
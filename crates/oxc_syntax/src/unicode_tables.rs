@@ -0,0 +1,363 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To regenerate run `just unicode <version>`, see `tasks/unicode`.
+
+use crate::identifier::CharTable;
+
+/// Version of the Unicode Character Database the tables are generated from,
+/// in the same format as [`char::UNICODE_VERSION`].
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Characters with the `ID_Start` property, from `DerivedCoreProperties.txt`
+#[rustfmt::skip]
+pub static ID_START: CharTable = CharTable {
+    index: &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 17, 17, 17, 17, 18, 17,
+        19, 17, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 21, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 22, 23, 24, 25, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 26, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+        20, 47, 48, 17, 17, 17, 17, 49, 20, 20, 50, 20, 20, 20, 20, 20, 20, 51, 20, 52, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 53, 17, 17, 17, 20, 54, 55, 56, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 57, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 58,
+        59, 60, 17, 17, 17, 17, 61, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 62, 63, 17, 17, 17, 64,
+        65, 66, 67, 68, 69, 17, 17, 70, 17, 17, 17, 17, 17, 17, 17, 17, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 71, 20, 20, 20, 20, 20, 20, 20, 20, 72, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 73,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 74, 20, 75, 17, 17, 17, 17, 20, 76, 17, 17,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 77, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 78,
+    ],
+    chunks: &[
+        [0, 1, 2, 3, 4, 4, 4, 4], [4, 4, 4, 5, 0, 6, 7, 8],
+        [4, 4, 9, 4, 10, 11, 12, 13], [14, 15, 4, 16, 17, 18, 19, 20],
+        [21, 22, 23, 24, 25, 26, 27, 28], [29, 30, 31, 32, 33, 34, 35, 36],
+        [37, 38, 39, 40, 41, 42, 43, 44], [45, 44, 46, 47, 48, 49, 50, 0],
+        [51, 52, 53, 54, 4, 4, 4, 4], [4, 55, 56, 57, 58, 59, 60, 61],
+        [62, 4, 4, 4, 4, 4, 4, 4], [4, 63, 64, 65, 66, 67, 68, 69],
+        [14, 70, 71, 72, 73, 74, 75, 24], [76, 77, 78, 0, 79, 80, 81, 82],
+        [83, 84, 85, 86, 4, 4, 4, 0], [4, 4, 4, 4, 87, 88, 89, 90],
+        [0, 91, 92, 0, 93, 94, 12, 0], [0, 0, 0, 0, 0, 0, 0, 0],
+        [4, 4, 4, 95, 96, 97, 98, 99], [100, 62, 101, 102, 103, 4, 104, 105],
+        [4, 4, 4, 4, 4, 4, 4, 4], [4, 4, 4, 4, 4, 4, 4, 0],
+        [4, 4, 106, 107, 4, 4, 4, 4], [108, 109, 110, 111, 112, 4, 113, 114],
+        [115, 68, 116, 117, 118, 119, 120, 121], [122, 123, 124, 125, 126, 127, 4, 128],
+        [4, 4, 4, 4, 4, 4, 129, 130], [0, 0, 0, 0, 4, 4, 4, 4],
+        [4, 131, 4, 132, 133, 134, 135, 136], [4, 4, 4, 4, 137, 138, 139, 140],
+        [0, 141, 4, 142, 143, 144, 145, 146], [147, 148, 4, 149, 0, 150, 0, 0],
+        [0, 0, 151, 152, 153, 154, 110, 155], [4, 4, 156, 157, 158, 159, 160, 68],
+        [4, 4, 4, 4, 161, 162, 163, 0], [164, 165, 73, 166, 167, 132, 168, 0],
+        [169, 170, 171, 172, 72, 173, 174, 0], [4, 12, 175, 175, 83, 176, 177, 0],
+        [0, 0, 178, 179, 180, 181, 182, 183], [184, 185, 186, 187, 188, 189, 190, 191],
+        [192, 48, 193, 73, 33, 194, 195, 196], [150, 197, 111, 198, 0, 0, 199, 200],
+        [111, 201, 202, 0, 59, 44, 0, 0], [203, 0, 14, 204, 205, 206, 207, 208],
+        [209, 210, 211, 70, 0, 0, 0, 212], [213, 214, 215, 0, 216, 217, 218, 219],
+        [0, 0, 0, 220, 221, 0, 222, 0], [4, 4, 4, 4, 4, 4, 132, 0],
+        [4, 199, 4, 4, 4, 223, 0, 0], [0, 0, 0, 0, 0, 0, 138, 224],
+        [111, 225, 4, 4, 4, 4, 4, 4], [4, 4, 4, 4, 4, 4, 4, 149],
+        [4, 44, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 226, 0, 0, 0],
+        [70, 227, 145, 228, 111, 229, 215, 0], [0, 0, 0, 0, 0, 230, 0, 0],
+        [0, 4, 231, 232, 4, 233, 234, 235], [4, 4, 4, 236, 73, 0, 4, 175],
+        [0, 0, 0, 0, 0, 0, 0, 237], [4, 4, 4, 4, 238, 239, 4, 4],
+        [4, 4, 4, 240, 0, 0, 0, 0], [4, 241, 242, 0, 0, 0, 0, 0],
+        [4, 243, 244, 245, 246, 247, 4, 4], [4, 4, 248, 249, 250, 251, 252, 253],
+        [0, 0, 0, 0, 254, 0, 0, 0], [105, 255, 0, 0, 256, 257, 0, 0],
+        [0, 0, 228, 203, 0, 0, 0, 0], [0, 0, 0, 258, 0, 0, 0, 259],
+        [0, 0, 0, 260, 0, 0, 0, 261], [4, 4, 4, 262, 4, 263, 0, 0],
+        [264, 265, 266, 0, 0, 0, 0, 0], [4, 4, 4, 267, 4, 4, 4, 4],
+        [110, 4, 4, 4, 4, 4, 4, 4], [4, 4, 131, 4, 4, 4, 4, 4],
+        [4, 4, 4, 4, 4, 4, 4, 268], [4, 226, 0, 0, 0, 0, 0, 0],
+        [226, 0, 0, 0, 0, 0, 0, 0], [4, 4, 4, 4, 4, 269, 4, 4],
+        [4, 270, 0, 0, 0, 0, 0, 0],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000, 0x07ff_fffe_07ff_fffe, 0x0420_0400_0000_0000, 0xff7f_ffff_ff7f_ffff,
+        0xffff_ffff_ffff_ffff, 0x0000_501f_0003_ffc3, 0xbcdf_0000_0000_0000, 0xffff_fffb_ffff_d740,
+        0xffbf_ffff_ffff_ffff, 0xffff_ffff_ffff_fc03, 0xfffe_ffff_ffff_ffff, 0xffff_ffff_027f_ffff,
+        0x0000_0000_0000_01ff, 0x0007_87ff_ffff_0000, 0xffff_ffff_0000_0000, 0xfffe_c000_0000_07ff,
+        0x9c00_c060_002f_ffff, 0x0000_ffff_fffd_0000, 0xffff_ffff_ffff_e000, 0x0002_003f_ffff_ffff,
+        0x0430_07ff_ffff_fc00, 0x0000_0110_043f_ffff, 0xffff_07ff_01ff_ffff, 0xffff_ffff_0000_feff,
+        0x0000_0000_0000_03ff, 0x23ff_ffff_ffff_fff0, 0xfffe_0003_ff01_0000, 0x23c5_fdff_fff9_9fe1,
+        0x1003_0003_b000_4000, 0x036d_fdff_fff9_87e0, 0x001c_0000_5e00_0000, 0x23ed_fdff_fffb_bfe0,
+        0x0200_0003_0001_0000, 0x23ed_fdff_fff9_9fe0, 0x0002_0003_b000_0000, 0x03ff_c718_d63d_c7e8,
+        0x0000_0000_0001_0000, 0x23ff_fdff_fffd_dfe0, 0x0000_0003_3700_0000, 0x23ef_fdff_fffd_dfe1,
+        0x0006_0003_7000_0000, 0x27ff_ffff_fffd_dff0, 0xfc00_0003_8070_4000, 0x2ffb_ffff_fc7f_ffe0,
+        0x0000_0000_0000_007f, 0x000d_ffff_ffff_fffe, 0x200d_ffaf_ffff_f7d6, 0x0000_0000_f000_005f,
+        0x0000_0000_0000_0001, 0x0000_1fff_ffff_feff, 0x0000_0000_0000_1f00, 0x8000_07ff_ffff_ffff,
+        0xffe1_c062_3c3f_0000, 0xffff_ffff_0000_4003, 0xf7ff_ffff_ffff_20bf, 0xffff_ffff_3d7f_3dff,
+        0x7f3d_ffff_ffff_3dff, 0xffff_ffff_ff7f_ff3d, 0xffff_ffff_ff3d_ffff, 0x0000_0000_07ff_ffff,
+        0xffff_ffff_0000_ffff, 0x3f3f_ffff_ffff_ffff, 0xffff_ffff_ffff_fffe, 0xffff_9fff_ffff_ffff,
+        0xffff_ffff_07ff_fffe, 0x01ff_c7ff_ffff_ffff, 0x0003_ffff_8003_ffff, 0x0001_dfff_0003_ffff,
+        0x000f_ffff_ffff_ffff, 0x0000_0000_1080_0000, 0x01ff_ffff_ffff_ffff, 0xffff_05ff_ffff_ffff,
+        0x003f_ffff_ffff_ffff, 0x0000_0000_7fff_ffff, 0x001f_3fff_ffff_0000, 0xffff_0fff_ffff_ffff,
+        0xffff_ffff_007f_ffff, 0x0000_0000_001f_ffff, 0x0000_0080_0000_0000, 0x000f_ffff_ffff_ffe0,
+        0x0000_0000_0000_1fe0, 0xfc00_c001_ffff_fff8, 0x0000_003f_ffff_ffff, 0x0000_000f_ffff_ffff,
+        0x3fff_ffff_fc00_e000, 0xe7ff_ffff_ffff_07ff, 0x046f_de00_0000_0000, 0xffff_ffff_3f3f_ffff,
+        0x3fff_ffff_aaff_3f3f, 0x5fdf_ffff_ffff_ffff, 0x1fdc_1fff_0fcf_1fdc, 0x8002_0000_0000_0000,
+        0x0000_0000_1fff_0000, 0xf3ff_fd50_3f2f_fc84, 0xffff_ffff_0000_43e0, 0x000c_781f_ffff_ffff,
+        0xffff_20bf_ffff_ffff, 0x0000_80ff_ffff_ffff, 0x7f7f_7f7f_007f_ffff, 0x0000_0000_7f7f_7f7f,
+        0x1f3e_03fe_0000_00e0, 0xffff_fffe_f87f_ffff, 0xf7ff_ffff_ffff_ffff, 0xfffe_ffff_ffff_ffe0,
+        0xffff_ffff_0000_7fff, 0xffff_0000_0000_0000, 0x0000_0000_0000_1fff, 0x3fff_ffff_ffff_0000,
+        0x0000_0c00_ffff_1fff, 0x8000_7fff_ffff_ffff, 0xffff_ffff_3fff_ffff, 0x0000_ffff_ffff_ffff,
+        0xffff_fffc_ff80_0000, 0xffff_ffff_ffff_f9ff, 0xfffe_0000_1fff_ffff, 0x0000_0007_ffff_f7bb,
+        0x000f_ffff_ffff_fffc, 0x68fc_0000_0000_0000, 0xffff_003f_ffff_fc00, 0x1fff_ffff_0000_007f,
+        0x0007_ffff_ffff_fff0, 0x7c00_ffdf_0000_8000, 0x0000_01ff_ffff_ffff, 0xc47f_ffff_0000_0ff7,
+        0x3e62_ffff_ffff_ffff, 0x001c_07ff_3800_0005, 0xffff_7f7f_007e_7e7e, 0xffff_03ff_f7ff_ffff,
+        0x0000_0007_ffff_ffff, 0xffff_000f_ffff_ffff, 0x0fff_ffff_ffff_f87f, 0xffff_3fff_ffff_ffff,
+        0x0000_0000_03ff_ffff, 0x5f7f_fdff_a0f8_007f, 0xffff_ffff_ffff_ffdb, 0x0003_ffff_ffff_ffff,
+        0xffff_ffff_fff8_0000, 0x3fff_ffff_ffff_ffff, 0xffff_ffff_ffff_0000, 0xffff_ffff_fffc_ffff,
+        0x0fff_0000_0000_00ff, 0xffdf_0000_0000_0000, 0x1fff_ffff_ffff_ffff, 0x07ff_fffe_0000_0000,
+        0xffff_ffc0_07ff_fffe, 0x7fff_ffff_ffff_ffff, 0x0000_0000_1cfc_fcfc, 0xb7ff_ff7f_ffff_efff,
+        0x0000_0000_3fff_3fff, 0x07ff_ffff_ffff_ffff, 0x001f_ffff_ffff_ffff, 0xffff_ffff_1fff_ffff,
+        0x0000_0000_0001_ffff, 0xffff_e000_ffff_ffff, 0x003f_ffff_ffff_07ff, 0x0000_0000_003e_ff0f,
+        0xffff_0000_3fff_ffff, 0x0fff_ffff_ff0f_ffff, 0xffff_00ff_ffff_ffff, 0xf7ff_000f_ffff_ffff,
+        0x1bfb_fffb_ffb7_f7ff, 0x007f_ffff_ffff_ffff, 0x0000_00ff_003f_ffff, 0x07fd_ffff_ffff_ffbf,
+        0x91bf_ffff_ffff_fd3f, 0x007f_ffff_003f_ffff, 0x0037_ffff_0000_0000, 0x03ff_ffff_003f_ffff,
+        0xc0ff_ffff_ffff_ffff, 0x003f_ffff_feef_0001, 0x1fff_ffff_0000_0000, 0x0000_0000_1fff_ffff,
+        0x0000_001f_ffff_feff, 0x0007_ffff_003f_ffff, 0x0000_0000_0003_ffff, 0x0007_ffff_ffff_ffff,
+        0xffff_803f_ffff_fc00, 0x0000_0000_0000_003f, 0x0003_03ff_ffff_ffff, 0x0000_0000_0000_00fc,
+        0xffff_0080_1fff_ffff, 0xffff_0000_0000_003f, 0xffff_0000_0000_0003, 0x007f_ffff_0000_001f,
+        0x00ff_ffff_ffff_fff8, 0x0026_0000_0000_0000, 0x0000_ffff_ffff_fff8, 0x0000_01ff_ffff_0000,
+        0x0000_007f_ffff_fff8, 0x0047_ffff_ffff_0090, 0x0007_ffff_ffff_fff8, 0x0000_0000_1400_001e,
+        0x8000_0fff_fffb_ffff, 0xffff_01ff_bfff_bd7f, 0x0000_0003_e001_0000, 0x00bf_ffff_ffff_4bff,
+        0x0000_0000_000a_0000, 0x0000_0003_8000_0780, 0x0000_0000_0000_00b0, 0x0000_7fff_ffff_ffff,
+        0x0000_0000_0f00_0000, 0x0000_0000_0000_0010, 0x0100_07ff_ffff_ffff, 0x0000_0fff_ffff_ffff,
+        0x8000_0000_ffff_ffff, 0x8000_ffff_ff6f_f27f, 0x0000_0000_0000_0002, 0xffff_fcff_0000_0000,
+        0x0000_000a_0001_ffff, 0x0407_ffff_ffff_f801, 0xffff_ffff_f001_0000, 0xffff_0000_2000_03ff,
+        0x0000_0001_ffff_ffff, 0x0000_7fff_ffff_fdff, 0xfffc_0000_0000_0001, 0x0000_0000_0000_ffff,
+        0x0001_ffff_ffff_fb7f, 0xffff_fdbf_0000_0040, 0xffff_0000_0100_03ff, 0x0000_0000_0fff_ffff,
+        0x0007_ffff_0000_0000, 0x000f_ffff_fffd_fff4, 0x0001_0000_0000_0000, 0x0000_0000_0000_000f,
+        0x0001_ffff_ffff_ffff, 0xffff_ffff_0000_007e, 0x0000_0000_3fff_ffff, 0xffff_0000_7fff_ffff,
+        0x0000_3fff_ffff_0000, 0xe0ff_fff8_0000_000f, 0x0000_1fff_ffff_ffff, 0xf9ff_ffff_0000_0000,
+        0x0000_0000_000f_ffff, 0x0000_0000_0001_07ff, 0x0000_0000_fff8_0000, 0x007c_000b_0000_0000,
+        0x8000_0000_003f_ffff, 0x6fef_0000_0000_0000, 0x0004_0007_ffff_ffff, 0xffff_00f0_0027_0000,
+        0x0fff_ffff_ffff_ffff, 0x1fff_07ff_ffff_ffff, 0x0000_0000_03ff_01ff, 0xffff_ffff_ffdf_ffff,
+        0xebff_de64_dfff_ffff, 0xffff_ffff_ffff_ffef, 0x7bff_ffff_dfdf_e7bf, 0xffff_ffff_fffd_fc5f,
+        0xffff_ff3f_ffff_ffff, 0xf7ff_ffff_f7ff_fffd, 0xffdf_ffff_ffdf_ffff, 0xffff_7fff_ffff_7fff,
+        0xffff_fdff_ffff_fdff, 0x0000_0000_0000_0ff7, 0x0000_07e0_7fff_ffff, 0x0000_3fff_ffff_ffff,
+        0x3f80_1fff_ffff_ffff, 0x0000_0000_0000_4000, 0x0000_0fff_ffff_0000, 0x0001_3fff_ffff_0000,
+        0xc01f_3fb7_7fff_ffff, 0x7fff_6f7f_0000_0000, 0x0000_0000_0000_001f, 0x0000_0000_0000_080f,
+        0x0af7_fe96_ffff_ffef, 0x5ef7_f796_aa96_ea84, 0x0fff_fbee_0fff_fbff, 0x0000_0000_ffff_ffff,
+        0xffff_0001_ffff_ffff, 0xffff_ffff_ffff_07ff, 0x03ff_ffff_ffff_ffff,
+    ],
+};
+
+/// Characters with the `ID_Continue` property, from `DerivedCoreProperties.txt`
+#[rustfmt::skip]
+pub static ID_CONTINUE: CharTable = CharTable {
+    index: &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 17, 17, 17, 17, 18, 17,
+        19, 17, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 21, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 22, 23, 24, 25, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 26, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+        20, 47, 48, 17, 17, 17, 17, 49, 20, 20, 50, 20, 20, 20, 20, 20, 20, 51, 20, 52, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 53, 17, 17, 17, 20, 54, 55, 56, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 57, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 58,
+        59, 60, 17, 17, 17, 17, 61, 17, 17, 17, 17, 17, 17, 17, 62, 63, 64, 65, 66, 67, 17, 68, 17, 69,
+        70, 71, 72, 73, 74, 17, 17, 75, 17, 17, 17, 17, 17, 76, 17, 17, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 20, 77, 20, 20, 20, 20, 20, 20, 20, 20, 78, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 79,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 80, 20, 81, 17, 17, 17, 17, 20, 82, 17, 17,
+        20, 20, 20, 20, 20, 20, 20, 20, 20, 83, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+        20, 20, 84, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17,
+        17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 17, 85,
+    ],
+    chunks: &[
+        [0, 1, 2, 3, 4, 4, 4, 4], [4, 4, 4, 5, 4, 6, 7, 8],
+        [4, 4, 9, 4, 10, 11, 12, 13], [14, 15, 4, 16, 17, 18, 19, 20],
+        [21, 22, 23, 24, 4, 25, 26, 27], [28, 29, 30, 31, 32, 33, 34, 35],
+        [36, 37, 38, 39, 40, 41, 42, 43], [44, 45, 46, 47, 48, 49, 50, 51],
+        [4, 52, 53, 54, 4, 4, 4, 4], [4, 55, 56, 57, 58, 59, 60, 61],
+        [62, 4, 4, 4, 4, 4, 4, 4], [4, 63, 64, 65, 66, 67, 4, 68],
+        [69, 70, 71, 72, 73, 74, 75, 76], [77, 78, 79, 80, 4, 81, 4, 82],
+        [83, 84, 85, 86, 4, 4, 4, 4], [4, 4, 4, 4, 87, 88, 89, 90],
+        [91, 92, 93, 94, 95, 96, 97, 98], [98, 98, 98, 98, 98, 98, 98, 98],
+        [4, 4, 4, 99, 100, 101, 102, 103], [104, 62, 105, 4, 106, 4, 107, 108],
+        [4, 4, 4, 4, 4, 4, 4, 4], [4, 4, 4, 4, 4, 4, 4, 98],
+        [4, 4, 109, 110, 4, 4, 4, 4], [111, 112, 4, 19, 113, 4, 114, 115],
+        [116, 82, 4, 117, 118, 119, 4, 120], [121, 122, 4, 123, 124, 125, 4, 126],
+        [4, 4, 4, 4, 4, 4, 127, 128], [98, 98, 98, 98, 4, 4, 4, 4],
+        [4, 118, 4, 129, 130, 131, 19, 132], [4, 4, 4, 4, 133, 17, 134, 135],
+        [136, 137, 4, 138, 139, 140, 141, 142], [143, 144, 4, 145, 98, 146, 98, 147],
+        [98, 98, 148, 149, 150, 151, 53, 152], [4, 4, 153, 154, 155, 156, 157, 82],
+        [4, 4, 4, 4, 121, 158, 159, 98], [160, 161, 162, 163, 164, 129, 165, 98],
+        [166, 167, 168, 169, 72, 170, 171, 98], [4, 97, 172, 172, 173, 174, 175, 98],
+        [98, 98, 176, 177, 178, 179, 180, 181], [4, 182, 145, 183, 184, 185, 4, 186],
+        [187, 188, 189, 190, 191, 192, 193, 194], [4, 195, 4, 196, 98, 98, 197, 198],
+        [4, 199, 70, 200, 201, 202, 98, 98], [145, 98, 203, 204, 205, 206, 207, 208],
+        [141, 209, 210, 70, 98, 211, 98, 212], [213, 214, 215, 98, 216, 217, 218, 219],
+        [98, 98, 98, 220, 221, 222, 223, 98], [4, 4, 4, 4, 4, 4, 129, 98],
+        [4, 224, 4, 4, 4, 225, 98, 98], [98, 98, 98, 98, 98, 98, 17, 226],
+        [227, 228, 4, 4, 4, 4, 4, 4], [4, 4, 4, 4, 4, 4, 4, 145],
+        [4, 202, 98, 98, 98, 98, 98, 98], [98, 98, 98, 98, 229, 98, 98, 98],
+        [70, 230, 141, 231, 121, 232, 233, 98], [98, 98, 98, 98, 98, 234, 98, 98],
+        [98, 4, 235, 236, 4, 237, 238, 239], [4, 4, 4, 240, 162, 98, 4, 172],
+        [98, 98, 98, 98, 98, 98, 98, 241], [4, 4, 4, 4, 242, 243, 4, 4],
+        [4, 4, 4, 244, 98, 98, 98, 98], [4, 245, 246, 98, 98, 98, 98, 98],
+        [98, 98, 98, 0, 98, 98, 98, 98], [98, 98, 98, 98, 118, 202, 98, 98],
+        [98, 98, 98, 98, 98, 247, 248, 98], [98, 249, 98, 98, 98, 98, 98, 98],
+        [4, 250, 251, 252, 253, 254, 4, 4], [4, 4, 255, 256, 257, 258, 259, 260],
+        [261, 262, 263, 98, 98, 98, 98, 98], [98, 98, 98, 98, 264, 98, 98, 98],
+        [265, 21, 266, 98, 267, 268, 98, 98], [98, 98, 269, 229, 98, 98, 98, 98],
+        [98, 98, 98, 270, 98, 98, 98, 271], [98, 98, 98, 272, 98, 98, 98, 273],
+        [4, 4, 4, 274, 4, 275, 98, 98], [276, 277, 278, 98, 98, 98, 98, 98],
+        [98, 98, 98, 98, 98, 98, 98, 0], [4, 4, 4, 279, 4, 4, 4, 4],
+        [53, 4, 4, 4, 4, 4, 4, 4], [4, 4, 118, 4, 4, 4, 4, 4],
+        [4, 4, 4, 4, 4, 4, 4, 280], [4, 281, 98, 98, 98, 98, 98, 98],
+        [281, 98, 98, 98, 98, 98, 98, 98], [4, 4, 4, 4, 4, 282, 4, 4],
+        [4, 229, 98, 98, 98, 98, 98, 98], [98, 98, 98, 98, 4, 4, 4, 227],
+    ],
+    leaves: &[
+        0x03ff_0000_0000_0000, 0x07ff_fffe_87ff_fffe, 0x04a0_0400_0000_0000, 0xff7f_ffff_ff7f_ffff,
+        0xffff_ffff_ffff_ffff, 0x0000_501f_0003_ffc3, 0xbcdf_ffff_ffff_ffff, 0xffff_fffb_ffff_d7c0,
+        0xffbf_ffff_ffff_ffff, 0xffff_ffff_ffff_fcfb, 0xfffe_ffff_ffff_ffff, 0xffff_ffff_027f_ffff,
+        0xbfff_ffff_fffe_01ff, 0x0007_87ff_ffff_00b6, 0xffff_ffff_07ff_0000, 0xffff_c3ff_ffff_ffff,
+        0x9fff_fdff_9fef_ffff, 0xffff_ffff_ffff_0000, 0xffff_ffff_ffff_e7ff, 0x0003_ffff_ffff_ffff,
+        0x243f_ffff_ffff_ffff, 0x0000_3fff_ffff_ffff, 0xffff_07ff_0fff_ffff, 0xffff_ffff_ff80_feff,
+        0xffff_fffb_ffff_ffff, 0xfffe_ffcf_ffff_ffff, 0xf3c5_fdff_fff9_9fef, 0x5003_ffcf_b080_799f,
+        0xd36d_fdff_fff9_87ee, 0x003f_ffc0_5e02_3987, 0xf3ed_fdff_fffb_bfee, 0xfe00_ffcf_0001_3bbf,
+        0xf3ed_fdff_fff9_9fee, 0x0002_ffcf_b0e0_399f, 0xc3ff_c718_d63d_c7ec, 0x0000_ffc0_0081_3dc7,
+        0xf3ff_fdff_fffd_dfff, 0x0000_ffcf_3760_3ddf, 0xf3ef_fdff_fffd_dfef, 0x000e_ffcf_7060_3ddf,
+        0xffff_ffff_fffd_dfff, 0xfc00_ffcf_80f0_7ddf, 0x2ffb_ffff_fc7f_ffee, 0x000c_ffc0_ff5f_847f,
+        0x07ff_ffff_ffff_fffe, 0x0000_0000_03ff_7fff, 0x3fff_ffaf_ffff_f7d6, 0x0000_0000_f3ff_7f5f,
+        0xc2a0_03ff_0300_0001, 0xfffe_1fff_ffff_feff, 0x1fff_ffff_feff_ffdf, 0x0000_0000_0000_0040,
+        0xffff_ffff_ffff_03ff, 0xffff_ffff_3fff_ffff, 0xf7ff_ffff_ffff_20bf, 0xffff_ffff_3d7f_3dff,
+        0x7f3d_ffff_ffff_3dff, 0xffff_ffff_ff7f_ff3d, 0xffff_ffff_ff3d_ffff, 0x0003_fe00_e7ff_ffff,
+        0xffff_ffff_0000_ffff, 0x3f3f_ffff_ffff_ffff, 0xffff_ffff_ffff_fffe, 0xffff_9fff_ffff_ffff,
+        0xffff_ffff_07ff_fffe, 0x01ff_c7ff_ffff_ffff, 0x001f_ffff_803f_ffff, 0x000d_dfff_000f_ffff,
+        0x0000_03ff_308f_ffff, 0xffff_ffff_03ff_b800, 0x01ff_ffff_ffff_ffff, 0xffff_07ff_ffff_ffff,
+        0x003f_ffff_ffff_ffff, 0x0fff_0fff_7fff_ffff, 0x001f_3fff_ffff_ffc0, 0xffff_0fff_ffff_ffff,
+        0x0000_0000_07ff_03ff, 0xffff_ffff_0fff_ffff, 0x9fff_ffff_7fff_ffff, 0xbfff_0080_03ff_03ff,
+        0x0000_0fff_3fff_ffff, 0x000f_f800_03ff_1fff, 0x000f_ffff_ffff_ffff, 0x00ff_ffff_ffff_ffff,
+        0x3fff_ffff_ffff_e3ff, 0xe7ff_ffff_ffff_07ff, 0x07ff_ffff_fff7_0000, 0xffff_ffff_3f3f_ffff,
+        0x3fff_ffff_aaff_3f3f, 0x5fdf_ffff_ffff_ffff, 0x1fdc_1fff_0fcf_1fdc, 0x8000_0000_0000_3000,
+        0x8002_0000_0010_0001, 0x0000_0000_1fff_0000, 0x0001_ffe2_1fff_0000, 0xf3ff_fd50_3f2f_fc84,
+        0xffff_ffff_0000_43e0, 0x0000_0000_0000_01ff, 0x0000_0000_0000_0000, 0x000f_f81f_ffff_ffff,
+        0xffff_20bf_ffff_ffff, 0x8000_80ff_ffff_ffff, 0x7f7f_7f7f_007f_ffff, 0xffff_ffff_7f7f_7f7f,
+        0x1f3e_fffe_0000_00e0, 0xffff_fffe_fe7f_ffff, 0xfffe_ffff_ffff_ffe0, 0xffff_ffff_0000_7fff,
+        0xffff_0000_0000_0000, 0x0000_0000_0000_1fff, 0x3fff_ffff_ffff_0000, 0x0000_0fff_ffff_1fff,
+        0xbff0_ffff_ffff_ffff, 0xffff_fffc_ff80_0000, 0xffff_ffff_ffff_f9ff, 0xfffe_0000_1fff_ffff,
+        0x0000_10ff_ffff_ffff, 0xe8ff_ffff_03ff_003f, 0xffff_3fff_ffff_ffff, 0x1fff_ffff_000f_ffff,
+        0x7fff_ffff_03ff_8001, 0x007f_ffff_ffff_ffff, 0xfc7f_ffff_03ff_3fff, 0x007c_ffff_3800_0007,
+        0xffff_7f7f_007e_7e7e, 0xffff_03ff_f7ff_ffff, 0x03ff_37ff_ffff_ffff, 0xffff_000f_ffff_ffff,
+        0x0fff_ffff_ffff_f87f, 0x0000_0000_03ff_ffff, 0x5f7f_fdff_e0f8_007f, 0xffff_ffff_ffff_ffdb,
+        0xffff_ffff_fff8_0000, 0x3fff_ffff_ffff_ffff, 0xffff_ffff_fffc_ffff, 0x0fff_0000_0000_00ff,
+        0x0018_ffff_0000_ffff, 0xffdf_0000_0000_e000, 0x1fff_ffff_ffff_ffff, 0x87ff_fffe_03ff_0000,
+        0xffff_ffe0_07ff_fffe, 0x7fff_ffff_ffff_ffff, 0x0000_0000_1cfc_fcfc, 0xb7ff_ff7f_ffff_efff,
+        0x0000_0000_3fff_3fff, 0x07ff_ffff_ffff_ffff, 0x001f_ffff_ffff_ffff, 0x2000_0000_0000_0000,
+        0xffff_ffff_1fff_ffff, 0x0000_0001_0001_ffff, 0xffff_e000_ffff_ffff, 0x07ff_ffff_ffff_07ff,
+        0x0000_0000_003e_ff0f, 0xffff_03ff_3fff_ffff, 0x0fff_ffff_ff0f_ffff, 0xffff_00ff_ffff_ffff,
+        0xf7ff_000f_ffff_ffff, 0x1bfb_fffb_ffb7_f7ff, 0x0000_00ff_003f_ffff, 0x07fd_ffff_ffff_ffbf,
+        0x91bf_ffff_ffff_fd3f, 0x007f_ffff_003f_ffff, 0x0000_0000_7fff_ffff, 0x0037_ffff_0000_0000,
+        0x03ff_ffff_003f_ffff, 0xc0ff_ffff_ffff_ffff, 0x873f_ffff_feef_f06f, 0x1fff_ffff_0000_0000,
+        0x0000_0000_1fff_ffff, 0x0000_007f_ffff_feff, 0x0007_ffff_003f_ffff, 0x0000_0000_0003_ffff,
+        0x0007_ffff_ffff_ffff, 0x03ff_00ff_ffff_ffff, 0xffff_be3f_ffff_ffff, 0x0000_0000_0000_003f,
+        0x0003_1bff_ffff_ffff, 0xfc00_0000_0000_00fc, 0xffff_0080_1fff_ffff, 0xffff_0000_0001_ffff,
+        0xffff_0000_0000_003f, 0x007f_ffff_0000_001f, 0x803f_ffc0_0000_007f, 0x03ff_01ff_ffff_0004,
+        0xffdf_ffff_ffff_ffff, 0x004f_ffff_ffff_00f0, 0x0000_0000_17ff_de1f, 0xc0ff_ffff_fffb_ffff,
+        0x0000_0000_0000_0003, 0xffff_01ff_bfff_bd7f, 0x03ff_07ff_ffff_ffff, 0xfbed_fdff_fff9_9fef,
+        0x001f_1fcf_e081_399f, 0xffbf_ffff_ffff_4bff, 0x0000_0006_000f_f7a5, 0x0000_0003_c3ff_07ff,
+        0x0000_0000_03ff_00bf, 0xff3f_ffff_ffff_ffff, 0x0000_0000_3f00_0001, 0x0000_0000_03ff_0011,
+        0x0000_000f_ffff_03ff, 0x03ff_0fff_e7ff_ffff, 0x0000_0000_0000_007f, 0xffff_ffff_0000_0000,
+        0x8000_03ff_ffff_ffff, 0xf9bf_ffff_ff6f_f27f, 0x0000_0000_03ff_000f, 0xffff_fcff_0000_0000,
+        0x0000_001b_fcff_ffff, 0xffff_ffff_ffff_0080, 0xffff_0000_23ff_ffff, 0x0000_00ff_0000_0000,
+        0x03ff_0001_ffff_ffff, 0xff7f_ffff_ffff_fdff, 0xfffc_0000_03ff_0001, 0x007f_feff_fffc_ffff,
+        0xb47f_ffff_ffff_fb7f, 0xffff_fdbf_03ff_00ff, 0xffff_03ff_01fb_7fff, 0x0000_03ff_0fff_ffff,
+        0x007f_ffff_0000_0000, 0xc7ff_ffff_fffd_ffff, 0x0000_0000_07ff_0007, 0x0001_0000_0000_0000,
+        0x0000_7fff_ffff_ffff, 0x0000_0000_0000_000f, 0x0001_ffff_ffff_ffff, 0x0000_ffff_ffff_ffff,
+        0xffff_ffff_003f_ffff, 0x03ff_ffff_ffff_ffff, 0xffff_03ff_7fff_ffff, 0x001f_3fff_ffff_03ff,
+        0xe0ff_fff8_03ff_000f, 0x0000_0000_0000_ffff, 0x03ff_1fff_ffff_ffff, 0xf9ff_ffff_0000_0000,
+        0x0000_0000_000f_ffff, 0xffff_ffff_ffff_87ff, 0x0000_0000_ffff_80ff, 0x007f_001b_0000_0000,
+        0x8000_0000_003f_ffff, 0x6fef_0000_0000_0000, 0x0004_0007_ffff_ffff, 0xffff_00f0_0027_0000,
+        0x0fff_ffff_ffff_ffff, 0x1fff_07ff_ffff_ffff, 0x0000_0000_63ff_01ff, 0xf807_e3e0_0000_0000,
+        0x0000_3c00_0000_0fe7, 0x0000_0000_0000_001c, 0xffff_ffff_ffdf_ffff, 0xebff_de64_dfff_ffff,
+        0xffff_ffff_ffff_ffef, 0x7bff_ffff_dfdf_e7bf, 0xffff_ffff_fffd_fc5f, 0xffff_ff3f_ffff_ffff,
+        0xf7ff_ffff_f7ff_fffd, 0xffdf_ffff_ffdf_ffff, 0xffff_7fff_ffff_7fff, 0xffff_fdff_ffff_fdff,
+        0xffff_ffff_ffff_cff7, 0xf87f_ffff_ffff_ffff, 0x0020_1fff_ffff_ffff, 0x0000_fffe_f800_0010,
+        0x0000_07e0_7fff_ffff, 0xffff_07db_f9ff_ff7f, 0x0000_0000_0000_8000, 0x3fff_1fff_ffff_ffff,
+        0x0000_0000_0000_43ff, 0x0000_7fff_ffff_0000, 0x03ff_ffff_ffff_0000, 0x07ff_ffff_ffff_0000,
+        0xc03f_ffff_7fff_ffff, 0x7fff_6f7f_0000_0000, 0x0000_0000_007f_001f, 0x0000_0000_03ff_0fff,
+        0x0af7_fe96_ffff_ffef, 0x5ef7_f796_aa96_ea84, 0x0fff_fbee_0fff_fbff, 0x0000_0000_ffff_ffff,
+        0xffff_0001_ffff_ffff, 0x0000_0000_3fff_ffff, 0xffff_ffff_ffff_07ff,
+    ],
+};
+
+/// Characters of the `Space_Separator` (`Zs`) general category, from `extracted/DerivedGeneralCategory.txt`
+#[rustfmt::skip]
+pub static SPACE_SEPARATOR: CharTable = CharTable {
+    index: &[
+        0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 1,
+        4,
+    ],
+    chunks: &[
+        [0, 1, 0, 1, 1, 1, 1, 1], [1, 1, 1, 1, 1, 1, 1, 1],
+        [1, 1, 2, 1, 1, 1, 1, 1], [3, 4, 1, 1, 1, 1, 1, 1],
+        [2, 1, 1, 1, 1, 1, 1, 1],
+    ],
+    leaves: &[
+        0x0000_0001_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0001, 0x0000_8000_0000_07ff,
+        0x0000_0000_8000_0000,
+    ],
+};
@@ -0,0 +1,227 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_ecmascript::{ConstantEvaluation, ConstantValue};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionalCompilationOptions {
+    /// Values of the flags, e.g. `{ "DEBUG": false, "EDITION_PRO": true }`
+    #[serde(default)]
+    pub defines: FxHashMap<String, bool>,
+}
+
+/// Conditional compilation
+///
+/// Removes the code which is inactive for the values of the flags in
+/// [`ConditionalCompilationOptions::defines`], for builds of several editions of an SDK from
+/// the same sources:
+///
+/// * Statements between `/* #if FLAG */`, `/* #else */` and `/* #endif */` comment pragmas,
+///   which nest and may be single line comments. `#if !FLAG` negates a flag, flags which are
+///   not defined are `false`.
+/// * `if` statements whose test only consists of defined flags, `!`, `&&` and `||`,
+///   e.g. `if (DEBUG && !EDITION_PRO) { ... }`, are replaced by the active branch.
+///   Flags shadowed by a binding are left alone.
+///
+/// The remaining nodes keep their spans, so source maps still point at the original code.
+pub struct ConditionalCompilation<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ConditionalCompilationOptions,
+    /// Spans of the inactive regions of the comment pragmas, in source order
+    inactive_regions: std::vec::Vec<Span>,
+}
+
+/// A `#if` pragma which is not closed yet
+struct Pragma {
+    /// Whether the code around the `#if` is active
+    parent_active: bool,
+    /// Whether the current branch is active
+    active: bool,
+}
+
+impl<'a> ConditionalCompilation<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.conditional_compilation.clone().map(|options| Self {
+            ast,
+            ctx,
+            options,
+            inactive_regions: vec![],
+        })
+    }
+
+    /// Find the inactive regions of the comment pragmas
+    pub fn transform_program(&mut self, program: &Program<'a>) {
+        let semantic = self.ctx.semantic();
+        let source_text = semantic.source_text();
+        let mut stack: std::vec::Vec<Pragma> = vec![];
+        let mut inactive_start = None;
+        for (_, span) in semantic.trivias().comments_spans() {
+            let text = span.source_text(source_text).trim();
+            let active = stack.last().map_or(true, |pragma| pragma.active);
+            if let Some(condition) = text.strip_prefix("#if ") {
+                let condition = self.evaluate_pragma(condition.trim());
+                stack.push(Pragma { parent_active: active, active: active && condition });
+            } else if text == "#else" {
+                let Some(pragma) = stack.last_mut() else { continue };
+                pragma.active = pragma.parent_active && !pragma.active;
+            } else if text == "#endif" {
+                stack.pop();
+            } else {
+                continue;
+            }
+            let active = stack.last().map_or(true, |pragma| pragma.active);
+            match (inactive_start, active) {
+                (None, false) => inactive_start = Some(span.end),
+                (Some(start), true) => {
+                    self.inactive_regions.push(Span::new(start, span.start));
+                    inactive_start = None;
+                }
+                _ => {}
+            }
+        }
+        // An unterminated `#if`
+        if let Some(start) = inactive_start {
+            self.inactive_regions.push(Span::new(start, program.span.end));
+        }
+    }
+
+    /// Remove the inactive statements of a statement list
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.inactive_regions.is_empty() {
+            stmts.retain(|stmt| !self.is_inactive(stmt.span()));
+        }
+        let mut stmts_to_keep = self.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in self.ast.move_statement_vec(stmts) {
+            if self.transform_if_statement(&mut stmt) {
+                stmts_to_keep.push(stmt);
+            }
+        }
+        *stmts = stmts_to_keep;
+    }
+
+    /// Replace an `if` statement depending on the flags only, outside of statement lists
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !self.transform_if_statement(stmt) {
+            *stmt = self.ast.empty_statement(stmt.span());
+        }
+    }
+
+    /// Replace an `if` statement depending on the flags only by its active branch,
+    /// `false` if there is no active branch.
+    fn transform_if_statement(&self, stmt: &mut Statement<'a>) -> bool {
+        loop {
+            let Statement::IfStatement(if_stmt) = stmt else { return true };
+            let Some(condition) = self.evaluate(&if_stmt.test) else { return true };
+            let branch = if condition {
+                Some(self.ast.move_statement(&mut if_stmt.consequent))
+            } else {
+                if_stmt.alternate.as_mut().map(|alternate| self.ast.move_statement(alternate))
+            };
+            let Some(branch) = branch else { return false };
+            *stmt = branch;
+        }
+    }
+
+    fn is_inactive(&self, span: Span) -> bool {
+        let index = self.inactive_regions.partition_point(|region| region.end < span.end);
+        self.inactive_regions
+            .get(index)
+            .is_some_and(|region| region.start <= span.start && span.end <= region.end)
+    }
+
+    /// Value of an `if` test consisting of flags, `None` if it depends on other values
+    fn evaluate(&self, expr: &Expression<'a>) -> Option<bool> {
+        self.is_flag_condition(expr).then(|| self.eval_to_boolean(expr)).flatten()
+    }
+
+    /// Whether the expression only consists of defined flags, `!`, `&&` and `||`
+    fn is_flag_condition(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => self.eval_defined_identifier(ident).is_some(),
+            Expression::ParenthesizedExpression(expr) => self.is_flag_condition(&expr.expression),
+            Expression::UnaryExpression(expr) => {
+                expr.operator == UnaryOperator::LogicalNot && self.is_flag_condition(&expr.argument)
+            }
+            Expression::LogicalExpression(expr) => {
+                expr.operator != LogicalOperator::Coalesce
+                    && self.is_flag_condition(&expr.left)
+                    && self.is_flag_condition(&expr.right)
+            }
+            _ => false,
+        }
+    }
+
+    /// Value of the condition of a `#if` pragma, `FLAG` or `!FLAG`
+    fn evaluate_pragma(&self, condition: &str) -> bool {
+        match condition.strip_prefix('!') {
+            Some(flag) => !self.flag(flag.trim()),
+            None => self.flag(condition),
+        }
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.defines.get(name).copied().unwrap_or(false)
+    }
+}
+
+impl<'a> ConstantEvaluation<'a> for ConditionalCompilation<'a> {
+    fn is_global_reference(&self, ident: &IdentifierReference) -> bool {
+        ident
+            .reference_id
+            .get()
+            .is_some_and(|reference_id| self.ctx.symbols().is_global_reference(reference_id))
+    }
+
+    fn eval_defined_identifier(
+        &self,
+        ident: &IdentifierReference,
+    ) -> Option<ConstantValue<'static>> {
+        if !self.is_global_reference(ident) {
+            return None;
+        }
+        self.options.defines.get(ident.name.as_str()).copied().map(ConstantValue::Boolean)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{tester::Tester, ConditionalCompilationOptions, TransformOptions};
+
+    #[test]
+    fn test() {
+        let defines = [("DEBUG", false), ("PRO", true)]
+            .into_iter()
+            .map(|(flag, value)| (flag.to_string(), value))
+            .collect();
+        let options = TransformOptions {
+            conditional_compilation: Some(ConditionalCompilationOptions { defines }),
+            ..TransformOptions::default()
+        };
+        let tester = Tester::new("test.js", options);
+        tester.test(&[
+            ("a();\n/* #if DEBUG */\nlog();\n/* #endif */\nb();", "a(); b();"),
+            ("// #if PRO\npro();\n// #else\nfree();\n// #endif", "pro();"),
+            ("/* #if !PRO */\nfree();\n/* #if DEBUG */\nlog();\n/* #endif */\n/* #endif */\nc();", "c();"),
+            ("/* #if MISSING */\nx();", ""),
+            ("if (DEBUG) { log(); } else { run(); }", "{ run(); }"),
+            ("if (PRO && !DEBUG) pro();", "pro();"),
+            ("if (DEBUG) log();\nrun();", "run();"),
+            ("if (x) if (DEBUG) log();", "if (x) ;"),
+            ("if (DEBUG || x) log();", "if (DEBUG || x) log();"),
+            ("function f(DEBUG) { if (DEBUG) log(); }", "function f(DEBUG) { if (DEBUG) log(); }"),
+        ]);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{borrow::Cow, mem, rc::Rc};
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CssInJsOptions {
+    /// Import sources of the CSS-in-JS libraries,
+    /// defaults to `styled-components`, `@linaria/core` and `@linaria/react`
+    #[serde(default = "default_libraries")]
+    pub libraries: Vec<Cow<'static, str>>,
+}
+
+impl Default for CssInJsOptions {
+    fn default() -> Self {
+        Self { libraries: default_libraries() }
+    }
+}
+
+fn default_libraries() -> Vec<Cow<'static, str>> {
+    vec!["styled-components".into(), "@linaria/core".into(), "@linaria/react".into()]
+}
+
+/// A tagged template or call expression of a CSS-in-JS library,
+/// e.g. ``styled.div`color: ${color};` `` or `css("color: red")`
+#[derive(Debug)]
+pub struct CssInJsExpression<'s> {
+    /// Span of the whole expression
+    pub span: Span,
+    /// Import source of the library
+    pub library: &'s str,
+    /// Imported name of the binding the tag or callee is based on,
+    /// `default` for default imports and `*` for namespace imports
+    pub imported: &'s str,
+    /// Source text of the tag or callee, e.g. `styled.div` or `styled(Button)`
+    pub tag: &'s str,
+    /// Static parts around the interpolations, one more than `expressions`.
+    ///
+    /// Template literals are passed raw so CSS escapes are kept,
+    /// the arguments of a call are joined into a single template.
+    pub quasis: Vec<&'s str>,
+    /// Spans of the interpolated expressions
+    pub expressions: Vec<Span>,
+}
+
+/// Receives every CSS-in-JS expression, returning a string replaces the expression with
+/// a string literal, e.g. the class name of the extracted styles.
+pub type CssInJsHook<'a> = Box<dyn FnMut(&CssInJsExpression) -> Option<String> + 'a>;
+
+/// CSS-in-JS extraction
+///
+/// Calls the hook set by [`crate::Transformer::with_css_in_js_hook`] for tagged templates and
+/// calls whose tag or callee is based on a binding imported from one of the configured libraries,
+/// so zero-runtime CSS extraction tools can plug into the transformer.
+///
+/// References:
+/// * <https://styled-components.com/docs/tooling#babel-plugin>
+/// * <https://github.com/callstack/linaria/blob/master/docs/HOW_IT_WORKS.md>
+pub struct CssInJs<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: CssInJsOptions,
+    hook: Option<CssInJsHook<'a>>,
+    /// Bindings imported from the libraries, symbol -> (library, imported name)
+    imports: FxHashMap<SymbolId, (Atom, Atom)>,
+    /// Calls which are part of a visited tag or callee, e.g. `styled(Button)` in ``styled(Button)`...` ``
+    tags: FxHashSet<Span>,
+}
+
+impl<'a> CssInJs<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.css_in_js.clone().map(|options| Self {
+            ast,
+            ctx,
+            options,
+            hook: None,
+            imports: FxHashMap::default(),
+            tags: FxHashSet::default(),
+        })
+    }
+
+    pub fn set_hook(&mut self, hook: CssInJsHook<'a>) {
+        self.hook = Some(hook);
+    }
+
+    /// Collect the bindings imported from the libraries
+    pub fn transform_program(&mut self, program: &Program<'a>) {
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(module_decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**module_decl else { continue };
+            if !self.options.libraries.iter().any(|library| decl.source.value == library) {
+                continue;
+            }
+            for specifier in decl.specifiers.iter().flatten() {
+                let (local, imported) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        (&specifier.local, specifier.imported.name().clone())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, Atom::from("default"))
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        (&specifier.local, Atom::from("*"))
+                    }
+                };
+                if let Some(symbol_id) = local.symbol_id.get() {
+                    self.imports.insert(symbol_id, (decl.source.value.clone(), imported));
+                }
+            }
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.hook.is_none() || self.imports.is_empty() {
+            return;
+        }
+        let source_text = self.ctx.semantic().source_text();
+        let (span, tag, quasis, expressions) = match &*expr {
+            Expression::TaggedTemplateExpression(tagged) => {
+                let quasis =
+                    tagged.quasi.quasis.iter().map(|quasi| quasi.value.raw.as_str()).collect();
+                let expressions =
+                    tagged.quasi.expressions.iter().map(GetSpan::span).collect::<Vec<_>>();
+                (tagged.span, &tagged.tag, quasis, expressions)
+            }
+            Expression::CallExpression(call) => {
+                if self.tags.contains(&call.span) {
+                    return;
+                }
+                let (quasis, expressions) = self.call_parts(&call.arguments);
+                (call.span, &call.callee, quasis, expressions)
+            }
+            _ => return,
+        };
+        let Some((library, imported)) = self.library_binding(tag) else { return };
+        self.collect_tags(tag);
+
+        let expression = CssInJsExpression {
+            span,
+            library: library.as_str(),
+            imported: imported.as_str(),
+            tag: tag.span().source_text(source_text),
+            quasis,
+            expressions,
+        };
+        let Some(hook) = self.hook.as_mut() else { return };
+        if let Some(replacement) = hook(&expression) {
+            *expr =
+                self.ast.literal_string_expression(StringLiteral::new(span, replacement.into()));
+        }
+    }
+
+    /// The library and imported name of the binding `tag` is based on,
+    /// following member expressions and calls, e.g. `styled` in `styled.div.attrs({})`
+    fn library_binding(&self, tag: &Expression<'a>) -> Option<(Atom, Atom)> {
+        let mut expr = tag.get_inner_expression();
+        loop {
+            expr = match expr {
+                Expression::MemberExpression(member) => member.object(),
+                Expression::CallExpression(call) => &call.callee,
+                Expression::Identifier(ident) => {
+                    let reference_id = ident.reference_id.get()?;
+                    let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+                    return self.imports.get(&symbol_id).cloned();
+                }
+                _ => return None,
+            }
+            .get_inner_expression();
+        }
+    }
+
+    /// Remember the calls inside `tag` so they are not reported on their own
+    fn collect_tags(&mut self, tag: &Expression<'a>) {
+        let mut expr = tag.get_inner_expression();
+        loop {
+            expr = match expr {
+                Expression::MemberExpression(member) => member.object(),
+                Expression::CallExpression(call) => {
+                    self.tags.insert(call.span);
+                    &call.callee
+                }
+                _ => return,
+            }
+            .get_inner_expression();
+        }
+    }
+
+    /// Join the arguments of a call into static parts and interpolations,
+    /// string literals and template literals are static, anything else is interpolated.
+    fn call_parts(&self, arguments: &[Argument<'a>]) -> (Vec<&'a str>, Vec<Span>) {
+        let mut quasis = vec![];
+        let mut expressions = vec![];
+        let mut quasi = String::new();
+        for argument in arguments {
+            match argument {
+                Argument::Expression(Expression::StringLiteral(literal)) => {
+                    quasi.push_str(&literal.value);
+                }
+                Argument::Expression(Expression::TemplateLiteral(template)) => {
+                    for (element, expr) in template.quasis.iter().zip(&template.expressions) {
+                        quasi.push_str(&element.value.raw);
+                        quasis.push(self.ast.new_str(&mem::take(&mut quasi)));
+                        expressions.push(expr.span());
+                    }
+                    if let Some(element) = template.quasis.last() {
+                        quasi.push_str(&element.value.raw);
+                    }
+                }
+                Argument::Expression(expr) => {
+                    quasis.push(self.ast.new_str(&mem::take(&mut quasi)));
+                    expressions.push(expr.span());
+                }
+                Argument::SpreadElement(spread) => {
+                    quasis.push(self.ast.new_str(&mem::take(&mut quasi)));
+                    expressions.push(spread.span);
+                }
+            }
+        }
+        quasis.push(self.ast.new_str(&quasi));
+        (quasis, expressions)
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_allocator::Allocator;
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::Transformer;
+
+    let source_text = r#"
+import styled, { css } from "styled-components";
+import { css as other } from "other";
+const a = styled.div`color: ${color};`;
+const b = styled(Button).attrs({ x: 1 })`margin: 0;`;
+const c = css("color: red;", `width: ${width}px;`, height);
+const d = other`color: blue;`;
+function f(css) {
+    return css`color: green;`;
+}
+"#;
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let semantic = SemanticBuilder::new(source_text, source_type).build(&program).semantic;
+    let program = allocator.alloc(program);
+    let options = TransformOptions {
+        css_in_js: Some(CssInJsOptions::default()),
+        ..TransformOptions::default()
+    };
+
+    let mut calls = vec![];
+    Transformer::new(&allocator, source_type, semantic, options)
+        .with_css_in_js_hook(|expr| {
+            let expressions = expr
+                .expressions
+                .iter()
+                .map(|span| span.source_text(source_text))
+                .collect::<Vec<_>>()
+                .join(", ");
+            calls.push(format!("{} {} {:?} [{expressions}]", expr.imported, expr.tag, expr.quasis));
+            // Extract the styled components only
+            (expr.imported == "default").then(|| format!("class{}", calls.len()))
+        })
+        .build(program)
+        .unwrap();
+    let code = Codegen::<false>::new(source_text.len(), CodegenOptions::default()).build(program);
+
+    assert_eq!(
+        calls,
+        [
+            r#"default styled.div ["color: ", ";"] [color]"#,
+            r#"default styled(Button).attrs({ x: 1 }) ["margin: 0;"] []"#,
+            r#"css css ["color: red;width: ", "px;", ""] [width, height]"#,
+        ]
+    );
+    assert!(code.contains("const a = 'class1';"), "{code}");
+    assert!(code.contains("const b = 'class2';"), "{code}");
+    assert!(code.contains("const c = css("), "{code}");
+}
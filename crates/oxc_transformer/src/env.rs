@@ -0,0 +1,136 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Environment variable `{0}` is not defined.")]
+#[diagnostic(help("Define it in an env file or in the environment of the build."))]
+struct UndefinedVariable(Atom, #[label] Span);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvOptions {
+    /// Values of the variables, e.g. loaded from `.env` files
+    #[serde(default)]
+    pub variables: FxHashMap<String, String>,
+    /// Only variables starting with one of these are inlined, so secrets of the build
+    /// environment are not exposed to the client, defaults to `VITE_`
+    #[serde(default = "default_prefixes")]
+    pub prefixes: Vec<String>,
+}
+
+impl Default for EnvOptions {
+    fn default() -> Self {
+        Self { variables: FxHashMap::default(), prefixes: default_prefixes() }
+    }
+}
+
+fn default_prefixes() -> Vec<String> {
+    vec!["VITE_".to_string()]
+}
+
+/// Environment variables
+///
+/// Replaces `import.meta.env.NAME` and `process.env.NAME` with the value of `NAME` in
+/// [`EnvOptions::variables`] as a string literal when `NAME` starts with one of
+/// [`EnvOptions::prefixes`], like Vite does. Variables with an allowed prefix which are not
+/// defined are reported, others are left alone.
+///
+/// References:
+/// * <https://vitejs.dev/guide/env-and-mode>
+pub struct Env<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: EnvOptions,
+}
+
+impl<'a> Env<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.env.clone().map(|options| Self { ast, ctx, options })
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::MemberExpression(member_expr) = expr else { return };
+        if !self.is_env_object(member_expr.object()) {
+            return;
+        }
+        let Some((_, name)) = member_expr.static_property_info() else { return };
+        if !self.options.prefixes.iter().any(|prefix| name.starts_with(prefix.as_str())) {
+            return;
+        }
+        let span = member_expr.span();
+        if let Some(value) = self.options.variables.get(name) {
+            let value = StringLiteral::new(span, value.as_str().into());
+            *expr = self.ast.literal_string_expression(value);
+        } else {
+            let name = Atom::from(name);
+            self.ctx.error(UndefinedVariable(name, span));
+        }
+    }
+
+    /// `import.meta.env` and `process.env`, unless `process` is a local binding
+    fn is_env_object(&self, expr: &Expression<'a>) -> bool {
+        let Expression::MemberExpression(member_expr) = expr else { return false };
+        if member_expr.static_property_name() != Some("env") {
+            return false;
+        }
+        match member_expr.object() {
+            Expression::MetaProperty(meta) => {
+                meta.meta.name == "import" && meta.property.name == "meta"
+            }
+            Expression::Identifier(ident) => {
+                ident.name == "process"
+                    && ident.reference_id.get().map_or(true, |reference_id| {
+                        self.ctx.symbols().is_global_reference(reference_id)
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashMap;
+
+    use super::EnvOptions;
+    use crate::{tester::Tester, TransformOptions};
+
+    #[test]
+    fn env() {
+        let variables = FxHashMap::from_iter([
+            ("VITE_API".to_string(), "https://api.example.com".to_string()),
+            ("SECRET".to_string(), "hunter2".to_string()),
+        ]);
+        let options = TransformOptions {
+            env: Some(EnvOptions { variables, ..EnvOptions::default() }),
+            ..TransformOptions::default()
+        };
+        let tests = [
+            ("fetch(import.meta.env.VITE_API)", "fetch('https://api.example.com')"),
+            ("x = process.env['VITE_API']", "x = 'https://api.example.com'"),
+            (
+                "import.meta.env.SECRET; process.env.NODE_ENV",
+                "import.meta.env.SECRET; process.env.NODE_ENV",
+            ),
+            (
+                "function f(process) { process.env.VITE_API }",
+                "function f(process) { process.env.VITE_API }",
+            ),
+        ];
+        Tester::new("test.mjs", options).test(&tests);
+    }
+}
@@ -60,7 +60,7 @@ impl<'a> ShorthandProperties<'a> {
 
         // Convert `[__proto__]` to `["__proto__"]`
 
-        let proto = StringLiteral { span: obj_prop.key.span(), value: "__proto__".into() };
+        let proto = StringLiteral::new(obj_prop.key.span(), "__proto__".into());
         let expr = self.ast.literal_string_expression(proto);
         obj_prop.key = PropertyKey::Expression(expr);
     }
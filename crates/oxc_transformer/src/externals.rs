@@ -0,0 +1,329 @@
+use std::{borrow::Cow, rc::Rc};
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::{Atom, Span, SPAN};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions, utils::is_valid_identifier};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Re-exports of `{0}` cannot be replaced by a global.")]
+#[diagnostic(severity(warning), help("Import the bindings and export them instead."))]
+struct ReExportOfGlobal(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalsOptions {
+    /// Bare specifier -> replacement, e.g.
+    /// `{ "react": { "global": "window.React" }, "lodash-es": { "url": "https://esm.sh/lodash-es" } }`
+    #[serde(default)]
+    pub externals: FxHashMap<String, External>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum External {
+    /// Read the module from a global, a dotted path like `window.React`
+    Global(String),
+    /// Import the module from a URL, subpaths of the specifier are appended to it
+    Url(String),
+}
+
+/// The replacement of an imported specifier
+enum Target<'s> {
+    Global(&'s str),
+    Url(Cow<'s, str>),
+}
+
+/// Externalize dependencies
+///
+/// Rewrites the imports of the configured bare specifiers, so browser builds can load their
+/// dependencies from a CDN or from the globals of UMD scripts without a bundler:
+///
+/// * [`External::Url`]: the specifiers of imports, re-exports and `import()` are replaced by the
+///   URL, e.g. `import { debounce } from "lodash-es/debounce"` becomes
+///   `import { debounce } from "https://esm.sh/lodash-es/debounce"`.
+/// * [`External::Global`]: imports become constants read from the global,
+///   `import React, { useState as s } from "react"` becomes
+///   `const React = window.React, s = window.React.useState`.
+///   `import("react")` becomes `Promise.resolve(window.React)` and `require("react")` the global.
+///   Re-exports are reported, they have no local binding to read the global into.
+pub struct Externals<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ExternalsOptions,
+}
+
+impl<'a> Externals<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.externals.clone().map(|options| Self { ast, ctx, options })
+    }
+
+    /// Rewrite the import and export declarations
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        let mut removed = false;
+        for stmt in program.body.iter_mut() {
+            let Statement::ModuleDeclaration(module_decl) = stmt else { continue };
+            match &mut **module_decl {
+                ModuleDeclaration::ImportDeclaration(decl) => {
+                    if decl.import_kind.is_type() {
+                        continue;
+                    }
+                    match self.resolve(&decl.source.value) {
+                        Some(Target::Url(url)) => decl.source.value = url.into(),
+                        Some(Target::Global(global)) => {
+                            let global = global.to_string();
+                            if let Some(new_stmt) = self.import_global(decl, &global) {
+                                *stmt = new_stmt;
+                            } else {
+                                *stmt = self.ast.empty_statement(SPAN);
+                                removed = true;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    let Some(source) = &mut decl.source else { continue };
+                    self.transform_re_export(source);
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    self.transform_re_export(&mut decl.source);
+                }
+                _ => {}
+            }
+        }
+        if removed {
+            program.body.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+        }
+    }
+
+    /// Replace `import("react")` and `require("react")`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ImportExpression(import_expr) => {
+                let Expression::StringLiteral(source) = &mut import_expr.source else { return };
+                match self.resolve(&source.value) {
+                    Some(Target::Url(url)) => source.value = url.into(),
+                    Some(Target::Global(global)) => {
+                        let global = self.global_expression(global);
+                        let callee = self.ast.static_member_expression(
+                            SPAN,
+                            self.ast.identifier_reference_expression(IdentifierReference::new(
+                                SPAN,
+                                "Promise".into(),
+                            )),
+                            IdentifierName::new(SPAN, "resolve".into()),
+                            false,
+                        );
+                        let arguments = self.ast.new_vec_single(Argument::Expression(global));
+                        *expr = self.ast.call_expression(
+                            import_expr.span,
+                            callee,
+                            arguments,
+                            false,
+                            None,
+                        );
+                    }
+                    None => {}
+                }
+            }
+            Expression::CallExpression(call_expr) => {
+                let Expression::Identifier(callee) = &call_expr.callee else { return };
+                if callee.name != "require" || call_expr.arguments.len() != 1 {
+                    return;
+                }
+                let is_global = callee.reference_id.get().map_or(true, |reference_id| {
+                    self.ctx.symbols().is_global_reference(reference_id)
+                });
+                if !is_global {
+                    return;
+                }
+                let Argument::Expression(Expression::StringLiteral(source)) =
+                    &call_expr.arguments[0]
+                else {
+                    return;
+                };
+                // `require` of a URL does not load it, only globals are replaced
+                if let Some(Target::Global(global)) = self.resolve(&source.value) {
+                    *expr = self.global_expression(global);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn transform_re_export(&mut self, source: &mut StringLiteral) {
+        let is_global = match self.resolve(&source.value) {
+            Some(Target::Url(url)) => {
+                source.value = url.into();
+                false
+            }
+            Some(Target::Global(_)) => true,
+            None => false,
+        };
+        if is_global {
+            self.ctx.error(ReExportOfGlobal(source.value.clone(), source.span));
+        }
+    }
+
+    /// `const React = window.React, s = window.React.useState`,
+    /// `None` for side effect imports like `import "react"`
+    fn import_global(&self, decl: &ImportDeclaration<'a>, global: &str) -> Option<Statement<'a>> {
+        let kind = VariableDeclarationKind::Const;
+        let mut declarators = self.ast.new_vec();
+        for specifier in decl.specifiers.iter().flatten() {
+            let (local, init) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let imported = specifier.imported.name();
+                    let init = if imported == "default" {
+                        self.global_expression(global)
+                    } else if is_valid_identifier(imported, true) {
+                        self.ast.static_member_expression(
+                            SPAN,
+                            self.global_expression(global),
+                            IdentifierName::new(SPAN, imported.clone()),
+                            false,
+                        )
+                    } else {
+                        self.ast.computed_member_expression(
+                            SPAN,
+                            self.global_expression(global),
+                            self.ast.literal_string_expression(StringLiteral::new(
+                                SPAN,
+                                imported.clone(),
+                            )),
+                            false,
+                        )
+                    };
+                    (&specifier.local, init)
+                }
+                // UMD globals have no default export, the global is the module
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (&specifier.local, self.global_expression(global))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    (&specifier.local, self.global_expression(global))
+                }
+            };
+            let binding = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(
+                    local.span,
+                    local.name.clone(),
+                )),
+                None,
+                false,
+            );
+            declarators.push(self.ast.variable_declarator(SPAN, kind, binding, Some(init), false));
+        }
+        if declarators.is_empty() {
+            return None;
+        }
+        let decl = self.ast.variable_declaration(decl.span, kind, declarators, Modifiers::empty());
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+
+    /// `window.React` for the path `window.React`
+    fn global_expression(&self, global: &str) -> Expression<'a> {
+        let mut parts = global.split('.');
+        let first = parts.next().unwrap_or_default();
+        let mut expr =
+            self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, first.into()));
+        for part in parts {
+            expr = self.ast.static_member_expression(
+                SPAN,
+                expr,
+                IdentifierName::new(SPAN, part.into()),
+                false,
+            );
+        }
+        expr
+    }
+
+    fn resolve(&self, specifier: &str) -> Option<Target<'_>> {
+        if let Some(external) = self.options.externals.get(specifier) {
+            return Some(match external {
+                External::Global(global) => Target::Global(global),
+                External::Url(url) => Target::Url(Cow::Borrowed(url)),
+            });
+        }
+        // Subpaths of a URL external, e.g. `lodash-es/debounce`
+        let (name, subpath) = split_package_name(specifier)?;
+        match self.options.externals.get(name)? {
+            External::Url(url) => {
+                Some(Target::Url(Cow::Owned(format!("{}/{subpath}", url.trim_end_matches('/')))))
+            }
+            External::Global(_) => None,
+        }
+    }
+}
+
+/// `("@scope/name", "subpath")` for `@scope/name/subpath`
+fn split_package_name(specifier: &str) -> Option<(&str, &str)> {
+    let name_end = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        scope_end + 1 + specifier[scope_end + 1..].find('/')?
+    } else {
+        specifier.find('/')?
+    };
+    let subpath = &specifier[name_end + 1..];
+    (!subpath.is_empty()).then(|| (&specifier[..name_end], subpath))
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashMap;
+
+    use super::{split_package_name, External, ExternalsOptions};
+    use crate::{tester::Tester, TransformOptions};
+
+    #[test]
+    fn package_name() {
+        assert_eq!(split_package_name("react"), None);
+        assert_eq!(split_package_name("react/jsx-runtime"), Some(("react", "jsx-runtime")));
+        assert_eq!(split_package_name("@scope/name"), None);
+        assert_eq!(split_package_name("@scope/name/a/b"), Some(("@scope/name", "a/b")));
+    }
+
+    #[test]
+    fn externals() {
+        let externals = FxHashMap::from_iter([
+            ("react".to_string(), External::Global("window.React".to_string())),
+            ("lodash-es".to_string(), External::Url("https://esm.sh/lodash-es/".to_string())),
+        ]);
+        let options = TransformOptions {
+            externals: Some(ExternalsOptions { externals }),
+            ..TransformOptions::default()
+        };
+        let tests = [
+            (
+                "import React, { useState as s, 'a-b' as ab } from 'react'; s();",
+                "const React = window.React, s = window.React.useState, ab = window.React['a-b']; s();",
+            ),
+            ("import * as R from 'react'; import 'react'; R;", "const R = window.React; R;"),
+            ("import('react'); require('react');", "Promise.resolve(window.React); window.React;"),
+            (
+                "import { debounce } from 'lodash-es/debounce'; export * from 'lodash-es';",
+                "import { debounce } from 'https://esm.sh/lodash-es/debounce'; export * from 'https://esm.sh/lodash-es/';",
+            ),
+            ("import('lodash-es'); import x from 'vue';", "import('https://esm.sh/lodash-es/'); import x from 'vue';"),
+            ("function f(require) { require('react'); }", "function f(require) { require('react'); }"),
+        ];
+        Tester::new("test.mjs", options).test(&tests);
+    }
+}
@@ -7,7 +7,10 @@
 //! * <https://babel.dev/docs/presets>
 //! * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformer.ts>
 
+mod conditional_compilation;
 mod context;
+mod css_in_js;
+mod env;
 mod es2015;
 mod es2016;
 mod es2019;
@@ -15,6 +18,7 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod es3;
+mod externals;
 mod options;
 mod proposals;
 mod react_jsx;
@@ -35,7 +39,10 @@ use oxc_span::SourceType;
 use proposals::Decorators;
 
 use crate::{
+    conditional_compilation::ConditionalCompilation,
     context::TransformerCtx,
+    css_in_js::CssInJs,
+    env::Env,
     es2015::*,
     es2016::ExponentiationOperator,
     es2019::{JsonStrings, OptionalCatchBinding},
@@ -43,6 +50,7 @@ use crate::{
     es2021::LogicalAssignmentOperators,
     es2022::ClassStaticBlock,
     es3::PropertyLiteral,
+    externals::Externals,
     react_jsx::ReactJsx,
     regexp::RegexpFlags,
     typescript::TypeScript,
@@ -50,8 +58,12 @@ use crate::{
 };
 
 pub use crate::{
+    conditional_compilation::ConditionalCompilationOptions,
+    css_in_js::{CssInJsExpression, CssInJsHook, CssInJsOptions},
+    env::EnvOptions,
     es2015::ArrowFunctionsOptions,
     es2020::NullishCoalescingOperatorOptions,
+    externals::{External, ExternalsOptions},
     options::{TransformOptions, TransformTarget},
     proposals::DecoratorsOptions,
     react_jsx::{ReactJsxOptions, ReactJsxRuntime, ReactJsxRuntimeOption},
@@ -60,6 +72,7 @@ pub use crate::{
 
 pub struct Transformer<'a> {
     ctx: TransformerCtx<'a>,
+    conditional_compilation: Option<ConditionalCompilation<'a>>,
     decorators: Option<Decorators<'a>>,
     #[allow(unused)]
     typescript: Option<TypeScript<'a>>,
@@ -85,6 +98,9 @@ pub struct Transformer<'a> {
     es2015_instanceof: Option<Instanceof<'a>>,
     es2015_new_target: Option<NewTarget<'a>>,
     es3_property_literal: Option<PropertyLiteral<'a>>,
+    css_in_js: Option<CssInJs<'a>>,
+    externals: Option<Externals<'a>>,
+    env: Option<Env<'a>>,
 }
 
 impl<'a> Transformer<'a> {
@@ -103,6 +119,7 @@ impl<'a> Transformer<'a> {
 
         Self {
             ctx: ctx.clone(),
+            conditional_compilation: ConditionalCompilation::new(Rc::clone(&ast), ctx.clone(), &options),
             decorators: Decorators::new(Rc::clone(&ast), ctx.clone(), &options),
             // TODO: pass verbatim_module_syntax from user config
             typescript: source_type.is_typescript().then(|| TypeScript::new(Rc::clone(&ast), ctx.clone(), false, &options)),
@@ -128,10 +145,24 @@ impl<'a> Transformer<'a> {
             es2015_new_target: NewTarget::new(Rc::clone(&ast),ctx.clone(), &options),
             // other
             es3_property_literal: PropertyLiteral::new(Rc::clone(&ast), &options),
+            css_in_js: CssInJs::new(Rc::clone(&ast), ctx.clone(), &options),
+            externals: Externals::new(Rc::clone(&ast), ctx.clone(), &options),
+            env: Env::new(Rc::clone(&ast), ctx.clone(), &options),
             react_jsx: ReactJsx::new(Rc::clone(&ast), ctx.clone(), options)
         }
     }
 
+    /// Set the hook receiving CSS-in-JS expressions,
+    /// ignored unless [`TransformOptions::css_in_js`] is set.
+    #[must_use]
+    pub fn with_css_in_js_hook<F: FnMut(&CssInJsExpression) -> Option<String> + 'a>(
+        mut self,
+        hook: F,
+    ) -> Self {
+        self.css_in_js.as_mut().map(|t| t.set_hook(Box::new(hook)));
+        self
+    }
+
     /// # Errors
     /// Returns `Vec<Error>` if any errors were collected during the transformation.
     pub fn build(mut self, program: &mut Program<'a>) -> Result<(), Vec<Error>> {
@@ -175,7 +206,10 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
             self.visit_directive(directive);
         }
 
+        self.conditional_compilation.as_mut().map(|t| t.transform_program(program));
+        self.css_in_js.as_mut().map(|t| t.transform_program(program));
         self.typescript.as_mut().map(|t| t.transform_program(program));
+        self.externals.as_mut().map(|t| t.transform_program(program));
         self.visit_statements(&mut program.body);
 
         self.react_jsx.as_mut().map(|t| t.add_react_jsx_runtime_imports(program));
@@ -197,6 +231,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_statements(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        self.conditional_compilation.as_mut().map(|t| t.transform_statements(stmts));
         self.typescript.as_mut().map(|t| t.transform_statements(stmts));
 
         for stmt in stmts.iter_mut() {
@@ -210,6 +245,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.conditional_compilation.as_mut().map(|t| t.transform_statement(stmt));
         self.typescript.as_mut().map(|t| t.transform_statement(stmt));
         self.decorators.as_mut().map(|t| t.transform_statement(stmt));
         self.visit_statement_match(stmt);
@@ -223,6 +259,9 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
         // self.typescript.as_mut().map(|t| t.transform_expression(expr));
+        self.css_in_js.as_mut().map(|t| t.transform_expression(expr));
+        self.externals.as_mut().map(|t| t.transform_expression(expr));
+        self.env.as_mut().map(|t| t.transform_expression(expr));
         self.react_jsx.as_mut().map(|t| t.transform_expression(expr));
         self.regexp_flags.as_mut().map(|t| t.transform_expression(expr));
 
@@ -239,7 +278,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
 
     fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
         let kind = AstKind::CatchClause(self.alloc(clause));
-        self.enter_scope(ScopeFlags::empty());
+        self.enter_scope(ScopeFlags::CatchClause);
         self.enter_node(kind);
 
         self.es2019_optional_catch_binding.as_mut().map(|t| t.transform_catch_clause(clause));
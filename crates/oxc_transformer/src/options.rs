@@ -1,8 +1,10 @@
 use oxc_syntax::assumptions::CompilerAssumptions;
 
 use crate::{
-    es2015::ArrowFunctionsOptions, es2020::NullishCoalescingOperatorOptions,
-    proposals::DecoratorsOptions, react_jsx::ReactJsxOptions, typescript::TypescriptOptions,
+    conditional_compilation::ConditionalCompilationOptions, css_in_js::CssInJsOptions,
+    env::EnvOptions, es2015::ArrowFunctionsOptions, es2020::NullishCoalescingOperatorOptions,
+    externals::ExternalsOptions, proposals::DecoratorsOptions, react_jsx::ReactJsxOptions,
+    typescript::TypescriptOptions,
 };
 
 #[derive(Debug, Default, Clone)]
@@ -38,6 +40,15 @@ pub struct TransformOptions {
     pub new_target: bool,
     // Proposal
     pub decorators: Option<DecoratorsOptions>,
+    // Other
+    /// Remove the code which is inactive for the defined flags
+    pub conditional_compilation: Option<ConditionalCompilationOptions>,
+    /// Call the hook set by [`crate::Transformer::with_css_in_js_hook`] for CSS-in-JS expressions
+    pub css_in_js: Option<CssInJsOptions>,
+    /// Replace the imports of bare specifiers by URLs or globals
+    pub externals: Option<ExternalsOptions>,
+    /// Inline `import.meta.env.*` and `process.env.*`
+    pub env: Option<EnvOptions>,
 }
 
 /// See <https://www.typescriptlang.org/tsconfig#target>
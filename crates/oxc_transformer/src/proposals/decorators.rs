@@ -1,8 +1,12 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use bitflags::bitflags;
 use oxc_allocator::{Box, Vec};
-use oxc_ast::{ast::*, AstBuilder};
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_semantic::ReferenceId;
 use oxc_span::{Atom, SPAN};
 use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
 use serde::Deserialize;
@@ -17,13 +21,15 @@ use crate::{context::TransformerCtx, options::TransformOptions};
 /// * <https://github.com/tc39/proposal-decorators>
 pub struct Decorators<'a> {
     ast: Rc<AstBuilder<'a>>,
-    _ctx: TransformerCtx<'a>,
+    ctx: TransformerCtx<'a>,
     options: DecoratorsOptions,
     // Insert to the top of the program
     top_statements: Vec<'a, Statement<'a>>,
     // Insert to the bottom of the program
     bottom_statements: Vec<'a, Statement<'a>>,
     uid_map: HashMap<Atom, u32>,
+    // References to decorated classes, replaced with the class returned by the class decorators
+    class_references: HashMap<ReferenceId, Atom>,
 }
 
 bitflags! {
@@ -95,11 +101,26 @@ enum Version {
     #[serde(rename = "2023-05")]
     #[default]
     Year202305,
+    /// Adds `Symbol.metadata` support, the parent class is passed to the helper so that
+    /// `context.metadata` inherits from the parent class's metadata object.
+    #[serde(rename = "2023-11")]
+    Year202311,
 }
 impl Version {
     fn is_legacy(self) -> bool {
         matches!(self, Self::Legacy)
     }
+
+    fn has_metadata(self) -> bool {
+        matches!(self, Self::Year202311)
+    }
+
+    fn helper_name(self) -> &'static str {
+        match self {
+            Self::Year202311 => "applyDecs2311",
+            Self::Legacy | Self::Year202305 => "applyDecs2305",
+        }
+    }
 }
 
 impl<'a> Decorators<'a> {
@@ -112,11 +133,12 @@ impl<'a> Decorators<'a> {
         let bottom_statements = ast.new_vec();
         options.decorators.map(|options| Self {
             ast,
-            _ctx: ctx,
+            ctx,
             options,
             top_statements,
             bottom_statements,
             uid_map: HashMap::new(),
+            class_references: HashMap::new(),
         })
     }
 
@@ -170,7 +192,24 @@ impl<'a> Decorators<'a> {
         )
     }
 
+    /// `_call_a` / `_init_a` for the member `a`, `_init_computedKey` for keys without a static name
+    fn get_member_unique_name(
+        &mut self,
+        prefix: &str,
+        key: &PropertyKey<'a>,
+        computed: bool,
+    ) -> Atom {
+        match key.name() {
+            Some(name) if !computed => self.get_unique_name(&format!("{prefix}_{name}").into()),
+            _ => self.get_unique_name(&"init_computedKey".into()),
+        }
+    }
+
     pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if !self.class_references.is_empty() {
+            ClassReferenceReplacer { class_references: &self.class_references }
+                .visit_program(program);
+        }
         program.body.splice(0..0, self.top_statements.drain(..));
         program.body.append(&mut self.bottom_statements);
     }
@@ -220,6 +259,7 @@ impl<'a> Decorators<'a> {
                                                     ),
                                                 )),
                                                 None,
+                                                None,
                                                 ImportOrExportKind::Value,
                                             ),
                                         ),
@@ -273,6 +313,7 @@ impl<'a> Decorators<'a> {
                                             )),
                                         )),
                                         None,
+                                        None,
                                         ImportOrExportKind::Value,
                                     ),
                                 ),
@@ -311,7 +352,7 @@ impl<'a> Decorators<'a> {
         !class.decorators.is_empty() || class.body.body.iter().any(ClassElement::has_decorator)
     }
 
-    /// transform version: 2023-05
+    /// transform version: 2023-05, 2023-11
     pub fn transform_class(
         &mut self,
         class: &mut Box<'a, Class<'a>>,
@@ -339,7 +380,17 @@ impl<'a> Decorators<'a> {
             Argument::Expression(self.ast.array_expression(SPAN, self.ast.new_vec(), None));
 
         if has_decorator {
-            let class_name = class_name.unwrap_or_else(|| self.get_unique_name(&"class".into()));
+            let class_name = class_name.unwrap_or_else(|| {
+                let name = class.id.as_ref().map_or_else(|| "class".into(), |id| id.name.clone());
+                self.get_unique_name(&name)
+            });
+
+            // Other references to the class are to the class returned by the class decorators
+            if let Some(symbol_id) = class.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                for reference_id in self.ctx.symbols().get_resolved_reference_ids(symbol_id) {
+                    self.class_references.insert(*reference_id, class_name.clone());
+                }
+            }
 
             let class_decs_name = self.get_unique_name(&"classDecs".into());
             let init_class_name = self.get_unique_name(&"initClass".into());
@@ -399,7 +450,8 @@ impl<'a> Decorators<'a> {
                 ));
 
             {
-                // call _initClass
+                // call _initClass after the static elements are evaluated,
+                // to run the initializers added by the class decorators with `addInitializer`
                 let callee = self.ast.identifier_reference_expression(IdentifierReference::new(
                     SPAN,
                     init_class_name,
@@ -409,9 +461,11 @@ impl<'a> Decorators<'a> {
                 let statements =
                     self.ast.new_vec_single(self.ast.expression_statement(SPAN, call_expr));
                 let static_block = self.ast.static_block(SPAN, statements);
-                class.body.body.insert(0, static_block);
+                class.body.body.push(static_block);
             }
-        } else if has_member_decorator {
+        }
+
+        if has_member_decorator {
             let mut is_proto = false;
             let mut is_static = false;
 
@@ -505,11 +559,7 @@ impl<'a> Decorators<'a> {
                                 }
                             }
 
-                            name = self.get_unique_name(&if def.computed {
-                                "init_computedKey".into()
-                            } else {
-                                format!("call_{}", def.key.name().unwrap()).into()
-                            });
+                            name = self.get_member_unique_name("call", &def.key, def.computed);
 
                             let mut arguments = self.ast.new_vec_with_capacity(2);
                             arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
@@ -581,11 +631,7 @@ impl<'a> Decorators<'a> {
                             DecoratorFlags::Field
                         };
 
-                        name = self.get_unique_name(&if def.computed {
-                            "init_computedKey".into()
-                        } else {
-                            format!("init_{}", def.key.name().unwrap()).into()
-                        });
+                        name = self.get_member_unique_name("init", &def.key, def.computed);
 
                         def.decorators.iter().for_each(|decorator| {
                             member_decorators_vec.push(ArrayExpressionElement::Expression(
@@ -613,6 +659,36 @@ impl<'a> Decorators<'a> {
                             None,
                         ));
                     }
+                    ClassElement::AccessorProperty(def) => {
+                        let mut flag = DecoratorFlags::Accessor;
+                        if def.r#static {
+                            is_static = true;
+                            flag |= DecoratorFlags::Static;
+                        } else {
+                            is_proto = true;
+                        }
+
+                        name = self.get_member_unique_name("init", &def.key, def.computed);
+
+                        def.decorators.iter().for_each(|decorator| {
+                            member_decorators_vec.push(ArrayExpressionElement::Expression(
+                                self.get_decorator_info(&def.key, None, flag, decorator),
+                            ));
+                        });
+                        def.decorators.clear();
+
+                        // the storage is initialized with `_init_a(this, value)`,
+                        // the getter and setter are created by `lower_accessor_properties`
+                        let mut arguments = self
+                            .ast
+                            .new_vec_single(Argument::Expression(self.ast.this_expression(SPAN)));
+
+                        if let Some(value) = &mut def.value {
+                            arguments.push(Argument::Expression(self.ast.move_expression(value)));
+                        }
+
+                        def.value = Some(self.get_call_with_arguments(name.clone(), arguments));
+                    }
                     _ => return,
                 }
 
@@ -704,54 +780,96 @@ impl<'a> Decorators<'a> {
             //     instanceBrand: Function,
             //     parentClass: any,
             //   ) {}
-            // call babelHelpers.applyDecs2305
+            // applyDecs2311(
+            //     targetClass: any,
+            //     classDecs: Function[],
+            //     memberDecs: DecoratorInfo[],
+            //     classDecsHaveThis: number,
+            //     instanceBrand: Function,
+            //     parentClass: any,
+            //   ) {}
+            // call babelHelpers.applyDecs2305 or babelHelpers.applyDecs2311
+            let version = self.options.version;
             let callee = self.ast.static_member_expression(
                 SPAN,
                 self.ast.identifier_reference_expression(IdentifierReference::new(
                     SPAN,
                     "babelHelpers".into(),
                 )),
-                IdentifierName::new(SPAN, "applyDecs2305".into()),
+                IdentifierName::new(SPAN, version.helper_name().into()),
                 false,
             );
 
+            let member_decorators_argument =
+                Argument::Expression(self.ast.array_expression(SPAN, member_decorators_vec, None));
+            let parent_class =
+                if version.has_metadata() { self.get_parent_class(class) } else { None };
+
             let mut arguments =
                 self.ast.new_vec_single(Argument::Expression(self.ast.this_expression(SPAN)));
-            arguments.push(Argument::Expression(self.ast.array_expression(
-                SPAN,
-                member_decorators_vec,
-                None,
-            )));
-            arguments.push(class_decorators_argument);
-            if let Some(private_in_expression) = private_in_expression {
+            if version.has_metadata() {
+                arguments.push(class_decorators_argument);
+                arguments.push(member_decorators_argument);
+            } else {
+                arguments.push(member_decorators_argument);
+                arguments.push(class_decorators_argument);
+            }
+            if private_in_expression.is_some() || parent_class.is_some() {
                 // classDecsHaveThis
                 arguments.push(Argument::Expression(self.ast.literal_number_expression(
                     // TODO: use correct number instead of `0`
                     self.ast.number_literal(SPAN, 0f64, "0", oxc_syntax::NumberBase::Decimal),
                 )));
                 // instanceBrand
-                arguments.push(Argument::Expression(private_in_expression));
+                arguments.push(Argument::Expression(
+                    private_in_expression.unwrap_or_else(|| self.ast.void_0()),
+                ));
+            }
+            if let Some(parent_class) = parent_class {
+                // parentClass, used for inheriting `Symbol.metadata`
+                arguments.push(Argument::Expression(parent_class));
             }
 
-            let mut call_expr = self.ast.call_expression(SPAN, callee, arguments, false, None);
-
-            if has_decorator && has_decorator == has_member_decorator {
-                // TODO: support this case
-            } else if has_decorator || has_member_decorator {
-                call_expr = self.ast.static_member_expression(
+            let call_expr = self.ast.call_expression(SPAN, callee, arguments, false, None);
+
+            let new_expr = if has_decorator && has_member_decorator {
+                // ({ e: [...], c: [...] } = applyDecs(...))
+                let mut properties = self.ast.new_vec_with_capacity(2);
+                properties.push(self.get_assignment_target_property("e", e_elements));
+                properties.push(self.get_assignment_target_property("c", c_elements));
+                let left = AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(
+                        self.ast.alloc(ObjectAssignmentTarget {
+                            span: SPAN,
+                            properties,
+                            rest: None,
+                        }),
+                    ),
+                );
+                self.ast.parenthesized_expression(
+                    SPAN,
+                    self.ast.assignment_expression(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        left,
+                        call_expr,
+                    ),
+                )
+            } else {
+                // [...] = applyDecs(...).c or [...] = applyDecs(...).e
+                let call_expr = self.ast.static_member_expression(
                     SPAN,
                     call_expr,
                     IdentifierName::new(SPAN, if has_decorator { "c".into() } else { "e".into() }),
                     false,
                 );
-            }
-
-            let left = self.ast.array_assignment_target(ArrayAssignmentTarget::new_with_elements(
-                SPAN,
-                if c_elements.is_empty() { e_elements } else { c_elements },
-            ));
-            let new_expr =
-                self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, call_expr);
+                let left =
+                    self.ast.array_assignment_target(ArrayAssignmentTarget::new_with_elements(
+                        SPAN,
+                        if has_decorator { c_elements } else { e_elements },
+                    ));
+                self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, call_expr)
+            };
 
             let mut statements = self.ast.new_vec();
             statements.push(self.ast.expression_statement(SPAN, new_expr));
@@ -774,9 +892,223 @@ impl<'a> Decorators<'a> {
             class.body.body.insert(0, static_block);
         }
 
+        self.lower_accessor_properties(class);
+
         Declaration::ClassDeclaration(self.ast.copy(class))
     }
 
+    /// `name: [...elements]` of an object assignment target
+    fn get_assignment_target_property(
+        &self,
+        name: &str,
+        elements: Vec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
+    ) -> AssignmentTargetProperty<'a> {
+        let target = self
+            .ast
+            .array_assignment_target(ArrayAssignmentTarget::new_with_elements(SPAN, elements));
+        AssignmentTargetProperty::AssignmentTargetPropertyProperty(self.ast.alloc(
+            AssignmentTargetPropertyProperty {
+                span: SPAN,
+                name: self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into())),
+                binding: AssignmentTargetMaybeDefault::AssignmentTarget(target),
+            },
+        ))
+    }
+
+    /// Returns a reference to the super class which can be evaluated more than once.
+    ///
+    /// `class A extends B {}` returns `B`,
+    /// `class A extends foo() {}` is rewritten to `class A extends (_classSuper = foo()) {}`
+    /// and returns `_classSuper`.
+    fn get_parent_class(&mut self, class: &mut Box<'a, Class<'a>>) -> Option<Expression<'a>> {
+        let super_class = class.super_class.as_mut()?;
+        if let Expression::Identifier(ident) = super_class {
+            return Some(self.ast.identifier_reference_expression(IdentifierReference::new(
+                SPAN,
+                ident.name.clone(),
+            )));
+        }
+
+        let name = self.get_unique_name(&"classSuper".into());
+        let declarations = self.ast.new_vec_single(self.get_variable_declarator(name.clone()));
+        let variable_declaration = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+        self.top_statements
+            .push(Statement::Declaration(Declaration::VariableDeclaration(variable_declaration)));
+
+        let left = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        let right = self.ast.move_expression(super_class);
+        *super_class = self.ast.parenthesized_expression(
+            SPAN,
+            self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, right),
+        );
+
+        Some(self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name)))
+    }
+
+    /// Lower auto-accessors into a private storage field and a getter / setter pair.
+    ///
+    /// ```js
+    /// class A { accessor a = 1 }
+    /// // =>
+    /// class A { #A = 1; get a() { return this.#A; } set a(v) { this.#A = v; } }
+    /// ```
+    fn lower_accessor_properties(&self, class: &mut Box<'a, Class<'a>>) {
+        if !class
+            .body
+            .body
+            .iter()
+            .any(|element| matches!(element, ClassElement::AccessorProperty(_)))
+        {
+            return;
+        }
+
+        // the storage names must not clash with the existing private names of the class
+        let private_names = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| element.property_key()?.private_name())
+            .collect::<HashSet<_>>();
+        let mut storage_count = 0u32;
+        let mut body = self.ast.new_vec_with_capacity(class.body.body.len() + 2);
+        for element in class.body.body.drain(..) {
+            let ClassElement::AccessorProperty(mut def) = element else {
+                body.push(element);
+                continue;
+            };
+
+            let storage_name = Self::get_accessor_storage_name(&mut storage_count, &private_names);
+
+            body.push(self.ast.class_property(
+                def.span,
+                PropertyKey::PrivateIdentifier(
+                    self.ast.alloc(PrivateIdentifier::new(SPAN, storage_name.clone())),
+                ),
+                def.value.take(),
+                false,
+                def.r#static,
+                self.ast.new_vec(),
+            ));
+
+            // get a() { return this.#A; }
+            let getter_body = self.ast.new_vec_single(
+                self.ast.return_statement(SPAN, Some(self.get_storage_field(&storage_name))),
+            );
+            body.push(self.get_accessor_method(
+                &def,
+                MethodDefinitionKind::Get,
+                self.ast.new_vec(),
+                getter_body,
+            ));
+
+            // set a(v) { this.#A = v; }
+            let params = self.ast.new_vec_single(self.ast.formal_parameter(
+                SPAN,
+                self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, "v".into())),
+                    None,
+                    false,
+                ),
+                None,
+                false,
+                self.ast.new_vec(),
+            ));
+            let storage_field = self.ast.private_field(
+                SPAN,
+                self.ast.this_expression(SPAN),
+                PrivateIdentifier::new(SPAN, storage_name),
+                false,
+            );
+            let setter_body = self.ast.new_vec_single(self.ast.expression_statement(
+                SPAN,
+                self.ast.assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    self.ast.simple_assignment_target_member_expression(storage_field),
+                    self.ast.identifier_reference_expression(IdentifierReference::new(
+                        SPAN,
+                        "v".into(),
+                    )),
+                ),
+            ));
+            body.push(self.get_accessor_method(
+                &def,
+                MethodDefinitionKind::Set,
+                params,
+                setter_body,
+            ));
+        }
+        class.body.body = body;
+    }
+
+    /// `#A`, `#B`, ..., `#Z`, `#A1`, ..., skipping `private_names`
+    fn get_accessor_storage_name(index: &mut u32, private_names: &HashSet<Atom>) -> Atom {
+        loop {
+            let letter = char::from(b'A' + u8::try_from(*index % 26).unwrap());
+            let round = *index / 26;
+            *index += 1;
+            let name = if round == 0 {
+                Atom::from(letter.to_string())
+            } else {
+                Atom::from(format!("{letter}{round}"))
+            };
+            if !private_names.contains(&name) {
+                return name;
+            }
+        }
+    }
+
+    /// `this.#A`
+    fn get_storage_field(&self, storage_name: &Atom) -> Expression<'a> {
+        self.ast.member_expression(self.ast.private_field(
+            SPAN,
+            self.ast.this_expression(SPAN),
+            PrivateIdentifier::new(SPAN, storage_name.clone()),
+            false,
+        ))
+    }
+
+    fn get_accessor_method(
+        &self,
+        def: &AccessorProperty<'a>,
+        kind: MethodDefinitionKind,
+        params: Vec<'a, FormalParameter<'a>>,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> ClassElement<'a> {
+        let value = self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            None,
+            self.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, None),
+            Some(self.ast.function_body(SPAN, self.ast.new_vec(), statements)),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        ClassElement::MethodDefinition(self.ast.alloc(MethodDefinition {
+            span: SPAN,
+            key: self.ast.copy(&def.key),
+            value,
+            kind,
+            computed: def.computed,
+            r#static: def.r#static,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        }))
+    }
+
     /// transform version: legacy
     pub fn transform_class_legacy(
         &mut self,
@@ -867,7 +1199,7 @@ impl<'a> Decorators<'a> {
         decorator_elements.push(ArrayExpressionElement::Expression(
             self.ast.literal_number_expression(NumericLiteral::new(
                 SPAN,
-                0f64,
+                f64::from(flag.to_value()),
                 self.ast.new_str(flag.to_value().to_string().as_str()),
                 oxc_syntax::NumberBase::Decimal,
             )),
@@ -990,3 +1322,128 @@ impl<'a> Decorators<'a> {
         self.ast.array_expression(SPAN, decorator_elements, None)
     }
 }
+
+/// Replaces references to decorated classes, see [`Decorators::class_references`]
+struct ClassReferenceReplacer<'b> {
+    class_references: &'b HashMap<ReferenceId, Atom>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ClassReferenceReplacer<'b> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if let Some(name) = ident.reference_id.get().and_then(|id| self.class_references.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+#[test]
+fn test_2023_11() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions { version: Version::Year202311 }),
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class Foo { @dec accessor a = 1; }",
+            "var _init_a, _initProto;
+            class Foo {
+                static { [_init_a, _initProto] = babelHelpers.applyDecs2311(this, [], [[dec, 1, 'a']]).e; }
+                constructor() { _initProto(this); }
+                #A = _init_a(this, 1);
+                get a() { return this.#A; }
+                set a(v) { this.#A = v; }
+            }",
+        ),
+        (
+            "class Foo extends Bar { @dec static method() {} }",
+            "var _initStatic;
+            class Foo extends Bar {
+                static { [_initStatic] = babelHelpers.applyDecs2311(this, [], [[dec, 10, 'method']], 0, void 0, Bar).e; _initStatic(this); }
+                static method() {}
+            }",
+        ),
+        (
+            "class Foo { #A = 1; @dec accessor a = 1; }",
+            "var _init_a, _initProto;
+            class Foo {
+                static { [_init_a, _initProto] = babelHelpers.applyDecs2311(this, [], [[dec, 1, 'a']]).e; }
+                constructor() { _initProto(this); }
+                #A = 1;
+                #B = _init_a(this, 1);
+                get a() { return this.#B; }
+                set a(v) { this.#B = v; }
+            }",
+        ),
+        (
+            "class Foo { @dec [a] = 1; }",
+            "var _init_computedKey;
+            class Foo {
+                static { [_init_computedKey] = babelHelpers.applyDecs2311(this, [], [[dec, 0]]).e; }
+                [a] = _init_computedKey(this, 1);
+            }",
+        ),
+        (
+            "@dec class Foo { static x = Foo; } new Foo();",
+            "var _initClass, _classDecs;
+            _classDecs = [dec];
+            let _Foo;
+            class Foo {
+                static { [_Foo, _initClass] = babelHelpers.applyDecs2311(this, _classDecs, []).c; }
+                static x = _Foo;
+                static { _initClass(); }
+            }
+            new _Foo();",
+        ),
+        (
+            "@dec class Foo { @dec2 method() {} }",
+            "var _initClass, _classDecs, _initProto;
+            _classDecs = [dec];
+            let _Foo;
+            class Foo {
+                static { ({ e: [_initProto], c: [_Foo, _initClass] } = babelHelpers.applyDecs2311(this, _classDecs, [[dec2, 2, 'method']])); }
+                constructor() { _initProto(this); }
+                method() {}
+                static { _initClass(); }
+            }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
+
+#[test]
+fn test_2023_05() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions { version: Version::Year202305 }),
+        ..TransformOptions::default()
+    };
+
+    // The flags are 0 for fields, 1 for accessors, 2 for methods, 3 for getters and 4 for setters,
+    // plus 8 for static members
+    let tests = &[(
+        "class Foo { @dec a = 1; @dec accessor b; @dec method() {} @dec get c() {} @dec set c(v) {} @dec static d() {} }",
+        "var _init_a, _init_b, _initProto, _initStatic;
+        class Foo {
+            static {
+                [_init_a, _init_b, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [[dec, 0, 'a'], [dec, 1, 'b'], [dec, 2, 'method'], [dec, 3, 'c'], [dec, 4, 'c'], [dec, 10, 'd']], []).e;
+                _initStatic(this);
+            }
+            constructor() { _initProto(this); }
+            a = _init_a(this, 1);
+            #A = _init_b(this);
+            get b() { return this.#A; }
+            set b(v) { this.#A = v; }
+            method() {}
+            get c() {}
+            set c(v) {}
+            static d() {}
+        }",
+    )];
+
+    Tester::new("test.js", options).test(tests);
+}
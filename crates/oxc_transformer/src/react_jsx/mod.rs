@@ -313,6 +313,7 @@ impl<'a> ReactJsx<'a> {
             source,
             None,
             ImportOrExportKind::Value,
+            None,
         );
         let decl =
             self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(import_statement));
@@ -277,6 +277,7 @@ impl<'a> TypeScript<'a> {
                 None,
                 self.ast.new_vec(),
                 None,
+                None,
                 ImportOrExportKind::Value,
             );
             let export_decl = ModuleDeclaration::ExportNamedDeclaration(empty_export);
@@ -658,6 +659,7 @@ impl<'a> TypeScript<'a> {
                         Some(decl),
                         self.ast.new_vec(),
                         None,
+                        None,
                         ImportOrExportKind::Value,
                     ),
                 ))
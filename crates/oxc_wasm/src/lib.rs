@@ -123,8 +123,9 @@ impl Oxc {
     ) -> Result<(), serde_wasm_bindgen::Error> {
         self.diagnostics = RefCell::default();
 
-        let allocator = Allocator::default();
         let source_text = &self.source_text;
+        let allocator =
+            Allocator::with_capacity(Allocator::capacity_for_source_len(source_text.len()));
         let path = PathBuf::from("test.tsx");
         let source_type = SourceType::from_path(&path).unwrap_or_default();
 
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use oxc_allocator::Allocator;
+use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{Atom, SourceType};
+use oxc_tasks_common::TestFiles;
+
+/// Comparing and hashing the names of all bindings, like name lookups in semantic analysis and
+/// lint rules do.
+fn bench_atom(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("atom");
+    for file in TestFiles::complicated().files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &file.source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(&file.source_text, source_type).build(program).semantic;
+        let names = semantic.symbols().names.iter().cloned().collect::<Vec<Atom>>();
+        group.bench_with_input(BenchmarkId::from_parameter(&file.file_name), &names, |b, names| {
+            b.iter(|| {
+                let equal = names.windows(2).filter(|pair| pair[0] == pair[1]).count();
+                let unique = names.iter().collect::<HashSet<_>>().len();
+                (equal, unique)
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(atom, bench_atom);
+criterion_main!(atom);
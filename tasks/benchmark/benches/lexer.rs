@@ -1,3 +1,5 @@
+use std::hint::black_box;
+
 use oxc_allocator::Allocator;
 use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use oxc_parser::lexer::{Kind, Lexer};
@@ -42,5 +44,25 @@ fn bench_lexer(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(lexer, bench_lexer);
+/// Identifier vs keyword classification, on every word of the test files
+fn bench_match_keyword(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("match_keyword");
+    for file in TestFiles::complicated().files() {
+        let words = file
+            .source_text
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::from_parameter(&file.file_name), &words, |b, words| {
+            b.iter(|| {
+                for word in words {
+                    black_box(Kind::match_keyword(black_box(word)));
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(lexer, bench_lexer, bench_match_keyword);
 criterion_main!(lexer);
@@ -1,6 +1,6 @@
 use oxc_allocator::Allocator;
 use oxc_benchmark::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 use oxc_tasks_common::TestFiles;
 
@@ -26,5 +26,26 @@ fn bench_parser(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(parser, bench_parser);
+fn bench_parser_minimal(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("parser_minimal");
+    for file in TestFiles::complicated().files() {
+        let source_type = SourceType::from_path(&file.file_name).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&file.file_name),
+            &file.source_text,
+            |b, source_text| {
+                b.iter_with_large_drop(|| {
+                    let allocator = Allocator::default();
+                    _ = Parser::new(&allocator, source_text, source_type)
+                        .with_options(ParseOptions::minimal())
+                        .parse();
+                    allocator
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(parser, bench_parser, bench_parser_minimal);
 criterion_main!(parser);
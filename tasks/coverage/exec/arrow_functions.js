@@ -0,0 +1,16 @@
+// `this` and `arguments` are captured from the enclosing function
+function Counter() {
+  this.count = 0;
+  const increment = () => {
+    this.count += arguments.length;
+    return this.count;
+  };
+  increment();
+  increment();
+  return this;
+}
+console.log(new Counter(1, 2, 3).count);
+
+const nested = () => () => () => 42;
+console.log(nested()()());
+console.log([1, 2, 3].map((x) => ({ x })).map(({ x }) => x * 2).join(","));
@@ -0,0 +1,31 @@
+class Animal {
+  static count = 0;
+  #name;
+  constructor(name) {
+    this.#name = name;
+    Animal.count++;
+  }
+  get name() {
+    return this.#name;
+  }
+  speak() {
+    return `${this.name} makes a sound`;
+  }
+  static {
+    this.kind = "animal";
+  }
+}
+
+class Dog extends Animal {
+  speak() {
+    return `${super.speak()}, ${this.name} barks`;
+  }
+  toString() {
+    return new.target === undefined ? "dog" : "constructed";
+  }
+}
+
+const dog = new Dog("Rex");
+console.log(dog.speak(), Animal.count, Animal.kind, dog instanceof Animal, String(dog));
+console.log(Object.getOwnPropertyNames(Dog.prototype).join(","));
+console.log(Dog.name, Animal.prototype.speak.name);
@@ -0,0 +1,29 @@
+// Every iteration of a `let` loop gets its own binding, `var` is shared
+const lets = [];
+for (let i = 0; i < 3; i++) {
+  lets.push(() => i);
+}
+const vars = [];
+for (var j = 0; j < 3; j++) {
+  vars.push(() => j);
+}
+console.log(lets.map((f) => f()).join(","), vars.map((f) => f()).join(","));
+
+function makeCounter() {
+  let count = 0;
+  return { increment: () => ++count, get value() { return count; } };
+}
+const counter = makeCounter();
+counter.increment();
+counter.increment();
+console.log(counter.value);
+
+// Shadowing and hoisting
+var shadowed = "outer";
+function hoisting() {
+  console.log(typeof hoisted, shadowed);
+  var shadowed = "inner";
+  function hoisted() {}
+  return shadowed;
+}
+console.log(hoisting());
@@ -0,0 +1,47 @@
+function classify(value) {
+  switch (typeof value) {
+    case "number":
+      if (value < 0) return "negative";
+    // falls through
+    case "bigint":
+      return "numeric";
+    case "string":
+      return "string";
+    default:
+      return "other";
+  }
+}
+console.log([1, -1, 1n, "a", null].map(classify).join(","));
+
+const found = [];
+outer: for (let i = 0; i < 3; i++) {
+  for (let j = 0; j < 3; j++) {
+    if (j === 1) continue outer;
+    if (i === 2) break outer;
+    found.push(`${i}${j}`);
+  }
+}
+console.log(found.join(","));
+
+function finallyOverrides() {
+  try {
+    return "try";
+  } finally {
+    console.log("finally runs");
+  }
+}
+console.log(finallyOverrides());
+
+try {
+  null.property;
+} catch {
+  console.log("caught without binding");
+}
+
+let count = 0;
+do count++; while (count < 5);
+console.log(count);
+
+// Automatic semicolon insertion
+const asi = 1
+;[2, 3].forEach((x) => console.log(x + asi))
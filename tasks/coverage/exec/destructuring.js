@@ -0,0 +1,18 @@
+const { a, b: { c = 2 } = {}, ...rest } = { a: 1, d: 4, e: 5 };
+console.log(a, c, JSON.stringify(rest));
+
+const [first, , third = "default", ...others] = [1, 2, undefined, 4, 5];
+console.log(first, third, others.join(","));
+
+function parameters({ x = 1, y } = {}, [z] = [3], ...args) {
+  return [x, y, z, args.length].join(",");
+}
+console.log(parameters(), parameters({ y: 2 }, [4], 5, 6));
+
+let left = 1;
+let right = 2;
+[left, right] = [right, left];
+console.log(left, right);
+
+const shorthand = { left, right, method() { return left + right; } };
+console.log(JSON.stringify(shorthand), shorthand.method(), shorthand.method.name);
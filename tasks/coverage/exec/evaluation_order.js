@@ -0,0 +1,23 @@
+// Side effects must happen once and in source order
+const log = [];
+function effect(name, value) {
+  log.push(name);
+  return value;
+}
+const object = { a: 1 };
+effect("object", object)[effect("key", "a")] += effect("value", 2);
+console.log(object.a, log.join(","));
+
+log.length = 0;
+const result = (effect("left", 0) || effect("right", 0)) ?? effect("nullish", 3);
+console.log(result, log.join(","));
+
+log.length = 0;
+const sequence = (effect("first", 1), effect("second", 2));
+console.log(sequence, log.join(","));
+
+let i = 0;
+const array = [i++, i++, ++i, i--];
+console.log(array.join(","), i);
+
+console.log(2 ** 3 ** 2, (-2) ** 2, 10 - 2 - 3, 2 * 3 % 4);
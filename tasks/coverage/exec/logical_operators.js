@@ -0,0 +1,19 @@
+const values = [0, "", null, undefined, false, NaN, "x", 1];
+console.log(values.map((value) => String(value ?? "default")).join(","));
+console.log(values.map((value) => String(value || "default")).join(","));
+console.log(values.map((value) => String(value && "truthy")).join(","));
+
+const object = { nested: { value: 1 }, method() { return this.nested.value; } };
+console.log(object?.nested?.value, object.missing?.value, object.method?.(), object.missing?.());
+
+let a = null;
+let b = 0;
+let c = 1;
+a ??= "a";
+b ||= "b";
+c &&= "c";
+console.log(a, b, c);
+
+const getters = { count: 0, get value() { this.count++; return null; } };
+getters.value ?? getters.value;
+console.log(getters.count);
@@ -0,0 +1,11 @@
+const name = "world";
+console.log(`hello ${name}!`, `multi
+line`, `\${escaped}`, `${1 + 1}${"a"}`);
+console.log("quotes: ' \" `", 'single \' quote', "\u{1F600}".length, "\x41B");
+console.log(String.raw`a\nb${name}`, "tab\tnewline\\n");
+function tag(strings, ...values) {
+  return strings.raw.join("|") + values.join("|");
+}
+console.log(tag`a${1}b${2}c`);
+console.log("a-b_c".replace(/[-_]/g, " "), /(?<year>\d{4})/.exec("in 2024").groups.year);
+console.log(JSON.stringify({ "key with space": 1, "0": 2, valid_identifier: 3 }));
@@ -151,7 +151,7 @@ fn get_typescript_result(
     source_text: &str,
     source_type: SourceType,
 ) -> bool {
-    let options = CodegenOptions { enable_typescript: true };
+    let options = CodegenOptions { enable_typescript: true, ..CodegenOptions::default() };
     let allocator = Allocator::default();
     let parse_result1 = Parser::new(&allocator, source_text, source_type).parse();
     let source_text1 =
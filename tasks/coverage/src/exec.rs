@@ -0,0 +1,189 @@
+//! Execute the programs in `tasks/coverage/exec` before and after each pass with Node.js
+//! and compare what they print.
+//!
+//! Output-diff tests check that the output looks as expected,
+//! this catches passes which change the behavior of the program.
+//! Requires `node` in `PATH`.
+
+use std::{
+    fs::{self, File},
+    io::{stdout, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_tasks_common::{normalize_path, print_diff_in_terminal};
+use oxc_transformer::{TransformOptions, TransformTarget, Transformer};
+use walkdir::WalkDir;
+
+use crate::{project_root, AppArgs};
+
+const CORPUS_PATH: &str = "tasks/coverage/exec";
+
+#[derive(Debug, Clone, Copy)]
+enum Pass {
+    Codegen,
+    Minifier,
+    /// Lower to ES5
+    Transformer,
+}
+
+const PASSES: [Pass; 3] = [Pass::Codegen, Pass::Minifier, Pass::Transformer];
+
+impl Pass {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Codegen => "codegen",
+            Self::Minifier => "minifier",
+            Self::Transformer => "transformer",
+        }
+    }
+
+    fn run(self, source_text: &str, source_type: SourceType) -> Result<String, String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        if let Some(error) = ret.errors.first() {
+            return Err(error.to_string());
+        }
+        let code = match self {
+            Self::Codegen => Codegen::<false>::new(source_text.len(), CodegenOptions::default())
+                .build(&ret.program),
+            Self::Minifier => {
+                let program = allocator.alloc(ret.program);
+                Minifier::new(MinifierOptions::default()).build(&allocator, program);
+                Codegen::<true>::new(source_text.len(), CodegenOptions::default()).build(program)
+            }
+            Self::Transformer => {
+                let semantic = SemanticBuilder::new(source_text, source_type)
+                    .with_trivias(ret.trivias)
+                    .build(&ret.program)
+                    .semantic;
+                let program = allocator.alloc(ret.program);
+                let options = TransformOptions {
+                    target: TransformTarget::ES5,
+                    ..TransformOptions::default()
+                };
+                Transformer::new(&allocator, source_type, semantic, options)
+                    .build(program)
+                    .map_err(|errors| {
+                        errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+                    })?;
+                Codegen::<false>::new(source_text.len(), CodegenOptions::default()).build(program)
+            }
+        };
+        Ok(code)
+    }
+}
+
+struct Mismatch {
+    path: PathBuf,
+    pass: Pass,
+    expected: String,
+    actual: String,
+}
+
+/// Run the corpus and save the report to `tasks/coverage/{name}.snap`
+pub fn run(name: &str, args: &AppArgs) {
+    let corpus_root = project_root().join(CORPUS_PATH);
+    let mut paths = WalkDir::new(&corpus_root)
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| path.extension().is_some_and(|ext| ext == "js"))
+        .filter(|path| {
+            args.filter.as_ref().map_or(true, |filter| path.to_string_lossy().contains(filter))
+        })
+        .collect::<Vec<_>>();
+    paths.sort_unstable();
+
+    let temp_dir = std::env::temp_dir().join("oxc_coverage_exec");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let mut mismatches = vec![];
+    for path in &paths {
+        let source_text = fs::read_to_string(path).unwrap();
+        let source_type = SourceType::default();
+        let expected = execute(path);
+        for pass in PASSES {
+            let actual = match pass.run(&source_text, source_type) {
+                Ok(code) => {
+                    let file_name = format!(
+                        "{}.{}.cjs",
+                        path.file_stem().unwrap().to_string_lossy(),
+                        pass.name()
+                    );
+                    let output_path = temp_dir.join(file_name);
+                    fs::write(&output_path, &code).unwrap();
+                    execute(&output_path)
+                }
+                Err(error) => error,
+            };
+            if actual != expected {
+                let path = path.strip_prefix(&corpus_root).unwrap().to_path_buf();
+                mismatches.push(Mismatch { path, pass, expected: expected.clone(), actual });
+            }
+        }
+    }
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    print_report(name, &paths, &mismatches, &mut stdout()).unwrap();
+    if args.diff {
+        for mismatch in &mismatches {
+            println!("{} {}:", mismatch.pass.name(), normalize_path(&mismatch.path));
+            print_diff_in_terminal(&mismatch.expected, &mismatch.actual);
+        }
+    }
+    if args.filter.is_none() {
+        let path = project_root().join(format!("tasks/coverage/{name}.snap"));
+        let mut file = File::create(path).unwrap();
+        print_report(name, &paths, &mismatches, &mut file).unwrap();
+    }
+}
+
+/// stdout and stderr of running `path` with Node.js
+///
+/// # Panics
+///
+/// Panics if `node` is not installed.
+fn execute(path: &Path) -> String {
+    let output = Command::new("node")
+        .arg(path)
+        .output()
+        .expect("Failed to run `node`, install Node.js to run the exec tests");
+    let mut result = String::from_utf8_lossy(&output.stdout).to_string();
+    if !output.status.success() {
+        // Only the first line, the stack trace contains the path of the executed file
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        result.push_str(stderr.lines().find(|line| line.contains("Error")).unwrap_or_default());
+    }
+    result
+}
+
+fn print_report<W: Write>(
+    name: &str,
+    paths: &[PathBuf],
+    mismatches: &[Mismatch],
+    writer: &mut W,
+) -> std::io::Result<()> {
+    writeln!(writer, "{name} Summary:")?;
+    for pass in PASSES {
+        let failed = mismatches.iter().filter(|mismatch| mismatch.pass.name() == pass.name());
+        let passed = paths.len() - failed.count();
+        writeln!(writer, "{:<12}: {passed}/{}", pass.name(), paths.len())?;
+    }
+    for mismatch in mismatches {
+        writeln!(
+            writer,
+            "Mismatch ({}): {}",
+            mismatch.pass.name(),
+            normalize_path(&mismatch.path)
+        )?;
+    }
+    writer.flush()
+}
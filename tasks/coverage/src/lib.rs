@@ -1,5 +1,6 @@
 mod babel;
 mod codegen;
+mod exec;
 mod minifier;
 mod misc;
 mod prettier;
@@ -119,6 +120,12 @@ impl AppArgs {
             .expect("Write v8 test262 status failed");
     }
 
+    /// Execute the exec corpus before and after codegen, minification and transformation.
+    /// Not part of `run_all` because it requires Node.js.
+    pub fn run_exec(&self) {
+        exec::run("exec", self);
+    }
+
     pub fn run_minifier(&self) {
         Test262Suite::<MinifierTest262Case>::new().run("minifier_test262", self);
         BabelSuite::<MinifierBabelCase>::new().run("minifier_babel", self);
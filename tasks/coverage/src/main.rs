@@ -19,6 +19,7 @@ fn main() {
         "codegen-runtime" => args.run_codegen_runtime(),
         "prettier" => args.run_prettier(),
         "minifier" => args.run_minifier(),
+        "exec" => args.run_exec(),
         "v8_test262_status" => args.run_sync_v8_test262_status(),
         _ => args.run_all(),
     };
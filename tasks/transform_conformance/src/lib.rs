@@ -6,7 +6,7 @@ use std::{
     path::{Path, PathBuf},
     process::Command,
 };
-use test_case::TestCaseKind;
+use test_case::{TestCaseKind, TestResult};
 use walkdir::WalkDir;
 
 mod test_case;
@@ -184,6 +184,7 @@ impl TestRunner {
         let mut total = 0;
         let mut all_passed = vec![];
         let mut all_passed_count = 0;
+        let mut semantic_match_count = 0;
 
         for (case, test_cases) in paths {
             // Skip empty test cases, e.g. some cases do not have `exec.js` file.
@@ -196,9 +197,13 @@ impl TestRunner {
             total += num_of_tests;
 
             // Run the test
-            let (passed, failed): (Vec<TestCaseKind>, Vec<TestCaseKind>) = test_cases
+            let (passed, failed): (Vec<_>, Vec<_>) = test_cases
                 .into_iter()
-                .partition(|test_case| test_case.test(self.options.filter.is_some()));
+                .map(|test_case| {
+                    let result = test_case.test(self.options.filter.is_some());
+                    (test_case, result)
+                })
+                .partition(|(_, result)| *result == TestResult::Passed);
             all_passed_count += passed.len();
 
             // Snapshot
@@ -208,11 +213,15 @@ impl TestRunner {
                 snapshot.push_str("# ");
                 snapshot.push_str(&case);
                 snapshot.push_str(&format!(" ({}/{})\n", passed.len(), num_of_tests));
-                for test_case in failed {
+                for (test_case, result) in failed {
                     snapshot.push_str("* ");
                     snapshot.push_str(&normalize_path(
                         test_case.path().strip_prefix(&case_root).unwrap(),
                     ));
+                    if result == TestResult::SemanticMatch {
+                        semantic_match_count += 1;
+                        snapshot.push_str(" (semantic match)");
+                    }
                     snapshot.push('\n');
                 }
                 snapshot.push('\n');
@@ -223,7 +232,7 @@ impl TestRunner {
             let all_passed =
                 all_passed.into_iter().map(|s| format!("* {s}")).collect::<Vec<_>>().join("\n");
             let snapshot = format!(
-                "Passed: {all_passed_count}/{total}\n\n# All Passed:\n{all_passed}\n\n\n{snapshot}"
+                "Passed: {all_passed_count}/{total}\nSemantic matches: {semantic_match_count}\n\n# All Passed:\n{all_passed}\n\n\n{snapshot}"
             );
             let mut file = File::create(dest).unwrap();
             file.write_all(snapshot.as_bytes()).unwrap();
@@ -0,0 +1,201 @@
+//! Generates the Unicode tables of `oxc_syntax` from the Unicode Character Database.
+//!
+//! ```bash
+//! just unicode 16.0.0
+//! ```
+//!
+//! The tables only change with a new Unicode version, which ECMAScript follows,
+//! so run this for each release of <https://www.unicode.org/versions/latest/>.
+
+use std::fmt::Write;
+
+use oxc_tasks_common::{agent, project_root};
+
+const OUTPUT_PATH: &str = "crates/oxc_syntax/src/unicode_tables.rs";
+
+/// Code points in each bitmap of the tables
+const LEAF_BITS: u32 = 64;
+/// Leaves in each chunk of the tables, so a chunk covers `LEAF_BITS * CHUNK_LEAVES` code points
+const CHUNK_LEAVES: usize = 8;
+
+struct Table {
+    name: &'static str,
+    doc: &'static str,
+    file: &'static str,
+    property: &'static str,
+}
+
+const TABLES: [Table; 3] = [
+    Table {
+        name: "ID_START",
+        doc: "Characters with the `ID_Start` property",
+        file: "DerivedCoreProperties.txt",
+        property: "ID_Start",
+    },
+    Table {
+        name: "ID_CONTINUE",
+        doc: "Characters with the `ID_Continue` property",
+        file: "DerivedCoreProperties.txt",
+        property: "ID_Continue",
+    },
+    Table {
+        name: "SPACE_SEPARATOR",
+        doc: "Characters of the `Space_Separator` (`Zs`) general category",
+        file: "extracted/DerivedGeneralCategory.txt",
+        property: "Zs",
+    },
+];
+
+fn main() {
+    let version = std::env::args().nth(1).expect("Usage: just unicode <version>, e.g. 16.0.0");
+    let version_numbers = version
+        .split('.')
+        .map(|n| n.parse::<u8>().expect("version should be in the form `16.0.0`"))
+        .collect::<Vec<_>>();
+    let [major, minor, update] = version_numbers[..] else {
+        panic!("version should be in the form `16.0.0`");
+    };
+
+    let mut out = String::new();
+    writeln!(out, "// Auto-generated code, DO NOT EDIT DIRECTLY!").unwrap();
+    writeln!(out, "// To regenerate run `just unicode <version>`, see `tasks/unicode`.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use crate::identifier::CharTable;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// Version of the Unicode Character Database the tables are generated from,")
+        .unwrap();
+    writeln!(out, "/// in the same format as [`char::UNICODE_VERSION`].").unwrap();
+    writeln!(out, "pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});")
+        .unwrap();
+
+    let mut files: Vec<(&str, String)> = vec![];
+    for table in &TABLES {
+        if !files.iter().any(|(file, _)| *file == table.file) {
+            files.push((table.file, download(&version, table.file)));
+        }
+        let text = &files.iter().find(|(file, _)| *file == table.file).unwrap().1;
+        let ranges = parse_ranges(text, table.property);
+        assert!(!ranges.is_empty(), "`{}` not found in {}", table.property, table.file);
+        write_table(&mut out, table, &ranges);
+    }
+
+    let path = project_root().join(OUTPUT_PATH);
+    std::fs::write(&path, out).unwrap();
+    println!("Unicode {version} tables written to {}", path.display());
+}
+
+fn download(version: &str, file: &str) -> String {
+    let url = format!("https://www.unicode.org/Public/{version}/ucd/{file}");
+    println!("Downloading {url}");
+    agent()
+        .get(&url)
+        .call()
+        .unwrap_or_else(|e| panic!("Failed to download {url}: {e}"))
+        .into_string()
+        .unwrap()
+}
+
+/// Sorted and merged code point ranges of `property` in a UCD file with lines like
+/// `0041..005A    ; ID_Start # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z`
+fn parse_ranges(text: &str, property: &str) -> Vec<(u32, u32)> {
+    let mut ranges = text
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next()?;
+            let (code_points, value) = line.split_once(';')?;
+            if value.trim() != property {
+                return None;
+            }
+            let code_points = code_points.trim();
+            let (start, end) = code_points.split_once("..").unwrap_or((code_points, code_points));
+            let parse = |s: &str| u32::from_str_radix(s, 16).unwrap();
+            Some((parse(start), parse(end)))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Write `ranges` as an `oxc_syntax::identifier::CharTable`: bitmaps of
+/// `LEAF_BITS` code points, grouped into chunks of `CHUNK_LEAVES` bitmaps, both deduplicated.
+fn write_table(out: &mut String, table: &Table, ranges: &[(u32, u32)]) {
+    let chunk_bits = LEAF_BITS as usize * CHUNK_LEAVES;
+    let max = ranges.last().unwrap().1 as usize;
+    let mut bitmaps = vec![0u64; (max / chunk_bits + 1) * CHUNK_LEAVES];
+    for &(start, end) in ranges {
+        for c in start..=end {
+            bitmaps[(c / LEAF_BITS) as usize] |= 1 << (c % LEAF_BITS);
+        }
+    }
+
+    let mut leaves: Vec<u64> = vec![];
+    let mut chunks: Vec<Vec<u16>> = vec![];
+    let mut index: Vec<u8> = vec![];
+    for chunk in bitmaps.chunks(CHUNK_LEAVES) {
+        let chunk = chunk
+            .iter()
+            .map(|bitmap| {
+                let leaf = leaves.iter().position(|leaf| leaf == bitmap).unwrap_or_else(|| {
+                    leaves.push(*bitmap);
+                    leaves.len() - 1
+                });
+                u16::try_from(leaf).expect("too many distinct leaves")
+            })
+            .collect::<Vec<_>>();
+        let chunk = chunks.iter().position(|c| *c == chunk).unwrap_or_else(|| {
+            chunks.push(chunk);
+            chunks.len() - 1
+        });
+        index.push(u8::try_from(chunk).expect("too many distinct chunks"));
+    }
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// {}, from `{}`", table.doc, table.file).unwrap();
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(out, "pub static {}: CharTable = CharTable {{", table.name).unwrap();
+    write_entries(out, "index", 24, index.iter().map(ToString::to_string));
+    write_entries(
+        out,
+        "chunks",
+        2,
+        chunks.iter().map(|chunk| {
+            let leaves = chunk.iter().map(ToString::to_string).collect::<Vec<_>>();
+            format!("[{}]", leaves.join(", "))
+        }),
+    );
+    write_entries(
+        out,
+        "leaves",
+        4,
+        leaves.iter().map(|leaf| {
+            let [a, b, c, d] = [48, 32, 16, 0].map(|shift| (leaf >> shift) & 0xffff);
+            format!("0x{a:04x}_{b:04x}_{c:04x}_{d:04x}")
+        }),
+    );
+    writeln!(out, "}};").unwrap();
+}
+
+fn write_entries<I>(out: &mut String, field: &str, per_line: usize, entries: I)
+where
+    I: Iterator<Item = String>,
+{
+    let entries = entries.collect::<Vec<_>>();
+    writeln!(out, "    {field}: &[").unwrap();
+    for line in entries.chunks(per_line) {
+        writeln!(
+            out,
+            "        {}",
+            line.iter().map(|entry| format!("{entry},")).collect::<Vec<_>>().join(" ")
+        )
+        .unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+}